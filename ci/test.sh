@@ -54,6 +54,9 @@ check() {
     cd ../lexical-write-integer
     cargo check --tests
 
+    cd ../lexical-format-macro
+    cargo check --tests
+
     cd ..
 }
 
@@ -79,6 +82,26 @@ test() {
     cargo test $test_features $DOCTESTS --release
 }
 
+# Verify the parsers and writers have no reachable panics.
+no_panic() {
+    if [ ! -z $DISABLE_TESTS ]; then
+        return
+    fi
+    if [ ! -z $NO_STD ]; then
+        return
+    fi
+
+    # The panic checks only link with optimizations.
+    for crate in lexical-parse-integer lexical-parse-float lexical-write-integer lexical-write-float; do
+        cd "$crate"
+        cargo test --release --features=no-panic --test no_panic_tests
+        for features in "${FEATURES[@]}"; do
+            cargo test --release --features=no-panic,$features --test no_panic_tests
+        done
+        cd ..
+    done
+}
+
 # Dry-run bench target
 bench() {
     if [ ! -z $DISABLE_TESTS ]; then
@@ -105,6 +128,7 @@ main() {
     check
     build
     test
+    no_panic
     bench
 
     if [ ! -z $NIGHTLY ]; then
@@ -0,0 +1,27 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_util::digit::char_is_digit_const;
+use lexical_util::format::{is_valid_radix, Format, NumberFormatBuilder};
+
+fuzz_target!(|bits: u128| {
+    // Re-packing the arbitrary bits drops any unused bits.
+    let format = NumberFormatBuilder::from_packed(bits).build();
+    let builder = NumberFormatBuilder::from_packed(format);
+    assert_eq!(builder.build(), format);
+
+    // The checked builder and the runtime format must agree on validity.
+    let runtime = Format::new(format);
+    assert_eq!(builder.build_checked().is_ok(), runtime.is_ok());
+    if let Ok(runtime) = runtime {
+        assert_eq!(runtime.packed(), format);
+        let radix = runtime.radix();
+        assert!(is_valid_radix(radix));
+        assert!(is_valid_radix(runtime.exponent_base()));
+        assert!(is_valid_radix(runtime.exponent_radix()));
+        // Punctuation must never be mistaken for a digit.
+        for &c in [runtime.digit_separator(), runtime.base_prefix(), runtime.base_suffix()].iter() {
+            assert!(c == 0 || !char_is_digit_const(c, radix));
+        }
+    }
+});
@@ -0,0 +1,28 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use lexical_parse_float::FromLexical as _;
+use lexical_parse_integer::FromLexical as _;
+
+fuzz_target!(|data: &[u8]| {
+    // Re-parsing the consumed prefix as a complete number must give the same value.
+    if let Ok((value, count)) = f64::from_lexical_partial(data) {
+        assert!(count <= data.len());
+        let complete = f64::from_lexical(&data[..count]).unwrap();
+        assert!(value.to_bits() == complete.to_bits() || (value.is_nan() && complete.is_nan()));
+    }
+    // Partial integer parsers return 0 if no digits were consumed, which
+    // the complete parsers reject, so only re-parse prefixes with digits.
+    if let Ok((value, count)) = i64::from_lexical_partial(data) {
+        assert!(count <= data.len());
+        if data[..count].iter().any(u8::is_ascii_digit) {
+            assert_eq!(i64::from_lexical(&data[..count]), Ok(value));
+        }
+    }
+    if let Ok((value, count)) = u64::from_lexical_partial(data) {
+        assert!(count <= data.len());
+        if data[..count].iter().any(u8::is_ascii_digit) {
+            assert_eq!(u64::from_lexical(&data[..count]), Ok(value));
+        }
+    }
+});
@@ -0,0 +1,77 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+use core::num;
+use lexical_parse_float::FromLexicalWithOptions;
+use lexical_util::format::STANDARD;
+use lexical_util::num::Float;
+use lexical_write_float::ToLexicalWithOptions;
+
+type ParseOptions = lexical_parse_float::Options;
+type WriteOptions = lexical_write_float::Options;
+
+/// Get the write and parse options from the fuzzed configuration bits.
+///
+/// Only options which write the shortest, correctly rounded digits are
+/// used, and negative zero is always signed, so every float must
+/// round-trip exactly.
+fn options(config: u32) -> (WriteOptions, ParseOptions) {
+    let min_digits = config & 0x3F;
+    let positive_break = (config >> 6) & 0x1F;
+    let negative_break = (config >> 11) & 0x1F;
+    let trim_floats = config & (1 << 16) != 0;
+    let exponent = if config & (1 << 17) != 0 {
+        b'^'
+    } else {
+        b'e'
+    };
+    let decimal_point = if config & (1 << 18) != 0 {
+        b','
+    } else {
+        b'.'
+    };
+    let min_exponent_digits = (config >> 19) & 0x3;
+
+    let write = WriteOptions::builder()
+        .min_significant_digits(num::NonZeroUsize::new(min_digits as usize))
+        .positive_exponent_break(num::NonZeroI32::new(positive_break as i32))
+        .negative_exponent_break(num::NonZeroI32::new(-(negative_break as i32)))
+        .min_exponent_digits(num::NonZeroUsize::new(min_exponent_digits as usize))
+        .trim_floats(trim_floats)
+        .signed_zero(true)
+        .exponent(exponent)
+        .decimal_point(decimal_point)
+        .build()
+        .unwrap();
+    let parse =
+        ParseOptions::builder().exponent(exponent).decimal_point(decimal_point).build().unwrap();
+    (write, parse)
+}
+
+/// Write the float, parse it back, and check the bits are identical.
+fn roundtrip<F>(value: F, write: &WriteOptions, parse: &ParseOptions)
+where
+    F: Float
+        + ToLexicalWithOptions<Options = WriteOptions>
+        + FromLexicalWithOptions<Options = ParseOptions>,
+{
+    let mut buffer = vec![0u8; write.buffer_size_const::<STANDARD>()];
+    let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, write);
+    let parsed = match F::from_lexical_with_options::<STANDARD>(digits, parse) {
+        Ok(parsed) => parsed,
+        Err(error) => panic!("failed to parse {:?}: {:?}", std::str::from_utf8(digits), error),
+    };
+    // NaN payloads are not preserved, so only the NaN-ness must match.
+    if value.is_nan() {
+        assert!(parsed.is_nan());
+    } else {
+        assert!(value.to_bits() == parsed.to_bits());
+    }
+}
+
+fuzz_target!(|input: (u32, u64, u32)| {
+    let (f32_bits, f64_bits, config) = input;
+    let (write, parse) = options(config);
+    roundtrip(f32::from_bits(f32_bits), &write, &parse);
+    roundtrip(f64::from_bits(f64_bits), &write, &parse);
+});
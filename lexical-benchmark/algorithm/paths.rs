@@ -0,0 +1,250 @@
+//! Benchmark the individual algorithms used to parse floats.
+//!
+//! This isolates the fast path, the moderate path (Eisel-Lemire), and
+//! the slow path algorithms (`digit_comp` and `byte_comp`), so regressions
+//! in the big-integer arithmetic are not hidden by the parsing overhead.
+//! The near-halfway inputs require every digit to correctly round the
+//! float, and are parsed end-to-end with up to 10,000 digits and, with
+//! the `radix` feature, in every radix.
+
+use core::time::Duration;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use fastrand::Rng;
+use lexical_parse_float::float::ExtendedFloat80;
+use lexical_parse_float::number::Number;
+use lexical_parse_float::parse::{self, ParseFloat};
+#[cfg(feature = "radix")]
+use lexical_parse_float::slow;
+use lexical_parse_float::{lemire, shared, FromLexicalWithOptions, Options};
+#[cfg(feature = "radix")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use lexical_util::iterator::AsBytes;
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// INPUT
+
+/// Get the exact digits of `1 + 2^-53`, halfway between `1.0` and the next `f64`.
+///
+/// The digits are truncated to `count` fraction digits for radixes without
+/// a finite representation, and otherwise padded with zeros, followed by a
+/// `1` to be just above halfway. Either way, the float can only be correctly
+/// rounded by processing every digit.
+fn halfway(radix: u32, count: usize) -> String {
+    let mut string = String::from("1.");
+    let mut numerator = 1u64;
+    while string.len() < count + 2 {
+        numerator *= radix as u64;
+        let digit = (numerator >> 53) as u32;
+        numerator &= (1 << 53) - 1;
+        string.push(core::char::from_digit(digit, radix).unwrap().to_ascii_uppercase());
+    }
+    if numerator == 0 {
+        string.pop();
+        string.push('1');
+    }
+    string
+}
+
+/// Get the decimal digits of `mant * 2^exp`, an integer.
+fn integer_digits(mant: u64, exp: u32) -> String {
+    // Store the digits in little-endian order, and double them `exp` times.
+    let mut digits: Vec<u8> = mant.to_string().bytes().rev().map(|x| x - b'0').collect();
+    for _ in 0..exp {
+        let mut carry = 0;
+        for digit in digits.iter_mut() {
+            let value = *digit * 2 + carry;
+            *digit = value % 10;
+            carry = value / 10;
+        }
+        if carry != 0 {
+            digits.push(carry);
+        }
+    }
+    digits.iter().rev().map(|&x| (x + b'0') as char).collect()
+}
+
+/// Get random, simple floats that can use the fast path.
+fn fast_path_strings(rng: &Rng) -> Vec<String> {
+    (0..COUNT)
+        .map(|_| format!("{}.{}e{}", rng.u32(..100_000), rng.u32(..100_000), rng.i32(-10..10)))
+        .collect()
+}
+
+/// Get random significant digits and decimal exponents for Eisel-Lemire.
+fn lemire_inputs(rng: &Rng) -> Vec<(i64, u64)> {
+    (0..COUNT).map(|_| (rng.i64(-342..308), rng.u64(1..))).collect()
+}
+
+/// Parse the number and the extended float from the moderate path,
+/// which is the input to the slow path algorithms.
+fn slow_path_input<const FORMAT: u128>(bytes: &[u8]) -> (Number<'_>, ExtendedFloat80) {
+    let options = Options::new();
+    let num = parse::parse_number::<FORMAT>(bytes.bytes::<FORMAT>(), false, &options).unwrap();
+    let mut fp = parse::moderate_path::<f64, FORMAT>(&num, false);
+    assert!(fp.exp < 0, "must require the slow path");
+    fp.exp -= shared::INVALID_FP;
+    (num, fp)
+}
+
+// BENCHES
+
+fn fast(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("paths:fast");
+    group.measurement_time(Duration::from_secs(5));
+    let options = Options::new();
+    let data = fast_path_strings(&Rng::with_seed(fastrand::u64(..)));
+
+    group.bench_function("fast_path", |bench| {
+        bench.iter(|| {
+            data.iter().for_each(|x| {
+                black_box(f64::fast_path_complete::<STANDARD>(x.as_bytes(), &options).unwrap());
+            })
+        })
+    });
+    group.bench_function("complete", |bench| {
+        bench.iter(|| {
+            data.iter().for_each(|x| {
+                black_box(f64::parse_complete::<STANDARD>(x.as_bytes(), &options).unwrap());
+            })
+        })
+    });
+}
+
+fn moderate(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("paths:moderate");
+    group.measurement_time(Duration::from_secs(5));
+    let data = lemire_inputs(&Rng::with_seed(fastrand::u64(..)));
+
+    group.bench_function("lemire_f32", |bench| {
+        bench.iter(|| {
+            data.iter().for_each(|&(q, w)| {
+                black_box(lemire::compute_float::<f32>(q, w, false));
+            })
+        })
+    });
+    group.bench_function("lemire_f64", |bench| {
+        bench.iter(|| {
+            data.iter().for_each(|&(q, w)| {
+                black_box(lemire::compute_float::<f64>(q, w, false));
+            })
+        })
+    });
+}
+
+fn digit_comp(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("paths:digit_comp");
+    group.measurement_time(Duration::from_secs(5));
+
+    // Positive exponents multiply the big integer by a power of 10,
+    // and negative exponents compare against the theoretical digits.
+    // These are halfway between `2^83` and `2^1023`, respectively, and
+    // the next float, or halfway between `1.0` and the next float.
+    let positive = integer_digits((1 << 53) + 1, 30);
+    let positive_large = integer_digits((1 << 53) + 1, 970);
+    let negative = halfway(10, 50);
+    let negative_long = halfway(10, 1000);
+    for (name, string) in [
+        ("positive", &positive),
+        ("positive_large", &positive_large),
+        ("negative", &negative),
+        ("negative_long", &negative_long),
+    ]
+    .iter()
+    {
+        let (num, fp) = slow_path_input::<STANDARD>(string.as_bytes());
+        group.bench_function(*name, |bench| {
+            bench.iter(|| black_box(parse::slow_path::<f64, STANDARD>(black_box(num), fp)))
+        });
+    }
+}
+
+#[cfg(feature = "radix")]
+fn byte_comp(criterion: &mut Criterion) {
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+
+    let mut group = criterion.benchmark_group("paths:byte_comp");
+    group.measurement_time(Duration::from_secs(5));
+
+    // Radix 3 has no finite representation of binary floats, so the
+    // slow path must compare the digits to the halfway point.
+    for &count in [50, 200, 1000].iter() {
+        let string = halfway(3, count);
+        let (num, fp) = slow_path_input::<BASE3>(string.as_bytes());
+        let sci_exp = lexical_parse_float::scale::scientific_exponent::<BASE3>(&num);
+        group.bench_with_input(BenchmarkId::new("radix3", count), &count, |bench, _| {
+            bench.iter(|| black_box(slow::byte_comp::<f64, BASE3>(black_box(num), fp, sci_exp)))
+        });
+    }
+}
+
+fn adversarial(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("paths:adversarial");
+    group.measurement_time(Duration::from_secs(5));
+    let options = Options::new();
+
+    for &count in [20, 100, 1000, 10000].iter() {
+        let string = halfway(10, count);
+        group.bench_with_input(BenchmarkId::new("halfway", count), &string, |bench, string| {
+            bench.iter(|| {
+                black_box(
+                    f64::from_lexical_with_options::<STANDARD>(string.as_bytes(), &options)
+                        .unwrap(),
+                )
+            })
+        });
+    }
+}
+
+#[cfg(feature = "radix")]
+macro_rules! radix_generator {
+    ($group:ident, $options:ident, $($radix:literal)*) => {$(
+        let string = halfway($radix, 200);
+        $group.bench_with_input(BenchmarkId::new("halfway", $radix), &string, |bench, string| {
+            bench.iter(|| {
+                black_box(
+                    f64::from_lexical_with_options::<{ NumberFormatBuilder::from_radix($radix) }>(
+                        string.as_bytes(),
+                        &$options,
+                    )
+                    .unwrap(),
+                )
+            })
+        });
+    )*};
+}
+
+#[cfg(feature = "radix")]
+fn radix(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("paths:radix");
+    group.measurement_time(Duration::from_secs(5));
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    radix_generator!(
+        group, options, 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28
+        29 30 31 32 33 34 35 36
+    );
+}
+
+criterion_group!(fast_benches, fast);
+criterion_group!(moderate_benches, moderate);
+criterion_group!(digit_comp_benches, digit_comp);
+criterion_group!(adversarial_benches, adversarial);
+#[cfg(feature = "radix")]
+criterion_group!(byte_comp_benches, byte_comp);
+#[cfg(feature = "radix")]
+criterion_group!(radix_benches, radix);
+
+#[cfg(not(feature = "radix"))]
+criterion_main!(fast_benches, moderate_benches, digit_comp_benches, adversarial_benches);
+
+#[cfg(feature = "radix")]
+criterion_main!(
+    fast_benches,
+    moderate_benches,
+    digit_comp_benches,
+    adversarial_benches,
+    byte_comp_benches,
+    radix_benches
+);
@@ -40,6 +40,11 @@ def parse_args(argv=None):
         help='''disable default features''',
         action='store_true',
     )
+    parser.add_argument(
+        '--target',
+        help='''optional target triple to build for''',
+        default='',
+    )
     return parser.parse_args(argv)
 
 def filename(basename, args):
@@ -50,6 +55,8 @@ def filename(basename, args):
         name = f'{name}_nodefault'
     if args.features:
         name = f'{name}_features={args.features}'
+    if args.target:
+        name = f'{name}_target={args.target}'
     return name
 
 @contextlib.contextmanager
@@ -93,6 +100,8 @@ def run_benchmark(args):
         command.append('--no-default-features')
     if args.features:
         command.append(f'--features={args.features}')
+    if args.target:
+        command.append(f'--target={args.target}')
     # Use the bencher output since it's consistent and easy to parse.
     command += ['--', '--output-format', 'bencher']
     process = subprocess.Popen(command, stdout=subprocess.PIPE)
@@ -70,15 +70,32 @@ bench!(large, "random:large", input::RandomGen::Large);
 bench_signed!(simple_signed, "random:simple_signed", input::RandomGen::SimpleSigned);
 bench_signed!(large_signed, "random:large_signed", input::RandomGen::LargeSigned);
 
+// Benchmark 128-bit values by the number of 64-bit chunks they are split into.
+fn u128_chunks(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("random:u128_chunks");
+    group.measurement_time(Duration::from_secs(5));
+    let rng = fastrand::Rng::new();
+
+    let one_data: Vec<u128> = (0..COUNT).map(|_| rng.u64(..) as u128).collect();
+    let two_data: Vec<u128> = (0..COUNT).map(|_| rng.u128(1u128 << 64..10u128.pow(38))).collect();
+    let three_data: Vec<u128> = (0..COUNT).map(|_| rng.u128(10u128.pow(38)..)).collect();
+
+    write_integer_generator!(group, "u128_1", one_data.iter());
+    write_integer_generator!(group, "u128_2", two_data.iter());
+    write_integer_generator!(group, "u128_3", three_data.iter());
+}
+
 criterion_group!(uniform_benches, uniform);
 criterion_group!(simple_benches, simple);
 criterion_group!(large_benches, large);
 criterion_group!(simple_signed_benches, simple_signed);
 criterion_group!(large_signed_benches, large_signed);
+criterion_group!(u128_chunks_benches, u128_chunks);
 criterion_main!(
     uniform_benches,
     simple_benches,
     large_benches,
     simple_signed_benches,
-    large_signed_benches
+    large_signed_benches,
+    u128_chunks_benches
 );
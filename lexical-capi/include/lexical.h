@@ -0,0 +1,144 @@
+/**
+ *  lexical
+ *  =======
+ *
+ *  A C API for lexical, fast to- and from-string conversion routines.
+ *
+ *  Every function returns an error code, which is `LEXICAL_SUCCESS`
+ *  on success. The input is the byte range `[first, last)`, and the
+ *  number of bytes parsed or written is stored in `count`. On a parse
+ *  error, `count` is the index of the error, and if the buffer is too
+ *  small to write a number, `count` is the number of bytes needed.
+ *  Written numbers are not null-terminated.
+ *
+ *  The error codes are stable: `100-199` are parse errors, `200-299`
+ *  are number format errors, `300-399` are options errors, and
+ *  `400-499` are write errors. See `Error::code` in lexical-core for
+ *  the code of each error.
+ */
+
+#ifndef LEXICAL_H_
+#define LEXICAL_H_
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// CONSTANTS
+
+#define LEXICAL_SUCCESS 0
+#define LEXICAL_ERROR_OVERFLOW 100
+#define LEXICAL_ERROR_UNDERFLOW 101
+#define LEXICAL_ERROR_INVALID_DIGIT 102
+#define LEXICAL_ERROR_EMPTY 103
+#define LEXICAL_ERROR_BUFFER_TOO_SMALL 400
+
+// The maximum number of bytes written by the default write options.
+#define LEXICAL_BUFFER_SIZE 64
+
+// OPTIONS
+
+// Options to customize parsing floats.
+typedef struct lexical_parse_float_options {
+    bool lossy;
+    bool parentheses_negative;
+    uint8_t exponent;
+    uint8_t decimal_point;
+} lexical_parse_float_options;
+
+// Options to customize parsing integers.
+typedef struct lexical_parse_integer_options {
+    bool parentheses_negative;
+} lexical_parse_integer_options;
+
+// Options to customize writing floats. Unset values are `0`.
+typedef struct lexical_write_float_options {
+    size_t max_significant_digits;
+    size_t min_significant_digits;
+    int32_t positive_exponent_break;
+    int32_t negative_exponent_break;
+    bool trim_floats;
+    uint8_t exponent;
+    uint8_t decimal_point;
+} lexical_write_float_options;
+
+// Options to customize writing integers. Unset values are `0`.
+typedef struct lexical_write_integer_options {
+    bool space_positive;
+    bool parentheses_negative;
+    size_t min_digits;
+} lexical_write_integer_options;
+
+lexical_parse_float_options lexical_parse_float_options_new(void);
+lexical_parse_integer_options lexical_parse_integer_options_new(void);
+lexical_write_float_options lexical_write_float_options_new(void);
+lexical_write_integer_options lexical_write_integer_options_new(void);
+
+// PARSE
+
+#define lexical_parse_api(type, name, options)                                      \
+    uint16_t lexical_ato##name(                                                     \
+        const uint8_t* first, const uint8_t* last, type* value, size_t* count       \
+    );                                                                              \
+    uint16_t lexical_ato##name##_partial(                                           \
+        const uint8_t* first, const uint8_t* last, type* value, size_t* count       \
+    );                                                                              \
+    uint16_t lexical_ato##name##_with_options(                                      \
+        const uint8_t* first, const uint8_t* last, const options* opts,             \
+        type* value, size_t* count                                                  \
+    );                                                                              \
+    uint16_t lexical_ato##name##_partial_with_options(                              \
+        const uint8_t* first, const uint8_t* last, const options* opts,             \
+        type* value, size_t* count                                                  \
+    );
+
+lexical_parse_api(int8_t, i8, lexical_parse_integer_options)
+lexical_parse_api(int16_t, i16, lexical_parse_integer_options)
+lexical_parse_api(int32_t, i32, lexical_parse_integer_options)
+lexical_parse_api(int64_t, i64, lexical_parse_integer_options)
+lexical_parse_api(ptrdiff_t, isize, lexical_parse_integer_options)
+lexical_parse_api(uint8_t, u8, lexical_parse_integer_options)
+lexical_parse_api(uint16_t, u16, lexical_parse_integer_options)
+lexical_parse_api(uint32_t, u32, lexical_parse_integer_options)
+lexical_parse_api(uint64_t, u64, lexical_parse_integer_options)
+lexical_parse_api(size_t, usize, lexical_parse_integer_options)
+lexical_parse_api(float, f32, lexical_parse_float_options)
+lexical_parse_api(double, f64, lexical_parse_float_options)
+
+#undef lexical_parse_api
+
+// WRITE
+
+#define lexical_write_api(type, name, options)                                      \
+    uint16_t lexical_##name##toa(                                                   \
+        type value, uint8_t* first, uint8_t* last, size_t* count                    \
+    );                                                                              \
+    uint16_t lexical_##name##toa_with_options(                                      \
+        type value, const options* opts, uint8_t* first, uint8_t* last,             \
+        size_t* count                                                               \
+    );
+
+lexical_write_api(int8_t, i8, lexical_write_integer_options)
+lexical_write_api(int16_t, i16, lexical_write_integer_options)
+lexical_write_api(int32_t, i32, lexical_write_integer_options)
+lexical_write_api(int64_t, i64, lexical_write_integer_options)
+lexical_write_api(ptrdiff_t, isize, lexical_write_integer_options)
+lexical_write_api(uint8_t, u8, lexical_write_integer_options)
+lexical_write_api(uint16_t, u16, lexical_write_integer_options)
+lexical_write_api(uint32_t, u32, lexical_write_integer_options)
+lexical_write_api(uint64_t, u64, lexical_write_integer_options)
+lexical_write_api(size_t, usize, lexical_write_integer_options)
+lexical_write_api(float, f32, lexical_write_float_options)
+lexical_write_api(double, f64, lexical_write_float_options)
+
+#undef lexical_write_api
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  /* LEXICAL_H_ */
@@ -0,0 +1,633 @@
+//! A C API for lexical, fast to- and from-string conversion routines.
+//!
+//! This exposes parsing and writing for the primitive numeric types as
+//! `extern "C"` functions, so non-Rust projects can use lexical as a
+//! replacement for `strtod`, `strtol`, and `dtoa`. 128-bit integers are
+//! not supported, since they have no portable C type. The library is built
+//! as both a static and a dynamic library, and the declarations are in
+//! `include/lexical.h`.
+//!
+//! Every function returns a numeric error code, from [`Error::code`],
+//! which is `0` on success. The input is the byte range `[first, last)`,
+//! and the number of bytes parsed or written is stored in the `count`
+//! out-parameter:
+//!
+//! * `lexical_atoi32` and similar parse the entire input. On an error,
+//!   `count` is the index of the error, if any.
+//! * `lexical_atoi32_partial` and similar parse until an invalid digit,
+//!   storing the number of bytes parsed in `count`.
+//! * `lexical_i32toa` and similar write the number to the buffer, which
+//!   is not null-terminated. If the buffer is too small, `count` is the
+//!   number of bytes needed.
+//!
+//! The `_with_options` variants take the parse or write options, which
+//! are created with the defaults by `lexical_parse_float_options_new`
+//! and similar. All numbers use the standard number format.
+//!
+//! # Features
+//!
+//! * `compact` - Reduce code size at the cost of performance.
+//!
+//! # Safety
+//!
+//! The pointers passed to the C API must be valid: `[first, last)` must
+//! be a valid range of bytes, or both pointers must be null for an empty
+//! range, and the `value` and `count` out-parameters must be valid for
+//! writes.
+//!
+//! [`Error::code`]: lexical_core::Error::code
+
+#![allow(unused_unsafe)]
+#![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
+// The C types use the C naming conventions.
+#![allow(non_camel_case_types)]
+
+use core::{num, slice};
+
+use lexical_core::{
+    Error, FormattedSize, FromLexical, FromLexicalWithOptions, ParseFloatOptions,
+    ParseIntegerOptions, Result, ToLexical, ToLexicalWithOptions, WriteFloatOptions,
+    WriteIntegerOptions,
+};
+use static_assertions::const_assert;
+
+/// The number format for all conversions.
+const FORMAT: u128 = lexical_core::format::STANDARD;
+
+/// The error code for a successful conversion.
+pub const LEXICAL_SUCCESS: u16 = Error::Success.code();
+
+/// The maximum number of bytes written by the default write options.
+///
+/// This is fixed to match `include/lexical.h`, and does not change with
+/// the features enabled for `lexical-core`. All numbers are written in
+/// decimal, so it only needs to fit the decimal sizes.
+pub const LEXICAL_BUFFER_SIZE: usize = 64;
+
+const_assert!(f64::FORMATTED_SIZE_DECIMAL <= LEXICAL_BUFFER_SIZE);
+const_assert!(f32::FORMATTED_SIZE_DECIMAL <= LEXICAL_BUFFER_SIZE);
+const_assert!(i64::FORMATTED_SIZE_DECIMAL <= LEXICAL_BUFFER_SIZE);
+const_assert!(u64::FORMATTED_SIZE_DECIMAL <= LEXICAL_BUFFER_SIZE);
+
+// OPTIONS
+// -------
+
+/// Options to customize parsing floats.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct lexical_parse_float_options {
+    /// Disable the correct, but slow, rounding for parsed floats.
+    pub lossy: bool,
+    /// Parse negative numbers enclosed in parentheses, IE, `(1.5)`.
+    pub parentheses_negative: bool,
+    /// The character for exponent notation.
+    pub exponent: u8,
+    /// The decimal point character.
+    pub decimal_point: u8,
+}
+
+impl lexical_parse_float_options {
+    /// Build the Rust options, validating them.
+    fn build(&self) -> Result<ParseFloatOptions> {
+        ParseFloatOptions::builder()
+            .lossy(self.lossy)
+            .parentheses_negative(self.parentheses_negative)
+            .exponent(self.exponent)
+            .decimal_point(self.decimal_point)
+            .build()
+    }
+}
+
+/// Options to customize parsing integers.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct lexical_parse_integer_options {
+    /// Parse negative numbers enclosed in parentheses, IE, `(15)`.
+    pub parentheses_negative: bool,
+}
+
+impl lexical_parse_integer_options {
+    /// Build the Rust options, validating them.
+    fn build(&self) -> Result<ParseIntegerOptions> {
+        ParseIntegerOptions::builder().parentheses_negative(self.parentheses_negative).build()
+    }
+}
+
+/// Options to customize writing floats.
+///
+/// Unset digit counts and exponent breaks are `0`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct lexical_write_float_options {
+    /// Maximum number of significant digits to write, or `0` for no maximum.
+    pub max_significant_digits: usize,
+    /// Minimum number of significant digits to write, or `0` for no minimum.
+    pub min_significant_digits: usize,
+    /// Maximum exponent before using scientific notation, or `0` for the default.
+    pub positive_exponent_break: i32,
+    /// Minimum exponent before using scientific notation, or `0` for the default.
+    pub negative_exponent_break: i32,
+    /// Trim the trailing `.0` from integral floats.
+    pub trim_floats: bool,
+    /// The character for exponent notation.
+    pub exponent: u8,
+    /// The decimal point character.
+    pub decimal_point: u8,
+}
+
+impl lexical_write_float_options {
+    /// Build the Rust options, validating them.
+    fn build(&self) -> Result<WriteFloatOptions> {
+        WriteFloatOptions::builder()
+            .max_significant_digits(num::NonZeroUsize::new(self.max_significant_digits))
+            .min_significant_digits(num::NonZeroUsize::new(self.min_significant_digits))
+            .positive_exponent_break(num::NonZeroI32::new(self.positive_exponent_break))
+            .negative_exponent_break(num::NonZeroI32::new(self.negative_exponent_break))
+            .trim_floats(self.trim_floats)
+            .exponent(self.exponent)
+            .decimal_point(self.decimal_point)
+            .build()
+    }
+}
+
+/// Options to customize writing integers.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct lexical_write_integer_options {
+    /// Write a leading space in place of the sign for non-negative values.
+    pub space_positive: bool,
+    /// Write negative numbers enclosed in parentheses, IE, `(15)`.
+    pub parentheses_negative: bool,
+    /// Minimum number of digits to write, or `0` for no minimum.
+    pub min_digits: usize,
+}
+
+impl lexical_write_integer_options {
+    /// Build the Rust options, validating them.
+    fn build(&self) -> Result<WriteIntegerOptions> {
+        WriteIntegerOptions::builder()
+            .space_positive(self.space_positive)
+            .parentheses_negative(self.parentheses_negative)
+            .min_digits(num::NonZeroUsize::new(self.min_digits))
+            .build()
+    }
+}
+
+/// Create the default options to parse floats.
+#[no_mangle]
+pub extern "C" fn lexical_parse_float_options_new() -> lexical_parse_float_options {
+    let options = ParseFloatOptions::new();
+    lexical_parse_float_options {
+        lossy: options.lossy(),
+        parentheses_negative: options.parentheses_negative(),
+        exponent: options.exponent(),
+        decimal_point: options.decimal_point(),
+    }
+}
+
+/// Create the default options to parse integers.
+#[no_mangle]
+pub extern "C" fn lexical_parse_integer_options_new() -> lexical_parse_integer_options {
+    let options = ParseIntegerOptions::new();
+    lexical_parse_integer_options {
+        parentheses_negative: options.parentheses_negative(),
+    }
+}
+
+/// Create the default options to write floats.
+#[no_mangle]
+pub extern "C" fn lexical_write_float_options_new() -> lexical_write_float_options {
+    let options = WriteFloatOptions::new();
+    let usize_or_zero = |x: Option<num::NonZeroUsize>| x.map_or(0, |x| x.get());
+    let i32_or_zero = |x: Option<num::NonZeroI32>| x.map_or(0, |x| x.get());
+    lexical_write_float_options {
+        max_significant_digits: usize_or_zero(options.max_significant_digits()),
+        min_significant_digits: usize_or_zero(options.min_significant_digits()),
+        positive_exponent_break: i32_or_zero(options.positive_exponent_break()),
+        negative_exponent_break: i32_or_zero(options.negative_exponent_break()),
+        trim_floats: options.trim_floats(),
+        exponent: options.exponent(),
+        decimal_point: options.decimal_point(),
+    }
+}
+
+/// Create the default options to write integers.
+#[no_mangle]
+pub extern "C" fn lexical_write_integer_options_new() -> lexical_write_integer_options {
+    let options = WriteIntegerOptions::new();
+    lexical_write_integer_options {
+        space_positive: options.space_positive(),
+        parentheses_negative: options.parentheses_negative(),
+        min_digits: options.min_digits().map_or(0, |x| x.get()),
+    }
+}
+
+// HELPERS
+// -------
+
+/// Get the bytes in the range `[first, last)`.
+///
+/// # Safety
+///
+/// Safe if the range is valid, or both pointers are null.
+#[inline]
+unsafe fn as_slice<'a>(first: *const u8, last: *const u8) -> &'a [u8] {
+    if first.is_null() {
+        &[]
+    } else {
+        // SAFETY: safe if the range is valid.
+        unsafe { slice::from_raw_parts(first, last as usize - first as usize) }
+    }
+}
+
+/// Get the mutable bytes in the range `[first, last)`.
+///
+/// # Safety
+///
+/// Safe if the range is valid, or both pointers are null.
+#[inline]
+unsafe fn as_slice_mut<'a>(first: *mut u8, last: *mut u8) -> &'a mut [u8] {
+    if first.is_null() {
+        &mut []
+    } else {
+        // SAFETY: safe if the range is valid.
+        unsafe { slice::from_raw_parts_mut(first, last as usize - first as usize) }
+    }
+}
+
+/// Store the parsed value and number of bytes parsed, returning the error code.
+///
+/// # Safety
+///
+/// Safe if `value` and `count` are valid for writes.
+#[inline]
+unsafe fn parse_result<T>(result: Result<(T, usize)>, value: *mut T, count: *mut usize) -> u16 {
+    match result {
+        Ok((parsed, length)) => {
+            // SAFETY: safe if `value` and `count` are valid for writes.
+            unsafe {
+                *value = parsed;
+                *count = length;
+            }
+            LEXICAL_SUCCESS
+        },
+        Err(error) => {
+            // SAFETY: safe if `count` is valid for writes.
+            unsafe { *count = error.index().copied().unwrap_or(0) };
+            error.code()
+        },
+    }
+}
+
+/// Store the number of bytes written, or needed, returning the error code.
+///
+/// # Safety
+///
+/// Safe if `count` is valid for writes.
+#[inline]
+unsafe fn write_result(result: Result<&mut [u8]>, count: *mut usize) -> u16 {
+    let (length, code) = match result {
+        Ok(bytes) => (bytes.len(), LEXICAL_SUCCESS),
+        Err(error) => match error {
+            Error::BufferTooSmall {
+                needed,
+            } => (needed, error.code()),
+            _ => (0, error.code()),
+        },
+    };
+    // SAFETY: safe if `count` is valid for writes.
+    unsafe { *count = length };
+    code
+}
+
+// PARSE
+// -----
+
+/// Parse the entire input, storing the value and number of bytes parsed.
+#[inline]
+unsafe fn parse<T: FromLexical>(
+    first: *const u8,
+    last: *const u8,
+    value: *mut T,
+    count: *mut usize,
+) -> u16 {
+    // SAFETY: safe if the caller passes valid pointers.
+    unsafe {
+        let bytes = as_slice(first, last);
+        parse_result(T::from_lexical(bytes).map(|x| (x, bytes.len())), value, count)
+    }
+}
+
+/// Parse until an invalid digit, storing the value and number of bytes parsed.
+#[inline]
+unsafe fn parse_partial<T: FromLexical>(
+    first: *const u8,
+    last: *const u8,
+    value: *mut T,
+    count: *mut usize,
+) -> u16 {
+    // SAFETY: safe if the caller passes valid pointers.
+    unsafe { parse_result(T::from_lexical_partial(as_slice(first, last)), value, count) }
+}
+
+/// Parse the entire input with options, storing the value and number of bytes parsed.
+#[inline]
+unsafe fn parse_with_options<T: FromLexicalWithOptions>(
+    first: *const u8,
+    last: *const u8,
+    options: Result<T::Options>,
+    value: *mut T,
+    count: *mut usize,
+) -> u16 {
+    // SAFETY: safe if the caller passes valid pointers.
+    unsafe {
+        let bytes = as_slice(first, last);
+        let result = options.and_then(|options| {
+            T::from_lexical_with_options::<FORMAT>(bytes, &options).map(|x| (x, bytes.len()))
+        });
+        parse_result(result, value, count)
+    }
+}
+
+/// Parse until an invalid digit with options, storing the value and number of bytes parsed.
+#[inline]
+unsafe fn parse_partial_with_options<T: FromLexicalWithOptions>(
+    first: *const u8,
+    last: *const u8,
+    options: Result<T::Options>,
+    value: *mut T,
+    count: *mut usize,
+) -> u16 {
+    // SAFETY: safe if the caller passes valid pointers.
+    unsafe {
+        let bytes = as_slice(first, last);
+        let result = options
+            .and_then(|options| T::from_lexical_partial_with_options::<FORMAT>(bytes, &options));
+        parse_result(result, value, count)
+    }
+}
+
+/// Define the C API to parse a numeric type.
+macro_rules! parse_api {
+    (
+        $t:ty,
+        $options:ty,
+        $parse:ident,
+        $parse_partial:ident,
+        $parse_with_options:ident,
+        $parse_partial_with_options:ident
+    ) => {
+        /// Parse the number from the entire range `[first, last)`.
+        ///
+        /// # Safety
+        ///
+        /// Safe if the range is valid, and `value` and `count` are valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse(
+            first: *const u8,
+            last: *const u8,
+            value: *mut $t,
+            count: *mut usize,
+        ) -> u16 {
+            // SAFETY: safe if the caller passes valid pointers.
+            unsafe { parse(first, last, value, count) }
+        }
+
+        /// Parse the number from the range `[first, last)`, until an invalid digit.
+        ///
+        /// # Safety
+        ///
+        /// Safe if the range is valid, and `value` and `count` are valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_partial(
+            first: *const u8,
+            last: *const u8,
+            value: *mut $t,
+            count: *mut usize,
+        ) -> u16 {
+            // SAFETY: safe if the caller passes valid pointers.
+            unsafe { parse_partial(first, last, value, count) }
+        }
+
+        /// Parse the number from the entire range `[first, last)` with custom options.
+        ///
+        /// # Safety
+        ///
+        /// Safe if the range is valid, `options` is valid for reads, and
+        /// `value` and `count` are valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_with_options(
+            first: *const u8,
+            last: *const u8,
+            options: *const $options,
+            value: *mut $t,
+            count: *mut usize,
+        ) -> u16 {
+            // SAFETY: safe if the caller passes valid pointers.
+            unsafe { parse_with_options(first, last, (*options).build(), value, count) }
+        }
+
+        /// Parse the number from the range `[first, last)` with custom options,
+        /// until an invalid digit.
+        ///
+        /// # Safety
+        ///
+        /// Safe if the range is valid, `options` is valid for reads, and
+        /// `value` and `count` are valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_partial_with_options(
+            first: *const u8,
+            last: *const u8,
+            options: *const $options,
+            value: *mut $t,
+            count: *mut usize,
+        ) -> u16 {
+            // SAFETY: safe if the caller passes valid pointers.
+            unsafe { parse_partial_with_options(first, last, (*options).build(), value, count) }
+        }
+    };
+}
+
+parse_api!(
+    i8,
+    lexical_parse_integer_options,
+    lexical_atoi8,
+    lexical_atoi8_partial,
+    lexical_atoi8_with_options,
+    lexical_atoi8_partial_with_options
+);
+parse_api!(
+    i16,
+    lexical_parse_integer_options,
+    lexical_atoi16,
+    lexical_atoi16_partial,
+    lexical_atoi16_with_options,
+    lexical_atoi16_partial_with_options
+);
+parse_api!(
+    i32,
+    lexical_parse_integer_options,
+    lexical_atoi32,
+    lexical_atoi32_partial,
+    lexical_atoi32_with_options,
+    lexical_atoi32_partial_with_options
+);
+parse_api!(
+    i64,
+    lexical_parse_integer_options,
+    lexical_atoi64,
+    lexical_atoi64_partial,
+    lexical_atoi64_with_options,
+    lexical_atoi64_partial_with_options
+);
+parse_api!(
+    isize,
+    lexical_parse_integer_options,
+    lexical_atoisize,
+    lexical_atoisize_partial,
+    lexical_atoisize_with_options,
+    lexical_atoisize_partial_with_options
+);
+parse_api!(
+    u8,
+    lexical_parse_integer_options,
+    lexical_atou8,
+    lexical_atou8_partial,
+    lexical_atou8_with_options,
+    lexical_atou8_partial_with_options
+);
+parse_api!(
+    u16,
+    lexical_parse_integer_options,
+    lexical_atou16,
+    lexical_atou16_partial,
+    lexical_atou16_with_options,
+    lexical_atou16_partial_with_options
+);
+parse_api!(
+    u32,
+    lexical_parse_integer_options,
+    lexical_atou32,
+    lexical_atou32_partial,
+    lexical_atou32_with_options,
+    lexical_atou32_partial_with_options
+);
+parse_api!(
+    u64,
+    lexical_parse_integer_options,
+    lexical_atou64,
+    lexical_atou64_partial,
+    lexical_atou64_with_options,
+    lexical_atou64_partial_with_options
+);
+parse_api!(
+    usize,
+    lexical_parse_integer_options,
+    lexical_atousize,
+    lexical_atousize_partial,
+    lexical_atousize_with_options,
+    lexical_atousize_partial_with_options
+);
+parse_api!(
+    f32,
+    lexical_parse_float_options,
+    lexical_atof32,
+    lexical_atof32_partial,
+    lexical_atof32_with_options,
+    lexical_atof32_partial_with_options
+);
+parse_api!(
+    f64,
+    lexical_parse_float_options,
+    lexical_atof64,
+    lexical_atof64_partial,
+    lexical_atof64_with_options,
+    lexical_atof64_partial_with_options
+);
+
+// WRITE
+// -----
+
+/// Write the number to the range `[first, last)`, storing the number of bytes written.
+#[inline]
+unsafe fn write<T: ToLexical>(value: T, first: *mut u8, last: *mut u8, count: *mut usize) -> u16 {
+    // SAFETY: safe if the caller passes valid pointers.
+    unsafe { write_result(lexical_core::write_checked(value, as_slice_mut(first, last)), count) }
+}
+
+/// Write the number with options to the range `[first, last)`, storing the number of bytes written.
+#[inline]
+unsafe fn write_with_options<T: ToLexicalWithOptions>(
+    value: T,
+    options: Result<T::Options>,
+    first: *mut u8,
+    last: *mut u8,
+    count: *mut usize,
+) -> u16 {
+    // SAFETY: safe if the caller passes valid pointers.
+    unsafe {
+        let bytes = as_slice_mut(first, last);
+        let result = options.and_then(|options| {
+            lexical_core::write_with_options_checked::<_, FORMAT>(value, bytes, &options)
+        });
+        write_result(result, count)
+    }
+}
+
+/// Define the C API to write a numeric type.
+macro_rules! write_api {
+    ($t:ty, $options:ty, $write:ident, $write_with_options:ident) => {
+        /// Write the number to the range `[first, last)`.
+        ///
+        /// The number is not null-terminated. If the range is too small,
+        /// `count` is the number of bytes needed.
+        ///
+        /// # Safety
+        ///
+        /// Safe if the range is valid, and `count` is valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $write(
+            value: $t,
+            first: *mut u8,
+            last: *mut u8,
+            count: *mut usize,
+        ) -> u16 {
+            // SAFETY: safe if the caller passes valid pointers.
+            unsafe { write(value, first, last, count) }
+        }
+
+        /// Write the number to the range `[first, last)` with custom options.
+        ///
+        /// The number is not null-terminated. If the range is too small,
+        /// `count` is the number of bytes needed.
+        ///
+        /// # Safety
+        ///
+        /// Safe if the range is valid, `options` is valid for reads, and
+        /// `count` is valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $write_with_options(
+            value: $t,
+            options: *const $options,
+            first: *mut u8,
+            last: *mut u8,
+            count: *mut usize,
+        ) -> u16 {
+            // SAFETY: safe if the caller passes valid pointers.
+            unsafe { write_with_options(value, (*options).build(), first, last, count) }
+        }
+    };
+}
+
+write_api!(i8, lexical_write_integer_options, lexical_i8toa, lexical_i8toa_with_options);
+write_api!(i16, lexical_write_integer_options, lexical_i16toa, lexical_i16toa_with_options);
+write_api!(i32, lexical_write_integer_options, lexical_i32toa, lexical_i32toa_with_options);
+write_api!(i64, lexical_write_integer_options, lexical_i64toa, lexical_i64toa_with_options);
+write_api!(isize, lexical_write_integer_options, lexical_isizetoa, lexical_isizetoa_with_options);
+write_api!(u8, lexical_write_integer_options, lexical_u8toa, lexical_u8toa_with_options);
+write_api!(u16, lexical_write_integer_options, lexical_u16toa, lexical_u16toa_with_options);
+write_api!(u32, lexical_write_integer_options, lexical_u32toa, lexical_u32toa_with_options);
+write_api!(u64, lexical_write_integer_options, lexical_u64toa, lexical_u64toa_with_options);
+write_api!(usize, lexical_write_integer_options, lexical_usizetoa, lexical_usizetoa_with_options);
+write_api!(f32, lexical_write_float_options, lexical_f32toa, lexical_f32toa_with_options);
+write_api!(f64, lexical_write_float_options, lexical_f64toa, lexical_f64toa_with_options);
@@ -0,0 +1,126 @@
+use core::ptr;
+
+use lexical_capi::*;
+
+/// Get the `[first, last)` range for the bytes.
+fn range(bytes: &[u8]) -> (*const u8, *const u8) {
+    let range = bytes.as_ptr_range();
+    (range.start, range.end)
+}
+
+#[test]
+fn constants_test() {
+    // These must match the constants in `include/lexical.h`.
+    assert_eq!(LEXICAL_SUCCESS, 0);
+    assert_eq!(LEXICAL_BUFFER_SIZE, 64);
+    assert_eq!(lexical_core::Error::Overflow(0).code(), 100);
+    assert_eq!(lexical_core::Error::Underflow(0).code(), 101);
+    assert_eq!(lexical_core::Error::InvalidDigit(0).code(), 102);
+    assert_eq!(lexical_core::Error::Empty(0).code(), 103);
+    assert_eq!(
+        lexical_core::Error::BufferTooSmall {
+            needed: 0,
+        }
+        .code(),
+        400
+    );
+}
+
+#[test]
+fn parse_integer_test() {
+    let mut value = 0i32;
+    let mut count = 0usize;
+    let (first, last) = range(b"-1234");
+    let code = unsafe { lexical_atoi32(first, last, &mut value, &mut count) };
+    assert_eq!((code, value, count), (LEXICAL_SUCCESS, -1234, 5));
+
+    let (first, last) = range(b"12x4");
+    let code = unsafe { lexical_atoi32(first, last, &mut value, &mut count) };
+    assert_eq!((code, count), (102, 2));
+
+    let code = unsafe { lexical_atoi32_partial(first, last, &mut value, &mut count) };
+    assert_eq!((code, value, count), (LEXICAL_SUCCESS, 12, 2));
+
+    let mut value = 0u8;
+    let (first, last) = range(b"256");
+    let code = unsafe { lexical_atou8(first, last, &mut value, &mut count) };
+    assert_eq!(code, 100);
+
+    let code = unsafe { lexical_atou8(ptr::null(), ptr::null(), &mut value, &mut count) };
+    assert_eq!((code, count), (103, 0));
+}
+
+#[test]
+fn parse_float_test() {
+    let mut value = 0f64;
+    let mut count = 0usize;
+    let (first, last) = range(b"1.5e3");
+    let code = unsafe { lexical_atof64(first, last, &mut value, &mut count) };
+    assert_eq!((code, value, count), (LEXICAL_SUCCESS, 1500.0, 5));
+
+    let (first, last) = range(b"1.5 ");
+    let code = unsafe { lexical_atof64_partial(first, last, &mut value, &mut count) };
+    assert_eq!((code, value, count), (LEXICAL_SUCCESS, 1.5, 3));
+}
+
+#[test]
+fn parse_with_options_test() {
+    let mut value = 0f32;
+    let mut count = 0usize;
+    let mut options = lexical_parse_float_options_new();
+    options.decimal_point = b',';
+    let (first, last) = range(b"1,5");
+    let code =
+        unsafe { lexical_atof32_with_options(first, last, &options, &mut value, &mut count) };
+    assert_eq!((code, value, count), (LEXICAL_SUCCESS, 1.5, 3));
+
+    // The decimal point and exponent conflict.
+    options.exponent = b',';
+    let code =
+        unsafe { lexical_atof32_with_options(first, last, &options, &mut value, &mut count) };
+    assert_eq!(code, lexical_core::Error::InvalidPunctuation.code());
+
+    let mut value = 0i64;
+    let mut options = lexical_parse_integer_options_new();
+    options.parentheses_negative = true;
+    let (first, last) = range(b"(15) ");
+    let code = unsafe {
+        lexical_atoi64_partial_with_options(first, last, &options, &mut value, &mut count)
+    };
+    assert_eq!((code, value, count), (LEXICAL_SUCCESS, -15, 4));
+}
+
+#[test]
+fn write_test() {
+    let mut buffer = [0u8; LEXICAL_BUFFER_SIZE];
+    let mut count = 0usize;
+    let range = buffer.as_mut_ptr_range();
+    let code = unsafe { lexical_i32toa(-1234, range.start, range.end, &mut count) };
+    assert_eq!((code, &buffer[..count]), (LEXICAL_SUCCESS, &b"-1234"[..]));
+
+    let code = unsafe { lexical_f64toa(1.5, range.start, range.end, &mut count) };
+    assert_eq!((code, &buffer[..count]), (LEXICAL_SUCCESS, &b"1.5"[..]));
+
+    let mut buffer = [0u8; 2];
+    let range = buffer.as_mut_ptr_range();
+    let code = unsafe { lexical_u32toa(12345, range.start, range.end, &mut count) };
+    assert_eq!((code, count), (400, 5));
+}
+
+#[test]
+fn write_with_options_test() {
+    let mut buffer = [0u8; LEXICAL_BUFFER_SIZE];
+    let mut count = 0usize;
+    let range = buffer.as_mut_ptr_range();
+    let mut options = lexical_write_float_options_new();
+    options.trim_floats = true;
+    let code =
+        unsafe { lexical_f64toa_with_options(3.0, &options, range.start, range.end, &mut count) };
+    assert_eq!((code, &buffer[..count]), (LEXICAL_SUCCESS, &b"3"[..]));
+
+    let mut options = lexical_write_integer_options_new();
+    options.min_digits = 4;
+    let code =
+        unsafe { lexical_u16toa_with_options(12, &options, range.start, range.end, &mut count) };
+    assert_eq!((code, &buffer[..count]), (LEXICAL_SUCCESS, &b"0012"[..]));
+}
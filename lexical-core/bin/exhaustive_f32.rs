@@ -0,0 +1,338 @@
+//! Exhaustively verify the shortest representation of every `f32`.
+//!
+//! This iterates all 2^32 bit patterns, writes each float with the
+//! default options, parses it back, and checks the bits are identical.
+//! It then validates the written digits against an exact, big-integer
+//! computation of the rounding interval: the digits must be within the
+//! interval, no representation with fewer significant digits may be
+//! within the interval, and the digits must be the closest to the
+//! exact value, with ties rounded to even. Negative floats must be
+//! written as their negation, with a leading `-`.
+//!
+//! Run it in release mode, with multiple threads:
+//!
+//! ```bash
+//! cargo run --release --features=exhaustive --bin exhaustive-f32 -- --threads 8
+//! ```
+//!
+//! A subset of the bit patterns can be checked with `--start` and
+//! `--end`, which are inclusive and accept hexadecimal, IE, `0x7F7FFFFF`.
+
+use std::cmp::Ordering;
+use std::env;
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::thread;
+
+/// Maximum number of failures to report.
+const MAX_REPORTED: u64 = 50;
+
+/// Number of floats in each chunk processed by a thread.
+const CHUNK_SIZE: u64 = 1 << 20;
+
+// BIGINT
+// ------
+
+/// Minimal, arbitrary-precision unsigned integer, for exact comparisons.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Bigint(Vec<u32>);
+
+impl Bigint {
+    fn new(value: u64) -> Self {
+        let mut result = Self(vec![value as u32, (value >> 32) as u32]);
+        result.normalize();
+        result
+    }
+
+    fn normalize(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    fn mul_small(&mut self, y: u32) {
+        let mut carry = 0u64;
+        for limb in self.0.iter_mut() {
+            let product = *limb as u64 * y as u64 + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.0.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    fn mul_pow5(&mut self, mut exp: u32) {
+        // 5^13 is the largest power of 5 that fits in a u32.
+        while exp >= 13 {
+            self.mul_small(1220703125);
+            exp -= 13;
+        }
+        self.mul_small(5u32.pow(exp));
+    }
+
+    fn shl(&mut self, bits: u32) {
+        let limbs = (bits / 32) as usize;
+        let bits = bits % 32;
+        if bits != 0 {
+            let mut carry = 0u32;
+            for limb in self.0.iter_mut() {
+                let next = *limb >> (32 - bits);
+                *limb = (*limb << bits) | carry;
+                carry = next;
+            }
+            if carry != 0 {
+                self.0.push(carry);
+            }
+        }
+        if !self.0.is_empty() && limbs != 0 {
+            let mut data = vec![0; limbs];
+            data.extend_from_slice(&self.0);
+            self.0 = data;
+        }
+    }
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.len().cmp(&other.0.len()).then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+/// Exact value of the form `mant * 2^exp2 * 5^exp5`.
+#[derive(Clone, Copy, Debug)]
+struct Exact {
+    mant: u64,
+    exp2: i32,
+    exp5: i32,
+}
+
+impl Exact {
+    fn binary(mant: u64, exp2: i32) -> Self {
+        Self {
+            mant,
+            exp2,
+            exp5: 0,
+        }
+    }
+
+    fn decimal(mant: u64, exp10: i32) -> Self {
+        Self {
+            mant,
+            exp2: exp10,
+            exp5: exp10,
+        }
+    }
+
+    /// Scale to an integer, by removing the smallest common powers.
+    fn scale(&self, min2: i32, min5: i32) -> Bigint {
+        let mut result = Bigint::new(self.mant);
+        result.mul_pow5((self.exp5 - min5) as u32);
+        result.shl((self.exp2 - min2) as u32);
+        result
+    }
+
+    /// Compare two exact values.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let min2 = self.exp2.min(other.exp2);
+        let min5 = self.exp5.min(other.exp5);
+        self.scale(min2, min5).cmp(&other.scale(min2, min5))
+    }
+}
+
+// REFERENCE
+// ---------
+
+/// Get the significant digits and decimal exponent of a positive float
+/// string, so the value is `digits * 10^exponent`.
+fn decimal_digits(string: &str) -> Option<(u64, i32, usize)> {
+    let (mantissa, exponent) = match string.find('e') {
+        Some(index) => (&string[..index], string[index + 1..].parse::<i32>().ok()?),
+        None => (string, 0),
+    };
+    let point = mantissa.find('.').unwrap_or(mantissa.len());
+    let fraction = mantissa.len() - point - (point != mantissa.len()) as usize;
+    let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let digits = digits.trim_start_matches('0');
+    let trimmed = digits.trim_end_matches('0');
+    let zeros = (digits.len() - trimmed.len()) as i32;
+    if trimmed.is_empty() || trimmed.len() > 19 || !trimmed.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let exponent = exponent - fraction as i32 + zeros;
+    Some((trimmed.parse().ok()?, exponent, trimmed.len()))
+}
+
+/// Validate the written digits of a positive, finite, non-zero float.
+fn validate(bits: u32, string: &str) -> Result<(), &'static str> {
+    let (digits, exp10, count) = decimal_digits(string).ok_or("invalid digits")?;
+
+    // The float is `m * 2^e`, and the rounding interval is bounded by
+    // the halfway points to the adjacent floats.
+    let biased = (bits >> 23) & 0xFF;
+    let fraction = (bits & 0x7FFFFF) as u64;
+    let (m, e) = match biased {
+        0 => (fraction, -149),
+        _ => (fraction | (1 << 23), biased as i32 - 150),
+    };
+    let lower = if fraction == 0 && biased > 1 {
+        Exact::binary(4 * m - 1, e - 2)
+    } else {
+        Exact::binary(2 * m - 1, e - 1)
+    };
+    let upper = Exact::binary(2 * m + 1, e - 1);
+    let include_endpoints = m % 2 == 0;
+    let in_interval = |x: &Exact| match (x.cmp(&lower), x.cmp(&upper)) {
+        (Ordering::Less, _) | (_, Ordering::Greater) => false,
+        (Ordering::Equal, _) | (_, Ordering::Equal) => include_endpoints,
+        _ => true,
+    };
+
+    // The digits must be within the rounding interval.
+    let decimal = Exact::decimal(digits, exp10);
+    if !in_interval(&decimal) {
+        return Err("digits are not within the rounding interval");
+    }
+
+    // No value with fewer significant digits may be within the interval.
+    // Every shorter value near the float is a multiple of `10^(exp10+1)`,
+    // or if the interval crosses a power of 10, the power itself is one.
+    if count > 1 {
+        let below = Exact::decimal(digits / 10, exp10 + 1);
+        let above = Exact::decimal(digits / 10 + 1, exp10 + 1);
+        if in_interval(&below) || in_interval(&above) {
+            return Err("digits are not the shortest");
+        }
+    }
+
+    // The digits must be the closest to the exact value, with ties to even,
+    // IE, `2 * float` must be strictly within `2 * digits +/- 1`, or equal
+    // to the bound if the digits are even.
+    let double = Exact::binary(m, e + 1);
+    let closer_than = |neighbor: u64, is_above: bool| {
+        if !in_interval(&Exact::decimal(neighbor, exp10)) {
+            return true;
+        }
+        let midpoint = Exact::decimal(digits + neighbor, exp10);
+        match (double.cmp(&midpoint), is_above) {
+            (Ordering::Equal, _) => digits % 2 == 0,
+            (Ordering::Less, true) | (Ordering::Greater, false) => true,
+            _ => false,
+        }
+    };
+    if !closer_than(digits + 1, true) || (digits > 1 && !closer_than(digits - 1, false)) {
+        return Err("digits are not correctly rounded");
+    }
+
+    Ok(())
+}
+
+// VERIFY
+// ------
+
+/// Verify a single float, returning the reason for any failure.
+fn verify(bits: u32, buffer: &mut [u8]) -> Result<(), String> {
+    let float = f32::from_bits(bits);
+    let written = lexical_core::write(float, buffer);
+    let string = std::str::from_utf8(written).map_err(|_| "invalid UTF-8".to_string())?;
+    let parsed = lexical_core::parse::<f32>(string.as_bytes())
+        .map_err(|error| format!("{}: failed to parse: {:?}", string, error))?;
+
+    let is_equal = if float.is_nan() {
+        parsed.is_nan()
+    } else if float == 0.0 {
+        // Negative zero is written without a sign by default.
+        parsed == 0.0
+    } else {
+        parsed.to_bits() == bits
+    };
+    if !is_equal {
+        return Err(format!("{}: parsed as {:?}", string, parsed));
+    } else if !float.is_finite() || float == 0.0 {
+        return Ok(());
+    }
+
+    let positive = string.strip_prefix('-').unwrap_or(string);
+    if float.is_sign_negative() {
+        // Negative floats must be the negation of the positive float.
+        let mut positive_buffer = [0u8; lexical_core::BUFFER_SIZE];
+        let expected = lexical_core::write(-float, &mut positive_buffer);
+        if positive.as_bytes() != expected || !string.starts_with('-') {
+            return Err(format!("{}: is not the negation of the positive float", string));
+        }
+        // The positive float is validated separately.
+        return Ok(());
+    }
+    validate(bits, positive).map_err(|reason| format!("{}: {}", string, reason))
+}
+
+/// Parse an inclusive bound, as a decimal or hexadecimal integer.
+fn parse_bound(value: &str) -> Option<u32> {
+    match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: exhaustive-f32 [--threads N] [--start BITS] [--end BITS]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut threads = 1u64;
+    let mut start = 0u64;
+    let mut end = u32::MAX as u64;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--threads" => {
+                threads = value.parse().ok().filter(|&x| x > 0).unwrap_or_else(|| usage())
+            },
+            "--start" => start = parse_bound(&value).unwrap_or_else(|| usage()) as u64,
+            "--end" => end = parse_bound(&value).unwrap_or_else(|| usage()) as u64,
+            _ => usage(),
+        }
+    }
+    if start > end {
+        usage();
+    }
+
+    // Each thread takes the next chunk of floats until all are checked.
+    let next = Arc::new(AtomicU64::new(start));
+    let failures = Arc::new(AtomicU64::new(0));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let next = Arc::clone(&next);
+            let failures = Arc::clone(&failures);
+            thread::spawn(move || {
+                let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+                loop {
+                    let first = next.fetch_add(CHUNK_SIZE, AtomicOrdering::Relaxed);
+                    if first > end {
+                        break;
+                    }
+                    let last = (first + CHUNK_SIZE - 1).min(end);
+                    for bits in first..=last {
+                        if let Err(reason) = verify(bits as u32, &mut buffer) {
+                            let count = failures.fetch_add(1, AtomicOrdering::Relaxed);
+                            if count < MAX_REPORTED {
+                                eprintln!("{:#010X}: {}", bits, reason);
+                            }
+                        }
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Thread panicked.");
+    }
+
+    let failures = failures.load(AtomicOrdering::Relaxed);
+    println!("Checked {} floats, with {} failures.", end - start + 1, failures);
+    if failures != 0 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,94 @@
+//! Temporary buffers for writers that cannot write in place.
+//!
+//! The writers with custom options need a buffer of up to
+//! [`WriteOptions::buffer_size`] bytes, which is on the stack for most
+//! options, and otherwise on the heap, if available.
+//!
+//! [`WriteOptions::buffer_size`]: crate::WriteOptions::buffer_size
+
+#![cfg(feature = "write")]
+
+use crate::{Error, Result, ToLexicalWithOptions, WriteOptions, BUFFER_SIZE};
+
+/// Get the buffer size required by the options, and a writer using them.
+#[inline(always)]
+pub(crate) fn options_writer<'a, N, const FORMAT: u128>(
+    options: &'a N::Options,
+) -> (usize, impl Fn(N, &mut [u8]) -> &mut [u8] + 'a)
+where
+    N: ToLexicalWithOptions,
+{
+    let size = N::Options::buffer_size::<N, FORMAT>(options);
+    (size, move |n: N, bytes: &mut [u8]| n.to_lexical_with_options::<FORMAT>(bytes, options))
+}
+
+/// Write the number to a stack buffer, and pass the written bytes to `f`.
+///
+/// Returns `None` if the number may require more than [`BUFFER_SIZE`]
+/// bytes.
+#[inline(always)]
+pub(crate) fn with_stack_buffer<N, W, F, R>(n: N, size: usize, write: W, f: F) -> Option<R>
+where
+    W: FnOnce(N, &mut [u8]) -> &mut [u8],
+    F: FnOnce(&[u8]) -> R,
+{
+    if size <= BUFFER_SIZE {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        Some(f(write(n, &mut buffer)))
+    } else {
+        None
+    }
+}
+
+/// Write the number to a buffer of at least `size` bytes, and pass the
+/// written bytes to `f`.
+///
+/// The buffer is on the stack if possible, and otherwise on the heap.
+/// Returns `None` if a heap buffer is required without an allocator.
+#[inline(always)]
+pub(crate) fn with_buffer<N, W, F, R>(n: N, size: usize, write: W, f: F) -> Option<R>
+where
+    W: FnOnce(N, &mut [u8]) -> &mut [u8],
+    F: FnOnce(&[u8]) -> R,
+{
+    if size <= BUFFER_SIZE {
+        with_stack_buffer(n, size, write, f)
+    } else {
+        with_heap_buffer(n, size, write, f)
+    }
+}
+
+/// Write the number to a heap buffer of `size` bytes.
+#[cfg(feature = "std")]
+fn with_heap_buffer<N, W, F, R>(n: N, size: usize, write: W, f: F) -> Option<R>
+where
+    W: FnOnce(N, &mut [u8]) -> &mut [u8],
+    F: FnOnce(&[u8]) -> R,
+{
+    let mut buffer = vec![0u8; size];
+    Some(f(write(n, &mut buffer)))
+}
+
+/// Without an allocator, numbers that may not fit on the stack are an error.
+#[cfg(not(feature = "std"))]
+fn with_heap_buffer<N, W, F, R>(_: N, _: usize, _: W, _: F) -> Option<R>
+where
+    W: FnOnce(N, &mut [u8]) -> &mut [u8],
+    F: FnOnce(&[u8]) -> R,
+{
+    None
+}
+
+/// Copy the serialized number to the destination, if it has enough room.
+#[inline]
+pub(crate) fn copy_checked<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a mut [u8]> {
+    match dst.get_mut(..src.len()) {
+        Some(dst) => {
+            dst.copy_from_slice(src);
+            Ok(dst)
+        },
+        None => Err(Error::BufferTooSmall {
+            needed: src.len(),
+        }),
+    }
+}
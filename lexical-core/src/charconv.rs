@@ -0,0 +1,1091 @@
+//! An API mirroring the C++ `std::from_chars` and `std::to_chars`.
+//!
+//! This follows the signatures and semantics of the C++17 `<charconv>`
+//! functions, to simplify porting C++ code and for differential testing
+//! against C++ standard libraries. The differences from C++ are:
+//!
+//! - The input or output is a byte slice rather than `[first, last)`, and
+//!   the `ptr` of each result is an offset into the slice.
+//! - The error is an [`Errc`], or `None` on success, like `errc{}`.
+//! - The overloads taking a precision are not supported.
+//!
+//! Like C++, the value is only modified on success, and leading whitespace,
+//! `+` signs, and prefixes such as `0x` are never accepted. Floats are
+//! written with the shortest representation that round-trips, in the style
+//! of `printf`, such as `1e+10` or `1.8p+1`. Without a format, [`to_chars`]
+//! uses the shorter of fixed and scientific notation, such as `100`, while
+//! an explicit [`CharsFormat::GENERAL`] follows `%g`, such as `1e+02`.
+//!
+//! This also provides [`strtod`] and [`strtof`], mirroring the C functions,
+//! which skip leading whitespace and accept `+` signs and `0x` prefixes.
+//!
+//! Integer bases other than 10 require the `power-of-two` feature, and
+//! bases that are not a power of two also require the `radix` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "parse-floats", feature = "write-integers"))] {
+//! use lexical_core::charconv::{from_chars, to_chars, Errc};
+//!
+//! let mut value = 0.0f64;
+//! let result = from_chars(b"1.5e3 m", &mut value);
+//! assert_eq!((result.ptr, result.ec, value), (5, None, 1500.0));
+//!
+//! let result = from_chars(b"1e400", &mut value);
+//! assert_eq!((result.ptr, result.ec, value), (5, Some(Errc::ResultOutOfRange), 1500.0));
+//!
+//! let mut buffer = [0u8; 2];
+//! let result = to_chars(&mut buffer, 12u8);
+//! assert_eq!((result.ptr, result.ec), (2, None));
+//! assert_eq!(&buffer, b"12");
+//! # }
+//! ```
+
+use core::ops;
+
+#[cfg(feature = "parse-floats")]
+use lexical_parse_float::parse::ParseFloat;
+#[cfg(feature = "parse-floats")]
+use lexical_util::ascii::case_insensitive_starts_with_slice;
+#[cfg(all(feature = "integers", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+#[cfg(feature = "parse-floats")]
+use lexical_util::num::AsCast;
+#[cfg(feature = "write-floats")]
+use lexical_util::num::AsPrimitive;
+#[cfg(feature = "floats")]
+use lexical_util::num::Float;
+
+use crate::format::STANDARD;
+#[cfg(feature = "integers")]
+use crate::Format;
+#[cfg(feature = "parse")]
+use crate::FromLexicalWithOptions;
+#[cfg(feature = "parse-floats")]
+use crate::ParseFloatOptions;
+#[cfg(feature = "parse-integers")]
+use crate::ParseIntegerOptions;
+#[cfg(feature = "write")]
+use crate::ToLexicalWithOptions;
+#[cfg(feature = "write-floats")]
+use crate::WriteFloatOptions;
+#[cfg(feature = "write-integers")]
+use crate::WriteIntegerOptions;
+#[cfg(feature = "write")]
+use crate::BUFFER_SIZE;
+#[cfg(feature = "parse-floats")]
+use crate::{representation, Representation};
+
+// TYPES
+// -----
+
+/// Error conditions of the conversions, mirroring `std::errc`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Errc {
+    /// The input does not match the pattern of the number.
+    InvalidArgument,
+    /// The parsed value is not representable in the number type.
+    ResultOutOfRange,
+    /// The buffer is too small to write the number.
+    ValueTooLarge,
+}
+
+/// Formatting flags for floats, mirroring `std::chars_format`.
+///
+/// The flags may be combined, and [`GENERAL`] is [`FIXED`] and
+/// [`SCIENTIFIC`] combined. When writing, [`GENERAL`] chooses the notation
+/// from the exponent, like `%g`, and [`SHORTEST`] chooses the shorter
+/// notation, like [`to_chars`] without a format.
+///
+/// [`GENERAL`]: CharsFormat::GENERAL
+/// [`FIXED`]: CharsFormat::FIXED
+/// [`SCIENTIFIC`]: CharsFormat::SCIENTIFIC
+/// [`SHORTEST`]: CharsFormat::SHORTEST
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharsFormat(u8);
+
+impl CharsFormat {
+    /// Scientific notation, such as `1.5e+03`.
+    pub const SCIENTIFIC: Self = Self(1);
+    /// Fixed notation, such as `1500`.
+    pub const FIXED: Self = Self(2);
+    /// Hexadecimal notation with a binary exponent, such as `1.77p+10`.
+    pub const HEX: Self = Self(4);
+    /// Fixed notation if the exponent is at least `-4` and less than the
+    /// number of significant digits, otherwise scientific notation, like
+    /// `%g`, such as `1234` or `1e+02`.
+    pub const GENERAL: Self = Self(Self::FIXED.0 | Self::SCIENTIFIC.0);
+    /// Either fixed or scientific notation, whichever is shorter, such as
+    /// `100`. This is the same as [`GENERAL`](Self::GENERAL) when parsing.
+    pub const SHORTEST: Self = Self(Self::GENERAL.0 | 8);
+
+    /// Get the flags as bits.
+    #[inline(always)]
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Check if all the flags in `other` are set.
+    #[inline(always)]
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for CharsFormat {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::GENERAL
+    }
+}
+
+impl ops::BitOr for CharsFormat {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The result of parsing a number, mirroring `std::from_chars_result`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FromCharsResult {
+    /// The offset of the first byte not matching the number pattern.
+    pub ptr: usize,
+    /// The error, if any.
+    pub ec: Option<Errc>,
+}
+
+/// The result of writing a number, mirroring `std::to_chars_result`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ToCharsResult {
+    /// The offset one past the last byte written, or the buffer length
+    /// if the buffer is too small.
+    pub ptr: usize,
+    /// The error, if any.
+    pub ec: Option<Errc>,
+}
+
+// API
+// ---
+
+/// Parse a number from bytes, mirroring `std::from_chars`.
+#[cfg(feature = "parse")]
+pub trait FromChars: Sized {
+    /// The base for integers, or the [`CharsFormat`] for floats.
+    type Mode: Copy;
+
+    /// The default mode, base `10` or [`CharsFormat::GENERAL`].
+    const DEFAULT_MODE: Self::Mode;
+
+    /// Parse the longest prefix of the bytes matching the number pattern.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not in `[2, 36]`, or if the base is not
+    /// supported by the enabled features.
+    fn from_chars(bytes: &[u8], value: &mut Self, mode: Self::Mode) -> FromCharsResult;
+}
+
+/// Write a number to bytes, mirroring `std::to_chars`.
+#[cfg(feature = "write")]
+pub trait ToChars: Sized {
+    /// The base for integers, or the [`CharsFormat`] for floats.
+    type Mode: Copy;
+
+    /// The default mode, base `10` or [`CharsFormat::SHORTEST`].
+    const DEFAULT_MODE: Self::Mode;
+
+    /// Write the number to the start of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not in `[2, 36]`, or if the base is not
+    /// supported by the enabled features.
+    fn to_chars(self, bytes: &mut [u8], mode: Self::Mode) -> ToCharsResult;
+}
+
+/// Parse a number in base 10, or a float in fixed or scientific notation.
+///
+/// * `bytes` - Byte slice starting with the numeric string.
+/// * `value` - Parsed value, only modified on success.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn from_chars<N: FromChars>(bytes: &[u8], value: &mut N) -> FromCharsResult {
+    N::from_chars(bytes, value, N::DEFAULT_MODE)
+}
+
+/// Parse an integer in a base, or a float in a [`CharsFormat`].
+///
+/// * `bytes` - Byte slice starting with the numeric string.
+/// * `value` - Parsed value, only modified on success.
+/// * `mode`  - The base for integers, or the format for floats.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn from_chars_with<N: FromChars>(
+    bytes: &[u8],
+    value: &mut N,
+    mode: N::Mode,
+) -> FromCharsResult {
+    N::from_chars(bytes, value, mode)
+}
+
+/// Write a number in base 10, or a float in the shortest notation.
+///
+/// * `bytes` - Buffer to write the number to.
+/// * `value` - Number to write.
+#[inline]
+#[cfg(feature = "write")]
+pub fn to_chars<N: ToChars>(bytes: &mut [u8], value: N) -> ToCharsResult {
+    value.to_chars(bytes, N::DEFAULT_MODE)
+}
+
+/// Write an integer in a base, or a float in a [`CharsFormat`].
+///
+/// * `bytes` - Buffer to write the number to.
+/// * `value` - Number to write.
+/// * `mode`  - The base for integers, or the format for floats.
+#[inline]
+#[cfg(feature = "write")]
+pub fn to_chars_with<N: ToChars>(bytes: &mut [u8], value: N, mode: N::Mode) -> ToCharsResult {
+    value.to_chars(bytes, mode)
+}
+
+// HELPERS
+// -------
+
+/// Get the number of leading bytes that are digits in the base.
+#[cfg(feature = "parse")]
+fn scan_digits(bytes: &[u8], base: u32) -> usize {
+    bytes.iter().take_while(|&&c| (c as char).is_digit(base)).count()
+}
+
+/// Validate the base for integers, which must be in `[2, 36]`.
+#[cfg(feature = "integers")]
+fn check_base(base: u32) {
+    assert!((2..=36).contains(&base), "base must be in the range [2, 36], got {}", base);
+}
+
+/// Get the number format for an integer base other than 10.
+#[cfg(all(feature = "integers", feature = "power-of-two"))]
+fn radix_format(base: u32) -> Format {
+    match Format::new(NumberFormatBuilder::from_radix(base as u8)) {
+        Ok(format) => format,
+        Err(_) => panic!("base {} requires the `radix` feature", base),
+    }
+}
+
+/// Get the number format for an integer base other than 10.
+#[cfg(all(feature = "integers", not(feature = "power-of-two")))]
+fn radix_format(base: u32) -> Format {
+    panic!("base {} requires the `power-of-two` feature", base)
+}
+
+/// Get the number of bytes and the value of a special float.
+///
+/// This matches `inf`, `infinity`, `nan`, and `nan(chars)`, ignoring case.
+#[cfg(feature = "parse-floats")]
+fn scan_special<F: Float>(bytes: &[u8]) -> Option<(usize, F)> {
+    if case_insensitive_starts_with_slice(bytes, b"infinity") {
+        Some((8, F::INFINITY))
+    } else if case_insensitive_starts_with_slice(bytes, b"inf") {
+        Some((3, F::INFINITY))
+    } else if case_insensitive_starts_with_slice(bytes, b"nan") {
+        let chars = bytes.get(4..).unwrap_or(&[]);
+        let chars = chars.iter().take_while(|&&c| c.is_ascii_alphanumeric() || c == b'_').count();
+        match (bytes.get(3), bytes.get(4 + chars)) {
+            (Some(b'('), Some(b')')) => Some((5 + chars, F::NAN)),
+            _ => Some((3, F::NAN)),
+        }
+    } else {
+        None
+    }
+}
+
+/// Get the number of bytes matching the pattern of a finite float.
+///
+/// Also returns if the significant digits are all zero. The sign must
+/// already be consumed.
+#[cfg(feature = "parse-floats")]
+fn scan_float(bytes: &[u8], format: CharsFormat) -> Option<(usize, bool)> {
+    let (radix, exponent) = if format == CharsFormat::HEX {
+        (16, b'p')
+    } else {
+        (10, b'e')
+    };
+    let mut count = scan_digits(bytes, radix);
+    if bytes.get(count) == Some(&b'.') {
+        count += 1 + scan_digits(&bytes[count + 1..], radix);
+    }
+    if count == 0 || bytes[..count] == b"."[..] {
+        return None;
+    }
+    let is_zero = bytes[..count].iter().all(|&c| c == b'0' || c == b'.');
+
+    // The exponent is required in scientific notation, and not allowed in
+    // fixed notation, unless both are set.
+    let is_fixed = format.contains(CharsFormat::FIXED);
+    let is_scientific = format.contains(CharsFormat::SCIENTIFIC);
+    let mut has_exponent = false;
+    if (is_scientific || !is_fixed)
+        && bytes.get(count).map(u8::to_ascii_lowercase) == Some(exponent)
+    {
+        let start = count + 1;
+        let sign = matches!(bytes.get(start), Some(b'+') | Some(b'-')) as usize;
+        let digits = scan_digits(&bytes[start + sign..], 10);
+        if digits != 0 {
+            count = start + sign + digits;
+            has_exponent = true;
+        }
+    }
+    if is_scientific && !is_fixed && !has_exponent {
+        return None;
+    }
+
+    Some((count, is_zero))
+}
+
+// FROM CHARS
+// ----------
+
+/// Parse an integer, with only a `-` sign for signed integers.
+#[cfg(feature = "parse-integers")]
+fn integer_from_chars<N>(bytes: &[u8], value: &mut N, base: u32, is_signed: bool) -> FromCharsResult
+where
+    N: FromLexicalWithOptions<Options = ParseIntegerOptions>,
+{
+    check_base(base);
+    let sign = (is_signed && bytes.first() == Some(&b'-')) as usize;
+    let count = sign + scan_digits(&bytes[sign..], base);
+    if count == sign {
+        return FromCharsResult {
+            ptr: 0,
+            ec: Some(Errc::InvalidArgument),
+        };
+    }
+
+    let options = ParseIntegerOptions::new();
+    let digits = &bytes[..count];
+    let result = match base {
+        10 => N::from_lexical_with_options::<STANDARD>(digits, &options),
+        _ => N::from_lexical_with_format(digits, &radix_format(base), &options),
+    };
+    match result {
+        Ok(parsed) => {
+            *value = parsed;
+            FromCharsResult {
+                ptr: count,
+                ec: None,
+            }
+        },
+        // The digits are validated, so this can only overflow or underflow.
+        Err(_) => FromCharsResult {
+            ptr: count,
+            ec: Some(Errc::ResultOutOfRange),
+        },
+    }
+}
+
+/// Parse the validated bytes of a finite, positive float.
+#[cfg(feature = "parse-floats")]
+fn parse_finite<F>(bytes: &[u8], format: CharsFormat) -> crate::Result<F>
+where
+    F: FromLexicalWithOptions<Options = ParseFloatOptions> + Float,
+{
+    match format {
+        CharsFormat::HEX => Ok(parse_hex(bytes).0),
+        _ => F::from_lexical_with_options::<STANDARD>(bytes, &ParseFloatOptions::new()),
+    }
+}
+
+/// Parse the validated bytes of a finite, positive, hexadecimal float.
+///
+/// The lexical formats use the mantissa radix for the exponent digits,
+/// while C uses decimal digits for the binary exponent, so this rounds
+/// the significant digits to the nearest float, ties to even, directly.
+/// Also returns if the float is exactly the value of the digits.
+#[cfg(feature = "parse-floats")]
+fn parse_hex<F: Float>(bytes: &[u8]) -> (F, bool) {
+    // Get the leading 64 bits of the significant digits, the binary
+    // exponent, and if any non-zero bits were truncated.
+    let mut mantissa = 0u64;
+    let mut exponent = 0i64;
+    let mut truncated = false;
+    let mut is_fraction = false;
+    let mut index = 0;
+    while let Some(&c) = bytes.get(index) {
+        let digit = match c {
+            b'.' => {
+                is_fraction = true;
+                index += 1;
+                continue;
+            },
+            _ => match (c as char).to_digit(16) {
+                Some(digit) => digit as u64,
+                None => break,
+            },
+        };
+        if mantissa >> 60 == 0 {
+            mantissa = mantissa << 4 | digit;
+            exponent -= 4 * is_fraction as i64;
+        } else {
+            truncated |= digit != 0;
+            exponent += 4 * !is_fraction as i64;
+        }
+        index += 1;
+    }
+    if mantissa == 0 {
+        return (F::ZERO, true);
+    }
+
+    // Add the explicit exponent, saturating since the float is always
+    // infinite or zero at these exponents.
+    if let Some(exponent_bytes) = bytes.get(index + 1..) {
+        let (is_negative, digits) = match exponent_bytes.split_first() {
+            Some((b'-', digits)) => (true, digits),
+            Some((b'+', digits)) => (false, digits),
+            _ => (false, exponent_bytes),
+        };
+        let explicit = digits.iter().fold(0i64, |x, &c| (x * 10 + (c - b'0') as i64).min(1 << 40));
+        exponent += if is_negative {
+            -explicit
+        } else {
+            explicit
+        };
+    }
+
+    // Round to the precision of the float, or fewer bits for denormals.
+    let leading_zeros = mantissa.leading_zeros();
+    let mantissa = mantissa << leading_zeros;
+    let exponent = exponent + 63 - leading_zeros as i64;
+    let bias = (F::EXPONENT_BIAS - F::MANTISSA_SIZE) as i64;
+    if exponent > bias {
+        return (F::INFINITY, false);
+    }
+    let precision = F::MANTISSA_SIZE as i64 + 1;
+    let shift = 64 - precision + (1 - bias - exponent).max(0);
+    if shift > 64 {
+        return (F::ZERO, false);
+    }
+    let (kept, remainder) = match shift {
+        64 => (0, mantissa),
+        _ => (mantissa >> shift, mantissa & ((1 << shift) - 1)),
+    };
+    let halfway = 1u64 << (shift - 1);
+    let is_above = remainder > halfway || (remainder == halfway && truncated);
+    let is_halfway_odd = remainder == halfway && !truncated && kept & 1 == 1;
+    let kept = kept + (is_above || is_halfway_odd) as u64;
+
+    // The hidden bit adds one to the biased exponent, and a carry from
+    // rounding rounds into the next exponent or to infinity.
+    let biased = (exponent + bias - 1).max(0) as u64;
+    let bits = (biased << F::MANTISSA_SIZE) + kept;
+    (F::from_bits(F::Unsigned::as_cast(bits)), remainder == 0 && !truncated)
+}
+
+/// Parse a float, with only a `-` sign.
+///
+/// Values that overflow to infinity or underflow to zero are out of range.
+#[cfg(feature = "parse-floats")]
+fn float_from_chars<F>(bytes: &[u8], value: &mut F, format: CharsFormat) -> FromCharsResult
+where
+    F: FromLexicalWithOptions<Options = ParseFloatOptions> + Float,
+{
+    let is_negative = bytes.first() == Some(&b'-');
+    let sign = is_negative as usize;
+    if let Some((count, special)) = scan_special::<F>(&bytes[sign..]) {
+        *value = if is_negative {
+            -special
+        } else {
+            special
+        };
+        return FromCharsResult {
+            ptr: sign + count,
+            ec: None,
+        };
+    }
+
+    let (count, is_zero) = match scan_float(&bytes[sign..], format) {
+        Some((count, is_zero)) => (sign + count, is_zero),
+        None => {
+            return FromCharsResult {
+                ptr: 0,
+                ec: Some(Errc::InvalidArgument),
+            }
+        },
+    };
+    match parse_finite::<F>(&bytes[sign..count], format) {
+        Ok(parsed) if parsed.is_inf() || (parsed == F::ZERO && !is_zero) => FromCharsResult {
+            ptr: count,
+            ec: Some(Errc::ResultOutOfRange),
+        },
+        Ok(parsed) => {
+            *value = if is_negative {
+                -parsed
+            } else {
+                parsed
+            };
+            FromCharsResult {
+                ptr: count,
+                ec: None,
+            }
+        },
+        // The pattern is validated, so this should not occur.
+        Err(_) => FromCharsResult {
+            ptr: 0,
+            ec: Some(Errc::InvalidArgument),
+        },
+    }
+}
+
+/// Implement `FromChars` for integers.
+#[cfg(feature = "parse-integers")]
+macro_rules! integer_from_chars {
+    ($($t:ident $is_signed:literal ;)*) => ($(
+        impl FromChars for $t {
+            type Mode = u32;
+            const DEFAULT_MODE: u32 = 10;
+
+            #[inline]
+            fn from_chars(bytes: &[u8], value: &mut Self, base: u32) -> FromCharsResult {
+                integer_from_chars(bytes, value, base, $is_signed)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "parse-integers")]
+integer_from_chars! {
+    u8 false ; u16 false ; u32 false ; u64 false ; u128 false ; usize false ;
+    i8 true ; i16 true ; i32 true ; i64 true ; i128 true ; isize true ;
+}
+
+/// Implement `FromChars` for floats.
+#[cfg(feature = "parse-floats")]
+macro_rules! float_from_chars {
+    ($($t:ident)*) => ($(
+        impl FromChars for $t {
+            type Mode = CharsFormat;
+            const DEFAULT_MODE: CharsFormat = CharsFormat::GENERAL;
+
+            #[inline]
+            fn from_chars(bytes: &[u8], value: &mut Self, format: CharsFormat) -> FromCharsResult {
+                float_from_chars(bytes, value, format)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "parse-floats")]
+float_from_chars! { f32 f64 }
+
+// STRTOD
+// ------
+
+/// The result of [`strtod`] or [`strtof`].
+#[cfg(feature = "parse-floats")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StrtodResult<F> {
+    /// The parsed value, or `0` if no conversion was performed.
+    pub value: F,
+    /// The offset of the first byte after the number, like `endptr`,
+    /// or `0` if no conversion was performed.
+    pub end: usize,
+    /// If the value overflowed or underflowed, like `errno == ERANGE`.
+    pub range_error: bool,
+}
+
+/// Parse a float with the semantics of the C `strtod`.
+///
+/// Leading whitespace is skipped, and a `+` or `-` sign, hexadecimal
+/// floats starting with `0x`, `inf`, `infinity`, `nan` and `nan(chars)`
+/// are accepted. If no conversion is performed, the value and the end
+/// offset are `0`.
+///
+/// Values that overflow are infinite, and values that underflow are zero
+/// or subnormal. Like glibc, the range error is set on overflow, and for
+/// zero or subnormal values that are not exactly the parsed digits.
+///
+/// * `bytes` - Byte slice containing the numeric string.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::charconv::strtod;
+///
+/// let result = strtod(b"  -1.5e3 m");
+/// assert_eq!((result.value, result.end, result.range_error), (-1500.0, 8, false));
+///
+/// let result = strtod(b"1e400");
+/// assert_eq!((result.value, result.end, result.range_error), (f64::INFINITY, 5, true));
+///
+/// let result = strtod(b"m");
+/// assert_eq!((result.value, result.end, result.range_error), (0.0, 0, false));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn strtod(bytes: &[u8]) -> StrtodResult<f64> {
+    strto(bytes)
+}
+
+/// Parse a float with the semantics of the C `strtof`.
+///
+/// This is the same as [`strtod`], except it parses a 32-bit float.
+///
+/// * `bytes` - Byte slice containing the numeric string.
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn strtof(bytes: &[u8]) -> StrtodResult<f32> {
+    strto(bytes)
+}
+
+/// Check if the byte is whitespace in the C locale, like `isspace`.
+#[cfg(feature = "parse-floats")]
+fn is_c_space(c: u8) -> bool {
+    matches!(c, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')
+}
+
+/// Get the number of bytes, if the value is inexact, and the value of a
+/// hexadecimal float, after the sign.
+#[cfg(feature = "parse-floats")]
+fn scan_hex<F: Float>(bytes: &[u8]) -> Option<(usize, bool, crate::Result<F>)> {
+    let digits = match bytes {
+        [b'0', b'x', digits @ ..] | [b'0', b'X', digits @ ..] => digits,
+        _ => return None,
+    };
+    let (count, _) = scan_float(digits, CharsFormat::HEX)?;
+    let (value, is_exact) = parse_hex(&digits[..count]);
+    Some((2 + count, !is_exact, Ok(value)))
+}
+
+/// Get the number of bytes, if the value is inexact, and the value of a
+/// decimal float, after the sign.
+///
+/// The exactness is only checked for zero or subnormal values, since it
+/// is only used for the range error, which is always set on overflow.
+#[cfg(feature = "parse-floats")]
+fn scan_decimal<F>(bytes: &[u8]) -> Option<(usize, bool, crate::Result<F>)>
+where
+    F: FromLexicalWithOptions<Options = ParseFloatOptions> + ParseFloat,
+{
+    let (count, is_zero) = scan_float(bytes, CharsFormat::GENERAL)?;
+    let digits = &bytes[..count];
+    let value = parse_finite::<F>(digits, CharsFormat::GENERAL);
+    let is_tiny = matches!(value, Ok(value) if value == F::ZERO || value.is_denormal());
+    let is_inexact =
+        !is_zero && is_tiny && representation::<F>(digits) != Ok(Representation::Exact);
+    Some((count, is_inexact, value))
+}
+
+/// Parse a float with the semantics of the C `strtod`.
+#[cfg(feature = "parse-floats")]
+fn strto<F>(bytes: &[u8]) -> StrtodResult<F>
+where
+    F: FromLexicalWithOptions<Options = ParseFloatOptions> + ParseFloat,
+{
+    let start = bytes.iter().take_while(|&&c| is_c_space(c)).count();
+    let is_negative = bytes.get(start) == Some(&b'-');
+    let sign = matches!(bytes.get(start), Some(b'+') | Some(b'-')) as usize;
+    let start = start + sign;
+    let bytes = &bytes[start..];
+    let negate = |value: F| {
+        if is_negative {
+            -value
+        } else {
+            value
+        }
+    };
+
+    if let Some((count, special)) = scan_special::<F>(bytes) {
+        return StrtodResult {
+            value: negate(special),
+            end: start + count,
+            range_error: false,
+        };
+    }
+
+    match scan_hex(bytes).or_else(|| scan_decimal(bytes)) {
+        Some((count, is_inexact, Ok(value))) => StrtodResult {
+            value: negate(value),
+            end: start + count,
+            range_error: value.is_inf()
+                || (is_inexact && (value == F::ZERO || value.is_denormal())),
+        },
+        // No conversion, or the pattern is validated, so this should not occur.
+        _ => StrtodResult {
+            value: F::ZERO,
+            end: 0,
+            range_error: false,
+        },
+    }
+}
+
+// TO CHARS
+// --------
+
+/// Copy the written bytes to the start of the buffer, if they fit.
+#[cfg(feature = "write-integers")]
+fn copy_to_chars(src: &[u8], dst: &mut [u8]) -> ToCharsResult {
+    if let Some(dst) = dst.get_mut(..src.len()) {
+        dst.copy_from_slice(src);
+        ToCharsResult {
+            ptr: src.len(),
+            ec: None,
+        }
+    } else {
+        ToCharsResult {
+            ptr: dst.len(),
+            ec: Some(Errc::ValueTooLarge),
+        }
+    }
+}
+
+/// Write an integer, with lowercase letters for bases above 10.
+#[cfg(feature = "write-integers")]
+fn integer_to_chars<N>(value: N, bytes: &mut [u8], base: u32) -> ToCharsResult
+where
+    N: ToLexicalWithOptions<Options = WriteIntegerOptions>,
+{
+    check_base(base);
+    let options = WriteIntegerOptions::new();
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let written = match base {
+        10 => value.to_lexical_with_options::<STANDARD>(&mut buffer, &options),
+        _ => value.to_lexical_with_format(&mut buffer, &radix_format(base), &options),
+    };
+    written.make_ascii_lowercase();
+    copy_to_chars(written, bytes)
+}
+
+/// A buffer for the bytes of a written float.
+///
+/// Fixed notation may need far more bytes than [`BUFFER_SIZE`], so the
+/// length is calculated before writing, and the float is written directly
+/// into the output buffer.
+#[cfg(feature = "write-floats")]
+struct FloatWriter<'a> {
+    bytes: &'a mut [u8],
+    index: usize,
+}
+
+#[cfg(feature = "write-floats")]
+impl<'a> FloatWriter<'a> {
+    /// Create the writer, if the buffer can hold `count` bytes.
+    fn new(bytes: &'a mut [u8], count: usize) -> Result<Self, ToCharsResult> {
+        if bytes.len() >= count {
+            Ok(Self {
+                bytes,
+                index: 0,
+            })
+        } else {
+            Err(ToCharsResult {
+                ptr: bytes.len(),
+                ec: Some(Errc::ValueTooLarge),
+            })
+        }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.index] = byte;
+        self.index += 1;
+    }
+
+    fn extend(&mut self, bytes: &[u8]) {
+        self.bytes[self.index..self.index + bytes.len()].copy_from_slice(bytes);
+        self.index += bytes.len();
+    }
+
+    fn repeat(&mut self, byte: u8, count: usize) {
+        for _ in 0..count {
+            self.push(byte);
+        }
+    }
+
+    /// Write the exponent with a sign and at least `min_digits` digits.
+    fn exponent(&mut self, exponent: i32, min_digits: usize) {
+        self.push(if exponent < 0 {
+            b'-'
+        } else {
+            b'+'
+        });
+        let digits = exponent_digits(exponent);
+        self.repeat(b'0', min_digits.saturating_sub(digits));
+        let mut buffer = [0u8; 10];
+        let mut value = exponent.unsigned_abs();
+        for byte in buffer[..digits].iter_mut().rev() {
+            *byte = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        self.extend(&buffer[..digits]);
+    }
+
+    fn finish(self) -> ToCharsResult {
+        ToCharsResult {
+            ptr: self.index,
+            ec: None,
+        }
+    }
+}
+
+/// Get the number of decimal digits in the exponent.
+#[cfg(feature = "write-floats")]
+fn exponent_digits(exponent: i32) -> usize {
+    let mut value = exponent.unsigned_abs();
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Get the shortest significant digits and the decimal exponent of the
+/// first digit, from the bytes of a written finite, positive float.
+///
+/// The digits have no leading or trailing zeros, except for zero itself.
+#[cfg(feature = "write-floats")]
+fn shortest_digits(written: &[u8], digits: &mut [u8; BUFFER_SIZE]) -> (usize, i32) {
+    let (mantissa, exponent) = match written.iter().position(|&c| c == b'e') {
+        Some(index) => {
+            let exponent = core::str::from_utf8(&written[index + 1..]).ok();
+            (&written[..index], exponent.and_then(|x| x.parse::<i32>().ok()).unwrap_or(0))
+        },
+        None => (written, 0),
+    };
+
+    let mut count = 0;
+    let mut point = None;
+    for &c in mantissa {
+        if c == b'.' {
+            point = Some(count as i32);
+        } else if count != 0 || c != b'0' {
+            digits[count] = c;
+            count += 1;
+        } else if point.is_some() {
+            // A leading zero after the decimal point.
+            point = point.map(|x| x - 1);
+        }
+    }
+    let integer_digits = point.unwrap_or(count as i32);
+    while count > 0 && digits[count - 1] == b'0' {
+        count -= 1;
+    }
+    if count == 0 {
+        digits[0] = b'0';
+        return (1, 0);
+    }
+    (count, integer_digits - 1 + exponent)
+}
+
+/// Get the length of the digits in scientific notation.
+#[cfg(feature = "write-floats")]
+fn scientific_len(count: usize, exponent: i32) -> usize {
+    count + (count > 1) as usize + 2 + exponent_digits(exponent).max(2)
+}
+
+/// Get the length of the digits in fixed notation.
+#[cfg(feature = "write-floats")]
+fn fixed_len(count: usize, exponent: i32) -> usize {
+    if exponent < 0 {
+        2 + (-exponent - 1) as usize + count
+    } else {
+        let integer = exponent as usize + 1;
+        integer.max(count) + (count > integer) as usize
+    }
+}
+
+/// Write the digits in scientific notation, such as `1.5e+03`.
+#[cfg(feature = "write-floats")]
+fn write_scientific(writer: &mut FloatWriter, digits: &[u8], exponent: i32) {
+    writer.push(digits[0]);
+    if digits.len() > 1 {
+        writer.push(b'.');
+        writer.extend(&digits[1..]);
+    }
+    writer.push(b'e');
+    writer.exponent(exponent, 2);
+}
+
+/// Write the digits in fixed notation, such as `1500`.
+#[cfg(feature = "write-floats")]
+fn write_fixed(writer: &mut FloatWriter, digits: &[u8], exponent: i32) {
+    if exponent < 0 {
+        writer.extend(b"0.");
+        writer.repeat(b'0', (-exponent - 1) as usize);
+        writer.extend(digits);
+        return;
+    }
+
+    let integer = exponent as usize + 1;
+    if digits.len() > integer {
+        writer.extend(&digits[..integer]);
+        writer.push(b'.');
+        writer.extend(&digits[integer..]);
+    } else {
+        writer.extend(digits);
+        writer.repeat(b'0', integer - digits.len());
+    }
+}
+
+/// Get the components of a finite, positive float in hexadecimal notation.
+///
+/// Returns the leading digit, the fraction without trailing zero nibbles,
+/// the number of fraction nibbles, and the binary exponent.
+#[cfg(feature = "write-floats")]
+fn hex_parts<F: Float>(value: F) -> (u8, u64, usize, i32) {
+    let mantissa_size = F::MANTISSA_SIZE as usize;
+    let pad = (4 - mantissa_size % 4) % 4;
+    let nibbles = (mantissa_size + pad) / 4;
+    let fraction = (value.to_bits() & F::MANTISSA_MASK).as_u64() << pad;
+    let (leading, exponent) = if value == F::ZERO {
+        (b'0', 0)
+    } else if value.is_denormal() {
+        (b'0', F::DENORMAL_EXPONENT + F::MANTISSA_SIZE)
+    } else {
+        (b'1', value.exponent() + F::MANTISSA_SIZE)
+    };
+    let trailing = if fraction == 0 {
+        nibbles
+    } else {
+        fraction.trailing_zeros() as usize / 4
+    };
+    (leading, fraction >> (4 * trailing), nibbles - trailing, exponent)
+}
+
+/// Write a finite, positive float in hexadecimal notation, such as `1.8p+1`.
+#[cfg(feature = "write-floats")]
+fn write_hex(writer: &mut FloatWriter, leading: u8, fraction: u64, count: usize, exponent: i32) {
+    writer.push(leading);
+    if count != 0 {
+        writer.push(b'.');
+        for index in (0..count).rev() {
+            let nibble = ((fraction >> (4 * index)) & 0xF) as u32;
+            writer.push(core::char::from_digit(nibble, 16).unwrap_or('0') as u8);
+        }
+    }
+    writer.push(b'p');
+    writer.exponent(exponent, 1);
+}
+
+/// Write a float in the style of `printf`, with the shortest digits.
+///
+/// [`CharsFormat::GENERAL`] chooses the notation like `%g`, with the
+/// precision of the shortest digits. Otherwise, unless only one of fixed
+/// or scientific notation is set, the shorter notation is used, preferring
+/// fixed notation on ties.
+#[cfg(feature = "write-floats")]
+fn float_to_chars<F>(value: F, bytes: &mut [u8], format: CharsFormat) -> ToCharsResult
+where
+    F: ToLexicalWithOptions<Options = WriteFloatOptions> + Float,
+{
+    let sign = value.is_sign_negative() as usize;
+    let special: Option<&[u8]> = if value.is_nan() {
+        Some(b"nan")
+    } else if value.is_inf() {
+        Some(b"inf")
+    } else {
+        None
+    };
+    if let Some(special) = special {
+        let mut writer = match FloatWriter::new(bytes, sign + special.len()) {
+            Ok(writer) => writer,
+            Err(result) => return result,
+        };
+        writer.repeat(b'-', sign);
+        writer.extend(special);
+        return writer.finish();
+    }
+
+    let value = if sign == 1 {
+        -value
+    } else {
+        value
+    };
+    if format == CharsFormat::HEX {
+        let (leading, fraction, count, exponent) = hex_parts(value);
+        let length = sign + 1 + (count != 0) as usize + count + 2 + exponent_digits(exponent);
+        let mut writer = match FloatWriter::new(bytes, length) {
+            Ok(writer) => writer,
+            Err(result) => return result,
+        };
+        writer.repeat(b'-', sign);
+        write_hex(&mut writer, leading, fraction, count, exponent);
+        return writer.finish();
+    }
+
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let mut digits = [0u8; BUFFER_SIZE];
+    let options = WriteFloatOptions::new();
+    let written = value.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+    let (count, exponent) = shortest_digits(written, &mut digits);
+    let digits = &digits[..count];
+
+    let is_fixed = format.contains(CharsFormat::FIXED);
+    let is_scientific = format.contains(CharsFormat::SCIENTIFIC);
+    let scientific = scientific_len(count, exponent);
+    let fixed = fixed_len(count, exponent);
+    let use_fixed = if format == CharsFormat::GENERAL {
+        -4 <= exponent && exponent < count as i32
+    } else {
+        is_fixed && (!is_scientific || fixed <= scientific)
+    };
+    let length = sign
+        + if use_fixed {
+            fixed
+        } else {
+            scientific
+        };
+    let mut writer = match FloatWriter::new(bytes, length) {
+        Ok(writer) => writer,
+        Err(result) => return result,
+    };
+    writer.repeat(b'-', sign);
+    if use_fixed {
+        write_fixed(&mut writer, digits, exponent);
+    } else {
+        write_scientific(&mut writer, digits, exponent);
+    }
+    writer.finish()
+}
+
+/// Implement `ToChars` for integers.
+#[cfg(feature = "write-integers")]
+macro_rules! integer_to_chars {
+    ($($t:ident)*) => ($(
+        impl ToChars for $t {
+            type Mode = u32;
+            const DEFAULT_MODE: u32 = 10;
+
+            #[inline]
+            fn to_chars(self, bytes: &mut [u8], base: u32) -> ToCharsResult {
+                integer_to_chars(self, bytes, base)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "write-integers")]
+integer_to_chars! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Implement `ToChars` for floats.
+#[cfg(feature = "write-floats")]
+macro_rules! float_to_chars {
+    ($($t:ident)*) => ($(
+        impl ToChars for $t {
+            type Mode = CharsFormat;
+            const DEFAULT_MODE: CharsFormat = CharsFormat::SHORTEST;
+
+            #[inline]
+            fn to_chars(self, bytes: &mut [u8], format: CharsFormat) -> ToCharsResult {
+                float_to_chars(self, bytes, format)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "write-floats")]
+float_to_chars! { f32 f64 }
@@ -0,0 +1,163 @@
+//! Parse delimited columns of numbers, with missing values.
+//!
+//! This is designed for dataframe libraries, which parse a column of
+//! a CSV file or similar data in one call, and store the values in the
+//! layout used by Apache Arrow: the parsed values, and a validity bitmap
+//! with a set bit for each value that is not null. Values that match one
+//! of the null tokens, such as `NA` or `null`, are nulls rather than
+//! errors, and are stored as the default value, which is zero.
+//!
+//! The bitmap has one bit per value, starting from the least significant
+//! bit of the first byte, padded with zeros to a whole number of bytes.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "parse-integers", feature = "parse-floats"))] {
+//! use lexical_core::column;
+//!
+//! let (values, validity) = column::parse::<i32>(b"1,NA,3,,5", b",", column::NULLS).unwrap();
+//! assert_eq!(values, [1, 0, 3, 0, 5]);
+//! assert_eq!(validity, [0b10101]);
+//!
+//! let (values, validity) = column::parse::<f64>(b"1.5\nnull", b"\n", column::NULLS).unwrap();
+//! assert_eq!(values, [1.5, 0.0]);
+//! assert_eq!(validity, [0b01]);
+//! # }
+//! ```
+
+#![cfg(all(feature = "parse", feature = "std"))]
+
+use crate::{FromLexical, FromLexicalWithOptions, Result};
+
+/// The default null tokens: an empty value, `NA`, and `null`.
+pub const NULLS: &[&[u8]] = &[b"", b"NA", b"null"];
+
+/// Parse a delimited column of numbers, with null tokens.
+///
+/// Returns the values and the validity bitmap of the column. Each value
+/// that exactly matches one of the `nulls` is a null, and any other value
+/// must be a complete number. An empty column has no values, and an empty
+/// delimiter parses the column as a single value.
+///
+/// * `bytes`       - Byte slice containing the delimited numeric strings.
+/// * `delimiter`   - Bytes between each value.
+/// * `nulls`       - Tokens for missing values.
+///
+/// # Errors
+///
+/// Returns the first error parsing a value that is not null, with the
+/// index of the error in the column, rather than in the value.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "parse-integers")] {
+/// use lexical_core::{column, Error};
+///
+/// let (values, validity) = column::parse::<u8>(b"1;-;3", b";", &[b"-"]).unwrap();
+/// assert_eq!(values, [1, 0, 3]);
+/// assert_eq!(validity, [0b101]);
+///
+/// assert_eq!(column::parse::<u8>(b"1;2x", b";", column::NULLS), Err(Error::InvalidDigit(3)));
+/// # }
+/// ```
+#[inline]
+pub fn parse<N: FromLexical + Default>(
+    bytes: &[u8],
+    delimiter: &[u8],
+    nulls: &[&[u8]],
+) -> Result<(Vec<N>, Vec<u8>)> {
+    parse_impl(bytes, delimiter, nulls, N::from_lexical)
+}
+
+/// Parse a delimited column of numbers, with null tokens and custom options.
+///
+/// This is the same as [`parse`], parsing each value that is not null
+/// with the number format and options.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Byte slice containing the delimited numeric strings.
+/// * `delimiter`   - Bytes between each value.
+/// * `nulls`       - Tokens for missing values.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{column, ParseFloatOptions};
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+/// let bytes = b"1,5;N/A;-2,25";
+/// let nulls: &[&[u8]] = &[b"N/A"];
+/// let (values, validity) =
+///     column::parse_with_options::<f64, FORMAT>(bytes, b";", nulls, &options).unwrap();
+/// assert_eq!(values, [1.5, 0.0, -2.25]);
+/// assert_eq!(validity, [0b101]);
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<N: FromLexicalWithOptions + Default, const FORMAT: u128>(
+    bytes: &[u8],
+    delimiter: &[u8],
+    nulls: &[&[u8]],
+    options: &N::Options,
+) -> Result<(Vec<N>, Vec<u8>)> {
+    parse_impl(bytes, delimiter, nulls, |value| {
+        N::from_lexical_with_options::<FORMAT>(value, options)
+    })
+}
+
+/// Parse a delimited column of numbers, using a callback for each value.
+fn parse_impl<N: Default, F: Fn(&[u8]) -> Result<N>>(
+    bytes: &[u8],
+    delimiter: &[u8],
+    nulls: &[&[u8]],
+    parse: F,
+) -> Result<(Vec<N>, Vec<u8>)> {
+    let mut values = Vec::new();
+    let mut validity = Vec::new();
+    if bytes.is_empty() {
+        return Ok((values, validity));
+    }
+
+    // A trailing delimiter is followed by an empty value.
+    let mut offset = 0;
+    loop {
+        let length = find(&bytes[offset..], delimiter).unwrap_or(bytes.len() - offset);
+        let value = &bytes[offset..offset + length];
+        let row = values.len();
+        if row % 8 == 0 {
+            validity.push(0);
+        }
+        if nulls.contains(&value) {
+            values.push(N::default());
+        } else {
+            values.push(parse(value).map_err(|error| error.shift_index(offset))?);
+            validity[row / 8] |= 1 << (row % 8);
+        }
+
+        offset += length;
+        if offset == bytes.len() {
+            break;
+        }
+        offset += delimiter.len();
+    }
+    Ok((values, validity))
+}
+
+/// Find the index of the first delimiter in the bytes.
+fn find(bytes: &[u8], delimiter: &[u8]) -> Option<usize> {
+    if delimiter.is_empty() {
+        return None;
+    }
+    bytes.windows(delimiter.len()).position(|window| window == delimiter)
+}
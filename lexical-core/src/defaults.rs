@@ -0,0 +1,142 @@
+//! Process-wide default options for the conversions without options.
+
+#![cfg(all(feature = "std", any(feature = "parse", feature = "write")))]
+
+use std::sync::OnceLock;
+
+#[cfg(feature = "write")]
+use crate::format::STANDARD;
+#[cfg(feature = "parse-floats")]
+use crate::ParseFloatOptions;
+#[cfg(feature = "parse-integers")]
+use crate::ParseIntegerOptions;
+#[cfg(feature = "write-floats")]
+use crate::WriteFloatOptions;
+#[cfg(feature = "write-integers")]
+use crate::WriteIntegerOptions;
+#[cfg(feature = "write")]
+use crate::{FormattedSize, WriteOptions};
+
+/// Options that can be installed as the process-wide defaults.
+///
+/// The defaults are used with the [`STANDARD`] number format by the
+/// conversion functions without explicit options, such as [`parse`] and
+/// [`write`], so applications using a single set of options do not need
+/// to pass them to every call. Like a lazily-initialized static, the
+/// defaults can be installed only once.
+///
+/// [`STANDARD`]: crate::format::STANDARD
+/// [`parse`]: crate::parse
+/// [`write`]: fn@crate::write
+pub trait DefaultOptions: Sized + Sync + 'static {
+    /// Install the options as the process-wide defaults.
+    ///
+    /// Returns the options back if the defaults were already installed,
+    /// if the options are invalid, or if the write options may need
+    /// more than `FORMATTED_SIZE_DECIMAL` bytes for any number type.
+    fn set_default(self) -> core::result::Result<(), Self>;
+
+    /// Get the process-wide default options, if installed.
+    fn get_default() -> Option<&'static Self>;
+}
+
+/// Install the process-wide default options.
+///
+/// This affects all conversions of the number types using the options
+/// without explicit options, including [`parse`], [`parse_partial`] and
+/// [`write`]. Buffers sized for the plain conversions, such as
+/// `{integer}::FORMATTED_SIZE_DECIMAL` elements, remain sufficient, so
+/// write options are rejected if [`WriteOptions::buffer_size`] is larger
+/// than `FORMATTED_SIZE_DECIMAL` for any number type, such as options
+/// with a leading space or digit grouping.
+///
+/// Returns the options back if the defaults were already installed, if
+/// the options are invalid, or if the write options do not fit.
+///
+/// * `options` - Options to install as the defaults.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// assert!(lexical_core::set_default_options(options.clone()).is_ok());
+/// assert_eq!(lexical_core::parse::<f64>(b"3,5"), Ok(3.5));
+///
+/// // The defaults may only be installed once.
+/// assert!(lexical_core::set_default_options(options).is_err());
+/// # }
+/// # }
+/// ```
+///
+/// [`parse`]: crate::parse
+/// [`parse_partial`]: crate::parse_partial
+/// [`write`]: fn@crate::write
+/// [`WriteOptions::buffer_size`]: crate::WriteOptions::buffer_size
+#[inline]
+pub fn set_default_options<O: DefaultOptions>(options: O) -> core::result::Result<(), O> {
+    options.set_default()
+}
+
+/// Get the process-wide default options, if installed.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::WriteIntegerOptions;
+///
+/// assert_eq!(lexical_core::default_options::<WriteIntegerOptions>(), None);
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn default_options<O: DefaultOptions>() -> Option<&'static O> {
+    O::get_default()
+}
+
+/// Implement `DefaultOptions` for an options type.
+///
+/// The defaults are stored in a `OnceLock`, installed at most once.
+/// Write options are validated against every number type they apply to.
+macro_rules! default_options_impl {
+    ($options:ident, $cell:ident; $($t:ident)*) => {
+        static $cell: OnceLock<$options> = OnceLock::new();
+
+        impl DefaultOptions for $options {
+            fn set_default(self) -> core::result::Result<(), Self> {
+                let is_valid = self.is_valid()
+                    $(&& self.buffer_size::<$t, STANDARD>() <= $t::FORMATTED_SIZE_DECIMAL)*;
+                if !is_valid {
+                    return Err(self);
+                }
+                $cell.set(self)
+            }
+
+            #[inline(always)]
+            fn get_default() -> Option<&'static Self> {
+                $cell.get()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "parse-integers")]
+default_options_impl! { ParseIntegerOptions, PARSE_INTEGER_DEFAULTS; }
+
+#[cfg(feature = "parse-floats")]
+default_options_impl! { ParseFloatOptions, PARSE_FLOAT_DEFAULTS; }
+
+#[cfg(feature = "write-integers")]
+default_options_impl! {
+    WriteIntegerOptions, WRITE_INTEGER_DEFAULTS;
+    u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize
+}
+
+#[cfg(feature = "write-floats")]
+default_options_impl! { WriteFloatOptions, WRITE_FLOAT_DEFAULTS; f32 f64 }
@@ -0,0 +1,90 @@
+//! An API mirroring the `itoa` crate.
+//!
+//! This provides a [`Buffer`] with the same methods as `itoa::Buffer`,
+//! backed by lexical, so call sites can be migrated by changing the
+//! import, and both crates can be benchmarked behind one interface. The
+//! output is the same as `itoa`, and does not use the default options set
+//! by [`set_default_options`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use lexical_core::itoa;
+//!
+//! let mut buffer = itoa::Buffer::new();
+//! assert_eq!(buffer.format(128u64), "128");
+//! assert_eq!(buffer.format(i128::MIN), "-170141183460469231731687303715884105728");
+//! ```
+//!
+//! [`set_default_options`]: crate::set_default_options
+
+#![cfg(feature = "write-integers")]
+
+use core::mem::MaybeUninit;
+use core::str;
+
+use lexical_util::constants::FormattedSize;
+use lexical_write_integer::ToLexical;
+
+use crate::uninit_as_bytes;
+
+/// The number of bytes needed to write any integer.
+const BUFFER_SIZE: usize = i128::FORMATTED_SIZE_DECIMAL;
+
+/// A buffer to write integers to, mirroring `itoa::Buffer`.
+///
+/// The buffer is uninitialized, and large enough to write any integer
+/// without bounds checks.
+#[derive(Clone, Copy)]
+pub struct Buffer {
+    bytes: [MaybeUninit<u8>; BUFFER_SIZE],
+}
+
+impl Buffer {
+    /// Create a new buffer.
+    ///
+    /// This is cheap, since the buffer is not initialized.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bytes: [MaybeUninit::uninit(); BUFFER_SIZE],
+        }
+    }
+
+    /// Write an integer to the buffer, and get the written string.
+    #[inline]
+    pub fn format<I: Integer>(&mut self, i: I) -> &str {
+        // SAFETY: safe since the writers never read uninitialized memory,
+        // and only the written prefix is returned.
+        let bytes = i.to_lexical(unsafe { uninit_as_bytes(&mut self.bytes) });
+        // SAFETY: safe since all the numerical strings we write are ASCII.
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl Default for Buffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An integer that can be written to a [`Buffer`], mirroring `itoa::Integer`.
+///
+/// This is implemented for all primitive integers, and cannot be
+/// implemented outside of lexical.
+pub trait Integer: private::Sealed {}
+
+mod private {
+    /// Prevent `Integer` from being implemented outside of lexical.
+    pub trait Sealed: super::ToLexical {}
+}
+
+macro_rules! integer_impl {
+    ($($t:ident)*) => ($(
+        impl private::Sealed for $t {}
+        impl Integer for $t {}
+    )*);
+}
+
+integer_impl! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
@@ -84,6 +84,32 @@
 #![cfg_attr(feature = "write", doc = " - [`write_unchecked`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options_unchecked`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_format`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_override`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_checked`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_options_checked`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_uninit`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_options_uninit`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to_with_options`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_io`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_io_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_slice`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_slice_with_options`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_slice_to_vec`]")]
+#![cfg_attr(
+    all(feature = "write", feature = "std"),
+    doc = " - [`write_slice_to_vec_with_options`]"
+)]
+#![cfg_attr(
+    all(feature = "write", feature = "std", feature = "rayon"),
+    doc = " - [`write_slice_parallel`]"
+)]
+#![cfg_attr(
+    all(feature = "write", feature = "std", feature = "rayon"),
+    doc = " - [`write_slice_parallel_with_options`]"
+)]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_float_chunked`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -91,6 +117,64 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_with_format`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_format`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_with_override`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_override`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_narrow`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_narrow_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`representation`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`representation_with_options`]")]
+//!
+#![cfg_attr(all(feature = "parse", feature = "write"), doc = " **Reformat**")]
+#![cfg_attr(all(feature = "parse", feature = "write"), doc = "")]
+#![cfg_attr(all(feature = "parse", feature = "write"), doc = " - [`reformat`]")]
+#![cfg_attr(all(feature = "parse", feature = "write"), doc = " - [`reformat_with_formats`]")]
+//!
+#![cfg_attr(
+    all(feature = "std", any(feature = "parse", feature = "write")),
+    doc = " **Default Options**"
+)]
+#![cfg_attr(all(feature = "std", any(feature = "parse", feature = "write")), doc = "")]
+#![cfg_attr(
+    all(feature = "std", any(feature = "parse", feature = "write")),
+    doc = " - [`set_default_options`]"
+)]
+#![cfg_attr(
+    all(feature = "std", any(feature = "parse", feature = "write")),
+    doc = " - [`default_options`]"
+)]
+//!
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = " **C++ Compatibility**")]
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = "")]
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = " - [`charconv`]")]
+//!
+#![cfg_attr(feature = "write", doc = " **Rust Compatibility**")]
+#![cfg_attr(feature = "write", doc = "")]
+#![cfg_attr(feature = "write-integers", doc = " - [`itoa`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`ryu`]")]
+//!
+#![cfg_attr(all(feature = "parse-integers", feature = "parse-floats"), doc = " **Type Inference**")]
+#![cfg_attr(all(feature = "parse-integers", feature = "parse-floats"), doc = "")]
+#![cfg_attr(all(feature = "parse-integers", feature = "parse-floats"), doc = " - [`smallest`]")]
+//!
+#![cfg_attr(all(feature = "parse-integers", feature = "parse-floats"), doc = " **Validation**")]
+#![cfg_attr(all(feature = "parse-integers", feature = "parse-floats"), doc = "")]
+#![cfg_attr(all(feature = "parse-integers", feature = "parse-floats"), doc = " - [`lint`]")]
+//!
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " **Columnar Parsing**")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = "")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`column`]")]
+//!
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = " **Localized Digits**")]
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = "")]
+#![cfg_attr(any(feature = "parse", feature = "write"), doc = " - [`numerals`]")]
+//!
+#![cfg_attr(feature = "parse-floats", doc = " **Compile-Time Parsing**")]
+#![cfg_attr(feature = "parse-floats", doc = "")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`const_f64`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`const_f32`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`constant`]")]
 //!
 //! # Features
 //!
@@ -109,8 +193,9 @@
 //! ### std
 //!
 //! Enable use of the standard library. Currently, the standard library
-//! is not used for any functionality, and may be disabled without any
-//! change in functionality on stable.
+//! is only used to write to `io::Write` sinks and to install process-wide
+//! default options, and may be disabled without any other change in
+//! functionality on stable.
 //!
 //! ### write-integers
 //!
@@ -179,12 +264,32 @@
 //! for esoteric programming languages which use duodecimal floats, for
 //! example.
 //!
+//! ### radix-N
+//!
+//! Enable numeric conversions to and from strings in a single radix, such
+//! as `radix-12` or `radix-36`, without the pre-computed tables to parse
+//! floats in every other radix, which are most of the static storage used
+//! by `radix`. The tables for the enabled radixes are generated at build
+//! time, and parsing floats in any other radix that is not decimal or a
+//! power-of-two fails with `Error::InvalidMantissaRadix`. These features
+//! are additive: if `radix` is also enabled, the tables for every radix
+//! are generated. The power-of-two radixes, such as `radix-16`, only
+//! enable `power-of-two`.
+//!
 //! ### compact
 //!
 //! Reduce the generated code size at the cost of performance. This minimizes
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! ### wasm
+//!
+//! Optimize float conversions for WebAssembly. This avoids 128-bit
+//! multiplication, which is emulated on `wasm32`, always uses 32-bit limbs
+//! for big integers, and recovers the powers of five for the Eisel-Lemire
+//! and Dragonbox algorithms from compressed tables, shrinking them from
+//! about 10KB each to under 1KB.
+//!
 //! ### safe
 //!
 //! All numeric parsers are memory-safe by default, since parsing complex
@@ -196,6 +301,82 @@
 //! safe feature enabled and disabled, with the tests verified by Miri
 //! and Valgrind.
 //!
+//! ### no-panic
+//!
+//! Remove all reachable panics from the parsers and writers, for
+//! environments that must prove the absence of panics. If an internal
+//! invariant of the slow path float algorithms, which hold for any valid
+//! input, does not hold, [`Error::Internal`] is returned rather than
+//! panicking. This cannot be combined with `safe`, and the number writers
+//! still panic if the buffer is too small: use `write_checked` instead.
+//!
+//! ### asm
+//!
+//! Use inline assembly for the high bits of 64-bit multiplication when
+//! parsing floats, with `umulh` on aarch64 and `mulhu` on RISC-V, where
+//! the compiler may otherwise emit a slower sequence. Other architectures
+//! use native 128-bit multiplication. This requires Rust 1.59+.
+//!
+//! ### simd
+//!
+//! Use vectorized algorithms to write decimal integers 8 digits at a time.
+//! This uses SSE2 on x86 and x86_64, and NEON on little-endian aarch64,
+//! which requires Rust 1.59+. Other targets compute the digits in parallel
+//! within a 64-bit integer (SIMD-within-a-register).
+//!
+//! ### heapless
+//!
+//! Add `write_heapless`, to append numbers to a `heapless::String`,
+//! for embedded environments without an allocator.
+//!
+//! ### arrayvec
+//!
+//! Add `write_array_string`, to append numbers to an
+//! `arrayvec::ArrayString`, for environments without an allocator.
+//!
+//! ### rayon
+//!
+//! Add `write_slice_parallel`, to write large slices of numbers in
+//! parallel on the `rayon` thread pool, for multi-core export jobs.
+//! This requires the `std` feature.
+//!
+//! ### arbitrary
+//!
+//! Implement `arbitrary::Arbitrary` for the runtime number format,
+//! the number format builder, the options, and the rounding modes,
+//! so structured fuzzers can exercise any valid configuration.
+//! Only valid formats and options are generated. This requires
+//! a newer Rust version than the minimum supported version.
+//!
+//! ### proptest
+//!
+//! Add the `strategy` module, with property-testing strategies that
+//! generate random, valid number formats, and valid or near-valid
+//! numeric strings for a number format. This requires the `std`
+//! feature.
+//!
+//! ### defmt
+//!
+//! Implement `defmt::Format` for `Error`, so parse failures can be
+//! logged on embedded targets, such as over RTT, without the `core::fmt`
+//! machinery. This requires a newer Rust version than the minimum
+//! supported version.
+//!
+//! ### tracing
+//!
+//! Emit `tracing` events when parsing a float requires the moderate or
+//! slow path algorithms, with the digit counts and exponents, so services
+//! can diagnose why some inputs are slow to parse. This requires a newer
+//! Rust version than the minimum supported version.
+//!
+//! ### stats
+//!
+//! Count the floats parsed by the fast, moderate, and slow path
+//! algorithms, and the float parse errors, in global atomic counters.
+//! `stats` returns a snapshot of the counters, and `reset_stats` clears
+//! them, giving long-running services cheap visibility into the input
+//! they parse without the overhead of tracing.
+//!
 //! # Configuration API
 //!
 //! Lexical provides two main levels of configuration:
@@ -315,11 +496,51 @@
 //! [`write_unchecked`]: crate::write_unchecked
 //! [`write_with_options`]: crate::write_with_options
 //! [`write_with_options_unchecked`]: crate::write_with_options_unchecked
+//! [`write_with_format`]: crate::write_with_format
+//! [`write_with_override`]: crate::write_with_override
+//! [`write_checked`]: crate::write_checked
+//! [`write_with_options_checked`]: crate::write_with_options_checked
+//! [`write_uninit`]: crate::write_uninit
+//! [`write_with_options_uninit`]: crate::write_with_options_uninit
+//! [`write_to`]: crate::write_to
+//! [`write_to_with_options`]: crate::write_to_with_options
+//! [`write_io`]: crate::write_io
+//! [`write_io_with_options`]: crate::write_io_with_options
+//! [`write_slice`]: crate::write_slice
+//! [`write_slice_with_options`]: crate::write_slice_with_options
+//! [`write_slice_to_vec`]: crate::write_slice_to_vec
+//! [`write_slice_to_vec_with_options`]: crate::write_slice_to_vec_with_options
+//! [`write_slice_parallel`]: crate::write_slice_parallel
+//! [`write_slice_parallel_with_options`]: crate::write_slice_parallel_with_options
+//! [`write_float_chunked`]: crate::write_float_chunked
 //! [`parse`]: crate::parse
 //! [`parse_partial`]: crate::parse_partial
 //! [`parse_with_options`]: crate::parse_with_options
 //! [`parse_partial_with_options`]: crate::parse_partial_with_options
-//!
+//! [`parse_with_format`]: crate::parse_with_format
+//! [`parse_partial_with_format`]: crate::parse_partial_with_format
+//! [`parse_with_override`]: crate::parse_with_override
+//! [`parse_partial_with_override`]: crate::parse_partial_with_override
+//! [`parse_narrow`]: crate::parse_narrow
+//! [`parse_narrow_with_options`]: crate::parse_narrow_with_options
+//! [`representation`]: crate::representation
+//! [`representation_with_options`]: crate::representation_with_options
+//! [`reformat`]: crate::reformat
+//! [`reformat_with_formats`]: crate::reformat_with_formats
+//! [`set_default_options`]: crate::set_default_options
+//! [`default_options`]: crate::default_options
+//! [`charconv`]: crate::charconv
+//! [`itoa`]: crate::itoa
+//! [`ryu`]: crate::ryu
+//! [`smallest`]: crate::smallest
+//! [`lint`]: crate::lint
+//! [`column`]: crate::column
+//! [`numerals`]: crate::numerals
+//! [`const_f64`]: crate::const_f64
+//! [`const_f32`]: crate::const_f32
+//! [`constant`]: crate::constant
+//!
+//! [`Error::Internal`]: crate::Error::Internal
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
 //! [`ParseFloatOptions`]: crate::ParseFloatOptions
 //! [`ParseIntegerOptions`]: crate::ParseIntegerOptions
@@ -332,6 +553,13 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(all(feature = "write", any(feature = "heapless", feature = "arrayvec")))]
+use core::marker::PhantomData;
+#[cfg(feature = "write")]
+use core::mem::MaybeUninit;
+#[cfg(all(feature = "write", any(feature = "heapless", feature = "arrayvec")))]
+use core::str;
+
 #[cfg(feature = "parse-floats")]
 use lexical_parse_float::{
     FromLexical as FromFloat,
@@ -351,45 +579,104 @@ use lexical_write_float::{ToLexical as ToFloat, ToLexicalWithOptions as ToFloatW
 #[cfg(feature = "write-integers")]
 use lexical_write_integer::{ToLexical as ToInteger, ToLexicalWithOptions as ToIntegerWithOptions};
 
+#[cfg(feature = "write")]
+mod buffer;
+#[cfg(all(feature = "std", any(feature = "parse", feature = "write")))]
+mod defaults;
+#[cfg(all(feature = "write", feature = "std", feature = "rayon"))]
+mod parallel;
+#[cfg(feature = "write")]
+mod slice;
+#[cfg(feature = "write")]
+mod writer;
+
+#[cfg(any(feature = "parse", feature = "write"))]
+pub mod charconv;
+#[cfg(all(feature = "parse", feature = "std"))]
+pub mod column;
+#[cfg(feature = "write-integers")]
+pub mod itoa;
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+pub mod lint;
+#[cfg(any(feature = "parse", feature = "write"))]
+pub mod numerals;
+#[cfg(feature = "write-floats")]
+pub mod ryu;
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+pub mod smallest;
+
 // Re-exports
 #[cfg(feature = "parse-floats")]
 pub use lexical_parse_float::{
+    const_f32,
+    const_f64,
+    constant,
     options as parse_float_options,
+    parse_narrow,
+    parse_narrow_with_options,
+    representation,
+    representation_with_options,
+    Narrow,
     Options as ParseFloatOptions,
     OptionsBuilder as ParseFloatOptionsBuilder,
+    OptionsOverride as ParseFloatOptionsOverride,
+    Representation,
+    MAX_SLOW_PATH_STACK_BYTES,
 };
+#[cfg(all(feature = "parse-floats", feature = "stats"))]
+pub use lexical_parse_float::{reset_stats, stats, Stats};
 #[cfg(feature = "parse-integers")]
 pub use lexical_parse_integer::{
     options as parse_integer_options,
     Options as ParseIntegerOptions,
     OptionsBuilder as ParseIntegerOptionsBuilder,
+    OptionsOverride as ParseIntegerOptionsOverride,
 };
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 #[cfg(feature = "write")]
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-#[cfg(feature = "parse")]
-pub use lexical_util::error::Error;
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_util::error::{Error, ErrorContext, ErrorKind, PartialError};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
-pub use lexical_util::format::{self, format_error, format_is_valid, NumberFormatBuilder};
+pub use lexical_util::format::{self, format_error, format_is_valid, Format, NumberFormatBuilder};
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_util::options::OverrideOptions;
 #[cfg(feature = "parse")]
 pub use lexical_util::options::ParseOptions;
 #[cfg(feature = "write")]
 pub use lexical_util::options::WriteOptions;
-#[cfg(feature = "parse")]
+#[cfg(any(feature = "parse", feature = "write"))]
 pub use lexical_util::result::Result;
+#[cfg(all(feature = "proptest", feature = "std"))]
+pub use lexical_util::strategy;
 #[cfg(feature = "write-floats")]
 pub use lexical_write_float::{
     options as write_float_options,
+    write_chunked as write_float_chunked,
     Options as WriteFloatOptions,
     OptionsBuilder as WriteFloatOptionsBuilder,
+    OptionsOverride as WriteFloatOptionsOverride,
 };
+#[cfg(all(feature = "std", any(feature = "parse", feature = "write")))]
+pub use self::defaults::{default_options, set_default_options, DefaultOptions};
+#[cfg(all(feature = "write", feature = "std", feature = "rayon"))]
+pub use self::parallel::{write_slice_parallel, write_slice_parallel_with_options};
+#[cfg(feature = "write")]
+pub use self::slice::{write_slice, write_slice_with_options};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use self::slice::{write_slice_to_vec, write_slice_to_vec_with_options};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use self::writer::{write_io, write_io_with_options};
+#[cfg(feature = "write")]
+pub use self::writer::{write_to, write_to_with_options};
 #[cfg(feature = "write-integers")]
 pub use lexical_write_integer::{
     options as write_integer_options,
     Options as WriteIntegerOptions,
     OptionsBuilder as WriteIntegerOptionsBuilder,
+    OptionsOverride as WriteIntegerOptionsOverride,
 };
 
 // API
@@ -411,11 +698,23 @@ macro_rules! from_lexical_impl {
         impl FromLexical for $t {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical(bytes: &[u8]) -> Result<Self> {
+                #[cfg(feature = "std")]
+                if let Some(options) = $options::get_default() {
+                    return <Self as $from_options>::from_lexical_with_options::<
+                        { format::STANDARD },
+                    >(bytes, options);
+                }
                 <Self as $from>::from_lexical(bytes)
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+                #[cfg(feature = "std")]
+                if let Some(options) = $options::get_default() {
+                    return <Self as $from_options>::from_lexical_partial_with_options::<
+                        { format::STANDARD },
+                    >(bytes, options);
+                }
                 <Self as $from>::from_lexical_partial(bytes)
             }
         }
@@ -438,6 +737,24 @@ macro_rules! from_lexical_impl {
             ) -> Result<(Self, usize)> {
                 <Self as $from_options>::from_lexical_partial_with_options::<FORMAT>(bytes, options)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format(
+                bytes: &[u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> Result<Self> {
+                <Self as $from_options>::from_lexical_with_format(bytes, format, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_format(
+                bytes: &[u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                <Self as $from_options>::from_lexical_partial_with_format(bytes, format, options)
+            }
         }
     };
 }
@@ -477,6 +794,13 @@ macro_rules! to_lexical_impl {
 
             #[cfg_attr(not(feature = "compact"), inline)]
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
+                // The defaults always fit in `FORMATTED_SIZE_DECIMAL` elements.
+                #[cfg(feature = "std")]
+                if let Some(options) = $options::get_default() {
+                    return <Self as $to_options>::to_lexical_with_options::<
+                        { format::STANDARD },
+                    >(self, bytes, options);
+                }
                 <Self as $to>::to_lexical(self, bytes)
             }
         }
@@ -506,6 +830,16 @@ macro_rules! to_lexical_impl {
             ) -> &'a mut [u8] {
                 <Self as $to_options>::to_lexical_with_options::<FORMAT>(self, bytes, options)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical_with_format<'a>(
+                self,
+                bytes: &'a mut [u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                <Self as $to_options>::to_lexical_with_format(self, bytes, format, options)
+            }
         }
     };
 }
@@ -600,6 +934,9 @@ pub fn write<N: ToLexical>(n: N, bytes: &mut [u8]) -> &mut [u8] {
 /// to ensure the function will not overwrite the buffer, provide a
 /// buffer with at least `{integer}::FORMATTED_SIZE` elements.
 ///
+/// Unlike [`write`](fn@write), this never uses the process-wide default
+/// options, since they may need a larger buffer.
+///
 /// # Example
 ///
 /// ```
@@ -756,6 +1093,434 @@ pub unsafe fn write_with_options_unchecked<'a, N: ToLexicalWithOptions, const FO
     unsafe { n.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
 }
 
+/// Write number to string with a number format known at runtime.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. This
+/// is the same as [`write_with_options`], except the number format is
+/// validated and stored in a [`Format`], rather than a `FORMAT` const
+/// generic, for formats read from configuration or user input.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `format`  - Validated number format.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least [`WriteOptions::buffer_size_with_format`]
+/// elements.
+///
+/// Panics for floats if the mantissa radix and exponent base are not a
+/// supported combination, or if the options punctuation conflicts with
+/// the format.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::{BUFFER_SIZE, Format};
+///
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let format = Format::new(lexical_core::format::STANDARD).unwrap();
+/// let options = lexical_core::WriteFloatOptions::new();
+/// let float = 3.14159265359_f32;
+/// let digits = lexical_core::write_with_format(float, &mut buffer, &format, &options);
+/// assert_eq!(digits, b"3.1415927");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_with_format<'a, N: ToLexicalWithOptions>(
+    n: N,
+    bytes: &'a mut [u8],
+    format: &Format,
+    options: &N::Options,
+) -> &'a mut [u8] {
+    n.to_lexical_with_format(bytes, format, options)
+}
+
+/// Write number to string with per-call option overrides.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. This
+/// is the same as [`write_with_options`], except the overrides are
+/// applied to the options for this call only, such as to write a
+/// single value with a different precision. Returns an error if the
+/// options with the overrides applied are invalid.
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `value`     - Number to serialize.
+/// * `bytes`     - Buffer to write number to.
+/// * `options`   - Options to customize number writing.
+/// * `overrides` - Overrides for a subset of the options.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, with the overrides applied. In order to ensure the function
+/// will not panic, provide a buffer with at least
+/// [`WriteOptions::buffer_size`] elements for the options with the
+/// overrides applied.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use core::num::NonZeroUsize;
+/// use lexical_core::BUFFER_SIZE;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let mut buffer = [0u8; BUFFER_SIZE];
+/// let options = lexical_core::WriteFloatOptions::new();
+/// let overrides = lexical_core::WriteFloatOptionsOverride::new()
+///     .max_significant_digits(NonZeroUsize::new(3));
+/// let float = 3.14159265359_f32;
+/// let digits = lexical_core::write_with_override::<_, FORMAT>(float, &mut buffer, &options, &overrides);
+/// assert_eq!(digits.unwrap(), b"3.14");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_with_override<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+    overrides: &<N::Options as OverrideOptions>::Override,
+) -> Result<&'a mut [u8]>
+where
+    N::Options: OverrideOptions,
+{
+    let options = options.with_override(overrides)?;
+    Ok(n.to_lexical_with_options::<FORMAT>(bytes, &options))
+}
+
+/// Write number to string, returning an error if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. Unlike
+/// [`write`](fn@write), this never panics: if the serialized number does
+/// not fit in the buffer, [`Error::BufferTooSmall`] is returned with the
+/// number of bytes required, and the buffer may be left unchanged.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::Error;
+///
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(lexical_core::write_checked(123u32, &mut buffer).unwrap(), b"123");
+/// assert_eq!(lexical_core::write_checked(12345u32, &mut buffer), Err(Error::BufferTooSmall {
+///     needed: 5
+/// }));
+/// # }
+/// # }
+/// ```
+///
+/// [`Error::BufferTooSmall`]: crate::Error::BufferTooSmall
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_checked<N: ToLexical>(n: N, bytes: &mut [u8]) -> Result<&mut [u8]> {
+    if bytes.len() >= BUFFER_SIZE {
+        Ok(n.to_lexical(bytes))
+    } else {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        buffer::copy_checked(n.to_lexical(&mut buffer), bytes)
+    }
+}
+
+/// Write number to string with custom options, returning an error if
+/// the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. Unlike
+/// [`write_with_options`], this never panics due to an insufficient
+/// buffer: [`Error::BufferTooSmall`] is returned instead, with the number
+/// of bytes required, and the buffer may be left unchanged. If the options
+/// may require more than [`BUFFER_SIZE`] bytes, such as with custom digit
+/// precision control or exponent break points for floats, the number of
+/// bytes required is the upper bound from [`WriteOptions::buffer_size`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::Error;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::new();
+/// let mut buffer = [0u8; 4];
+/// let result = lexical_core::write_with_options_checked::<_, FORMAT>(1.5f64, &mut buffer, &options);
+/// assert_eq!(result.unwrap(), b"1.5");
+/// let result = lexical_core::write_with_options_checked::<_, FORMAT>(1.125f64, &mut buffer, &options);
+/// assert_eq!(result, Err(Error::BufferTooSmall {
+///     needed: 5
+/// }));
+/// # }
+/// # }
+/// ```
+///
+/// [`Error::BufferTooSmall`]: crate::Error::BufferTooSmall
+/// [`WriteOptions::buffer_size`]: crate::WriteOptions::buffer_size
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_with_options_checked<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> Result<&'a mut [u8]> {
+    let (size, write) = buffer::options_writer::<N, FORMAT>(options);
+    if bytes.len() >= size {
+        Ok(write(n, bytes))
+    } else {
+        buffer::with_stack_buffer(n, size, write, move |src| buffer::copy_checked(src, bytes))
+            .unwrap_or(Err(Error::BufferTooSmall {
+                needed: size,
+            }))
+    }
+}
+
+/// Write number to an uninitialized buffer.
+///
+/// Returns the initialized prefix of the input buffer containing the
+/// written bytes, starting from the same address in memory as the
+/// input slice. This avoids zero-initializing large scratch buffers.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Uninitialized buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use core::mem::MaybeUninit;
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); BUFFER_SIZE];
+/// let float = 3.14159265359_f32;
+///
+/// assert_eq!(lexical_core::write_uninit(float, &mut buffer), b"3.1415927");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_uninit<N: ToLexical>(n: N, bytes: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    // SAFETY: safe since the writers never read uninitialized memory,
+    // and only the written prefix is returned.
+    n.to_lexical(unsafe { uninit_as_bytes(bytes) })
+}
+
+/// Write number to an uninitialized buffer with custom options.
+///
+/// Returns the initialized prefix of the input buffer containing the
+/// written bytes, starting from the same address in memory as the
+/// input slice. This avoids zero-initializing large scratch buffers.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Uninitialized buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements. If you
+/// are using custom digit precision control or exponent break points
+/// for writing floats, these constants may be insufficient to store
+/// the serialized number, and up to 1200 bytes may be required with
+/// radix support.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use core::mem::MaybeUninit;
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); BUFFER_SIZE];
+/// let float = 3.14159265359_f32;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::new();
+/// let bytes = lexical_core::write_with_options_uninit::<_, FORMAT>(float, &mut buffer, &options);
+/// assert_eq!(bytes, b"3.1415927");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_with_options_uninit<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [MaybeUninit<u8>],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    // SAFETY: safe since the writers never read uninitialized memory,
+    // and only the written prefix is returned.
+    n.to_lexical_with_options::<FORMAT>(unsafe { uninit_as_bytes(bytes) }, options)
+}
+
+/// Get an uninitialized buffer as a slice of bytes.
+///
+/// # Safety
+///
+/// Safe if we never read uninitialized memory.
+#[inline(always)]
+#[cfg(feature = "write")]
+unsafe fn uninit_as_bytes(bytes: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    // SAFETY: safe since `MaybeUninit<u8>` has the same layout as `u8`,
+    // as long as uninitialized memory is never read.
+    unsafe { &mut *(bytes as *mut [MaybeUninit<u8>] as *mut [u8]) }
+}
+
+/// Compile-time check that a string with a fixed capacity can hold any `N`.
+#[cfg(all(feature = "write", any(feature = "heapless", feature = "arrayvec")))]
+struct Capacity<N, const CAP: usize>(PhantomData<N>);
+
+#[cfg(all(feature = "write", any(feature = "heapless", feature = "arrayvec")))]
+impl<N: FormattedSize, const CAP: usize> Capacity<N, CAP> {
+    /// Fails to evaluate if `CAP` is smaller than the decimal formatted size.
+    const CHECK: () = [()][(CAP < N::FORMATTED_SIZE_DECIMAL) as usize];
+}
+
+/// Append number to a [`heapless::String`].
+///
+/// The capacity of the string is checked at compile time to be large
+/// enough to hold any value of the number type, which is
+/// [`FORMATTED_SIZE_DECIMAL`] bytes. If the remaining capacity is not
+/// sufficient for the serialized number, the string is left unchanged
+/// and an error is returned.
+///
+/// * `value`   - Number to serialize.
+/// * `string`  - String to append the number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut string = heapless::String::<16>::new();
+/// lexical_core::write_heapless(12345u32, &mut string).unwrap();
+/// assert_eq!(string, "12345");
+/// # }
+/// # }
+/// ```
+///
+/// This will fail to compile, because the string can never hold an `u64`:
+///
+/// ```compile_fail
+/// # #[cfg(feature = "write-integers")] {
+/// let mut string = heapless::String::<8>::new();
+/// lexical_core::write_heapless(12345u64, &mut string);
+/// # }
+/// # #[cfg(not(feature = "write-integers"))] {
+/// #     compile_error!("");
+/// # }
+/// ```
+///
+/// [`heapless::String`]: heapless::String
+/// [`FORMATTED_SIZE_DECIMAL`]: crate::FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline]
+#[cfg(all(feature = "write", feature = "heapless"))]
+pub fn write_heapless<N: ToLexical, const CAP: usize>(
+    n: N,
+    string: &mut heapless::String<CAP>,
+) -> core::result::Result<(), ()> {
+    #[allow(clippy::let_unit_value)]
+    let _ = Capacity::<N, CAP>::CHECK;
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = n.to_lexical(&mut buffer);
+    // SAFETY: safe since all the numerical strings we write are ASCII.
+    string.push_str(unsafe { str::from_utf8_unchecked(bytes) })
+}
+
+/// Append number to an [`arrayvec::ArrayString`].
+///
+/// The capacity of the string is checked at compile time to be large
+/// enough to hold any value of the number type, which is
+/// [`FORMATTED_SIZE_DECIMAL`] bytes. If the remaining capacity is not
+/// sufficient for the serialized number, the string is left unchanged
+/// and an error is returned.
+///
+/// * `value`   - Number to serialize.
+/// * `string`  - String to append the number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut string = arrayvec::ArrayString::<16>::new();
+/// lexical_core::write_array_string(12345u32, &mut string).unwrap();
+/// assert_eq!(string.as_str(), "12345");
+/// # }
+/// # }
+/// ```
+///
+/// This will fail to compile, because the string can never hold an `u64`:
+///
+/// ```compile_fail
+/// # #[cfg(feature = "write-integers")] {
+/// let mut string = arrayvec::ArrayString::<8>::new();
+/// lexical_core::write_array_string(12345u64, &mut string);
+/// # }
+/// # #[cfg(not(feature = "write-integers"))] {
+/// #     compile_error!("");
+/// # }
+/// ```
+///
+/// [`arrayvec::ArrayString`]: arrayvec::ArrayString
+/// [`FORMATTED_SIZE_DECIMAL`]: crate::FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline]
+#[cfg(all(feature = "write", feature = "arrayvec"))]
+pub fn write_array_string<N: ToLexical, const CAP: usize>(
+    n: N,
+    string: &mut arrayvec::ArrayString<CAP>,
+) -> core::result::Result<(), arrayvec::CapacityError> {
+    #[allow(clippy::let_unit_value)]
+    let _ = Capacity::<N, CAP>::CHECK;
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = n.to_lexical(&mut buffer);
+    // SAFETY: safe since all the numerical strings we write are ASCII.
+    string.try_push_str(unsafe { str::from_utf8_unchecked(bytes) }).map_err(|e| e.simplify())
+}
+
 /// Parse complete number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -867,3 +1632,372 @@ pub fn parse_partial_with_options<N: FromLexicalWithOptions, const FORMAT: u128>
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes, options)
 }
+
+/// Parse complete number from string with a number format known at runtime.
+///
+/// This is the same as [`parse_with_options`], except the number format
+/// is validated and stored in a [`Format`], rather than a `FORMAT` const
+/// generic, for formats read from configuration or user input.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Validated number format.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::Format;
+///
+/// let format = Format::new(lexical_core::format::JSON).unwrap();
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string = "3.14159265359";
+/// let result = lexical_core::parse_with_format::<f32>(string.as_bytes(), &format, &options);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_with_format<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    format: &Format,
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_with_format(bytes, format, options)
+}
+
+/// Parse partial number from string with a number format known at runtime.
+///
+/// This is the same as [`parse_partial_with_options`], except the number
+/// format is validated and stored in a [`Format`], rather than a `FORMAT`
+/// const generic.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Validated number format.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::Format;
+///
+/// let format = Format::new(lexical_core::format::JSON).unwrap();
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let string = "3.14159265359 hello";
+/// let result = lexical_core::parse_partial_with_format::<f32>(string.as_bytes(), &format, &options);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_with_format<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    format: &Format,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    N::from_lexical_partial_with_format(bytes, format, options)
+}
+
+/// Parse complete number from string with per-call option overrides.
+///
+/// This is the same as [`parse_with_options`], except the overrides
+/// are applied to the options for this call only, such as to parse a
+/// single value with a different decimal point. Returns an error if
+/// the options with the overrides applied are invalid.
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `bytes`     - Byte slice containing a numeric string.
+/// * `options`   - Options to customize number parsing.
+/// * `overrides` - Overrides for a subset of the options.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let overrides = lexical_core::ParseFloatOptionsOverride::new().decimal_point(b',');
+/// let string = "3,14159265359";
+/// let result = lexical_core::parse_with_override::<f32, FORMAT>(string.as_bytes(), &options, &overrides);
+/// assert_eq!(result, Ok(3.14159265359_f32));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_with_override<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+    overrides: &<N::Options as OverrideOptions>::Override,
+) -> Result<N>
+where
+    N::Options: OverrideOptions,
+{
+    let options = options.with_override(overrides)?;
+    N::from_lexical_with_options::<FORMAT>(bytes, &options)
+}
+
+/// Parse partial number from string with per-call option overrides.
+///
+/// This is the same as [`parse_partial_with_options`], except the
+/// overrides are applied to the options for this call only. Returns an
+/// error if the options with the overrides applied are invalid.
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `bytes`     - Byte slice containing a numeric string.
+/// * `options`   - Options to customize number parsing.
+/// * `overrides` - Overrides for a subset of the options.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let overrides = lexical_core::ParseFloatOptionsOverride::new().decimal_point(b',');
+/// let string = "3,14159265359 hello";
+/// let result = lexical_core::parse_partial_with_override::<f32, FORMAT>(string.as_bytes(), &options, &overrides);
+/// assert_eq!(result, Ok((3.14159265359_f32, 13)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_with_override<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+    overrides: &<N::Options as OverrideOptions>::Override,
+) -> Result<(N, usize)>
+where
+    N::Options: OverrideOptions,
+{
+    let options = options.with_override(overrides)?;
+    N::from_lexical_partial_with_options::<FORMAT>(bytes, &options)
+}
+
+/// Parse complete number from string, recovering the partially parsed value.
+///
+/// This is the same as [`parse`], except on an error, the value parsed
+/// from the longest valid prefix of the input and the number of bytes
+/// in that prefix are returned with the error. For integers that
+/// overflow, this is the value before the digit that overflowed.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// let error = lexical_core::parse_recover::<u8>(b"2567").unwrap_err();
+/// assert_eq!(error.value(), Some(&25));
+/// assert_eq!(error.count(), 2);
+///
+/// let error = lexical_core::parse_recover::<u8>(b"12x").unwrap_err();
+/// assert_eq!(error.value(), Some(&12));
+/// assert_eq!(error.count(), 2);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_recover<N: FromLexical>(bytes: &[u8]) -> core::result::Result<N, PartialError<N>> {
+    recover(bytes, N::from_lexical, N::from_lexical_partial)
+}
+
+/// Parse complete number from string with custom parsing options,
+/// recovering the partially parsed value.
+///
+/// This is the same as [`parse_with_options`], except on an error, the
+/// value parsed from the longest valid prefix of the input and the number
+/// of bytes in that prefix are returned with the error.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let error = lexical_core::parse_recover_with_options::<f64, FORMAT>(b"1.5 ", &options);
+/// assert_eq!(error.unwrap_err().value(), Some(&1.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_recover_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> core::result::Result<N, PartialError<N>> {
+    recover(
+        bytes,
+        |bytes| N::from_lexical_with_options::<FORMAT>(bytes, options),
+        |bytes| N::from_lexical_partial_with_options::<FORMAT>(bytes, options),
+    )
+}
+
+/// Parse the input, recovering the value from the longest valid prefix on an error.
+#[cfg(feature = "parse")]
+fn recover<N, Complete, Partial>(
+    bytes: &[u8],
+    complete: Complete,
+    partial: Partial,
+) -> core::result::Result<N, PartialError<N>>
+where
+    Complete: Fn(&[u8]) -> Result<N>,
+    Partial: Fn(&[u8]) -> Result<(N, usize)>,
+{
+    let error = match complete(bytes) {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+    let is_overflow = |error: &Error| error.is_overflow() || error.is_underflow();
+    let prefix = match partial(bytes) {
+        Err(ref error) if is_overflow(error) => {
+            // Any prefix longer than one that overflows also overflows, so
+            // binary search for the shortest prefix that overflows, and use
+            // the prefix just before it.
+            let mut lo = 0;
+            let mut hi = bytes.len();
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                match partial(&bytes[..mid]) {
+                    Err(ref error) if is_overflow(error) => hi = mid,
+                    _ => lo = mid + 1,
+                }
+            }
+            partial(&bytes[..lo.saturating_sub(1)])
+        },
+        prefix => prefix,
+    };
+    Err(match prefix {
+        Ok((value, count)) if count != 0 => PartialError::new(error, Some(value), count),
+        _ => PartialError::new(error, None, 0),
+    })
+}
+
+/// Parse a number and write it with custom options, in a single call.
+///
+/// This converts any representation accepted by the parse options to
+/// the representation configured by the write options, such as to
+/// normalize numbers from different sources. Returns a subslice of the
+/// output buffer containing the written bytes, starting from the same
+/// address in memory as the output slice, or the parse error. If the
+/// serialized number does not fit in the output buffer,
+/// [`Error::BufferTooSmall`] is returned, as with
+/// [`write_with_options_checked`].
+///
+/// * `bytes`           - Byte slice containing a numeric string.
+/// * `parse_options`   - Options to customize number parsing.
+/// * `write_options`   - Options to customize number writing.
+/// * `out`             - Buffer to write number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "write-floats"))] {
+/// use core::num::NonZeroUsize;
+///
+/// let parse_options = lexical_core::ParseFloatOptions::new();
+/// let write_options = lexical_core::WriteFloatOptions::builder()
+///     .max_significant_digits(NonZeroUsize::new(3))
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// let result = lexical_core::reformat::<f64>(b"1.23456e1", &parse_options, &write_options, &mut buffer);
+/// assert_eq!(result.unwrap(), b"12.3");
+/// # }
+/// # }
+/// ```
+///
+/// [`Error::BufferTooSmall`]: crate::Error::BufferTooSmall
+#[inline]
+#[cfg(all(feature = "parse", feature = "write"))]
+pub fn reformat<'a, N>(
+    bytes: &[u8],
+    parse_options: &<N as FromLexicalWithOptions>::Options,
+    write_options: &<N as ToLexicalWithOptions>::Options,
+    out: &'a mut [u8],
+) -> Result<&'a mut [u8]>
+where
+    N: FromLexicalWithOptions + ToLexicalWithOptions,
+{
+    reformat_with_formats::<N, { format::STANDARD }, { format::STANDARD }>(
+        bytes,
+        parse_options,
+        write_options,
+        out,
+    )
+}
+
+/// Parse a number and write it with custom formats and options, in a single call.
+///
+/// This is the same as [`reformat`], except the number is parsed with
+/// `PARSE_FORMAT` and written with `WRITE_FORMAT`, such as to remove
+/// digit separators or convert between radixes.
+///
+/// * `PARSE_FORMAT`    - Packed struct containing the format to parse.
+/// * `WRITE_FORMAT`    - Packed struct containing the format to write.
+/// * `bytes`           - Byte slice containing a numeric string.
+/// * `parse_options`   - Options to customize number parsing.
+/// * `write_options`   - Options to customize number writing.
+/// * `out`             - Buffer to write number to.
+///
+/// # Panics
+///
+/// If the provided formats are not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the formats, or checking
+/// their validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "format", feature = "parse-integers", feature = "write-integers"))] {
+/// use core::num::NonZeroU8;
+///
+/// const PARSE_FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+///     .digit_separator(NonZeroU8::new(b'_'))
+///     .integer_internal_digit_separator(true)
+///     .build();
+/// const WRITE_FORMAT: u128 = lexical_core::format::STANDARD;
+/// let parse_options = lexical_core::ParseIntegerOptions::new();
+/// let write_options = lexical_core::WriteIntegerOptions::new();
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// let result = lexical_core::reformat_with_formats::<u32, PARSE_FORMAT, WRITE_FORMAT>(
+///     b"1_000_000",
+///     &parse_options,
+///     &write_options,
+///     &mut buffer,
+/// );
+/// assert_eq!(result.unwrap(), b"1000000");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "write"))]
+pub fn reformat_with_formats<'a, N, const PARSE_FORMAT: u128, const WRITE_FORMAT: u128>(
+    bytes: &[u8],
+    parse_options: &<N as FromLexicalWithOptions>::Options,
+    write_options: &<N as ToLexicalWithOptions>::Options,
+    out: &'a mut [u8],
+) -> Result<&'a mut [u8]>
+where
+    N: FromLexicalWithOptions + ToLexicalWithOptions,
+{
+    let value = N::from_lexical_with_options::<PARSE_FORMAT>(bytes, parse_options)?;
+    write_with_options_checked::<N, WRITE_FORMAT>(value, out, write_options)
+}
@@ -0,0 +1,180 @@
+//! Detect numeric strings that are likely to be problematic.
+//!
+//! This is designed for editors and validators, to warn users about
+//! numbers that parse successfully but may not mean what was written,
+//! such as floats with more digits than can be stored, before any data
+//! is lost.
+//!
+//! # Example
+//!
+//! ```rust
+//! use lexical_core::lint::{self, Lints};
+//!
+//! assert_eq!(lint::analyze(b"1.5").unwrap(), Lints::default());
+//!
+//! let lints = lint::analyze(b"3.14159265358979323846").unwrap();
+//! assert!(lints.excess_precision);
+//!
+//! let lints = lint::analyze(b"1e400").unwrap();
+//! assert!(lints.exponent_out_of_range);
+//!
+//! let lints = lint::analyze(b"00755").unwrap();
+//! assert!(lints.leading_zeros);
+//!
+//! let lints = lint::analyze(b"9223372036854775808").unwrap();
+//! assert!(lints.integer_overflow);
+//! ```
+
+#![cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::format::NumberFormat;
+
+use crate::format::STANDARD;
+use crate::{
+    representation_with_options,
+    Error,
+    FromLexicalWithOptions,
+    ParseFloatOptions,
+    ParseIntegerOptions,
+    Representation,
+    Result,
+};
+
+/// The likely problems detected in a numeric string.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Lints {
+    /// The number has more significant digits than an `f64` can always
+    /// store, and is rounded as an `f64`, so some of the digits are lost.
+    pub excess_precision: bool,
+    /// The integer digits have redundant leading zeros, such as `007`,
+    /// which some languages parse as octal.
+    pub leading_zeros: bool,
+    /// The float is non-zero, but its exponent is out of range, so it
+    /// overflows to infinity or underflows to zero as an `f64`.
+    pub exponent_out_of_range: bool,
+    /// The number is an integer, but is too large for an `i64`.
+    pub integer_overflow: bool,
+}
+
+/// Detect the likely problems in a numeric string.
+///
+/// This uses the standard number format and the default options, and
+/// returns the error from parsing the number as a float if it is not a
+/// valid integer or float.
+#[inline]
+pub fn analyze(bytes: &[u8]) -> Result<Lints> {
+    analyze_with_options::<STANDARD>(bytes, &ParseIntegerOptions::new(), &ParseFloatOptions::new())
+}
+
+/// Detect the likely problems in a numeric string, using custom options.
+///
+/// This is the same as [`analyze`], for a custom number format. Floats
+/// with excess precision are only supported for radixes with a finite
+/// number of digits in binary, other than powers-of-two, as with
+/// [`representation`].
+///
+/// [`representation`]: crate::representation
+pub fn analyze_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    integer_options: &ParseIntegerOptions,
+    float_options: &ParseFloatOptions,
+) -> Result<Lints> {
+    let value = f64::from_lexical_with_options::<FORMAT>(bytes, float_options)?;
+    let mantissa = scan_mantissa::<FORMAT>(bytes, float_options.decimal_point());
+    let mut lints = Lints {
+        leading_zeros: mantissa.leading_zeros,
+        ..Lints::default()
+    };
+    if mantissa.significant_digits != 0 {
+        lints.exponent_out_of_range = value.is_infinite() || value == 0.0;
+    }
+
+    // Floats with few significant digits round-trip, even if inexact.
+    let format = NumberFormat::<{ FORMAT }> {};
+    let max_digits = max_exact_digits(format.mantissa_radix());
+    if !lints.exponent_out_of_range && mantissa.significant_digits > max_digits {
+        let representation = representation_with_options::<f64, FORMAT>(bytes, float_options)?;
+        lints.excess_precision = representation != Representation::Exact;
+    }
+
+    let integer = i64::from_lexical_with_options::<FORMAT>(bytes, integer_options);
+    lints.integer_overflow = matches!(integer, Err(Error::Overflow(_)) | Err(Error::Underflow(_)));
+    Ok(lints)
+}
+
+/// The digits in the mantissa of a numeric string.
+struct Mantissa {
+    /// The number of digits, excluding leading and trailing zeros.
+    significant_digits: usize,
+    /// If the integer digits have redundant leading zeros.
+    leading_zeros: bool,
+}
+
+/// Scan the digits in the mantissa of a valid numeric string.
+///
+/// This stops at the first byte that is not a digit, the decimal point,
+/// or a digit separator, such as the exponent. Special values, such as
+/// `NaN`, have no digits for radixes without the letters as digits.
+fn scan_mantissa<const FORMAT: u128>(bytes: &[u8], decimal_point: u8) -> Mantissa {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let separator = format.digit_separator();
+    let mut bytes = match bytes.first() {
+        Some(&b'+') | Some(&b'-') => &bytes[1..],
+        _ => bytes,
+    };
+    // Skip the base prefix, so its zero is not a leading zero.
+    let prefix = format.base_prefix();
+    if prefix != 0 && bytes.len() >= 2 && bytes[0] == b'0' && bytes[1].eq_ignore_ascii_case(&prefix)
+    {
+        bytes = &bytes[2..];
+    }
+
+    let mut is_fraction = false;
+    let mut integer_digits = 0;
+    let mut is_zero_first = false;
+    let mut index = 0;
+    let mut first = None;
+    let mut last = 0;
+    for &c in bytes {
+        if c == decimal_point && !is_fraction {
+            is_fraction = true;
+        } else if c == separator && separator != 0 {
+            continue;
+        } else if let Some(digit) = char_to_digit_const(c, radix) {
+            if !is_fraction {
+                is_zero_first |= integer_digits == 0 && digit == 0;
+                integer_digits += 1;
+            }
+            if digit != 0 {
+                first = first.or(Some(index));
+                last = index;
+            }
+            index += 1;
+        } else {
+            break;
+        }
+    }
+
+    Mantissa {
+        significant_digits: first.map_or(0, |first| last - first + 1),
+        leading_zeros: is_zero_first && integer_digits > 1,
+    }
+}
+
+/// Get the maximum number of digits that an `f64` can always store.
+///
+/// Any number with this many significant digits in the radix is
+/// written back with the same digits once rounded to an `f64`.
+fn max_exact_digits(radix: u32) -> usize {
+    let max = 1u64 << (f64::MANTISSA_DIGITS - 1);
+    let mut power = radix as u64;
+    let mut digits = 0;
+    while power <= max {
+        power *= radix as u64;
+        digits += 1;
+    }
+    digits
+}
@@ -0,0 +1,362 @@
+//! Parse and write numbers with non-ASCII decimal digits.
+//!
+//! This is designed for localized data, such as CSV or JSON files from
+//! Arabic or Hindi sources, where the decimal digits are the native
+//! digits of a script rather than `0` to `9`. The input is UTF-8, and the
+//! [`Numerals`] are ten consecutive Unicode code points for the digits,
+//! with presets for the [`ARABIC_INDIC`], [`EXTENDED_ARABIC_INDIC`], and
+//! [`DEVANAGARI`] digits. Any other characters, such as the sign, the
+//! decimal point, and the exponent, are the same as for ASCII numbers.
+//!
+//! When parsing, both the native and the ASCII digits are accepted, and
+//! when writing, every decimal digit is written as a native digit.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(all(feature = "std", feature = "parse-floats", feature = "write-integers"))] {
+//! use lexical_core::numerals::{self, ARABIC_INDIC, DEVANAGARI};
+//!
+//! assert_eq!(numerals::parse::<f64>("١٢.٥".as_bytes(), &ARABIC_INDIC), Ok(12.5));
+//!
+//! let mut string = String::new();
+//! numerals::write_to(2024u32, &DEVANAGARI, &mut string).unwrap();
+//! assert_eq!(string, "२०२४");
+//! # }
+//! ```
+
+#![cfg(any(feature = "parse", feature = "write"))]
+
+#[cfg(feature = "write")]
+use core::fmt;
+
+#[cfg(all(feature = "parse", feature = "std"))]
+use crate::{FromLexical, FromLexicalWithOptions, Result};
+#[cfg(feature = "write")]
+use crate::{ToLexical, ToLexicalWithOptions};
+
+/// The Arabic-Indic digits, `٠` to `٩`, used in Arabic.
+pub const ARABIC_INDIC: Numerals = Numerals::from_zero('\u{0660}');
+
+/// The Extended Arabic-Indic digits, `۰` to `۹`, used in Persian and Urdu.
+pub const EXTENDED_ARABIC_INDIC: Numerals = Numerals::from_zero('\u{06F0}');
+
+/// The Devanagari digits, `०` to `९`, used in Hindi and other languages.
+pub const DEVANAGARI: Numerals = Numerals::from_zero('\u{0966}');
+
+/// The ten decimal digits of a script, as consecutive code points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Numerals {
+    /// The digit for zero.
+    zero: char,
+}
+
+impl Numerals {
+    /// Create the digits of a script, from the code point of the zero digit.
+    ///
+    /// Returns `None` if the ten code points from `zero` are not all valid
+    /// characters, or if they include ASCII characters other than the
+    /// ASCII digits.
+    #[inline]
+    pub const fn new(zero: char) -> Option<Self> {
+        let first = zero as u32;
+        let last = first + 9;
+        let is_ascii = first != '0' as u32 && first < 0x80;
+        let is_invalid = last > 0x10FFFF || (first <= 0xDFFF && last >= 0xD800);
+        if is_ascii || is_invalid {
+            None
+        } else {
+            Some(Self::from_zero(zero))
+        }
+    }
+
+    /// Create the digits of a script, without validating them.
+    #[inline(always)]
+    const fn from_zero(zero: char) -> Self {
+        Self {
+            zero,
+        }
+    }
+
+    /// Get the digit for zero.
+    #[inline(always)]
+    pub const fn zero(self) -> char {
+        self.zero
+    }
+
+    /// Get the character for a decimal digit, or `None` if it is not a
+    /// decimal digit.
+    #[inline]
+    pub fn to_char(self, digit: u32) -> Option<char> {
+        match digit {
+            0..=9 => core::char::from_u32(self.zero as u32 + digit),
+            _ => None,
+        }
+    }
+
+    /// Get the decimal digit of a character, or `None` if it is not one
+    /// of the digits.
+    #[inline]
+    pub fn to_digit(self, c: char) -> Option<u32> {
+        let digit = (c as u32).wrapping_sub(self.zero as u32);
+        if digit <= 9 {
+            Some(digit)
+        } else {
+            None
+        }
+    }
+
+    /// Get the UTF-8 encoding of every digit, and the length of each.
+    #[cfg(all(feature = "parse", feature = "std"))]
+    fn encode(self) -> ([[u8; 4]; 10], [usize; 10]) {
+        let mut encoded = [[0u8; 4]; 10];
+        let mut lengths = [0; 10];
+        for digit in 0..10 {
+            // This cannot fail, since the digits are valid characters.
+            let c = self.to_char(digit as u32).unwrap();
+            lengths[digit] = c.encode_utf8(&mut encoded[digit]).len();
+        }
+        (encoded, lengths)
+    }
+}
+
+// PARSE
+// -----
+
+/// Parse complete number from a string with native digits.
+///
+/// This method parses the entire string, returning an error if any
+/// invalid digits are found during parsing. The index of an error is the
+/// byte offset in the original string.
+///
+/// * `bytes`       - Byte slice containing a UTF-8 numeric string.
+/// * `numerals`    - Native digits of the numeric string.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(all(feature = "std", feature = "parse-integers"))] {
+/// use lexical_core::numerals::{self, EXTENDED_ARABIC_INDIC};
+/// use lexical_core::Error;
+///
+/// let bytes = "-۱۴۰۳".as_bytes();
+/// assert_eq!(numerals::parse::<i32>(bytes, &EXTENDED_ARABIC_INDIC), Ok(-1403));
+///
+/// // Each digit is 2 bytes, so the invalid digit is at index 4.
+/// let bytes = "۱۴x".as_bytes();
+/// assert_eq!(numerals::parse::<i32>(bytes, &EXTENDED_ARABIC_INDIC), Err(Error::InvalidDigit(4)));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "std"))]
+pub fn parse<N: FromLexical>(bytes: &[u8], numerals: &Numerals) -> Result<N> {
+    parse_impl(bytes, numerals, N::from_lexical)
+}
+
+/// Parse complete number from a string with native digits and custom
+/// parsing options.
+///
+/// This is the same as [`parse`], for a custom number format, which must
+/// be decimal.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Byte slice containing a UTF-8 numeric string.
+/// * `numerals`    - Native digits of the numeric string.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(all(feature = "std", feature = "parse-floats"))] {
+/// use lexical_core::numerals::{self, DEVANAGARI};
+/// use lexical_core::ParseFloatOptions;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+/// let bytes = "३,२५".as_bytes();
+/// let result = numerals::parse_with_options::<f64, FORMAT>(bytes, &DEVANAGARI, &options);
+/// assert_eq!(result, Ok(3.25));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "parse", feature = "std"))]
+pub fn parse_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    numerals: &Numerals,
+    options: &N::Options,
+) -> Result<N> {
+    parse_impl(bytes, numerals, |ascii| N::from_lexical_with_options::<FORMAT>(ascii, options))
+}
+
+/// Parse a number with native digits, by replacing them with ASCII digits.
+#[cfg(all(feature = "parse", feature = "std"))]
+fn parse_impl<N, F: Fn(&[u8]) -> Result<N>>(
+    bytes: &[u8],
+    numerals: &Numerals,
+    parse: F,
+) -> Result<N> {
+    let (encoded, lengths) = numerals.encode();
+    let mut ascii = Vec::with_capacity(bytes.len());
+    let mut offsets = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        offsets.push(index);
+        let c = bytes[index];
+        let digit = if c < 0x80 {
+            None
+        } else {
+            (0..10).find(|&digit| bytes[index..].starts_with(&encoded[digit][..lengths[digit]]))
+        };
+        match digit {
+            Some(digit) => {
+                ascii.push(b'0' + digit as u8);
+                index += lengths[digit];
+            },
+            None => {
+                ascii.push(c);
+                index += 1;
+            },
+        }
+    }
+    offsets.push(bytes.len());
+
+    // Map the index of the error in the ASCII string to the input.
+    parse(&ascii).map_err(|error| match error.index().and_then(|&i| Some((i, *offsets.get(i)?))) {
+        Some((index, offset)) => error.shift_index(offset - index),
+        None => error,
+    })
+}
+
+// WRITE
+// -----
+
+/// Write number to a [`fmt::Write`] sink with native digits.
+///
+/// The number is written with ASCII digits to an intermediate stack
+/// buffer, as with [`write_to`], and each decimal digit is replaced with
+/// the native digit.
+///
+/// * `n`           - Number to serialize.
+/// * `numerals`    - Native digits to write.
+/// * `writer`      - Sink to write the number to.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "write-floats")] {
+/// use lexical_core::numerals::{self, ARABIC_INDIC};
+///
+/// let mut string = String::new();
+/// numerals::write_to(-0.5f64, &ARABIC_INDIC, &mut string).unwrap();
+/// assert_eq!(string, "-٠.٥");
+/// # }
+/// ```
+///
+/// [`fmt::Write`]: core::fmt::Write
+/// [`write_to`]: crate::write_to
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_to<N: ToLexical, W: fmt::Write + ?Sized>(
+    n: N,
+    numerals: &Numerals,
+    writer: &mut W,
+) -> fmt::Result {
+    crate::write_to(
+        n,
+        &mut NumeralsWriter {
+            writer,
+            numerals,
+        },
+    )
+}
+
+/// Write number to a [`fmt::Write`] sink with native digits and custom
+/// writing options.
+///
+/// This is the same as [`write_to`], for a custom number format, which
+/// should be decimal, since only the decimal digits are replaced.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `n`           - Number to serialize.
+/// * `numerals`    - Native digits to write.
+/// * `writer`      - Sink to write the number to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(all(feature = "write-integers", feature = "format"))] {
+/// use lexical_core::numerals::{self, DEVANAGARI};
+/// use lexical_core::{NumberFormatBuilder, WriteIntegerOptions};
+///
+/// const FORMAT: u128 = NumberFormatBuilder::new()
+///     .digit_separator(core::num::NonZeroU8::new(b','))
+///     .integer_internal_digit_separator(true)
+///     .build();
+/// let options = WriteIntegerOptions::builder()
+///     .digit_grouping(core::num::NonZeroUsize::new(3))
+///     .secondary_digit_grouping(core::num::NonZeroUsize::new(2))
+///     .build()
+///     .unwrap();
+///
+/// let mut string = String::new();
+/// numerals::write_to_with_options::<_, _, FORMAT>(1234567u32, &DEVANAGARI, &mut string, &options)
+///     .unwrap();
+/// assert_eq!(string, "१२,३४,५६७");
+/// # }
+/// ```
+///
+/// [`fmt::Write`]: core::fmt::Write
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_to_with_options<N, W, const FORMAT: u128>(
+    n: N,
+    numerals: &Numerals,
+    writer: &mut W,
+    options: &N::Options,
+) -> fmt::Result
+where
+    N: ToLexicalWithOptions,
+    W: fmt::Write + ?Sized,
+{
+    crate::write_to_with_options::<_, _, FORMAT>(
+        n,
+        &mut NumeralsWriter {
+            writer,
+            numerals,
+        },
+        options,
+    )
+}
+
+/// A [`fmt::Write`] sink that replaces ASCII digits with native digits.
+#[cfg(feature = "write")]
+struct NumeralsWriter<'a, W: fmt::Write + ?Sized> {
+    writer: &'a mut W,
+    numerals: &'a Numerals,
+}
+
+#[cfg(feature = "write")]
+impl<'a, W: fmt::Write + ?Sized> fmt::Write for NumeralsWriter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c.to_digit(10).and_then(|digit| self.numerals.to_char(digit)) {
+                Some(native) => self.writer.write_char(native)?,
+                None => self.writer.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+//! Write large slices of numbers in parallel, on the `rayon` thread pool.
+
+#![cfg(all(feature = "write", feature = "std", feature = "rayon"))]
+
+use rayon::prelude::*;
+
+use crate::slice::write_slice_to_vec_impl;
+use crate::{buffer, ToLexical, ToLexicalWithOptions};
+
+/// Number of numbers written by each task when writing slices in parallel.
+const PARALLEL_CHUNK_SIZE: usize = 4096;
+
+/// Append numbers to a vector in parallel, separated by a delimiter.
+///
+/// The numbers are split into chunks, which are written concurrently on
+/// the `rayon` thread pool, and then appended in order, with delimiters
+/// between the chunks, so the output is the same as [`write_slice_to_vec`].
+/// This is designed for exporting large slices on multi-core machines,
+/// and slices with at most a few thousand numbers are written on the
+/// current thread, since they are faster to write than to schedule.
+///
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Bytes to write between each number.
+/// * `vec`         - Vector to append the numbers to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// let values: Vec<f64> = (0..100_000).map(|x| x as f64 / 8.0).collect();
+/// let mut bytes = Vec::new();
+/// lexical_core::write_slice_parallel(&values, b"\n", &mut bytes);
+///
+/// let mut expected = Vec::new();
+/// lexical_core::write_slice_to_vec(&values, b"\n", &mut expected);
+/// assert_eq!(bytes, expected);
+/// # }
+/// # }
+/// ```
+///
+/// [`write_slice_to_vec`]: crate::write_slice_to_vec
+#[inline]
+pub fn write_slice_parallel<N: ToLexical + Sync>(
+    values: &[N],
+    delimiter: &[u8],
+    vec: &mut Vec<u8>,
+) {
+    write_slice_parallel_impl(values, delimiter, vec, N::FORMATTED_SIZE, N::to_lexical)
+}
+
+/// Append numbers to a vector in parallel with custom options, separated
+/// by a delimiter.
+///
+/// The numbers are split into chunks, which are written concurrently on
+/// the `rayon` thread pool, and then appended in order, with delimiters
+/// between the chunks, so the output is the same as
+/// [`write_slice_to_vec_with_options`].
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Bytes to write between each number.
+/// * `vec`         - Vector to append the numbers to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteIntegerOptions::new();
+///
+/// let values: Vec<u64> = (0..100_000).collect();
+/// let mut bytes = Vec::new();
+/// lexical_core::write_slice_parallel_with_options::<_, FORMAT>(&values, b",", &mut bytes, &options);
+/// assert!(bytes.starts_with(b"0,1,2,3"));
+/// assert!(bytes.ends_with(b"99998,99999"));
+/// # }
+/// # }
+/// ```
+///
+/// [`write_slice_to_vec_with_options`]: crate::write_slice_to_vec_with_options
+#[inline]
+pub fn write_slice_parallel_with_options<N, const FORMAT: u128>(
+    values: &[N],
+    delimiter: &[u8],
+    vec: &mut Vec<u8>,
+    options: &N::Options,
+) where
+    N: ToLexicalWithOptions + Sync,
+    N::Options: Sync,
+{
+    let (size, write) = buffer::options_writer::<N, FORMAT>(options);
+    write_slice_parallel_impl(values, delimiter, vec, size, write)
+}
+
+/// Append numbers to a vector in parallel, where each number requires up
+/// to `size` bytes.
+fn write_slice_parallel_impl<N, F>(
+    values: &[N],
+    delimiter: &[u8],
+    vec: &mut Vec<u8>,
+    size: usize,
+    write: F,
+) where
+    N: Copy + Sync,
+    F: Fn(N, &mut [u8]) -> &mut [u8] + Sync,
+{
+    if values.len() <= PARALLEL_CHUNK_SIZE {
+        return write_slice_to_vec_impl(values, delimiter, vec, size, write);
+    }
+
+    let chunks: Vec<Vec<u8>> = values
+        .par_chunks(PARALLEL_CHUNK_SIZE)
+        .map(|chunk| {
+            let mut bytes = Vec::new();
+            write_slice_to_vec_impl(chunk, delimiter, &mut bytes, size, &write);
+            bytes
+        })
+        .collect();
+    let length: usize = chunks.iter().map(Vec::len).sum();
+    vec.reserve(length + delimiter.len() * (chunks.len() - 1));
+    for (i, chunk) in chunks.iter().enumerate() {
+        if i != 0 {
+            vec.extend_from_slice(delimiter);
+        }
+        vec.extend_from_slice(chunk);
+    }
+}
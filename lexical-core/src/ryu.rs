@@ -0,0 +1,113 @@
+//! An API mirroring the `ryu` crate.
+//!
+//! This provides a [`Buffer`] with the same methods as `ryu::Buffer`,
+//! backed by lexical, so call sites can be migrated by changing the
+//! import, and both crates can be benchmarked behind one interface. Like
+//! `ryu`, floats are written with the shortest representation that
+//! round-trips, and non-finite floats are written as `NaN`, `inf`, and
+//! `-inf`. The default options set by [`set_default_options`] are not
+//! used.
+//!
+//! The digits are the same as `ryu`, but lexical switches to scientific
+//! notation at different exponents, and always writes a fraction in
+//! scientific notation, such as `1.0e-7` rather than `1e-7`. Code that
+//! compares the strings, rather than the parsed floats, may need to be
+//! updated.
+//!
+//! # Example
+//!
+//! ```rust
+//! use lexical_core::ryu;
+//!
+//! let mut buffer = ryu::Buffer::new();
+//! assert_eq!(buffer.format(1.234f64), "1.234");
+//! assert_eq!(buffer.format(0.1f32), "0.1");
+//! assert_eq!(buffer.format(f64::NEG_INFINITY), "-inf");
+//! ```
+//!
+//! [`set_default_options`]: crate::set_default_options
+
+#![cfg(feature = "write-floats")]
+
+use core::mem::MaybeUninit;
+use core::str;
+
+use lexical_util::constants::FormattedSize;
+use lexical_write_float::ToLexical;
+
+use crate::uninit_as_bytes;
+
+/// The number of bytes needed to write any float.
+const BUFFER_SIZE: usize = f64::FORMATTED_SIZE_DECIMAL;
+
+/// A buffer to write floats to, mirroring `ryu::Buffer`.
+///
+/// The buffer is uninitialized, and large enough to write any float
+/// without bounds checks.
+#[derive(Clone, Copy)]
+pub struct Buffer {
+    bytes: [MaybeUninit<u8>; BUFFER_SIZE],
+}
+
+impl Buffer {
+    /// Create a new buffer.
+    ///
+    /// This is cheap, since the buffer is not initialized.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            bytes: [MaybeUninit::uninit(); BUFFER_SIZE],
+        }
+    }
+
+    /// Write a float to the buffer, and get the written string.
+    ///
+    /// Non-finite floats are written as `NaN`, `inf`, and `-inf`.
+    #[inline]
+    pub fn format<F: Float>(&mut self, f: F) -> &str {
+        // SAFETY: safe since the writers never read uninitialized memory,
+        // and only the written prefix is returned.
+        let bytes = f.to_lexical(unsafe { uninit_as_bytes(&mut self.bytes) });
+        // SAFETY: safe since all the numerical strings we write are ASCII.
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+
+    /// Write a finite float to the buffer, and get the written string.
+    ///
+    /// With `ryu`, the string is unspecified for non-finite floats, so
+    /// this is the same as [`format`], which writes them as `NaN`, `inf`,
+    /// and `-inf`.
+    ///
+    /// [`format`]: Self::format
+    #[inline]
+    pub fn format_finite<F: Float>(&mut self, f: F) -> &str {
+        self.format(f)
+    }
+}
+
+impl Default for Buffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A float that can be written to a [`Buffer`], mirroring `ryu::Float`.
+///
+/// This is implemented for `f32` and `f64`, and cannot be implemented
+/// outside of lexical.
+pub trait Float: private::Sealed {}
+
+mod private {
+    /// Prevent `Float` from being implemented outside of lexical.
+    pub trait Sealed: super::ToLexical {}
+}
+
+macro_rules! float_impl {
+    ($($t:ident)*) => ($(
+        impl private::Sealed for $t {}
+        impl Float for $t {}
+    )*);
+}
+
+float_impl! { f32 f64 }
@@ -0,0 +1,283 @@
+//! Write slices of numbers, separated by a delimiter.
+
+#![cfg(feature = "write")]
+
+#[cfg(feature = "std")]
+use core::mem::MaybeUninit;
+#[cfg(feature = "std")]
+use core::slice;
+
+#[cfg(feature = "std")]
+use crate::uninit_as_bytes;
+use crate::{buffer, Error, Result, ToLexical, ToLexicalWithOptions};
+
+/// Write numbers to a buffer, separated by a delimiter, returning an
+/// error if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. This
+/// formats many numbers in one call, such as the fields of a CSV row,
+/// writing each number directly into the buffer when it has enough room.
+/// This never panics due to an insufficient buffer: [`Error::BufferTooSmall`]
+/// is returned instead, with the number of bytes required to write all
+/// the numbers, and the buffer may be partially written.
+///
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Bytes to write between each number.
+/// * `bytes`       - Buffer to write the numbers to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::Error;
+///
+/// let mut buffer = [0u8; 16];
+/// let values = [1.5f64, -2.0, 1e300];
+/// assert_eq!(lexical_core::write_slice(&values, b",", &mut buffer).unwrap(), b"1.5,-2.0,1.0e300");
+///
+/// let mut buffer = [0u8; 8];
+/// assert_eq!(lexical_core::write_slice(&values, b", ", &mut buffer), Err(Error::BufferTooSmall {
+///     needed: 18
+/// }));
+/// # }
+/// # }
+/// ```
+///
+/// [`Error::BufferTooSmall`]: crate::Error::BufferTooSmall
+#[inline]
+pub fn write_slice<'a, N: ToLexical>(
+    values: &[N],
+    delimiter: &[u8],
+    bytes: &'a mut [u8],
+) -> Result<&'a mut [u8]> {
+    write_slice_impl(values, delimiter, bytes, N::FORMATTED_SIZE, N::to_lexical)
+}
+
+/// Write numbers to a buffer with custom options, separated by a
+/// delimiter, returning an error if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// buffer size required by the options is only calculated once for all
+/// the numbers. If the options may require more than [`BUFFER_SIZE`]
+/// bytes, such as with custom digit precision control or exponent break
+/// points for floats, the number of bytes required for each number in
+/// [`Error::BufferTooSmall`] is the upper bound from
+/// [`WriteOptions::buffer_size`].
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Bytes to write between each number.
+/// * `bytes`       - Buffer to write the numbers to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+///
+/// let mut buffer = [0u8; 16];
+/// let values = [1.0f64, 2.5, 3.0];
+/// let result = lexical_core::write_slice_with_options::<_, FORMAT>(&values, b"\t", &mut buffer, &options);
+/// assert_eq!(result.unwrap(), b"1\t2.5\t3");
+/// # }
+/// # }
+/// ```
+///
+/// [`BUFFER_SIZE`]: crate::BUFFER_SIZE
+/// [`Error::BufferTooSmall`]: crate::Error::BufferTooSmall
+/// [`WriteOptions::buffer_size`]: crate::WriteOptions::buffer_size
+#[inline]
+pub fn write_slice_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    values: &[N],
+    delimiter: &[u8],
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> Result<&'a mut [u8]> {
+    let (size, write) = buffer::options_writer::<N, FORMAT>(options);
+    write_slice_impl(values, delimiter, bytes, size, write)
+}
+
+/// Write numbers to a buffer, where each number requires up to `size` bytes.
+fn write_slice_impl<'a, N, F>(
+    values: &[N],
+    delimiter: &[u8],
+    bytes: &'a mut [u8],
+    size: usize,
+    write: F,
+) -> Result<&'a mut [u8]>
+where
+    N: Copy,
+    F: Fn(N, &mut [u8]) -> &mut [u8],
+{
+    let mut index = 0;
+    for (i, &value) in values.iter().enumerate() {
+        let start = index;
+        if i != 0 {
+            match bytes.get_mut(index..index + delimiter.len()) {
+                Some(dst) => dst.copy_from_slice(delimiter),
+                None => return Err(slice_too_small(values, delimiter, i, start, size, &write)),
+            }
+            index += delimiter.len();
+        }
+        let dst = &mut bytes[index..];
+        if dst.len() >= size {
+            index += write(value, dst).len();
+        } else {
+            match buffer::with_stack_buffer(value, size, &write, |src| {
+                buffer::copy_checked(src, dst)
+            }) {
+                Some(Ok(dst)) => index += dst.len(),
+                _ => return Err(slice_too_small(values, delimiter, i, start, size, &write)),
+            }
+        }
+    }
+    Ok(&mut bytes[..index])
+}
+
+/// Get the error for a buffer too small to write the numbers.
+///
+/// The numbers before `index` were written to the first `written` bytes,
+/// and the rest are written to a stack buffer to count the bytes required,
+/// or assumed to require `size` bytes if it is larger than the stack buffer.
+#[cold]
+fn slice_too_small<N, F>(
+    values: &[N],
+    delimiter: &[u8],
+    index: usize,
+    written: usize,
+    size: usize,
+    write: &F,
+) -> Error
+where
+    N: Copy,
+    F: Fn(N, &mut [u8]) -> &mut [u8],
+{
+    let mut needed = written;
+    for (i, &value) in values.iter().enumerate().skip(index) {
+        if i != 0 {
+            needed += delimiter.len();
+        }
+        needed +=
+            buffer::with_stack_buffer(value, size, write, |bytes| bytes.len()).unwrap_or(size);
+    }
+    Error::BufferTooSmall {
+        needed,
+    }
+}
+
+/// Append numbers to a vector, separated by a delimiter.
+///
+/// Each number is written directly into the spare capacity of the
+/// vector, which grows as needed, without an intermediate buffer. This
+/// formats many numbers in one call, such as the fields of a CSV row.
+///
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Bytes to write between each number.
+/// * `vec`         - Vector to append the numbers to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut bytes = b"values=".to_vec();
+/// lexical_core::write_slice_to_vec(&[1u32, 22, 333], b",", &mut bytes);
+/// assert_eq!(bytes, b"values=1,22,333");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_slice_to_vec<N: ToLexical>(values: &[N], delimiter: &[u8], vec: &mut Vec<u8>) {
+    write_slice_to_vec_impl(values, delimiter, vec, N::FORMATTED_SIZE, N::to_lexical)
+}
+
+/// Append numbers to a vector with custom options, separated by a delimiter.
+///
+/// Each number is written directly into the spare capacity of the
+/// vector, which grows as needed, without an intermediate buffer. The
+/// buffer size required by the options is only calculated once for all
+/// the numbers.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Bytes to write between each number.
+/// * `vec`         - Vector to append the numbers to.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteIntegerOptions::new();
+///
+/// let mut bytes = Vec::new();
+/// lexical_core::write_slice_to_vec_with_options::<_, FORMAT>(&[-1i8, 2], b"\n", &mut bytes, &options);
+/// assert_eq!(bytes, b"-1\n2");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_slice_to_vec_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    values: &[N],
+    delimiter: &[u8],
+    vec: &mut Vec<u8>,
+    options: &N::Options,
+) {
+    let (size, write) = buffer::options_writer::<N, FORMAT>(options);
+    write_slice_to_vec_impl(values, delimiter, vec, size, write)
+}
+
+/// Append numbers to a vector, where each number requires up to `size` bytes.
+#[cfg(feature = "std")]
+pub(crate) fn write_slice_to_vec_impl<N, F>(
+    values: &[N],
+    delimiter: &[u8],
+    vec: &mut Vec<u8>,
+    size: usize,
+    write: F,
+) where
+    N: Copy,
+    F: Fn(N, &mut [u8]) -> &mut [u8],
+{
+    for (i, &value) in values.iter().enumerate() {
+        if i != 0 {
+            vec.extend_from_slice(delimiter);
+        }
+        vec.reserve(size);
+        let length = vec.len();
+        // SAFETY: safe since the spare capacity is at least `size` bytes,
+        // the writers never read uninitialized memory, and only the
+        // written bytes are added to the length of the vector.
+        unsafe {
+            let ptr = vec.as_mut_ptr().add(length) as *mut MaybeUninit<u8>;
+            let spare = slice::from_raw_parts_mut(ptr, vec.capacity() - length);
+            let count = write(value, uninit_as_bytes(spare)).len();
+            vec.set_len(length + count);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Parse numbers into the smallest type that represents them exactly.
+//!
+//! This is designed for type inference of columnar data, such as CSV
+//! files, where each value is parsed into the narrowest of `u8`, `u16`,
+//! `u32`, `u64`, `i64`, `f32`, or `f64` that represents it exactly,
+//! and the reasons a wider type was needed are reported alongside it.
+//! The widest type needed by any value in a column is then the type
+//! of the column.
+//!
+//! # Example
+//!
+//! ```rust
+//! use lexical_core::smallest::{self, Smallest};
+//!
+//! let (value, widening) = smallest::parse(b"300").unwrap();
+//! assert_eq!(value, Smallest::U16(300));
+//! assert!(widening.magnitude);
+//!
+//! let (value, widening) = smallest::parse(b"-1.5").unwrap();
+//! assert_eq!(value, Smallest::F32(-1.5));
+//! assert!(widening.not_integer);
+//!
+//! let (value, widening) = smallest::parse(b"0.1").unwrap();
+//! assert_eq!(value, Smallest::F64(0.1));
+//! assert!(widening.precision && widening.inexact);
+//! ```
+
+#![cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+
+use core::convert::TryFrom;
+
+use crate::format::STANDARD;
+use crate::{
+    representation_with_options,
+    Error,
+    FromLexicalWithOptions,
+    ParseFloatOptions,
+    ParseIntegerOptions,
+    Representation,
+    Result,
+};
+
+/// A number parsed into the smallest type that represents it exactly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Smallest {
+    /// A non-negative integer that fits in a `u8`.
+    U8(u8),
+    /// A non-negative integer that fits in a `u16`.
+    U16(u16),
+    /// A non-negative integer that fits in a `u32`.
+    U32(u32),
+    /// A non-negative integer that fits in a `u64`.
+    U64(u64),
+    /// A negative integer that fits in an `i64`.
+    I64(i64),
+    /// A float that is exactly representable as an `f32`.
+    F32(f32),
+    /// Any other float, rounded to the nearest `f64`.
+    F64(f64),
+}
+
+/// The reasons a number needed a wider type than `u8`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Widening {
+    /// The integer is too large for a narrower type, or for any integer type.
+    pub magnitude: bool,
+    /// The integer has a negative sign, so requires a signed type.
+    pub negative: bool,
+    /// The number is not written as an integer, so requires a float.
+    pub not_integer: bool,
+    /// The float is not exactly representable as an `f32`.
+    pub precision: bool,
+    /// The float is not exactly representable as an `f64` either, and is rounded.
+    pub inexact: bool,
+}
+
+/// Parse a number into the smallest type that represents it exactly.
+///
+/// This uses the standard number format and the default options, and
+/// returns the error from parsing the number as a float if it is not a
+/// valid integer or float.
+#[inline]
+pub fn parse(bytes: &[u8]) -> Result<(Smallest, Widening)> {
+    parse_with_options::<STANDARD>(bytes, &ParseIntegerOptions::new(), &ParseFloatOptions::new())
+}
+
+/// Parse a number into the smallest type that represents it exactly, using custom options.
+///
+/// This is the same as [`parse`], for a custom number format. Floats
+/// are only supported for radixes with a finite number of digits in
+/// binary, other than powers-of-two, as with [`representation`].
+///
+/// [`representation`]: crate::representation
+pub fn parse_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    integer_options: &ParseIntegerOptions,
+    float_options: &ParseFloatOptions,
+) -> Result<(Smallest, Widening)> {
+    let mut widening = Widening::default();
+    match u64::from_lexical_with_options::<FORMAT>(bytes, integer_options) {
+        Ok(value) => {
+            widening.magnitude = value > u8::MAX as u64;
+            let smallest = if let Ok(value) = u8::try_from(value) {
+                Smallest::U8(value)
+            } else if let Ok(value) = u16::try_from(value) {
+                Smallest::U16(value)
+            } else if let Ok(value) = u32::try_from(value) {
+                Smallest::U32(value)
+            } else {
+                Smallest::U64(value)
+            };
+            return Ok((smallest, widening));
+        },
+        Err(Error::Overflow(_)) => widening.magnitude = true,
+        Err(_) => (),
+    }
+
+    if !widening.magnitude {
+        match i64::from_lexical_with_options::<FORMAT>(bytes, integer_options) {
+            Ok(value) => {
+                widening.negative = true;
+                return Ok((Smallest::I64(value), widening));
+            },
+            Err(Error::Underflow(_)) => {
+                widening.negative = true;
+                widening.magnitude = true;
+            },
+            Err(_) => widening.not_integer = true,
+        }
+    }
+
+    let value = f64::from_lexical_with_options::<FORMAT>(bytes, float_options)?;
+    let exact = Representation::Exact;
+    if representation_with_options::<f32, FORMAT>(bytes, float_options)? == exact {
+        return Ok((Smallest::F32(value as f32), widening));
+    }
+    widening.precision = true;
+    widening.inexact = representation_with_options::<f64, FORMAT>(bytes, float_options)? != exact;
+    Ok((Smallest::F64(value), widening))
+}
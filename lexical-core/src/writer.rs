@@ -0,0 +1,185 @@
+//! Write numbers to [`fmt::Write`] and [`io::Write`] sinks.
+//!
+//! [`fmt::Write`]: core::fmt::Write
+//! [`io::Write`]: std::io::Write
+
+#![cfg(feature = "write")]
+
+use core::{fmt, str};
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::{buffer, ToLexical, ToLexicalWithOptions, BUFFER_SIZE};
+
+/// Write number to a [`fmt::Write`] sink.
+///
+/// The number is serialized to an intermediate stack buffer of
+/// [`BUFFER_SIZE`] bytes, which is then written to the sink, so callers
+/// do not need to manage the buffer themselves.
+///
+/// * `value`   - Number to serialize.
+/// * `writer`  - Sink to write the number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use core::fmt::Write;
+///
+/// let mut string = String::from("pi=");
+/// lexical_core::write_to(3.14159265359_f32, &mut string).unwrap();
+/// assert_eq!(string, "pi=3.1415927");
+/// # }
+/// # }
+/// ```
+///
+/// [`fmt::Write`]: core::fmt::Write
+#[inline]
+pub fn write_to<N: ToLexical, W: fmt::Write + ?Sized>(n: N, writer: &mut W) -> fmt::Result {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = n.to_lexical(&mut buffer);
+    // SAFETY: safe since all the numerical strings we write are ASCII.
+    writer.write_str(unsafe { str::from_utf8_unchecked(bytes) })
+}
+
+/// Write number to a [`fmt::Write`] sink with custom options.
+///
+/// The number is serialized to an intermediate stack buffer of
+/// [`BUFFER_SIZE`] bytes, which is then written to the sink. If the
+/// options may require a larger buffer, as with custom digit precision
+/// control or exponent break points for floats, a heap buffer is used
+/// instead when the `std` feature is enabled, or [`fmt::Error`] is
+/// returned otherwise.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `writer`  - Sink to write the number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+///
+/// let mut string = String::new();
+/// lexical_core::write_to_with_options::<_, _, FORMAT>(1.0f64, &mut string, &options).unwrap();
+/// assert_eq!(string, "1");
+/// # }
+/// # }
+/// ```
+///
+/// [`fmt::Write`]: core::fmt::Write
+/// [`fmt::Error`]: core::fmt::Error
+#[inline]
+pub fn write_to_with_options<N, W, const FORMAT: u128>(
+    n: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> fmt::Result
+where
+    N: ToLexicalWithOptions,
+    W: fmt::Write + ?Sized,
+{
+    let (size, write) = buffer::options_writer::<N, FORMAT>(options);
+    buffer::with_buffer(n, size, write, |bytes| {
+        // SAFETY: safe since all the numerical strings we write are ASCII.
+        writer.write_str(unsafe { str::from_utf8_unchecked(bytes) })
+    })
+    .unwrap_or(Err(fmt::Error))
+}
+
+/// Write number to an [`io::Write`] sink.
+///
+/// The number is serialized to an intermediate stack buffer of
+/// [`BUFFER_SIZE`] bytes, which is then written to the sink, so callers
+/// do not need to manage the buffer themselves.
+///
+/// * `value`   - Number to serialize.
+/// * `writer`  - Sink to write the number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut bytes = Vec::new();
+/// lexical_core::write_io(12345u32, &mut bytes).unwrap();
+/// assert_eq!(bytes, b"12345");
+/// # }
+/// # }
+/// ```
+///
+/// [`io::Write`]: std::io::Write
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_io<N: ToLexical, W: io::Write + ?Sized>(n: N, writer: &mut W) -> io::Result<()> {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    writer.write_all(n.to_lexical(&mut buffer))
+}
+
+/// Write number to an [`io::Write`] sink with custom options.
+///
+/// The number is serialized to an intermediate stack buffer of
+/// [`BUFFER_SIZE`] bytes, which is then written to the sink. If the
+/// options may require a larger buffer, as with custom digit precision
+/// control or exponent break points for floats, a heap buffer is used
+/// instead.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `writer`  - Sink to write the number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+///
+/// let mut bytes = Vec::new();
+/// lexical_core::write_io_with_options::<_, _, FORMAT>(1.0f64, &mut bytes, &options).unwrap();
+/// assert_eq!(bytes, b"1");
+/// # }
+/// # }
+/// ```
+///
+/// [`io::Write`]: std::io::Write
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_io_with_options<N, W, const FORMAT: u128>(
+    n: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> io::Result<()>
+where
+    N: ToLexicalWithOptions,
+    W: io::Write + ?Sized,
+{
+    let (size, write) = buffer::options_writer::<N, FORMAT>(options);
+    buffer::with_buffer(n, size, write, |bytes| writer.write_all(bytes))
+        .unwrap_or_else(|| Err(io::ErrorKind::OutOfMemory.into()))
+}
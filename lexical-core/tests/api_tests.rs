@@ -24,6 +24,300 @@ fn float_to_string_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_checked_test() {
+    use lexical_core::Error;
+
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::write_checked(12345u32, &mut buffer).unwrap(), b"12345");
+    assert_eq!(lexical_core::write_checked(-12345i32, &mut buffer[..6]).unwrap(), b"-12345");
+    assert_eq!(
+        lexical_core::write_checked(-12345i32, &mut buffer[..5]),
+        Err(Error::BufferTooSmall {
+            needed: 6
+        })
+    );
+    assert_eq!(
+        lexical_core::write_checked(0u8, &mut []),
+        Err(Error::BufferTooSmall {
+            needed: 1
+        })
+    );
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result =
+        lexical_core::write_with_options_checked::<_, FORMAT>(7u64, &mut buffer[..1], &options);
+    assert_eq!(result.unwrap(), b"7");
+    let result =
+        lexical_core::write_with_options_checked::<_, FORMAT>(77u64, &mut buffer[..1], &options);
+    assert_eq!(
+        result,
+        Err(Error::BufferTooSmall {
+            needed: 2
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_checked_test() {
+    use lexical_core::Error;
+
+    let mut buffer = [b'0'; 512];
+    assert_eq!(lexical_core::write_checked(12345.0f32, &mut buffer).unwrap(), b"12345.0");
+    assert_eq!(lexical_core::write_checked(1.5f64, &mut buffer[..3]).unwrap(), b"1.5");
+    assert_eq!(
+        lexical_core::write_checked(1.25f64, &mut buffer[..3]),
+        Err(Error::BufferTooSmall {
+            needed: 4
+        })
+    );
+
+    // Large exponent break points require more than `BUFFER_SIZE` bytes.
+    let options = lexical_core::WriteFloatOptions::builder()
+        .positive_exponent_break(core::num::NonZeroI32::new(400))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result =
+        lexical_core::write_with_options_checked::<_, FORMAT>(1e300f64, &mut buffer, &options);
+    assert_eq!(result.unwrap(), format!("1{}.0", "0".repeat(300)).as_bytes());
+    let result =
+        lexical_core::write_with_options_checked::<_, FORMAT>(1.5f64, &mut buffer[..64], &options);
+    assert!(matches!(result, Err(Error::BufferTooSmall { needed }) if needed > 64));
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_uninit_test() {
+    let mut buffer = [core::mem::MaybeUninit::<u8>::uninit(); lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::write_uninit(12345u32, &mut buffer), b"12345");
+    assert_eq!(
+        lexical_core::write_uninit(i128::MIN, &mut buffer),
+        i128::MIN.to_string().as_bytes()
+    );
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::write_with_options_uninit::<_, FORMAT>(12345u32, &mut buffer, &options),
+        b"12345"
+    );
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_uninit_test() {
+    let mut buffer = [core::mem::MaybeUninit::<u8>::uninit(); lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::write_uninit(12345.0f32, &mut buffer), b"12345.0");
+    assert_eq!(lexical_core::write_uninit(1.2345e-300f64, &mut buffer), b"1.2345e-300");
+    let options = lexical_core::WriteFloatOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::write_with_options_uninit::<_, FORMAT>(12345.0f32, &mut buffer, &options),
+        b"12345.0"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-integers"))]
+fn integer_write_to_test() {
+    let mut string = String::from("x=");
+    lexical_core::write_to(-12345i32, &mut string).unwrap();
+    assert_eq!(string, "x=-12345");
+
+    let mut bytes = Vec::new();
+    lexical_core::write_io(u128::MAX, &mut bytes).unwrap();
+    assert_eq!(bytes, u128::MAX.to_string().as_bytes());
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut string = String::new();
+    lexical_core::write_to_with_options::<_, _, FORMAT>(12345u32, &mut string, &options).unwrap();
+    assert_eq!(string, "12345");
+    let mut bytes = Vec::new();
+    lexical_core::write_io_with_options::<_, _, FORMAT>(12345u32, &mut bytes, &options).unwrap();
+    assert_eq!(bytes, b"12345");
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-floats"))]
+fn float_write_to_test() {
+    let mut string = String::new();
+    lexical_core::write_to(12345.0f32, &mut string).unwrap();
+    assert_eq!(string, "12345.0");
+
+    let mut bytes = Vec::new();
+    lexical_core::write_io(12345.0f64, &mut bytes).unwrap();
+    assert_eq!(bytes, b"12345.0");
+
+    // Large exponent break points require more than `BUFFER_SIZE` bytes.
+    let options = lexical_core::WriteFloatOptions::builder()
+        .positive_exponent_break(core::num::NonZeroI32::new(400))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let expected = format!("1{}.0", "0".repeat(300));
+    let mut string = String::new();
+    lexical_core::write_to_with_options::<_, _, FORMAT>(1e300f64, &mut string, &options).unwrap();
+    assert_eq!(string, expected);
+    let mut bytes = Vec::new();
+    lexical_core::write_io_with_options::<_, _, FORMAT>(1e300f64, &mut bytes, &options).unwrap();
+    assert_eq!(bytes, expected.as_bytes());
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_slice_test() {
+    use lexical_core::Error;
+
+    let mut buffer = [0u8; 32];
+    let values = [0u32, 12345, u32::MAX];
+    assert_eq!(
+        lexical_core::write_slice(&values, b",", &mut buffer).unwrap(),
+        b"0,12345,4294967295"
+    );
+    assert_eq!(lexical_core::write_slice::<u32>(&[], b",", &mut buffer).unwrap(), b"");
+    assert_eq!(lexical_core::write_slice(&[-1i8], b",", &mut buffer).unwrap(), b"-1");
+    assert_eq!(lexical_core::write_slice(&values, b"", &mut buffer).unwrap(), b"0123454294967295");
+
+    // The buffer is too small for a number or a delimiter.
+    let needed = Err(Error::BufferTooSmall {
+        needed: 20,
+    });
+    assert_eq!(lexical_core::write_slice(&values, b", ", &mut buffer[..19]), needed);
+    assert_eq!(lexical_core::write_slice(&values, b", ", &mut buffer[..9]), needed);
+    assert_eq!(lexical_core::write_slice(&values, b", ", &mut buffer[..8]), needed);
+    assert_eq!(lexical_core::write_slice(&values, b", ", &mut buffer[..0]), needed);
+    assert_eq!(lexical_core::write_slice(&values, b", ", &mut buffer[..20]).unwrap().len(), 20);
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::write_slice_with_options::<_, FORMAT>(&values, b";", &mut buffer, &options)
+            .unwrap(),
+        b"0;12345;4294967295"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-integers"))]
+fn integer_write_slice_to_vec_test() {
+    let mut bytes = b"x=".to_vec();
+    let values: Vec<i64> = (-500..500).map(|x| x * 1_000_000_007).collect();
+    lexical_core::write_slice_to_vec(&values, b"\n", &mut bytes);
+    let expected = values.iter().map(|x| x.to_string()).collect::<Vec<_>>().join("\n");
+    assert_eq!(bytes, format!("x={}", expected).as_bytes());
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut bytes = Vec::new();
+    lexical_core::write_slice_to_vec_with_options::<_, FORMAT>(
+        &[1u8, 2],
+        b", ",
+        &mut bytes,
+        &options,
+    );
+    assert_eq!(bytes, b"1, 2");
+    lexical_core::write_slice_to_vec_with_options::<u8, FORMAT>(&[], b", ", &mut bytes, &options);
+    assert_eq!(bytes, b"1, 2");
+}
+
+#[test]
+#[cfg(all(feature = "rayon", feature = "write-integers"))]
+fn integer_write_slice_parallel_test() {
+    // Cover a partial final chunk, and slices written on the current thread.
+    for &count in &[0i64, 1, 4096, 4097, 50_000] {
+        let values: Vec<i64> = (0..count).map(|x| (x - count / 2) * 1_000_000_007).collect();
+        let mut bytes = b"x=".to_vec();
+        lexical_core::write_slice_parallel(&values, b", ", &mut bytes);
+        let mut expected = b"x=".to_vec();
+        lexical_core::write_slice_to_vec(&values, b", ", &mut expected);
+        assert_eq!(bytes, expected);
+    }
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let values: Vec<u16> = (0..=u16::MAX).collect();
+    let mut bytes = Vec::new();
+    lexical_core::write_slice_parallel_with_options::<_, FORMAT>(
+        &values,
+        b"",
+        &mut bytes,
+        &options,
+    );
+    let expected = values.iter().map(|x| x.to_string()).collect::<String>();
+    assert_eq!(bytes, expected.as_bytes());
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_slice_test() {
+    use lexical_core::Error;
+
+    let mut buffer = [0u8; 32];
+    let values = [1.5f64, -2.0, 1e300];
+    assert_eq!(lexical_core::write_slice(&values, b",", &mut buffer).unwrap(), b"1.5,-2.0,1.0e300");
+    assert_eq!(
+        lexical_core::write_slice(&values, b",", &mut buffer[..10]),
+        Err(Error::BufferTooSmall {
+            needed: 16
+        })
+    );
+
+    // Large exponent break points require more than `BUFFER_SIZE` bytes.
+    let options = lexical_core::WriteFloatOptions::builder()
+        .positive_exponent_break(core::num::NonZeroI32::new(400))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let size = lexical_core::WriteOptions::buffer_size::<f64, FORMAT>(&options);
+    assert!(size > lexical_core::BUFFER_SIZE);
+    assert_eq!(
+        lexical_core::write_slice_with_options::<_, FORMAT>(
+            &[1.0f64, 2.0],
+            b",",
+            &mut buffer,
+            &options
+        ),
+        Err(Error::BufferTooSmall {
+            needed: 2 * size + 1
+        })
+    );
+    let mut buffer = vec![0u8; 2 * size + 1];
+    let bytes = lexical_core::write_slice_with_options::<_, FORMAT>(
+        &[1e300f64, 2.0],
+        b",",
+        &mut buffer,
+        &options,
+    );
+    assert_eq!(bytes.unwrap(), format!("1{}.0,2.0", "0".repeat(300)).as_bytes());
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-floats"))]
+fn float_write_slice_to_vec_test() {
+    let mut bytes = Vec::new();
+    lexical_core::write_slice_to_vec(&[1.5f32, f32::MAX], b" ", &mut bytes);
+    assert_eq!(bytes, b"1.5 3.4028235e38");
+
+    let options = lexical_core::WriteFloatOptions::builder()
+        .positive_exponent_break(core::num::NonZeroI32::new(400))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut bytes = Vec::new();
+    lexical_core::write_slice_to_vec_with_options::<_, FORMAT>(
+        &[1e300f64, 1e300],
+        b",",
+        &mut bytes,
+        &options,
+    );
+    let expected = format!("1{}.0", "0".repeat(300));
+    assert_eq!(bytes, format!("{},{}", expected, expected).as_bytes());
+}
+
 #[test]
 #[cfg(feature = "parse-integers")]
 fn string_to_integer_test() {
@@ -53,3 +347,213 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn integer_parse_recover_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_recover::<u8>(b"255"), Ok(255));
+
+    let error = lexical_core::parse_recover::<u8>(b"2567").unwrap_err();
+    assert!(error.error().is_overflow());
+    assert_eq!(error.value(), Some(&25));
+    assert_eq!(error.count(), 2);
+
+    let error = lexical_core::parse_recover::<i8>(b"-1290").unwrap_err();
+    assert!(error.error().is_underflow());
+    assert_eq!(error.value(), Some(&-12));
+    assert_eq!(error.count(), 3);
+
+    let error = lexical_core::parse_recover::<u8>(b"12x").unwrap_err();
+    assert_eq!(error.error(), Error::InvalidDigit(2));
+    assert_eq!(error.value(), Some(&12));
+    assert_eq!(error.count(), 2);
+
+    let error = lexical_core::parse_recover::<u64>(b"123456789012345678901234").unwrap_err();
+    assert_eq!(error.into_value(), Some(12345678901234567890));
+
+    let error = lexical_core::parse_recover::<u8>(b"x").unwrap_err();
+    assert_eq!(error.error(), Error::InvalidDigit(0));
+    assert_eq!(error.value(), None);
+    assert_eq!(error.count(), 0);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_parse_recover_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_recover::<f64>(b"1.5"), Ok(1.5));
+
+    let error = lexical_core::parse_recover::<f64>(b"1.5x").unwrap_err();
+    assert_eq!(error.error(), Error::InvalidDigit(3));
+    assert_eq!(error.value(), Some(&1.5));
+    assert_eq!(error.count(), 3);
+
+    let options = lexical_core::ParseFloatOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let error = lexical_core::parse_recover_with_options::<f32, FORMAT>(b"", &options);
+    assert_eq!(error.unwrap_err().value(), None);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "write-integers", feature = "parse-integers"))]
+fn integer_digit_separator_roundtrip_test() {
+    use core::num::{NonZeroU8, NonZeroUsize};
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let write_options = lexical_core::WriteIntegerOptions::builder()
+        .digit_grouping(NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    let parse_options = lexical_core::ParseIntegerOptions::new();
+    for &value in [0i64, 1, -999, 1000, -1234567, i64::MIN, i64::MAX].iter() {
+        let bytes =
+            lexical_core::write_with_options::<_, FORMAT>(value, &mut buffer, &write_options);
+        assert_eq!(
+            lexical_core::parse_with_options::<i64, FORMAT>(bytes, &parse_options),
+            Ok(value)
+        );
+    }
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+fn float_override_test() {
+    use core::num::NonZeroUsize;
+    use lexical_core::{Error, BUFFER_SIZE};
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let options = lexical_core::WriteFloatOptions::new();
+    let overrides =
+        lexical_core::WriteFloatOptionsOverride::new().max_significant_digits(NonZeroUsize::new(2));
+    let result = lexical_core::write_with_override::<_, FORMAT>(
+        1.2345f64,
+        &mut buffer,
+        &options,
+        &overrides,
+    );
+    assert_eq!(result.unwrap(), b"1.2");
+    let overrides = overrides.min_significant_digits(NonZeroUsize::new(3));
+    let result = lexical_core::write_with_override::<_, FORMAT>(
+        1.2345f64,
+        &mut buffer,
+        &options,
+        &overrides,
+    );
+    assert_eq!(result, Err(Error::InvalidFloatPrecision));
+
+    let options = lexical_core::ParseFloatOptions::new();
+    let overrides = lexical_core::ParseFloatOptionsOverride::new().exponent(b'^');
+    let result = lexical_core::parse_with_override::<f64, FORMAT>(b"1.5^2", &options, &overrides);
+    assert_eq!(result, Ok(150.0));
+    let result =
+        lexical_core::parse_partial_with_override::<f64, FORMAT>(b"1.5e2", &options, &overrides);
+    assert_eq!(result, Ok((1.5, 3)));
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "parse-integers"))]
+fn integer_override_test() {
+    use core::num::NonZeroUsize;
+    use lexical_core::BUFFER_SIZE;
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let options = lexical_core::WriteIntegerOptions::new();
+    let overrides =
+        lexical_core::WriteIntegerOptionsOverride::new().min_digits(NonZeroUsize::new(4));
+    let result =
+        lexical_core::write_with_override::<_, FORMAT>(12u32, &mut buffer, &options, &overrides);
+    assert_eq!(result.unwrap(), b"0012");
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    let overrides = lexical_core::ParseIntegerOptionsOverride::new().parentheses_negative(true);
+    let result = lexical_core::parse_with_override::<i32, FORMAT>(b"(12)", &options, &overrides);
+    assert_eq!(result, Ok(-12));
+}
+
+#[test]
+#[cfg(all(feature = "heapless", feature = "write-integers"))]
+fn integer_write_heapless_test() {
+    let mut string = heapless::String::<24>::new();
+    lexical_core::write_heapless(12345u32, &mut string).unwrap();
+    lexical_core::write_heapless(-12345i64, &mut string).unwrap();
+    assert_eq!(string, "12345-12345");
+    // Not enough remaining capacity: the string is unchanged.
+    assert_eq!(lexical_core::write_heapless(u64::MAX, &mut string), Err(()));
+    assert_eq!(string, "12345-12345");
+}
+
+#[test]
+#[cfg(all(feature = "arrayvec", feature = "write-floats"))]
+fn float_write_array_string_test() {
+    let mut string = arrayvec::ArrayString::<72>::new();
+    lexical_core::write_array_string(1.5f64, &mut string).unwrap();
+    assert_eq!(string.as_str(), "1.5");
+    string.push_str(&"0".repeat(68));
+    // Not enough remaining capacity: the string is unchanged.
+    assert!(lexical_core::write_array_string(1.5f32, &mut string).is_err());
+    assert_eq!(string.len(), 71);
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn float_reformat_test() {
+    use core::num::NonZeroUsize;
+    use lexical_core::Error;
+
+    let parse_options = lexical_core::ParseFloatOptions::new();
+    let write_options = lexical_core::WriteFloatOptions::new();
+    let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+    let mut reformat = |bytes: &[u8], options| {
+        lexical_core::reformat::<f64>(bytes, &parse_options, options, &mut buffer)
+            .map(|x| x.to_vec())
+    };
+    assert_eq!(reformat(b"1.50000", &write_options), Ok(b"1.5".to_vec()));
+    assert_eq!(reformat(b"15e-1", &write_options), Ok(b"1.5".to_vec()));
+    assert_eq!(reformat(b"1.5x", &write_options), Err(Error::InvalidDigit(3)));
+
+    let write_options = lexical_core::WriteFloatOptions::builder()
+        .max_significant_digits(NonZeroUsize::new(2))
+        .build()
+        .unwrap();
+    assert_eq!(reformat(b"3.14159", &write_options), Ok(b"3.1".to_vec()));
+
+    let mut buffer = [0u8; 2];
+    let result = lexical_core::reformat::<f64>(b"1.5", &parse_options, &write_options, &mut buffer);
+    assert_eq!(
+        result,
+        Err(Error::BufferTooSmall {
+            needed: 3
+        })
+    );
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "parse-integers", feature = "write-integers"))]
+fn integer_reformat_test() {
+    use core::num::NonZeroU8;
+
+    const PARSE_FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    const WRITE_FORMAT: u128 = lexical_core::format::STANDARD;
+    let parse_options = lexical_core::ParseIntegerOptions::new();
+    let write_options = lexical_core::WriteIntegerOptions::new();
+    let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+    let result = lexical_core::reformat_with_formats::<i32, PARSE_FORMAT, WRITE_FORMAT>(
+        b"-1_000",
+        &parse_options,
+        &write_options,
+        &mut buffer,
+    );
+    assert_eq!(result.unwrap(), b"-1000");
+}
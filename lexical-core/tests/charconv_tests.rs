@@ -0,0 +1,392 @@
+#![cfg(any(feature = "parse", feature = "write"))]
+
+#[cfg(feature = "parse")]
+use lexical_core::charconv::{from_chars, from_chars_with, FromChars, FromCharsResult};
+#[cfg(feature = "parse-floats")]
+use lexical_core::charconv::{strtod, strtof};
+#[cfg(feature = "write")]
+use lexical_core::charconv::{to_chars, to_chars_with, ToChars};
+use lexical_core::charconv::{CharsFormat, Errc};
+
+#[cfg(feature = "parse")]
+fn parse<N: FromChars + Copy>(bytes: &[u8], mode: N::Mode, init: N) -> (N, FromCharsResult) {
+    let mut value = init;
+    let result = from_chars_with(bytes, &mut value, mode);
+    (value, result)
+}
+
+#[cfg(feature = "write")]
+fn write<N: ToChars>(value: N, mode: N::Mode) -> String {
+    let mut buffer = [0u8; 512];
+    let result = to_chars_with(&mut buffer, value, mode);
+    assert_eq!(result.ec, None);
+    String::from_utf8(buffer[..result.ptr].to_vec()).unwrap()
+}
+
+#[test]
+fn chars_format_test() {
+    assert_eq!(CharsFormat::default(), CharsFormat::GENERAL);
+    assert_eq!(CharsFormat::FIXED | CharsFormat::SCIENTIFIC, CharsFormat::GENERAL);
+    assert!(CharsFormat::GENERAL.contains(CharsFormat::FIXED));
+    assert!(CharsFormat::SHORTEST.contains(CharsFormat::GENERAL));
+    assert!(!CharsFormat::FIXED.contains(CharsFormat::HEX));
+    assert_eq!(CharsFormat::HEX.bits(), 4);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn integer_from_chars_test() {
+    let ok = |ptr| FromCharsResult {
+        ptr,
+        ec: None,
+    };
+    let error = |ptr, ec| FromCharsResult {
+        ptr,
+        ec: Some(ec),
+    };
+    let mut value = 0i32;
+    assert_eq!(from_chars(b"-123abc", &mut value), ok(4));
+    assert_eq!(value, -123);
+
+    assert_eq!(parse(b"123", 10, 7u32), (123, ok(3)));
+    assert_eq!(parse(b"-123", 10, 7u32), (7, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"+1", 10, 7i32), (7, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b" 1", 10, 7i32), (7, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"-", 10, 7i32), (7, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"", 10, 7i32), (7, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"256 ", 10, 7u8), (7, error(3, Errc::ResultOutOfRange)));
+    assert_eq!(parse(b"-129", 10, 7i8), (7, error(4, Errc::ResultOutOfRange)));
+    assert_eq!(parse(b"-128", 10, 7i8), (-128, ok(4)));
+    assert_eq!(parse(b"007", 10, 0u128), (7, ok(3)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "power-of-two"))]
+fn integer_from_chars_radix_test() {
+    let ok = |ptr| FromCharsResult {
+        ptr,
+        ec: None,
+    };
+    assert_eq!(parse(b"fF", 16, 0u8), (255, ok(2)));
+    assert_eq!(parse(b"0x10", 16, 7u8), (0, ok(1)));
+    assert_eq!(parse(b"-1012", 2, 0i32), (-5, ok(4)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "radix"))]
+fn integer_from_chars_any_radix_test() {
+    assert_eq!(parse(b"zZ", 36, 0u32).0, 1295);
+    assert_eq!(parse(b"123", 3, 0u32).0, 5);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "parse-integers")]
+fn integer_from_chars_invalid_base_test() {
+    parse(b"1", 37, 0u32);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_from_chars_test() {
+    let ok = |ptr| FromCharsResult {
+        ptr,
+        ec: None,
+    };
+    let error = |ptr, ec| FromCharsResult {
+        ptr,
+        ec: Some(ec),
+    };
+    let general = CharsFormat::GENERAL;
+    let mut value = 0f64;
+    assert_eq!(from_chars(b"1.5e3 m", &mut value), ok(5));
+    assert_eq!(value, 1500.0);
+
+    assert_eq!(parse(b"-1.5E+3", general, 0f64), (-1500.0, ok(7)));
+    assert_eq!(parse(b"1e", general, 0f64), (1.0, ok(1)));
+    assert_eq!(parse(b"1e+x", general, 0f64), (1.0, ok(1)));
+    assert_eq!(parse(b".5", general, 0f64), (0.5, ok(2)));
+    assert_eq!(parse(b"5.", general, 0f64), (5.0, ok(2)));
+    assert_eq!(parse(b"0x1", general, 0f64), (0.0, ok(1)));
+    assert_eq!(parse(b".", general, 7f64), (7.0, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"-", general, 7f64), (7.0, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"+1", general, 7f64), (7.0, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"e5", general, 7f64), (7.0, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"1e400", general, 7f64), (7.0, error(5, Errc::ResultOutOfRange)));
+    assert_eq!(parse(b"-1e-400", general, 7f64), (7.0, error(7, Errc::ResultOutOfRange)));
+    assert_eq!(parse(b"1e39", general, 7f32), (7.0, error(4, Errc::ResultOutOfRange)));
+    assert_eq!(parse(b"0e400", general, 7f64), (0.0, ok(5)));
+    assert_eq!(parse(b"5e-324", general, 0f64), (5e-324, ok(6)));
+
+    assert_eq!(parse(b"inf", general, 0f64), (f64::INFINITY, ok(3)));
+    assert_eq!(parse(b"-Infinity", general, 0f64), (f64::NEG_INFINITY, ok(9)));
+    assert_eq!(parse(b"infinit", general, 0f64), (f64::INFINITY, ok(3)));
+    assert!(parse(b"NaN", general, 0f64).0.is_nan());
+    assert_eq!(parse(b"nan(0x_1)", general, 0f64).1, ok(9));
+    assert_eq!(parse(b"nan(", general, 0f64).1, ok(3));
+    assert_eq!(parse(b"nan(-)", general, 0f64).1, ok(3));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_from_chars_format_test() {
+    let ok = |ptr| FromCharsResult {
+        ptr,
+        ec: None,
+    };
+    let error = |ptr, ec| FromCharsResult {
+        ptr,
+        ec: Some(ec),
+    };
+    let fixed = CharsFormat::FIXED;
+    let scientific = CharsFormat::SCIENTIFIC;
+    assert_eq!(parse(b"1.5e3", fixed, 0f64), (1.5, ok(3)));
+    assert_eq!(parse(b"1.5e3", scientific, 0f64), (1500.0, ok(5)));
+    assert_eq!(parse(b"1.5", scientific, 7f64), (7.0, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"1.5e", scientific, 7f64), (7.0, error(0, Errc::InvalidArgument)));
+    assert_eq!(parse(b"inf", scientific, 0f64), (f64::INFINITY, ok(3)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_from_chars_hex_test() {
+    let ok = |ptr| FromCharsResult {
+        ptr,
+        ec: None,
+    };
+    let hex = CharsFormat::HEX;
+    assert_eq!(parse(b"1.8p1", hex, 0f64), (3.0, ok(5)));
+    assert_eq!(parse(b"-a.8P-3", hex, 0f64), (-1.3125, ok(7)));
+    assert_eq!(parse(b"1.999999999999ap-4", hex, 0f64), (0.1, ok(18)));
+    assert_eq!(parse(b"0x1p1", hex, 7f64), (0.0, ok(1)));
+    assert_eq!(parse(b"ff", hex, 0f32), (255.0, ok(2)));
+    assert_eq!(parse(b"1p", hex, 0f32), (1.0, ok(1)));
+    assert_eq!(parse(b"1p10", hex, 0f64), (1024.0, ok(4)));
+    assert_eq!(parse(b"0.0000000000001p-1022", hex, 0f64), (5e-324, ok(21)));
+    assert_eq!(parse(b"1p-1074", hex, 0f64), (5e-324, ok(7)));
+    assert_eq!(parse(b"1.8p-1074", hex, 0f64), (1e-323, ok(9)));
+    assert_eq!(parse(b"1p-1023", hex, 0f64), (f64::MIN_POSITIVE / 2.0, ok(7)));
+    assert_eq!(parse(b"1.fffffffffffffp1023", hex, 0f64), (f64::MAX, ok(20)));
+    assert_eq!(parse(b"1.fffffffffffff7ffp1023", hex, 0f64), (f64::MAX, ok(23)));
+    assert_eq!(parse(b"1.fffffep127", hex, 0f32), (f32::MAX, ok(12)));
+    assert_eq!(parse(b"1.00000000000008p0", hex, 0f64), (1.0, ok(18)));
+    assert_eq!(parse(b"1.00000000000018p0", hex, 0f64), (1.0 + 2.0 * f64::EPSILON, ok(18)));
+    assert_eq!(parse(b"1.000000000000080001p0", hex, 0f64), (1.0 + f64::EPSILON, ok(22)));
+    assert_eq!(parse(b"0.00000000000000000000000000001p0", hex, 0f64).0, 2f64.powi(-116));
+
+    let error = |ptr| FromCharsResult {
+        ptr,
+        ec: Some(Errc::ResultOutOfRange),
+    };
+    assert_eq!(parse(b"1p-1075", hex, 7f64), (7.0, error(7)));
+    assert_eq!(parse(b"1p1024", hex, 7f64), (7.0, error(6)));
+    assert_eq!(parse(b"1.fffffffffffff8p1023", hex, 7f64), (7.0, error(21)));
+    assert_eq!(parse(b"1p99999999999999999999", hex, 7f64), (7.0, error(22)));
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_to_chars_test() {
+    let mut buffer = [0u8; 4];
+    let result = to_chars(&mut buffer, -128i8);
+    assert_eq!((result.ptr, result.ec), (4, None));
+    assert_eq!(&buffer, b"-128");
+
+    let result = to_chars(&mut buffer, 12345u32);
+    assert_eq!((result.ptr, result.ec), (4, Some(Errc::ValueTooLarge)));
+
+    assert_eq!(write(0u8, 10), "0");
+    assert_eq!(write(u128::MAX, 10), u128::MAX.to_string());
+    assert_eq!(write(i64::MIN, 10), i64::MIN.to_string());
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "power-of-two"))]
+fn integer_to_chars_radix_test() {
+    assert_eq!(write(255u8, 16), "ff");
+    assert_eq!(write(-5i32, 2), "-101");
+    assert_eq!(write(i128::MIN, 2), format!("-1{}", "0".repeat(127)));
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "radix"))]
+fn integer_to_chars_any_radix_test() {
+    assert_eq!(write(1295u32, 36), "zz");
+    assert_eq!(write(5u32, 3), "12");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_to_chars_test() {
+    let shortest = CharsFormat::SHORTEST;
+    assert_eq!(write(1.5f64, shortest), "1.5");
+    assert_eq!(write(0.1f64, shortest), "0.1");
+    assert_eq!(write(100.0f64, shortest), "100");
+    assert_eq!(write(123456789012.0f64, shortest), "123456789012");
+    assert_eq!(write(1e10f64, shortest), "1e+10");
+    assert_eq!(write(1e21f64, shortest), "1e+21");
+    assert_eq!(write(1e-5f64, shortest), "1e-05");
+    assert_eq!(write(0.001f64, shortest), "0.001");
+    assert_eq!(write(1.25e-7f64, shortest), "1.25e-07");
+    assert_eq!(write(0.0f64, shortest), "0");
+    assert_eq!(write(-0.0f64, shortest), "-0");
+    assert_eq!(write(5e-324f64, shortest), "5e-324");
+    assert_eq!(write(f64::MAX, shortest), "1.7976931348623157e+308");
+    assert_eq!(write(f64::INFINITY, shortest), "inf");
+    assert_eq!(write(f64::NEG_INFINITY, shortest), "-inf");
+    assert_eq!(write(f64::NAN, shortest), "nan");
+    assert_eq!(write(0.1f32, shortest), "0.1");
+    assert_eq!(write(1e10f32, shortest), "1e+10");
+    assert_eq!(write(16777216f32, shortest), "16777216");
+
+    let mut buffer = [0u8; 3];
+    let result = to_chars(&mut buffer, 100.0f64);
+    assert_eq!((result.ptr, result.ec), (3, None));
+    assert_eq!(&buffer, b"100");
+
+    let mut buffer = [0u8; 2];
+    let result = to_chars(&mut buffer, 1.5f64);
+    assert_eq!((result.ptr, result.ec), (2, Some(Errc::ValueTooLarge)));
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_to_chars_general_test() {
+    let general = CharsFormat::GENERAL;
+    assert_eq!(write(100.0f64, general), "1e+02");
+    assert_eq!(write(1500.0f64, general), "1.5e+03");
+    assert_eq!(write(1234.0f64, general), "1234");
+    assert_eq!(write(1.5f64, general), "1.5");
+    assert_eq!(write(123456789012.0f64, general), "123456789012");
+    assert_eq!(write(1e10f64, general), "1e+10");
+    assert_eq!(write(0.0001f64, general), "0.0001");
+    assert_eq!(write(0.00012f64, general), "0.00012");
+    assert_eq!(write(1e-5f64, general), "1e-05");
+    assert_eq!(write(0.0f64, general), "0");
+    assert_eq!(write(-0.0f64, general), "-0");
+    assert_eq!(write(1.0f64, general), "1");
+    assert_eq!(write(10.0f64, general), "1e+01");
+    assert_eq!(write(5e-324f64, general), "5e-324");
+    assert_eq!(write(16777216f32, general), "16777216");
+    assert_eq!(write(f64::INFINITY, general), "inf");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_to_chars_format_test() {
+    let fixed = CharsFormat::FIXED;
+    let scientific = CharsFormat::SCIENTIFIC;
+    assert_eq!(write(100.0f64, scientific), "1e+02");
+    assert_eq!(write(1.5f64, scientific), "1.5e+00");
+    assert_eq!(write(0.0f64, scientific), "0e+00");
+    assert_eq!(write(-1.25e-100f64, scientific), "-1.25e-100");
+    assert_eq!(write(1e21f64, fixed), "1000000000000000000000");
+    assert_eq!(write(1e-5f64, fixed), "0.00001");
+    assert_eq!(write(-12.5f64, fixed), "-12.5");
+    assert_eq!(write(5e-324f64, fixed), format!("0.{}5", "0".repeat(323)));
+    assert_eq!(write(f64::MAX, fixed).len(), 309);
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_to_chars_hex_test() {
+    let hex = CharsFormat::HEX;
+    assert_eq!(write(1.0f64, hex), "1p+0");
+    assert_eq!(write(3.0f64, hex), "1.8p+1");
+    assert_eq!(write(-0.1f64, hex), "-1.999999999999ap-4");
+    assert_eq!(write(0.0f64, hex), "0p+0");
+    assert_eq!(write(5e-324f64, hex), "0.0000000000001p-1022");
+    assert_eq!(write(f64::MAX, hex), "1.fffffffffffffp+1023");
+    assert_eq!(write(0.1f32, hex), "1.99999ap-4");
+    assert_eq!(write(f32::MIN_POSITIVE, hex), "1p-126");
+    assert_eq!(write(f32::NAN, hex), "nan");
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn float_roundtrip_test() {
+    let formats =
+        [CharsFormat::GENERAL, CharsFormat::FIXED, CharsFormat::SCIENTIFIC, CharsFormat::HEX];
+    let values = [1.5f64, 0.1, 1e-310, 5e-324, 123456.789, 1e300, f64::MAX, f64::MIN_POSITIVE];
+    for &format in formats.iter() {
+        for &value in values.iter() {
+            let string = write(value, format);
+            assert_eq!(
+                parse(string.as_bytes(), format, 0f64),
+                (
+                    value,
+                    FromCharsResult {
+                        ptr: string.len(),
+                        ec: None,
+                    }
+                )
+            );
+        }
+    }
+}
+
+#[cfg(feature = "parse-floats")]
+fn strtod_parts(bytes: &[u8]) -> (f64, usize, bool) {
+    let result = strtod(bytes);
+    (result.value, result.end, result.range_error)
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn strtod_test() {
+    assert_eq!(strtod_parts(b"1.5"), (1.5, 3, false));
+    assert_eq!(strtod_parts(b" \t\n\x0B\x0C\r+1.5e3x"), (1500.0, 12, false));
+    assert_eq!(strtod_parts(b"-.5e-1"), (-0.05, 6, false));
+    assert_eq!(strtod_parts(b"1e+"), (1.0, 1, false));
+    assert_eq!(strtod_parts(b"-INFINITY"), (f64::NEG_INFINITY, 9, false));
+    assert_eq!(strtod_parts(b" +inf"), (f64::INFINITY, 5, false));
+    assert_eq!(strtod(b"nan(1)").end, 6);
+    assert!(strtod(b"-nan").value.is_nan());
+
+    // No conversion.
+    assert_eq!(strtod_parts(b""), (0.0, 0, false));
+    assert_eq!(strtod_parts(b"  "), (0.0, 0, false));
+    assert_eq!(strtod_parts(b" -"), (0.0, 0, false));
+    assert_eq!(strtod_parts(b"+-1"), (0.0, 0, false));
+    assert_eq!(strtod_parts(b"."), (0.0, 0, false));
+    assert_eq!(strtod_parts(b"e1"), (0.0, 0, false));
+
+    // Range errors.
+    assert_eq!(strtod_parts(b"1e400"), (f64::INFINITY, 5, true));
+    assert_eq!(strtod_parts(b"-1e400"), (f64::NEG_INFINITY, 6, true));
+    assert_eq!(strtod_parts(b"1e-400"), (0.0, 6, true));
+    assert_eq!(strtod_parts(b"5e-324"), (5e-324, 6, true));
+    assert_eq!(strtod_parts(b"0e-400"), (0.0, 6, false));
+    assert_eq!(strtod_parts(b"2.2250738585072014e-308"), (f64::MIN_POSITIVE, 23, false));
+
+    // Only inexact subnormals are out of range.
+    let exact = b"1.40129846432481707092372958328991613128026194187651577175706828388979108268586060148663818836212158203125e-45";
+    let result = strtof(exact);
+    assert_eq!((result.value, result.end, result.range_error), (1e-45, exact.len(), false));
+    let result = strtof(b"1.4e-45");
+    assert_eq!((result.value, result.end, result.range_error), (1e-45, 7, true));
+    let result = strtof(b"0x3p-149");
+    assert_eq!((result.value, result.end, result.range_error), (4e-45, 8, false));
+
+    let result = strtof(b"1e39");
+    assert_eq!((result.value, result.end, result.range_error), (f32::INFINITY, 4, true));
+    let result = strtof(b"3.5");
+    assert_eq!((result.value, result.end, result.range_error), (3.5, 3, false));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn strtod_hex_test() {
+    assert_eq!(strtod_parts(b"0x1.8p1"), (3.0, 7, false));
+    assert_eq!(strtod_parts(b" -0XA.8P-3"), (-1.3125, 10, false));
+    assert_eq!(strtod_parts(b"0x.8"), (0.5, 4, false));
+    assert_eq!(strtod_parts(b"0x1p"), (1.0, 3, false));
+    assert_eq!(strtod_parts(b"0x"), (0.0, 1, false));
+    assert_eq!(strtod_parts(b"0xg"), (0.0, 1, false));
+    assert_eq!(strtod_parts(b"0x1p2000"), (f64::INFINITY, 8, true));
+    assert_eq!(strtod_parts(b"0x1p-1074"), (5e-324, 9, false));
+    assert_eq!(strtod_parts(b"0x1.8p-1074"), (1e-323, 11, true));
+    assert_eq!(strtod_parts(b"0x1.8p-1073"), (1.5e-323, 11, false));
+    assert_eq!(strtod_parts(b"0x1p-1075"), (0.0, 9, true));
+    assert_eq!(strtod_parts(b"0x0p-1075"), (0.0, 9, false));
+}
@@ -0,0 +1,57 @@
+#![cfg(all(feature = "std", feature = "parse-integers", feature = "parse-floats"))]
+
+use lexical_core::{column, Error};
+
+#[test]
+fn parse_test() {
+    let (values, validity) = column::parse::<i64>(b"", b",", column::NULLS).unwrap();
+    assert!(values.is_empty() && validity.is_empty());
+    assert_eq!(column::parse::<i64>(b",", b",", column::NULLS), Ok((vec![0, 0], vec![0b00])));
+    assert_eq!(column::parse::<i64>(b"7", b",", column::NULLS), Ok((vec![7], vec![0b1])));
+    assert_eq!(column::parse::<i64>(b"7", b",", &[b"7"]), Ok((vec![0], vec![0b0])));
+    assert_eq!(
+        column::parse::<i64>(b"1\n2\n", b"\n", column::NULLS),
+        Ok((vec![1, 2, 0], vec![0b011]))
+    );
+    assert_eq!(column::parse::<i64>(b"1, 2", b", ", column::NULLS), Ok((vec![1, 2], vec![0b11])));
+    assert_eq!(column::parse::<i64>(b"1,2", b"", column::NULLS), Err(Error::InvalidDigit(1)));
+
+    // The bitmap is padded to a whole number of bytes.
+    let bytes = b"1,2,3,4,5,6,7,8,NA,10";
+    let (values, validity) = column::parse::<u32>(bytes, b",", column::NULLS).unwrap();
+    assert_eq!(values, [1, 2, 3, 4, 5, 6, 7, 8, 0, 10]);
+    assert_eq!(validity, [0xFF, 0b10]);
+
+    let (values, validity) = column::parse::<f32>(b"null|1e3|NaN|NA", b"|", column::NULLS).unwrap();
+    assert_eq!(values[..2], [0.0, 1000.0]);
+    assert!(values[2].is_nan());
+    assert_eq!(validity, [0b0110]);
+}
+
+#[test]
+fn parse_error_test() {
+    // Null tokens are case-sensitive, and errors are indexed in the column.
+    assert_eq!(column::parse::<i32>(b"1,NULL", b",", column::NULLS), Err(Error::InvalidDigit(2)));
+    assert_eq!(column::parse::<i32>(b"1,,3", b",", &[]), Err(Error::Empty(2)));
+    assert_eq!(column::parse::<u8>(b"1::256", b"::", column::NULLS), Err(Error::Overflow(5)));
+    assert_eq!(
+        column::parse::<f64>(b"1.5\n1.5.", b"\n", column::NULLS),
+        Err(Error::InvalidDigit(7))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_with_options_test() {
+    use lexical_core::{NumberFormatBuilder, ParseIntegerOptions};
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let options = ParseIntegerOptions::new();
+    let nulls: &[&[u8]] = &[b"?"];
+    let bytes = b"1_000\t?\t-2_500_000";
+    let column = column::parse_with_options::<i64, FORMAT>(bytes, b"\t", nulls, &options);
+    assert_eq!(column, Ok((vec![1000, 0, -2500000], vec![0b101])));
+}
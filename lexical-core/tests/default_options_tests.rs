@@ -0,0 +1,106 @@
+// The defaults are process-wide, so each options type is only installed
+// by a single test in this binary.
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-floats"))]
+fn parse_float_default_options_test() {
+    use lexical_core::ParseFloatOptions;
+
+    assert_eq!(lexical_core::default_options::<ParseFloatOptions>(), None);
+    assert_eq!(lexical_core::parse::<f64>(b"3.5"), Ok(3.5));
+
+    let options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(lexical_core::set_default_options(options.clone()), Ok(()));
+    assert_eq!(lexical_core::default_options::<ParseFloatOptions>(), Some(&options));
+    assert_eq!(lexical_core::parse::<f64>(b"3,5"), Ok(3.5));
+    assert_eq!(lexical_core::parse::<f32>(b"3,5"), Ok(3.5));
+    assert_eq!(lexical_core::parse_partial::<f64>(b"3,5;"), Ok((3.5, 3)));
+    assert!(lexical_core::parse::<f64>(b"3.5").is_err());
+
+    // Explicit options are unaffected by the defaults.
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let standard = ParseFloatOptions::new();
+    assert_eq!(lexical_core::parse_with_options::<f64, FORMAT>(b"3.5", &standard), Ok(3.5));
+
+    // The defaults may only be installed once.
+    assert_eq!(lexical_core::set_default_options(standard.clone()), Err(standard));
+    assert_eq!(lexical_core::default_options::<ParseFloatOptions>(), Some(&options));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-integers"))]
+fn parse_integer_default_options_test() {
+    use lexical_core::ParseIntegerOptions;
+
+    let options = ParseIntegerOptions::new();
+    assert_eq!(lexical_core::set_default_options(options.clone()), Ok(()));
+    assert_eq!(lexical_core::parse::<u32>(b"12345"), Ok(12345));
+    assert_eq!(lexical_core::parse_partial::<i64>(b"-12345 "), Ok((-12345, 6)));
+    assert_eq!(lexical_core::set_default_options(options.clone()), Err(options));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-floats"))]
+fn write_float_default_options_test() {
+    use core::num::NonZeroUsize;
+    use lexical_core::{ryu, FormattedSize, WriteFloatOptions, BUFFER_SIZE};
+
+    // Options needing more than `FORMATTED_SIZE_DECIMAL` bytes are rejected.
+    let options = WriteFloatOptions::builder()
+        .min_significant_digits(NonZeroUsize::new(100))
+        .build()
+        .unwrap();
+    assert_eq!(lexical_core::set_default_options(options.clone()), Err(options));
+    assert_eq!(lexical_core::default_options::<WriteFloatOptions>(), None);
+
+    let options = WriteFloatOptions::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(lexical_core::set_default_options(options.clone()), Ok(()));
+
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    assert_eq!(lexical_core::write(3.5f64, &mut buffer), b"3,5");
+    assert_eq!(lexical_core::write(3.5f32, &mut buffer), b"3,5");
+    assert_eq!(lexical_core::write_checked(3.5f64, &mut buffer[..3]).unwrap(), b"3,5");
+
+    // The defaults are used regardless of the buffer size.
+    let mut buffer = [b'0'; f32::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(lexical_core::write(f32::MIN_POSITIVE, &mut buffer), b"1,1754944e-38");
+
+    // The unchecked writers and `ryu` never use the defaults.
+    assert_eq!(unsafe { lexical_core::write_unchecked(3.5f64, &mut buffer) }, b"3.5");
+    assert_eq!(ryu::Buffer::new().format(3.5f64), "3.5");
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-integers"))]
+fn write_integer_default_options_test() {
+    use core::num::NonZeroUsize;
+    use lexical_core::{itoa, FormattedSize, WriteIntegerOptions, BUFFER_SIZE};
+
+    // Options needing more than `FORMATTED_SIZE_DECIMAL` bytes are rejected.
+    let options = WriteIntegerOptions::builder().space_positive(true).build().unwrap();
+    assert_eq!(lexical_core::set_default_options(options.clone()), Err(options));
+    assert_eq!(lexical_core::default_options::<WriteIntegerOptions>(), None);
+
+    let options = WriteIntegerOptions::builder().min_digits(NonZeroUsize::new(2)).build().unwrap();
+    assert_eq!(lexical_core::set_default_options(options.clone()), Ok(()));
+
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    assert_eq!(lexical_core::write(5u32, &mut buffer), b"05");
+    assert_eq!(lexical_core::write(-5i64, &mut buffer), b"-05");
+    assert_eq!(lexical_core::write(255u8, &mut buffer), b"255");
+
+    // The defaults are used regardless of the buffer size.
+    let mut buffer = [b'0'; u8::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(lexical_core::write(5u8, &mut buffer), b"05");
+    let mut buffer = [b'0'; i8::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(lexical_core::write(i8::MIN, &mut buffer), b"-128");
+    let mut buffer = [b'0'; u128::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(
+        lexical_core::write(u128::MAX, &mut buffer),
+        b"340282366920938463463374607431768211455"
+    );
+
+    // `itoa` never uses the defaults.
+    assert_eq!(itoa::Buffer::new().format(5u32), "5");
+    assert_eq!(lexical_core::set_default_options(options.clone()), Err(options));
+}
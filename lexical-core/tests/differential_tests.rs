@@ -0,0 +1,199 @@
+//! Differential tests against libc's `strtod` and the ryu crate.
+//!
+//! These cross-check the float parsers against `strtod` and `strtof`,
+//! and the shortest digits written by the float writers against ryu,
+//! for generated inputs. The number of inputs for each test defaults
+//! to 1,000,000 and can be changed with `LEXICAL_DIFFERENTIAL_COUNT`,
+//! and the inputs are seeded by `LEXICAL_DIFFERENTIAL_SEED`. Every
+//! mismatch is reported with the string to reproduce it.
+
+#![cfg(all(feature = "differential", feature = "parse-floats", feature = "write-floats"))]
+
+use std::env;
+use std::ffi::CString;
+use std::fmt::Write;
+
+/// Maximum number of mismatches to report for each test.
+const MAX_REPORTED: usize = 50;
+
+/// Simple xorshift random number generator, so failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        let seed = env::var("LEXICAL_DIFFERENTIAL_SEED").ok().and_then(|x| x.parse().ok());
+        Self(seed.unwrap_or(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, max: u64) -> u64 {
+        self.next() % max
+    }
+
+    fn digits(&mut self, string: &mut String, count: u64) {
+        for _ in 0..count {
+            string.push((b'0' + self.below(10) as u8) as char);
+        }
+    }
+}
+
+fn count() -> usize {
+    env::var("LEXICAL_DIFFERENTIAL_COUNT").ok().and_then(|x| x.parse().ok()).unwrap_or(1_000_000)
+}
+
+/// Collect mismatches, and fail with the reproducer strings at the end.
+#[derive(Default)]
+struct Mismatches {
+    count: usize,
+    report: String,
+}
+
+impl Mismatches {
+    fn add(&mut self, input: &str, actual: &str, expected: &str) {
+        if self.count < MAX_REPORTED {
+            let _ =
+                writeln!(self.report, "  {:?}: lexical={} expected={}", input, actual, expected);
+        }
+        self.count += 1;
+    }
+
+    fn check(self, name: &str) {
+        assert!(self.count == 0, "{} mismatches for {}:\n{}", self.count, name, self.report);
+    }
+}
+
+/// Parse a float with libc's `strtod`.
+fn strtod(string: &str) -> f64 {
+    let string = CString::new(string).unwrap();
+    unsafe { libc::strtod(string.as_ptr(), std::ptr::null_mut()) }
+}
+
+/// Parse a float with libc's `strtof`.
+fn strtof(string: &str) -> f32 {
+    let string = CString::new(string).unwrap();
+    unsafe { libc::strtof(string.as_ptr(), std::ptr::null_mut()) }
+}
+
+/// Generate a decimal string, biased towards the cases that are difficult to round.
+fn decimal_string(rng: &mut Rng, string: &mut String) {
+    string.clear();
+    if rng.below(4) == 0 {
+        string.push('-');
+    }
+    match rng.below(4) {
+        // Random digits and exponent, including overflow and underflow.
+        0 => {
+            let integer = 1 + rng.below(20);
+            rng.digits(string, integer);
+            string.push('.');
+            let fraction = rng.below(20);
+            rng.digits(string, fraction);
+            let _ = write!(string, "e{}", rng.below(700) as i64 - 350);
+        },
+        // Many digits, which require the slow path algorithms.
+        1 => {
+            string.push_str("0.");
+            let count = 1 + rng.below(800);
+            rng.digits(string, count);
+            let _ = write!(string, "e{}", rng.below(640) as i64 - 320);
+        },
+        // Near-halfway cases: the exact digits of a random float, with the
+        // last digits changed so the value is just below, at, or above the
+        // halfway point to the next float.
+        2 => {
+            let float = f64::from_bits(rng.next() & 0x7FEF_FFFF_FFFF_FFFF);
+            let _ = write!(string, "{:.40e}", float);
+            let index = string.find('e').unwrap();
+            let exponent = string.split_off(index);
+            string.truncate(string.len() - 1 - rng.below(20) as usize);
+            let suffix = ["5", "49999999999999999999", "50000000000000000001"];
+            string.push_str(suffix[rng.below(3) as usize]);
+            string.push_str(&exponent);
+        },
+        // Denormal and near-denormal floats.
+        _ => {
+            let float = f64::from_bits(rng.below(0x0020_0000_0000_0000));
+            let _ = write!(string, "{:.*e}", rng.below(25) as usize, float);
+        },
+    }
+}
+
+#[test]
+fn strtod_test() {
+    let mut rng = Rng::new();
+    let mut mismatches = Mismatches::default();
+    let mut string = String::new();
+    for _ in 0..count() {
+        decimal_string(&mut rng, &mut string);
+        let actual = lexical_core::parse::<f64>(string.as_bytes()).unwrap();
+        let expected = strtod(&string);
+        if actual.to_bits() != expected.to_bits() {
+            mismatches.add(&string, &format!("{:e}", actual), &format!("{:e}", expected));
+        }
+    }
+    mismatches.check("strtod");
+}
+
+#[test]
+fn strtof_test() {
+    let mut rng = Rng::new();
+    let mut mismatches = Mismatches::default();
+    let mut string = String::new();
+    for _ in 0..count() {
+        decimal_string(&mut rng, &mut string);
+        let actual = lexical_core::parse::<f32>(string.as_bytes()).unwrap();
+        let expected = strtof(&string);
+        if actual.to_bits() != expected.to_bits() {
+            mismatches.add(&string, &format!("{:e}", actual), &format!("{:e}", expected));
+        }
+    }
+    mismatches.check("strtof");
+}
+
+/// Get the significant digits and the exponent of the first digit of a float string.
+fn significant_digits(string: &str) -> (String, i32) {
+    let (mantissa, exponent) = match string.find(|c| c == 'e' || c == 'E') {
+        Some(index) => (&string[..index], string[index + 1..].parse::<i32>().unwrap()),
+        None => (string, 0),
+    };
+    let mantissa = mantissa.trim_start_matches('-');
+    let point = mantissa.find('.').unwrap_or(mantissa.len()) as i32;
+    let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let leading = (digits.len() - digits.trim_start_matches('0').len()) as i32;
+    let digits = digits.trim_matches('0').to_string();
+    (digits, exponent + point - leading)
+}
+
+macro_rules! ryu_test {
+    ($name:ident, $float:ident, $uint:ident, $mask:expr) => {
+        #[test]
+        fn $name() {
+            let mut rng = Rng::new();
+            let mut mismatches = Mismatches::default();
+            let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+            let mut ryu = ryu::Buffer::new();
+            for _ in 0..count() {
+                let float = $float::from_bits(rng.next() as $uint & $mask);
+                if !float.is_finite() || float == 0.0 {
+                    continue;
+                }
+                let actual = lexical_core::write(float, &mut buffer);
+                let actual = std::str::from_utf8(actual).unwrap();
+                let expected = ryu.format_finite(float);
+                if significant_digits(actual) != significant_digits(expected) {
+                    mismatches.add(&format!("{:e}", float), actual, expected);
+                }
+            }
+            mismatches.check("ryu");
+        }
+    };
+}
+
+ryu_test!(ryu_f32_test, f32, u32, 0x7FFF_FFFF);
+ryu_test!(ryu_f64_test, f64, u64, 0x7FFF_FFFF_FFFF_FFFF);
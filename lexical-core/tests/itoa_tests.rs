@@ -0,0 +1,37 @@
+#![cfg(feature = "write-integers")]
+
+use lexical_core::itoa;
+use proptest::prelude::*;
+
+#[test]
+fn format_test() {
+    let mut buffer = itoa::Buffer::new();
+    assert_eq!(buffer.format(0u8), "0");
+    assert_eq!(buffer.format(u8::MAX), "255");
+    assert_eq!(buffer.format(-1i16), "-1");
+    assert_eq!(buffer.format(usize::MAX), usize::MAX.to_string());
+    assert_eq!(buffer.format(isize::MIN), isize::MIN.to_string());
+    assert_eq!(buffer.format(u128::MAX), "340282366920938463463374607431768211455");
+    assert_eq!(buffer.format(i128::MIN), "-170141183460469231731687303715884105728");
+
+    // Copies of the buffer are independent.
+    let mut copy = buffer;
+    assert_eq!(copy.format(7u32), "7");
+    assert_eq!(itoa::Buffer::default().format(i64::MIN), "-9223372036854775808");
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn u64_proptest(i in any::<u64>()) {
+        let mut buffer = itoa::Buffer::new();
+        prop_assert_eq!(buffer.format(i), i.to_string());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn i128_proptest(i in any::<i128>()) {
+        let mut buffer = itoa::Buffer::new();
+        prop_assert_eq!(buffer.format(i), i.to_string());
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+
+use lexical_core::lint::{self, Lints};
+use lexical_core::Error;
+#[cfg(feature = "format")]
+use lexical_core::{NumberFormatBuilder, ParseFloatOptions, ParseIntegerOptions};
+
+fn analyze(bytes: &[u8]) -> Lints {
+    lint::analyze(bytes).unwrap()
+}
+
+#[test]
+fn valid_test() {
+    assert_eq!(analyze(b"0"), Lints::default());
+    assert_eq!(analyze(b"-1.5"), Lints::default());
+    assert_eq!(analyze(b"0.1"), Lints::default());
+    assert_eq!(analyze(b"0.000123e-5"), Lints::default());
+    assert_eq!(analyze(b"0e999"), Lints::default());
+    assert_eq!(analyze(b"4503599627370496"), Lints::default());
+    assert_eq!(analyze(b"inf"), Lints::default());
+    assert_eq!(analyze(b"NaN"), Lints::default());
+    assert_eq!(lint::analyze(b"1.5x"), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn excess_precision_test() {
+    assert!(analyze(b"3.14159265358979323846").excess_precision);
+    assert!(analyze(b"0.1000000000000000055511151231257827").excess_precision);
+    // Exact or short floats keep all of their digits.
+    assert!(!analyze(b"0.100000000000000").excess_precision);
+    assert!(!analyze(b"0.1000000000000000000000000").excess_precision);
+    assert!(!analyze(b"9007199254740992").excess_precision);
+    assert!(analyze(b"9007199254740993").excess_precision);
+}
+
+#[test]
+fn leading_zeros_test() {
+    assert!(analyze(b"007").leading_zeros);
+    assert!(analyze(b"-00.5").leading_zeros);
+    assert!(!analyze(b"0.5").leading_zeros);
+    assert!(!analyze(b"0").leading_zeros);
+    assert!(!analyze(b".5").leading_zeros);
+    assert!(!analyze(b"100").leading_zeros);
+}
+
+#[test]
+fn exponent_out_of_range_test() {
+    assert!(analyze(b"1e400").exponent_out_of_range);
+    assert!(analyze(b"-1.5e309").exponent_out_of_range);
+    assert!(analyze(b"1e-400").exponent_out_of_range);
+    assert!(!analyze(b"1e308").exponent_out_of_range);
+    assert!(!analyze(b"5e-324").exponent_out_of_range);
+    // Out of range floats are not also reported as imprecise.
+    assert!(!analyze(b"1.00000000000000000001e400").excess_precision);
+}
+
+#[test]
+fn integer_overflow_test() {
+    assert!(analyze(b"9223372036854775808").integer_overflow);
+    assert!(analyze(b"-9223372036854775809").integer_overflow);
+    assert!(analyze(b"100000000000000000000000").integer_overflow);
+    assert!(!analyze(b"-9223372036854775808").integer_overflow);
+    // Large integers may fit in an `i64`, but not an `f64`.
+    let lints = analyze(b"9223372036854775807");
+    assert!(lints.excess_precision);
+    assert!(!lints.integer_overflow);
+    assert!(!analyze(b"1e100").integer_overflow);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn options_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let integer_options = ParseIntegerOptions::new();
+    let float_options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    let analyze =
+        |bytes| lint::analyze_with_options::<FORMAT>(bytes, &integer_options, &float_options);
+
+    assert_eq!(analyze(b"1_000,5"), Ok(Lints::default()));
+    assert!(analyze(b"0_0,5").unwrap().leading_zeros);
+    assert!(analyze(b"3,141_592_653_589_793_238").unwrap().excess_precision);
+    assert!(analyze(b"9_223_372_036_854_775_808").unwrap().integer_overflow);
+}
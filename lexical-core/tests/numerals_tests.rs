@@ -0,0 +1,103 @@
+#![cfg(any(feature = "parse", feature = "write"))]
+
+use lexical_core::numerals::{self, Numerals, ARABIC_INDIC, DEVANAGARI, EXTENDED_ARABIC_INDIC};
+
+#[test]
+fn numerals_test() {
+    assert_eq!(ARABIC_INDIC.zero(), '٠');
+    assert_eq!(EXTENDED_ARABIC_INDIC.to_char(4), Some('۴'));
+    assert_eq!(DEVANAGARI.to_char(9), Some('९'));
+    assert_eq!(DEVANAGARI.to_char(10), None);
+    assert_eq!(ARABIC_INDIC.to_digit('٧'), Some(7));
+    assert_eq!(ARABIC_INDIC.to_digit('7'), None);
+    assert_eq!(ARABIC_INDIC.to_digit('۷'), None);
+
+    assert_eq!(Numerals::new('٠'), Some(ARABIC_INDIC));
+    assert_eq!(Numerals::new('0').map(|x| x.to_char(5)), Some(Some('5')));
+    assert_eq!(Numerals::new('\u{FF10}').map(|x| x.to_char(1)), Some(Some('１')));
+    assert_eq!(Numerals::new('A'), None);
+    assert_eq!(Numerals::new('\u{D7FF}'), None);
+    assert_eq!(Numerals::new('\u{10FFFF}'), None);
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-integers", feature = "parse-floats"))]
+fn parse_test() {
+    use lexical_core::Error;
+
+    assert_eq!(numerals::parse::<u64>("١٢٣٤٥٦٧٨٩٠".as_bytes(), &ARABIC_INDIC), Ok(1234567890));
+    assert_eq!(numerals::parse::<i8>("-۱۲۸".as_bytes(), &EXTENDED_ARABIC_INDIC), Ok(-128));
+    assert_eq!(numerals::parse::<f64>("१.५e२".as_bytes(), &DEVANAGARI), Ok(150.0));
+    // ASCII digits are also accepted, even mixed with native digits.
+    assert_eq!(numerals::parse::<u32>("1٢3".as_bytes(), &ARABIC_INDIC), Ok(123));
+    assert_eq!(numerals::parse::<f32>(b"NaN", &DEVANAGARI).map(f32::is_nan), Ok(true));
+
+    // Error indexes are byte offsets in the input.
+    assert_eq!(numerals::parse::<u32>(b"", &DEVANAGARI), Err(Error::Empty(0)));
+    assert_eq!(numerals::parse::<u8>("२५६".as_bytes(), &DEVANAGARI), Err(Error::Overflow(6)));
+    assert_eq!(numerals::parse::<u32>("१२ ".as_bytes(), &DEVANAGARI), Err(Error::InvalidDigit(6)));
+    assert_eq!(numerals::parse::<u32>("١२".as_bytes(), &ARABIC_INDIC), Err(Error::InvalidDigit(2)));
+    assert_eq!(
+        numerals::parse::<f64>("٥e".as_bytes(), &ARABIC_INDIC),
+        Err(Error::EmptyExponent(3))
+    );
+    assert_eq!(numerals::parse::<u32>(b"1\xD9", &ARABIC_INDIC), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-floats"))]
+fn parse_with_options_test() {
+    use lexical_core::ParseFloatOptions;
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = ParseFloatOptions::builder().exponent(b'^').build().unwrap();
+    let bytes = "-۲.۵^۳".as_bytes();
+    let result =
+        numerals::parse_with_options::<f64, FORMAT>(bytes, &EXTENDED_ARABIC_INDIC, &options);
+    assert_eq!(result, Ok(-2500.0));
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "write-integers", feature = "write-floats"))]
+fn write_to_test() {
+    let mut string = String::from("x=");
+    numerals::write_to(1234567890u64, &ARABIC_INDIC, &mut string).unwrap();
+    assert_eq!(string, "x=١٢٣٤٥٦٧٨٩٠");
+
+    let mut string = String::new();
+    numerals::write_to(-1.5e-7f64, &EXTENDED_ARABIC_INDIC, &mut string).unwrap();
+    assert_eq!(string, "-۱.۵e-۷");
+
+    let mut string = String::new();
+    numerals::write_to(f64::INFINITY, &DEVANAGARI, &mut string).unwrap();
+    assert_eq!(string, "inf");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut string = String::new();
+    numerals::write_to_with_options::<_, _, FORMAT>(i64::MIN, &DEVANAGARI, &mut string, &options)
+        .unwrap();
+    assert_eq!(string, "-९२२३३७२०३६८५४७७५८०८");
+}
+
+#[test]
+#[cfg(all(
+    feature = "std",
+    feature = "write-integers",
+    feature = "parse-integers",
+    feature = "write-floats",
+    feature = "parse-floats"
+))]
+fn roundtrip_test() {
+    for &value in &[0u64, 7, 1_000_000, u64::MAX] {
+        let mut string = String::new();
+        numerals::write_to(value, &DEVANAGARI, &mut string).unwrap();
+        assert!(!string.is_ascii());
+        assert_eq!(numerals::parse(string.as_bytes(), &DEVANAGARI), Ok(value));
+    }
+    for &value in &[0.1f64, -2.5e300, 5e-324, f64::MAX] {
+        let mut string = String::new();
+        numerals::write_to(value, &ARABIC_INDIC, &mut string).unwrap();
+        assert_eq!(numerals::parse(string.as_bytes(), &ARABIC_INDIC), Ok(value));
+    }
+}
@@ -0,0 +1,174 @@
+//! Property-based round-trip tests across number formats and options.
+//!
+//! These generate random, valid number formats with the property-testing
+//! strategies, along with options that are valid for the format, and check
+//! that writing then parsing a number is the identity, and that writing
+//! floats with fewer significant digits preserves their order.
+
+#![cfg(all(feature = "proptest", feature = "std"))]
+
+use core::num;
+use lexical_core::{format, strategy, Format, WriteOptions};
+use proptest::prelude::*;
+
+/// Get the (write, parse) punctuation, which must not conflict with the format.
+fn punctuation(format: Format) -> impl Strategy<Value = (u8, u8)> {
+    let exponent = prop::sample::select(&b"e^@"[..]);
+    let decimal_point = prop::sample::select(&b".;"[..]);
+    (exponent, decimal_point).prop_filter("invalid punctuation", move |&(exponent, point)| {
+        format::is_valid_options_punctuation(format.packed(), exponent, point)
+    })
+}
+
+/// Get a number format with the punctuation for the float options.
+fn float_format() -> impl Strategy<Value = (Format, u8, u8)> {
+    strategy::format().prop_flat_map(|format| {
+        punctuation(format).prop_map(move |(exponent, point)| (format, exponent, point))
+    })
+}
+
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+mod floats {
+    use super::*;
+    use lexical_core::{ParseFloatOptions, WriteFloatOptions};
+
+    /// Get the write options from random bits, which never round the digits.
+    fn write_options(bits: u32, exponent: u8, point: u8) -> WriteFloatOptions {
+        let min_digits = num::NonZeroUsize::new((bits & 0x1F) as usize);
+        let positive_break = num::NonZeroI32::new(((bits >> 5) & 0x1F) as i32);
+        let negative_break = num::NonZeroI32::new(-(((bits >> 10) & 0x1F) as i32));
+        WriteFloatOptions::builder()
+            .min_significant_digits(min_digits)
+            .positive_exponent_break(positive_break)
+            .negative_exponent_break(negative_break)
+            .trim_floats(bits & (1 << 15) != 0)
+            .exponent(exponent)
+            .decimal_point(point)
+            .build()
+            .unwrap()
+    }
+
+    fn parse_options(exponent: u8, point: u8) -> ParseFloatOptions {
+        ParseFloatOptions::builder().exponent(exponent).decimal_point(point).build().unwrap()
+    }
+
+    macro_rules! roundtrip {
+        ($float:ident, $value:ident, $format:ident, $write:ident, $parse:ident) => {{
+            let mut buffer = vec![0u8; $write.buffer_size_with_format::<$float>(&$format)];
+            let written = lexical_core::write_with_format($value, &mut buffer, &$format, &$write);
+            let string = String::from_utf8(written.to_vec()).unwrap();
+            let parsed =
+                lexical_core::parse_with_format::<$float>(string.as_bytes(), &$format, &$parse);
+            // Negative zero is written without a sign by default, and floats
+            // in radixes that are not a power of two are approximate.
+            let radix = $format.mantissa_radix();
+            let is_exact = radix == 10 || radix.is_power_of_two();
+            let is_equal = |x: $float| {
+                x == $value
+                    || (!is_exact
+                        && approx::relative_eq!(x, $value, epsilon = 1e-6, max_relative = 3e-6))
+            };
+            prop_assert!(
+                parsed.map_or(false, is_equal),
+                "{:?} was written as {:?} and parsed as {:?}",
+                $value,
+                string,
+                parsed
+            );
+        }};
+    }
+
+    macro_rules! monotonic {
+        ($float:ident, $x:ident, $y:ident, $digits:ident, $format:ident, $parse:ident) => {{
+            let (x, y) = if $x <= $y {
+                ($x, $y)
+            } else {
+                ($y, $x)
+            };
+            let write = WriteFloatOptions::builder()
+                .max_significant_digits(num::NonZeroUsize::new($digits))
+                .exponent($parse.exponent())
+                .decimal_point($parse.decimal_point())
+                .build()
+                .unwrap();
+            let mut buffer = vec![0u8; write.buffer_size_with_format::<$float>(&$format)];
+            let written = lexical_core::write_with_format(x, &mut buffer, &$format, &write);
+            let lower = lexical_core::parse_with_format::<$float>(written, &$format, &$parse);
+            let written = lexical_core::write_with_format(y, &mut buffer, &$format, &write);
+            let upper = lexical_core::parse_with_format::<$float>(written, &$format, &$parse);
+            prop_assert!(lower.is_ok() && upper.is_ok());
+            prop_assert!(lower.unwrap() <= upper.unwrap(), "{:?} {:?}", lower, upper);
+        }};
+    }
+
+    proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn f32_roundtrip_proptest(
+            value in any::<f32>().prop_filter("finite", |x| x.is_finite()),
+            (format, exponent, point) in float_format(),
+            bits in any::<u32>(),
+        ) {
+            let write = write_options(bits, exponent, point);
+            let parse = parse_options(exponent, point);
+            roundtrip!(f32, value, format, write, parse);
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn f64_roundtrip_proptest(
+            value in any::<f64>().prop_filter("finite", |x| x.is_finite()),
+            (format, exponent, point) in float_format(),
+            bits in any::<u32>(),
+        ) {
+            let write = write_options(bits, exponent, point);
+            let parse = parse_options(exponent, point);
+            roundtrip!(f64, value, format, write, parse);
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn f64_monotonic_proptest(
+            x in any::<f64>().prop_filter("finite", |x| x.is_finite()),
+            y in any::<f64>().prop_filter("finite", |x| x.is_finite()),
+            digits in 1usize..20,
+            (format, exponent, point) in float_format(),
+        ) {
+            let parse = parse_options(exponent, point);
+            monotonic!(f64, x, y, digits, format, parse);
+        }
+    }
+}
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers"))]
+mod integers {
+    use super::*;
+    use lexical_core::{ParseIntegerOptions, WriteIntegerOptions};
+
+    macro_rules! roundtrip {
+        ($int:ident, $value:ident, $format:ident) => {{
+            let write = WriteIntegerOptions::new();
+            let parse = ParseIntegerOptions::new();
+            let mut buffer = vec![0u8; write.buffer_size_with_format::<$int>(&$format)];
+            let written = lexical_core::write_with_format($value, &mut buffer, &$format, &write);
+            let string = String::from_utf8(written.to_vec()).unwrap();
+            let parsed =
+                lexical_core::parse_with_format::<$int>(string.as_bytes(), &$format, &parse);
+            prop_assert_eq!(parsed, Ok($value), "{:?} was written as {:?}", $value, string);
+        }};
+    }
+
+    proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn i64_roundtrip_proptest(value in any::<i64>(), format in strategy::format()) {
+            roundtrip!(i64, value, format);
+        }
+
+        #[test]
+        #[cfg_attr(miri, ignore)]
+        fn u128_roundtrip_proptest(value in any::<u128>(), format in strategy::format()) {
+            roundtrip!(u128, value, format);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "write-floats")]
+
+use lexical_core::ryu;
+use proptest::prelude::*;
+
+#[test]
+fn format_test() {
+    let mut buffer = ryu::Buffer::new();
+    assert_eq!(buffer.format(0.0f64), "0.0");
+    assert_eq!(buffer.format(1.5f32), "1.5");
+    assert_eq!(buffer.format(-0.3f64), "-0.3");
+    assert_eq!(buffer.format(1e-7f64), "1.0e-7");
+    assert_eq!(buffer.format(f64::MAX), "1.7976931348623157e308");
+    assert_eq!(buffer.format(f32::MIN_POSITIVE), "1.1754944e-38");
+    assert_eq!(buffer.format(5e-324f64), "5.0e-324");
+    assert_eq!(buffer.format(f64::NAN), "NaN");
+    assert_eq!(buffer.format(f32::INFINITY), "inf");
+    assert_eq!(buffer.format(f64::NEG_INFINITY), "-inf");
+    assert_eq!(buffer.format_finite(2.5f64), "2.5");
+    assert_eq!(ryu::Buffer::default().format_finite(f64::NAN), "NaN");
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f64_roundtrip_proptest(f in any::<f64>()) {
+        let string = ryu::Buffer::new().format_finite(f).to_string();
+        prop_assume!(f.is_finite());
+        prop_assert_eq!(string.parse::<f64>(), Ok(f));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn f32_roundtrip_proptest(f in any::<f32>()) {
+        let string = ryu::Buffer::new().format(f).to_string();
+        prop_assume!(f.is_finite());
+        prop_assert_eq!(string.parse::<f32>(), Ok(f));
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+
+use lexical_core::smallest::{self, Smallest, Widening};
+use lexical_core::{Error, ParseFloatOptions, ParseIntegerOptions};
+
+fn parse(bytes: &[u8]) -> (Smallest, Widening) {
+    smallest::parse(bytes).unwrap()
+}
+
+#[test]
+fn integer_test() {
+    assert_eq!(parse(b"0"), (Smallest::U8(0), Widening::default()));
+    assert_eq!(parse(b"255"), (Smallest::U8(255), Widening::default()));
+    assert_eq!(parse(b"256").0, Smallest::U16(256));
+    assert_eq!(parse(b"65536").0, Smallest::U32(65536));
+    assert_eq!(parse(b"4294967296").0, Smallest::U64(4294967296));
+    assert_eq!(parse(b"18446744073709551615").0, Smallest::U64(u64::MAX));
+
+    let (value, widening) = parse(b"300");
+    assert_eq!(value, Smallest::U16(300));
+    assert!(widening.magnitude);
+    assert!(!widening.negative && !widening.not_integer);
+
+    let (value, widening) = parse(b"-1");
+    assert_eq!(value, Smallest::I64(-1));
+    assert!(widening.negative);
+    assert!(!widening.magnitude);
+    assert_eq!(parse(b"-9223372036854775808").0, Smallest::I64(i64::MIN));
+}
+
+#[test]
+fn float_test() {
+    let (value, widening) = parse(b"1.5");
+    assert_eq!(value, Smallest::F32(1.5));
+    assert!(widening.not_integer);
+    assert!(!widening.precision && !widening.inexact);
+
+    // Integers written with an exponent or fraction are floats.
+    assert_eq!(parse(b"1e3").0, Smallest::F32(1000.0));
+    assert_eq!(parse(b"1.0").0, Smallest::F32(1.0));
+
+    let (value, widening) = parse(b"16777217.5");
+    assert_eq!(value, Smallest::F64(16777217.5));
+    assert!(widening.precision);
+    assert!(!widening.inexact);
+
+    let (value, widening) = parse(b"0.1");
+    assert_eq!(value, Smallest::F64(0.1));
+    assert!(widening.precision && widening.inexact);
+
+    let (value, widening) = parse(b"inf");
+    assert_eq!(value, Smallest::F32(f32::INFINITY));
+    assert!(widening.not_integer);
+    assert!(matches!(parse(b"NaN").0, Smallest::F32(x) if x.is_nan()));
+}
+
+#[test]
+fn integer_overflow_test() {
+    let (value, widening) = parse(b"18446744073709551616");
+    assert_eq!(value, Smallest::F32(18446744073709551616.0));
+    assert!(widening.magnitude);
+    assert!(!widening.not_integer && !widening.negative);
+
+    let (value, widening) = parse(b"18446744073709551617");
+    assert_eq!(value, Smallest::F64(18446744073709551616.0));
+    assert!(widening.magnitude && widening.precision && widening.inexact);
+
+    let (value, widening) = parse(b"-9223372036854775809");
+    assert_eq!(value, Smallest::F64(-9223372036854775809.0));
+    assert!(widening.magnitude && widening.negative);
+    assert!(widening.precision && widening.inexact);
+}
+
+#[test]
+fn error_test() {
+    assert_eq!(smallest::parse(b""), Err(Error::Empty(0)));
+    assert_eq!(smallest::parse(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(smallest::parse(b"x"), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+fn options_test() {
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let integer_options = ParseIntegerOptions::new();
+    let float_options = ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    let result = smallest::parse_with_options::<FORMAT>(b"0,5", &integer_options, &float_options);
+    assert_eq!(result.unwrap().0, Smallest::F32(0.5));
+}
@@ -0,0 +1,124 @@
+//! Parse float literals in non-decimal radixes at compile time.
+
+#![cfg(feature = "power-of-two")]
+
+use core::num;
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+#[cfg(feature = "radix")]
+use lexical_util::format::Format;
+use lexical_util::format::NumberFormatBuilder;
+use proc_macro::Literal;
+
+/// Hexadecimal significant digits, with a binary exponent.
+const HEX: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(16))
+    .build();
+
+/// A float that can be written as a literal token.
+pub trait LiteralFloat: FromLexicalWithOptions<Options = Options> {
+    /// Get the sign and the literal for the magnitude of a finite float.
+    fn to_literal(self) -> Option<(bool, Literal)>;
+}
+
+macro_rules! literal_float_impl {
+    ($($t:ident $suffixed:ident ; )*) => ($(
+        impl LiteralFloat for $t {
+            #[inline]
+            fn to_literal(self) -> Option<(bool, Literal)> {
+                if self.is_finite() {
+                    Some((self.is_sign_negative(), Literal::$suffixed(self.abs())))
+                } else {
+                    None
+                }
+            }
+        }
+    )*);
+}
+
+literal_float_impl! {
+    f32 f32_suffixed ;
+    f64 f64_suffixed ;
+}
+
+/// Parse a hexadecimal float literal, such as `0x1.8p3`.
+///
+/// The literal has a required `0x` prefix, and an optional exponent
+/// after a `p`, which is a power of two written in decimal.
+pub fn parse_hex<F: LiteralFloat>(literal: &str) -> Result<(bool, Literal), String> {
+    let (sign, unsigned) = match literal.as_bytes().first() {
+        Some(&b'+') | Some(&b'-') => literal.split_at(1),
+        _ => ("", literal),
+    };
+    let digits = match unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+        Some(digits) => digits,
+        None => {
+            return Err(format!(
+                "invalid hexadecimal float literal `{}`: expected a `0x` prefix",
+                literal
+            ))
+        },
+    };
+    // The parser reads the exponent digits in the mantissa radix, so
+    // write the decimal exponent in hexadecimal.
+    let mut bytes = sign.to_string();
+    match digits.find(|c| c == 'p' || c == 'P') {
+        Some(index) => {
+            let exponent = match digits[index + 1..].parse::<i64>() {
+                Ok(exponent) => exponent,
+                Err(_) => {
+                    return Err(format!(
+                        "invalid hexadecimal float literal `{}`: invalid exponent",
+                        literal
+                    ))
+                },
+            };
+            let exponent_sign = if exponent < 0 {
+                "-"
+            } else {
+                ""
+            };
+            bytes.push_str(&digits[..index]);
+            bytes.push_str(&format!("p{}{:x}", exponent_sign, exponent.unsigned_abs()));
+        },
+        None => bytes.push_str(digits),
+    }
+    // This cannot fail, since `p` is not a hexadecimal digit.
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    match F::from_lexical_with_options::<HEX>(bytes.as_bytes(), &options) {
+        Ok(float) => float.to_literal().ok_or_else(|| {
+            format!("invalid hexadecimal float literal `{}`: the float is not finite", literal)
+        }),
+        Err(error) => Err(format!("invalid hexadecimal float literal `{}`: {}", literal, error)),
+    }
+}
+
+/// Parse a float literal in any radix from 2 to 36, such as `z.z`.
+///
+/// The exponent, if present, is a power of the radix written in the
+/// radix, after an `e` for radixes up to 14, and a `^` otherwise, since
+/// `e` is a digit in larger radixes.
+#[cfg(feature = "radix")]
+pub fn parse_radix<F: LiteralFloat>(radix: u32, literal: &str) -> Result<(bool, Literal), String> {
+    if !(2..=36).contains(&radix) {
+        return Err(format!("invalid radix `{}`: expected a radix from 2 to 36", radix));
+    }
+    let format = Format::new(NumberFormatBuilder::from_radix(radix as u8))
+        .map_err(|error| format!("invalid radix `{}`: {}", radix, error))?;
+    let exponent = if radix <= 14 {
+        b'e'
+    } else {
+        b'^'
+    };
+    // This cannot fail, since the exponent is not a digit in the radix.
+    let options = Options::builder().exponent(exponent).build().unwrap();
+    match F::from_lexical_with_format(literal.as_bytes(), &format, &options) {
+        Ok(float) => float.to_literal().ok_or_else(|| {
+            format!("invalid radix {} float literal `{}`: the float is not finite", radix, literal)
+        }),
+        Err(error) => {
+            Err(format!("invalid radix {} float literal `{}`: {}", radix, literal, error))
+        },
+    }
+}
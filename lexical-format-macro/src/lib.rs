@@ -0,0 +1,373 @@
+//! Compile-time number formats from a textual specification.
+//!
+//! [`number_format!`] expands to the packed `FORMAT` constant for a
+//! specification, validated at compile time, rather than assembling
+//! the format with a [`NumberFormatBuilder`] chain.
+//!
+//! ```rust
+//! # use lexical_format_macro::number_format;
+//! # use lexical_util::format::{NumberFormat, STANDARD};
+//! const FORMAT: u128 = number_format!("standard");
+//! assert_eq!(FORMAT, STANDARD);
+//! assert!(NumberFormat::<FORMAT> {}.is_valid());
+//! ```
+//!
+//! Invalid specifications are reported as compile errors:
+//!
+//! ```rust,compile_fail
+//! # use lexical_format_macro::number_format;
+//! const FORMAT: u128 = number_format!("standard, unknown");
+//! ```
+//!
+//! # Float Literals
+//!
+//! `hexf32!` and `hexf64!` parse hexadecimal float literals, and
+//! `basef32!` and `basef64!` parse float literals in any radix, at
+//! compile time. These expand to the correctly rounded float constant,
+//! so constants written in other radixes are not parsed at runtime.
+//!
+//! ```rust
+//! # #[cfg(feature = "radix")] {
+//! # use lexical_format_macro::{basef64, hexf32};
+//! const SCALE: f32 = hexf32!("0x1.8p3");
+//! assert_eq!(SCALE, 12.0);
+//! assert_eq!(basef64!(36, "z.i"), 35.5);
+//! # }
+//! ```
+//!
+//! # Features
+//!
+//! * `power-of-two` - Add support for power-of-two number formats, and hexadecimal float literals.
+//! * `radix` - Add support for number formats of any radix, and float literals in any radix.
+//! * `format` - Add support for custom number formats.
+//!
+//! These must match the features enabled for lexical, so the same
+//! formats are valid at compile time and when converting numbers.
+//!
+//! # Version Support
+//!
+//! The minimum, standard, required version is 1.51.0, for const generic
+//! support. Older versions of lexical support older Rust versions.
+//!
+//! [`NumberFormatBuilder`]: https://docs.rs/lexical-util/latest/lexical_util/format/struct.NumberFormatBuilder.html
+
+#![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
+
+extern crate proc_macro;
+
+mod float;
+
+use lexical_util::error::Error;
+use lexical_util::format::FormatSpec;
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Create the packed number format from a specification at compile time.
+///
+/// The specification is a string literal with the language parsed by
+/// `FormatSpec`, such as `"json, digit_separator='_', no_special"`.
+/// Only the number format may be specified: punctuation and special
+/// strings, such as `decimal_point`, belong to the options API.
+///
+/// ```rust
+/// # use lexical_format_macro::number_format;
+/// const FORMAT: u128 = number_format!("standard");
+/// ```
+#[proc_macro]
+pub fn number_format(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let (literal, span) = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => {
+            let span = literal.span();
+            (literal, span)
+        },
+        // Literals from macro expansions may be wrapped in an undelimited group.
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => {
+            return number_format(group.stream());
+        },
+        (Some(token), _) => {
+            return compile_error("expected a string literal", token.span());
+        },
+        (None, _) => return compile_error("expected a string literal", Span::call_site()),
+    };
+    let spec = match unquote(&literal.to_string()) {
+        Some(spec) => spec,
+        None => return compile_error("expected a string literal", span),
+    };
+    match parse_format(&spec) {
+        Ok(format) => {
+            let mut literal = Literal::u128_suffixed(format);
+            literal.set_span(span);
+            TokenTree::Literal(literal).into()
+        },
+        Err(message) => compile_error(&message, span),
+    }
+}
+
+/// Create an `f32` constant from a hexadecimal float literal at compile time.
+///
+/// The literal is a string with an optional sign, a required `0x` prefix,
+/// the hexadecimal significant digits, and an optional exponent after a
+/// `p`, which is a power of two written in decimal. Literals that are not
+/// exactly representable are rounded to the nearest float, and literals
+/// that overflow to infinity are rejected.
+///
+/// ```rust
+/// # use lexical_format_macro::hexf32;
+/// assert_eq!(hexf32!("0x1.8p3"), 12.0);
+/// assert_eq!(hexf32!("-0x1p-2"), -0.25);
+/// ```
+///
+/// Invalid literals are reported as compile errors:
+///
+/// ```rust,compile_fail
+/// # use lexical_format_macro::hexf32;
+/// let value = hexf32!("1.8p3");
+/// ```
+#[cfg(feature = "power-of-two")]
+#[proc_macro]
+pub fn hexf32(input: TokenStream) -> TokenStream {
+    hex_float::<f32>(input)
+}
+
+/// Create an `f64` constant from a hexadecimal float literal at compile time.
+///
+/// This is the same as [`hexf32!`], for an `f64`.
+///
+/// ```rust
+/// # use lexical_format_macro::hexf64;
+/// assert_eq!(hexf64!("0x1.fffffffffffffp1023"), f64::MAX);
+/// ```
+#[cfg(feature = "power-of-two")]
+#[proc_macro]
+pub fn hexf64(input: TokenStream) -> TokenStream {
+    hex_float::<f64>(input)
+}
+
+/// Create an `f32` constant from a float literal in any radix at compile time.
+///
+/// The arguments are the radix, from 2 to 36, and a string with an
+/// optional sign, the significant digits in the radix, and an optional
+/// exponent. The exponent is a power of the radix written in the radix,
+/// after an `e` for radixes up to 14, and after a `^` otherwise. Literals
+/// that are not exactly representable are rounded to the nearest float,
+/// and literals that overflow to infinity are rejected.
+///
+/// ```rust
+/// # use lexical_format_macro::basef32;
+/// assert_eq!(basef32!(2, "-101.1"), -5.5);
+/// assert_eq!(basef32!(36, "z^1"), 1260.0);
+/// ```
+#[cfg(feature = "radix")]
+#[proc_macro]
+pub fn basef32(input: TokenStream) -> TokenStream {
+    radix_float::<f32>(input)
+}
+
+/// Create an `f64` constant from a float literal in any radix at compile time.
+///
+/// This is the same as [`basef32!`], for an `f64`.
+///
+/// ```rust
+/// # use lexical_format_macro::basef64;
+/// assert_eq!(basef64!(3, "0.1"), 1.0 / 3.0);
+/// ```
+#[cfg(feature = "radix")]
+#[proc_macro]
+pub fn basef64(input: TokenStream) -> TokenStream {
+    radix_float::<f64>(input)
+}
+
+/// Expand a hexadecimal float literal to the float constant.
+#[cfg(feature = "power-of-two")]
+fn hex_float<F: float::LiteralFloat>(input: TokenStream) -> TokenStream {
+    let literals = match literal_arguments(input, 1, "expected a string literal") {
+        Ok(literals) => literals,
+        Err(error) => return error,
+    };
+    let span = literals[0].span();
+    match unquote(&literals[0].to_string()) {
+        Some(literal) => float_constant(float::parse_hex::<F>(&literal), span),
+        None => compile_error("expected a string literal", span),
+    }
+}
+
+/// Expand a float literal in any radix to the float constant.
+#[cfg(feature = "radix")]
+fn radix_float<F: float::LiteralFloat>(input: TokenStream) -> TokenStream {
+    let usage = "expected a radix and a string literal";
+    let literals = match literal_arguments(input, 2, usage) {
+        Ok(literals) => literals,
+        Err(error) => return error,
+    };
+    let radix = match unsuffix(&literals[0].to_string()).parse::<u32>() {
+        Ok(radix) => radix,
+        Err(_) => return compile_error("expected an integer radix", literals[0].span()),
+    };
+    let span = literals[1].span();
+    match unquote(&literals[1].to_string()) {
+        Some(literal) => float_constant(float::parse_radix::<F>(radix, &literal), span),
+        None => compile_error(usage, span),
+    }
+}
+
+/// Create the tokens for a float constant, or the error message.
+///
+/// The sign and literal are wrapped in an undelimited group, so the
+/// constant has the precedence of a single expression.
+#[cfg(feature = "power-of-two")]
+fn float_constant(result: Result<(bool, Literal), String>, span: Span) -> TokenStream {
+    let (is_negative, mut literal) = match result {
+        Ok(float) => float,
+        Err(message) => return compile_error(&message, span),
+    };
+    literal.set_span(span);
+    let mut tokens = Vec::new();
+    if is_negative {
+        let mut punct = Punct::new('-', Spacing::Alone);
+        punct.set_span(span);
+        tokens.push(TokenTree::Punct(punct));
+    }
+    tokens.push(TokenTree::Literal(literal));
+    let mut group = Group::new(Delimiter::None, tokens.into_iter().collect());
+    group.set_span(span);
+    TokenTree::Group(group).into()
+}
+
+/// Get the literal arguments, separated by commas, from the macro input.
+///
+/// Literals from macro expansions may be wrapped in undelimited groups.
+#[cfg(feature = "power-of-two")]
+fn literal_arguments(
+    input: TokenStream,
+    count: usize,
+    usage: &str,
+) -> Result<Vec<Literal>, TokenStream> {
+    let mut tokens = Vec::new();
+    flatten(input, &mut tokens);
+    let mut literals = Vec::new();
+    let mut iter = tokens.into_iter();
+    while let Some(token) = iter.next() {
+        match token {
+            TokenTree::Literal(literal) if literals.len() < count => literals.push(literal),
+            token => return Err(compile_error(usage, token.span())),
+        }
+        match iter.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => (),
+            Some(token) => return Err(compile_error(usage, token.span())),
+            None => break,
+        }
+    }
+    if literals.len() == count {
+        Ok(literals)
+    } else {
+        Err(compile_error(usage, Span::call_site()))
+    }
+}
+
+/// Flatten the tokens in undelimited groups.
+#[cfg(feature = "power-of-two")]
+fn flatten(input: TokenStream, tokens: &mut Vec<TokenTree>) {
+    for token in input {
+        match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                flatten(group.stream(), tokens)
+            },
+            token => tokens.push(token),
+        }
+    }
+}
+
+/// Remove the type suffix from an integer literal, such as `36u32`.
+#[cfg(feature = "radix")]
+fn unsuffix(literal: &str) -> &str {
+    match literal.find(|c| c == 'u' || c == 'i') {
+        Some(index) => &literal[..index],
+        None => literal,
+    }
+}
+
+/// Parse the packed number format from the specification.
+///
+/// Returns a readable error message if the specification is invalid.
+fn parse_format(spec: &str) -> Result<u128, String> {
+    let error = match FormatSpec::parse(spec) {
+        Ok(parsed) if has_options(&parsed) => {
+            return Err(format!(
+                "invalid number format `{}`: punctuation and special strings must be set in the options",
+                spec
+            ));
+        },
+        Ok(parsed) => return Ok(parsed.format().packed()),
+        Err(error) => error,
+    };
+    match error {
+        Error::InvalidFormatSpec(index) => Err(format!(
+            "invalid number format `{}`: {}\n  {}\n  {}^",
+            spec,
+            error,
+            spec,
+            " ".repeat(spec[..index].chars().count())
+        )),
+        _ => Err(format!("invalid number format `{}`: {}", spec, error)),
+    }
+}
+
+/// Determine if any options, rather than the number format, were specified.
+fn has_options(spec: &FormatSpec) -> bool {
+    spec.decimal_point().is_some()
+        || spec.exponent().is_some()
+        || spec.nan_string().is_some()
+        || spec.inf_string().is_some()
+        || spec.infinity_string().is_some()
+}
+
+/// Get the value of a string literal from its source representation.
+///
+/// Only the simple character escapes are supported, since the
+/// specification language is ASCII.
+fn unquote(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = &raw[hashes..raw.len().checked_sub(hashes)?];
+        return Some(raw.strip_prefix('"')?.strip_suffix('"')?.to_string());
+    }
+
+    let mut chars = literal.strip_prefix('"')?.strip_suffix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => value.push('\n'),
+            'r' => value.push('\r'),
+            't' => value.push('\t'),
+            '0' => value.push('\0'),
+            c @ '\\' | c @ '\'' | c @ '"' => value.push(c),
+            '\n' => {
+                // Line continuation: skip the leading whitespace of the next line.
+                let rest = chars.as_str().trim_start();
+                chars = rest.chars();
+            },
+            _ => return None,
+        }
+    }
+    Some(value)
+}
+
+/// Create a `compile_error!` invocation with the message.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut punct = Punct::new('!', Spacing::Alone);
+    punct.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+    group.set_span(span);
+    let tokens = vec![
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(punct),
+        TokenTree::Group(group),
+    ];
+    tokens.into_iter().collect()
+}
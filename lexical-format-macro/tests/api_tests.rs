@@ -0,0 +1,81 @@
+use lexical_format_macro::number_format;
+use lexical_util::format::{NumberFormat, STANDARD};
+
+#[test]
+fn standard_test() {
+    assert_eq!(number_format!(""), STANDARD);
+    assert_eq!(number_format!("standard"), STANDARD);
+    assert_eq!(number_format!(r#"Standard"#), STANDARD);
+    const FORMAT: u128 = number_format!("standard");
+    assert!(NumberFormat::<FORMAT> {}.is_valid());
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    assert_eq!(number_format!("radix=16"), HEX);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    use lexical_util::format::{NumberFormatBuilder, JSON};
+
+    const SEPARATOR: u128 = NumberFormatBuilder::from_packed(JSON)
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .no_special(true)
+        .build();
+    assert_eq!(number_format!("json"), JSON);
+    assert_eq!(
+        number_format!("json, digit_separator = \"_\", internal_digit_separator, no_special"),
+        SEPARATOR
+    );
+    assert_eq!(
+        number_format!(
+            "json, digit_separator='_', \
+             internal_digit_separator, no_special=true"
+        ),
+        SEPARATOR
+    );
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn hex_float_test() {
+    use lexical_format_macro::{hexf32, hexf64};
+
+    const SCALE: f32 = hexf32!("0x1.8p3");
+    assert_eq!(SCALE, 12.0);
+    assert_eq!(hexf32!("0X1P-2"), 0.25);
+    assert_eq!(hexf32!("+0x.8"), 0.5);
+    assert_eq!(hexf32!("-0xff"), -255.0);
+    assert_eq!(hexf32!("0x1p-149"), f32::from_bits(1));
+    assert_eq!(hexf32!("0x1.fffffep127"), f32::MAX);
+    assert_eq!(hexf64!("0x1.fffffffffffffp1023"), f64::MAX);
+    assert_eq!(hexf64!("0x1.0000000000000fp0"), 1.0 + f64::EPSILON);
+    assert_eq!(hexf64!(r"0x1.921fb54442d18p1"), core::f64::consts::PI);
+
+    let zero = hexf64!("-0x0p0");
+    assert_eq!(zero, 0.0);
+    assert!(zero.is_sign_negative());
+    // The sign is part of the constant.
+    assert_eq!(hexf64!("-0x1p2").abs(), 4.0);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_float_test() {
+    use lexical_format_macro::{basef32, basef64};
+
+    assert_eq!(basef32!(2, "-101.1"), -5.5);
+    assert_eq!(basef32!(36, "z.i"), 35.5);
+    assert_eq!(basef32!(36u32, "z^1"), 1260.0);
+    assert_eq!(basef64!(10, "1.5e3"), 1500.0);
+    assert_eq!(basef64!(3, "0.1"), 1.0 / 3.0);
+    assert_eq!(basef64!(12, "0.6",), 0.5);
+    assert_eq!(basef64!(7, "1e-10"), 7f64.powi(-7));
+}
@@ -0,0 +1,568 @@
+//! Generate the pre-computed tables for the radixes listed at build time.
+//!
+//! With the `build-tables` feature, this replaces the checked-in tables
+//! for non-decimal radixes with tables generated only for the radixes
+//! listed in the `LEXICAL_PARSE_FLOAT_RADIXES` environment variable, as
+//! a comma-separated list, and the radixes with an enabled `radix-N`
+//! feature. If no radixes are listed or enabled, or if the `radix`
+//! feature is enabled, tables are generated for every radix, since
+//! features must only add support for radixes. Decimal and power-of-two
+//! radixes always use their dedicated tables, and are always supported.
+//!
+//! Radixes from 2 to 62 are supported. The checked-in tables only cover
+//! radixes up to 36, so radixes from 37 to 62, which use case-sensitive
+//! digits, `0-9a-zA-Z`, are only supported if listed, even with `radix`.
+//!
+//! This generates the same values as `etc/powers_table.py` and
+//! `etc/bellerophon_table.py`, using a minimal big integer so the build
+//! script has no dependencies.
+
+use std::cmp::Ordering;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Environment variable for the radixes to generate tables for.
+const RADIXES_VAR: &str = "LEXICAL_PARSE_FLOAT_RADIXES";
+
+/// The cfg for 64-bit limbs, matching `bigint::Limb`.
+const LIMB64_CFG: &str =
+    r#"all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))"#;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={}", RADIXES_VAR);
+    if env::var_os("CARGO_FEATURE_BUILD_TABLES").is_none() {
+        return;
+    }
+
+    let features: Vec<u32> = (2..=36).filter(|&radix| is_radix_feature(radix)).collect();
+    let mut radixes = parse_radixes(env::var(RADIXES_VAR).ok(), &features);
+    if env::var_os("CARGO_FEATURE_RADIX").is_some() {
+        // Another crate requires every radix up to 36, so we cannot remove
+        // any tables, but still add the listed radixes above 36.
+        radixes.extend(parse_radixes(None, &[]));
+        radixes.sort_unstable();
+        radixes.dedup();
+    }
+    let out_dir = env::var("OUT_DIR").expect("cargo must set OUT_DIR");
+    let out_dir = Path::new(&out_dir);
+    write_file(&out_dir.join("table_radix.rs"), &small_tables(&radixes));
+    write_file(&out_dir.join("table_bellerophon_radix.rs"), &bellerophon_tables(&radixes));
+}
+
+fn write_file(path: &Path, contents: &str) {
+    fs::write(path, contents)
+        .unwrap_or_else(|err| panic!("unable to write {}: {}", path.display(), err));
+}
+
+/// Determine if the `radix-N` feature is enabled for the radix.
+fn is_radix_feature(radix: u32) -> bool {
+    env::var_os(format!("CARGO_FEATURE_RADIX_{}", radix)).is_some()
+}
+
+/// Parse the listed radixes and the radixes with an enabled feature,
+/// excluding those with dedicated tables.
+///
+/// If no radixes are listed or enabled, this is every radix up to 36.
+fn parse_radixes(value: Option<String>, features: &[u32]) -> Vec<u32> {
+    let mut radixes: Vec<u32> = match value {
+        Some(value) if !value.trim().is_empty() => value
+            .split(',')
+            .map(str::trim)
+            .filter(|radix| !radix.is_empty())
+            .map(|radix| {
+                radix.parse().unwrap_or_else(|_| {
+                    panic!("{}: invalid radix \"{}\", expected an integer", RADIXES_VAR, radix)
+                })
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    radixes.extend_from_slice(features);
+    if radixes.is_empty() {
+        radixes = (2..=36).collect();
+    }
+    for &radix in radixes.iter() {
+        if !(2..=62).contains(&radix) {
+            panic!(
+                "{}: unsupported radix {}, only radixes from 2 to 62 are supported",
+                RADIXES_VAR, radix
+            );
+        }
+    }
+    radixes.retain(|&radix| radix != 10 && !radix.is_power_of_two());
+    radixes.sort_unstable();
+    radixes.dedup();
+    radixes
+}
+
+// BIGINT
+// ------
+
+/// Minimal, unsigned big integer with 32-bit little-endian limbs.
+#[derive(Clone, PartialEq, Eq)]
+struct Big(Vec<u32>);
+
+impl Big {
+    fn one() -> Self {
+        Big(vec![1])
+    }
+
+    fn pow(base: u32, exp: u32) -> Self {
+        let mut value = Big::one();
+        for _ in 0..exp {
+            value.mul_small(base);
+        }
+        value
+    }
+
+    fn mul_small(&mut self, y: u32) {
+        let mut carry = 0u64;
+        for limb in self.0.iter_mut() {
+            let value = *limb as u64 * y as u64 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            self.0.push(carry as u32);
+        }
+    }
+
+    fn bit_length(&self) -> u32 {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            Some(index) => index as u32 * 32 + 32 - self.0[index].leading_zeros(),
+            None => 0,
+        }
+    }
+
+    fn bit(&self, index: u32) -> bool {
+        let limb = self.0.get((index / 32) as usize).copied().unwrap_or(0);
+        limb & (1 << (index % 32)) != 0
+    }
+
+    /// Get the bits `[start, start + 64)` as a native integer.
+    fn bits64(&self, start: u32) -> u64 {
+        (0..64).filter(|&i| self.bit(start + i)).fold(0, |acc, i| acc | 1 << i)
+    }
+
+    fn shl1(&mut self, bit: bool) {
+        let mut carry = bit as u32;
+        for limb in self.0.iter_mut() {
+            let next = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            self.0.push(carry);
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        let length = self.0.len().max(other.0.len());
+        for index in (0..length).rev() {
+            let x = self.0.get(index).copied().unwrap_or(0);
+            let y = other.0.get(index).copied().unwrap_or(0);
+            match x.cmp(&y) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn sub(&mut self, other: &Self) {
+        let mut borrow = 0i64;
+        for (index, limb) in self.0.iter_mut().enumerate() {
+            let y = other.0.get(index).copied().unwrap_or(0) as i64;
+            let value = *limb as i64 - y - borrow;
+            borrow = (value < 0) as i64;
+            *limb = value.rem_euclid(1 << 32) as u32;
+        }
+        debug_assert_eq!(borrow, 0);
+    }
+
+    /// Calculate `floor(2^exp / self)`, which must fit in 64 bits.
+    fn reciprocal64(&self, exp: u32) -> u64 {
+        let mut rem = Big(vec![0]);
+        let mut quotient = 0u128;
+        for index in (0..=exp).rev() {
+            rem.shl1(index == exp);
+            quotient <<= 1;
+            if rem.compare(self) != Ordering::Less {
+                rem.sub(self);
+                quotient |= 1;
+            }
+        }
+        assert!(quotient <= u64::MAX as u128, "reciprocal must fit in 64 bits");
+        quotient as u64
+    }
+
+    fn limbs32(&self) -> Vec<u64> {
+        let length = self.0.iter().rposition(|&limb| limb != 0).map_or(0, |index| index + 1);
+        self.0[..length].iter().map(|&limb| limb as u64).collect()
+    }
+
+    fn limbs64(&self) -> Vec<u64> {
+        self.limbs32()
+            .chunks(2)
+            .map(|chunk| chunk[0] | chunk.get(1).map_or(0, |&hi| hi << 32))
+            .collect()
+    }
+}
+
+// LIMITS
+// ------
+
+/// Remove the power-of-two factors from the radix.
+fn odd_part(radix: u32) -> u32 {
+    radix >> radix.trailing_zeros()
+}
+
+/// The largest power of the radix that fits in a `u64`.
+fn u64_power_limit(radix: u32) -> u32 {
+    let mut exp = 0;
+    let mut value = 1u64;
+    while let Some(next) = value.checked_mul(radix as u64) {
+        value = next;
+        exp += 1;
+    }
+    exp
+}
+
+/// The largest power of the radix exactly representable by a float.
+fn exponent_limit(radix: u32, mantissa_bits: u32) -> u32 {
+    let max = 1u64 << mantissa_bits;
+    let odd = odd_part(radix) as u64;
+    let mut exp = 0;
+    let mut value = odd;
+    while value <= max {
+        value *= odd;
+        exp += 1;
+    }
+    exp
+}
+
+/// Exact float power of a radix, which must be representable.
+fn float_power(radix: u32, exp: u32) -> f64 {
+    (0..exp).fold(1.0, |acc, _| acc * radix as f64)
+}
+
+// SMALL TABLES
+// ------------
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn small_tables(radixes: &[u32]) -> String {
+    // The slow path algorithms need integer and large powers of the odd
+    // factors of each radix, and the powers-of-5 are always present for
+    // decimal strings.
+    let mut odd: Vec<u32> = radixes.iter().map(|&radix| odd_part(radix)).collect();
+    odd.retain(|&radix| radix != 5);
+    odd.sort_unstable();
+    odd.dedup();
+    let mut int_radixes: Vec<u32> = radixes.iter().chain(odd.iter()).copied().collect();
+    int_radixes.retain(|&radix| radix != 5);
+    int_radixes.sort_unstable();
+    int_radixes.dedup();
+
+    let mut out = String::from("// Generated by `build.rs`: do not edit.\n\n");
+    let mut limits = Vec::new();
+    if !radixes.is_empty() {
+        limits.extend_from_slice(&["f32_exponent_limit", "f64_exponent_limit"]);
+    }
+    if !int_radixes.is_empty() {
+        limits.extend_from_slice(&["f64_mantissa_limit", "u64_power_limit"]);
+    }
+    if !limits.is_empty() {
+        writeln!(out, "use crate::limits::{{{}}};", limits.join(", ")).unwrap();
+        out.push_str("use static_assertions::const_assert;\n");
+    }
+
+    // Decimal and power-of-two radixes use the getters for their dedicated tables.
+    let dispatch = |out: &mut String, name: &str, ty: &str, getters: &[u32], tables: &[u32]| {
+        let (kind, table) = match ty {
+            "u64" => ("int", "SMALL_INT_POW"),
+            "f32" => ("f32", "SMALL_F32_POW"),
+            _ => ("f64", "SMALL_F64_POW"),
+        };
+        writeln!(out, "\n/// Get lookup table for small {} powers.", kind).unwrap();
+        out.push_str("///\n/// # Safety\n///\n");
+        out.push_str("/// Safe as long as the radix provided is valid, and exponent is smaller\n");
+        out.push_str("/// than the table for the radix.\n#[inline]\n");
+        writeln!(out, "pub unsafe fn {}(exponent: usize, radix: u32) -> {} {{", name, ty).unwrap();
+        out.push_str("    debug_assert_radix(radix);\n    unsafe {\n        match radix {\n");
+        for &radix in getters {
+            writeln!(out, "            {} => {}{}(exponent),", radix, name, radix).unwrap();
+        }
+        for &radix in tables {
+            writeln!(
+                out,
+                "            {} => index_unchecked!({}{}[exponent]),",
+                radix, table, radix
+            )
+            .unwrap();
+        }
+        out.push_str("            _ => unreachable_unchecked!(),\n        }\n    }\n}\n");
+    };
+    let getters = [2, 4, 8, 10, 16, 32];
+    dispatch(&mut out, "get_small_int_power", "u64", &[2, 4, 5, 8, 10, 16, 32], &int_radixes);
+    dispatch(&mut out, "get_small_f32_power", "f32", &getters, radixes);
+    dispatch(&mut out, "get_small_f64_power", "f64", &getters, radixes);
+
+    out.push_str("\n/// Get pre-computed power for a large power of radix.\n");
+    out.push_str("#[cfg(not(feature = \"lazy-tables\"))]\n");
+    if odd.is_empty() {
+        out.push_str("pub const fn get_large_int_power(_: u32) -> (&'static [Limb], u32) {\n");
+        out.push_str("    (&LARGE_POW5, LARGE_POW5_STEP)\n}\n");
+    } else {
+        out.push_str("pub const fn get_large_int_power(radix: u32) -> (&'static [Limb], u32) {\n");
+        out.push_str("    match radix {\n");
+        for &radix in odd.iter() {
+            writeln!(out, "        {0} => (&LARGE_POW{0}, LARGE_POW{0}_STEP),", radix).unwrap();
+        }
+        out.push_str("        // Remaining radix: must be 5.\n");
+        out.push_str("        _ => (&LARGE_POW5, LARGE_POW5_STEP),\n    }\n}\n");
+    }
+
+    for &radix in int_radixes.iter() {
+        let max_exp = u64_power_limit(radix);
+        let values: Vec<u64> = (0..=max_exp).map(|exp| (radix as u64).pow(exp)).collect();
+        writeln!(out, "\n/// Pre-computed, small powers-of-{}.", radix).unwrap();
+        writeln!(
+            out,
+            "pub const SMALL_INT_POW{}: [u64; {}] = [{}];",
+            radix,
+            values.len(),
+            join(&values)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "const_assert!(SMALL_INT_POW{0}.len() > f64_mantissa_limit({0}) as usize);",
+            radix
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "const_assert!(SMALL_INT_POW{0}.len() == u64_power_limit({0}) as usize + 1);",
+            radix
+        )
+        .unwrap();
+    }
+
+    for &radix in radixes {
+        let max_exp = exponent_limit(radix, 24);
+        let values: Vec<String> =
+            (0..=max_exp).map(|exp| format!("{:?}", float_power(radix, exp))).collect();
+        writeln!(out, "\n/// Pre-computed, small powers-of-{}.", radix).unwrap();
+        writeln!(
+            out,
+            "pub const SMALL_F32_POW{}: [f32; {}] = [{}];",
+            radix,
+            values.len(),
+            join(&values)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "const_assert!(SMALL_F32_POW{0}.len() > f32_exponent_limit({0}).1 as usize);",
+            radix
+        )
+        .unwrap();
+
+        let max_exp = exponent_limit(radix, 53);
+        let values: Vec<String> =
+            (0..=max_exp).map(|exp| format!("{:?}", float_power(radix, exp))).collect();
+        writeln!(out, "\n/// Pre-computed, small powers-of-{}.", radix).unwrap();
+        writeln!(
+            out,
+            "pub const SMALL_F64_POW{}: [f64; {}] = [{}];",
+            radix,
+            values.len(),
+            join(&values)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "const_assert!(SMALL_F64_POW{0}.len() > f64_exponent_limit({0}).1 as usize);",
+            radix
+        )
+        .unwrap();
+    }
+
+    for &radix in odd.iter() {
+        let step = 5 * u64_power_limit(radix);
+        let power = Big::pow(radix, step);
+        let limbs32 = power.limbs32();
+        let limbs64 = power.limbs64();
+        writeln!(out, "\n/// Pre-computed large power-of-{} for 32-bit limbs.", radix).unwrap();
+        writeln!(out, "#[cfg(not({}))]", LIMB64_CFG).unwrap();
+        writeln!(
+            out,
+            "pub const LARGE_POW{}: [u32; {}] = [{}];",
+            radix,
+            limbs32.len(),
+            join(&limbs32)
+        )
+        .unwrap();
+        writeln!(out, "\n/// Pre-computed large power-of-{} for 64-bit limbs.", radix).unwrap();
+        writeln!(out, "#[cfg({})]", LIMB64_CFG).unwrap();
+        writeln!(
+            out,
+            "pub const LARGE_POW{}: [u64; {}] = [{}];",
+            radix,
+            limbs64.len(),
+            join(&limbs64)
+        )
+        .unwrap();
+        writeln!(out, "\n/// Step for large power-of-{}.", radix).unwrap();
+        writeln!(out, "pub const LARGE_POW{}_STEP: u32 = {};", radix, step).unwrap();
+    }
+
+    out
+}
+
+// BELLEROPHON TABLES
+// ------------------
+
+/// Normalized, truncated 64-bit mantissa and binary exponent of `radix^exp`.
+fn normalize(radix: u32, exp: i32) -> (u64, i32) {
+    let power = Big::pow(radix, exp.unsigned_abs());
+    let bits = power.bit_length() as i32;
+    if exp >= 0 && bits <= 64 {
+        (power.bits64(0) << (64 - bits), bits - 64)
+    } else if exp >= 0 {
+        (power.bits64((bits - 64) as u32), bits - 64)
+    } else {
+        // `1 / radix^n` is never a power of two, so it's in `(2^-bits, 2^(1-bits))`.
+        (power.reciprocal64((bits + 63) as u32), -bits - 63)
+    }
+}
+
+/// Find the log2 multiplier and shift to calculate the binary exponents.
+fn log2_multiplier(radix: u32, exps: &[(i32, i32)]) -> (i64, i32) {
+    for &shift in [16, 32].iter() {
+        let log2 = ((radix as f64).log2() * (1u64 << shift) as f64).ceil() as i64;
+        if exps.iter().all(|&(exp, binary)| -63 + ((log2 * exp as i64) >> shift) == binary as i64) {
+            return (log2, shift);
+        }
+    }
+    panic!("unable to find a log2 multiplier for radix {}", radix);
+}
+
+fn bellerophon_tables(radixes: &[u32]) -> String {
+    let mut out = String::from("// Generated by `build.rs`: do not edit.\n\n");
+    out.push_str("/// Determine if the radix has pre-computed tables.\n#[inline(always)]\n");
+    out.push_str("pub const fn is_table_radix(radix: u32) -> bool {\n");
+    let mut supported = radixes.to_vec();
+    supported.extend_from_slice(&[2, 4, 8, 10, 16, 32]);
+    supported.sort_unstable();
+    // Merge consecutive radixes into ranges.
+    let mut patterns: Vec<String> = Vec::new();
+    let mut index = 0;
+    while index < supported.len() {
+        let start = supported[index];
+        while index + 1 < supported.len() && supported[index + 1] == supported[index] + 1 {
+            index += 1;
+        }
+        match supported[index] {
+            end if end == start => patterns.push(start.to_string()),
+            end => patterns.push(format!("{}..={}", start, end)),
+        }
+        index += 1;
+    }
+    writeln!(out, "    matches!(radix, {})\n}}", patterns.join(" | ")).unwrap();
+
+    out.push_str("\n/// Get Bellerophon powers from radix.\n#[inline]\n");
+    out.push_str("#[cfg(not(feature = \"lazy-tables\"))]\n");
+    out.push_str("pub const fn bellerophon_powers(radix: u32) -> &'static BellerophonPowers {\n");
+    out.push_str("    match radix {\n");
+    for &radix in radixes {
+        writeln!(out, "        {0} => &BASE{0}_POWERS,", radix).unwrap();
+    }
+    out.push_str("        #[cfg(feature = \"compact\")]\n        10 => &BASE10_POWERS,\n");
+    out.push_str("        // Fallback if we have an invalid radix.\n");
+    out.push_str("        _ => &INVALID_POWERS,\n    }\n}\n");
+
+    out.push_str("\npub const INVALID_POWERS: BellerophonPowers = BellerophonPowers {\n");
+    out.push_str("    small: &[],\n    large: &[],\n    small_int: &[],\n    step: 0,\n");
+    out.push_str("    bias: 0,\n    log2: 0,\n    log2_shift: 0,\n};\n");
+
+    // The smallest and largest exponents required for an f64.
+    let min_value = (5e-324f64).ln();
+    let max_value = f64::MAX.ln();
+    let mantissa = (u64::MAX as f64).ln();
+    for &radix in radixes {
+        let ln_radix = (radix as f64).ln();
+        let min_exp = (min_value / ln_radix - mantissa / ln_radix).floor() as i32;
+        let max_exp = (max_value / ln_radix).ceil() as i32;
+        let mut step = 0;
+        while (radix as u64).pow(step + 1) <= 10_000_000_000 {
+            step += 1;
+        }
+        let step = step as i32;
+
+        let mut large_exps = vec![0];
+        let mut exp = -step;
+        while exp > min_exp - step {
+            large_exps.insert(0, exp);
+            exp -= step;
+        }
+        let mut exp = step;
+        while exp < max_exp {
+            large_exps.push(exp);
+            exp += step;
+        }
+        let bias = -large_exps[0];
+
+        let small: Vec<(i32, u64, i32)> = (0..step)
+            .map(|exp| {
+                let (mant, binary) = normalize(radix, exp);
+                (exp, mant, binary)
+            })
+            .collect();
+        let large: Vec<(i32, u64, i32)> = large_exps
+            .iter()
+            .map(|&exp| {
+                let (mant, binary) = normalize(radix, exp);
+                (exp, mant, binary)
+            })
+            .collect();
+        let exps: Vec<(i32, i32)> =
+            small.iter().chain(large.iter()).map(|&(exp, _, binary)| (exp, binary)).collect();
+        let (log2, log2_shift) = log2_multiplier(radix, &exps);
+
+        writeln!(out, "\npub const BASE{}_POWERS: BellerophonPowers = BellerophonPowers {{", radix)
+            .unwrap();
+        writeln!(out, "    small: &BASE{}_SMALL_MANTISSA,", radix).unwrap();
+        writeln!(out, "    large: &BASE{}_LARGE_MANTISSA,", radix).unwrap();
+        writeln!(out, "    small_int: &BASE{}_SMALL_INT_POWERS,", radix).unwrap();
+        writeln!(out, "    step: {},\n    bias: {},", step, bias).unwrap();
+        writeln!(out, "    log2: {},\n    log2_shift: {},\n}};", log2, log2_shift).unwrap();
+
+        let table = |out: &mut String, name: &str, values: &[(i32, u64, i32)]| {
+            writeln!(out, "\nconst BASE{}_{}: [u64; {}] = [", radix, name, values.len()).unwrap();
+            for &(exp, mant, _) in values {
+                writeln!(out, "    {}, // {}^{}", mant, radix, exp).unwrap();
+            }
+            out.push_str("];\n");
+        };
+        table(&mut out, "SMALL_MANTISSA", &small);
+        table(&mut out, "LARGE_MANTISSA", &large);
+        let small_int: Vec<u64> = (0..step as u32).map(|exp| (radix as u64).pow(exp)).collect();
+        writeln!(
+            out,
+            "\nconst BASE{}_SMALL_INT_POWERS: [u64; {}] = [{}];",
+            radix,
+            small_int.len(),
+            join(&small_int)
+        )
+        .unwrap();
+    }
+
+    out
+}
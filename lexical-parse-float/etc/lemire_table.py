@@ -17,7 +17,6 @@ available here: <https://github.com/fastfloat/fast_float/blob/main/script/table_
 from __future__ import print_function
 from math import ceil, floor, log, log2
 from fractions import Fraction
-from collections import deque
 
 HEADER = """
 //! Pre-computed tables powers-of-5 for extended-precision representations.
@@ -29,6 +28,11 @@ HEADER = """
 //! DO NOT MODIFY: Generated by `src/etc/lemire_table.py`
 """
 
+# The number of powers between each compressed power. This is the largest
+# power of 5 that fits in a 64-bit integer, so every power can be recovered
+# with a single 64-bit multiplication of each half of a compressed power.
+COMPRESSION_RATIO = 27
+
 STATIC_WARNING = """
 // Use static to avoid long compile times: Rust compiler errors
 // can have the entire table compiled multiple times, and then
@@ -39,7 +43,6 @@ STATIC_WARNING = """
 def main():
     min_exp = minimum_exponent(10)
     max_exp = maximum_exponent(10)
-    bias = -minimum_exponent(5)
 
     print(HEADER.strip())
     print()
@@ -48,7 +51,10 @@ def main():
     print('pub const N_POWERS_OF_FIVE: usize = ', end='')
     print('(LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize;')
     print()
-    print_proper_powers(min_exp, max_exp, bias)
+    powers = proper_powers(min_exp, max_exp)
+    print_proper_powers(powers)
+    print()
+    print_compressed_powers(powers)
 
 
 def minimum_exponent(base):
@@ -59,8 +65,7 @@ def maximum_exponent(base):
     return floor(log(1.7976931348623157e+308, base))
 
 
-def print_proper_powers(min_exp, max_exp, bias):
-    powers = deque()
+def proper_powers(min_exp, max_exp):
 
     # Add negative exponents.
     # 2^(2b)/(5^−q) with b=64 + int(math.ceil(log2(5^−q)))
@@ -93,18 +98,80 @@ def print_proper_powers(min_exp, max_exp, bias):
             power5 //= 2
         powers.append((power5, q))
 
-    # Print the powers.
+    return powers
+
+
+def print_proper_powers(powers):
     print(STATIC_WARNING.strip())
+    print('#[cfg(not(feature = "wasm"))]')
     print('#[rustfmt::skip]')
     typ = '[(u64, u64); N_POWERS_OF_FIVE]'
     print('pub static POWER_OF_FIVE_128: {} = ['.format(typ))
-    lo_mask = (1 << 64) - 1
     for c, exp in powers:
-        hi = '0x{:x}'.format(c // (1 << 64))
-        lo = '0x{:x}'.format(c % (1 << 64))
-        value = '    ({}, {}), '.format(hi, lo)
-        comment = '// {}^{}'.format(5, exp)
-        print(value.ljust(46, ' ') + comment)
+        print_power(c, exp)
+    print('];')
+
+
+def print_power(c, exp):
+    hi = '0x{:x}'.format(c // (1 << 64))
+    lo = '0x{:x}'.format(c % (1 << 64))
+    value = '    ({}, {}), '.format(hi, lo)
+    comment = '// {}^{}'.format(5, exp)
+    print(value.ljust(46, ' ') + comment)
+
+
+def recover_power(compressed, offset):
+    # Multiply by the small power and keep the high 128 bits.
+    product = compressed * 5**offset
+    return product >> (product.bit_length() - 128)
+
+
+def print_compressed_powers(powers):
+    # Store every 27th power, and the difference between the recovered
+    # and the exact powers, plus 1, in 2 bits.
+    compressed = powers[::COMPRESSION_RATIO]
+    corrections = []
+    for index, (c, _) in enumerate(powers):
+        base = compressed[index // COMPRESSION_RATIO][0]
+        correction = c - recover_power(base, index % COMPRESSION_RATIO) + 1
+        assert 0 <= correction < 4
+        corrections.append(correction)
+
+    print('/// The number of powers of five between each compressed power.')
+    print('#[cfg(feature = "wasm")]')
+    print('pub const POWER_OF_FIVE_STEP: usize = {};'.format(COMPRESSION_RATIO))
+    print()
+    print('/// Every 27th power of five, to recover the other powers.')
+    print('#[cfg(feature = "wasm")]')
+    print('#[rustfmt::skip]')
+    typ = '[(u64, u64); {}]'.format(len(compressed))
+    print('pub static COMPRESSED_POWER_OF_FIVE_128: {} = ['.format(typ))
+    for c, exp in compressed:
+        print_power(c, exp)
+    print('];')
+    print()
+    print('/// The small powers of five to recover the other powers.')
+    print('#[cfg(feature = "wasm")]')
+    print('#[rustfmt::skip]')
+    print('pub static POWER_OF_FIVE_64: [u64; POWER_OF_FIVE_STEP] = [')
+    for exp in range(COMPRESSION_RATIO):
+        print('    {},'.format(5**exp))
+    print('];')
+    print()
+    print('/// The difference between the exact and recovered powers, plus 1,')
+    print('/// packed into 2 bits for each power.')
+    print('#[cfg(feature = "wasm")]')
+    print('#[rustfmt::skip]')
+    words = []
+    for index in range(0, len(corrections), 32):
+        word = 0
+        for bit, correction in enumerate(corrections[index:index + 32]):
+            word |= correction << (2 * bit)
+        words.append(word)
+    typ = '[u64; {}]'.format(len(words))
+    print('pub static POWER_OF_FIVE_CORRECTION: {} = ['.format(typ))
+    for word in words:
+        print('    0x{:016x},'.format(word))
     print('];')
 
 
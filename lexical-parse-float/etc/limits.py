@@ -30,7 +30,7 @@ def feature(radix):
         return ''
     elif is_pow2(radix):
         return 'if cfg!(feature = "power-of-two") '
-    return 'if cfg!(feature = "radix") '
+    return 'if cfg!(feature = "any-radix") '
 
 
 def exponent_limit(radix, mantissa_size, max_exp):
@@ -78,7 +78,7 @@ def all_limits(mantissa_size, exponent_size, type_name):
     print('#[inline(always)]')
     print(f'pub const fn {type_name}_exponent_limit(radix: u32) -> (i64, i64) {{')
     print('    match radix {')
-    for radix in range(2, 37):
+    for radix in range(2, 63):
         exp_limit = exponent_limit(radix, mantissa_size, max_exp)
         print(f'        {radix} {feature(radix)}=> {exp_limit},')
     print('        _ => (0, 0),')
@@ -90,7 +90,7 @@ def all_limits(mantissa_size, exponent_size, type_name):
     print('#[inline(always)]')
     print(f'pub const fn {type_name}_mantissa_limit(radix: u32) -> i64 {{')
     print('    match radix {')
-    for radix in range(2, 37):
+    for radix in range(2, 63):
         mant_limit = mantissa_limit(radix, mantissa_size)
         print(f'        {radix} {feature(radix)}=> {mant_limit},')
     print('        _ => 0,')
@@ -69,7 +69,7 @@ def print_large(radix, max_exp):
     limb32 = as_u32(power)
     limb64 = as_u64(power)
     print(f'/// Pre-computed large power-of-{radix} for 32-bit limbs.')
-    print('#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]')
+    print('#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]')
     print(f'pub const LARGE_POW{radix}: [u32; {len(limb32)}] = [')
     for value in limb32:
         print(f'    {value},')
@@ -77,7 +77,7 @@ def print_large(radix, max_exp):
     print(f'')
 
     print(f'/// Pre-computed large power-of-{radix} for 64-bit limbs.')
-    print('#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]')
+    print('#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]')
     print(f'pub const LARGE_POW{radix}: [u64; {len(limb64)}] = [')
     for value in limb64:
         print(f'    {value},')
@@ -2,20 +2,36 @@
 
 #![doc(hidden)]
 
+use crate::number::{DigitCounts, ParseFlags};
 use crate::options::Options;
 use crate::parse::ParseFloat;
+use crate::slow::Representation;
+use crate::table::is_float_radix;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat, STANDARD};
+use lexical_util::result::Result;
 use lexical_util::{from_lexical, from_lexical_with_options};
 
 // API
 
 const DEFAULT_OPTIONS: Options = Options::new();
 
+/// Count a parse error, with the `stats` feature.
+#[inline(always)]
+fn count_error<T>(result: Result<T>) -> Result<T> {
+    #[cfg(feature = "stats")]
+    {
+        if result.is_err() {
+            crate::stats::increment(&crate::stats::ERRORS);
+        }
+    }
+    result
+}
+
 /// Implement FromLexical for numeric type.
 ///
 /// Need to inline these, otherwise codegen is suboptimal.
@@ -28,7 +44,7 @@ macro_rules! float_from_lexical {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self>
             {
-                Self::parse_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+                count_error(Self::parse_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS))
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -36,7 +52,7 @@ macro_rules! float_from_lexical {
                 bytes: &[u8],
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+                count_error(Self::parse_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS))
             }
         }
 
@@ -52,10 +68,16 @@ macro_rules! float_from_lexical {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
+                } else if !is_float_radix(format.mantissa_radix()) {
+                    return Err(Error::InvalidMantissaRadix);
+                } else if !is_float_radix(format.exponent_base()) {
+                    return Err(Error::InvalidExponentBase);
+                } else if !is_float_radix(format.exponent_radix()) {
+                    return Err(Error::InvalidExponentRadix);
                 } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
                     return Err(Error::InvalidPunctuation);
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                count_error(Self::parse_complete::<FORMAT>(bytes, options))
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -64,7 +86,27 @@ macro_rules! float_from_lexical {
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<FORMAT>(bytes, options)
+                count_error(Self::parse_partial::<FORMAT>(bytes, options))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format(
+                bytes: &[u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>
+            {
+                count_error(Self::parse_complete_with_format(bytes, format, options))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_format(
+                bytes: &[u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                count_error(Self::parse_partial_with_format(bytes, format, options))
             }
         }
     )*)
@@ -76,3 +118,237 @@ float_from_lexical! { f32 f64 }
 
 #[cfg(feature = "f16")]
 float_from_lexical! { bf16 f16 }
+
+/// Determine how a float string is represented by the nearest float.
+///
+/// This reports if the number is exactly representable as `F`, exactly
+/// halfway between two adjacent floats, or neither, for the standard
+/// number format. Special values are exact, and numbers that overflow
+/// are inexact. Half-precision floats are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{representation, Representation};
+///
+/// assert_eq!(representation::<f64>(b"0.5"), Ok(Representation::Exact));
+/// assert_eq!(representation::<f64>(b"9007199254740993"), Ok(Representation::Halfway));
+/// assert_eq!(representation::<f64>(b"0.1"), Ok(Representation::Inexact));
+/// ```
+#[inline]
+pub fn representation<F: ParseFloat>(bytes: &[u8]) -> Result<Representation> {
+    F::representation::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Determine how a float string is represented by the nearest float, using custom options.
+///
+/// This is the same as [`representation`], for a custom number format.
+/// Only radixes with a finite number of digits for all floats are
+/// supported, other than powers-of-two: that is, decimal and the other
+/// even radixes.
+#[inline]
+pub fn representation_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<Representation> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_float_radix(format.mantissa_radix()) {
+        return Err(Error::InvalidMantissaRadix);
+    } else if !is_float_radix(format.exponent_base()) {
+        return Err(Error::InvalidExponentBase);
+    } else if !is_float_radix(format.exponent_radix()) {
+        return Err(Error::InvalidExponentRadix);
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    F::representation::<FORMAT>(bytes, options)
+}
+
+/// Parse a float from bytes using a partial parser, counting the digits.
+///
+/// This returns the parsed float, the number of bytes processed, and
+/// the number of integer, fraction, and exponent digits, as well as the
+/// number of digit separators, consumed by the parser, for the standard
+/// number format.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_partial_with_counts, DigitCounts};
+///
+/// let (float, count, counts) = parse_partial_with_counts::<f64>(b"12.345e-6 ").unwrap();
+/// assert_eq!(float, 12.345e-6);
+/// assert_eq!(count, 9);
+/// assert_eq!(counts, DigitCounts {
+///     integer: 2,
+///     fraction: 3,
+///     exponent: 1,
+///     separators: 0,
+/// });
+/// ```
+#[inline]
+pub fn parse_partial_with_counts<F: ParseFloat>(bytes: &[u8]) -> Result<(F, usize, DigitCounts)> {
+    count_error(F::parse_partial_with_counts::<STANDARD>(bytes, &DEFAULT_OPTIONS))
+}
+
+/// Parse a float from bytes using a partial parser and custom options, counting the digits.
+///
+/// This is the same as [`parse_partial_with_counts`], for a custom number format.
+#[inline]
+pub fn parse_partial_with_counts_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize, DigitCounts)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_float_radix(format.mantissa_radix()) {
+        return Err(Error::InvalidMantissaRadix);
+    } else if !is_float_radix(format.exponent_base()) {
+        return Err(Error::InvalidExponentBase);
+    } else if !is_float_radix(format.exponent_radix()) {
+        return Err(Error::InvalidExponentRadix);
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    count_error(F::parse_partial_with_counts::<FORMAT>(bytes, options))
+}
+
+/// Parse a float from bytes using a partial parser, describing the syntax used.
+///
+/// This returns the parsed float, the number of bytes processed, and
+/// flags for the optional components of the number, such as if it had an
+/// exponent or digit separators, for the standard number format. This is
+/// useful for linters, or to preserve the original style when writing the
+/// number.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_partial_with_flags, ParseFlags};
+///
+/// let (float, count, flags) = parse_partial_with_flags::<f64>(b"12.5e-6 ").unwrap();
+/// assert_eq!(float, 12.5e-6);
+/// assert_eq!(count, 7);
+/// assert_eq!(flags, ParseFlags {
+///     has_exponent: true,
+///     has_fraction: true,
+///     has_separators: false,
+///     has_base_prefix: false,
+///     is_special: false,
+/// });
+///
+/// let (_, _, flags) = parse_partial_with_flags::<f64>(b"inf").unwrap();
+/// assert!(flags.is_special);
+/// ```
+#[inline]
+pub fn parse_partial_with_flags<F: ParseFloat>(bytes: &[u8]) -> Result<(F, usize, ParseFlags)> {
+    count_error(F::parse_partial_with_flags::<STANDARD>(bytes, &DEFAULT_OPTIONS))
+}
+
+/// Parse a float from bytes using a partial parser and custom options, describing the syntax used.
+///
+/// This is the same as [`parse_partial_with_flags`], for a custom number format.
+#[inline]
+pub fn parse_partial_with_flags_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize, ParseFlags)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_float_radix(format.mantissa_radix()) {
+        return Err(Error::InvalidMantissaRadix);
+    } else if !is_float_radix(format.exponent_base()) {
+        return Err(Error::InvalidExponentBase);
+    } else if !is_float_radix(format.exponent_radix()) {
+        return Err(Error::InvalidExponentRadix);
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    count_error(F::parse_partial_with_flags::<FORMAT>(bytes, options))
+}
+
+/// A float that can be parsed as a wider float, and then narrowed.
+pub trait Narrow: Sized {
+    /// The wider float type the number is parsed as.
+    type Wide: FromLexicalWithOptions<Options = Options>;
+
+    /// Narrow the wider float, if it is exactly representable.
+    ///
+    /// NaN values are always narrowed, even if the payload is not preserved.
+    fn narrow(wide: Self::Wide) -> Option<Self>;
+}
+
+impl Narrow for f32 {
+    type Wide = f64;
+
+    #[inline]
+    fn narrow(wide: f64) -> Option<Self> {
+        let narrow = wide as f32;
+        if wide.is_nan() || narrow as f64 == wide {
+            Some(narrow)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "f16")]
+macro_rules! narrow_via_f32 {
+    ($($t:ident)*) => ($(
+        impl Narrow for $t {
+            type Wide = f64;
+
+            #[inline]
+            fn narrow(wide: f64) -> Option<Self> {
+                let float = f32::narrow(wide)?;
+                let narrow = $t::from_f32(float);
+                if float.is_nan() || narrow.as_f32() == float {
+                    Some(narrow)
+                } else {
+                    None
+                }
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "f16")]
+narrow_via_f32! { bf16 f16 }
+
+/// Parse a float string as a wider float, and narrow it to `F` without losing information.
+///
+/// The number is parsed as `F::Wide`, IE, `f64` for `f32`, and this errors
+/// with [`Error::InexactNarrowing`] if the value is not exactly representable
+/// as `F`, rather than rounding it twice. The error index is the start of
+/// the number. Numbers that overflow `F` but not the wider float are also
+/// an error.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_narrow, Error};
+///
+/// assert_eq!(parse_narrow::<f32>(b"1.5"), Ok(1.5));
+/// assert_eq!(parse_narrow::<f32>(b"0.1"), Err(Error::InexactNarrowing(0)));
+/// assert_eq!(parse_narrow::<f32>(b"1e300"), Err(Error::InexactNarrowing(0)));
+/// ```
+#[inline]
+pub fn parse_narrow<F: Narrow>(bytes: &[u8]) -> Result<F> {
+    parse_narrow_with_options::<F, STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Parse a float string as a wider float, and narrow it to `F`, using custom options.
+///
+/// This is the same as [`parse_narrow`], for a custom number format.
+#[inline]
+pub fn parse_narrow_with_options<F: Narrow, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<F> {
+    let wide = F::Wide::from_lexical_with_options::<FORMAT>(bytes, options)?;
+    F::narrow(wide).ok_or(Error::InexactNarrowing(0))
+}
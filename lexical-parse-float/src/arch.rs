@@ -0,0 +1,8 @@
+//! Platform-specific, assembly instructions for wide multiplication.
+//!
+//! These are provided by `lexical-util`, and are re-exported here.
+
+#![cfg(feature = "asm")]
+#![doc(hidden)]
+
+pub use lexical_util::arch::{mul64, mulhi64};
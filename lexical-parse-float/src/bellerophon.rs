@@ -14,7 +14,7 @@
 //! found [here](https://github.com/golang/go/blob/b10849fbb97a2244c086991b4623ae9f32c212d0/src/strconv/extfloat.go).
 //! This code is therefore subject to a 3-clause BSD license.
 
-#![cfg(any(feature = "compact", feature = "radix"))]
+#![cfg(any(feature = "compact", feature = "any-radix"))]
 #![doc(hidden)]
 
 use crate::float::{ExtendedFloat80, RawFloat};
@@ -41,6 +41,33 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     debug_assert!(!matches!(format.radix(), 2 | 4 | 8 | 16 | 32));
     debug_assert!(format.mantissa_radix() == format.exponent_base());
 
+    // If we have many digits, the truncated digits are in the range
+    // `[mantissa, mantissa + 1)`, so if both bounds round to the same
+    // float, we correctly round on the first pass.
+    let fp = compute_float::<F, FORMAT>(num.exponent, num.mantissa, lossy, false);
+    if !lossy
+        && num.many_digits
+        && fp.exp >= 0
+        && fp != compute_float::<F, FORMAT>(num.exponent, num.mantissa + 1, false, false)
+    {
+        // Need to re-calculate, since the previous values are rounded
+        // when the slow path algorithm expects a normalized extended float.
+        return compute_float::<F, FORMAT>(num.exponent, num.mantissa, false, true);
+    }
+    fp
+}
+
+/// Compute the float for the mantissa and exponent in the radix.
+///
+/// If `is_error`, this always returns the extended-precision float
+/// as if we were unable to unambiguously round it.
+fn compute_float<F: RawFloat, const FORMAT: u128>(
+    exponent: i64,
+    mantissa: u64,
+    lossy: bool,
+    is_error: bool,
+) -> ExtendedFloat80 {
+    let format = NumberFormat::<{ FORMAT }> {};
     let fp_zero = ExtendedFloat80 {
         mant: 0,
         exp: 0,
@@ -53,23 +80,22 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     // Early short-circuit, in case of literal 0 or infinity.
     // This allows us to avoid narrow casts causing numeric overflow,
     // and is a quick check for any radix.
-    if num.mantissa == 0 || num.exponent <= -0x1000 {
+    if mantissa == 0 || exponent <= -0x1000 {
         return fp_zero;
-    } else if num.exponent >= 0x1000 {
+    } else if exponent >= 0x1000 {
         return fp_inf;
     }
 
     // Calculate our indexes for our extended-precision multiplication.
     let powers = bellerophon_powers(format.radix());
     // This narrowing cast is safe, since exponent must be in a valid range.
-    let exponent = num.exponent as i32 + powers.bias;
-    let small_index = exponent % powers.step;
-    let large_index = exponent / powers.step;
-
+    let exponent = exponent as i32 + powers.bias;
     if exponent < 0 {
         // Guaranteed underflow (assign 0).
         return fp_zero;
     }
+    let small_index = exponent % powers.step;
+    let large_index = exponent / powers.step;
     if large_index as usize >= powers.large.len() {
         // Overflow (assign infinity)
         return fp_inf;
@@ -80,15 +106,12 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
 
     // Track errors to as a factor of unit in last-precision.
     let mut errors: u32 = 0;
-    if num.many_digits {
-        errors += error_halfscale();
-    }
 
     // Multiply by the small power.
     // Check if we can directly multiply by an integer, if not,
     // use extended-precision multiplication.
     let mut fp = ExtendedFloat80 {
-        mant: num.mantissa,
+        mant: mantissa,
         exp: 0,
     };
     match fp.mant.overflowing_mul(powers.get_small_int(small_index as usize)) {
@@ -123,7 +146,7 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     }
 
     // Too many errors accumulated, return an error.
-    if !lossy && !error_is_accurate::<F>(errors, &fp) {
+    if is_error || (!lossy && !error_is_accurate::<F>(errors, &fp)) {
         // Bias the exponent so we know it's invalid.
         fp.exp += shared::INVALID_FP;
         return fp;
@@ -30,6 +30,11 @@ pub fn binary<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> Ext
         mant: 0,
         exp: 0,
     };
+    // Zero is normally handled by the fast path, which requires the
+    // exponent base to be the radix, so we cannot normalize it.
+    if num.mantissa == 0 {
+        return fp_zero;
+    }
 
     // Normalize our mantissa for simpler results.
     let ctlz = num.mantissa.leading_zeros();
@@ -0,0 +1,465 @@
+//! Parse decimal floats in const contexts.
+//!
+//! These parsers are `const fn`, so static tables of floats can be built
+//! from string data at compile time, using [`const_f64`] and [`const_f32`].
+//! They support the standard decimal format with the default options,
+//! but only a subset of the floats: numbers with at most 19 significant
+//! digits, and decimal exponents small enough to scale and round them
+//! exactly using 128-bit integers, down to about `1e-54`. Any other float
+//! requires the slow path algorithms, with big integers, and fails with
+//! [`Error::RequiresSlowPath`].
+//!
+//! Floats are returned as their bits, since creating native floats in a
+//! `const fn` requires a newer Rust version than the minimum supported
+//! version.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::constant::{parse_f32_bits, parse_f64_bits};
+//! use lexical_parse_float::{const_f64, Error};
+//!
+//! const BITS: u64 = match parse_f64_bits(b"1.5") {
+//!     Ok(bits) => bits,
+//!     Err(_) => 0,
+//! };
+//! assert_eq!(f64::from_bits(BITS), 1.5);
+//! assert_eq!(parse_f32_bits(b"-0.1"), Ok((-0.1f32).to_bits()));
+//! assert_eq!(parse_f64_bits(b"1e-400"), Err(Error::RequiresSlowPath(0)));
+//!
+//! static TABLE: [f64; 3] = [const_f64!("0.1"), const_f64!("-2.5e10"), const_f64!("NaN")];
+//! assert_eq!(TABLE[0], 0.1);
+//! assert_eq!(TABLE[1], -2.5e10);
+//! assert!(TABLE[2].is_nan());
+//! ```
+//!
+//! [`const_f64`]: crate::const_f64
+//! [`const_f32`]: crate::const_f32
+
+use lexical_util::error::Error;
+use lexical_util::result::Result;
+
+/// The layout of the bits of a native float.
+#[derive(Clone, Copy)]
+struct Layout {
+    /// Number of explicit bits in the mantissa.
+    mantissa_size: i32,
+    /// Number of bits in the exponent.
+    exponent_size: i32,
+    /// Bias of the exponent, for a mantissa in the range `[1, 2)`.
+    exponent_bias: i32,
+}
+
+/// The layout of an `f32`.
+const F32: Layout = Layout {
+    mantissa_size: 23,
+    exponent_size: 8,
+    exponent_bias: 127,
+};
+
+/// The layout of an `f64`.
+const F64: Layout = Layout {
+    mantissa_size: 52,
+    exponent_size: 11,
+    exponent_bias: 1023,
+};
+
+/// The largest power of 5 that fits in a `u128`.
+const MAX_POW5: i64 = 55;
+
+/// Parse a decimal string to the bits of an `f64`, in a const context.
+///
+/// This parses the complete string, and fails with
+/// [`Error::RequiresSlowPath`] if the float has more than 19 significant
+/// digits, or its exponent is too large to scale exactly with 128-bit
+/// integers.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::constant::parse_f64_bits;
+/// use lexical_parse_float::Error;
+///
+/// assert_eq!(parse_f64_bits(b"1.5"), Ok(1.5f64.to_bits()));
+/// assert_eq!(parse_f64_bits(b"-1e22"), Ok((-1e22f64).to_bits()));
+/// assert_eq!(parse_f64_bits(b"1.5x"), Err(Error::InvalidDigit(3)));
+/// ```
+#[inline]
+pub const fn parse_f64_bits(bytes: &[u8]) -> Result<u64> {
+    parse_bits(bytes, F64)
+}
+
+/// Parse a decimal string to the bits of an `f32`, in a const context.
+///
+/// This is the same as [`parse_f64_bits`], rounding directly to an `f32`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::constant::parse_f32_bits;
+///
+/// assert_eq!(parse_f32_bits(b"1.5"), Ok(1.5f32.to_bits()));
+/// assert_eq!(parse_f32_bits(b"1e-45"), Ok(1e-45f32.to_bits()));
+/// ```
+#[inline]
+pub const fn parse_f32_bits(bytes: &[u8]) -> Result<u32> {
+    match parse_bits(bytes, F32) {
+        Ok(bits) => Ok(bits as u32),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse a decimal `f64` from a string literal or constant at compile time.
+///
+/// An invalid float, or one that requires the slow path, fails to
+/// compile, with an index out of bounds error where the index is the
+/// [`Error::code`] of the error.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::const_f64;
+///
+/// const PI: f64 = const_f64!("3.141592653589793");
+/// assert_eq!(PI, core::f64::consts::PI);
+/// ```
+///
+/// ```rust,compile_fail
+/// use lexical_parse_float::const_f64;
+///
+/// const TOO_PRECISE: f64 = const_f64!("3.14159265358979323846");
+/// ```
+///
+/// [`Error::code`]: crate::Error::code
+#[macro_export]
+macro_rules! const_f64 {
+    ($string:expr) => {{
+        const BITS: u64 =
+            $crate::constant::unwrap_f64_bits($crate::constant::parse_f64_bits($string.as_bytes()));
+        // SAFETY: safe, since every bit pattern is a valid float.
+        unsafe {
+            $crate::constant::FloatBits {
+                u64: BITS,
+            }
+            .f64
+        }
+    }};
+}
+
+/// Parse a decimal `f32` from a string literal or constant at compile time.
+///
+/// This is the same as [`const_f64`], rounding directly to an `f32`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::const_f32;
+///
+/// static TABLE: [f32; 2] = [const_f32!("0.1"), const_f32!("3.4e38")];
+/// assert_eq!(TABLE, [0.1, 3.4e38]);
+/// ```
+///
+/// [`const_f64`]: crate::const_f64
+#[macro_export]
+macro_rules! const_f32 {
+    ($string:expr) => {{
+        const BITS: u32 =
+            $crate::constant::unwrap_f32_bits($crate::constant::parse_f32_bits($string.as_bytes()));
+        // SAFETY: safe, since every bit pattern is a valid float.
+        unsafe {
+            $crate::constant::FloatBits {
+                u32: BITS,
+            }
+            .f32
+        }
+    }};
+}
+
+/// Reinterpret the bits of a float in a const context.
+///
+/// `from_bits` is only a `const fn` in newer Rust versions, while reading
+/// a union field is allowed in constants.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub union FloatBits {
+    pub u32: u32,
+    pub u64: u64,
+    pub f32: f32,
+    pub f64: f64,
+}
+
+/// Unwrap the bits of a float, failing to compile in a const context.
+///
+/// Panics in const contexts require a newer Rust version than the minimum
+/// supported version, so this indexes an empty array out-of-bounds.
+#[doc(hidden)]
+#[inline]
+pub const fn unwrap_f64_bits(result: Result<u64>) -> u64 {
+    match result {
+        Ok(bits) => bits,
+        Err(error) => {
+            let invalid_float: [u64; 0] = [];
+            invalid_float[error.code() as usize]
+        },
+    }
+}
+
+/// Unwrap the bits of a float, failing to compile in a const context.
+#[doc(hidden)]
+#[inline]
+pub const fn unwrap_f32_bits(result: Result<u32>) -> u32 {
+    match result {
+        Ok(bits) => bits,
+        Err(error) => {
+            let invalid_float: [u32; 0] = [];
+            invalid_float[error.code() as usize]
+        },
+    }
+}
+
+/// Parse a decimal string to the bits of a float with the given layout.
+const fn parse_bits(bytes: &[u8], layout: Layout) -> Result<u64> {
+    let length = bytes.len();
+    let mut index = 0;
+    let is_negative = length != 0 && bytes[0] == b'-';
+    if length != 0 && (bytes[0] == b'+' || bytes[0] == b'-') {
+        index += 1;
+    }
+    if index == length {
+        return Err(Error::Empty(index));
+    }
+    let sign = (is_negative as u64) << (layout.mantissa_size + layout.exponent_size);
+    if let Some(bits) = parse_special(bytes, index, layout) {
+        return Ok(sign | bits);
+    }
+
+    // Parse the significant digits, skipping leading and trailing zeros,
+    // which only change the exponent.
+    let mut mantissa: u64 = 0;
+    let mut exponent: i64 = 0;
+    let mut zeros: i64 = 0;
+    let mut digits = 0;
+    let mut is_fraction = false;
+    let mut is_truncated = false;
+    while index < length {
+        let c = bytes[index];
+        if c == b'.' && !is_fraction {
+            is_fraction = true;
+            index += 1;
+            continue;
+        } else if !c.is_ascii_digit() {
+            break;
+        }
+        let digit = (c - b'0') as u64;
+        digits += 1;
+        exponent -= is_fraction as i64;
+        if digit == 0 {
+            zeros += 1;
+        } else if mantissa == 0 {
+            mantissa = digit;
+            zeros = 0;
+        } else {
+            match push_digit(mantissa, zeros, digit) {
+                Some(value) => mantissa = value,
+                None => is_truncated = true,
+            }
+            zeros = 0;
+        }
+        index += 1;
+    }
+    if digits == 0 {
+        return Err(Error::EmptyMantissa(index));
+    }
+    if mantissa != 0 {
+        exponent += zeros;
+    }
+
+    // Parse the exponent, saturating on overflow.
+    if index < length && (bytes[index] == b'e' || bytes[index] == b'E') {
+        index += 1;
+        let is_negative_exponent = index < length && bytes[index] == b'-';
+        if index < length && (bytes[index] == b'+' || bytes[index] == b'-') {
+            index += 1;
+        }
+        let mut explicit: i64 = 0;
+        let start = index;
+        while index < length && bytes[index].is_ascii_digit() {
+            if explicit < 0x1000_0000 {
+                explicit = explicit * 10 + (bytes[index] - b'0') as i64;
+            }
+            index += 1;
+        }
+        if index == start {
+            return Err(Error::EmptyExponent(index));
+        }
+        if is_negative_exponent {
+            exponent -= explicit;
+        } else {
+            exponent += explicit;
+        }
+    }
+    if index < length {
+        return Err(Error::InvalidDigit(index));
+    }
+
+    if mantissa == 0 {
+        Ok(sign)
+    } else if is_truncated {
+        Err(Error::RequiresSlowPath(0))
+    } else {
+        match scale(mantissa, exponent, layout) {
+            Some(bits) => Ok(sign | bits),
+            None => Err(Error::RequiresSlowPath(0)),
+        }
+    }
+}
+
+/// Parse the case-insensitive special floats, without the sign.
+const fn parse_special(bytes: &[u8], index: usize, layout: Layout) -> Option<u64> {
+    let infinity = ((1u64 << layout.exponent_size) - 1) << layout.mantissa_size;
+    if is_special(bytes, index, b"nan") {
+        Some(infinity | (1u64 << (layout.mantissa_size - 1)))
+    } else if is_special(bytes, index, b"inf") || is_special(bytes, index, b"infinity") {
+        Some(infinity)
+    } else {
+        None
+    }
+}
+
+/// Determine if the rest of the bytes are a lowercase special string.
+const fn is_special(bytes: &[u8], index: usize, special: &[u8]) -> bool {
+    if bytes.len() - index != special.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < special.len() {
+        if bytes[index + i] | 0x20 != special[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Append a digit after pending zeros to the mantissa, or `None` on overflow.
+const fn push_digit(mantissa: u64, zeros: i64, digit: u64) -> Option<u64> {
+    let mut value = mantissa;
+    let mut i = 0;
+    while i <= zeros {
+        value = match value.checked_mul(10) {
+            Some(value) => value,
+            None => return None,
+        };
+        i += 1;
+    }
+    value.checked_add(digit)
+}
+
+/// Calculate `5^exp`, for an exponent up to `MAX_POW5`.
+const fn pow5(exp: i64) -> u128 {
+    let mut value = 1;
+    let mut i = 0;
+    while i < exp {
+        value *= 5;
+        i += 1;
+    }
+    value
+}
+
+/// Scale a non-zero mantissa by `10^exponent`, and round it to float bits.
+///
+/// Returns `None` if the scaled value cannot be calculated exactly, or
+/// with enough bits to round it correctly, using 128-bit integers.
+const fn scale(mantissa: u64, exponent: i64, layout: Layout) -> Option<u64> {
+    if exponent > MAX_POW5 || exponent < -MAX_POW5 {
+        return None;
+    }
+    // The value is `mantissa * 5^exponent * 2^exponent`.
+    if exponent >= 0 {
+        match (mantissa as u128).checked_mul(pow5(exponent)) {
+            Some(value) => round(value, exponent as i32, false, layout),
+            None => None,
+        }
+    } else {
+        let divisor = pow5(-exponent);
+        let mut shift = (mantissa as u128).leading_zeros();
+        let numerator = (mantissa as u128) << shift;
+        let mut quotient = numerator / divisor;
+        let mut remainder = numerator % divisor;
+        // Continue the long division until the bit after the mantissa is
+        // in the quotient, so the remainder is only needed to break ties.
+        let min_bits = (layout.mantissa_size + 2) as u32;
+        while 128 - quotient.leading_zeros() < min_bits {
+            let mut step = min_bits - (128 - quotient.leading_zeros());
+            if step > remainder.leading_zeros() {
+                step = remainder.leading_zeros();
+            }
+            if step == 0 {
+                return None;
+            }
+            remainder <<= step;
+            quotient = (quotient << step) | (remainder / divisor);
+            remainder %= divisor;
+            shift += step;
+        }
+        round(quotient, exponent as i32 - shift as i32, remainder != 0, layout)
+    }
+}
+
+/// Round `value * 2^exponent` to the nearest float bits, with ties to even.
+///
+/// `is_truncated` is if there are non-zero bits below the value, which
+/// can only break ties, so this returns `None` if the bit after the
+/// mantissa is not in the value.
+const fn round(value: u128, exponent: i32, is_truncated: bool, layout: Layout) -> Option<u64> {
+    let mantissa_size = layout.mantissa_size;
+    let bits = 128 - value.leading_zeros() as i32;
+    // Shift the value to the hidden bit, or the exponent of denormal floats.
+    let denormal_exponent = 1 - layout.exponent_bias - mantissa_size;
+    let mut shift = bits - (mantissa_size + 1);
+    if exponent + shift < denormal_exponent {
+        shift = denormal_exponent - exponent;
+    }
+    if shift > bits {
+        // Below half of the smallest denormal float.
+        return Some(0);
+    } else if shift <= 0 && is_truncated {
+        return None;
+    }
+
+    let mut mantissa = if shift <= 0 {
+        (value << -shift) as u64
+    } else {
+        let halfway = 1u128 << (shift - 1);
+        let is_above = value & (halfway - 1) != 0 || is_truncated;
+        let mantissa = if shift == 128 {
+            0
+        } else {
+            (value >> shift) as u64
+        };
+        let is_odd = mantissa & 1 == 1;
+        if value & halfway != 0 && (is_above || is_odd) {
+            mantissa + 1
+        } else {
+            mantissa
+        }
+    };
+    let mut exponent = exponent + shift;
+    if mantissa >> (mantissa_size + 1) != 0 {
+        mantissa >>= 1;
+        exponent += 1;
+    }
+    if mantissa >> mantissa_size == 0 {
+        // Denormal floats and zero have a biased exponent of 0.
+        return Some(mantissa);
+    }
+
+    let max_exponent = (1 << layout.exponent_size) - 1;
+    let biased_exponent = exponent + mantissa_size + layout.exponent_bias;
+    if biased_exponent >= max_exponent {
+        Some((max_exponent as u64) << mantissa_size)
+    } else {
+        let mask = (1u64 << mantissa_size) - 1;
+        Some(((biased_exponent as u64) << mantissa_size) | (mantissa & mask))
+    }
+}
@@ -3,38 +3,53 @@
 //! This is used very sparing in parsers, only when code can trivially
 //! be shown to be safe, since parsers are tricky to validate.
 
-#![cfg_attr(not(feature = "power-of-two"), allow(unused_macros))]
+#![cfg_attr(any(not(feature = "power-of-two"), feature = "compact"), allow(unused_macros))]
 #![doc(hidden)]
 
-/// Index a buffer, without bounds checking.
-#[cfg(not(feature = "safe"))]
-macro_rules! index_unchecked {
-    ($x:ident[$i:expr]) => {
-        *$x.get_unchecked($i)
+/// Unwrap a value that is always present for valid floats.
+#[cfg(not(feature = "no-panic"))]
+macro_rules! unwrap_infallible {
+    ($x:expr) => {
+        $x.unwrap()
     };
 }
 
-/// Index a buffer and get a mutable reference, without bounds checking.
+/// Unwrap a value that is always present for valid floats, returning
+/// the error from the function rather than panicking if it is missing.
+#[cfg(feature = "no-panic")]
+macro_rules! unwrap_infallible {
+    ($x:expr) => {
+        $x?
+    };
+}
+
+/// Mark a branch as unreachable, without checking.
 #[cfg(not(feature = "safe"))]
-#[allow(unknown_lints, unused_macro_rules)]
-macro_rules! index_unchecked_mut {
-    ($x:ident[$i:expr]) => {
-        *$x.get_unchecked_mut($i)
+macro_rules! unreachable_unchecked {
+    () => {
+        core::hint::unreachable_unchecked()
     };
 }
 
-/// Index a buffer, with bounds checking.
+/// Mark a branch as unreachable, panicking if it is reached.
 #[cfg(feature = "safe")]
+macro_rules! unreachable_unchecked {
+    () => {
+        unreachable!()
+    };
+}
+
+/// Index a buffer, without bounds checking.
+#[cfg(not(feature = "safe"))]
 macro_rules! index_unchecked {
     ($x:ident[$i:expr]) => {
-        $x[$i]
+        *$x.get_unchecked($i)
     };
 }
 
-/// Index a buffer and get a mutable reference, with bounds checking.
+/// Index a buffer, with bounds checking.
 #[cfg(feature = "safe")]
-#[allow(unknown_lints, unused_macro_rules)]
-macro_rules! index_unchecked_mut {
+macro_rules! index_unchecked {
     ($x:ident[$i:expr]) => {
         $x[$i]
     };
@@ -6,10 +6,20 @@
 #![cfg(not(feature = "compact"))]
 #![doc(hidden)]
 
+#[cfg(all(feature = "asm", not(feature = "wasm")))]
+use crate::arch::mul64;
 use crate::float::{ExtendedFloat80, LemireFloat};
 use crate::number::Number;
 use crate::shared;
-use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+#[cfg(not(feature = "wasm"))]
+use crate::table::POWER_OF_FIVE_128;
+#[cfg(feature = "wasm")]
+use crate::table::{
+    COMPRESSED_POWER_OF_FIVE_128, POWER_OF_FIVE_64, POWER_OF_FIVE_CORRECTION, POWER_OF_FIVE_STEP,
+};
+use crate::table::{LARGEST_POWER_OF_FIVE, SMALLEST_POWER_OF_FIVE};
+#[cfg(feature = "wasm")]
+use lexical_util::mul::mul;
 
 /// Ensure truncation of digits doesn't affect our computation, by doing 2 passes.
 #[inline]
@@ -17,11 +27,15 @@ pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
     // If significant digits were truncated, then we can have rounding error
     // only if `mantissa + 1` produces a different result. We also avoid
     // redundantly using the Eisel-Lemire algorithm if it was unable to
-    // correctly round on the first pass.
+    // correctly round on the first pass. The exponent is always within
+    // the power-of-ten limits when the results differ, but checking
+    // it lets the compiler elide the bounds check in `compute_error`.
     let mut fp = compute_float::<F>(num.exponent, num.mantissa, lossy);
     if !lossy
         && num.many_digits
         && fp.exp >= 0
+        && num.exponent >= F::SMALLEST_POWER_OF_TEN as i64
+        && num.exponent <= F::LARGEST_POWER_OF_TEN as i64
         && fp != compute_float::<F>(num.exponent, num.mantissa + 1, false)
     {
         // Need to re-calculate, since the previous values are rounded
@@ -185,11 +199,67 @@ fn power(q: i32) -> i32 {
 }
 
 #[inline]
+#[cfg(not(any(feature = "asm", feature = "wasm")))]
 fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
     let r = (a as u128) * (b as u128);
     (r as u64, (r >> 64) as u64)
 }
 
+/// Multiply using the native instruction for the high bits, if available.
+#[inline]
+#[cfg(all(feature = "asm", not(feature = "wasm")))]
+fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+    mul64(a, b)
+}
+
+/// Multiply without 128-bit multiplication, which is emulated on WebAssembly.
+#[inline]
+#[cfg(feature = "wasm")]
+fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+    let (hi, lo) = mul::<u64, u32>(a, b);
+    (lo, hi)
+}
+
+/// Get the normalized, 128-bit power of five for the index.
+#[inline]
+#[cfg(not(feature = "wasm"))]
+fn power_of_five_128(index: usize) -> (u64, u64) {
+    POWER_OF_FIVE_128[index]
+}
+
+/// Get the normalized, 128-bit power of five for the index.
+///
+/// This recovers the power from the nearest smaller compressed power,
+/// multiplied by a small power of five, and then corrects the rounding
+/// error from the truncated bits.
+#[inline]
+#[cfg(feature = "wasm")]
+fn power_of_five_128(index: usize) -> (u64, u64) {
+    let (hi, lo) = COMPRESSED_POWER_OF_FIVE_128[index / POWER_OF_FIVE_STEP];
+    let offset = index % POWER_OF_FIVE_STEP;
+    if offset == 0 {
+        return (hi, lo);
+    }
+
+    // Calculate the 192-bit product, and keep the high 128 bits. The
+    // high word is always non-zero and less than `2^63`, so the shift
+    // is always in the range `[1, 63]`.
+    let pow5 = POWER_OF_FIVE_64[offset];
+    let (hi_lo, hi_hi) = full_multiplication(hi, pow5);
+    let (lo_lo, lo_hi) = full_multiplication(lo, pow5);
+    let (mid, carry) = hi_lo.overflowing_add(lo_hi);
+    let upper = hi_hi + carry as u64;
+    let shift = upper.leading_zeros();
+    let hi = (upper << shift) | (mid >> (64 - shift));
+    let lo = (mid << shift) | (lo_lo >> (64 - shift));
+
+    // Apply the correction, which is stored with a bias of 1.
+    let correction = (POWER_OF_FIVE_CORRECTION[index / 32] >> (2 * (index % 32))) & 3;
+    let (lo, carry) = lo.overflowing_add(correction);
+    let (lo, borrow) = lo.overflowing_sub(1);
+    (hi + carry as u64 - borrow as u64, lo)
+}
+
 // This will compute or rather approximate w * 5**q and return a pair of 64-bit words
 // approximating the result, with the "high" part corresponding to the most significant
 // bits and the low part corresponding to the least significant bits.
@@ -208,7 +278,7 @@ fn compute_product_approx(q: i64, w: u64, precision: usize) -> (u64, u64) {
     // That means whenever we need to round ties to even, we always have
     // an exact value.
     let index = (q - SMALLEST_POWER_OF_FIVE as i64) as usize;
-    let (lo5, hi5) = POWER_OF_FIVE_128[index];
+    let (lo5, hi5) = power_of_five_128(index);
     // Only need one multiplication as long as there is 1 zero but
     // in the explicit mantissa bits, +1 for the hidden bit, +1 to
     // determine the rounding direction, +1 for if the computed
@@ -30,12 +30,73 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `format` - Add support for parsing custom integer formats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `wasm` - Optimize for WebAssembly, with smaller pre-computed tables.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `no-panic` - Remove all reachable panics from the parsers.
+//! * `asm` - Use assembly instructions for wide multiplication on aarch64 and RISC-V.
 //! * `nightly` - Enable assembly instructions to control FPU rounding modes.
+//! * `arbitrary` - Implement `Arbitrary` for options, for fuzzing.
+//! * `build-tables` - Generate the tables for non-decimal radixes at build time.
+//! * `radix-N` - Add support for strings of a single radix, such as `radix-12`.
+//! * `lazy-tables` - Compute the tables of large powers on first use.
+//! * `tracing` - Emit trace events when parsing requires slower algorithms.
+//! * `stats` - Count the floats parsed by each algorithm in global counters.
 //!
 //! `safe` has a fairly minimal impact, since all parsers are memory-safe
 //! by default except where unsafe functionality can trivially be proven
-//! correct.
+//! correct. It does, however, bounds-check the unchecked operations in
+//! the big-integer arithmetic used by the slow path algorithms, which
+//! has a performance cost for very long inputs.
+//!
+//! `build-tables` generates the pre-computed tables for non-decimal
+//! radixes in a build script, only for the radixes listed in the
+//! `LEXICAL_PARSE_FLOAT_RADIXES` environment variable, as a comma-separated
+//! list such as `LEXICAL_PARSE_FLOAT_RADIXES=3,12`, trimming the tables
+//! for all other radixes. If the variable is unset, or if `radix` is
+//! also enabled, tables are generated for every radix. Decimal and
+//! power-of-two radixes are always supported, and parsing any other radix
+//! fails with [`Error::InvalidMantissaRadix`]. Radixes from 37 to 62,
+//! which use case-sensitive digits, `0-9a-zA-Z`, are only supported
+//! with `build-tables`, and must be listed in `LEXICAL_PARSE_FLOAT_RADIXES`
+//! even if `radix` is enabled.
+//!
+//! `radix-3` to `radix-36` add support for strings of a single radix,
+//! without the tables for any other radix. For radixes that are not
+//! decimal or a power-of-two, this enables `build-tables`, generating the
+//! tables for the radixes with an enabled feature, and any listed in
+//! `LEXICAL_PARSE_FLOAT_RADIXES`, so `radix-12` and `radix-36` only add
+//! the tables for those two radixes. These features are additive, so if
+//! another crate enables `radix`, the tables for every radix are still
+//! generated. The power-of-two radixes only enable `power-of-two`.
+//!
+//! `lazy-tables` replaces the static tables of large powers, used by the
+//! slow path algorithms and, for non-decimal radixes, the Bellerophon
+//! algorithm, with tables computed on the first use for each radix. This
+//! removes up to ~20 KB of tables from the binary with `radix`, for
+//! applications that rarely parse floats requiring the slow path.
+//!
+//! `tracing` emits [`tracing`](https://docs.rs/tracing) events, at the
+//! trace level, when the fast path cannot parse a float and the moderate
+//! path is used, and when the moderate path cannot correctly round it and
+//! the slow path is used. The events contain the radix, the significant
+//! digits and exponent, and the number of integer and fraction digits,
+//! to diagnose why some inputs are slow. This requires a newer Rust
+//! version than the minimum supported version.
+//!
+//! `stats` counts the floats parsed by the fast, moderate, and slow path
+//! algorithms, and the strings that failed to parse, in global relaxed
+//! atomic counters. `stats` returns a snapshot of the counters, giving
+//! long-running services cheap visibility into the input they parse,
+//! without the overhead of tracing. This requires atomic integers with
+//! compare-and-swap operations, which are not available on some embedded
+//! targets.
+//!
+//! `no-panic` returns [`Error::Internal`] if an internal invariant of the
+//! slow path algorithms, which hold for any valid input, does not hold,
+//! rather than panicking, so the parsers never panic. It cannot be
+//! combined with `safe`.
+//!
+//! [`Error::Internal`]: lexical_util::error::Error::Internal
 //!
 //! # Note
 //!
@@ -69,6 +130,21 @@
 //! assert_eq!(result, Ok(1.34000));
 //! ```
 //!
+//! # Rounding and Scaling
+//!
+//! The helpers used to round extended-precision floats to native floats
+//! are part of the public API, in the [`round`] module, for use by other
+//! parsing algorithms or float types. The helpers used by the slow path
+//! algorithms to compare digits to the halfway point between floats are
+//! in the [`scale`] module, for use by custom correctness checkers.
+//!
+//! # Compile-Time Parsing
+//!
+//! Decimal floats that do not require the slow path can be parsed in
+//! const contexts, using the `const fn` parsers in the [`constant`]
+//! module, or the [`const_f64`] and [`const_f32`] macros, to build static
+//! tables of floats from string data at compile time.
+//!
 //! # Version Support
 //!
 //! The minimum, standard, required version is 1.51.0, for const generic
@@ -95,9 +171,11 @@ mod index;
 #[macro_use]
 pub mod shared;
 
+pub mod arch;
 pub mod bellerophon;
 pub mod bigint;
 pub mod binary;
+pub mod constant;
 pub mod float;
 pub mod fpu;
 pub mod lemire;
@@ -107,28 +185,51 @@ pub mod mask;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod round;
+pub mod runtime;
+pub mod scale;
 pub mod slow;
 pub mod table;
 
 mod api;
+mod stats;
 mod table_bellerophon_decimal;
 mod table_bellerophon_radix;
 mod table_binary;
 mod table_decimal;
+mod table_generated;
 mod table_large;
+mod table_lazy;
 mod table_lemire;
 mod table_radix;
 mod table_small;
 
 // Re-exports
-pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::api::{
+    parse_narrow,
+    parse_narrow_with_options,
+    parse_partial_with_counts,
+    parse_partial_with_counts_with_options,
+    parse_partial_with_flags,
+    parse_partial_with_flags_with_options,
+    representation,
+    representation_with_options,
+    FromLexical,
+    FromLexicalWithOptions,
+    Narrow,
+};
+pub use self::bigint::MAX_SLOW_PATH_STACK_BYTES;
+pub use self::number::{DigitCounts, ParseFlags};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, OptionsOverride};
+pub use self::slow::Representation;
+#[cfg(feature = "stats")]
+pub use self::stats::{reset_stats, stats, Stats};
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 pub use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormatBuilder};
-pub use lexical_util::options::ParseOptions;
+pub use lexical_util::options::{OverrideOptions, ParseOptions};
 pub use lexical_util::result::Result;
@@ -103,40 +103,40 @@ impl ExactFloat for bf16 {
 //        debug_assert_radix(radix);
 //        match radix {
 //            2 if cfg!(feature = "power-of-two") => (-16494, 16383),
-//            3 if cfg!(feature = "radix") => (-71, 71),
+//            3 if cfg!(feature = "any-radix") => (-71, 71),
 //            4 if cfg!(feature = "power-of-two") => (-8247, 8191),
-//            5 if cfg!(feature = "radix") => (-48, 48),
-//            6 if cfg!(feature = "radix") => (-71, 71),
-//            7 if cfg!(feature = "radix") => (-40, 40),
+//            5 if cfg!(feature = "any-radix") => (-48, 48),
+//            6 if cfg!(feature = "any-radix") => (-71, 71),
+//            7 if cfg!(feature = "any-radix") => (-40, 40),
 //            8 if cfg!(feature = "power-of-two") => (-5498, 5461),
-//            9 if cfg!(feature = "radix") => (-35, 35),
+//            9 if cfg!(feature = "any-radix") => (-35, 35),
 //            10 => (-48, 48),
-//            11 if cfg!(feature = "radix") => (-32, 32),
-//            12 if cfg!(feature = "radix") => (-71, 71),
-//            13 if cfg!(feature = "radix") => (-30, 30),
-//            14 if cfg!(feature = "radix") => (-40, 40),
-//            15 if cfg!(feature = "radix") => (-28, 28),
+//            11 if cfg!(feature = "any-radix") => (-32, 32),
+//            12 if cfg!(feature = "any-radix") => (-71, 71),
+//            13 if cfg!(feature = "any-radix") => (-30, 30),
+//            14 if cfg!(feature = "any-radix") => (-40, 40),
+//            15 if cfg!(feature = "any-radix") => (-28, 28),
 //            16 if cfg!(feature = "power-of-two") => (-4123, 4095),
-//            17 if cfg!(feature = "radix") => (-27, 27),
-//            18 if cfg!(feature = "radix") => (-35, 35),
-//            19 if cfg!(feature = "radix") => (-26, 26),
-//            20 if cfg!(feature = "radix") => (-48, 48),
-//            21 if cfg!(feature = "radix") => (-25, 25),
-//            22 if cfg!(feature = "radix") => (-32, 32),
-//            23 if cfg!(feature = "radix") => (-24, 24),
-//            24 if cfg!(feature = "radix") => (-71, 71),
-//            25 if cfg!(feature = "radix") => (-24, 24),
-//            26 if cfg!(feature = "radix") => (-30, 30),
-//            27 if cfg!(feature = "radix") => (-23, 23),
-//            28 if cfg!(feature = "radix") => (-40, 40),
-//            29 if cfg!(feature = "radix") => (-23, 23),
-//            30 if cfg!(feature = "radix") => (-28, 28),
-//            31 if cfg!(feature = "radix") => (-22, 22),
+//            17 if cfg!(feature = "any-radix") => (-27, 27),
+//            18 if cfg!(feature = "any-radix") => (-35, 35),
+//            19 if cfg!(feature = "any-radix") => (-26, 26),
+//            20 if cfg!(feature = "any-radix") => (-48, 48),
+//            21 if cfg!(feature = "any-radix") => (-25, 25),
+//            22 if cfg!(feature = "any-radix") => (-32, 32),
+//            23 if cfg!(feature = "any-radix") => (-24, 24),
+//            24 if cfg!(feature = "any-radix") => (-71, 71),
+//            25 if cfg!(feature = "any-radix") => (-24, 24),
+//            26 if cfg!(feature = "any-radix") => (-30, 30),
+//            27 if cfg!(feature = "any-radix") => (-23, 23),
+//            28 if cfg!(feature = "any-radix") => (-40, 40),
+//            29 if cfg!(feature = "any-radix") => (-23, 23),
+//            30 if cfg!(feature = "any-radix") => (-28, 28),
+//            31 if cfg!(feature = "any-radix") => (-22, 22),
 //            32 if cfg!(feature = "power-of-two") => (-3298, 3276),
-//            33 if cfg!(feature = "radix") => (-22, 22),
-//            34 if cfg!(feature = "radix") => (-27, 27),
-//            35 if cfg!(feature = "radix") => (-22, 22),
-//            36 if cfg!(feature = "radix") => (-35, 35),
+//            33 if cfg!(feature = "any-radix") => (-22, 22),
+//            34 if cfg!(feature = "any-radix") => (-27, 27),
+//            35 if cfg!(feature = "any-radix") => (-22, 22),
+//            36 if cfg!(feature = "any-radix") => (-35, 35),
 //            // Invalid radix
 //            _ => unreachable!(),
 //        }
@@ -147,40 +147,40 @@ impl ExactFloat for bf16 {
 //        debug_assert_radix(radix);
 //        match radix {
 //            2 if cfg!(feature = "power-of-two") => 113,
-//            3 if cfg!(feature = "radix") => 71,
+//            3 if cfg!(feature = "any-radix") => 71,
 //            4 if cfg!(feature = "power-of-two") => 56,
-//            5 if cfg!(feature = "radix") => 48,
-//            6 if cfg!(feature = "radix") => 43,
-//            7 if cfg!(feature = "radix") => 40,
+//            5 if cfg!(feature = "any-radix") => 48,
+//            6 if cfg!(feature = "any-radix") => 43,
+//            7 if cfg!(feature = "any-radix") => 40,
 //            8 if cfg!(feature = "power-of-two") => 37,
-//            9 if cfg!(feature = "radix") => 35,
+//            9 if cfg!(feature = "any-radix") => 35,
 //            10 => 34,
-//            11 if cfg!(feature = "radix") => 32,
-//            12 if cfg!(feature = "radix") => 31,
-//            13 if cfg!(feature = "radix") => 30,
-//            14 if cfg!(feature = "radix") => 29,
-//            15 if cfg!(feature = "radix") => 28,
+//            11 if cfg!(feature = "any-radix") => 32,
+//            12 if cfg!(feature = "any-radix") => 31,
+//            13 if cfg!(feature = "any-radix") => 30,
+//            14 if cfg!(feature = "any-radix") => 29,
+//            15 if cfg!(feature = "any-radix") => 28,
 //            16 if cfg!(feature = "power-of-two") => 28,
-//            17 if cfg!(feature = "radix") => 27,
-//            18 if cfg!(feature = "radix") => 27,
-//            19 if cfg!(feature = "radix") => 26,
-//            20 if cfg!(feature = "radix") => 26,
-//            21 if cfg!(feature = "radix") => 25,
-//            22 if cfg!(feature = "radix") => 25,
-//            23 if cfg!(feature = "radix") => 24,
-//            24 if cfg!(feature = "radix") => 24,
-//            25 if cfg!(feature = "radix") => 24,
-//            26 if cfg!(feature = "radix") => 24,
-//            27 if cfg!(feature = "radix") => 23,
-//            28 if cfg!(feature = "radix") => 23,
-//            29 if cfg!(feature = "radix") => 23,
-//            30 if cfg!(feature = "radix") => 23,
-//            31 if cfg!(feature = "radix") => 22,
+//            17 if cfg!(feature = "any-radix") => 27,
+//            18 if cfg!(feature = "any-radix") => 27,
+//            19 if cfg!(feature = "any-radix") => 26,
+//            20 if cfg!(feature = "any-radix") => 26,
+//            21 if cfg!(feature = "any-radix") => 25,
+//            22 if cfg!(feature = "any-radix") => 25,
+//            23 if cfg!(feature = "any-radix") => 24,
+//            24 if cfg!(feature = "any-radix") => 24,
+//            25 if cfg!(feature = "any-radix") => 24,
+//            26 if cfg!(feature = "any-radix") => 24,
+//            27 if cfg!(feature = "any-radix") => 23,
+//            28 if cfg!(feature = "any-radix") => 23,
+//            29 if cfg!(feature = "any-radix") => 23,
+//            30 if cfg!(feature = "any-radix") => 23,
+//            31 if cfg!(feature = "any-radix") => 22,
 //            32 if cfg!(feature = "power-of-two") => 22,
-//            33 if cfg!(feature = "radix") => 22,
-//            34 if cfg!(feature = "radix") => 22,
-//            35 if cfg!(feature = "radix") => 22,
-//            36 if cfg!(feature = "radix") => 21,
+//            33 if cfg!(feature = "any-radix") => 22,
+//            34 if cfg!(feature = "any-radix") => 22,
+//            35 if cfg!(feature = "any-radix") => 22,
+//            36 if cfg!(feature = "any-radix") => 21,
 //            // Invalid radix
 //            _ => unreachable!(),
 //        }
@@ -195,40 +195,66 @@ impl ExactFloat for bf16 {
 pub const fn f32_exponent_limit(radix: u32) -> (i64, i64) {
     match radix {
         2 if cfg!(feature = "power-of-two") => (-127, 127),
-        3 if cfg!(feature = "radix") => (-15, 15),
+        3 if cfg!(feature = "any-radix") => (-15, 15),
         4 if cfg!(feature = "power-of-two") => (-63, 63),
-        5 if cfg!(feature = "radix") => (-10, 10),
-        6 if cfg!(feature = "radix") => (-15, 15),
-        7 if cfg!(feature = "radix") => (-8, 8),
+        5 if cfg!(feature = "any-radix") => (-10, 10),
+        6 if cfg!(feature = "any-radix") => (-15, 15),
+        7 if cfg!(feature = "any-radix") => (-8, 8),
         8 if cfg!(feature = "power-of-two") => (-42, 42),
-        9 if cfg!(feature = "radix") => (-7, 7),
+        9 if cfg!(feature = "any-radix") => (-7, 7),
         10 => (-10, 10),
-        11 if cfg!(feature = "radix") => (-6, 6),
-        12 if cfg!(feature = "radix") => (-15, 15),
-        13 if cfg!(feature = "radix") => (-6, 6),
-        14 if cfg!(feature = "radix") => (-8, 8),
-        15 if cfg!(feature = "radix") => (-6, 6),
+        11 if cfg!(feature = "any-radix") => (-6, 6),
+        12 if cfg!(feature = "any-radix") => (-15, 15),
+        13 if cfg!(feature = "any-radix") => (-6, 6),
+        14 if cfg!(feature = "any-radix") => (-8, 8),
+        15 if cfg!(feature = "any-radix") => (-6, 6),
         16 if cfg!(feature = "power-of-two") => (-31, 31),
-        17 if cfg!(feature = "radix") => (-5, 5),
-        18 if cfg!(feature = "radix") => (-7, 7),
-        19 if cfg!(feature = "radix") => (-5, 5),
-        20 if cfg!(feature = "radix") => (-10, 10),
-        21 if cfg!(feature = "radix") => (-5, 5),
-        22 if cfg!(feature = "radix") => (-6, 6),
-        23 if cfg!(feature = "radix") => (-5, 5),
-        24 if cfg!(feature = "radix") => (-15, 15),
-        25 if cfg!(feature = "radix") => (-5, 5),
-        26 if cfg!(feature = "radix") => (-6, 6),
-        27 if cfg!(feature = "radix") => (-5, 5),
-        28 if cfg!(feature = "radix") => (-8, 8),
-        29 if cfg!(feature = "radix") => (-4, 4),
-        30 if cfg!(feature = "radix") => (-6, 6),
-        31 if cfg!(feature = "radix") => (-4, 4),
+        17 if cfg!(feature = "any-radix") => (-5, 5),
+        18 if cfg!(feature = "any-radix") => (-7, 7),
+        19 if cfg!(feature = "any-radix") => (-5, 5),
+        20 if cfg!(feature = "any-radix") => (-10, 10),
+        21 if cfg!(feature = "any-radix") => (-5, 5),
+        22 if cfg!(feature = "any-radix") => (-6, 6),
+        23 if cfg!(feature = "any-radix") => (-5, 5),
+        24 if cfg!(feature = "any-radix") => (-15, 15),
+        25 if cfg!(feature = "any-radix") => (-5, 5),
+        26 if cfg!(feature = "any-radix") => (-6, 6),
+        27 if cfg!(feature = "any-radix") => (-5, 5),
+        28 if cfg!(feature = "any-radix") => (-8, 8),
+        29 if cfg!(feature = "any-radix") => (-4, 4),
+        30 if cfg!(feature = "any-radix") => (-6, 6),
+        31 if cfg!(feature = "any-radix") => (-4, 4),
         32 if cfg!(feature = "power-of-two") => (-25, 25),
-        33 if cfg!(feature = "radix") => (-4, 4),
-        34 if cfg!(feature = "radix") => (-5, 5),
-        35 if cfg!(feature = "radix") => (-4, 4),
-        36 if cfg!(feature = "radix") => (-7, 7),
+        33 if cfg!(feature = "any-radix") => (-4, 4),
+        34 if cfg!(feature = "any-radix") => (-5, 5),
+        35 if cfg!(feature = "any-radix") => (-4, 4),
+        36 if cfg!(feature = "any-radix") => (-7, 7),
+        37 if cfg!(feature = "any-radix") => (-4, 4),
+        38 if cfg!(feature = "any-radix") => (-5, 5),
+        39 if cfg!(feature = "any-radix") => (-4, 4),
+        40 if cfg!(feature = "any-radix") => (-10, 10),
+        41 if cfg!(feature = "any-radix") => (-4, 4),
+        42 if cfg!(feature = "any-radix") => (-5, 5),
+        43 if cfg!(feature = "any-radix") => (-4, 4),
+        44 if cfg!(feature = "any-radix") => (-6, 6),
+        45 if cfg!(feature = "any-radix") => (-4, 4),
+        46 if cfg!(feature = "any-radix") => (-5, 5),
+        47 if cfg!(feature = "any-radix") => (-4, 4),
+        48 if cfg!(feature = "any-radix") => (-15, 15),
+        49 if cfg!(feature = "any-radix") => (-4, 4),
+        50 if cfg!(feature = "any-radix") => (-5, 5),
+        51 if cfg!(feature = "any-radix") => (-4, 4),
+        52 if cfg!(feature = "any-radix") => (-6, 6),
+        53 if cfg!(feature = "any-radix") => (-4, 4),
+        54 if cfg!(feature = "any-radix") => (-5, 5),
+        55 if cfg!(feature = "any-radix") => (-4, 4),
+        56 if cfg!(feature = "any-radix") => (-8, 8),
+        57 if cfg!(feature = "any-radix") => (-4, 4),
+        58 if cfg!(feature = "any-radix") => (-4, 4),
+        59 if cfg!(feature = "any-radix") => (-4, 4),
+        60 if cfg!(feature = "any-radix") => (-6, 6),
+        61 if cfg!(feature = "any-radix") => (-4, 4),
+        62 if cfg!(feature = "any-radix") => (-4, 4),
         _ => (0, 0),
     }
 }
@@ -238,40 +264,66 @@ pub const fn f32_exponent_limit(radix: u32) -> (i64, i64) {
 pub const fn f32_mantissa_limit(radix: u32) -> i64 {
     match radix {
         2 if cfg!(feature = "power-of-two") => 24,
-        3 if cfg!(feature = "radix") => 15,
+        3 if cfg!(feature = "any-radix") => 15,
         4 if cfg!(feature = "power-of-two") => 12,
-        5 if cfg!(feature = "radix") => 10,
-        6 if cfg!(feature = "radix") => 9,
-        7 if cfg!(feature = "radix") => 8,
+        5 if cfg!(feature = "any-radix") => 10,
+        6 if cfg!(feature = "any-radix") => 9,
+        7 if cfg!(feature = "any-radix") => 8,
         8 if cfg!(feature = "power-of-two") => 8,
-        9 if cfg!(feature = "radix") => 7,
+        9 if cfg!(feature = "any-radix") => 7,
         10 => 7,
-        11 if cfg!(feature = "radix") => 6,
-        12 if cfg!(feature = "radix") => 6,
-        13 if cfg!(feature = "radix") => 6,
-        14 if cfg!(feature = "radix") => 6,
-        15 if cfg!(feature = "radix") => 6,
+        11 if cfg!(feature = "any-radix") => 6,
+        12 if cfg!(feature = "any-radix") => 6,
+        13 if cfg!(feature = "any-radix") => 6,
+        14 if cfg!(feature = "any-radix") => 6,
+        15 if cfg!(feature = "any-radix") => 6,
         16 if cfg!(feature = "power-of-two") => 6,
-        17 if cfg!(feature = "radix") => 5,
-        18 if cfg!(feature = "radix") => 5,
-        19 if cfg!(feature = "radix") => 5,
-        20 if cfg!(feature = "radix") => 5,
-        21 if cfg!(feature = "radix") => 5,
-        22 if cfg!(feature = "radix") => 5,
-        23 if cfg!(feature = "radix") => 5,
-        24 if cfg!(feature = "radix") => 5,
-        25 if cfg!(feature = "radix") => 5,
-        26 if cfg!(feature = "radix") => 5,
-        27 if cfg!(feature = "radix") => 5,
-        28 if cfg!(feature = "radix") => 4,
-        29 if cfg!(feature = "radix") => 4,
-        30 if cfg!(feature = "radix") => 4,
-        31 if cfg!(feature = "radix") => 4,
+        17 if cfg!(feature = "any-radix") => 5,
+        18 if cfg!(feature = "any-radix") => 5,
+        19 if cfg!(feature = "any-radix") => 5,
+        20 if cfg!(feature = "any-radix") => 5,
+        21 if cfg!(feature = "any-radix") => 5,
+        22 if cfg!(feature = "any-radix") => 5,
+        23 if cfg!(feature = "any-radix") => 5,
+        24 if cfg!(feature = "any-radix") => 5,
+        25 if cfg!(feature = "any-radix") => 5,
+        26 if cfg!(feature = "any-radix") => 5,
+        27 if cfg!(feature = "any-radix") => 5,
+        28 if cfg!(feature = "any-radix") => 4,
+        29 if cfg!(feature = "any-radix") => 4,
+        30 if cfg!(feature = "any-radix") => 4,
+        31 if cfg!(feature = "any-radix") => 4,
         32 if cfg!(feature = "power-of-two") => 4,
-        33 if cfg!(feature = "radix") => 4,
-        34 if cfg!(feature = "radix") => 4,
-        35 if cfg!(feature = "radix") => 4,
-        36 if cfg!(feature = "radix") => 4,
+        33 if cfg!(feature = "any-radix") => 4,
+        34 if cfg!(feature = "any-radix") => 4,
+        35 if cfg!(feature = "any-radix") => 4,
+        36 if cfg!(feature = "any-radix") => 4,
+        37 if cfg!(feature = "any-radix") => 4,
+        38 if cfg!(feature = "any-radix") => 4,
+        39 if cfg!(feature = "any-radix") => 4,
+        40 if cfg!(feature = "any-radix") => 4,
+        41 if cfg!(feature = "any-radix") => 4,
+        42 if cfg!(feature = "any-radix") => 4,
+        43 if cfg!(feature = "any-radix") => 4,
+        44 if cfg!(feature = "any-radix") => 4,
+        45 if cfg!(feature = "any-radix") => 4,
+        46 if cfg!(feature = "any-radix") => 4,
+        47 if cfg!(feature = "any-radix") => 4,
+        48 if cfg!(feature = "any-radix") => 4,
+        49 if cfg!(feature = "any-radix") => 4,
+        50 if cfg!(feature = "any-radix") => 4,
+        51 if cfg!(feature = "any-radix") => 4,
+        52 if cfg!(feature = "any-radix") => 4,
+        53 if cfg!(feature = "any-radix") => 4,
+        54 if cfg!(feature = "any-radix") => 4,
+        55 if cfg!(feature = "any-radix") => 4,
+        56 if cfg!(feature = "any-radix") => 4,
+        57 if cfg!(feature = "any-radix") => 4,
+        58 if cfg!(feature = "any-radix") => 4,
+        59 if cfg!(feature = "any-radix") => 4,
+        60 if cfg!(feature = "any-radix") => 4,
+        61 if cfg!(feature = "any-radix") => 4,
+        62 if cfg!(feature = "any-radix") => 4,
         _ => 0,
     }
 }
@@ -281,40 +333,66 @@ pub const fn f32_mantissa_limit(radix: u32) -> i64 {
 pub const fn f64_exponent_limit(radix: u32) -> (i64, i64) {
     match radix {
         2 if cfg!(feature = "power-of-two") => (-1023, 1023),
-        3 if cfg!(feature = "radix") => (-33, 33),
+        3 if cfg!(feature = "any-radix") => (-33, 33),
         4 if cfg!(feature = "power-of-two") => (-511, 511),
-        5 if cfg!(feature = "radix") => (-22, 22),
-        6 if cfg!(feature = "radix") => (-33, 33),
-        7 if cfg!(feature = "radix") => (-18, 18),
+        5 if cfg!(feature = "any-radix") => (-22, 22),
+        6 if cfg!(feature = "any-radix") => (-33, 33),
+        7 if cfg!(feature = "any-radix") => (-18, 18),
         8 if cfg!(feature = "power-of-two") => (-341, 341),
-        9 if cfg!(feature = "radix") => (-16, 16),
+        9 if cfg!(feature = "any-radix") => (-16, 16),
         10 => (-22, 22),
-        11 if cfg!(feature = "radix") => (-15, 15),
-        12 if cfg!(feature = "radix") => (-33, 33),
-        13 if cfg!(feature = "radix") => (-14, 14),
-        14 if cfg!(feature = "radix") => (-18, 18),
-        15 if cfg!(feature = "radix") => (-13, 13),
+        11 if cfg!(feature = "any-radix") => (-15, 15),
+        12 if cfg!(feature = "any-radix") => (-33, 33),
+        13 if cfg!(feature = "any-radix") => (-14, 14),
+        14 if cfg!(feature = "any-radix") => (-18, 18),
+        15 if cfg!(feature = "any-radix") => (-13, 13),
         16 if cfg!(feature = "power-of-two") => (-255, 255),
-        17 if cfg!(feature = "radix") => (-12, 12),
-        18 if cfg!(feature = "radix") => (-16, 16),
-        19 if cfg!(feature = "radix") => (-12, 12),
-        20 if cfg!(feature = "radix") => (-22, 22),
-        21 if cfg!(feature = "radix") => (-12, 12),
-        22 if cfg!(feature = "radix") => (-15, 15),
-        23 if cfg!(feature = "radix") => (-11, 11),
-        24 if cfg!(feature = "radix") => (-33, 33),
-        25 if cfg!(feature = "radix") => (-11, 11),
-        26 if cfg!(feature = "radix") => (-14, 14),
-        27 if cfg!(feature = "radix") => (-11, 11),
-        28 if cfg!(feature = "radix") => (-18, 18),
-        29 if cfg!(feature = "radix") => (-10, 10),
-        30 if cfg!(feature = "radix") => (-13, 13),
-        31 if cfg!(feature = "radix") => (-10, 10),
+        17 if cfg!(feature = "any-radix") => (-12, 12),
+        18 if cfg!(feature = "any-radix") => (-16, 16),
+        19 if cfg!(feature = "any-radix") => (-12, 12),
+        20 if cfg!(feature = "any-radix") => (-22, 22),
+        21 if cfg!(feature = "any-radix") => (-12, 12),
+        22 if cfg!(feature = "any-radix") => (-15, 15),
+        23 if cfg!(feature = "any-radix") => (-11, 11),
+        24 if cfg!(feature = "any-radix") => (-33, 33),
+        25 if cfg!(feature = "any-radix") => (-11, 11),
+        26 if cfg!(feature = "any-radix") => (-14, 14),
+        27 if cfg!(feature = "any-radix") => (-11, 11),
+        28 if cfg!(feature = "any-radix") => (-18, 18),
+        29 if cfg!(feature = "any-radix") => (-10, 10),
+        30 if cfg!(feature = "any-radix") => (-13, 13),
+        31 if cfg!(feature = "any-radix") => (-10, 10),
         32 if cfg!(feature = "power-of-two") => (-204, 204),
-        33 if cfg!(feature = "radix") => (-10, 10),
-        34 if cfg!(feature = "radix") => (-12, 12),
-        35 if cfg!(feature = "radix") => (-10, 10),
-        36 if cfg!(feature = "radix") => (-16, 16),
+        33 if cfg!(feature = "any-radix") => (-10, 10),
+        34 if cfg!(feature = "any-radix") => (-12, 12),
+        35 if cfg!(feature = "any-radix") => (-10, 10),
+        36 if cfg!(feature = "any-radix") => (-16, 16),
+        37 if cfg!(feature = "any-radix") => (-10, 10),
+        38 if cfg!(feature = "any-radix") => (-12, 12),
+        39 if cfg!(feature = "any-radix") => (-10, 10),
+        40 if cfg!(feature = "any-radix") => (-22, 22),
+        41 if cfg!(feature = "any-radix") => (-9, 9),
+        42 if cfg!(feature = "any-radix") => (-12, 12),
+        43 if cfg!(feature = "any-radix") => (-9, 9),
+        44 if cfg!(feature = "any-radix") => (-15, 15),
+        45 if cfg!(feature = "any-radix") => (-9, 9),
+        46 if cfg!(feature = "any-radix") => (-11, 11),
+        47 if cfg!(feature = "any-radix") => (-9, 9),
+        48 if cfg!(feature = "any-radix") => (-33, 33),
+        49 if cfg!(feature = "any-radix") => (-9, 9),
+        50 if cfg!(feature = "any-radix") => (-11, 11),
+        51 if cfg!(feature = "any-radix") => (-9, 9),
+        52 if cfg!(feature = "any-radix") => (-14, 14),
+        53 if cfg!(feature = "any-radix") => (-9, 9),
+        54 if cfg!(feature = "any-radix") => (-11, 11),
+        55 if cfg!(feature = "any-radix") => (-9, 9),
+        56 if cfg!(feature = "any-radix") => (-18, 18),
+        57 if cfg!(feature = "any-radix") => (-9, 9),
+        58 if cfg!(feature = "any-radix") => (-10, 10),
+        59 if cfg!(feature = "any-radix") => (-9, 9),
+        60 if cfg!(feature = "any-radix") => (-13, 13),
+        61 if cfg!(feature = "any-radix") => (-8, 8),
+        62 if cfg!(feature = "any-radix") => (-10, 10),
         _ => (0, 0),
     }
 }
@@ -324,40 +402,66 @@ pub const fn f64_exponent_limit(radix: u32) -> (i64, i64) {
 pub const fn f64_mantissa_limit(radix: u32) -> i64 {
     match radix {
         2 if cfg!(feature = "power-of-two") => 53,
-        3 if cfg!(feature = "radix") => 33,
+        3 if cfg!(feature = "any-radix") => 33,
         4 if cfg!(feature = "power-of-two") => 26,
-        5 if cfg!(feature = "radix") => 22,
-        6 if cfg!(feature = "radix") => 20,
-        7 if cfg!(feature = "radix") => 18,
+        5 if cfg!(feature = "any-radix") => 22,
+        6 if cfg!(feature = "any-radix") => 20,
+        7 if cfg!(feature = "any-radix") => 18,
         8 if cfg!(feature = "power-of-two") => 17,
-        9 if cfg!(feature = "radix") => 16,
+        9 if cfg!(feature = "any-radix") => 16,
         10 => 15,
-        11 if cfg!(feature = "radix") => 15,
-        12 if cfg!(feature = "radix") => 14,
-        13 if cfg!(feature = "radix") => 14,
-        14 if cfg!(feature = "radix") => 13,
-        15 if cfg!(feature = "radix") => 13,
+        11 if cfg!(feature = "any-radix") => 15,
+        12 if cfg!(feature = "any-radix") => 14,
+        13 if cfg!(feature = "any-radix") => 14,
+        14 if cfg!(feature = "any-radix") => 13,
+        15 if cfg!(feature = "any-radix") => 13,
         16 if cfg!(feature = "power-of-two") => 13,
-        17 if cfg!(feature = "radix") => 12,
-        18 if cfg!(feature = "radix") => 12,
-        19 if cfg!(feature = "radix") => 12,
-        20 if cfg!(feature = "radix") => 12,
-        21 if cfg!(feature = "radix") => 12,
-        22 if cfg!(feature = "radix") => 11,
-        23 if cfg!(feature = "radix") => 11,
-        24 if cfg!(feature = "radix") => 11,
-        25 if cfg!(feature = "radix") => 11,
-        26 if cfg!(feature = "radix") => 11,
-        27 if cfg!(feature = "radix") => 11,
-        28 if cfg!(feature = "radix") => 11,
-        29 if cfg!(feature = "radix") => 10,
-        30 if cfg!(feature = "radix") => 10,
-        31 if cfg!(feature = "radix") => 10,
+        17 if cfg!(feature = "any-radix") => 12,
+        18 if cfg!(feature = "any-radix") => 12,
+        19 if cfg!(feature = "any-radix") => 12,
+        20 if cfg!(feature = "any-radix") => 12,
+        21 if cfg!(feature = "any-radix") => 12,
+        22 if cfg!(feature = "any-radix") => 11,
+        23 if cfg!(feature = "any-radix") => 11,
+        24 if cfg!(feature = "any-radix") => 11,
+        25 if cfg!(feature = "any-radix") => 11,
+        26 if cfg!(feature = "any-radix") => 11,
+        27 if cfg!(feature = "any-radix") => 11,
+        28 if cfg!(feature = "any-radix") => 11,
+        29 if cfg!(feature = "any-radix") => 10,
+        30 if cfg!(feature = "any-radix") => 10,
+        31 if cfg!(feature = "any-radix") => 10,
         32 if cfg!(feature = "power-of-two") => 10,
-        33 if cfg!(feature = "radix") => 10,
-        34 if cfg!(feature = "radix") => 10,
-        35 if cfg!(feature = "radix") => 10,
-        36 if cfg!(feature = "radix") => 10,
+        33 if cfg!(feature = "any-radix") => 10,
+        34 if cfg!(feature = "any-radix") => 10,
+        35 if cfg!(feature = "any-radix") => 10,
+        36 if cfg!(feature = "any-radix") => 10,
+        37 if cfg!(feature = "any-radix") => 10,
+        38 if cfg!(feature = "any-radix") => 10,
+        39 if cfg!(feature = "any-radix") => 10,
+        40 if cfg!(feature = "any-radix") => 9,
+        41 if cfg!(feature = "any-radix") => 9,
+        42 if cfg!(feature = "any-radix") => 9,
+        43 if cfg!(feature = "any-radix") => 9,
+        44 if cfg!(feature = "any-radix") => 9,
+        45 if cfg!(feature = "any-radix") => 9,
+        46 if cfg!(feature = "any-radix") => 9,
+        47 if cfg!(feature = "any-radix") => 9,
+        48 if cfg!(feature = "any-radix") => 9,
+        49 if cfg!(feature = "any-radix") => 9,
+        50 if cfg!(feature = "any-radix") => 9,
+        51 if cfg!(feature = "any-radix") => 9,
+        52 if cfg!(feature = "any-radix") => 9,
+        53 if cfg!(feature = "any-radix") => 9,
+        54 if cfg!(feature = "any-radix") => 9,
+        55 if cfg!(feature = "any-radix") => 9,
+        56 if cfg!(feature = "any-radix") => 9,
+        57 if cfg!(feature = "any-radix") => 9,
+        58 if cfg!(feature = "any-radix") => 9,
+        59 if cfg!(feature = "any-radix") => 9,
+        60 if cfg!(feature = "any-radix") => 8,
+        61 if cfg!(feature = "any-radix") => 8,
+        62 if cfg!(feature = "any-radix") => 8,
         _ => 0,
     }
 }
@@ -383,7 +487,7 @@ pub const fn f64_mantissa_limit(radix: u32) -> i64 {
 //      print(f'pub const fn u{bits}_power_limit(radix: u32) -> u32 {{')
 //      print('    match radix {')
 //      max_value = 2**bits - 1
-//      for radix in range(2, 37):
+//      for radix in range(2, 63):
 //          power = find_power(radix, max_value)
 //          print(f'        {radix} => {power},')
 //      print('        // Any other radix should be unreachable.')
@@ -402,40 +506,66 @@ pub const fn f64_mantissa_limit(radix: u32) -> i64 {
 pub const fn u32_power_limit(radix: u32) -> u32 {
     match radix {
         2 if cfg!(feature = "power-of-two") => 31,
-        3 if cfg!(feature = "radix") => 20,
+        3 if cfg!(feature = "any-radix") => 20,
         4 if cfg!(feature = "power-of-two") => 15,
         5 => 13,
-        6 if cfg!(feature = "radix") => 12,
-        7 if cfg!(feature = "radix") => 11,
+        6 if cfg!(feature = "any-radix") => 12,
+        7 if cfg!(feature = "any-radix") => 11,
         8 if cfg!(feature = "power-of-two") => 10,
-        9 if cfg!(feature = "radix") => 10,
+        9 if cfg!(feature = "any-radix") => 10,
         10 => 9,
-        11 if cfg!(feature = "radix") => 9,
-        12 if cfg!(feature = "radix") => 8,
-        13 if cfg!(feature = "radix") => 8,
-        14 if cfg!(feature = "radix") => 8,
-        15 if cfg!(feature = "radix") => 8,
+        11 if cfg!(feature = "any-radix") => 9,
+        12 if cfg!(feature = "any-radix") => 8,
+        13 if cfg!(feature = "any-radix") => 8,
+        14 if cfg!(feature = "any-radix") => 8,
+        15 if cfg!(feature = "any-radix") => 8,
         16 if cfg!(feature = "power-of-two") => 7,
-        17 if cfg!(feature = "radix") => 7,
-        18 if cfg!(feature = "radix") => 7,
-        19 if cfg!(feature = "radix") => 7,
-        20 if cfg!(feature = "radix") => 7,
-        21 if cfg!(feature = "radix") => 7,
-        22 if cfg!(feature = "radix") => 7,
-        23 if cfg!(feature = "radix") => 7,
-        24 if cfg!(feature = "radix") => 6,
-        25 if cfg!(feature = "radix") => 6,
-        26 if cfg!(feature = "radix") => 6,
-        27 if cfg!(feature = "radix") => 6,
-        28 if cfg!(feature = "radix") => 6,
-        29 if cfg!(feature = "radix") => 6,
-        30 if cfg!(feature = "radix") => 6,
-        31 if cfg!(feature = "radix") => 6,
+        17 if cfg!(feature = "any-radix") => 7,
+        18 if cfg!(feature = "any-radix") => 7,
+        19 if cfg!(feature = "any-radix") => 7,
+        20 if cfg!(feature = "any-radix") => 7,
+        21 if cfg!(feature = "any-radix") => 7,
+        22 if cfg!(feature = "any-radix") => 7,
+        23 if cfg!(feature = "any-radix") => 7,
+        24 if cfg!(feature = "any-radix") => 6,
+        25 if cfg!(feature = "any-radix") => 6,
+        26 if cfg!(feature = "any-radix") => 6,
+        27 if cfg!(feature = "any-radix") => 6,
+        28 if cfg!(feature = "any-radix") => 6,
+        29 if cfg!(feature = "any-radix") => 6,
+        30 if cfg!(feature = "any-radix") => 6,
+        31 if cfg!(feature = "any-radix") => 6,
         32 if cfg!(feature = "power-of-two") => 6,
-        33 if cfg!(feature = "radix") => 6,
-        34 if cfg!(feature = "radix") => 6,
-        35 if cfg!(feature = "radix") => 6,
-        36 if cfg!(feature = "radix") => 6,
+        33 if cfg!(feature = "any-radix") => 6,
+        34 if cfg!(feature = "any-radix") => 6,
+        35 if cfg!(feature = "any-radix") => 6,
+        36 if cfg!(feature = "any-radix") => 6,
+        37 if cfg!(feature = "any-radix") => 6,
+        38 if cfg!(feature = "any-radix") => 6,
+        39 if cfg!(feature = "any-radix") => 6,
+        40 if cfg!(feature = "any-radix") => 6,
+        41 if cfg!(feature = "any-radix") => 5,
+        42 if cfg!(feature = "any-radix") => 5,
+        43 if cfg!(feature = "any-radix") => 5,
+        44 if cfg!(feature = "any-radix") => 5,
+        45 if cfg!(feature = "any-radix") => 5,
+        46 if cfg!(feature = "any-radix") => 5,
+        47 if cfg!(feature = "any-radix") => 5,
+        48 if cfg!(feature = "any-radix") => 5,
+        49 if cfg!(feature = "any-radix") => 5,
+        50 if cfg!(feature = "any-radix") => 5,
+        51 if cfg!(feature = "any-radix") => 5,
+        52 if cfg!(feature = "any-radix") => 5,
+        53 if cfg!(feature = "any-radix") => 5,
+        54 if cfg!(feature = "any-radix") => 5,
+        55 if cfg!(feature = "any-radix") => 5,
+        56 if cfg!(feature = "any-radix") => 5,
+        57 if cfg!(feature = "any-radix") => 5,
+        58 if cfg!(feature = "any-radix") => 5,
+        59 if cfg!(feature = "any-radix") => 5,
+        60 if cfg!(feature = "any-radix") => 5,
+        61 if cfg!(feature = "any-radix") => 5,
+        62 if cfg!(feature = "any-radix") => 5,
         // Any other radix should be unreachable.
         _ => 1,
     }
@@ -447,40 +577,66 @@ pub const fn u32_power_limit(radix: u32) -> u32 {
 pub const fn u64_power_limit(radix: u32) -> u32 {
     match radix {
         2 if cfg!(feature = "power-of-two") => 63,
-        3 if cfg!(feature = "radix") => 40,
+        3 if cfg!(feature = "any-radix") => 40,
         4 if cfg!(feature = "power-of-two") => 31,
         5 => 27,
-        6 if cfg!(feature = "radix") => 24,
-        7 if cfg!(feature = "radix") => 22,
+        6 if cfg!(feature = "any-radix") => 24,
+        7 if cfg!(feature = "any-radix") => 22,
         8 if cfg!(feature = "power-of-two") => 21,
-        9 if cfg!(feature = "radix") => 20,
+        9 if cfg!(feature = "any-radix") => 20,
         10 => 19,
-        11 if cfg!(feature = "radix") => 18,
-        12 if cfg!(feature = "radix") => 17,
-        13 if cfg!(feature = "radix") => 17,
-        14 if cfg!(feature = "radix") => 16,
-        15 if cfg!(feature = "radix") => 16,
+        11 if cfg!(feature = "any-radix") => 18,
+        12 if cfg!(feature = "any-radix") => 17,
+        13 if cfg!(feature = "any-radix") => 17,
+        14 if cfg!(feature = "any-radix") => 16,
+        15 if cfg!(feature = "any-radix") => 16,
         16 if cfg!(feature = "power-of-two") => 15,
-        17 if cfg!(feature = "radix") => 15,
-        18 if cfg!(feature = "radix") => 15,
-        19 if cfg!(feature = "radix") => 15,
-        20 if cfg!(feature = "radix") => 14,
-        21 if cfg!(feature = "radix") => 14,
-        22 if cfg!(feature = "radix") => 14,
-        23 if cfg!(feature = "radix") => 14,
-        24 if cfg!(feature = "radix") => 13,
-        25 if cfg!(feature = "radix") => 13,
-        26 if cfg!(feature = "radix") => 13,
-        27 if cfg!(feature = "radix") => 13,
-        28 if cfg!(feature = "radix") => 13,
-        29 if cfg!(feature = "radix") => 13,
-        30 if cfg!(feature = "radix") => 13,
-        31 if cfg!(feature = "radix") => 12,
+        17 if cfg!(feature = "any-radix") => 15,
+        18 if cfg!(feature = "any-radix") => 15,
+        19 if cfg!(feature = "any-radix") => 15,
+        20 if cfg!(feature = "any-radix") => 14,
+        21 if cfg!(feature = "any-radix") => 14,
+        22 if cfg!(feature = "any-radix") => 14,
+        23 if cfg!(feature = "any-radix") => 14,
+        24 if cfg!(feature = "any-radix") => 13,
+        25 if cfg!(feature = "any-radix") => 13,
+        26 if cfg!(feature = "any-radix") => 13,
+        27 if cfg!(feature = "any-radix") => 13,
+        28 if cfg!(feature = "any-radix") => 13,
+        29 if cfg!(feature = "any-radix") => 13,
+        30 if cfg!(feature = "any-radix") => 13,
+        31 if cfg!(feature = "any-radix") => 12,
         32 if cfg!(feature = "power-of-two") => 12,
-        33 if cfg!(feature = "radix") => 12,
-        34 if cfg!(feature = "radix") => 12,
-        35 if cfg!(feature = "radix") => 12,
-        36 if cfg!(feature = "radix") => 12,
+        33 if cfg!(feature = "any-radix") => 12,
+        34 if cfg!(feature = "any-radix") => 12,
+        35 if cfg!(feature = "any-radix") => 12,
+        36 if cfg!(feature = "any-radix") => 12,
+        37 if cfg!(feature = "any-radix") => 12,
+        38 if cfg!(feature = "any-radix") => 12,
+        39 if cfg!(feature = "any-radix") => 12,
+        40 if cfg!(feature = "any-radix") => 12,
+        41 if cfg!(feature = "any-radix") => 11,
+        42 if cfg!(feature = "any-radix") => 11,
+        43 if cfg!(feature = "any-radix") => 11,
+        44 if cfg!(feature = "any-radix") => 11,
+        45 if cfg!(feature = "any-radix") => 11,
+        46 if cfg!(feature = "any-radix") => 11,
+        47 if cfg!(feature = "any-radix") => 11,
+        48 if cfg!(feature = "any-radix") => 11,
+        49 if cfg!(feature = "any-radix") => 11,
+        50 if cfg!(feature = "any-radix") => 11,
+        51 if cfg!(feature = "any-radix") => 11,
+        52 if cfg!(feature = "any-radix") => 11,
+        53 if cfg!(feature = "any-radix") => 11,
+        54 if cfg!(feature = "any-radix") => 11,
+        55 if cfg!(feature = "any-radix") => 11,
+        56 if cfg!(feature = "any-radix") => 11,
+        57 if cfg!(feature = "any-radix") => 10,
+        58 if cfg!(feature = "any-radix") => 10,
+        59 if cfg!(feature = "any-radix") => 10,
+        60 if cfg!(feature = "any-radix") => 10,
+        61 if cfg!(feature = "any-radix") => 10,
+        62 if cfg!(feature = "any-radix") => 10,
         // Any other radix should be unreachable.
         _ => 1,
     }
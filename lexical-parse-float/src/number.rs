@@ -137,3 +137,39 @@ impl<'a> Number<'a> {
         value
     }
 }
+
+/// The number of digits and digit separators consumed by a parser.
+///
+/// The digits are counted for each component of the number, excluding
+/// any base prefix or suffix, and separators are counted across the entire
+/// number. Special values, such as `NaN` or `inf`, have no digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DigitCounts {
+    /// The number of integer digits.
+    pub integer: usize,
+    /// The number of fraction digits.
+    pub fraction: usize,
+    /// The number of exponent digits.
+    pub exponent: usize,
+    /// The number of digit separators skipped.
+    pub separators: usize,
+}
+
+/// The syntax used by a number consumed by a parser.
+///
+/// This describes the optional components of the number, for linters, or
+/// to preserve the original style when writing the number. Special values,
+/// such as `NaN` or `inf`, only set `is_special`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseFlags {
+    /// If the number had an exponent.
+    pub has_exponent: bool,
+    /// If the number had a decimal point, even without fraction digits.
+    pub has_fraction: bool,
+    /// If the number had any digit separators.
+    pub has_separators: bool,
+    /// If the number had a base prefix, such as `0x`.
+    pub has_base_prefix: bool,
+    /// If the number was a special value, such as `NaN` or `inf`.
+    pub is_special: bool,
+}
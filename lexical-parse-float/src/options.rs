@@ -1,7 +1,10 @@
 //! Configuration options for parsing floats.
 
+#[cfg(feature = "arbitrary")]
+use lexical_util::arbitrary::{self, Arbitrary, Unstructured};
 use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::error::Error;
+use lexical_util::format::{options_punctuation_error, Format, FormatSpec};
 use lexical_util::options::{self, ParseOptions};
 use lexical_util::result::Result;
 use static_assertions::const_assert;
@@ -15,6 +18,8 @@ pub struct OptionsBuilder {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// Parse floats in parentheses as negative values.
+    parentheses_negative: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -33,6 +38,7 @@ impl OptionsBuilder {
     pub const fn new() -> Self {
         Self {
             lossy: false,
+            parentheses_negative: false,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
@@ -49,6 +55,12 @@ impl OptionsBuilder {
         self.lossy
     }
 
+    /// Get if we parse floats in parentheses as negative values.
+    #[inline(always)]
+    pub const fn get_parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn get_exponent(&self) -> u8 {
@@ -88,6 +100,16 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we parse floats in parentheses as negative values.
+    ///
+    /// This is the accounting style for negative values, IE, `(1.5)` is
+    /// parsed as `-1.5`. A sign is not allowed inside the parentheses.
+    #[inline(always)]
+    pub const fn parentheses_negative(mut self, parentheses_negative: bool) -> Self {
+        self.parentheses_negative = parentheses_negative;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(mut self, exponent: u8) -> Self {
@@ -123,6 +145,29 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the punctuation and special strings from a format specification.
+    ///
+    /// Only the values set in the specification are changed.
+    #[inline(always)]
+    pub const fn format_spec(mut self, spec: &FormatSpec<'static>) -> Self {
+        if let Some(decimal_point) = spec.decimal_point() {
+            self.decimal_point = decimal_point;
+        }
+        if let Some(exponent) = spec.exponent() {
+            self.exponent = exponent;
+        }
+        if let Some(nan_string) = spec.nan_string() {
+            self.nan_string = Some(nan_string);
+        }
+        if let Some(inf_string) = spec.inf_string() {
+            self.inf_string = Some(inf_string);
+        }
+        if let Some(infinity_string) = spec.infinity_string() {
+            self.infinity_string = Some(infinity_string);
+        }
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -226,6 +271,7 @@ impl OptionsBuilder {
     pub const unsafe fn build_unchecked(&self) -> Options {
         Options {
             lossy: self.lossy,
+            parentheses_negative: self.parentheses_negative,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
@@ -287,6 +333,23 @@ impl OptionsBuilder {
         // SAFETY: always safe, since it must be valid.
         Ok(unsafe { self.build_unchecked() })
     }
+
+    /// Build the Options struct, validating the punctuation against a format.
+    ///
+    /// This is the same as [`build`](Self::build), but also rejects an
+    /// exponent or decimal point that is a valid digit for the format, or
+    /// that conflicts with the digit separator, base prefix or base suffix,
+    /// which would otherwise fail when parsing with the format.
+    pub const fn build_with_format(&self, format: &Format) -> Result<Options> {
+        let options = match self.build() {
+            Ok(options) => options,
+            Err(error) => return Err(error),
+        };
+        match options_punctuation_error(format.packed(), self.exponent, self.decimal_point) {
+            Error::Success => Ok(options),
+            error => Err(error),
+        }
+    }
 }
 
 impl Default for OptionsBuilder {
@@ -318,6 +381,8 @@ pub struct Options {
     /// Disable the use of arbitrary-precision arithmetic, and always
     /// return the results from the fast or intermediate path algorithms.
     lossy: bool,
+    /// Parse floats in parentheses as negative values.
+    parentheses_negative: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -369,6 +434,12 @@ impl Options {
         self.lossy
     }
 
+    /// Get if we parse floats in parentheses as negative values.
+    #[inline(always)]
+    pub const fn parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(&self) -> u8 {
@@ -411,6 +482,16 @@ impl Options {
         self.lossy = lossy
     }
 
+    /// Set if we parse floats in parentheses as negative values.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_parentheses_negative(&mut self, parentheses_negative: bool) {
+        self.parentheses_negative = parentheses_negative;
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// # Safety
@@ -479,6 +560,7 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             lossy: self.lossy,
+            parentheses_negative: self.parentheses_negative,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
@@ -511,6 +593,53 @@ const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
     }
 }
 
+// ARBITRARY
+// ---------
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for OptionsBuilder {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            lossy: u.arbitrary()?,
+            parentheses_negative: u.arbitrary()?,
+            exponent: u.int_in_range(0..=0x7F)?,
+            decimal_point: u.int_in_range(0..=0x7F)?,
+            nan_string: options::arbitrary_special(u)?,
+            inf_string: options::arbitrary_special(u)?,
+            infinity_string: options::arbitrary_special(u)?,
+        })
+    }
+}
+
+// Only valid options are generated: invalid builder states are rejected.
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Options {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let builder: OptionsBuilder = u.arbitrary()?;
+        builder.build().map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+// OVERRIDES
+// ---------
+
+lexical_util::options_override! {
+    /// Set if we disable the use of arbitrary-precision arithmetic.
+    lossy: bool;
+    /// Set if we parse floats in parentheses as negative values.
+    parentheses_negative: bool;
+    /// Set the character to designate the exponent component of a float.
+    exponent: u8;
+    /// Set the character to separate the integer from the fraction components.
+    decimal_point: u8;
+    /// Set the string representation for `NaN`.
+    nan_string: Option<&'static [u8]>;
+    /// Set the short string representation for `Infinity`.
+    inf_string: Option<&'static [u8]>;
+    /// Set the long string representation for `Infinity`.
+    infinity_string: Option<&'static [u8]>;
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -554,6 +683,38 @@ pub const CARAT_EXPONENT: Options = unsafe {
 };
 const_assert!(CARAT_EXPONENT.is_valid());
 
+/// Number format to parse a float like C's `strtod` in the "C" locale.
+///
+/// Special values are parsed from `nan`, `inf`, and `infinity`.
+#[rustfmt::skip]
+pub const C_LOCALE: Options = unsafe {
+    Options::builder()
+        .nan_string(options::C_LOCALE_NAN)
+        .inf_string(options::C_LOCALE_INF)
+        .infinity_string(options::C_LOCALE_INFINITY)
+        .build_unchecked()
+};
+const_assert!(C_LOCALE.is_valid());
+
+/// Number format to parse a float in most European locales, with a decimal comma.
+///
+/// Use a number format with a `.` digit separator to parse grouped digits.
+#[rustfmt::skip]
+pub const EUROPEAN_LOCALE: Options = DECIMAL_COMMA;
+
+/// Number format to parse a float written in scientific notation with
+/// a fixed number of significant digits.
+///
+/// This parses the floats written with the write options of the same name.
+#[rustfmt::skip]
+pub const SCIENTIFIC_FIXED: Options = C_LOCALE;
+
+/// Number format to parse floats with the most common special values.
+///
+/// This should be used with the `PERMISSIVE` number format.
+#[rustfmt::skip]
+pub const PERMISSIVE: Options = C_LOCALE;
+
 /// Number format for a Rust literal floating-point number.
 #[rustfmt::skip]
 pub const RUST_LITERAL: Options = unsafe {
@@ -1189,6 +1350,17 @@ pub const POSTGRESQL: Options = unsafe {
 };
 const_assert!(POSTGRESQL.is_valid());
 
+/// Number format to parse a PostgreSQL float from string.
+#[rustfmt::skip]
+pub const POSTGRESQL_STRING: Options = unsafe {
+    Options::builder()
+        .nan_string(options::POSTGRESQL_STRING_NAN)
+        .inf_string(options::POSTGRESQL_STRING_INF)
+        .infinity_string(options::POSTGRESQL_STRING_INFINITY)
+        .build_unchecked()
+};
+const_assert!(POSTGRESQL_STRING.is_valid());
+
 /// Number format for a MySQL literal floating-point number.
 #[rustfmt::skip]
 pub const MYSQL: Options = unsafe {
@@ -5,17 +5,19 @@
 
 #![doc(hidden)]
 
-#[cfg(any(feature = "compact", feature = "radix"))]
+#[cfg(any(feature = "compact", feature = "any-radix"))]
 use crate::bellerophon::bellerophon;
 #[cfg(feature = "power-of-two")]
 use crate::binary::{binary, slow_binary};
 use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
 #[cfg(not(feature = "compact"))]
 use crate::lemire::lemire;
-use crate::number::Number;
+use crate::number::{DigitCounts, Number, ParseFlags};
 use crate::options::Options;
+use crate::runtime;
 use crate::shared;
-use crate::slow::slow_radix;
+use crate::slow::{self, slow_radix, Representation};
+use crate::table::is_table_radix;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 #[cfg(feature = "f16")]
@@ -24,7 +26,7 @@ use lexical_util::digit::{char_to_digit_const, char_to_valid_digit_const};
 use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::{Format, NumberFormat};
 use lexical_util::iterator::{AsBytes, Bytes, BytesIter};
 use lexical_util::result::Result;
 use lexical_util::step::u64_step;
@@ -40,10 +42,30 @@ macro_rules! is_power_two {
     };
 }
 
+/// Emit a trace event for the algorithms used, with the `tracing` feature.
+macro_rules! trace_path {
+    ($num:ident, $radix:expr, $message:literal $(, $field:ident = $value:expr)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            radix = $radix,
+            mantissa = $num.mantissa,
+            exponent = $num.exponent,
+            many_digits = $num.many_digits,
+            integer_digits = $num.integer.len(),
+            fraction_digits = $num.fraction.map_or(0, |x| x.len()),
+            $($field = $value,)*
+            $message
+        );
+    };
+}
+
 /// Check if the radix is valid and error otherwise
 macro_rules! check_radix {
     ($format:ident) => {{
         let format = NumberFormat::<{ $format }> {};
+        if !is_table_radix(format.radix()) {
+            return Err(Error::InvalidMantissaRadix);
+        }
         #[cfg(feature = "power-of-two")]
         {
             if format.radix() != format.exponent_base() {
@@ -82,6 +104,26 @@ pub trait ParseFloat: LemireFloat {
         parse_partial::<Self, FORMAT>(bytes, options)
     }
 
+    /// Forward partial parser parameters to the backend, counting the digits.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_with_counts<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, usize, DigitCounts)> {
+        check_radix!(FORMAT);
+        parse_partial_with_counts::<Self, FORMAT>(bytes, options)
+    }
+
+    /// Forward partial parser parameters to the backend, describing the syntax used.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_with_flags<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(Self, usize, ParseFlags)> {
+        check_radix!(FORMAT);
+        parse_partial_with_flags::<Self, FORMAT>(bytes, options)
+    }
+
     /// Forward complete parser parameters to the backend, using only the fast path.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
@@ -98,6 +140,36 @@ pub trait ParseFloat: LemireFloat {
         check_radix!(FORMAT);
         fast_path_partial::<Self, FORMAT>(bytes, options)
     }
+
+    /// Forward complete parser parameters to the runtime-format backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_with_format(
+        bytes: &[u8],
+        format: &Format,
+        options: &Options,
+    ) -> Result<Self> {
+        runtime::parse_complete::<Self>(bytes, format, options)
+    }
+
+    /// Forward partial parser parameters to the runtime-format backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_with_format(
+        bytes: &[u8],
+        format: &Format,
+        options: &Options,
+    ) -> Result<(Self, usize)> {
+        runtime::parse_partial::<Self>(bytes, format, options)
+    }
+
+    /// Forward representation queries to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn representation<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<Representation> {
+        check_radix!(FORMAT);
+        representation_complete::<Self, FORMAT>(bytes, options)
+    }
 }
 
 macro_rules! parse_float_impl {
@@ -127,6 +199,24 @@ macro_rules! parse_float_as_f32 {
                 Ok((Self::from_f32(float), count))
             }
 
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial_with_counts<const FORMAT: u128>(bytes: &[u8], options: &Options)
+                -> Result<(Self, usize, DigitCounts)>
+            {
+                let (float, count, counts) =
+                    parse_partial_with_counts::<f32, FORMAT>(bytes, options)?;
+                Ok((Self::from_f32(float), count, counts))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial_with_flags<const FORMAT: u128>(bytes: &[u8], options: &Options)
+                -> Result<(Self, usize, ParseFlags)>
+            {
+                let (float, count, flags) =
+                    parse_partial_with_flags::<f32, FORMAT>(bytes, options)?;
+                Ok((Self::from_f32(float), count, flags))
+            }
+
             #[cfg_attr(not(feature = "compact"), inline(always))]
             fn fast_path_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<Self>
@@ -141,6 +231,29 @@ macro_rules! parse_float_as_f32 {
                 let (float, count) = fast_path_partial::<f32, FORMAT>(bytes, options)?;
                 Ok((Self::from_f32(float), count))
             }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_with_format(bytes: &[u8], format: &Format, options: &Options)
+                -> Result<Self>
+            {
+                Ok(Self::from_f32(runtime::parse_complete::<f32>(bytes, format, options)?))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial_with_format(bytes: &[u8], format: &Format, options: &Options)
+                -> Result<(Self, usize)>
+            {
+                let (float, count) = runtime::parse_partial::<f32>(bytes, format, options)?;
+                Ok((Self::from_f32(float), count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn representation<const FORMAT: u128>(_: &[u8], _: &Options)
+                -> Result<Representation>
+            {
+                // These are parsed via `f32`, so there is no exact algorithm.
+                Err(Error::InvalidFloatParseAlgorithm)
+            }
         }
     )*)
 }
@@ -181,7 +294,7 @@ macro_rules! parse_exponent_sign {
                 return Err(Error::InvalidPositiveExponentSign($byte.cursor()));
             },
             Some(&b'-') => (true, 1),
-            Some(_) if $format.required_mantissa_sign() => {
+            Some(_) if $format.required_exponent_sign() => {
                 return Err(Error::MissingExponentSign($byte.cursor()));
             },
             _ => (false, 0),
@@ -230,6 +343,9 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     bytes: &[u8],
     options: &Options,
 ) -> Result<F> {
+    if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+        return parse_parentheses_complete::<F, FORMAT>(bytes, options);
+    }
     let format = NumberFormat::<{ FORMAT }> {};
     let mut byte = bytes.bytes::<{ FORMAT }>();
     let (is_negative, shift) = parse_mantissa_sign!(byte, format);
@@ -241,9 +357,12 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
 
     // Parse our a small representation of our number.
     let num = parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special);
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
+    // Try the fast-path algorithm, which requires the exponent base to be the radix.
+    if format.mantissa_radix() == format.exponent_base() {
+        if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+            count_path!(FAST_PATH);
+            return Ok(value);
+        }
     }
     // Now try the moderate path algorithm.
     let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
@@ -255,7 +374,10 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
         debug_assert!(!options.lossy());
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        fp = slow_path::<F, FORMAT>(num, fp).ok_or(Error::Internal(0))?;
+        count_path!(SLOW_PATH);
+    } else {
+        count_path!(MODERATE_PATH);
     }
 
     // Convert to native float and return result.
@@ -286,6 +408,46 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     bytes: &[u8],
     options: &Options,
 ) -> Result<(F, usize)> {
+    let (float, count, _, _) = parse_partial_with_syntax::<F, FORMAT>(bytes, options)?;
+    Ok((float, count))
+}
+
+/// Parse a float from bytes using a partial parser, counting the digits.
+pub fn parse_partial_with_counts<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize, DigitCounts)> {
+    let (float, count, counts, _) = parse_partial_with_syntax::<F, FORMAT>(bytes, options)?;
+    Ok((float, count, counts))
+}
+
+/// Parse a float from bytes using a partial parser, describing the syntax used.
+pub fn parse_partial_with_flags<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize, ParseFlags)> {
+    let (float, count, _, flags) = parse_partial_with_syntax::<F, FORMAT>(bytes, options)?;
+    Ok((float, count, flags))
+}
+
+/// Parse a float from bytes using a partial parser, counting the digits
+/// and describing the syntax used.
+pub fn parse_partial_with_syntax<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize, DigitCounts, ParseFlags)> {
+    if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+        let mut counts = DigitCounts::default();
+        let mut flags = ParseFlags::default();
+        let (float, count) = parse_parentheses_partial_with(bytes, |digits| {
+            let (float, count, digit_counts, parse_flags) =
+                parse_partial_with_syntax::<F, FORMAT>(digits, options)?;
+            counts = digit_counts;
+            flags = parse_flags;
+            Ok((float, count))
+        })?;
+        return Ok((float, count, counts, flags));
+    }
     let format = NumberFormat::<{ FORMAT }> {};
     let mut byte = bytes.bytes::<{ FORMAT }>();
     let (is_negative, shift) = parse_mantissa_sign!(byte, format);
@@ -296,17 +458,29 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     }
 
     // Parse our a small representation of our number.
-    let (num, count) = parse_number!(
-        FORMAT,
-        byte,
-        is_negative,
-        options,
-        parse_partial_number,
-        parse_partial_special
-    );
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+    // Special values have no digits, so use the default counts.
+    let (num, count, counts, flags) =
+        match parse_partial_number_with_syntax::<FORMAT>(byte.clone(), is_negative, options) {
+            Ok(n) => n,
+            Err(e) => {
+                return match parse_partial_special::<_, FORMAT>(byte, is_negative, options) {
+                    Some((value, count)) => {
+                        let flags = ParseFlags {
+                            is_special: true,
+                            ..ParseFlags::default()
+                        };
+                        Ok((value, count, DigitCounts::default(), flags))
+                    },
+                    None => Err(e),
+                };
+            },
+        };
+    // Try the fast-path algorithm, which requires the exponent base to be the radix.
+    if format.mantissa_radix() == format.exponent_base() {
+        if let Some(value) = num.try_fast_path::<_, FORMAT>() {
+            count_path!(FAST_PATH);
+            return Ok((value, count, counts, flags));
+        }
     }
     // Now try the moderate path algorithm.
     let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
@@ -318,11 +492,116 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         debug_assert!(!options.lossy());
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        fp = slow_path::<F, FORMAT>(num, fp).ok_or(Error::Internal(0))?;
+        count_path!(SLOW_PATH);
+    } else {
+        count_path!(MODERATE_PATH);
     }
 
     // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    Ok((to_native!(F, fp, is_negative), count, counts, flags))
+}
+
+/// Determine how a float string is represented by the nearest float.
+///
+/// This always uses the correctly rounded float, even if the options are
+/// lossy, and only supports radixes with a finite number of digits.
+pub fn representation_complete<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<Representation> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let max_digits = match F::max_digits(format.radix()) {
+        Some(max_digits) => max_digits,
+        None => return Err(Error::InvalidMantissaRadix),
+    };
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (_, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // The sign does not affect the representation, so only parse the magnitude.
+    let num = match parse_number::<FORMAT>(byte.clone(), false, options) {
+        Ok(num) => num,
+        // Special values are represented exactly.
+        Err(error) => {
+            return match parse_special::<F, FORMAT>(byte, false, options) {
+                Some(_) => Ok(Representation::Exact),
+                None => Err(error),
+            };
+        },
+    };
+    let float = match num.try_fast_path::<F, FORMAT>() {
+        Some(value) => value,
+        None => {
+            let mut fp = moderate_path::<F, FORMAT>(&num, false);
+            if fp.exp < 0 {
+                // Undo the invalid extended float biasing.
+                fp.exp -= shared::INVALID_FP;
+                fp = slow_path::<F, FORMAT>(num, fp).ok_or(Error::Internal(0))?;
+            }
+            extended_to_float::<F>(fp)
+        },
+    };
+
+    slow::representation::<F, FORMAT>(num, float, max_digits).ok_or(Error::Internal(0))
+}
+
+/// Parse an accounting-style negative float, IE, `(1.5)`, as a complete parser.
+pub fn parse_parentheses_complete<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<F> {
+    parse_parentheses_complete_with(bytes, |digits| parse_partial::<F, FORMAT>(digits, options))
+}
+
+/// Parse an accounting-style negative float, IE, `(1.5)`, as a partial parser.
+///
+/// The input must start with `(`. A sign is not allowed inside the parentheses.
+pub fn parse_parentheses_partial<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, usize)> {
+    parse_parentheses_partial_with(bytes, |digits| parse_partial::<F, FORMAT>(digits, options))
+}
+
+/// Parse an accounting-style negative float using a partial parser for
+/// the digits, as a complete parser.
+pub fn parse_parentheses_complete_with<F, Parse>(bytes: &[u8], parse: Parse) -> Result<F>
+where
+    F: LemireFloat,
+    Parse: FnOnce(&[u8]) -> Result<(F, usize)>,
+{
+    let (float, count) = parse_parentheses_partial_with(bytes, parse)?;
+    if count == bytes.len() {
+        Ok(float)
+    } else {
+        Err(Error::InvalidDigit(count))
+    }
+}
+
+/// Parse an accounting-style negative float using a partial parser for
+/// the digits, as a partial parser.
+///
+/// The input must start with `(`. A sign is not allowed inside the parentheses.
+pub fn parse_parentheses_partial_with<F, Parse>(bytes: &[u8], parse: Parse) -> Result<(F, usize)>
+where
+    F: LemireFloat,
+    Parse: FnOnce(&[u8]) -> Result<(F, usize)>,
+{
+    debug_assert!(bytes.first() == Some(&b'('));
+    let digits = &bytes[1..];
+    if matches!(digits.first(), Some(&b'+') | Some(&b'-') | Some(&b'(')) {
+        return Err(Error::InvalidDigit(1));
+    }
+    let (float, count) = parse(digits).map_err(|error| error.shift_index(1))?;
+    if digits.get(count) != Some(&b')') {
+        return Err(Error::InvalidDigit(count + 1));
+    }
+    Ok((-float, count + 2))
 }
 
 /// Parse a float using only the fast path as a partial parser.
@@ -361,6 +640,11 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
     num: &Number,
     lossy: bool,
 ) -> ExtendedFloat80 {
+    trace_path!(
+        num,
+        NumberFormat::<{ FORMAT }>::MANTISSA_RADIX,
+        "fast path failed, using the moderate path"
+    );
     #[cfg(feature = "compact")]
     {
         #[cfg(feature = "power-of-two")]
@@ -382,7 +666,7 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 
     #[cfg(not(feature = "compact"))]
     {
-        #[cfg(feature = "radix")]
+        #[cfg(feature = "any-radix")]
         {
             let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
@@ -396,7 +680,7 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             }
         }
 
-        #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+        #[cfg(all(feature = "power-of-two", not(feature = "any-radix")))]
         {
             let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
@@ -418,11 +702,21 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 
 /// Invoke the slow path.
 /// At this point, the float string has already been validated.
+///
+/// Returns `None` if an internal invariant did not hold, which only
+/// occurs with the `no-panic` feature.
 #[inline]
 pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
+    trace_path!(
+        num,
+        NumberFormat::<{ FORMAT }>::MANTISSA_RADIX,
+        "moderate path failed, using the slow path",
+        fp_mant = fp.mant,
+        fp_exp = fp.exp
+    );
     #[cfg(not(feature = "power-of-two"))]
     {
         slow_radix::<F, FORMAT>(num, fp)
@@ -432,7 +726,7 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     {
         let format = NumberFormat::<{ FORMAT }> {};
         if is_power_two!(format.mantissa_radix()) {
-            slow_binary::<F, FORMAT>(num)
+            Some(slow_binary::<F, FORMAT>(num))
         } else {
             slow_radix::<F, FORMAT>(num, fp)
         }
@@ -447,12 +741,29 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
 /// This creates a representation of the float as the
 /// significant digits and the decimal exponent.
 #[inline]
-#[allow(clippy::collapsible_if)]
 pub fn parse_partial_number<'a, const FORMAT: u128>(
-    mut byte: Bytes<'a, FORMAT>,
+    byte: Bytes<'a, FORMAT>,
     is_negative: bool,
     options: &Options,
 ) -> Result<(Number<'a>, usize)> {
+    let (number, count, _, _) =
+        parse_partial_number_with_syntax::<FORMAT>(byte, is_negative, options)?;
+    Ok((number, count))
+}
+
+/// Parse a partial, non-special floating point number, counting the digits
+/// and describing the syntax used.
+///
+/// This is the same as [`parse_partial_number`], and also returns the
+/// number of digits parsed for each component, and the optional
+/// components present in the number.
+#[inline]
+#[allow(clippy::collapsible_if)]
+pub fn parse_partial_number_with_syntax<'a, const FORMAT: u128>(
+    mut byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, usize, DigitCounts, ParseFlags)> {
     //  NOTE:
     //      There are no satisfactory optimizations to reduce the number
     //      of multiplications for very long input strings, but this will
@@ -487,14 +798,19 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     debug_assert!(!byte.is_done());
     let bits_per_digit = shared::log2(format.mantissa_radix()) as i64;
     let bits_per_base = shared::log2(format.exponent_base()) as i64;
+    let start_index = byte.cursor();
+    let start_count = byte.current_count();
 
     // INTEGER
 
     // Check to see if we have a valid base prefix.
     let base_prefix = format.base_prefix();
     let mut is_prefix = false;
-    let mut iter = byte.integer_iter();
-    if cfg!(feature = "format") && base_prefix != 0 && iter.peek() == Some(&b'0') {
+    let prefix_index = byte.cursor();
+    if cfg!(feature = "format") && base_prefix != 0 && byte.integer_iter().peek() == Some(&b'0') {
+        // Only consume the leading zero if it's part of the base prefix.
+        let mut prefix = byte.clone();
+        let mut iter = prefix.integer_iter();
         // SAFETY: safe since `byte.len() >= 1`.
         unsafe { iter.step_unchecked() };
         // Check to see if the next character is the base prefix.
@@ -511,9 +827,13 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
                 if iter.is_done() {
                     return Err(Error::Empty(iter.cursor()));
                 }
+                byte = prefix;
             }
         }
     }
+    if cfg!(feature = "format") && format.required_base_prefix() && !is_prefix {
+        return Err(Error::MissingBasePrefix(prefix_index));
+    }
 
     // Parse our integral digits.
     let mut mantissa = 0_u64;
@@ -524,6 +844,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         mantissa = mantissa.wrapping_mul(format.radix() as _).wrapping_add(digit as _);
     });
     let mut n_digits = byte.current_count() - start.current_count();
+    let n_integer = n_digits;
     if cfg!(feature = "format") && format.required_integer_digits() && n_digits == 0 {
         return Err(Error::EmptyInteger(byte.cursor()));
     }
@@ -533,9 +854,25 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     debug_assert!(n_digits <= start.as_slice().len());
     let integer_digits = unsafe { start.as_slice().get_unchecked(..n_digits) };
 
-    // Check if integer leading zeros are disabled.
-    if cfg!(feature = "format") && !is_prefix && format.no_float_leading_zeros() {
-        if integer_digits.len() > 1 && integer_digits.get(0) == Some(&b'0') {
+    // Check if integer leading zeros are disabled. Values without a
+    // decimal point or exponent are integers, and use the integer policy.
+    if cfg!(feature = "format")
+        && !is_prefix
+        && integer_digits.len() > 1
+        && integer_digits.get(0) == Some(&b'0')
+    {
+        let is_float = byte.first_is(decimal_point)
+            || if format.case_sensitive_exponent() {
+                byte.first_is(exponent_character)
+            } else {
+                byte.case_insensitive_first_is(exponent_character)
+            };
+        let no_leading_zeros = if is_float {
+            format.no_float_leading_zeros()
+        } else {
+            format.no_integer_leading_zeros()
+        };
+        if no_leading_zeros {
             return Err(Error::InvalidLeadingZeros(start.cursor()));
         }
     }
@@ -586,6 +923,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
 
     // Handle scientific notation.
     let mut explicit_exponent = 0_i64;
+    let mut n_exponent = 0;
     let is_exponent = if cfg!(feature = "format") && format.case_sensitive_exponent() {
         byte.first_is(exponent_character)
     } else {
@@ -619,7 +957,8 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
                 explicit_exponent += digit as i64;
             }
         });
-        if format.required_exponent_digits() && byte.current_count() - before == 0 {
+        n_exponent = byte.current_count() - before;
+        if format.required_exponent_digits() && n_exponent == 0 {
             return Err(Error::EmptyExponent(byte.cursor()));
         }
         // Handle our sign, and get the explicit part of the exponent.
@@ -646,6 +985,8 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         if is_suffix {
             // SAFETY: safe since `byte.len() >= 1`.
             unsafe { byte.step_unchecked() };
+        } else if cfg!(feature = "format") && format.required_base_suffix() {
+            return Err(Error::MissingBaseSuffix(byte.cursor()));
         }
     }
 
@@ -653,6 +994,20 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
 
     // Get the number of parsed digits (total), and redo if we had overflow.
     let end = byte.cursor();
+    let counts = DigitCounts {
+        integer: n_integer,
+        fraction: n_after_dot,
+        exponent: n_exponent,
+        // Every consumed byte is counted unless it's a digit separator.
+        separators: (end - start_index) - (byte.current_count() - start_count),
+    };
+    let flags = ParseFlags {
+        has_exponent: is_exponent,
+        has_fraction: fraction_digits.is_some(),
+        has_separators: counts.separators != 0,
+        has_base_prefix: is_prefix,
+        is_special: false,
+    };
     let mut step = u64_step(format.radix());
     let mut many_digits = false;
     if cfg!(feature = "format") && !format.required_mantissa_digits() && n_digits == 0 {
@@ -669,6 +1024,8 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
                 fraction: fraction_digits,
             },
             end,
+            counts,
+            flags,
         ));
     }
 
@@ -713,7 +1070,8 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             // but parsing only the integral digits produced less
             // than 19 digits. That means we must have a decimal
             // point, and at least 1 fractional digit.
-            let mut fraction = fraction_digits.unwrap().bytes::<{ FORMAT }>();
+            let fraction = unwrap_infallible!(fraction_digits.ok_or(Error::Internal(end)));
+            let mut fraction = fraction.bytes::<{ FORMAT }>();
             let mut fraction_iter = fraction.fraction_iter();
             // Skip leading zeros, so we can use the step properly.
             if mantissa == 0 {
@@ -742,6 +1100,8 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             fraction: fraction_digits,
         },
         end,
+        counts,
+        flags,
     ))
 }
 
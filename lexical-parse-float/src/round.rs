@@ -0,0 +1,227 @@
+//! Rounding of extended-precision floats to native floats.
+//!
+//! These are the rounding helpers used by every algorithm in this crate,
+//! and are exposed so implementers of [`RawFloat`] for other float types,
+//! or other parsing algorithms, can reuse them. An algorithm computes an
+//! [`ExtendedFloat80`], a 64-bit significand and binary exponent, which
+//! [`round`] shifts into place for the native float, using a callback to
+//! round the truncated bits. The rounded float is then converted to the
+//! native float with [`extended_to_float`].
+//!
+//! The exponent is biased by `F::EXPONENT_BIAS`, which includes the mantissa
+//! size, such that the value is `mant * 2^(exp - F::EXPONENT_BIAS)`. For
+//! example, `1.0_f64` is `mant = 1 << 63` and `exp = 1075 - 63`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::round::{self, extended_to_float, ExtendedFloat80};
+//!
+//! // The significand of 1.0 followed by an exact halfway point.
+//! let mut fp = ExtendedFloat80 {
+//!     mant: (1 << 63) | (1 << 10),
+//!     exp: 1075 - 63,
+//! };
+//! round::round::<f64, _>(&mut fp, |f, s| {
+//!     round::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+//!         is_above || (is_odd && is_halfway)
+//!     });
+//! });
+//! assert_eq!(extended_to_float::<f64>(fp), 1.0);
+//! ```
+
+#[doc(inline)]
+pub use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
+use crate::mask::{lower_n_halfway, lower_n_mask};
+use lexical_util::num::AsPrimitive;
+
+/// Round an extended-precision float to the nearest machine float.
+///
+/// Shifts the significant digits into place, adjusts the exponent,
+/// so it can be easily converted to a native float. The callback
+/// shifts the significand right by the number of bits provided,
+/// adding that shift to the exponent, and rounds the truncated bits,
+/// such as [`round_nearest_tie_even`] or [`round_down`].
+///
+/// On return, `fp.mant` holds the mantissa bits without the hidden bit,
+/// and `fp.exp` the biased exponent, or `F::INFINITE_POWER` for infinity.
+/// Denormal floats have a biased exponent of 0, and values that round
+/// to the smallest normal float have a biased exponent of 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::round::{self, extended_to_float, ExtendedFloat80};
+///
+/// // Truncate 1 + 2^-53, which is 1 + half an ULP.
+/// let mut fp = ExtendedFloat80 {
+///     mant: (1 << 63) | (1 << 10),
+///     exp: 1075 - 63,
+/// };
+/// round::round::<f64, _>(&mut fp, round::round_down);
+/// assert_eq!(extended_to_float::<f64>(fp), 1.0);
+///
+/// // Values above the largest float are infinite.
+/// let mut fp = ExtendedFloat80 {
+///     mant: u64::MAX,
+///     exp: 1075 + 1023 - 63,
+/// };
+/// round::round::<f64, _>(&mut fp, |f, s| {
+///     round::round_nearest_tie_even(f, s, |_, is_halfway, is_above| is_halfway || is_above);
+/// });
+/// assert_eq!(extended_to_float::<f64>(fp), f64::INFINITY);
+/// ```
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round<F, Cb>(fp: &mut ExtendedFloat80, cb: Cb)
+where
+    F: RawFloat,
+    Cb: Fn(&mut ExtendedFloat80, i32),
+{
+    let fp_inf = ExtendedFloat80 {
+        mant: 0,
+        exp: F::INFINITE_POWER,
+    };
+
+    // Calculate our shift in significant digits.
+    let mantissa_shift = 64 - F::MANTISSA_SIZE - 1;
+
+    // Check for a denormal float, if after the shift the exponent is negative.
+    if -fp.exp >= mantissa_shift {
+        // Have a denormal float that isn't a literal 0.
+        // The extra 1 is to adjust for the denormal float, which is
+        // `1 - F::EXPONENT_BIAS`. This works as before, because our
+        // old logic rounded to `F::DENORMAL_EXPONENT` (now 1), and then
+        // checked if `exp == F::DENORMAL_EXPONENT` and no hidden mask
+        // bit was set. Here, we handle that here, rather than later.
+        //
+        // This might round-down to 0, but shift will be at **max** 65,
+        // for halfway cases rounding towards 0.
+        let shift = -fp.exp + 1;
+        debug_assert!(shift <= 65);
+        cb(fp, shift.min(64));
+        // Check for round-up: if rounding-nearest carried us to the hidden bit.
+        fp.exp = (fp.mant >= F::HIDDEN_BIT_MASK.as_u64()) as i32;
+        return;
+    }
+
+    // The float is normal, round to the hidden bit.
+    cb(fp, mantissa_shift);
+
+    // Check if we carried, and if so, shift the bit to the hidden bit.
+    let carry_mask = F::CARRY_MASK.as_u64();
+    if fp.mant & carry_mask == carry_mask {
+        fp.mant >>= 1;
+        fp.exp += 1;
+    }
+
+    // Handle if we carried and check for overflow again.
+    if fp.exp >= F::INFINITE_POWER {
+        // Exponent is above largest normal value, must be infinite.
+        *fp = fp_inf;
+        return;
+    }
+
+    // Remove the hidden bit.
+    fp.mant &= F::MANTISSA_MASK.as_u64();
+}
+
+/// Shift right N-bytes and round towards a direction.
+///
+/// The shift must be at most 64. The callback decides if the shifted
+/// significand is rounded up, and takes the following parameters:
+///     1. is_odd: if the least-significant bit after the shift is set.
+///     1. is_halfway: if the truncated bits are exactly halfway.
+///     1. is_above: if the truncated bits are above halfway.
+///
+/// Round-nearest, tie-even is `is_above || (is_odd && is_halfway)`,
+/// while other callbacks can use additional information, such as
+/// round-up for halfway cases known to be above halfway.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::round::{round_nearest_tie_even, ExtendedFloat80};
+///
+/// let nearest = |is_odd: bool, is_halfway: bool, is_above: bool| {
+///     is_above || (is_odd && is_halfway)
+/// };
+///
+/// // Halfway and even: round down.
+/// let mut fp = ExtendedFloat80 { mant: 0b1010, exp: 0 };
+/// round_nearest_tie_even(&mut fp, 2, nearest);
+/// assert_eq!(fp, ExtendedFloat80 { mant: 0b10, exp: 2 });
+///
+/// // Halfway and odd: round up.
+/// let mut fp = ExtendedFloat80 { mant: 0b1110, exp: 0 };
+/// round_nearest_tie_even(&mut fp, 2, nearest);
+/// assert_eq!(fp, ExtendedFloat80 { mant: 0b100, exp: 2 });
+/// ```
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_nearest_tie_even<Cb>(fp: &mut ExtendedFloat80, shift: i32, cb: Cb)
+where
+    // is_odd, is_halfway, is_above
+    Cb: Fn(bool, bool, bool) -> bool,
+{
+    // Ensure we've already handled denormal values that underflow.
+    debug_assert!(shift <= 64);
+
+    // Extract the truncated bits using mask.
+    // Calculate if the value of the truncated bits are either above
+    // the mid-way point, or equal to it.
+    //
+    // For example, for 4 truncated bytes, the mask would be 0b1111
+    // and the midway point would be 0b1000.
+    let mask = lower_n_mask(shift as u64);
+    let halfway = lower_n_halfway(shift as u64);
+    let truncated_bits = fp.mant & mask;
+    let is_above = truncated_bits > halfway;
+    let is_halfway = truncated_bits == halfway;
+
+    // Bit shift so the leading bit is in the hidden bit.
+    // This optimixes pretty well:
+    //  ```text
+    //   mov     ecx, esi
+    //   shr     rdi, cl
+    //   xor     eax, eax
+    //   cmp     esi, 64
+    //   cmovne  rax, rdi
+    //   ret
+    //  ```
+    fp.mant = match shift == 64 {
+        true => 0,
+        false => fp.mant >> shift,
+    };
+    fp.exp += shift;
+
+    // Extract the last bit after shifting (and determine if it is odd).
+    let is_odd = fp.mant & 1 == 1;
+
+    // Calculate if we need to roundup.
+    // We need to roundup if we are above halfway, or if we are odd
+    // and at half-way (need to tie-to-even). Avoid the branch here.
+    fp.mant += cb(is_odd, is_halfway, is_above) as u64;
+}
+
+/// Round our significant digits into place, truncating them.
+///
+/// The shift must be at most 64. This is used to find the float
+/// below a value, such as `b` in the slow-path algorithms.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::round::{round_down, ExtendedFloat80};
+///
+/// let mut fp = ExtendedFloat80 { mant: 0b1111, exp: 0 };
+/// round_down(&mut fp, 2);
+/// assert_eq!(fp, ExtendedFloat80 { mant: 0b11, exp: 2 });
+/// ```
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_down(fp: &mut ExtendedFloat80, shift: i32) {
+    // Might have a shift greater than 64 if we have an error.
+    fp.mant = match shift == 64 {
+        true => 0,
+        false => fp.mant >> shift,
+    };
+    fp.exp += shift;
+}
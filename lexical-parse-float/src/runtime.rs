@@ -0,0 +1,615 @@
+//! Parse floats using a number format known at runtime.
+//!
+//! The number grammar, including digit separators, base prefixes and
+//! suffixes, and special values, is validated at runtime using a
+//! [`Format`], with the same errors and error indexes as the `FORMAT`
+//! const generic parsers. The significant digits are then copied,
+//! without digit separators, to a buffer and converted to a float by the
+//! algorithms for a `FORMAT` with the same radix and exponent base.
+//!
+//! Inputs with more than [`MAX_DIGITS`] significant digits are truncated,
+//! with any non-zero truncated digits represented by a trailing `1`.
+//! This is exact for decimal and power-of-two radixes, since the halfway
+//! points between floats have fewer digits, but for other radixes,
+//! rounding may be incorrect if the input matches a halfway point for
+//! every retained digit.
+
+#![doc(hidden)]
+
+use crate::float::{extended_to_float, LemireFloat};
+use crate::number::Number;
+use crate::options::Options;
+use crate::parse::{
+    moderate_path,
+    parse_parentheses_complete_with,
+    parse_parentheses_partial_with,
+    slow_path,
+};
+use crate::shared;
+use crate::table::is_float_radix;
+use lexical_util::ascii::case_insensitive_eq;
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat, STANDARD};
+use lexical_util::iterator::{AsBytes, FormatBytes, NumberComponent};
+use lexical_util::result::Result;
+
+/// The maximum number of significant digits, including the truncated digit.
+pub const MAX_DIGITS: usize = 1100;
+
+const INTEGER: NumberComponent = NumberComponent::Integer;
+const FRACTION: NumberComponent = NumberComponent::Fraction;
+const EXPONENT: NumberComponent = NumberComponent::Exponent;
+const SPECIAL: NumberComponent = NumberComponent::Special;
+
+/// Create the format used to convert the significant digits.
+#[cfg(feature = "power-of-two")]
+const fn digits_format(radix: u8, exponent_base: u8) -> u128 {
+    NumberFormatBuilder::new()
+        .mantissa_radix(radix)
+        .exponent_base(core::num::NonZeroU8::new(exponent_base))
+        .exponent_radix(core::num::NonZeroU8::new(radix))
+        .build()
+}
+
+/// Call `$body` with `$inner` as a const format with the radix and exponent base.
+///
+/// The radix and exponent base must be validated with `check_radix` first.
+macro_rules! with_digits_format {
+    ($radix:expr, $base:expr, $inner:ident => $body:expr) => {
+        with_digits_format!(
+            @arms ($radix, $base), $inner, $body ;
+            power_of_two: (2, 2) (4, 4) (8, 8) (16, 16) (32, 32) (4, 2) (8, 2) (16, 2) (32, 2) (16, 4) ;
+            radix: (3, 3) (5, 5) (6, 6) (7, 7) (9, 9) (11, 11) (12, 12) (13, 13) (14, 14) (15, 15)
+                (17, 17) (18, 18) (19, 19) (20, 20) (21, 21) (22, 22) (23, 23) (24, 24) (25, 25)
+                (26, 26) (27, 27) (28, 28) (29, 29) (30, 30) (31, 31) (33, 33) (34, 34) (35, 35)
+                (36, 36) ;
+            table_radix: (37, 37) (38, 38) (39, 39) (40, 40) (41, 41) (42, 42) (43, 43) (44, 44)
+                (45, 45) (46, 46) (47, 47) (48, 48) (49, 49) (50, 50) (51, 51) (52, 52) (53, 53)
+                (54, 54) (55, 55) (56, 56) (57, 57) (58, 58) (59, 59) (60, 60) (61, 61) (62, 62)
+        )
+    };
+    (
+        @arms $key:expr, $inner:ident, $body:expr ;
+        power_of_two: $(($pr:literal, $pb:literal))* ;
+        radix: $(($rr:literal, $rb:literal))* ;
+        table_radix: $(($tr:literal, $tb:literal))*
+    ) => {
+        match $key {
+            $(
+                #[cfg(feature = "power-of-two")]
+                ($pr, $pb) => {
+                    const $inner: u128 = digits_format($pr, $pb);
+                    $body
+                },
+            )*
+            $(
+                #[cfg(feature = "any-radix")]
+                ($rr, $rb) => {
+                    const $inner: u128 = digits_format($rr, $rb);
+                    $body
+                },
+            )*
+            $(
+                #[cfg(feature = "build-tables")]
+                ($tr, $tb) => {
+                    const $inner: u128 = digits_format($tr, $tb);
+                    $body
+                },
+            )*
+            _ => {
+                const $inner: u128 = STANDARD;
+                $body
+            },
+        }
+    };
+}
+
+/// Check if the format and options are valid for parsing floats.
+#[inline]
+pub fn check_format(format: &Format, options: &Options) -> Result<()> {
+    let radix = format.mantissa_radix();
+    let exponent_base = format.exponent_base();
+    if !is_float_radix(radix) {
+        return Err(Error::InvalidMantissaRadix);
+    } else if !is_float_radix(exponent_base) {
+        return Err(Error::InvalidExponentBase);
+    } else if !is_float_radix(format.exponent_radix()) {
+        return Err(Error::InvalidExponentRadix);
+    } else if !is_valid_options_punctuation(
+        format.packed(),
+        options.exponent(),
+        options.decimal_point(),
+    ) {
+        return Err(Error::InvalidPunctuation);
+    }
+
+    let is_valid = if cfg!(feature = "power-of-two") {
+        radix == exponent_base
+            || matches!((radix, exponent_base), (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4))
+    } else {
+        radix == exponent_base
+    };
+    if is_valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidRadix)
+    }
+}
+
+/// Parse a float from bytes using a complete parser.
+pub fn parse_complete<F: LemireFloat>(
+    bytes: &[u8],
+    format: &Format,
+    options: &Options,
+) -> Result<F> {
+    if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+        return parse_parentheses_complete_with(bytes, |digits| {
+            parse_partial::<F>(digits, format, options)
+        });
+    }
+    parse::<F>(bytes, format, options, false).map(|(float, _)| float)
+}
+
+/// Parse a float from bytes using a partial parser.
+pub fn parse_partial<F: LemireFloat>(
+    bytes: &[u8],
+    format: &Format,
+    options: &Options,
+) -> Result<(F, usize)> {
+    if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+        return parse_parentheses_partial_with(bytes, |digits| {
+            parse_partial::<F>(digits, format, options)
+        });
+    }
+    parse::<F>(bytes, format, options, true)
+}
+
+/// Parse the sign, number or special value, without parentheses.
+///
+/// Complete parsers require the number or special value to consume
+/// every byte, and otherwise return the error from parsing the number.
+fn parse<F: LemireFloat>(
+    bytes: &[u8],
+    format: &Format,
+    options: &Options,
+    is_partial: bool,
+) -> Result<(F, usize)> {
+    check_format(format, options)?;
+    let mut byte = FormatBytes::new(bytes, *format);
+    let (is_negative, shift) = match byte.peek(INTEGER) {
+        Some(b'+') if !format.no_positive_mantissa_sign() => (false, 1),
+        Some(b'+') => return Err(Error::InvalidPositiveSign(byte.cursor())),
+        Some(b'-') => (true, 1),
+        Some(_) if format.required_mantissa_sign() => {
+            return Err(Error::MissingSign(byte.cursor()));
+        },
+        _ => (false, 0),
+    };
+    if shift == 1 {
+        byte.step();
+    }
+    if byte.is_consumed(INTEGER) {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    let mut digits = Digits::new();
+    let number = match parse_partial_number(byte.clone(), format, options, &mut digits) {
+        Ok(count) if !is_partial && count != bytes.len() => Err(Error::InvalidDigit(count)),
+        number => number,
+    };
+    match number {
+        Ok(count) => Ok((digits.into_float::<F>(format, is_negative, options.lossy())?, count)),
+        Err(error) => match parse_partial_special::<F>(byte, format, options) {
+            Some((float, count)) if is_partial || count == bytes.len() => {
+                let float = if is_negative {
+                    -float
+                } else {
+                    float
+                };
+                Ok((float, count))
+            },
+            _ => Err(error),
+        },
+    }
+}
+
+// DIGITS
+// ------
+
+/// The significant digits of a float, without digit separators.
+struct Digits {
+    /// The integer digits, a decimal point, and the fraction digits.
+    buffer: [u8; MAX_DIGITS + 2],
+    /// The number of integer digits.
+    integer: usize,
+    /// The number of written bytes, including the decimal point.
+    length: usize,
+    /// If any non-zero digits were truncated.
+    truncated: bool,
+    /// The number of digits to shift the significant digits by.
+    shift: i64,
+    /// The explicit exponent, in the exponent base.
+    exponent: i64,
+}
+
+impl Digits {
+    fn new() -> Self {
+        Self {
+            buffer: [b'0'; MAX_DIGITS + 2],
+            integer: 0,
+            length: 0,
+            truncated: false,
+            shift: 0,
+            exponent: 0,
+        }
+    }
+
+    /// Add an integer digit, skipping any leading zeros.
+    #[inline]
+    fn push_integer(&mut self, c: u8) {
+        if self.length == 0 && c == b'0' {
+            return;
+        }
+        if self.length + 1 < MAX_DIGITS {
+            self.buffer[self.length] = c;
+            self.length += 1;
+            self.integer += 1;
+        } else {
+            // Truncated integer digits scale the significant digits.
+            self.truncated |= c != b'0';
+            self.shift += 1;
+        }
+    }
+
+    /// Add a fraction digit, skipping any leading zeros.
+    #[inline]
+    fn push_fraction(&mut self, c: u8) {
+        if self.length == self.integer {
+            self.buffer[self.length] = b'.';
+            self.length += 1;
+        }
+        if self.length == 1 && c == b'0' {
+            // Leading fraction zeros scale the significant digits.
+            self.shift -= 1;
+        } else if self.length < MAX_DIGITS {
+            self.buffer[self.length] = c;
+            self.length += 1;
+        } else {
+            self.truncated |= c != b'0';
+        }
+    }
+
+    /// Convert the significant digits and exponent to a float.
+    fn into_float<F: LemireFloat>(
+        mut self,
+        format: &Format,
+        is_negative: bool,
+        lossy: bool,
+    ) -> Result<F> {
+        if self.integer == 0 && self.length <= 1 {
+            return Ok(if is_negative {
+                -F::ZERO
+            } else {
+                F::ZERO
+            });
+        }
+        if self.truncated {
+            // Append a non-zero digit after the retained digits, which
+            // rounds identically as long as no halfway point is skipped.
+            if self.length == self.integer {
+                self.buffer[self.length] = b'.';
+                self.length += 1;
+            }
+            self.buffer[self.length] = b'1';
+            self.length += 1;
+        }
+
+        let radix = format.mantissa_radix();
+        let exponent_base = format.exponent_base();
+        let shift = if radix == exponent_base {
+            self.shift
+        } else {
+            self.shift * shared::log2(radix) as i64 / shared::log2(exponent_base) as i64
+        };
+        let exponent = shift + self.exponent;
+        let bytes = &self.buffer[..self.length];
+        with_digits_format!(radix, exponent_base, INNER => {
+            to_float::<F, INNER>(bytes, exponent, is_negative, lossy)
+        })
+    }
+}
+
+/// Determine if the mantissa radix and exponent base of the format are equal.
+#[inline(always)]
+const fn is_same_base<const FORMAT: u128>() -> bool {
+    let format = NumberFormat::<FORMAT> {};
+    format.mantissa_radix() == format.exponent_base()
+}
+
+/// Convert the significant digits to a float, scaled by the exponent.
+fn to_float<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    exponent: i64,
+    is_negative: bool,
+    lossy: bool,
+) -> Result<F> {
+    let options = Options::new();
+    let number = crate::parse::parse_partial_number::<FORMAT>(
+        bytes.bytes::<FORMAT>(),
+        is_negative,
+        &options,
+    );
+    // The digits are always valid, since they were copied from the input.
+    let (mut num, _): (Number, _) = unwrap_infallible!(number.map_err(|_| Error::Internal(0)));
+    num.exponent += exponent;
+
+    // The fast path requires the exponent base to be the radix.
+    if is_same_base::<FORMAT>() {
+        if let Some(value) = num.try_fast_path::<F, FORMAT>() {
+            count_path!(FAST_PATH);
+            return Ok(value);
+        }
+    }
+    let mut fp = moderate_path::<F, FORMAT>(&num, lossy);
+    if fp.exp < 0 {
+        debug_assert!(!lossy);
+        // Undo the invalid extended float biasing.
+        fp.exp -= shared::INVALID_FP;
+        fp = slow_path::<F, FORMAT>(num, fp).ok_or(Error::Internal(0))?;
+        count_path!(SLOW_PATH);
+    } else {
+        count_path!(MODERATE_PATH);
+    }
+    let float = extended_to_float::<F>(fp);
+    Ok(if is_negative {
+        -float
+    } else {
+        float
+    })
+}
+
+// NUMBER
+// ------
+
+/// Check if the byte is a base prefix or suffix character.
+#[inline(always)]
+fn is_affix(c: u8, affix: u8, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        c == affix
+    } else {
+        c.eq_ignore_ascii_case(&affix)
+    }
+}
+
+/// Parse a partial, non-special floating point number.
+///
+/// Stores the significant digits and exponent, returning the number
+/// of processed bytes.
+#[allow(clippy::collapsible_if)]
+fn parse_partial_number(
+    mut byte: FormatBytes,
+    format: &Format,
+    options: &Options,
+    digits: &mut Digits,
+) -> Result<usize> {
+    let radix = format.radix();
+    let decimal_point = options.decimal_point();
+    let exponent_character = options.exponent();
+
+    // INTEGER
+
+    // Check to see if we have a valid base prefix.
+    let base_prefix = format.base_prefix();
+    let mut is_prefix = false;
+    let prefix_index = byte.cursor();
+    if base_prefix != 0 && byte.peek(INTEGER) == Some(b'0') {
+        // Only consume the leading zero if it's part of the base prefix.
+        let mut prefix = byte.clone();
+        prefix.step();
+        if let Some(c) = prefix.peek(INTEGER) {
+            is_prefix = is_affix(c, base_prefix, format.case_sensitive_base_prefix());
+            if is_prefix {
+                prefix.step();
+                if prefix.is_done() {
+                    return Err(Error::Empty(prefix.cursor()));
+                }
+                byte = prefix;
+            }
+        }
+    }
+    if format.required_base_prefix() && !is_prefix {
+        return Err(Error::MissingBasePrefix(prefix_index));
+    }
+
+    // Parse our integral digits.
+    let start = byte.clone();
+    while let Some(c) = byte.peek(INTEGER) {
+        if char_to_digit_const(c, radix).is_none() {
+            break;
+        }
+        digits.push_integer(c);
+        byte.step();
+    }
+    let mut n_digits = byte.current_count() - start.current_count();
+    if format.required_integer_digits() && n_digits == 0 {
+        return Err(Error::EmptyInteger(byte.cursor()));
+    }
+
+    // Check if integer leading zeros are disabled. Values without a
+    // decimal point or exponent are integers, and use the integer policy.
+    if !is_prefix && n_digits > 1 && start.first_is(b'0') {
+        let is_float = byte.first_is(decimal_point)
+            || if format.case_sensitive_exponent() {
+                byte.first_is(exponent_character)
+            } else {
+                byte.case_insensitive_first_is(exponent_character)
+            };
+        let no_leading_zeros = if is_float {
+            format.no_float_leading_zeros()
+        } else {
+            format.no_integer_leading_zeros()
+        };
+        if no_leading_zeros {
+            return Err(Error::InvalidLeadingZeros(start.cursor()));
+        }
+    }
+
+    // FRACTION
+
+    // Handle decimal point and digits afterwards.
+    let mut is_fraction = false;
+    if byte.first_is(decimal_point) {
+        is_fraction = true;
+        byte.step();
+        let before = byte.clone();
+        while let Some(c) = byte.peek(FRACTION) {
+            if char_to_digit_const(c, radix).is_none() {
+                break;
+            }
+            digits.push_fraction(c);
+            byte.step();
+        }
+        let n_after_dot = byte.current_count() - before.current_count();
+        if format.required_fraction_digits() && n_after_dot == 0 {
+            return Err(Error::EmptyFraction(byte.cursor()));
+        }
+        n_digits += n_after_dot;
+    }
+
+    if format.required_mantissa_digits() && n_digits == 0 {
+        return Err(Error::EmptyMantissa(byte.cursor()));
+    }
+
+    // EXPONENT
+
+    // Handle scientific notation.
+    let is_exponent = if format.case_sensitive_exponent() {
+        byte.first_is(exponent_character)
+    } else {
+        byte.case_insensitive_first_is(exponent_character)
+    };
+    if is_exponent {
+        // Check float format syntax checks.
+        if format.no_exponent_notation() {
+            return Err(Error::InvalidExponent(byte.cursor()));
+        }
+        // Check if we have no fraction but we required exponent notation.
+        if format.no_exponent_without_fraction() && !is_fraction {
+            return Err(Error::ExponentWithoutFraction(byte.cursor()));
+        }
+
+        byte.step();
+        let (is_negative, shift) = match byte.peek(INTEGER) {
+            Some(b'+') if !format.no_positive_exponent_sign() => (false, 1),
+            Some(b'+') => return Err(Error::InvalidPositiveExponentSign(byte.cursor())),
+            Some(b'-') => (true, 1),
+            Some(_) if format.required_exponent_sign() => {
+                return Err(Error::MissingExponentSign(byte.cursor()));
+            },
+            _ => (false, 0),
+        };
+        if shift == 1 {
+            byte.step();
+        }
+        if format.required_exponent_sign() && shift == 0 {
+            return Err(Error::MissingExponentSign(byte.cursor()));
+        }
+
+        // The exponent digits use the mantissa radix, like the const parsers.
+        let before = byte.current_count();
+        let mut explicit_exponent = 0_i64;
+        while let Some(c) = byte.peek(EXPONENT) {
+            let digit = match char_to_digit_const(c, radix) {
+                Some(digit) => digit,
+                None => break,
+            };
+            if explicit_exponent < 0x10000000 {
+                explicit_exponent *= radix as i64;
+                explicit_exponent += digit as i64;
+            }
+            byte.step();
+        }
+        if format.required_exponent_digits() && byte.current_count() - before == 0 {
+            return Err(Error::EmptyExponent(byte.cursor()));
+        }
+        digits.exponent = if is_negative {
+            -explicit_exponent
+        } else {
+            explicit_exponent
+        };
+    } else if format.required_exponent_notation() {
+        return Err(Error::MissingExponent(byte.cursor()));
+    }
+
+    // Check to see if we have a valid base suffix.
+    let base_suffix = format.base_suffix();
+    if base_suffix != 0 {
+        let is_suffix = if format.case_sensitive_base_suffix() {
+            byte.first_is(base_suffix)
+        } else {
+            byte.case_insensitive_first_is(base_suffix)
+        };
+        if is_suffix {
+            byte.step();
+        } else if format.required_base_suffix() {
+            return Err(Error::MissingBaseSuffix(byte.cursor()));
+        }
+    }
+
+    Ok(byte.cursor())
+}
+
+// SPECIAL
+// -------
+
+/// Determine if the input data matches the special string.
+/// If there's no match, returns 0. Otherwise, returns the byte's cursor.
+fn is_special_eq(mut byte: FormatBytes, format: &Format, string: &'static [u8]) -> usize {
+    let case_sensitive = format.case_sensitive_special();
+    for &expected in string {
+        let is_equal = match byte.next(SPECIAL) {
+            Some(c) if case_sensitive => c == expected,
+            Some(c) => case_insensitive_eq(c, expected),
+            None => false,
+        };
+        if !is_equal {
+            return 0;
+        }
+    }
+    // Trim the iterator afterwards.
+    byte.peek(SPECIAL);
+    byte.cursor()
+}
+
+/// Parse a partial representation of a positive special, non-finite float.
+fn parse_partial_special<F: LemireFloat>(
+    byte: FormatBytes,
+    format: &Format,
+    options: &Options,
+) -> Option<(F, usize)> {
+    if format.no_special() {
+        return None;
+    }
+
+    let length = byte.length() - byte.cursor();
+    let strings = [
+        (options.nan_string(), F::NAN),
+        (options.infinity_string(), F::INFINITY),
+        (options.inf_string(), F::INFINITY),
+    ];
+    for &(string, float) in strings.iter() {
+        if let Some(string) = string {
+            if length >= string.len() {
+                let count = is_special_eq(byte.clone(), format, string);
+                if count != 0 {
+                    return Some((float, count));
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,131 @@
+//! Scaling helpers to compare decimal strings to native floats.
+//!
+//! These are the building blocks of the slow path algorithms, which
+//! compare the significant digits of a number to the halfway point
+//! between two floats, and are exposed so custom correctness checkers
+//! can reuse them. For a float `b`, [`b`] is the exact value of the float
+//! and [`bh`] is the halfway point `b+h` to the next float, both as an
+//! [`ExtendedFloat80`] with the value `mant * 2^exp`. The scientific
+//! exponent of the number, from [`scientific_exponent`], is used to scale
+//! the digits and the binary representation to the same exponent.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::scale::{b, bh};
+//!
+//! // 1.0 is `2^52 * 2^-52`.
+//! let fp = b(1.0_f64);
+//! assert_eq!((fp.mant, fp.exp), (1 << 52, -52));
+//!
+//! // The halfway point to the next float is `1 + 2^-53`.
+//! let fp = bh(1.0_f64);
+//! assert_eq!((fp.mant, fp.exp), ((1 << 53) + 1, -53));
+//! ```
+
+#[doc(inline)]
+pub use crate::float::{ExtendedFloat80, RawFloat};
+#[doc(inline)]
+pub use crate::number::Number;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::AsPrimitive;
+
+/// Calculate the scientific exponent from a `Number` value.
+///
+/// This is the exponent of the most significant digit in the radix of
+/// the number format, so the value is in the range `[radix^exp,
+/// radix^(exp + 1))`. Only the mantissa and exponent of the number are
+/// used, and if the mantissa is zero, this is the exponent of the number.
+/// Any other attempts would require slowdowns for faster algorithms.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::format::STANDARD;
+/// use lexical_parse_float::scale::{scientific_exponent, Number};
+///
+/// // 123.45, or 1.2345e2.
+/// let num = Number {
+///     mantissa: 12345,
+///     exponent: -2,
+///     ..Number::default()
+/// };
+/// assert_eq!(scientific_exponent::<{ STANDARD }>(&num), 2);
+/// ```
+#[inline]
+pub fn scientific_exponent<const FORMAT: u128>(num: &Number) -> i32 {
+    // This has the significant digits and exponent relative to those
+    // digits: therefore, we just need to scale to mantissa to `[1, radix)`.
+    // This doesn't need to be very fast.
+    let format = NumberFormat::<FORMAT> {};
+
+    // Use power reduction to make this faster: we need at least
+    // F::MANTISSA_SIZE bits, so we must have at least radix^4 digits.
+    // IF we're using base 3, we can have at most 11 divisions, and
+    // base 36, at most ~4. So, this is reasonably efficient.
+    let radix = format.radix() as u64;
+    let radix2 = radix * radix;
+    let radix4 = radix2 * radix2;
+    let mut mantissa = num.mantissa;
+    let mut exponent = num.exponent;
+    while mantissa >= radix4 {
+        mantissa /= radix4;
+        exponent += 4;
+    }
+    while mantissa >= radix2 {
+        mantissa /= radix2;
+        exponent += 2;
+    }
+    while mantissa >= radix {
+        mantissa /= radix;
+        exponent += 1;
+    }
+    exponent as i32
+}
+
+/// Calculate `b` from a a representation of `b` as a float.
+///
+/// This is the exact value of the float, with the hidden bit for normal
+/// floats, and the unbiased exponent of the least significant bit.
+/// The float must be finite and positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::scale::b;
+///
+/// let fp = b(5e-324_f64);
+/// assert_eq!((fp.mant, fp.exp), (1, -1074));
+/// let fp = b(1.5_f32);
+/// assert_eq!((fp.mant, fp.exp), (3 << 22, -23));
+/// ```
+#[inline]
+pub fn b<F: RawFloat>(float: F) -> ExtendedFloat80 {
+    ExtendedFloat80 {
+        mant: float.mantissa().as_u64(),
+        exp: float.exponent(),
+    }
+}
+
+/// Calculate `b+h` from a a representation of `b` as a float.
+///
+/// This is the exact halfway point between `b` and the next float,
+/// with one more bit of precision than [`b`]. The float must be finite
+/// and positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::scale::bh;
+///
+/// let fp = bh(5e-324_f64);
+/// assert_eq!((fp.mant, fp.exp), (3, -1075));
+/// ```
+#[inline]
+pub fn bh<F: RawFloat>(float: F) -> ExtendedFloat80 {
+    let fp = b(float);
+    ExtendedFloat80 {
+        mant: (fp.mant << 1) + 1,
+        exp: fp.exp - 1,
+    }
+}
@@ -6,8 +6,8 @@
 
 #![doc(hidden)]
 
-#[cfg(feature = "radix")]
-use crate::bigint::Bigfloat;
+#[cfg(feature = "any-radix")]
+use crate::bigint::{large_mul, Bigfloat};
 use crate::bigint::{Bigint, Limb, LIMB_BITS};
 use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use crate::limits::{u32_power_limit, u64_power_limit};
@@ -17,11 +17,11 @@ use core::cmp;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
 use lexical_util::digit::char_to_valid_digit_const;
-#[cfg(feature = "radix")]
-use lexical_util::digit::digit_to_char_const;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, BytesIter};
-use lexical_util::num::{AsPrimitive, Integer};
+#[cfg(feature = "any-radix")]
+use lexical_util::num::AsPrimitive;
+use lexical_util::num::Integer;
 
 // ALGORITHM
 // ---------
@@ -42,11 +42,14 @@ use lexical_util::num::{AsPrimitive, Integer};
 /// any value before or equal to `16777217.0` must be rounded down
 /// to `16777216.0`. These near-halfway conversions therefore may require
 /// a large number of digits to unambiguously determine how to round.
+///
+/// Returns `None` if an internal invariant of the big-integer arithmetic
+/// did not hold, which only occurs with the `no-panic` feature.
 #[inline]
 pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
@@ -67,7 +70,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     // to have a finite representation in radix `y`, `b` should divide
     // an integer power of `y`. This means for binary, all even radixes
     // have finite representations, and all odd ones do not.
-    #[cfg(feature = "radix")]
+    #[cfg(feature = "any-radix")]
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
@@ -78,10 +81,10 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
         }
     }
 
-    #[cfg(not(feature = "radix"))]
+    #[cfg(not(feature = "any-radix"))]
     {
         // Can use our finite number of digit algorithm.
-        let max_digits = F::max_digits(format.radix()).unwrap();
+        let max_digits = unwrap_infallible!(F::max_digits(format.radix()));
         digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
     }
 }
@@ -99,8 +102,8 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
     fp: ExtendedFloat80,
     sci_exp: i32,
     max_digits: usize,
-) -> ExtendedFloat80 {
-    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+) -> Option<ExtendedFloat80> {
+    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits)?;
     // This can't underflow, since `digits` is at most `max_digits`.
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
@@ -114,14 +117,14 @@ pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
 pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
     mut bigmant: Bigint,
     exponent: i32,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
     let format = NumberFormat::<{ FORMAT }> {};
 
     // Simple, we just need to multiply by the power of the radix.
     // Now, we can calculate the mantissa and the exponent from this.
     // The binary exponent is the binary exponent for the mantissa
     // shifted to the hidden bit.
-    bigmant.pow(format.radix(), exponent as u32).unwrap();
+    unwrap_infallible!(bigmant.pow(format.radix(), exponent as u32));
 
     // Get the exact representation of the float from the big integer.
     // hi64 checks **all** the remaining bits after the mantissa,
@@ -139,7 +142,7 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
             is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
         });
     });
-    fp
+    Some(fp)
 }
 
 /// Generate the significant digits with a negative exponent relative to mantissa.
@@ -167,7 +170,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     bigmant: Bigint,
     mut fp: ExtendedFloat80,
     exponent: i32,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
@@ -212,15 +215,15 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     };
 
     if halfradix_exp != 0 {
-        theor_digits.pow(radix / 2, halfradix_exp as u32).unwrap();
+        unwrap_infallible!(theor_digits.pow(radix / 2, halfradix_exp as u32));
     }
     if radix_exp != 0 {
-        theor_digits.pow(radix, radix_exp as u32).unwrap();
+        unwrap_infallible!(theor_digits.pow(radix, radix_exp as u32));
     }
     if binary_exp > 0 {
-        theor_digits.pow(2, binary_exp as u32).unwrap();
+        unwrap_infallible!(theor_digits.pow(2, binary_exp as u32));
     } else if binary_exp < 0 {
-        real_digits.pow(2, (-binary_exp) as u32).unwrap();
+        unwrap_infallible!(real_digits.pow(2, (-binary_exp) as u32));
     }
 
     // Compare our theoretical and real digits and round nearest, tie even.
@@ -237,7 +240,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
             }
         });
     });
-    fp
+    Some(fp)
 }
 
 /// Try to parse 8 digits at a time.
@@ -291,8 +294,8 @@ macro_rules! add_digit {
 macro_rules! add_temporary {
     // Multiply by the small power and add the native value.
     (@mul $result:ident, $power:expr, $value:expr) => {
-        $result.data.mul_small($power).unwrap();
-        $result.data.add_small($value).unwrap();
+        unwrap_infallible!($result.data.mul_small($power));
+        unwrap_infallible!($result.data.add_small($value));
     };
 
     // Add a temporary where we won't read the counter results internally.
@@ -341,7 +344,7 @@ macro_rules! round_up_nonzero {
                 if value != 0x3030_3030_3030_3030 {
                     // Have non-zero digits, exit early.
                     round_up_truncated!($format, $result, $count);
-                    return ($result, $count);
+                    return Some(($result, $count));
                 }
             }
         }
@@ -349,7 +352,7 @@ macro_rules! round_up_nonzero {
         for &digit in iter {
             if digit != b'0' {
                 round_up_truncated!($format, $result, $count);
-                return ($result, $count);
+                return Some(($result, $count));
             }
         }
     }};
@@ -359,7 +362,10 @@ macro_rules! round_up_nonzero {
 ///
 /// Returns the parsed mantissa and the number of digits in the mantissa.
 /// The max digits is the maximum number of digits plus one.
-pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bigint, usize) {
+pub fn parse_mantissa<const FORMAT: u128>(
+    num: Number,
+    max_digits: usize,
+) -> Option<(Bigint, usize)> {
     let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
@@ -407,7 +413,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
                 let mut fraction = fraction.bytes::<FORMAT>();
                 round_up_nonzero!(format, fraction.fraction_iter(), result, count)
             }
-            return (result, count);
+            return Some((result, count));
         } else {
             // Add our temporary from the loop.
             // SAFETY: safe since `counter <= step`.
@@ -441,7 +447,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
                 // SAFETY: safe since `counter <= step`.
                 add_temporary!(@end format, result, counter, value);
                 round_up_nonzero!(format, fraction_iter, result, count);
-                return (result, count);
+                return Some((result, count));
             } else {
                 // Add our temporary from the loop.
                 // SAFETY: safe since `counter <= step`.
@@ -455,28 +461,29 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
     // SAFETY: safe since `counter <= step`.
     add_temporary!(@end format, result, counter, value);
 
-    (result, count)
+    Some((result, count))
 }
 
 /// Compare actual integer digits to the theoretical digits.
-#[cfg(feature = "radix")]
+#[cfg(feature = "any-radix")]
 macro_rules! integer_compare {
     ($iter:ident, $num:ident, $den:ident, $radix:ident) => {{
         // Compare the integer digits.
         while !$num.data.is_empty() {
-            // All digits **must** be valid.
+            // All digits **must** be valid. Compare the digit values, since
+            // the order of the characters differs from the digits for
+            // lowercase letters, and radixes above 36.
             let actual = match $iter.next() {
-                Some(&v) => v,
+                Some(&v) => char_to_valid_digit_const(v, $radix),
                 // Could have hit the decimal point.
                 _ => break,
             };
-            let rem = $num.data.quorem(&$den.data) as u32;
-            let expected = digit_to_char_const(rem, $radix);
-            $num.data.mul_small($radix as Limb).unwrap();
+            let expected = unwrap_infallible!($num.data.quorem(&$den.data)) as u32;
+            unwrap_infallible!($num.data.mul_small($radix as Limb));
             if actual < expected {
-                return cmp::Ordering::Less;
+                return Some(cmp::Ordering::Less);
             } else if actual > expected {
-                return cmp::Ordering::Greater;
+                return Some(cmp::Ordering::Greater);
             }
         }
 
@@ -484,7 +491,7 @@ macro_rules! integer_compare {
         if $num.data.is_empty() {
             for &digit in $iter {
                 if digit != b'0' {
-                    return cmp::Ordering::Greater;
+                    return Some(cmp::Ordering::Greater);
                 }
             }
         }
@@ -492,7 +499,7 @@ macro_rules! integer_compare {
 }
 
 /// Compare actual fraction digits to the theoretical digits.
-#[cfg(feature = "radix")]
+#[cfg(feature = "any-radix")]
 macro_rules! fraction_compare {
     ($iter:ident, $num:ident, $den:ident, $radix:ident) => {{
         // Compare the fraction digits.
@@ -500,24 +507,23 @@ macro_rules! fraction_compare {
         while !$num.data.is_empty() {
             // All digits **must** be valid.
             let actual = match $iter.next() {
-                Some(&v) => v,
+                Some(&v) => char_to_valid_digit_const(v, $radix),
                 // No more actual digits, or hit the exponent.
-                _ => return cmp::Ordering::Less,
+                _ => return Some(cmp::Ordering::Less),
             };
-            let rem = $num.data.quorem(&$den.data) as u32;
-            let expected = digit_to_char_const(rem, $radix);
-            $num.data.mul_small($radix as Limb).unwrap();
+            let expected = unwrap_infallible!($num.data.quorem(&$den.data)) as u32;
+            unwrap_infallible!($num.data.mul_small($radix as Limb));
             if actual < expected {
-                return cmp::Ordering::Less;
+                return Some(cmp::Ordering::Less);
             } else if actual > expected {
-                return cmp::Ordering::Greater;
+                return Some(cmp::Ordering::Greater);
             }
         }
 
         // Still have fraction digits, check if any are non-zero.
         for &digit in $iter {
             if digit != b'0' {
-                return cmp::Ordering::Greater;
+                return Some(cmp::Ordering::Greater);
             }
         }
     }};
@@ -536,30 +542,68 @@ macro_rules! fraction_compare {
 ///
 /// Adapted from "Bigcomp: Deciding Truncated, Near Halfway Conversions",
 /// available [here](https://www.exploringbinary.com/bigcomp-deciding-truncated-near-halfway-conversions/).
-#[cfg(feature = "radix")]
-#[allow(clippy::comparison_chain)]
+#[cfg(feature = "any-radix")]
 pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     number: Number,
-    mut fp: ExtendedFloat80,
+    fp: ExtendedFloat80,
     sci_exp: i32,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
 
-    let format = NumberFormat::<FORMAT> {};
-
     // Round down our extended-precision float and calculate `b`.
     let mut b = fp;
     shared::round::<F, _>(&mut b, shared::round_down);
-    let b = extended_to_float::<F>(b);
+    let mut b = extended_to_float::<F>(b);
+    let mut ord = compare_halfway::<F, FORMAT>(number, b, sci_exp)?;
+
+    // For large radixes, few digits fit in the 64-bit mantissa, so the
+    // extended-precision float may be more than a half ULP below the
+    // digits. If so, the digits are also above the next halfway point.
+    while ord == cmp::Ordering::Greater {
+        let next = b.next_up();
+        if next.is_inf() {
+            break;
+        }
+        match compare_halfway::<F, FORMAT>(number, next, sci_exp)? {
+            cmp::Ordering::Less => break,
+            next_ord => {
+                b = next;
+                ord = next_ord;
+            },
+        }
+    }
+
+    // Round nearest, tie even, using the comparison to `b+h`.
+    let is_odd = b.to_bits() & F::Unsigned::ONE == F::Unsigned::ONE;
+    let float = match ord {
+        cmp::Ordering::Greater => b.next_up(),
+        cmp::Ordering::Equal if is_odd => b.next_up(),
+        _ => b,
+    };
+    let bits = float.to_bits();
+    Some(ExtendedFloat80 {
+        mant: (bits & F::MANTISSA_MASK).as_u64(),
+        exp: (bits >> F::MANTISSA_SIZE).as_i32(),
+    })
+}
+
+/// Compare the actual digits to the halfway point between `b` and `b+u`.
+#[cfg(feature = "any-radix")]
+fn compare_halfway<F: RawFloat, const FORMAT: u128>(
+    number: Number,
+    b: F,
+    sci_exp: i32,
+) -> Option<cmp::Ordering> {
+    let format = NumberFormat::<FORMAT> {};
 
     // Calculate `b+h` to create a ratio for our theoretical digits.
     let theor = Bigfloat::from_float(bh::<F>(b));
 
     // Now, create a scaling factor for the digit count.
     let mut factor = Bigfloat::from_u32(1);
-    factor.pow(format.radix(), sci_exp.unsigned_abs()).unwrap();
+    unwrap_infallible!(factor.pow(format.radix(), sci_exp.unsigned_abs()));
     let mut num: Bigfloat;
     let mut den: Bigfloat;
 
@@ -568,7 +612,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
         // be the denominator. Since we assumed that theor was the numerator,
         // if it's the denominator, we need to multiply it into the numerator.
         num = factor;
-        num.data *= &theor.data;
+        unwrap_infallible!(large_mul(&mut num.data, &theor.data));
         den = Bigfloat::from_u32(1);
         den.exp = -theor.exp;
     } else {
@@ -581,7 +625,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     let wlz = integral_binary_factor(format.radix());
     let nlz = den.leading_zeros().wrapping_sub(wlz) & (32 - 1);
     if nlz != 0 {
-        den.shl_bits(nlz as usize).unwrap();
+        unwrap_infallible!(den.shl_bits(nlz as usize));
         den.exp -= nlz as i32;
     }
 
@@ -591,7 +635,7 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
     let shift = diff.unsigned_abs() as usize;
     if diff < 0 {
         // Need to shift the numerator left.
-        num.shl(shift).unwrap();
+        unwrap_infallible!(num.shl(shift));
         num.exp -= shift as i32;
     } else if diff > 0 {
         // Need to shift denominator left, go by a power of LIMB_BITS.
@@ -601,39 +645,25 @@ pub fn byte_comp<F: RawFloat, const FORMAT: u128>(
         let (q, r) = shift.ceil_divmod(LIMB_BITS);
         let r = -r;
         if r != 0 {
-            num.shl_bits(r as usize).unwrap();
+            unwrap_infallible!(num.shl_bits(r as usize));
             num.exp -= r;
         }
         if q != 0 {
-            den.shl_limbs(q).unwrap();
+            unwrap_infallible!(den.shl_limbs(q));
             den.exp -= LIMB_BITS as i32 * q as i32;
         }
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
-    let ord = compare_bytes::<FORMAT>(number, num, den);
-    shared::round::<F, _>(&mut fp, |f, s| {
-        shared::round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
-    fp
+    compare_bytes::<FORMAT>(number, num, den)
 }
 
 /// Compare digits between the generated values the ratio and the actual view.
-#[cfg(feature = "radix")]
+#[cfg(feature = "any-radix")]
 pub fn compare_bytes<const FORMAT: u128>(
     number: Number,
     mut num: Bigfloat,
     den: Bigfloat,
-) -> cmp::Ordering {
+) -> Option<cmp::Ordering> {
     let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
@@ -644,7 +674,7 @@ pub fn compare_bytes<const FORMAT: u128>(
     integer_iter.skip_zeros();
     if integer_iter.is_done() {
         // Cannot be empty, since we must have at least **some** significant digits.
-        let mut fraction = number.fraction.unwrap().bytes::<{ FORMAT }>();
+        let mut fraction = unwrap_infallible!(number.fraction).bytes::<{ FORMAT }>();
         let mut fraction_iter = fraction.fraction_iter();
         fraction_iter.skip_zeros();
         fraction_compare!(fraction_iter, num, den, radix);
@@ -656,103 +686,174 @@ pub fn compare_bytes<const FORMAT: u128>(
             fraction_compare!(fraction_iter, num, den, radix);
         } else if !num.data.is_empty() {
             // We had more theoretical digits, but no more actual digits.
-            return cmp::Ordering::Less;
+            return Some(cmp::Ordering::Less);
         }
     }
 
     // Exhausted both, must be equal.
-    cmp::Ordering::Equal
+    Some(cmp::Ordering::Equal)
 }
 
-// SCALING
-// -------
-
-/// Calculate the scientific exponent from a `Number` value.
-/// Any other attempts would require slowdowns for faster algorithms.
-#[inline]
-pub fn scientific_exponent<const FORMAT: u128>(num: &Number) -> i32 {
-    // This has the significant digits and exponent relative to those
-    // digits: therefore, we just need to scale to mantissa to `[1, radix)`.
-    // This doesn't need to be very fast.
-    let format = NumberFormat::<FORMAT> {};
+// REPRESENTATION
+// --------------
+
+/// How a number is represented by the nearest float.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Representation {
+    /// The number is exactly representable as a float.
+    Exact,
+    /// The number is exactly halfway between two adjacent floats, and
+    /// is rounded to the float with an even significand.
+    Halfway,
+    /// The number is neither exactly representable nor a halfway case.
+    Inexact,
+}
 
-    // Use power reduction to make this faster: we need at least
-    // F::MANTISSA_SIZE bits, so we must have at least radix^4 digits.
-    // IF we're using base 3, we can have at most 11 divisions, and
-    // base 36, at most ~4. So, this is reasonably efficient.
-    let radix = format.radix() as u64;
-    let radix2 = radix * radix;
-    let radix4 = radix2 * radix2;
-    let mut mantissa = num.mantissa;
-    let mut exponent = num.exponent;
-    while mantissa >= radix4 {
-        mantissa /= radix4;
-        exponent += 4;
-    }
-    while mantissa >= radix2 {
-        mantissa /= radix2;
-        exponent += 2;
+/// Determine how the significant digits are represented by the nearest float.
+///
+/// `float` must be the magnitude of the correctly rounded float for the
+/// number. We first compare the real digits to the float, and then to
+/// the halfway point between the float and the adjacent float in the
+/// direction of the real digits, all using exact, big-integer arithmetic.
+/// This requires a radix with a finite number of digits, given by
+/// `max_digits`. Numbers that overflow are always inexact.
+pub fn representation<F: RawFloat, const FORMAT: u128>(
+    num: Number,
+    float: F,
+    max_digits: usize,
+) -> Option<Representation> {
+    if num.mantissa == 0 {
+        return Some(Representation::Exact);
+    } else if float.is_inf() {
+        return Some(Representation::Inexact);
     }
-    while mantissa >= radix {
-        mantissa /= radix;
-        exponent += 1;
+
+    let format = NumberFormat::<FORMAT> {};
+    if float == F::ZERO {
+        // The number underflowed, so it can only be equal to the halfway
+        // point to the smallest denormal float, `2^N2`. Since the number is
+        // less than `2^64 * b^N1`, we can skip numbers that are too small,
+        // avoiding creating big integers too large for the storage.
+        let log2_radix = (31 - format.radix().leading_zeros()) as i64;
+        let halfway_exp = bh(float).exp as i64;
+        if 64 + num.exponent.saturating_mul(log2_radix) <= halfway_exp {
+            return Some(Representation::Inexact);
+        }
     }
-    exponent as i32
-}
 
-/// Calculate `b` from a a representation of `b` as a float.
-#[inline]
-pub fn b<F: RawFloat>(float: F) -> ExtendedFloat80 {
-    ExtendedFloat80 {
-        mant: float.mantissa().as_u64(),
-        exp: float.exponent(),
+    let sci_exp = scientific_exponent::<FORMAT>(&num);
+    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits)?;
+    // This can't underflow, since `digits` is at most `max_digits`.
+    let exponent = sci_exp + 1 - digits as i32;
+    let halfway = match compare_digits::<FORMAT>(bigmant.clone(), exponent, b(float))? {
+        cmp::Ordering::Equal => return Some(Representation::Exact),
+        cmp::Ordering::Greater => bh(float),
+        cmp::Ordering::Less => bh(float.next_down()),
+    };
+    match compare_digits::<FORMAT>(bigmant, exponent, halfway)? {
+        cmp::Ordering::Equal => Some(Representation::Halfway),
+        _ => Some(Representation::Inexact),
     }
 }
 
-/// Calculate `b+h` from a a representation of `b` as a float.
-#[inline]
-pub fn bh<F: RawFloat>(float: F) -> ExtendedFloat80 {
-    let fp = b(float);
-    ExtendedFloat80 {
-        mant: (fp.mant << 1) + 1,
-        exp: fp.exp - 1,
+/// Compare the real digits, `m1 * b^N1`, to an extended-precision float, `m2 * 2^N2`.
+///
+/// This scales both sides to integers, like `negative_digit_comp`, and
+/// requires an even radix if `N1` is negative.
+pub fn compare_digits<const FORMAT: u128>(
+    mut real_digits: Bigint,
+    real_exp: i32,
+    fp: ExtendedFloat80,
+) -> Option<cmp::Ordering> {
+    let format = NumberFormat::<FORMAT> {};
+    let radix = format.radix();
+    let mut theor_digits = Bigint::from_u64(fp.mant);
+    let theor_exp = fp.exp;
+
+    // Both exponents are relative to `theor_digits`.
+    let binary_exp = if real_exp >= 0 {
+        unwrap_infallible!(real_digits.pow(radix, real_exp as u32));
+        theor_exp
+    } else {
+        // Remove a power-of-two from both sides of the equation.
+        debug_assert!(radix.is_even());
+        unwrap_infallible!(theor_digits.pow(radix / 2, (-real_exp) as u32));
+        theor_exp - real_exp
+    };
+    if binary_exp > 0 {
+        unwrap_infallible!(theor_digits.pow(2, binary_exp as u32));
+    } else if binary_exp < 0 {
+        unwrap_infallible!(real_digits.pow(2, (-binary_exp) as u32));
     }
+
+    Some(real_digits.data.cmp(&theor_digits.data))
 }
 
+// SCALING
+// -------
+
+pub use crate::scale::{b, bh, scientific_exponent};
+
 /// Calculate the integral ceiling of the binary factor from a basen number.
 #[inline]
 pub const fn integral_binary_factor(radix: u32) -> u32 {
     match radix {
-        3 if cfg!(feature = "radix") => 2,
-        5 if cfg!(feature = "radix") => 3,
-        6 if cfg!(feature = "radix") => 3,
-        7 if cfg!(feature = "radix") => 3,
-        9 if cfg!(feature = "radix") => 4,
+        3 if cfg!(feature = "any-radix") => 2,
+        5 if cfg!(feature = "any-radix") => 3,
+        6 if cfg!(feature = "any-radix") => 3,
+        7 if cfg!(feature = "any-radix") => 3,
+        9 if cfg!(feature = "any-radix") => 4,
         10 => 4,
-        11 if cfg!(feature = "radix") => 4,
-        12 if cfg!(feature = "radix") => 4,
-        13 if cfg!(feature = "radix") => 4,
-        14 if cfg!(feature = "radix") => 4,
-        15 if cfg!(feature = "radix") => 4,
-        17 if cfg!(feature = "radix") => 5,
-        18 if cfg!(feature = "radix") => 5,
-        19 if cfg!(feature = "radix") => 5,
-        20 if cfg!(feature = "radix") => 5,
-        21 if cfg!(feature = "radix") => 5,
-        22 if cfg!(feature = "radix") => 5,
-        23 if cfg!(feature = "radix") => 5,
-        24 if cfg!(feature = "radix") => 5,
-        25 if cfg!(feature = "radix") => 5,
-        26 if cfg!(feature = "radix") => 5,
-        27 if cfg!(feature = "radix") => 5,
-        28 if cfg!(feature = "radix") => 5,
-        29 if cfg!(feature = "radix") => 5,
-        30 if cfg!(feature = "radix") => 5,
-        31 if cfg!(feature = "radix") => 5,
-        33 if cfg!(feature = "radix") => 6,
-        34 if cfg!(feature = "radix") => 6,
-        35 if cfg!(feature = "radix") => 6,
-        36 if cfg!(feature = "radix") => 6,
+        11 if cfg!(feature = "any-radix") => 4,
+        12 if cfg!(feature = "any-radix") => 4,
+        13 if cfg!(feature = "any-radix") => 4,
+        14 if cfg!(feature = "any-radix") => 4,
+        15 if cfg!(feature = "any-radix") => 4,
+        17 if cfg!(feature = "any-radix") => 5,
+        18 if cfg!(feature = "any-radix") => 5,
+        19 if cfg!(feature = "any-radix") => 5,
+        20 if cfg!(feature = "any-radix") => 5,
+        21 if cfg!(feature = "any-radix") => 5,
+        22 if cfg!(feature = "any-radix") => 5,
+        23 if cfg!(feature = "any-radix") => 5,
+        24 if cfg!(feature = "any-radix") => 5,
+        25 if cfg!(feature = "any-radix") => 5,
+        26 if cfg!(feature = "any-radix") => 5,
+        27 if cfg!(feature = "any-radix") => 5,
+        28 if cfg!(feature = "any-radix") => 5,
+        29 if cfg!(feature = "any-radix") => 5,
+        30 if cfg!(feature = "any-radix") => 5,
+        31 if cfg!(feature = "any-radix") => 5,
+        33 if cfg!(feature = "any-radix") => 6,
+        34 if cfg!(feature = "any-radix") => 6,
+        35 if cfg!(feature = "any-radix") => 6,
+        36 if cfg!(feature = "any-radix") => 6,
+        37 if cfg!(feature = "any-radix") => 6,
+        38 if cfg!(feature = "any-radix") => 6,
+        39 if cfg!(feature = "any-radix") => 6,
+        40 if cfg!(feature = "any-radix") => 6,
+        41 if cfg!(feature = "any-radix") => 6,
+        42 if cfg!(feature = "any-radix") => 6,
+        43 if cfg!(feature = "any-radix") => 6,
+        44 if cfg!(feature = "any-radix") => 6,
+        45 if cfg!(feature = "any-radix") => 6,
+        46 if cfg!(feature = "any-radix") => 6,
+        47 if cfg!(feature = "any-radix") => 6,
+        48 if cfg!(feature = "any-radix") => 6,
+        49 if cfg!(feature = "any-radix") => 6,
+        50 if cfg!(feature = "any-radix") => 6,
+        51 if cfg!(feature = "any-radix") => 6,
+        52 if cfg!(feature = "any-radix") => 6,
+        53 if cfg!(feature = "any-radix") => 6,
+        54 if cfg!(feature = "any-radix") => 6,
+        55 if cfg!(feature = "any-radix") => 6,
+        56 if cfg!(feature = "any-radix") => 6,
+        57 if cfg!(feature = "any-radix") => 6,
+        58 if cfg!(feature = "any-radix") => 6,
+        59 if cfg!(feature = "any-radix") => 6,
+        60 if cfg!(feature = "any-radix") => 6,
+        61 if cfg!(feature = "any-radix") => 6,
+        62 if cfg!(feature = "any-radix") => 6,
         // Invalid radix
         _ => 0,
     }
@@ -0,0 +1,79 @@
+//! Global counters for the algorithms used to parse floats.
+//!
+//! These are relaxed atomic counters, which are incremented when a float
+//! is parsed using each algorithm, giving long-running services cheap
+//! visibility into the input they parse, without the overhead of tracing.
+//! The counters are shared by all threads and all float types.
+
+#![cfg(feature = "stats")]
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of floats parsed using the fast path.
+pub(crate) static FAST_PATH: AtomicUsize = AtomicUsize::new(0);
+/// Number of floats parsed using the moderate path.
+pub(crate) static MODERATE_PATH: AtomicUsize = AtomicUsize::new(0);
+/// Number of floats parsed using the slow path.
+pub(crate) static SLOW_PATH: AtomicUsize = AtomicUsize::new(0);
+/// Number of strings that failed to parse as floats.
+pub(crate) static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+/// Increment a global counter.
+#[inline(always)]
+pub(crate) fn increment(counter: &AtomicUsize) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Snapshot of the global counters for the algorithms used to parse floats.
+///
+/// Each successfully parsed float, other than special values, is counted
+/// by exactly one of the algorithms. The counters wrap on overflow.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// Number of floats parsed using the fast path, from native floats.
+    pub fast_path: usize,
+    /// Number of floats parsed using the moderate path, IE, the
+    /// Eisel-Lemire algorithm for decimal strings, or the extended-float
+    /// algorithms for other radixes.
+    pub moderate_path: usize,
+    /// Number of floats parsed using the slow path, from big integers.
+    pub slow_path: usize,
+    /// Number of strings that failed to parse as floats.
+    pub errors: usize,
+}
+
+/// Get a snapshot of the global counters for the algorithms used to parse floats.
+///
+/// The counters are loaded individually, so the snapshot may be
+/// inconsistent if floats are concurrently parsed on other threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{stats, FromLexical};
+///
+/// let before = stats();
+/// assert_eq!(f64::from_lexical(b"1.5"), Ok(1.5));
+/// assert!(stats().fast_path > before.fast_path);
+/// ```
+#[inline]
+pub fn stats() -> Stats {
+    Stats {
+        fast_path: FAST_PATH.load(Ordering::Relaxed),
+        moderate_path: MODERATE_PATH.load(Ordering::Relaxed),
+        slow_path: SLOW_PATH.load(Ordering::Relaxed),
+        errors: ERRORS.load(Ordering::Relaxed),
+    }
+}
+
+/// Reset the global counters for the algorithms used to parse floats.
+///
+/// Floats concurrently parsed on other threads may be counted before or
+/// after the reset.
+#[inline]
+pub fn reset_stats() {
+    FAST_PATH.store(0, Ordering::Relaxed);
+    MODERATE_PATH.store(0, Ordering::Relaxed);
+    SLOW_PATH.store(0, Ordering::Relaxed);
+    ERRORS.store(0, Ordering::Relaxed);
+}
@@ -3,6 +3,28 @@
 #![doc(hidden)]
 
 // Re-export all the feature-specific files.
+#[cfg(feature = "build-tables")]
+pub use crate::table_generated::*;
 pub use crate::table_large::*;
+#[cfg(feature = "lazy-tables")]
+pub use crate::table_lazy::*;
 #[cfg(not(feature = "compact"))]
 pub use crate::table_small::*;
+
+use lexical_util::format::is_valid_radix;
+
+/// Determine if the radix has pre-computed tables.
+///
+/// Every radix up to 36 has tables unless they are generated by the build
+/// script, which may also generate tables for radixes from 37 to 62.
+#[inline(always)]
+#[cfg(not(feature = "build-tables"))]
+pub const fn is_table_radix(radix: u32) -> bool {
+    radix <= 36
+}
+
+/// Determine if the radix is supported for floats.
+#[inline(always)]
+pub const fn is_float_radix(radix: u32) -> bool {
+    is_valid_radix(radix) && is_table_radix(radix)
+}
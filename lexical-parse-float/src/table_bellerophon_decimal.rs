@@ -23,7 +23,7 @@ use crate::bellerophon::BellerophonPowers;
 
 /// Get Bellerophon powers from radix.
 #[inline]
-#[cfg(not(feature = "radix"))]
+#[cfg(not(any(feature = "any-radix", feature = "lazy-tables")))]
 pub const fn bellerophon_powers(_: u32) -> &'static BellerophonPowers {
     &BASE10_POWERS
 }
@@ -16,15 +16,17 @@
 //!
 //! DO NOT MODIFY: Generated by `etc/bellerophon_table.py`
 
-#![cfg(feature = "radix")]
+#![cfg(feature = "any-radix")]
+#![cfg(not(feature = "build-tables"))]
 #![doc(hidden)]
 
 use crate::bellerophon::BellerophonPowers;
-#[cfg(feature = "compact")]
+#[cfg(all(feature = "compact", not(feature = "lazy-tables")))]
 use crate::table_bellerophon_decimal::BASE10_POWERS;
 
 /// Get Bellerophon powers from radix.
 #[inline]
+#[cfg(not(feature = "lazy-tables"))]
 pub const fn bellerophon_powers(radix: u32) -> &'static BellerophonPowers {
     match radix {
         3 => &BASE3_POWERS,
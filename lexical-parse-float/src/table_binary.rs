@@ -4,11 +4,9 @@
 #![cfg(not(feature = "compact"))]
 #![doc(hidden)]
 
-#[cfg(not(feature = "radix"))]
+#[cfg(not(feature = "any-radix"))]
 use crate::table_decimal::*;
-#[cfg(not(feature = "radix"))]
-use core::hint;
-#[cfg(not(feature = "radix"))]
+#[cfg(not(feature = "any-radix"))]
 use lexical_util::assert::debug_assert_radix;
 use lexical_util::num::Float;
 
@@ -22,7 +20,7 @@ use lexical_util::num::Float;
 /// Safe as long as the radix provided is valid, and exponent is smaller
 /// than the table for the radix.
 #[inline]
-#[cfg(not(feature = "radix"))]
+#[cfg(not(feature = "any-radix"))]
 pub unsafe fn get_small_int_power(exponent: usize, radix: u32) -> u64 {
     // NOTE: don't check the radix since we also use it for half radix, or 5.
     unsafe {
@@ -34,7 +32,7 @@ pub unsafe fn get_small_int_power(exponent: usize, radix: u32) -> u64 {
             10 => get_small_int_power10(exponent),
             16 => get_small_int_power16(exponent),
             32 => get_small_int_power32(exponent),
-            _ => hint::unreachable_unchecked(),
+            _ => unreachable_unchecked!(),
         }
     }
 }
@@ -46,7 +44,7 @@ pub unsafe fn get_small_int_power(exponent: usize, radix: u32) -> u64 {
 /// Safe as long as the radix provided is valid, and exponent is smaller
 /// than the table for the radix.
 #[inline]
-#[cfg(not(feature = "radix"))]
+#[cfg(not(feature = "any-radix"))]
 pub unsafe fn get_small_f32_power(exponent: usize, radix: u32) -> f32 {
     debug_assert_radix(radix);
     unsafe {
@@ -57,7 +55,7 @@ pub unsafe fn get_small_f32_power(exponent: usize, radix: u32) -> f32 {
             10 => get_small_f32_power10(exponent),
             16 => get_small_f32_power16(exponent),
             32 => get_small_f32_power32(exponent),
-            _ => hint::unreachable_unchecked(),
+            _ => unreachable_unchecked!(),
         }
     }
 }
@@ -69,7 +67,7 @@ pub unsafe fn get_small_f32_power(exponent: usize, radix: u32) -> f32 {
 /// Safe as long as the radix provided is valid, and exponent is smaller
 /// than the table for the radix.
 #[inline]
-#[cfg(not(feature = "radix"))]
+#[cfg(not(feature = "any-radix"))]
 pub unsafe fn get_small_f64_power(exponent: usize, radix: u32) -> f64 {
     debug_assert_radix(radix);
     unsafe {
@@ -80,7 +78,7 @@ pub unsafe fn get_small_f64_power(exponent: usize, radix: u32) -> f64 {
             10 => get_small_f64_power10(exponent),
             16 => get_small_f64_power16(exponent),
             32 => get_small_f64_power32(exponent),
-            _ => hint::unreachable_unchecked(),
+            _ => unreachable_unchecked!(),
         }
     }
 }
@@ -3,7 +3,7 @@
 #![doc(hidden)]
 #![cfg(not(feature = "compact"))]
 
-#[cfg(not(feature = "radix"))]
+#[cfg(not(any(feature = "any-radix", feature = "lazy-tables")))]
 use crate::bigint::Limb;
 use crate::limits::{f32_exponent_limit, f64_exponent_limit, f64_mantissa_limit, u64_power_limit};
 #[cfg(not(feature = "power-of-two"))]
@@ -57,7 +57,11 @@ pub unsafe fn get_small_f64_power(exponent: usize, radix: u32) -> f64 {
 }
 
 /// Get pre-computed power for a large power of radix.
-#[cfg(not(feature = "radix"))]
+///
+/// This is also used for power-of-two builds without `radix`, since
+/// the only odd factor of any supported radix is 5, and powers of two
+/// are applied as shifts.
+#[cfg(not(any(feature = "any-radix", feature = "lazy-tables")))]
 pub const fn get_large_int_power(_: u32) -> (&'static [Limb], u32) {
     (&LARGE_POW5, LARGE_POW5_STEP)
 }
@@ -178,14 +182,14 @@ pub const SMALL_F64_POW10: [f64; 32] = [
 const_assert!(SMALL_F64_POW10.len() > f64_exponent_limit(10).1 as usize);
 
 /// Pre-computed large power-of-5 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW5: [u32; 10] = [
     4279965485, 329373468, 4020270615, 2137533757, 4287402176, 1057042919, 1071430142, 2440757623,
     381945767, 46164893,
 ];
 
 /// Pre-computed large power-of-5 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW5: [u64; 5] = [
     1414648277510068013,
     9180637584431281687,
@@ -0,0 +1,25 @@
+//! Pre-computed tables for non-decimal strings, generated at build time.
+//!
+//! These replace the tables in `table_radix` and `table_bellerophon_radix`,
+//! and are generated by `build.rs` only for the radixes listed in the
+//! `LEXICAL_PARSE_FLOAT_RADIXES` environment variable.
+
+#![cfg(feature = "build-tables")]
+#![doc(hidden)]
+#![allow(clippy::excessive_precision)]
+
+use crate::bellerophon::BellerophonPowers;
+#[cfg(not(any(feature = "compact", feature = "lazy-tables")))]
+use crate::bigint::Limb;
+#[cfg(all(feature = "compact", not(feature = "lazy-tables")))]
+use crate::table_bellerophon_decimal::BASE10_POWERS;
+#[cfg(not(feature = "compact"))]
+use crate::table_binary::*;
+#[cfg(not(feature = "compact"))]
+use crate::table_decimal::*;
+#[cfg(not(feature = "compact"))]
+use lexical_util::assert::debug_assert_radix;
+
+include!(concat!(env!("OUT_DIR"), "/table_bellerophon_radix.rs"));
+#[cfg(not(feature = "compact"))]
+include!(concat!(env!("OUT_DIR"), "/table_radix.rs"));
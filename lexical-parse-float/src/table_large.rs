@@ -5,7 +5,7 @@
 // Re-export all the feature-specific files.
 #[cfg(feature = "compact")]
 pub use crate::table_bellerophon_decimal::*;
-#[cfg(feature = "radix")]
+#[cfg(all(feature = "any-radix", not(feature = "build-tables")))]
 pub use crate::table_bellerophon_radix::*;
 #[cfg(not(feature = "compact"))]
 pub use crate::table_lemire::*;
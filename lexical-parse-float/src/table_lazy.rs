@@ -0,0 +1,165 @@
+//! Lazily computed tables of large powers.
+//!
+//! These replace the static arrays of large powers, used by the slow path
+//! and Bellerophon algorithms, with tables computed on the first use for
+//! each radix. This removes the tables from the binary, at the cost of
+//! computing them once per radix, which is a few thousand big-integer
+//! multiplications.
+
+#![cfg(feature = "lazy-tables")]
+#![doc(hidden)]
+
+#[cfg(any(feature = "compact", feature = "any-radix"))]
+use crate::bellerophon::BellerophonPowers;
+use crate::bigint::Bigint;
+#[cfg(not(feature = "compact"))]
+use crate::bigint::Limb;
+#[cfg(any(feature = "compact", feature = "any-radix"))]
+use crate::bigint::{large_div, shl};
+#[cfg(not(feature = "compact"))]
+use crate::limits::u64_power_limit;
+use core::cell::UnsafeCell;
+use std::sync::Once;
+
+// LAZY
+// ----
+
+/// A value computed on first use, shared between threads.
+///
+/// This is a minimal `OnceLock`, which requires Rust 1.70.
+struct Lazy<T> {
+    once: Once,
+    value: UnsafeCell<Option<T>>,
+}
+
+// SAFETY: safe, since the value is only written once, within `call_once`,
+// which synchronizes the write with every read.
+unsafe impl<T: Send + Sync> Sync for Lazy<T> {}
+
+impl<T> Lazy<T> {
+    const fn new() -> Self {
+        Self {
+            once: Once::new(),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    /// Get the value, computing it on the first use.
+    fn get_or_init<F: FnOnce() -> T>(&'static self, init: F) -> &'static T {
+        // SAFETY: safe, since no other thread can access the value until
+        // `call_once` completes.
+        self.once.call_once(|| unsafe { *self.value.get() = Some(init()) });
+        // SAFETY: safe, since the value is never written after `call_once`.
+        match unsafe { &*self.value.get() } {
+            Some(value) => value,
+            None => unreachable!("Lazy::get_or_init() must initialize the value."),
+        }
+    }
+}
+
+/// Multiply the big integer by a small power of the radix.
+fn mul_pow(x: &mut Bigint, radix: u32, exp: u32) {
+    for _ in 0..exp {
+        x.data.mul_small(radix as _).expect("Large powers must fit in a Bigint.");
+    }
+}
+
+// LARGE INT
+// ---------
+
+/// Get pre-computed power for a large power of radix.
+#[cfg(not(feature = "compact"))]
+pub fn get_large_int_power(radix: u32) -> (&'static [Limb], u32) {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const POWER: Lazy<Vec<Limb>> = Lazy::new();
+    static LARGE_POWERS: [Lazy<Vec<Limb>>; 37] = [POWER; 37];
+
+    let step = 5 * u64_power_limit(radix);
+    let power = LARGE_POWERS[radix as usize].get_or_init(|| {
+        let mut power = Bigint::from_u32(1);
+        mul_pow(&mut power, radix, step);
+        power.data.to_vec()
+    });
+    (power, step)
+}
+
+// BELLEROPHON
+// -----------
+
+/// Get Bellerophon powers from radix.
+#[cfg(any(feature = "compact", feature = "any-radix"))]
+pub fn bellerophon_powers(radix: u32) -> &'static BellerophonPowers {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const POWERS: Lazy<BellerophonPowers> = Lazy::new();
+    static BELLEROPHON_POWERS: [Lazy<BellerophonPowers>; 37] = [POWERS; 37];
+
+    if (3..=36).contains(&radix) && !radix.is_power_of_two() {
+        BELLEROPHON_POWERS[radix as usize].get_or_init(|| compute_powers(radix))
+    } else {
+        // Fallback if we have an invalid radix.
+        &BellerophonPowers {
+            small: &[],
+            large: &[],
+            small_int: &[],
+            step: 0,
+            bias: 0,
+            log2: 0,
+            log2_shift: 0,
+        }
+    }
+}
+
+/// Calculate the powers of the radix, as generated by `etc/bellerophon_table.py`.
+#[cfg(any(feature = "compact", feature = "any-radix"))]
+fn compute_powers(radix: u32) -> BellerophonPowers {
+    // The small powers are the powers up to `10^10`, with large powers
+    // of every `step` from the smallest denormal float, shifted by the
+    // mantissa bits, to the largest float.
+    let mut step = 0;
+    while (radix as u64).pow(step + 1) <= 10_000_000_000 {
+        step += 1;
+    }
+    let ln_radix = (radix as f64).ln();
+    let min_exp = ((5e-324_f64).ln() / ln_radix - (u64::MAX as f64).ln() / ln_radix).floor();
+    let max_exp = (f64::MAX.ln() / ln_radix).ceil() as u32;
+    let negative = (-min_exp / step as f64).ceil() as u32;
+    let positive = (max_exp - 1) / step;
+
+    let mut power = Bigint::from_u32(1);
+    let mut small = Vec::new();
+    let mut small_int = Vec::new();
+    for exp in 0..step {
+        small.push(power.hi64().0);
+        small_int.push((radix as u64).pow(exp));
+        mul_pow(&mut power, radix, 1);
+    }
+
+    // Calculate `2^(bits + 63) / radix^n` for negative powers, which
+    // is the normalized, truncated reciprocal since it's in `(2^63, 2^64)`.
+    let mut large = Vec::new();
+    let mut power = Bigint::from_u32(1);
+    for _ in 0..negative {
+        mul_pow(&mut power, radix, step);
+        let mut x = Bigint::from_u32(1);
+        shl(&mut x.data, power.bit_length() as usize + 63).expect("Must fit in a Bigint.");
+        large_div(&mut x.data, &power.data).expect("Divisor must be non-zero.");
+        large.push(x.hi64().0);
+    }
+    large.reverse();
+    let mut power = Bigint::from_u32(1);
+    large.push(power.hi64().0);
+    for _ in 0..positive {
+        mul_pow(&mut power, radix, step);
+        large.push(power.hi64().0);
+    }
+
+    BellerophonPowers {
+        small: small.leak(),
+        large: large.leak(),
+        small_int: small_int.leak(),
+        step: step as i32,
+        bias: (negative * step) as i32,
+        log2: ((radix as f64).log2() * (1u64 << 32) as f64).ceil() as i64,
+        log2_shift: 32,
+    }
+}
@@ -20,6 +20,7 @@ pub const N_POWERS_OF_FIVE: usize = (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_F
 // can have the entire table compiled multiple times, and then
 // emit code multiple times, even if it's stripped out in
 // the final binary.
+#[cfg(not(feature = "wasm"))]
 #[rustfmt::skip]
 pub static POWER_OF_FIVE_128: [(u64, u64); N_POWERS_OF_FIVE] = [
     (0xeef453d6923bd65a, 0x113faa2906a13b3f), // 5^-342
@@ -674,3 +675,99 @@ pub static POWER_OF_FIVE_128: [(u64, u64); N_POWERS_OF_FIVE] = [
     (0xe3d8f9e563a198e5, 0x58180fddd97723a6), // 5^307
     (0x8e679c2f5e44ff8f, 0x570f09eaa7ea7648), // 5^308
 ];
+
+/// The number of powers of five between each compressed power.
+#[cfg(feature = "wasm")]
+pub const POWER_OF_FIVE_STEP: usize = 27;
+
+/// Every 27th power of five, to recover the other powers.
+#[cfg(feature = "wasm")]
+#[rustfmt::skip]
+pub static COMPRESSED_POWER_OF_FIVE_128: [(u64, u64); 25] = [
+    (0xeef453d6923bd65a, 0x113faa2906a13b3f), // 5^-342
+    (0xc1069cd4eabe89f8, 0x999ec0bb696e840a), // 5^-315
+    (0x9becce62836ac577, 0x4ee367f9430aec32), // 5^-288
+    (0xfbe9141915d7a922, 0x4bf1ff9f0062baa8), // 5^-261
+    (0xcb7ddcdda26da268, 0xa9942f5dcf7dfd09), // 5^-234
+    (0xa46116538d0deb78, 0x52d9be85f074e608), // 5^-207
+    (0x84c8d4dfd2c63f3b, 0x29ecd9f40041e073), // 5^-180
+    (0xd686619ba27255a2, 0xc80a537b0efefebd), // 5^-153
+    (0xad4ab7112eb3929d, 0x86c16c98d2c953c6), // 5^-126
+    (0x8bfbea76c619ef36, 0x57eb4edb3c55b65a), // 5^-99
+    (0xe2280b6c20dd5232, 0x25c6da63c38de1b0), // 5^-72
+    (0xb6b00d69bb55c8d1, 0x3d607b97c5fd0d22), // 5^-45
+    (0x9392ee8e921d5d07, 0x3aff322e62439fd0), // 5^-18
+    (0xee6b280000000000, 0x0),                // 5^9
+    (0xc097ce7bc90715b3, 0x4b9f100000000000), // 5^36
+    (0x9b934c3b330c8577, 0x63cc55f49f88eb2f), // 5^63
+    (0xfb5878494ace3a5f, 0x4ab48a04065c723),  // 5^90
+    (0xcb090c8001ab551c, 0x5cadf5bfd3072cc5), // 5^117
+    (0xa402b9c5a8d3a6e7, 0x5f16206c9c6209a6), // 5^144
+    (0x847c9b5d7c2e09b7, 0x69956135febada11), // 5^171
+    (0xd60b3bd56a5586f1, 0x8a71e223d8d3b074), // 5^198
+    (0xace73cbfdc0bfb7b, 0x636cc64d1001550b), // 5^225
+    (0x8bab8eefb6409c1a, 0x1ad089b6c2f7548e), // 5^252
+    (0xe1a63853bbd26451, 0x5e7873f8a0396973), // 5^279
+    (0xb6472e511c81471d, 0xe0133fe4adf8e952), // 5^306
+];
+
+/// The small powers of five to recover the other powers.
+#[cfg(feature = "wasm")]
+#[rustfmt::skip]
+pub static POWER_OF_FIVE_64: [u64; POWER_OF_FIVE_STEP] = [
+    1,
+    5,
+    25,
+    125,
+    625,
+    3125,
+    15625,
+    78125,
+    390625,
+    1953125,
+    9765625,
+    48828125,
+    244140625,
+    1220703125,
+    6103515625,
+    30517578125,
+    152587890625,
+    762939453125,
+    3814697265625,
+    19073486328125,
+    95367431640625,
+    476837158203125,
+    2384185791015625,
+    11920928955078125,
+    59604644775390625,
+    298023223876953125,
+    1490116119384765625,
+];
+
+/// The difference between the exact and recovered powers, plus 1,
+/// packed into 2 bits for each power.
+#[cfg(feature = "wasm")]
+#[rustfmt::skip]
+pub static POWER_OF_FIVE_CORRECTION: [u64; 21] = [
+    0x6a5a65656a6aa995,
+    0xa69a95559a9a5a56,
+    0x9565555699a9aa9a,
+    0x565aa95a99a59656,
+    0xaaaaaa6aeba655a5,
+    0x9569a69a9a6a969a,
+    0x595599a5a56956aa,
+    0xa595aa5955555555,
+    0x55555555599aa9aa,
+    0xab96655655555555,
+    0x44105555555541fa,
+    0x5555555555555555,
+    0x99aaa55555555555,
+    0xaaaa69a9a9696656,
+    0x55556aa5595aaa5a,
+    0x5666655555955955,
+    0xa96aa96669a69a95,
+    0x9aaa5a5aaaa9a9aa,
+    0x5565565aa56aaa6a,
+    0xa969aaaaa5955696,
+    0x0000000000156aab,
+];
@@ -1,15 +1,16 @@
 //! Pre-computed tables for writing non-decimal strings.
 
-#![cfg(feature = "radix")]
+#![cfg(feature = "any-radix")]
 #![cfg(not(feature = "compact"))]
+#![cfg(not(feature = "build-tables"))]
 #![doc(hidden)]
 #![allow(clippy::excessive_precision)]
 
+#[cfg(not(feature = "lazy-tables"))]
 use crate::bigint::Limb;
 use crate::limits::{f32_exponent_limit, f64_exponent_limit, f64_mantissa_limit, u64_power_limit};
 use crate::table_binary::*;
 use crate::table_decimal::*;
-use core::hint;
 use lexical_util::assert::debug_assert_radix;
 use static_assertions::const_assert;
 
@@ -62,7 +63,7 @@ pub unsafe fn get_small_int_power(exponent: usize, radix: u32) -> u64 {
             34 => get_small_int_power34(exponent),
             35 => get_small_int_power35(exponent),
             36 => get_small_int_power36(exponent),
-            _ => hint::unreachable_unchecked(),
+            _ => unreachable_unchecked!(),
         }
     }
 }
@@ -113,7 +114,7 @@ pub unsafe fn get_small_f32_power(exponent: usize, radix: u32) -> f32 {
             34 => get_small_f32_power34(exponent),
             35 => get_small_f32_power35(exponent),
             36 => get_small_f32_power36(exponent),
-            _ => hint::unreachable_unchecked(),
+            _ => unreachable_unchecked!(),
         }
     }
 }
@@ -164,12 +165,13 @@ pub unsafe fn get_small_f64_power(exponent: usize, radix: u32) -> f64 {
             34 => get_small_f64_power34(exponent),
             35 => get_small_f64_power35(exponent),
             36 => get_small_f64_power36(exponent),
-            _ => hint::unreachable_unchecked(),
+            _ => unreachable_unchecked!(),
         }
     }
 }
 
 /// Get pre-computed power for a large power of radix.
+#[cfg(not(feature = "lazy-tables"))]
 pub const fn get_large_int_power(radix: u32) -> (&'static [Limb], u32) {
     match radix {
         3 => (&LARGE_POW3, LARGE_POW3_STEP),
@@ -1154,14 +1156,14 @@ pub const SMALL_F64_POW3: [f64; 34] = [
 const_assert!(SMALL_F64_POW3.len() > f64_exponent_limit(3).1 as usize);
 
 /// Pre-computed large power-of-3 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW3: [u32; 10] = [
     2868424865, 1543175966, 3836194338, 2213345014, 1148585654, 4252227966, 1995653935, 3256521594,
     1051739806, 534087228,
 ];
 
 /// Pre-computed large power-of-3 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW3: [u64; 5] = [
     6627890308811632801,
     9506244453730856482,
@@ -1356,14 +1358,14 @@ pub const SMALL_F64_POW7: [f64; 19] = [
 const_assert!(SMALL_F64_POW7.len() > f64_exponent_limit(7).1 as usize);
 
 /// Pre-computed large power-of-7 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW7: [u32; 10] = [
     3938635601, 4013708425, 513691597, 1762742544, 3619207677, 480247883, 3793395133, 740892944,
     1592317061, 1837154,
 ];
 
 /// Pre-computed large power-of-7 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW7: [u64; 5] = [
     17238746424993304401,
     7570921578261532621,
@@ -1429,14 +1431,14 @@ pub const SMALL_F64_POW9: [f64; 17] = [
 const_assert!(SMALL_F64_POW9.len() > f64_exponent_limit(9).1 as usize);
 
 /// Pre-computed large power-of-9 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW9: [u32; 10] = [
     2868424865, 1543175966, 3836194338, 2213345014, 1148585654, 4252227966, 1995653935, 3256521594,
     1051739806, 534087228,
 ];
 
 /// Pre-computed large power-of-9 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW9: [u64; 5] = [
     6627890308811632801,
     9506244453730856482,
@@ -1499,14 +1501,14 @@ pub const SMALL_F64_POW11: [f64; 16] = [
 const_assert!(SMALL_F64_POW11.len() > f64_exponent_limit(11).1 as usize);
 
 /// Pre-computed large power-of-11 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW11: [u32; 10] = [
     2172432537, 2346616081, 1851665372, 2301834192, 1763429507, 4086589879, 4002403721, 2932076170,
     987565374, 10683238,
 ];
 
 /// Pre-computed large power-of-11 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW11: [u64; 5] = [
     10078639326335119513,
     9886302577306250204,
@@ -1651,14 +1653,14 @@ pub const SMALL_F64_POW13: [f64; 15] = [
 const_assert!(SMALL_F64_POW13.len() > f64_exponent_limit(13).1 as usize);
 
 /// Pre-computed large power-of-13 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW13: [u32; 10] = [
     3146523293, 4222426932, 2977536293, 1295813598, 1909522258, 1606005718, 3366933208, 327990755,
     3779976816, 97397137,
 ];
 
 /// Pre-computed large power-of-13 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW13: [u64; 5] = [
     18135185585836139165,
     5565477028099627301,
@@ -1769,14 +1771,14 @@ pub const SMALL_F64_POW15: [f64; 14] = [
 const_assert!(SMALL_F64_POW15.len() > f64_exponent_limit(15).1 as usize);
 
 /// Pre-computed large power-of-15 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW15: [u32; 10] = [
     3507049217, 2300028134, 3886839708, 4190270956, 1622122702, 1947334599, 204338878, 3105278257,
     2490561006, 24584533,
 ];
 
 /// Pre-computed large power-of-15 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW15: [u64; 5] = [
     9878545618916954881,
     17997076721285494684,
@@ -1833,14 +1835,14 @@ pub const SMALL_F64_POW17: [f64; 13] = [
 const_assert!(SMALL_F64_POW17.len() > f64_exponent_limit(17).1 as usize);
 
 /// Pre-computed large power-of-17 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW17: [u32; 10] = [
     2990615473, 2810986799, 4066186761, 2554374905, 4073187723, 2831536001, 529177471, 3891721527,
     4211495815, 386393,
 ];
 
 /// Pre-computed large power-of-17 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW17: [u64; 5] = [
     12073096374183340977,
     10970956682764293641,
@@ -1946,14 +1948,14 @@ pub const SMALL_F64_POW19: [f64; 13] = [
 const_assert!(SMALL_F64_POW19.len() > f64_exponent_limit(19).1 as usize);
 
 /// Pre-computed large power-of-19 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW19: [u32; 10] = [
     844079147, 4109067463, 2265902219, 1405351247, 3107957240, 2205473157, 271286156, 2969717342,
     1924040718, 1621366965,
 ];
 
 /// Pre-computed large power-of-19 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW19: [u64; 5] = [
     17648310371486769195,
     6035937647523720331,
@@ -2074,14 +2076,14 @@ pub const SMALL_F64_POW21: [f64; 13] = [
 const_assert!(SMALL_F64_POW21.len() > f64_exponent_limit(21).1 as usize);
 
 /// Pre-computed large power-of-21 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW21: [u32; 10] = [
     138418921, 1265804130, 2218244279, 959999061, 1977606600, 816701562, 1115590038, 3476226057,
     1985711423, 722290,
 ];
 
 /// Pre-computed large power-of-21 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW21: [u64; 5] = [
     5436587341630151401,
     4123164573403953335,
@@ -2182,14 +2184,14 @@ pub const SMALL_F64_POW23: [f64; 12] = [
 const_assert!(SMALL_F64_POW23.len() > f64_exponent_limit(23).1 as usize);
 
 /// Pre-computed large power-of-23 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW23: [u32; 10] = [
     1403677489, 2801905613, 3028338484, 1469351396, 2741227823, 193620048, 1084942677, 2905110101,
     3742230796, 421026827,
 ];
 
 /// Pre-computed large power-of-23 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW23: [u64; 5] = [
     12034092975717509937,
     6310816195180283700,
@@ -2323,14 +2325,14 @@ pub const SMALL_F64_POW25: [f64; 12] = [
 const_assert!(SMALL_F64_POW25.len() > f64_exponent_limit(25).1 as usize);
 
 /// Pre-computed large power-of-25 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW25: [u32; 10] = [
     2358447641, 1624633829, 2031259829, 1986676888, 2941191183, 611941596, 1880507741, 990341507,
     3289036379, 14772,
 ];
 
 /// Pre-computed large power-of-25 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW25: [u64; 5] = [
     6977749165888704025,
     8532712263710314677,
@@ -2429,14 +2431,14 @@ pub const SMALL_F64_POW27: [f64; 12] = [
 const_assert!(SMALL_F64_POW27.len() > f64_exponent_limit(27).1 as usize);
 
 /// Pre-computed large power-of-27 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW27: [u32; 10] = [
     1249037595, 465894344, 2861423576, 2518924695, 4122946360, 4029669975, 3949684612, 3795800505,
     3556955416, 2197889,
 ];
 
 /// Pre-computed large power-of-27 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW27: [u64; 5] = [
     2001000972120411419,
     10818699188973198296,
@@ -2538,14 +2540,14 @@ pub const SMALL_F64_POW29: [f64; 11] = [
 const_assert!(SMALL_F64_POW29.len() > f64_exponent_limit(29).1 as usize);
 
 /// Pre-computed large power-of-29 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW29: [u32; 10] = [
     3437097245, 219578399, 3191687836, 3061529344, 4005823358, 3201416410, 694756510, 1988053185,
     463784885, 228681542,
 ];
 
 /// Pre-computed large power-of-29 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW29: [u64; 5] = [
     943082046050136349,
     13149168411416021660,
@@ -2641,14 +2643,14 @@ pub const SMALL_F64_POW31: [f64; 11] = [
 const_assert!(SMALL_F64_POW31.len() > f64_exponent_limit(31).1 as usize);
 
 /// Pre-computed large power-of-31 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW31: [u32; 10] = [
     3128270977, 627186439, 3737223222, 1519964902, 4275419645, 1305227997, 3310009113, 99290790,
     2685019127, 609,
 ];
 
 /// Pre-computed large power-of-31 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW31: [u64; 5] = [
     2693745247127969921,
     6528199548895068214,
@@ -2700,14 +2702,14 @@ pub const SMALL_F64_POW33: [f64; 11] = [
 const_assert!(SMALL_F64_POW33.len() > f64_exponent_limit(33).1 as usize);
 
 /// Pre-computed large power-of-33 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW33: [u32; 10] = [
     1612820353, 1081423072, 127566253, 3291061608, 3338225311, 2497994496, 2486573331, 4032720849,
     2585834285, 25953,
 ];
 
 /// Pre-computed large power-of-33 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW33: [u64; 5] = [
     4644676728992673665,
     14135001975608738221,
@@ -2800,14 +2802,14 @@ pub const SMALL_F64_POW35: [f64; 11] = [
 const_assert!(SMALL_F64_POW35.len() > f64_exponent_limit(35).1 as usize);
 
 /// Pre-computed large power-of-35 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
 pub const LARGE_POW35: [u32; 10] = [
     2481068081, 3589182317, 2073348182, 2214889340, 548239849, 1614245998, 4081052795, 291764764,
     3369344364, 886020,
 ];
 
 /// Pre-computed large power-of-35 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
 pub const LARGE_POW35: [u64; 5] = [
     15415420673377572913,
     9512877281632372822,
@@ -7,5 +7,5 @@
 #[cfg(feature = "power-of-two")]
 pub use crate::table_binary::*;
 pub use crate::table_decimal::*;
-#[cfg(feature = "radix")]
+#[cfg(all(feature = "any-radix", not(feature = "build-tables")))]
 pub use crate::table_radix::*;
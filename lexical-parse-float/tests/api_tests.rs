@@ -1,6 +1,21 @@
-#[cfg(feature = "format")]
+#[cfg(any(feature = "format", feature = "power-of-two"))]
 use core::num;
-use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_float::{
+    parse_narrow,
+    parse_narrow_with_options,
+    parse_partial_with_counts,
+    parse_partial_with_counts_with_options,
+    parse_partial_with_flags,
+    parse_partial_with_flags_with_options,
+    representation,
+    representation_with_options,
+    DigitCounts,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    ParseFlags,
+    Representation,
+};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::error::Error;
@@ -59,6 +74,37 @@ fn invalid_punctuation_test() {
     assert_eq!(res, Err(Error::InvalidPunctuation));
 }
 
+#[test]
+fn parentheses_negative_test() {
+    let options = Options::builder().parentheses_negative(true).build().unwrap();
+    assert_eq!(Ok(-1.5), f64::from_lexical_with_options::<STANDARD>(b"(1.5)", &options));
+    assert_eq!(Ok(1.5), f64::from_lexical_with_options::<STANDARD>(b"1.5", &options));
+    assert_eq!(Ok(-1e300), f64::from_lexical_with_options::<STANDARD>(b"(1e300)", &options));
+    assert_eq!(
+        Ok(f32::NEG_INFINITY),
+        f32::from_lexical_with_options::<STANDARD>(b"(inf)", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(1)),
+        f64::from_lexical_with_options::<STANDARD>(b"(-1.5)", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(4)),
+        f64::from_lexical_with_options::<STANDARD>(b"(1.5", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(5)),
+        f64::from_lexical_with_options::<STANDARD>(b"(1.5))", &options)
+    );
+    assert_eq!(
+        Ok((-1.5, 5)),
+        f64::from_lexical_partial_with_options::<STANDARD>(b"(1.5) ", &options)
+    );
+
+    let options = Options::new();
+    assert!(f64::from_lexical_with_options::<STANDARD>(b"(1.5)", &options).is_err());
+}
+
 #[test]
 fn f32_decimal_test() {
     // integer test
@@ -544,6 +590,45 @@ fn f64_radix_test() {
     assert_eq!(1234.0, f64::from_lexical_with_options::<FORMAT>(b"YA", &options).unwrap());
 }
 
+#[test]
+#[cfg(feature = "power-of-two")]
+fn f64_power_of_two_large_exponent_test() {
+    const OCTAL: u128 = NumberFormatBuilder::from_radix(8);
+    const BASE32: u128 = NumberFormatBuilder::from_radix(32);
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let parse = |x: &[u8]| f64::from_lexical_with_options::<OCTAL>(x, &options);
+    assert_eq!(parse(b"0.1^5300000000"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"0.1^-5300000000"), Ok(0.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<BASE32>(b"1^77777777777", &options),
+        Ok(f64::INFINITY)
+    );
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn f64_hex_binary_exponent_test() {
+    // Hexadecimal digits with a binary exponent. The exponent digits
+    // use the mantissa radix.
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(16))
+        .build();
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let parse = |x: &[u8]| f64::from_lexical_with_options::<HEX>(x, &options);
+    assert_eq!(parse(b"1.8p3"), Ok(12.0));
+    assert_eq!(parse(b"1p-2"), Ok(0.25));
+    assert_eq!(parse(b"-ff.8"), Ok(-255.5));
+    assert_eq!(parse(b"1.fffffffffffffp3ff"), Ok(f64::MAX));
+    assert_eq!(parse(b"1p-432"), Ok(f64::from_bits(1)));
+    assert_eq!(parse(b"0.0p10"), Ok(0.0));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<HEX>(b"1.8p3 ", &options),
+        Ok((12.0, 5))
+    );
+}
+
 #[test]
 fn parse_f64_large_zeros_test() {
     // Test numbers with a massive number of 0s in the integer component.
@@ -846,6 +931,7 @@ fn f64_required_mantissa_sign_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+3.0", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"-3.0", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"3.0", &options).is_err());
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"+3.0e7", &options), Ok(3.0e7));
 }
 
 #[test]
@@ -932,6 +1018,35 @@ fn f64_no_leading_zeros_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"010.0", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_no_integer_leading_zeros_test() {
+    const INTEGER: u128 = rebuild(format::PERMISSIVE).no_integer_leading_zeros(true).build();
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_options::<INTEGER>(b"0", &options), Ok(0.0));
+    assert_eq!(f64::from_lexical_with_options::<INTEGER>(b"01.5", &options), Ok(1.5));
+    assert_eq!(f64::from_lexical_with_options::<INTEGER>(b"01e1", &options), Ok(10.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<INTEGER>(b"01", &options),
+        Err(Error::InvalidLeadingZeros(0))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<INTEGER>(b"-00", &options),
+        Err(Error::InvalidLeadingZeros(1))
+    );
+
+    const FLOAT: u128 = rebuild(format::PERMISSIVE).no_float_leading_zeros(true).build();
+    assert_eq!(f64::from_lexical_with_options::<FLOAT>(b"01", &options), Ok(1.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<FLOAT>(b"01.5", &options),
+        Err(Error::InvalidLeadingZeros(0))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FLOAT>(b"01e1", &options),
+        Err(Error::InvalidLeadingZeros(0))
+    );
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn f64_required_exponent_notation_test() {
@@ -1150,6 +1265,67 @@ fn f64_json_no_leading_zero() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"-012.0", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_postgresql_test() {
+    use lexical_parse_float::options;
+
+    const LITERAL: u128 = format::POSTGRESQL16_LITERAL;
+    let options = options::POSTGRESQL;
+    assert_eq!(f64::from_lexical_with_options::<LITERAL>(b"1_000.000_1", &options), Ok(1000.0001));
+    assert_eq!(f64::from_lexical_with_options::<LITERAL>(b".5e1_0", &options), Ok(0.5e10));
+    assert!(f64::from_lexical_with_options::<LITERAL>(b"1000_", &options).is_err());
+    assert!(f64::from_lexical_with_options::<LITERAL>(b"_1000", &options).is_err());
+    assert!(f64::from_lexical_with_options::<LITERAL>(b"Infinity", &options).is_err());
+
+    const STRING: u128 = format::POSTGRESQL_STRING;
+    let options = options::POSTGRESQL_STRING;
+    assert_eq!(f64::from_lexical_with_options::<STRING>(b"1.5", &options), Ok(1.5));
+    assert_eq!(f64::from_lexical_with_options::<STRING>(b"Infinity", &options), Ok(f64::INFINITY));
+    assert_eq!(
+        f64::from_lexical_with_options::<STRING>(b"-infinity", &options),
+        Ok(f64::NEG_INFINITY)
+    );
+    assert_eq!(f64::from_lexical_with_options::<STRING>(b"inf", &options), Ok(f64::INFINITY));
+    assert!(f64::from_lexical_with_options::<STRING>(b"NaN", &options).unwrap().is_nan());
+    assert!(f64::from_lexical_with_options::<STRING>(b"1_000", &options).is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_r_literal_test() {
+    use lexical_parse_float::options;
+
+    const FORMAT: u128 = format::R_LITERAL;
+    let options = options::R_LITERAL;
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1e5L", &options), Ok(1e5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1.5L", &options), Ok(1.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1.5", &options), Ok(1.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"Inf", &options), Ok(f64::INFINITY));
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"NaN", &options).unwrap().is_nan());
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"1.5l", &options).is_err());
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"inf", &options).is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_matlab_literal_test() {
+    use lexical_parse_float::options;
+
+    const FORMAT: u128 = format::MATLAB_LITERAL;
+    let options = options::MATLAB_LITERAL;
+    assert_eq!(f64::from_lexical_partial_with_options::<FORMAT>(b"1i", &options), Ok((1.0, 1)));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"2.5e3j", &options),
+        Ok((2500.0, 5))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"1i", &options),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"1_000", &options), Ok(1000.0));
+}
+
 #[test]
 #[cfg(all(feature = "power-of-two", feature = "format"))]
 fn base_prefix_test() {
@@ -1163,6 +1339,9 @@ fn base_prefix_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+0x3.0e+300", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+0x3.0e+300", &options).is_ok());
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+0x3.0e+300 ", &options).is_err());
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"0", &options), Ok(0.0));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"0e1", &options), Ok(0.0));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"05", &options), Ok(5.0));
 
     assert!(f64::from_lexical_partial_with_options::<FORMAT>(b"+0x", &options).is_err());
     assert!(f64::from_lexical_partial_with_options::<FORMAT>(b"+0x ", &options).is_err());
@@ -1214,6 +1393,56 @@ fn base_prefix_and_suffix_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+0x3.0e+300h ", &options).is_err());
 }
 
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn required_base_prefix_and_suffix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .base_suffix(num::NonZeroU8::new(b'h'))
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .build();
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"0x3.5h", &options), Ok(3.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"-0X3.5H", &options), Ok(-3.5));
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b"0x3.0e+3h", &options), Ok(3000.0));
+    assert!(f64::from_lexical_with_options::<FORMAT>(b"NaN", &options).unwrap().is_nan());
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"3.5h", &options),
+        Err(Error::MissingBasePrefix(0))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"-3.5h", &options),
+        Err(Error::MissingBasePrefix(1))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"0x3.5", &options),
+        Err(Error::MissingBaseSuffix(5))
+    );
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"0x3.5 ", &options),
+        Err(Error::MissingBaseSuffix(5))
+    );
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"0x3.5h ", &options),
+        Ok((3.5, 6))
+    );
+
+    const CASE_SENSITIVE: u128 = NumberFormatBuilder::rebuild(FORMAT)
+        .case_sensitive_base_prefix(true)
+        .case_sensitive_base_suffix(true)
+        .build();
+    assert_eq!(f64::from_lexical_with_options::<CASE_SENSITIVE>(b"0x3.5h", &options), Ok(3.5));
+    assert_eq!(
+        f64::from_lexical_with_options::<CASE_SENSITIVE>(b"0X3.5h", &options),
+        Err(Error::MissingBasePrefix(0))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<CASE_SENSITIVE>(b"0x3.5H", &options),
+        Err(Error::MissingBaseSuffix(5))
+    );
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn issue66_test() {
@@ -1250,6 +1479,230 @@ fn float_equal<F: Float>(x: F, y: F) -> bool {
     }
 }
 
+#[test]
+fn parse_narrow_test() {
+    assert_eq!(parse_narrow::<f32>(b"1.5"), Ok(1.5));
+    assert_eq!(parse_narrow::<f32>(b"-0.0"), Ok(-0.0));
+    assert_eq!(parse_narrow::<f32>(b"16777216"), Ok(16777216.0));
+    assert_eq!(parse_narrow::<f32>(b"inf"), Ok(f32::INFINITY));
+    assert!(parse_narrow::<f32>(b"NaN").unwrap().is_nan());
+    assert_eq!(parse_narrow::<f32>(b"3.4028234663852886e38"), Ok(f32::MAX));
+    assert_eq!(parse_narrow::<f32>(b"1e-45"), Err(Error::InexactNarrowing(0)));
+    assert_eq!(parse_narrow::<f32>(b"1.401298464324817e-45"), Ok(1e-45));
+
+    // These would be silently rounded twice when parsing as `f64` and casting.
+    assert_eq!(parse_narrow::<f32>(b"0.1"), Err(Error::InexactNarrowing(0)));
+    assert_eq!(parse_narrow::<f32>(b"16777217"), Err(Error::InexactNarrowing(0)));
+    assert_eq!(parse_narrow::<f32>(b"1e39"), Err(Error::InexactNarrowing(0)));
+    assert_eq!(parse_narrow::<f32>(b"1e-50"), Err(Error::InexactNarrowing(0)));
+
+    // Parse errors take precedence.
+    assert_eq!(parse_narrow::<f32>(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_narrow::<f32>(b""), Err(Error::Empty(0)));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(parse_narrow_with_options::<f32, STANDARD>(b"0,25", &options), Ok(0.25));
+
+    #[cfg(feature = "f16")]
+    {
+        assert_eq!(parse_narrow::<f16>(b"1.5"), Ok(f16::from_f32(1.5)));
+        assert_eq!(parse_narrow::<f16>(b"2049"), Err(Error::InexactNarrowing(0)));
+        assert_eq!(parse_narrow::<bf16>(b"257"), Err(Error::InexactNarrowing(0)));
+        assert_eq!(parse_narrow::<bf16>(b"256"), Ok(bf16::from_f32(256.0)));
+    }
+}
+
+#[test]
+fn representation_test() {
+    assert_eq!(representation::<f64>(b"0.5"), Ok(Representation::Exact));
+    assert_eq!(representation::<f64>(b"-0.0"), Ok(Representation::Exact));
+    assert_eq!(representation::<f64>(b"0e-5000"), Ok(Representation::Exact));
+    assert_eq!(representation::<f64>(b"inf"), Ok(Representation::Exact));
+    assert_eq!(representation::<f64>(b"NaN"), Ok(Representation::Exact));
+    assert_eq!(representation::<f64>(b"9007199254740992"), Ok(Representation::Exact));
+    assert_eq!(representation::<f64>(b"9007199254740993"), Ok(Representation::Halfway));
+    assert_eq!(representation::<f64>(b"-9007199254740995"), Ok(Representation::Halfway));
+    assert_eq!(
+        representation::<f64>(b"9007199254740993.00000000000000000000000001"),
+        Ok(Representation::Inexact)
+    );
+    assert_eq!(representation::<f64>(b"0.1"), Ok(Representation::Inexact));
+    assert_eq!(representation::<f64>(b"1e400"), Ok(Representation::Inexact));
+    assert_eq!(representation::<f64>(b"1e-400"), Ok(Representation::Inexact));
+    assert_eq!(representation::<f64>(b"1e-5000"), Ok(Representation::Inexact));
+    assert_eq!(representation::<f32>(b"16777216"), Ok(Representation::Exact));
+    assert_eq!(representation::<f32>(b"16777217"), Ok(Representation::Halfway));
+    assert_eq!(representation::<f32>(b"16777217.5"), Ok(Representation::Inexact));
+
+    // The largest float, and the halfway point to the smallest denormal float.
+    let max = format!("{:.0}", f64::MAX);
+    assert_eq!(representation::<f64>(max.as_bytes()), Ok(Representation::Exact));
+    let halfway = b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324";
+    assert_eq!(representation::<f64>(halfway), Ok(Representation::Halfway));
+
+    assert_eq!(representation::<f64>(b""), Err(Error::Empty(0)));
+    assert_eq!(representation::<f64>(b"1.5x"), Err(Error::InvalidDigit(3)));
+    #[cfg(feature = "f16")]
+    assert_eq!(representation::<f16>(b"1.5"), Err(Error::InvalidFloatParseAlgorithm));
+
+    // Lossy options do not affect the representation.
+    let options = Options::builder().lossy(true).build().unwrap();
+    assert_eq!(
+        representation_with_options::<f64, STANDARD>(b"9007199254740993", &options),
+        Ok(Representation::Halfway)
+    );
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn representation_radix_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options = Options::new();
+    assert_eq!(
+        representation_with_options::<f64, BINARY>(b"1.1", &options),
+        Err(Error::InvalidMantissaRadix)
+    );
+
+    #[cfg(feature = "radix")]
+    {
+        const BASE12: u128 = NumberFormatBuilder::from_radix(12);
+        let options = Options::builder().exponent(b'^').build().unwrap();
+        assert_eq!(
+            representation_with_options::<f64, BASE12>(b"0.6", &options),
+            Ok(Representation::Exact)
+        );
+        assert_eq!(
+            representation_with_options::<f64, BASE12>(b"0.1", &options),
+            Ok(Representation::Inexact)
+        );
+    }
+}
+
+fn counts(integer: usize, fraction: usize, exponent: usize, separators: usize) -> DigitCounts {
+    DigitCounts {
+        integer,
+        fraction,
+        exponent,
+        separators,
+    }
+}
+
+#[test]
+fn parse_partial_with_counts_test() {
+    assert_eq!(parse_partial_with_counts::<f64>(b"1"), Ok((1.0, 1, counts(1, 0, 0, 0))));
+    assert_eq!(
+        parse_partial_with_counts::<f64>(b"-12.345e+67x"),
+        Ok((-12.345e67, 11, counts(2, 3, 2, 0)))
+    );
+    assert_eq!(parse_partial_with_counts::<f64>(b".5"), Ok((0.5, 2, counts(0, 1, 0, 0))));
+    assert_eq!(parse_partial_with_counts::<f32>(b"007."), Ok((7.0, 4, counts(3, 0, 0, 0))));
+    assert_eq!(
+        parse_partial_with_counts::<f64>(b"9007199254740993.00000000000000000000000001"),
+        Ok((9007199254740994.0, 43, counts(16, 26, 0, 0)))
+    );
+    assert_eq!(
+        parse_partial_with_counts::<f64>(b"inf"),
+        Ok((f64::INFINITY, 3, counts(0, 0, 0, 0)))
+    );
+    assert_eq!(parse_partial_with_counts::<f64>(b"x"), Err(Error::EmptyMantissa(0)));
+
+    let options = Options::builder().parentheses_negative(true).build().unwrap();
+    assert_eq!(
+        parse_partial_with_counts_with_options::<f64, STANDARD>(b"(1.25e2)", &options),
+        Ok((-125.0, 8, counts(1, 2, 1, 0)))
+    );
+    #[cfg(feature = "f16")]
+    assert_eq!(
+        parse_partial_with_counts::<f16>(b"1.5"),
+        Ok((f16::from_f32(1.5), 3, counts(1, 1, 0, 0)))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_partial_with_counts_separator_test() {
+    const FORMAT: u128 = rebuild(format::PERMISSIVE)
+        .internal_digit_separator(true)
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .build();
+    let options = Options::new();
+    assert_eq!(
+        parse_partial_with_counts_with_options::<f64, FORMAT>(b"1_000.2_5e1_0", &options),
+        Ok((1000.25e10, 13, counts(4, 2, 2, 3)))
+    );
+}
+
+fn flags(exponent: bool, fraction: bool, separators: bool, base_prefix: bool) -> ParseFlags {
+    ParseFlags {
+        has_exponent: exponent,
+        has_fraction: fraction,
+        has_separators: separators,
+        has_base_prefix: base_prefix,
+        is_special: false,
+    }
+}
+
+#[test]
+fn parse_partial_with_flags_test() {
+    assert_eq!(
+        parse_partial_with_flags::<f64>(b"1"),
+        Ok((1.0, 1, flags(false, false, false, false)))
+    );
+    assert_eq!(
+        parse_partial_with_flags::<f64>(b"-12.345e+67x"),
+        Ok((-12.345e67, 11, flags(true, true, false, false)))
+    );
+    assert_eq!(
+        parse_partial_with_flags::<f32>(b"7."),
+        Ok((7.0, 2, flags(false, true, false, false)))
+    );
+    assert_eq!(
+        parse_partial_with_flags::<f64>(b"1e5"),
+        Ok((1e5, 3, flags(true, false, false, false)))
+    );
+    assert_eq!(
+        parse_partial_with_flags::<f64>(b"9007199254740993.00000000000000000000000001"),
+        Ok((9007199254740994.0, 43, flags(false, true, false, false)))
+    );
+
+    let special = ParseFlags {
+        is_special: true,
+        ..ParseFlags::default()
+    };
+    assert_eq!(parse_partial_with_flags::<f64>(b"-inf"), Ok((f64::NEG_INFINITY, 4, special)));
+    assert_eq!(parse_partial_with_flags::<f64>(b"x"), Err(Error::EmptyMantissa(0)));
+
+    let options = Options::builder().parentheses_negative(true).build().unwrap();
+    assert_eq!(
+        parse_partial_with_flags_with_options::<f64, STANDARD>(b"(1.25e2)", &options),
+        Ok((-125.0, 8, flags(true, true, false, false)))
+    );
+    #[cfg(feature = "f16")]
+    assert_eq!(
+        parse_partial_with_flags::<bf16>(b"1.5"),
+        Ok((bf16::from_f32(1.5), 3, flags(false, true, false, false)))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn parse_partial_with_flags_format_test() {
+    const FORMAT: u128 = rebuild(format::PERMISSIVE)
+        .internal_digit_separator(true)
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build();
+    let options = Options::new();
+    assert_eq!(
+        parse_partial_with_flags_with_options::<f64, FORMAT>(b"1_000.25", &options),
+        Ok((1000.25, 8, flags(false, true, true, false)))
+    );
+    assert_eq!(
+        parse_partial_with_flags_with_options::<f64, FORMAT>(b"0x12e1", &options),
+        Ok((12e1, 6, flags(true, false, false, true)))
+    );
+}
+
 quickcheck! {
     #[cfg_attr(miri, ignore)]
     fn f32_roundtrip_quickcheck(x: f32) -> bool {
@@ -1290,6 +1743,20 @@ quickcheck! {
         actual.map_or(false, |y| expected.map_or(false, |x| float_equal(x, y)))
     }
 
+    #[cfg_attr(miri, ignore)]
+    fn f32_representation_quickcheck(x: f32) -> bool {
+        let x = x.abs();
+        let y = Float::next_up(x);
+        if x.is_nan() || y.is_inf() {
+            return true;
+        }
+        // Halfway points between two floats are exactly representable as an `f64`.
+        let exact = format!("{:.1000e}", x as f64);
+        let halfway = format!("{:.1000e}", (x as f64 + y as f64) / 2.0);
+        representation::<f32>(exact.as_bytes()) == Ok(Representation::Exact)
+            && representation::<f32>(halfway.as_bytes()) == Ok(Representation::Halfway)
+    }
+
     #[cfg_attr(miri, ignore)]
     fn f64_roundtrip_quickcheck(x: f64) -> bool {
         let string = x.to_string();
@@ -86,13 +86,7 @@ fn halfway_round_up_test() {
 
     // Add a 0 but say we're truncated.
     bellerophon_test::<f64, { STANDARD }>(9007199254740994000, -3, true, 1, 1076);
-    bellerophon_test::<f64, { STANDARD }>(
-        9007199254740994990,
-        -3,
-        true,
-        9223372036854778869,
-        1065 + INVALID_FP,
-    );
+    bellerophon_test::<f64, { STANDARD }>(9007199254740994990, -3, true, 1, 1076);
     bellerophon_test::<f64, { STANDARD }>(
         9007199254740995000,
         -3,
@@ -100,13 +94,7 @@ fn halfway_round_up_test() {
         9223372036854778879,
         1065 + INVALID_FP,
     );
-    bellerophon_test::<f64, { STANDARD }>(
-        9007199254740995010,
-        -3,
-        true,
-        9223372036854778890,
-        1065 + INVALID_FP,
-    );
+    bellerophon_test::<f64, { STANDARD }>(9007199254740995010, -3, true, 2, 1076);
     bellerophon_test::<f64, { STANDARD }>(9007199254740995050, -3, true, 2, 1076);
     bellerophon_test::<f64, { STANDARD }>(9007199254740996000, -3, true, 2, 1076);
 }
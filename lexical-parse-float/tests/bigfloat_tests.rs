@@ -61,3 +61,15 @@ fn leading_zeros_test() {
     assert_eq!(Bigfloat::from_u32(0xF0).leading_zeros(), LIMB_BITS as u32 - 8);
     assert_eq!(Bigfloat::from_u64(0xF000000000).leading_zeros(), 24);
 }
+
+#[test]
+fn format_test() {
+    let x = Bigfloat::from_float(ExtendedFloat80 {
+        mant: 0xABC,
+        exp: -3,
+    });
+    assert_eq!(format!("{:x}", x), "abcp-3");
+    assert_eq!(format!("{:#X}", x), "0xABCP-3");
+    assert_eq!(format!("{:?}", x), "Bigfloat { data: [2748], exp: -3 }");
+    assert_eq!(format!("{:x}", Bigfloat::new()), "0p0");
+}
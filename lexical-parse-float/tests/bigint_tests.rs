@@ -1,6 +1,7 @@
 mod stackvec;
 
 use lexical_parse_float::bigint::Bigint;
+use lexical_parse_float::MAX_SLOW_PATH_STACK_BYTES;
 use stackvec::vec_from_u32;
 
 #[test]
@@ -24,3 +25,38 @@ fn simple_test() {
     let expected = vec_from_u32(&[2755359744, 11]);
     assert!(x.data == expected, "failed");
 }
+
+#[test]
+fn max_slow_path_stack_bytes_test() {
+    let bigint = core::mem::size_of::<Bigint>();
+    assert_eq!(MAX_SLOW_PATH_STACK_BYTES, 6 * bigint);
+    let bits = if cfg!(feature = "radix") {
+        6000
+    } else {
+        4000
+    };
+    assert!(bigint >= bits / 8);
+}
+
+#[test]
+fn format_test() {
+    let mut x = Bigint::from_u64(0xABC);
+    assert_eq!(format!("{:x}", x), "abc");
+    assert_eq!(format!("{:#X}", x), "0xABC");
+    assert_eq!(format!("{:?}", x), "Bigint { data: [2748] }");
+
+    x.pow(2, 80).unwrap();
+    assert_eq!(format!("{:x}", x), "abc00000000000000000000");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn pow2_test() {
+    // Powers of two are applied as shifts, without a table of large powers.
+    for &(radix, shift) in [(2, 1), (4, 2), (8, 3), (16, 4), (32, 5)].iter() {
+        let mut x = Bigint::from_u32(3);
+        x.pow(radix, 300).unwrap();
+        assert_eq!(x.bit_length(), 2 + 300 * shift);
+        assert_eq!(x.hi64(), (3 << 62, false));
+    }
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "build-tables")]
+
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+use lexical_util::format::{Format, NumberFormatBuilder};
+
+const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+const BASE7: u128 = NumberFormatBuilder::from_radix(7);
+const BASE10: u128 = NumberFormatBuilder::from_radix(10);
+const BASE12: u128 = NumberFormatBuilder::from_radix(12);
+const BASE16: u128 = NumberFormatBuilder::from_radix(16);
+const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+const BASE52: u128 = NumberFormatBuilder::from_radix(52);
+const BASE62: u128 = NumberFormatBuilder::from_radix(62);
+
+/// The radixes tested with an enabled `radix-N` feature.
+const FEATURES: [(u32, bool); 4] = [
+    (3, cfg!(feature = "radix-3")),
+    (7, cfg!(feature = "radix-7")),
+    (12, cfg!(feature = "radix-12")),
+    (36, cfg!(feature = "radix-36")),
+];
+
+/// Determine if tables were generated for the radix at build time.
+///
+/// This assumes no other `radix-N` features are enabled. Radixes above
+/// 36 are only generated if listed in `LEXICAL_PARSE_FLOAT_RADIXES`.
+fn is_listed(radix: u32) -> bool {
+    let radixes = option_env!("LEXICAL_PARSE_FLOAT_RADIXES").unwrap_or("");
+    let is_env = radixes.split(',').any(|x| x.trim().parse() == Ok(radix));
+    if radix > 36 {
+        return is_env;
+    }
+    let is_feature = FEATURES.iter().any(|&(x, enabled)| x == radix && enabled);
+    let is_empty = radixes.trim().is_empty() && !FEATURES.iter().any(|&(_, enabled)| enabled);
+    if cfg!(feature = "radix") || is_empty {
+        true
+    } else {
+        radix == 10 || radix.is_power_of_two() || is_env || is_feature
+    }
+}
+
+fn check<const FORMAT: u128>(radix: u32, string: &[u8], expected: f64) {
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let result = f64::from_lexical_with_options::<FORMAT>(string, &options);
+    let runtime = Format::new(FORMAT).unwrap();
+    let runtime = f64::from_lexical_with_format(string, &runtime, &options);
+    if is_listed(radix) {
+        assert_eq!(result, Ok(expected));
+        assert_eq!(runtime, Ok(expected));
+    } else {
+        assert_eq!(result, Err(Error::InvalidMantissaRadix));
+        assert_eq!(runtime, Err(Error::InvalidMantissaRadix));
+    }
+}
+
+#[test]
+fn build_tables_test() {
+    check::<BASE3>(3, b"12.1", 5.333333333333333);
+    check::<BASE7>(7, b"1^-1000", 1.3532320500776432e-290);
+    check::<BASE10>(10, b"1.5^300", 1.5e300);
+    check::<BASE12>(12, b"B.6^100", 2.9026673722061283e156);
+    check::<BASE16>(16, b"A.8", 10.5);
+    check::<BASE36>(36, b"Z.I", 35.5);
+    check::<BASE52>(52, b"P.q", 51.5);
+    check::<BASE52>(52, b"aA.Kq^-2c", 4.890855785224125e-197);
+    check::<BASE62>(62, b"Zz.V", 3817.9193548387098);
+    check::<BASE62>(62, b"1^-9", 7.387113885860586e-17);
+    check::<BASE62>(62, b"Zaz^A", 7.896333617233197e+69);
+}
@@ -0,0 +1,124 @@
+use lexical_parse_float::constant::{parse_f32_bits, parse_f64_bits};
+use lexical_parse_float::{const_f32, const_f64, Error, FromLexical};
+use proptest::prelude::*;
+
+/// Check the const parsers match the runtime parsers, if supported.
+fn check_parse(bytes: &[u8]) {
+    match (parse_f64_bits(bytes), f64::from_lexical(bytes)) {
+        (Ok(bits), Ok(float)) => assert_eq!(bits, float.to_bits(), "{:?}", bytes),
+        (Err(Error::RequiresSlowPath(0)), Ok(_)) => (),
+        (actual, expected) => assert_eq!(actual.map(f64::from_bits), expected, "{:?}", bytes),
+    }
+    match (parse_f32_bits(bytes), f32::from_lexical(bytes)) {
+        (Ok(bits), Ok(float)) => assert_eq!(bits, float.to_bits(), "{:?}", bytes),
+        (Err(Error::RequiresSlowPath(0)), Ok(_)) => (),
+        (actual, expected) => assert_eq!(actual.map(f32::from_bits), expected, "{:?}", bytes),
+    }
+}
+
+#[test]
+fn parse_f64_bits_test() {
+    assert_eq!(parse_f64_bits(b"0"), Ok(0));
+    assert_eq!(parse_f64_bits(b"-0.000e999999999999"), Ok((-0.0f64).to_bits()));
+    assert_eq!(parse_f64_bits(b"1"), Ok(1.0f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"+1.5"), Ok(1.5f64.to_bits()));
+    assert_eq!(parse_f64_bits(b".5"), Ok(0.5f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"5."), Ok(5.0f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"0.1"), Ok(0.1f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"1e23"), Ok(1e23f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"1.7976931348623157e308"), Err(Error::RequiresSlowPath(0)));
+    assert_eq!(parse_f64_bits(b"9007199254740993"), Ok(9007199254740992f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"9007199254740995"), Ok(9007199254740996f64.to_bits()));
+    let bits = 1.2345678901234568e23f64.to_bits();
+    assert_eq!(parse_f64_bits(b"123456789012345678900000"), Ok(bits));
+    assert_eq!(parse_f64_bits(b"0.000000000000000000000000001"), Ok(1e-27f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"18446744073709551615"), Ok(18446744073709551616f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"18446744073709551616"), Err(Error::RequiresSlowPath(0)));
+    assert_eq!(parse_f64_bits(b"1e-54"), Ok(1e-54f64.to_bits()));
+    assert_eq!(parse_f64_bits(b"1e-56"), Err(Error::RequiresSlowPath(0)));
+    assert_eq!(parse_f64_bits(b"1e56"), Err(Error::RequiresSlowPath(0)));
+
+    assert_eq!(parse_f64_bits(b"inf"), Ok(f64::INFINITY.to_bits()));
+    assert_eq!(parse_f64_bits(b"-Infinity"), Ok(f64::NEG_INFINITY.to_bits()));
+    assert!(f64::from_bits(parse_f64_bits(b"NaN").unwrap()).is_nan());
+
+    assert_eq!(parse_f64_bits(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_f64_bits(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse_f64_bits(b"."), Err(Error::EmptyMantissa(1)));
+    assert_eq!(parse_f64_bits(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse_f64_bits(b"1e+"), Err(Error::EmptyExponent(3)));
+    assert_eq!(parse_f64_bits(b"1.2.3"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_f64_bits(b"1e5 "), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn parse_f32_bits_test() {
+    assert_eq!(parse_f32_bits(b"0.1"), Ok(0.1f32.to_bits()));
+    assert_eq!(parse_f32_bits(b"16777217"), Ok(16777216f32.to_bits()));
+    assert_eq!(parse_f32_bits(b"3.4028235e38"), Ok(f32::MAX.to_bits()));
+    assert_eq!(parse_f32_bits(b"1e39"), Ok(f32::INFINITY.to_bits()));
+    assert_eq!(parse_f32_bits(b"1.17549435e-38"), Ok(f32::MIN_POSITIVE.to_bits()));
+    assert_eq!(parse_f32_bits(b"1e-45"), Ok(1e-45f32.to_bits()));
+    assert_eq!(parse_f32_bits(b"8e-46"), Ok(1e-45f32.to_bits()));
+    assert_eq!(parse_f32_bits(b"7e-46"), Ok(0));
+    assert_eq!(parse_f32_bits(b"1e-50"), Ok(0));
+    assert_eq!(parse_f32_bits(b"-1e-54"), Ok((-0.0f32).to_bits()));
+    assert_eq!(parse_f32_bits(b"1e-56"), Err(Error::RequiresSlowPath(0)));
+    assert_eq!(parse_f32_bits(b"-INF"), Ok(f32::NEG_INFINITY.to_bits()));
+}
+
+#[test]
+fn parse_bits_runtime_test() {
+    let cases: &[&[u8]] = &[
+        b"",
+        b"+",
+        b"-.",
+        b"e5",
+        b".e5",
+        b"1.e5",
+        b"0.1e-5",
+        b"00001.00000",
+        b"1e-5x",
+        b"1x",
+        b"1.5e+22",
+        b"5e-324",
+        b"2.2250738585072014e-308",
+        b"4.9406564584124654e-324",
+        b"nan",
+        b"-nan",
+        b"infinit",
+        b"infinityx",
+        b"1.401298464324817e-45",
+        b"0.000000000000000000000000000000000000000000001",
+        b"12345678901234567890e-30",
+    ];
+    for bytes in cases {
+        check_parse(bytes);
+    }
+}
+
+#[test]
+fn const_macros_test() {
+    const HALF: f64 = const_f64!("0.5");
+    const DIGITS: &str = "-1.25e-3";
+    static TABLE: [f32; 3] = [const_f32!("1"), const_f32!(DIGITS), const_f32!("inf")];
+
+    assert_eq!(HALF, 0.5);
+    assert_eq!(const_f64!(DIGITS), -1.25e-3);
+    assert_eq!(TABLE, [1.0, -1.25e-3, f32::INFINITY]);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_bits_proptest(i in r"[+-]?[0-9]{0,20}(\.[0-9]{0,20})?([eE][+-]?[0-9]{1,2})?") {
+        check_parse(i.as_bytes());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn parse_bits_roundtrip_proptest(x in any::<f64>()) {
+        check_parse(format!("{:e}", x).as_bytes());
+        check_parse(format!("{}", x as f32).as_bytes());
+    }
+}
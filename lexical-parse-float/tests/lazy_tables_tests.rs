@@ -0,0 +1,115 @@
+#![cfg(feature = "lazy-tables")]
+
+#[cfg(any(feature = "compact", feature = "radix"))]
+use lexical_parse_float::bellerophon::BellerophonPowers;
+use lexical_parse_float::table;
+
+#[cfg(any(feature = "compact", feature = "radix"))]
+fn check_powers(radix: u32, expected: &BellerophonPowers) {
+    let powers = table::bellerophon_powers(radix);
+    assert_eq!(powers.step, expected.step);
+    assert_eq!(powers.bias, expected.bias);
+    assert_eq!(powers.small, expected.small);
+    assert_eq!(powers.large, expected.large);
+    assert_eq!(powers.small_int, expected.small_int);
+    for index in 0..powers.small.len() {
+        assert_eq!(powers.get_small(index), expected.get_small(index));
+    }
+    for index in 0..powers.large.len() {
+        assert_eq!(powers.get_large(index), expected.get_large(index));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "compact"))]
+fn large_int_power_test() {
+    let expected = (&table::LARGE_POW5[..], table::LARGE_POW5_STEP);
+    assert_eq!(table::get_large_int_power(5), expected);
+
+    #[cfg(feature = "radix")]
+    {
+        macro_rules! check {
+            ($($radix:literal => $power:ident, $step:ident ;)*) => ($(
+                assert_eq!(table::get_large_int_power($radix), (&table::$power[..], table::$step));
+            )*);
+        }
+        check! {
+            3 => LARGE_POW3, LARGE_POW3_STEP;
+            7 => LARGE_POW7, LARGE_POW7_STEP;
+            9 => LARGE_POW9, LARGE_POW9_STEP;
+            11 => LARGE_POW11, LARGE_POW11_STEP;
+            13 => LARGE_POW13, LARGE_POW13_STEP;
+            15 => LARGE_POW15, LARGE_POW15_STEP;
+            17 => LARGE_POW17, LARGE_POW17_STEP;
+            19 => LARGE_POW19, LARGE_POW19_STEP;
+            21 => LARGE_POW21, LARGE_POW21_STEP;
+            23 => LARGE_POW23, LARGE_POW23_STEP;
+            25 => LARGE_POW25, LARGE_POW25_STEP;
+            27 => LARGE_POW27, LARGE_POW27_STEP;
+            29 => LARGE_POW29, LARGE_POW29_STEP;
+            31 => LARGE_POW31, LARGE_POW31_STEP;
+            33 => LARGE_POW33, LARGE_POW33_STEP;
+            35 => LARGE_POW35, LARGE_POW35_STEP;
+        }
+    }
+}
+
+#[test]
+#[cfg(all(feature = "compact", not(feature = "radix")))]
+fn bellerophon_decimal_powers_test() {
+    check_powers(10, &table::BASE10_POWERS);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn bellerophon_radix_powers_test() {
+    #[cfg(feature = "compact")]
+    check_powers(10, &table::BASE10_POWERS);
+    check_powers(3, &table::BASE3_POWERS);
+    check_powers(5, &table::BASE5_POWERS);
+    check_powers(6, &table::BASE6_POWERS);
+    check_powers(7, &table::BASE7_POWERS);
+    check_powers(9, &table::BASE9_POWERS);
+    check_powers(11, &table::BASE11_POWERS);
+    check_powers(12, &table::BASE12_POWERS);
+    check_powers(13, &table::BASE13_POWERS);
+    check_powers(14, &table::BASE14_POWERS);
+    check_powers(15, &table::BASE15_POWERS);
+    check_powers(17, &table::BASE17_POWERS);
+    check_powers(18, &table::BASE18_POWERS);
+    check_powers(19, &table::BASE19_POWERS);
+    check_powers(20, &table::BASE20_POWERS);
+    check_powers(21, &table::BASE21_POWERS);
+    check_powers(22, &table::BASE22_POWERS);
+    check_powers(23, &table::BASE23_POWERS);
+    check_powers(24, &table::BASE24_POWERS);
+    check_powers(25, &table::BASE25_POWERS);
+    check_powers(26, &table::BASE26_POWERS);
+    check_powers(27, &table::BASE27_POWERS);
+    check_powers(28, &table::BASE28_POWERS);
+    check_powers(29, &table::BASE29_POWERS);
+    check_powers(30, &table::BASE30_POWERS);
+    check_powers(31, &table::BASE31_POWERS);
+    check_powers(33, &table::BASE33_POWERS);
+    check_powers(34, &table::BASE34_POWERS);
+    check_powers(35, &table::BASE35_POWERS);
+    check_powers(36, &table::BASE36_POWERS);
+    assert!(table::bellerophon_powers(16).large.is_empty());
+}
+
+#[test]
+#[cfg(any(feature = "compact", feature = "radix"))]
+fn bellerophon_powers_threads_test() {
+    let radix = if cfg!(feature = "radix") {
+        7
+    } else {
+        10
+    };
+    let threads: Vec<_> = (0..8)
+        .map(|_| std::thread::spawn(move || table::bellerophon_powers(radix) as *const _ as usize))
+        .collect();
+    let expected = table::bellerophon_powers(radix) as *const _ as usize;
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), expected);
+    }
+}
@@ -0,0 +1,53 @@
+// These tests only link if the parsers have no reachable panics, which
+// requires optimizations: run with `cargo test --release --features no-panic`.
+// The wrappers are never inlined, so constant arguments cannot hide a panic.
+#![cfg(all(feature = "no-panic", not(debug_assertions)))]
+
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+use no_panic::no_panic;
+
+#[no_panic]
+#[inline(never)]
+fn parse_f32(bytes: &[u8]) -> Result<f32> {
+    f32::from_lexical(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_f64(bytes: &[u8]) -> Result<f64> {
+    f64::from_lexical(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_partial_f64(bytes: &[u8]) -> Result<(f64, usize)> {
+    f64::from_lexical_partial(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_options_f64(bytes: &[u8], options: &Options) -> Result<f64> {
+    f64::from_lexical_with_options::<STANDARD>(bytes, options)
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(parse_f32(b"1.5"), Ok(1.5));
+    assert_eq!(parse_f64(b"1.5e300"), Ok(1.5e300));
+    assert_eq!(parse_partial_f64(b"1.5x"), Ok((1.5, 3)));
+    assert!(parse_f64(b"1.5x").is_err());
+    assert!(parse_f64(b"").is_err());
+}
+
+#[test]
+fn parse_slow_path_test() {
+    // Halfway cases, which require the big-integer algorithms.
+    assert_eq!(parse_f64(b"9007199254740993"), Ok(9007199254740992.0));
+    assert_eq!(parse_f64(b"9007199254740993.00000000000000000000000001"), Ok(9007199254740994.0));
+    assert_eq!(parse_f64(b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125001e-324"), Ok(5e-324));
+
+    let options = Options::new();
+    assert_eq!(parse_options_f64(b"1e-400", &options), Ok(0.0));
+}
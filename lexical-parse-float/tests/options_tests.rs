@@ -93,6 +93,7 @@ fn builder_test() {
     let mut builder = OptionsBuilder::default();
 
     builder = builder.lossy(true);
+    builder = builder.parentheses_negative(true);
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
@@ -100,6 +101,7 @@ fn builder_test() {
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
 
     assert_eq!(builder.get_lossy(), true);
+    assert!(builder.get_parentheses_negative());
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
@@ -116,6 +118,7 @@ fn options_test() {
 
     unsafe {
         opts.set_lossy(true);
+        opts.set_parentheses_negative(true);
         opts.set_exponent(b'^');
         opts.set_decimal_point(b',');
         opts.set_nan_string(Some(b"nan"));
@@ -124,6 +127,7 @@ fn options_test() {
     }
 
     assert_eq!(opts.lossy(), true);
+    assert!(opts.parentheses_negative());
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
@@ -134,3 +138,100 @@ fn options_test() {
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn format_spec_test() {
+    use lexical_util::format::FormatSpec;
+
+    let spec = FormatSpec::parse("exponent=^, nan_string=nan, infinity_string=infinity").unwrap();
+    let options = Options::builder().decimal_point(b',').format_spec(&spec).build().unwrap();
+    assert_eq!(options.exponent(), b'^');
+    assert_eq!(options.decimal_point(), b',');
+    assert_eq!(options.nan_string(), Some(&b"nan"[..]));
+    assert_eq!(options.inf_string(), Some(&b"inf"[..]));
+    assert_eq!(options.infinity_string(), Some(&b"infinity"[..]));
+}
+
+#[test]
+fn build_with_format_test() {
+    use lexical_util::error::{Error, Punctuation};
+    use lexical_util::format::{Format, STANDARD};
+
+    let format = Format::new(STANDARD).unwrap();
+    let builder = Options::builder().exponent(b'^').decimal_point(b',');
+    assert_eq!(builder.build_with_format(&format), builder.build());
+    assert_eq!(
+        builder.clone().decimal_point(b'^').build_with_format(&format),
+        Err(Error::ConflictingPunctuation(Punctuation::DecimalPoint, Punctuation::Exponent))
+    );
+    assert_eq!(
+        builder.clone().exponent(b'1').build_with_format(&format),
+        Err(Error::DigitPunctuation(Punctuation::Exponent))
+    );
+    assert_eq!(
+        builder.exponent(b'\x00').build_with_format(&format),
+        Err(Error::InvalidExponentSymbol)
+    );
+}
+
+#[test]
+fn preset_test() {
+    use lexical_parse_float::options;
+    use lexical_parse_float::FromLexicalWithOptions;
+    use lexical_util::format::STANDARD;
+
+    let options = options::C_LOCALE;
+    assert!(f64::from_lexical_with_options::<STANDARD>(b"nan", &options).unwrap().is_nan());
+    assert!(f64::from_lexical_with_options::<STANDARD>(b"infinity", &options)
+        .unwrap()
+        .is_infinite());
+    assert_eq!(options::PERMISSIVE, options::C_LOCALE);
+    assert_eq!(options::SCIENTIFIC_FIXED, options::C_LOCALE);
+    assert_eq!(
+        f64::from_lexical_with_options::<STANDARD>(b"1,5", &options::EUROPEAN_LOCALE),
+        Ok(1.5)
+    );
+}
+
+#[test]
+fn override_test() {
+    use lexical_parse_float::options::OptionsOverride;
+    use lexical_util::error::Error;
+    use lexical_util::options::OverrideOptions;
+
+    let options = Options::builder().lossy(true).build().unwrap();
+    let overrides = OptionsOverride::new().decimal_point(b',').nan_string(None);
+    let result = options.with_override(&overrides).unwrap();
+    assert!(result.lossy());
+    assert_eq!(result.decimal_point(), b',');
+    assert_eq!(result.nan_string(), None);
+    assert_eq!(result.inf_string(), options.inf_string());
+
+    let overrides = OptionsOverride::new().exponent(b',').decimal_point(128);
+    assert_eq!(options.with_override(&overrides), Err(Error::InvalidDecimalPoint));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_test() {
+    use lexical_util::arbitrary::{Arbitrary, Unstructured};
+
+    // Pseudo-random input, so the generated values vary between iterations.
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for _ in 0..100 {
+        let bytes: Vec<u8> = (0..256)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        let builder = OptionsBuilder::arbitrary(&mut u).unwrap();
+        if builder.build().is_ok() {
+            assert!(builder.is_valid());
+        }
+        if let Ok(options) = Options::arbitrary(&mut u) {
+            assert!(options.is_valid());
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use lexical_parse_float::round::{self, extended_to_float, ExtendedFloat80};
+
+fn nearest(is_odd: bool, is_halfway: bool, is_above: bool) -> bool {
+    is_above || (is_odd && is_halfway)
+}
+
+fn round_nearest<F: round::RawFloat>(mant: u64, exp: i32) -> F {
+    let mut fp = ExtendedFloat80 {
+        mant,
+        exp,
+    };
+    round::round::<F, _>(&mut fp, |f, s| round::round_nearest_tie_even(f, s, nearest));
+    extended_to_float(fp)
+}
+
+fn round_down<F: round::RawFloat>(mant: u64, exp: i32) -> F {
+    let mut fp = ExtendedFloat80 {
+        mant,
+        exp,
+    };
+    round::round::<F, _>(&mut fp, round::round_down);
+    extended_to_float(fp)
+}
+
+#[test]
+fn round_normal_test() {
+    assert_eq!(round_nearest::<f64>(1 << 63, 1075 - 63), 1.0);
+    assert_eq!(round_nearest::<f64>((1 << 63) | (1 << 10), 1075 - 63), 1.0);
+    assert_eq!(round_nearest::<f64>((1 << 63) | (3 << 10), 1075 - 63), 1.0 + 2.0 * f64::EPSILON);
+    assert_eq!(round_nearest::<f64>((1 << 63) | (1 << 10) | 1, 1075 - 63), 1.0 + f64::EPSILON);
+    assert_eq!(round_down::<f64>((1 << 63) | (1 << 10) | 1, 1075 - 63), 1.0);
+    assert_eq!(round_down::<f64>(u64::MAX, 1075 - 63), 2.0 - f64::EPSILON);
+    assert_eq!(round_nearest::<f64>(u64::MAX, 1075 - 63), 2.0);
+
+    assert_eq!(round_nearest::<f32>(1 << 63, 150 - 63), 1.0);
+    assert_eq!(round_nearest::<f32>((1 << 63) | (1 << 39), 150 - 63), 1.0);
+    assert_eq!(round_nearest::<f32>((1 << 63) | (3 << 39), 150 - 63), 1.0 + 2.0 * f32::EPSILON);
+}
+
+#[test]
+fn round_special_test() {
+    // Overflow to infinity, after a carry.
+    assert_eq!(round_nearest::<f64>(u64::MAX, 1075 + 1023 - 63), f64::INFINITY);
+    assert_eq!(round_down::<f64>(u64::MAX, 1075 + 1023 - 63), f64::MAX);
+
+    // Denormal floats, and underflow to zero.
+    assert_eq!(round_nearest::<f64>(1 << 63, -62), 5e-324);
+    assert_eq!(round_nearest::<f64>(1 << 63, -63), 0.0);
+    assert_eq!(round_nearest::<f64>(3 << 62, -63), 5e-324);
+    assert_eq!(round_down::<f64>(3 << 62, -63), 0.0);
+
+    // Round-up from the largest denormal to the smallest normal float.
+    assert_eq!(round_nearest::<f64>(u64::MAX, -11), f64::MIN_POSITIVE);
+    assert_eq!(round_down::<f64>(u64::MAX, -11), f64::MIN_POSITIVE - 5e-324);
+}
+
+#[test]
+fn round_nearest_tie_even_test() {
+    let mut fp = ExtendedFloat80 {
+        mant: 0b1011,
+        exp: 0,
+    };
+    round::round_nearest_tie_even(&mut fp, 2, |is_odd, is_halfway, is_above| {
+        assert!(!is_odd && !is_halfway && is_above);
+        false
+    });
+    assert_eq!(fp.mant, 0b10);
+    assert_eq!(fp.exp, 2);
+
+    let mut fp = ExtendedFloat80 {
+        mant: u64::MAX,
+        exp: 0,
+    };
+    round::round_nearest_tie_even(&mut fp, 64, nearest);
+    assert_eq!(fp.mant, 1);
+    assert_eq!(fp.exp, 64);
+
+    let mut fp = ExtendedFloat80 {
+        mant: 0b1110,
+        exp: 5,
+    };
+    round::round_nearest_tie_even(&mut fp, 0, nearest);
+    assert_eq!(fp.mant, 0b1110);
+    assert_eq!(fp.exp, 5);
+}
+
+#[test]
+fn round_down_test() {
+    let mut fp = ExtendedFloat80 {
+        mant: 0b1111,
+        exp: -2,
+    };
+    round::round_down(&mut fp, 2);
+    assert_eq!(fp.mant, 0b11);
+    assert_eq!(fp.exp, 0);
+
+    let mut fp = ExtendedFloat80 {
+        mant: u64::MAX,
+        exp: 0,
+    };
+    round::round_down(&mut fp, 64);
+    assert_eq!(fp.mant, 0);
+    assert_eq!(fp.exp, 64);
+}
@@ -0,0 +1,358 @@
+use lexical_parse_float::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{Format, STANDARD};
+use lexical_util::strategy;
+use proptest::prelude::*;
+
+const INPUTS: &[&str] = &[
+    "", "0", "1", "-1", "+1", "-", "+", ".", "00", "01", "1.", ".1", "-.1", "1.5", "0.1",
+    "0.000123", "123.456", "1e5", "1e-5", "1E5", "1e+5", "1e", "1e+", "e5", "1.e5", ".e5",
+    "1.5e300", "1.5e-300", "2.5e-324", "1e308", "1.8e308", "1e400", "-1e-400", "1e1000000000",
+    "123456789012345678901234567890", "0.000000000000000000000000000123456789012345678901",
+    "9007199254740993", "9007199254740992.5", "2.2250738585072011e-308",
+    "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791",
+    "nan", "NaN", "-nan", "inf", "-inf", "Infinity", "infinity", "INF", "infx", "nanx", "in",
+    "1a", "a1", "1.5x", "1e5x", "12 ", " 12", "(1.5)", "(-1.5)", "()", "(1.5", "(nan)",
+    "0x1", "0x1.8p1", "0x", "1_2", "1_2.3_4e5_6", "12_", "1__2", "1.2_", "1e1_", "1'2",
+    "ff", "FF.8", "zz", "10.01", "101e11", "1p10", "1^10", "-0", "+0", "-0.0", "--1", "+-1",
+    "0x1h", "0X1.5H", "(0x1h)",
+];
+
+/// Assert two results are identical, treating NaN as equal to itself.
+fn assert_same<T: core::fmt::Debug + PartialEq>(
+    x: Result<(f64, T), Error>,
+    y: Result<(f64, T), Error>,
+    input: &str,
+) {
+    match (x, y) {
+        (Ok((x, xc)), Ok((y, yc))) => {
+            assert_eq!(x.to_bits(), y.to_bits(), "{:?}: {} != {}", input, x, y);
+            assert_eq!(xc, yc, "{:?}", input);
+        },
+        (x, y) => assert_eq!(x, y, "{:?}", input),
+    }
+}
+
+/// Assert the runtime parsers match the const generic parsers for the input.
+fn assert_runtime_eq<const FORMAT: u128>(input: &str, options: &Options) {
+    let format = Format::new(FORMAT).unwrap();
+    let bytes = input.as_bytes();
+    assert_same(
+        f64::from_lexical_with_options::<FORMAT>(bytes, options).map(|x| (x, ())),
+        f64::from_lexical_with_format(bytes, &format, options).map(|x| (x, ())),
+        input,
+    );
+    assert_same(
+        f64::from_lexical_partial_with_options::<FORMAT>(bytes, options),
+        f64::from_lexical_partial_with_format(bytes, &format, options),
+        input,
+    );
+    let x = f32::from_lexical_partial_with_options::<FORMAT>(bytes, options);
+    let y = f32::from_lexical_partial_with_format(bytes, &format, options);
+    assert_same(x.map(|(x, c)| (x as f64, c)), y.map(|(y, c)| (y as f64, c)), input);
+}
+
+/// Assert the runtime parsers match for every input, for the default and
+/// accounting options.
+fn assert_format_eq<const FORMAT: u128>() {
+    let parentheses = Options::builder().parentheses_negative(true).build().unwrap();
+    for input in INPUTS {
+        assert_runtime_eq::<FORMAT>(input, &Options::new());
+        assert_runtime_eq::<FORMAT>(input, &parentheses);
+    }
+}
+
+#[test]
+fn standard_runtime_test() {
+    let format = Format::new(STANDARD).unwrap();
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_format(b"-1.5e3", &format, &options), Ok(-1500.0));
+    assert_eq!(f64::from_lexical_partial_with_format(b"1.5x", &format, &options), Ok((1.5, 3)));
+    assert_eq!(
+        f64::from_lexical_with_format(b"1.5x", &format, &options),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_format_eq::<STANDARD>();
+}
+
+#[test]
+fn long_runtime_test() {
+    // Digits past the truncation limit must still round correctly.
+    let format = Format::new(STANDARD).unwrap();
+    let options = Options::new();
+    let halfway = "9007199254740993";
+    for zeros in [0, 100, 1090, 1100, 2000].iter() {
+        let below = format!("{}{}", halfway, "0".repeat(*zeros));
+        let above = format!("{}{}1", halfway, "0".repeat(*zeros));
+        let fraction = format!("0.{}{}e{}", "0".repeat(*zeros), halfway, zeros + 16);
+        for input in [below, above, fraction].iter() {
+            assert_runtime_eq::<STANDARD>(input, &options);
+        }
+    }
+    let input = format!("1{}", "0".repeat(2000));
+    assert_eq!(
+        f64::from_lexical_with_format(input.as_bytes(), &format, &options),
+        Ok(f64::INFINITY)
+    );
+    let input = format!("0.{}1", "0".repeat(2000));
+    assert_eq!(f64::from_lexical_with_format(input.as_bytes(), &format, &options), Ok(0.0));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_runtime_test() {
+    const BINARY: u128 = NumberFormatBuilder::new().mantissa_radix(2).build();
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(16))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .build();
+    const HEX_BINARY: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .build();
+    const OCTAL: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(8)
+        .exponent_base(core::num::NonZeroU8::new(8))
+        .build();
+    const INVALID: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(8)
+        .exponent_base(core::num::NonZeroU8::new(4))
+        .build();
+
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let format = Format::new(HEX_BINARY).unwrap();
+    assert_eq!(f64::from_lexical_with_format(b"1.8p1", &format, &options), Ok(3.0));
+    assert_eq!(f64::from_lexical_with_format(b"-A.8p-3", &format, &options), Ok(-1.3125));
+    let input = format!("1.{}1p1", "0".repeat(1200));
+    assert_eq!(f64::from_lexical_with_format(input.as_bytes(), &format, &options), Ok(2.0));
+    assert_runtime_eq::<HEX>(&format!("1.{}1p1", "0".repeat(1200)), &options);
+    for input in INPUTS {
+        assert_runtime_eq::<HEX>(input, &options);
+    }
+    let format = Format::new(INVALID).unwrap();
+    assert_eq!(
+        f64::from_lexical_with_format(b"1", &format, &Options::new()),
+        Err(Error::InvalidRadix)
+    );
+    assert_format_eq::<BINARY>();
+    assert_format_eq::<OCTAL>();
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn affix_runtime_test() {
+    const AFFIX: u128 = NumberFormatBuilder::new()
+        .base_prefix(core::num::NonZeroU8::new(b'x'))
+        .base_suffix(core::num::NonZeroU8::new(b'h'))
+        .build();
+    const CASE_SENSITIVE: u128 = NumberFormatBuilder::rebuild(AFFIX)
+        .case_sensitive_base_prefix(true)
+        .case_sensitive_base_suffix(true)
+        .build();
+    const REQUIRED: u128 = NumberFormatBuilder::rebuild(AFFIX)
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .build();
+
+    let format = Format::new(AFFIX).unwrap();
+    assert_eq!(f64::from_lexical_with_format(b"0x1.5h", &format, &Options::new()), Ok(1.5));
+    assert_format_eq::<AFFIX>();
+    assert_format_eq::<CASE_SENSITIVE>();
+    assert_format_eq::<REQUIRED>();
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn odd_radix_runtime_test() {
+    const BASE3: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(3)
+        .exponent_base(core::num::NonZeroU8::new(3))
+        .build();
+    const BASE36: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(36)
+        .exponent_base(core::num::NonZeroU8::new(36))
+        .exponent_radix(core::num::NonZeroU8::new(36))
+        .build();
+    assert_format_eq::<BASE3>();
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    for input in INPUTS {
+        assert_runtime_eq::<BASE36>(input, &options);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_runtime_test() {
+    const SEPARATOR: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    const TRAILING: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .trailing_digit_separator(true)
+        .consecutive_digit_separator(true)
+        .special_digit_separator(true)
+        .build();
+    const REQUIRED: u128 = NumberFormatBuilder::new()
+        .required_digits(true)
+        .required_exponent_sign(true)
+        .no_positive_mantissa_sign(true)
+        .no_float_leading_zeros(true)
+        .case_sensitive_special(true)
+        .build();
+    const LEADING: u128 = NumberFormatBuilder::new().no_integer_leading_zeros(true).build();
+    const NOTATION: u128 = NumberFormatBuilder::new()
+        .no_exponent_without_fraction(true)
+        .no_positive_exponent_sign(true)
+        .case_sensitive_exponent(true)
+        .build();
+    const NO_EXPONENT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build();
+    const EXPONENT: u128 = NumberFormatBuilder::new().required_exponent_notation(true).build();
+    const SIGN: u128 =
+        NumberFormatBuilder::new().required_mantissa_sign(true).no_special(true).build();
+
+    let format = Format::new(SEPARATOR).unwrap();
+    let options = Options::new();
+    assert_eq!(f64::from_lexical_with_format(b"1_2.3_4e1", &format, &options), Ok(123.4));
+    assert_format_eq::<SEPARATOR>();
+    assert_format_eq::<TRAILING>();
+    assert_format_eq::<REQUIRED>();
+    assert_format_eq::<LEADING>();
+    assert_format_eq::<NOTATION>();
+    assert_format_eq::<NO_EXPONENT>();
+    assert_format_eq::<EXPONENT>();
+    assert_format_eq::<SIGN>();
+
+    let options = Options::builder().decimal_point(b'_').build().unwrap();
+    assert_eq!(
+        f64::from_lexical_with_format(b"1", &format, &options),
+        Err(Error::InvalidPunctuation)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn separator_policy_runtime_test() {
+    use lexical_util::format::SeparatorPolicy;
+
+    const INTEGER: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .integer_separator_policy(SeparatorPolicy::INTERNAL)
+        .build();
+    const FRACTION: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .fraction_separator_policy(SeparatorPolicy::ALL)
+        .build();
+    assert_format_eq::<INTEGER>();
+    assert_format_eq::<FRACTION>();
+
+    let options = Options::new();
+    let format = Format::new(INTEGER).unwrap();
+    assert_eq!(f64::from_lexical_with_format(b"1_000.5", &format, &options), Ok(1000.5));
+    assert_eq!(
+        f64::from_lexical_with_format(b"1_000.5_0", &format, &options),
+        Err(Error::InvalidDigit(7))
+    );
+
+    let format = format.with_fraction_separator_policy(SeparatorPolicy::INTERNAL).unwrap();
+    assert_eq!(f64::from_lexical_with_format(b"1_000.5_0", &format, &options), Ok(1000.5));
+    assert_eq!(
+        f64::from_lexical_with_format(b"1e1_0", &format, &options),
+        Err(Error::InvalidDigit(3))
+    );
+
+    let consecutive = SeparatorPolicy {
+        consecutive: true,
+        ..SeparatorPolicy::NONE
+    };
+    assert_eq!(
+        format.with_exponent_separator_policy(consecutive),
+        Err(Error::InvalidConsecutiveExponentDigitSeparator)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn required_digits_runtime_test() {
+    const INTEGER: u128 = NumberFormatBuilder::new().required_integer_digits(true).build();
+    const FRACTION: u128 = NumberFormatBuilder::new().required_fraction_digits(true).build();
+    assert_format_eq::<INTEGER>();
+    assert_format_eq::<FRACTION>();
+
+    let options = Options::new();
+    let format = Format::new(STANDARD).unwrap();
+    assert_eq!(f64::from_lexical_with_format(b".5", &format, &options), Ok(0.5));
+    assert_eq!(f64::from_lexical_with_format(b"5.", &format, &options), Ok(5.0));
+
+    let format = format.with_required_integer_digits(true).unwrap();
+    assert_eq!(
+        f64::from_lexical_with_format(b".5", &format, &options),
+        Err(Error::EmptyInteger(0))
+    );
+    assert_eq!(f64::from_lexical_with_format(b"5.", &format, &options), Ok(5.0));
+
+    let format = format.with_required_integer_digits(false).unwrap();
+    let format = format.with_required_fraction_digits(true).unwrap();
+    assert_eq!(f64::from_lexical_with_format(b".5", &format, &options), Ok(0.5));
+    assert_eq!(
+        f64::from_lexical_with_format(b"5.", &format, &options),
+        Err(Error::EmptyFraction(2))
+    );
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn runtime_proptest(s in r"[+\-]?[1-9][0-9]{0,30}(\.[0-9]{0,30})?([eE][+\-]?[0-9]{1,3})?") {
+        let format = Format::new(STANDARD).unwrap();
+        let options = Options::new();
+        let bytes = s.as_bytes();
+        let x = f64::from_lexical_with_options::<STANDARD>(bytes, &options).unwrap();
+        let y = f64::from_lexical_with_format(bytes, &format, &options).unwrap();
+        prop_assert_eq!(x.to_bits(), y.to_bits());
+    }
+}
+
+/// Get the options to parse floats generated for the number format.
+fn strategy_options(format: &Format) -> Options {
+    let exponent = if format.mantissa_radix() >= 15 {
+        b'^'
+    } else {
+        b'e'
+    };
+    Options::builder().exponent(exponent).build().unwrap()
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn strategy_proptest((format, s) in strategy::format().prop_flat_map(|format| {
+        let exponent = strategy_options(&format).exponent();
+        (Just(format), strategy::float(format, b'.', exponent))
+    })) {
+        let options = strategy_options(&format);
+        let result = f64::from_lexical_with_format(s.as_bytes(), &format, &options);
+        if cfg!(not(feature = "radix")) && result == Err(Error::InvalidMantissaRadix) {
+            // The tables for the radix were not generated.
+            return Ok(());
+        }
+        prop_assert!(result.is_ok(), "{:?} {:?}: {:?}", format, s, result);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn near_valid_proptest((format, s) in strategy::format().prop_flat_map(|format| {
+        let exponent = strategy_options(&format).exponent();
+        (Just(format), strategy::near_valid(strategy::float(format, b'.', exponent)))
+    })) {
+        let options = strategy_options(&format);
+        if let Ok((_, count)) = f64::from_lexical_partial_with_format(s.as_bytes(), &format, &options) {
+            prop_assert!(count <= s.len());
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(feature = "power-of-two")]
+use lexical_parse_float::format::NumberFormatBuilder;
+use lexical_parse_float::format::STANDARD;
+use lexical_parse_float::scale::{self, Number};
+
+fn number(mantissa: u64, exponent: i64) -> Number<'static> {
+    Number {
+        mantissa,
+        exponent,
+        ..Number::default()
+    }
+}
+
+#[test]
+fn scientific_exponent_test() {
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(0, 5)), 5);
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(1, 0)), 0);
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(9, 0)), 0);
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(10, 0)), 1);
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(12345, -10)), -6);
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(u64::MAX, 0)), 19);
+    assert_eq!(scale::scientific_exponent::<STANDARD>(&number(1, -324)), -324);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn scientific_exponent_radix_test() {
+    const BASE2: u128 = NumberFormatBuilder::from_radix(2);
+    const BASE32: u128 = NumberFormatBuilder::from_radix(32);
+    assert_eq!(scale::scientific_exponent::<BASE2>(&number(0b1011, -1)), 2);
+    assert_eq!(scale::scientific_exponent::<BASE2>(&number(u64::MAX, 0)), 63);
+    assert_eq!(scale::scientific_exponent::<BASE32>(&number(1 << 20, 3)), 7);
+}
+
+#[test]
+fn b_test() {
+    let fp = scale::b(f64::MAX);
+    assert_eq!((fp.mant, fp.exp), ((1 << 53) - 1, 971));
+    let fp = scale::b(f32::MIN_POSITIVE);
+    assert_eq!((fp.mant, fp.exp), (1 << 23, -149));
+}
+
+#[test]
+fn bh_test() {
+    let fp = scale::bh(f64::MAX);
+    assert_eq!((fp.mant, fp.exp), ((1 << 54) - 1, 970));
+    let fp = scale::bh(f32::MIN_POSITIVE);
+    assert_eq!((fp.mant, fp.exp), ((1 << 24) + 1, -150));
+}
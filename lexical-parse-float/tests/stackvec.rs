@@ -2,14 +2,18 @@ use lexical_parse_float::bigint::{Limb, StackVec};
 
 pub fn vec_from_u32<const SIZE: usize>(x: &[u32]) -> StackVec<SIZE> {
     let mut vec = StackVec::<SIZE>::new();
-    #[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+    #[cfg(not(all(
+        target_pointer_width = "64",
+        not(target_arch = "sparc"),
+        not(feature = "wasm")
+    )))]
     {
         for &xi in x {
             vec.try_push(xi as Limb).unwrap();
         }
     }
 
-    #[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
     {
         for xi in x.chunks(2) {
             match xi.len() {
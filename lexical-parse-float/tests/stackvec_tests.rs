@@ -138,7 +138,7 @@ fn math_test() {
     {
         let mut x: VecType = vec_from_u32(&[0, 0, 0, 536870912]);
         let y: VecType = vec_from_u32(&[3358091099, 2770363594, 2782716766, 217327764]);
-        assert_eq!(x.quorem(&y), 2);
+        assert_eq!(x.quorem(&y), Some(2));
         let expected: VecType = vec_from_u32(&[1873752394, 3049207402, 3024501058, 102215382]);
         assert_eq!(&*x, &*expected);
     }
@@ -389,7 +389,7 @@ fn very_large_mul_test() {
 fn quorem_test() {
     let mut x: VecType = vec_from_u32(&[0, 0, 0, 536870912]);
     let y: VecType = vec_from_u32(&[3358091099, 2770363594, 2782716766, 217327764]);
-    assert_eq!(bigint::large_quorem(&mut x, &y), 2);
+    assert_eq!(bigint::large_quorem(&mut x, &y), Some(2));
     let expected: VecType = vec_from_u32(&[1873752394, 3049207402, 3024501058, 102215382]);
     assert_eq!(&*x, &*expected);
 }
@@ -466,6 +466,31 @@ fn split_radix_test() {
         assert_eq!(bigint::split_radix(8), (0, 3));
         assert_eq!(bigint::split_radix(16), (0, 4));
         assert_eq!(bigint::split_radix(32), (0, 5));
+        assert_eq!(bigint::split_radix(12), (3, 2));
         assert_eq!(bigint::split_radix(14), (7, 1));
     }
 }
+
+#[test]
+#[cfg(feature = "radix")]
+fn bigint_pow_radix_test() {
+    // The large powers are looked up by the odd factor, so radix 12 must
+    // use the powers of 3, not fall through to another radix.
+    let mut x = bigint::Bigint::from_u64(1);
+    x.pow(12, 100).unwrap();
+    let mut y = bigint::Bigint::from_u64(1);
+    y.pow(3, 100).unwrap();
+    y.pow(2, 200).unwrap();
+    assert_eq!(&*x.data, &*y.data);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "safe")]
+fn push_unchecked_safe_test() {
+    let mut x = StackVec::<1>::new();
+    unsafe {
+        x.push_unchecked(1);
+        x.push_unchecked(2);
+    }
+}
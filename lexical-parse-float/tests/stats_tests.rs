@@ -0,0 +1,55 @@
+#![cfg(feature = "stats")]
+
+use lexical_parse_float::{reset_stats, stats, FromLexical, Stats};
+
+// The counters are global, so they are checked in a single test, to
+// avoid counting floats concurrently parsed by other tests.
+#[test]
+fn stats_test() {
+    reset_stats();
+    assert_eq!(stats(), Stats::default());
+
+    assert_eq!(f64::from_lexical(b"1.5"), Ok(1.5));
+    assert_eq!(f32::from_lexical(b"-12345e-3"), Ok(-12.345));
+    assert_eq!(stats(), Stats {
+        fast_path: 2,
+        moderate_path: 0,
+        slow_path: 0,
+        errors: 0,
+    });
+
+    assert_eq!(f64::from_lexical(b"1.2345e300"), Ok(1.2345e300));
+    assert_eq!(f64::from_lexical_partial(b"1.2345e300 "), Ok((1.2345e300, 10)));
+    assert_eq!(stats(), Stats {
+        fast_path: 2,
+        moderate_path: 2,
+        slow_path: 0,
+        errors: 0,
+    });
+
+    // Just above halfway between `1.0` and the next float, so the
+    // truncated digits must be compared.
+    let halfway = b"1.00000000000000011102230246251565404236316680908203126";
+    assert_eq!(f64::from_lexical(halfway), Ok(1.0000000000000002));
+    assert_eq!(stats(), Stats {
+        fast_path: 2,
+        moderate_path: 2,
+        slow_path: 1,
+        errors: 0,
+    });
+
+    // Special values are not parsed by any algorithm.
+    assert!(f64::from_lexical(b"NaN").unwrap().is_nan());
+    assert!(f64::from_lexical(b"1.5e").is_err());
+    assert!(f64::from_lexical(b"").is_err());
+    assert!(f32::from_lexical_partial(b"x").is_err());
+    assert_eq!(stats(), Stats {
+        fast_path: 2,
+        moderate_path: 2,
+        slow_path: 1,
+        errors: 3,
+    });
+
+    reset_stats();
+    assert_eq!(stats(), Stats::default());
+}
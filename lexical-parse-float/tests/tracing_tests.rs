@@ -0,0 +1,84 @@
+#![cfg(all(feature = "tracing", feature = "std"))]
+
+use core::fmt;
+use lexical_parse_float::FromLexical;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Subscriber that records the fields of every event.
+#[derive(Clone, Default)]
+struct Events(Arc<Mutex<Vec<String>>>);
+
+struct Fields<'a>(&'a mut String);
+
+impl<'a> Visit for Fields<'a> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push_str(&format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Events {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = String::new();
+        event.record(&mut Fields(&mut fields));
+        self.0.lock().unwrap().push(fields);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn parse_events(bytes: &[u8]) -> Vec<String> {
+    let events = Events::default();
+    tracing::subscriber::with_default(events.clone(), || {
+        f64::from_lexical(bytes).unwrap();
+    });
+    let result = events.0.lock().unwrap().clone();
+    result
+}
+
+#[test]
+fn fast_path_test() {
+    assert!(parse_events(b"1.5").is_empty());
+    assert!(parse_events(b"-12345e-3").is_empty());
+}
+
+#[test]
+fn moderate_path_test() {
+    let events = parse_events(b"1.2345e300");
+    assert_eq!(events.len(), 1);
+    assert!(events[0].starts_with("message=fast path failed, using the moderate path"));
+    assert!(events[0].contains("mantissa=12345 exponent=296"));
+    assert!(events[0].contains("integer_digits=1 fraction_digits=4"));
+}
+
+#[test]
+fn slow_path_test() {
+    // Just above halfway between `1.0` and the next float, so the
+    // truncated digits must be compared.
+    let events = parse_events(b"1.00000000000000011102230246251565404236316680908203126");
+    assert_eq!(events.len(), 2);
+    assert!(events[0].starts_with("message=fast path failed, using the moderate path"));
+    assert!(events[0].contains("many_digits=true"));
+    assert!(events[1].starts_with("message=moderate path failed, using the slow path"));
+    assert!(events[1].contains("integer_digits=1 fraction_digits=53"));
+    assert!(events[1].contains("fp_mant="));
+}
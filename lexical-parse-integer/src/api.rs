@@ -4,7 +4,7 @@
 
 use crate::options::Options;
 use crate::parse::ParseInteger;
-use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::format::{Format, NumberFormat, STANDARD};
 use lexical_util::{from_lexical, from_lexical_with_options};
 
 /// Implement FromLexical for numeric type.
@@ -40,13 +40,16 @@ macro_rules! integer_from_lexical {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical_with_options<const FORMAT: u128>(
                 bytes: &[u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> lexical_util::result::Result<Self>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
                 }
+                if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+                    return Self::parse_parentheses_complete::<$unsigned, FORMAT>(bytes);
+                }
                 Self::parse_complete::<$unsigned, FORMAT>(bytes)
             }
 
@@ -54,15 +57,46 @@ macro_rules! integer_from_lexical {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical_partial_with_options<const FORMAT: u128>(
                 bytes: &[u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
                 }
+                if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+                    return Self::parse_parentheses_partial::<$unsigned, FORMAT>(bytes);
+                }
                 Self::parse_partial::<$unsigned, FORMAT>(bytes)
             }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_format(
+                bytes: &[u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>
+            {
+                if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+                    return Self::parse_parentheses_complete_with_format::<$unsigned>(bytes, format);
+                }
+                Self::parse_complete_with_format::<$unsigned>(bytes, format)
+            }
+
+            $(#[$meta:meta])?
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_format(
+                bytes: &[u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                if options.parentheses_negative() && bytes.first() == Some(&b'(') {
+                    return Self::parse_parentheses_partial_with_format::<$unsigned>(bytes, format);
+                }
+                Self::parse_partial_with_format::<$unsigned>(bytes, format)
+            }
         }
     )*)
 }
@@ -26,6 +26,8 @@
 //! * `format` - Add support for parsing custom integer formats.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `no-panic` - Remove all reachable panics from the parsers.
+//! * `arbitrary` - Implement `Arbitrary` for options, for fuzzing.
 //!
 //! `safe` is a no-op, since all parsers are memory-safe by default.
 //!
@@ -67,14 +69,15 @@ pub mod algorithm;
 pub mod compact;
 pub mod options;
 pub mod parse;
+pub mod runtime;
 
 mod api;
 
 // Re-exports
 pub use self::api::{FromLexical, FromLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, OptionsOverride};
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormatBuilder};
-pub use lexical_util::options::ParseOptions;
+pub use lexical_util::options::{OverrideOptions, ParseOptions};
 pub use lexical_util::result::Result;
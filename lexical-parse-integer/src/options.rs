@@ -1,18 +1,45 @@
 //! Configuration options for parsing integers.
 
+#[cfg(feature = "arbitrary")]
+use lexical_util::arbitrary::{self, Arbitrary, Unstructured};
 use lexical_util::options::ParseOptions;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// Parse integers in parentheses as negative values.
+    parentheses_negative: bool,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            parentheses_negative: false,
+        }
+    }
+
+    // GETTERS
+
+    /// Get if we parse integers in parentheses as negative values.
+    #[inline(always)]
+    pub const fn get_parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
+    // SETTERS
+
+    /// Set if we parse integers in parentheses as negative values.
+    ///
+    /// This is the accounting style for negative values, IE, `(15)` is
+    /// parsed as `-15`. A sign is not allowed inside the parentheses.
+    #[inline(always)]
+    pub const fn parentheses_negative(mut self, parentheses_negative: bool) -> Self {
+        self.parentheses_negative = parentheses_negative;
+        self
     }
 
     // BUILDERS
@@ -30,7 +57,9 @@ impl OptionsBuilder {
     /// Safe as long as`is_valid` is true.
     #[inline(always)]
     pub const unsafe fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            parentheses_negative: self.parentheses_negative,
+        }
     }
 
     /// Build the Options struct.
@@ -57,24 +86,49 @@ impl Default for OptionsBuilder {
 ///
 /// # pub fn main() {
 /// let options = Options::builder()
+///     .parentheses_negative(true)
 ///     .build()
 ///     .unwrap();
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// Parse integers in parentheses as negative values.
+    parentheses_negative: bool,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        // SAFETY: always safe since it uses the default arguments.
+        unsafe { Self::builder().build_unchecked() }
     }
 
     /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.rebuild().is_valid()
+    }
+
+    // GETTERS
+
+    /// Get if we parse integers in parentheses as negative values.
+    #[inline(always)]
+    pub const fn parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
+    // SETTERS
+
+    /// Set if we parse integers in parentheses as negative values.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_parentheses_negative(&mut self, parentheses_negative: bool) {
+        self.parentheses_negative = parentheses_negative;
     }
 
     // BUILDERS
@@ -88,7 +142,9 @@ impl Options {
     /// Create OptionsBuilder using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            parentheses_negative: self.parentheses_negative,
+        }
     }
 }
 
@@ -106,6 +162,35 @@ impl ParseOptions for Options {
     }
 }
 
+// ARBITRARY
+// ---------
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for OptionsBuilder {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            parentheses_negative: u.arbitrary()?,
+        })
+    }
+}
+
+// Only valid options are generated: invalid builder states are rejected.
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Options {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let builder: OptionsBuilder = u.arbitrary()?;
+        builder.build().map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+// OVERRIDES
+// ---------
+
+lexical_util::options_override! {
+    /// Set if we parse integers in parentheses as negative values.
+    parentheses_negative: bool;
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
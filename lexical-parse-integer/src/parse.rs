@@ -8,7 +8,10 @@ use crate::algorithm::{algorithm_complete, algorithm_partial};
 #[cfg(feature = "compact")]
 use crate::compact::{algorithm_complete, algorithm_partial};
 
-use lexical_util::num::{Integer, UnsignedInteger};
+use crate::runtime;
+use lexical_util::error::Error;
+use lexical_util::format::Format;
+use lexical_util::num::{as_cast, Integer, UnsignedInteger};
 use lexical_util::result::Result;
 
 /// Parse integer trait, implemented in terms of the optimized back-end.
@@ -26,6 +29,136 @@ pub trait ParseInteger: Integer {
     ) -> Result<(Self, usize)> {
         algorithm_partial::<_, Unsigned, { FORMAT }>(bytes)
     }
+
+    /// Forward complete parser parameters to the runtime-format backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_with_format<Unsigned: UnsignedInteger>(
+        bytes: &[u8],
+        format: &Format,
+    ) -> Result<Self> {
+        runtime::algorithm_complete::<_, Unsigned>(bytes, format)
+    }
+
+    /// Forward partial parser parameters to the runtime-format backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_with_format<Unsigned: UnsignedInteger>(
+        bytes: &[u8],
+        format: &Format,
+    ) -> Result<(Self, usize)> {
+        runtime::algorithm_partial::<_, Unsigned>(bytes, format)
+    }
+
+    /// Parse an accounting-style negative integer, IE, `(15)`.
+    ///
+    /// The input must start with `(`, and the closing `)` must be
+    /// the last byte.
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn parse_parentheses_complete<Unsigned, const FORMAT: u128>(bytes: &[u8]) -> Result<Self>
+    where
+        Unsigned: UnsignedInteger + ParseInteger,
+    {
+        Self::parse_parentheses_complete_with(bytes, Unsigned::parse_partial::<Unsigned, FORMAT>)
+    }
+
+    /// Parse an accounting-style negative integer, IE, `(15)`, returning
+    /// the value and the number of processed bytes.
+    ///
+    /// The input must start with `(`. A sign is not allowed inside
+    /// the parentheses, and magnitudes that cannot be negated into
+    /// the type are an underflow.
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn parse_parentheses_partial<Unsigned, const FORMAT: u128>(
+        bytes: &[u8],
+    ) -> Result<(Self, usize)>
+    where
+        Unsigned: UnsignedInteger + ParseInteger,
+    {
+        Self::parse_parentheses_partial_with(bytes, Unsigned::parse_partial::<Unsigned, FORMAT>)
+    }
+
+    /// Parse an accounting-style negative integer, IE, `(15)`, for a
+    /// runtime format.
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn parse_parentheses_complete_with_format<Unsigned>(
+        bytes: &[u8],
+        format: &Format,
+    ) -> Result<Self>
+    where
+        Unsigned: UnsignedInteger + ParseInteger,
+    {
+        Self::parse_parentheses_complete_with(bytes, |digits| {
+            Unsigned::parse_partial_with_format::<Unsigned>(digits, format)
+        })
+    }
+
+    /// Parse an accounting-style negative integer, IE, `(15)`, for a
+    /// runtime format, returning the value and the number of processed bytes.
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn parse_parentheses_partial_with_format<Unsigned>(
+        bytes: &[u8],
+        format: &Format,
+    ) -> Result<(Self, usize)>
+    where
+        Unsigned: UnsignedInteger + ParseInteger,
+    {
+        Self::parse_parentheses_partial_with(bytes, |digits| {
+            Unsigned::parse_partial_with_format::<Unsigned>(digits, format)
+        })
+    }
+
+    /// Parse an accounting-style negative integer, using `parse` to
+    /// parse the magnitude inside the parentheses.
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn parse_parentheses_complete_with<Unsigned, Parse>(bytes: &[u8], parse: Parse) -> Result<Self>
+    where
+        Unsigned: UnsignedInteger,
+        Parse: FnOnce(&[u8]) -> Result<(Unsigned, usize)>,
+    {
+        let (value, count) = Self::parse_parentheses_partial_with(bytes, parse)?;
+        if count == bytes.len() {
+            Ok(value)
+        } else {
+            Err(Error::InvalidDigit(count))
+        }
+    }
+
+    /// Parse an accounting-style negative integer, using `parse` to
+    /// parse the magnitude inside the parentheses, returning the value
+    /// and the number of processed bytes.
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn parse_parentheses_partial_with<Unsigned, Parse>(
+        bytes: &[u8],
+        parse: Parse,
+    ) -> Result<(Self, usize)>
+    where
+        Unsigned: UnsignedInteger,
+        Parse: FnOnce(&[u8]) -> Result<(Unsigned, usize)>,
+    {
+        debug_assert!(bytes.first() == Some(&b'('));
+        let digits = &bytes[1..];
+        if matches!(digits.first(), Some(&b'+') | Some(&b'-') | Some(&b'(')) {
+            return Err(Error::InvalidDigit(1));
+        }
+        let (magnitude, count) = match parse(digits) {
+            Ok(result) => result,
+            Err(Error::Overflow(index)) => return Err(Error::Underflow(index + 1)),
+            Err(error) => return Err(error.shift_index(1)),
+        };
+        if digits.get(count) != Some(&b')') {
+            return Err(Error::InvalidDigit(count + 1));
+        }
+
+        // The largest magnitude we can negate is `|MIN|`, which is 0 for
+        // unsigned types. Use a wrapping negation, since `|MIN|` for
+        // signed types does not fit in the signed type.
+        let max_magnitude: Unsigned = as_cast(Self::MIN);
+        let max_magnitude = max_magnitude.wrapping_neg();
+        if magnitude > max_magnitude {
+            // The last digit underflows, shifted past the opening parenthesis.
+            return Err(Error::Underflow(count - 1).shift_index(1));
+        }
+        Ok((as_cast(magnitude.wrapping_neg()), count + 2))
+    }
 }
 
 macro_rules! parse_integer_impl {
@@ -0,0 +1,208 @@
+//! Radix-generic string-to-integer conversion for runtime formats.
+//!
+//! These routines have the same grammar and error indexes as the
+//! algorithms in `algorithm` and `compact`, however, the number format
+//! is read from a [`Format`] at runtime, rather than the `FORMAT`
+//! const generic. This allows formats chosen at runtime without
+//! monomorphizing a parser for every format, at the cost of performance.
+
+#![doc(hidden)]
+
+use crate::shared::is_overflow_radix;
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::Format;
+use lexical_util::iterator::{FormatBytes, NumberComponent};
+use lexical_util::num::{as_cast, Integer, UnsignedInteger};
+use lexical_util::result::Result;
+use lexical_util::step::min_step;
+
+const INTEGER: NumberComponent = NumberComponent::Integer;
+
+/// Algorithm for the complete parser.
+#[inline]
+pub fn algorithm_complete<T, Unsigned>(bytes: &[u8], format: &Format) -> Result<T>
+where
+    T: Integer,
+    Unsigned: UnsignedInteger,
+{
+    algorithm::<T, Unsigned>(bytes, format, false).map(|(value, _)| value)
+}
+
+/// Algorithm for the partial parser.
+#[inline]
+pub fn algorithm_partial<T, Unsigned>(bytes: &[u8], format: &Format) -> Result<(T, usize)>
+where
+    T: Integer,
+    Unsigned: UnsignedInteger,
+{
+    algorithm::<T, Unsigned>(bytes, format, true)
+}
+
+/// Get the error for a value that overflowed the type.
+#[inline]
+fn overflow_error<T: Integer>(count: usize, radix: u32, is_negative: bool) -> Error {
+    let min = min_step(radix, T::BITS, T::IS_SIGNED);
+    let index = (count - 1).min(min + 1);
+    if T::IS_SIGNED && is_negative {
+        Error::Underflow(index)
+    } else {
+        Error::Overflow(index)
+    }
+}
+
+/// Convert the parsed magnitude to the type, checking for overflow.
+#[inline]
+fn into_value<T, Unsigned>(
+    value: Unsigned,
+    count: usize,
+    index: usize,
+    radix: u32,
+    is_negative: bool,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+    Unsigned: UnsignedInteger,
+{
+    if is_overflow_radix::<T, Unsigned>(value, count, is_negative, radix) {
+        Err(overflow_error::<T>(count, radix, is_negative))
+    } else if T::IS_SIGNED && is_negative {
+        // Need to cast it to the signed type first, so we don't
+        // get an invalid representation for i128 if it's widened.
+        Ok((as_cast::<T, _>(value.wrapping_neg()), index))
+    } else {
+        Ok((as_cast(value), index))
+    }
+}
+
+/// Check if the byte is a base prefix or suffix character.
+#[inline(always)]
+fn is_affix(c: u8, affix: u8, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        c == affix
+    } else {
+        c.eq_ignore_ascii_case(&affix)
+    }
+}
+
+/// Generic algorithm for both partial and complete parsers.
+///
+/// Complete parsers ignore the returned index, and error on the first
+/// invalid digit rather than checking for overflow.
+fn algorithm<T, Unsigned>(bytes: &[u8], format: &Format, is_partial: bool) -> Result<(T, usize)>
+where
+    T: Integer,
+    Unsigned: UnsignedInteger,
+{
+    let radix = format.radix();
+    let mut iter = FormatBytes::new(bytes, *format);
+
+    // Parse the sign. Negative signs are not consumed for unsigned types,
+    // so they are reported as an invalid digit.
+    let (is_negative, shift) = match iter.peek(INTEGER) {
+        Some(b'+') if !format.no_positive_mantissa_sign() => (false, 1),
+        Some(b'+') => return Err(Error::InvalidPositiveSign(0)),
+        Some(b'-') if T::IS_SIGNED => (true, 1),
+        Some(_) if format.required_mantissa_sign() => return Err(Error::MissingSign(0)),
+        _ => (false, 0),
+    };
+    if shift == 1 {
+        iter.step();
+    }
+    if iter.is_done() {
+        return Err(Error::Empty(shift));
+    }
+
+    // Skip any leading zeros.
+    let mut start_index = iter.cursor();
+    let zeros = iter.skip_zeros(INTEGER);
+    start_index += zeros;
+
+    // Now, check to see if we have a valid base prefix.
+    let base_prefix = format.base_prefix();
+    let mut is_prefix = false;
+    if base_prefix != 0 && zeros == 1 {
+        if let Some(c) = iter.peek(INTEGER) {
+            is_prefix = is_affix(c, base_prefix, format.case_sensitive_base_prefix());
+            if is_prefix {
+                iter.step();
+                if iter.is_done() {
+                    return Err(Error::Empty(iter.cursor()));
+                }
+                start_index += 1;
+            }
+        }
+    }
+    if format.required_base_prefix() && !is_prefix {
+        return Err(Error::MissingBasePrefix(iter.cursor() - zeros));
+    }
+
+    // Count the significant digits from here, ignoring any digit separators.
+    let start_count = iter.current_count();
+
+    // Check for invalid leading zeros, which are only invalid
+    // if followed by a digit.
+    if !is_prefix && format.no_integer_leading_zeros() && zeros != 0 {
+        let index = iter.cursor() - zeros;
+        if zeros > 1 {
+            return Err(Error::InvalidLeadingZeros(index));
+        }
+        return match iter.peek(INTEGER).map(|c| char_to_digit_const(c, radix)) {
+            Some(Some(_)) => Err(Error::InvalidLeadingZeros(index)),
+            _ => Ok((T::ZERO, index)),
+        };
+    }
+
+    // A base suffix directly after the leading zeros, IE, `0h`, is
+    // not seen by the digit parser, which requires at least 1 digit.
+    let base_suffix = format.base_suffix();
+    if base_suffix != 0 && !is_prefix && zeros != 0 {
+        if let Some(c) = iter.peek(INTEGER) {
+            let is_suffix = is_affix(c, base_suffix, format.case_sensitive_base_suffix());
+            if is_suffix && iter.as_slice().len() == 1 {
+                return Ok((T::ZERO, iter.length()));
+            }
+        }
+    }
+
+    // Parse the digits, using wrapping arithmetic, and check for overflow
+    // once we know the number of digits.
+    let mut value = Unsigned::ZERO;
+    let mut has_suffix = false;
+    while let Some(c) = iter.next(INTEGER) {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(digit) => digit,
+            None => {
+                // A base suffix is only valid after at least 1 digit.
+                let mut is_suffix = false;
+                if base_suffix != 0 && iter.cursor() - start_index > 1 {
+                    is_suffix = is_affix(c, base_suffix, format.case_sensitive_base_suffix());
+                    if is_suffix && iter.is_done() {
+                        has_suffix = true;
+                        break;
+                    }
+                }
+                if format.required_base_suffix() && !is_suffix {
+                    return Err(Error::MissingBaseSuffix(iter.cursor() - 1));
+                }
+                // Include the base suffix in the invalid digit, without
+                // stepping past it, since the next byte may be a separator.
+                let index = iter.cursor() - 1 + is_suffix as usize;
+                if !is_partial {
+                    return Err(Error::InvalidDigit(index));
+                }
+                let count = iter.current_count() - start_count - 1;
+                return into_value(value, count, index, radix, is_negative);
+            },
+        };
+        value = value.wrapping_mul(as_cast(radix));
+        value = value.wrapping_add(as_cast(digit));
+    }
+
+    if format.required_base_suffix() && !has_suffix {
+        return Err(Error::MissingBaseSuffix(iter.cursor()));
+    }
+    // The base suffix is not a digit, so don't count it for overflow.
+    let count = iter.current_count() - start_count - has_suffix as usize;
+    into_value(value, count, iter.length(), radix, is_negative)
+}
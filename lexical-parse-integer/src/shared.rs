@@ -58,10 +58,11 @@ macro_rules! invalid_digit_complete {
         $is_negative:ident,
         $start_index:ident,
         $t:ident,
-        $u:ident
+        $u:ident,
+        $index:expr
     ) => {{
         // Don't do any overflow checking here: we don't need it.
-        into_error!(InvalidDigit, $iter.cursor() - 1)
+        into_error!(InvalidDigit, $index)
     }};
 }
 
@@ -75,7 +76,8 @@ macro_rules! invalid_digit_partial {
         $is_negative:ident,
         $start_index:ident,
         $t:ident,
-        $u:ident
+        $u:ident,
+        $index:expr
     ) => {{
         let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
         let count = $iter.current_count() - $start_index - 1;
@@ -87,9 +89,9 @@ macro_rules! invalid_digit_partial {
                 into_error!(Overflow, (count - 1).min(min + 1))
             }
         } else if <$t>::IS_SIGNED && $is_negative {
-            into_ok_partial!($value.wrapping_neg(), $iter.cursor() - 1)
+            into_ok_partial!($value.wrapping_neg(), $index)
         } else {
-            into_ok_partial!($value, $iter.cursor() - 1)
+            into_ok_partial!($value, $index)
         }
     }};
 }
@@ -152,9 +154,18 @@ where
     U: UnsignedInteger,
 {
     let format = NumberFormat::<{ FORMAT }> {};
+    is_overflow_radix::<T, U>(value, count, is_negative, format.radix())
+}
 
-    let max = max_step(format.radix(), T::BITS, T::IS_SIGNED);
-    let radix: U = as_cast(format.radix());
+/// Determine if the value has overflowed, for a radix known at runtime.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub(super) fn is_overflow_radix<T, U>(value: U, count: usize, is_negative: bool, radix: u32) -> bool
+where
+    T: Integer,
+    U: UnsignedInteger,
+{
+    let max = max_step(radix, T::BITS, T::IS_SIGNED);
+    let radix: U = as_cast(radix);
     let min_value: U = radix.pow(max as u32 - 1);
     if T::IS_SIGNED {
         // Signed type: have to deal with 2's complement.
@@ -197,8 +208,10 @@ macro_rules! parse_value {
         // improvements due to decreased branching for all but `i8`.
         let mut value = <$u>::ZERO;
         let format = NumberFormat::<{ $format }> {};
-        $parser!(value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit);
-        let count = $iter.current_count() - $start_index;
+        let has_suffix =
+            $parser!(value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit);
+        // The base suffix is not a digit, so don't count it for overflow.
+        let count = $iter.current_count() - $start_index - has_suffix as usize;
 
         if is_overflow::<$t, $u, $format>(value, count, $is_negative) {
             let min = min_step(format.radix(), <$t as Integer>::BITS, <$t>::IS_SIGNED);
@@ -235,6 +248,7 @@ macro_rules! parse_1digit {
         let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
 
         // Do our slow parsing algorithm: 1 digit at a time.
+        let mut has_suffix = false;
         while let Some(&c) = $iter.next() {
             let digit = match char_to_digit_const(c, radix) {
                 Some(v) => v,
@@ -243,25 +257,27 @@ macro_rules! parse_1digit {
                     // We can't have a base suffix at the first value (need at least
                     // 1 digit).
                     let base_suffix = format.base_suffix();
+                    let mut is_suffix = false;
                     if cfg!(feature = "format") && base_suffix != 0 && $iter.cursor() - $start_index > 1 {
-                        let is_suffix = if format.case_sensitive_base_suffix() {
+                        is_suffix = if format.case_sensitive_base_suffix() {
                             c == base_suffix
                         } else {
                             c.to_ascii_lowercase() == base_suffix.to_ascii_lowercase()
                         };
                         if is_suffix && $iter.is_done() {
                             // Break out of the loop, we've finished parsing.
+                            has_suffix = true;
                             break;
-                        } else if is_suffix {
-                            // Haven't finished parsing, so we're going to call
-                            // invalid_digit!. Need to ensure we include the
-                            // base suffix in that.
-                            // SAFETY: safe since the iterator is not empty, as checked
-                            // in `$iter.is_done()` above.
-                            unsafe { $iter.step_unchecked() };
                         }
                     }
-                    // Might have handled our base-prefix here.
+                    if cfg!(feature = "format") && format.required_base_suffix() && !is_suffix {
+                        return into_error!(MissingBaseSuffix, $iter.cursor() - 1);
+                    }
+                    // Haven't finished parsing, so include any base suffix
+                    // in the invalid digit. Don't step past the suffix, since
+                    // it isn't a digit, and the next byte may be a digit
+                    // separator.
+                    let index = $iter.cursor() - 1 + is_suffix as usize;
                     return $invalid_digit!(
                         $value,
                         $iter,
@@ -269,13 +285,18 @@ macro_rules! parse_1digit {
                         $is_negative,
                         $start_index,
                         $t,
-                        $u
+                        $u,
+                        index
                     );
                 },
             };
             $value = $value.wrapping_mul(as_cast(radix));
             $value = $value.wrapping_add(as_cast(digit));
         }
+        if cfg!(feature = "format") && format.required_base_suffix() && !has_suffix {
+            return into_error!(MissingBaseSuffix, $iter.cursor());
+        }
+        has_suffix
     }};
 }
 
@@ -355,6 +376,9 @@ macro_rules! algorithm {
                 }
             }
         }
+        if cfg!(feature = "format") && format.required_base_prefix() && !is_prefix {
+            return into_error!(MissingBasePrefix, iter.cursor() - zeros);
+        }
 
         // If we have a format that doesn't accept leading zeros,
         // check if the next value is invalid. It's invalid if the
@@ -373,6 +397,22 @@ macro_rules! algorithm {
             };
         }
 
+        // A base suffix directly after the leading zeros, IE, `0h`, is
+        // not seen by the digit parser, which requires at least 1 digit.
+        let base_suffix = format.base_suffix();
+        if cfg!(feature = "format") && base_suffix != 0 && !is_prefix && zeros != 0 {
+            if let Some(&c) = iter.peek() {
+                let is_suffix = if format.case_sensitive_base_suffix() {
+                    c == base_suffix
+                } else {
+                    c.eq_ignore_ascii_case(&base_suffix)
+                };
+                if is_suffix && iter.as_slice().len() == 1 {
+                    return $into_ok!(<$t>::ZERO, iter.length());
+                }
+            }
+        }
+
         //  NOTE:
         //      Don't add optimizations for 128-bit integers.
         //      128-bit multiplication is rather efficient, it's only division
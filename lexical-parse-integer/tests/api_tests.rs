@@ -147,6 +147,58 @@ fn options_test() {
     assert_eq!(Ok(0), i128::from_lexical_with_options::<STANDARD>(b"0", &options));
 }
 
+#[test]
+fn parentheses_negative_test() {
+    let options = Options::builder().parentheses_negative(true).build().unwrap();
+    assert_eq!(Ok(-15), i32::from_lexical_with_options::<STANDARD>(b"(15)", &options));
+    assert_eq!(Ok(15), i32::from_lexical_with_options::<STANDARD>(b"15", &options));
+    assert_eq!(Ok(-128), i8::from_lexical_with_options::<STANDARD>(b"(128)", &options));
+    assert_eq!(Ok(0), u8::from_lexical_with_options::<STANDARD>(b"(0)", &options));
+    assert_eq!(
+        Err(Error::Underflow(3)),
+        i8::from_lexical_with_options::<STANDARD>(b"(129)", &options)
+    );
+    assert_eq!(
+        Err(Error::Underflow(1)),
+        u8::from_lexical_with_options::<STANDARD>(b"(5)", &options)
+    );
+    assert_eq!(
+        Err(Error::Underflow(4)),
+        i8::from_lexical_with_options::<STANDARD>(b"(1290)", &options)
+    );
+    assert_eq!(
+        Err(Error::Underflow(5)),
+        i16::from_lexical_with_options::<STANDARD>(b"(32769)", &options)
+    );
+    assert_eq!(
+        Err(Error::Underflow(6)),
+        i16::from_lexical_with_options::<STANDARD>(b"(655360)", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(1)),
+        i32::from_lexical_with_options::<STANDARD>(b"(-1)", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(3)),
+        i32::from_lexical_with_options::<STANDARD>(b"(15", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(4)),
+        i32::from_lexical_with_options::<STANDARD>(b"(15))", &options)
+    );
+    assert_eq!(Err(Error::Empty(1)), i32::from_lexical_with_options::<STANDARD>(b"(", &options));
+    assert_eq!(
+        Ok((-15, 4)),
+        i32::from_lexical_partial_with_options::<STANDARD>(b"(15) ", &options)
+    );
+
+    let options = Options::new();
+    assert_eq!(
+        Err(Error::InvalidDigit(0)),
+        i32::from_lexical_with_options::<STANDARD>(b"(15)", &options)
+    );
+}
+
 #[test]
 #[cfg(feature = "power-of-two")]
 fn i32_binary_test() {
@@ -203,6 +255,22 @@ fn radix_test() {
     radix_to_u32::<{ from_radix(36) }>(b"11", 37);
 }
 
+#[test]
+#[cfg(feature = "radix")]
+fn radix62_test() {
+    radix_to_u32::<{ from_radix(37) }>(b"10", 37);
+    radix_to_u32::<{ from_radix(62) }>(b"a", 10);
+    radix_to_u32::<{ from_radix(62) }>(b"A", 36);
+    radix_to_u32::<{ from_radix(62) }>(b"Z", 61);
+    radix_to_u32::<{ from_radix(62) }>(b"4GFfc3", u32::MAX);
+
+    let options = Options::new();
+    let result = u32::from_lexical_with_options::<{ from_radix(61) }>(b"Z", &options);
+    assert_eq!(result, Err(Error::InvalidDigit(0)));
+    let result = u32::from_lexical_with_options::<{ from_radix(62) }>(b"4GFfc4", &options);
+    assert_eq!(result, Err(Error::Overflow(5)));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn i32_no_leading_zeros_test() {
@@ -331,6 +399,53 @@ fn base_suffix_test() {
 
     assert!(i32::from_lexical_partial_with_options::<FORMAT>(b"-0x012h", &options).is_ok());
     assert!(i32::from_lexical_partial_with_options::<FORMAT>(b"-0x012h ", &options).is_ok());
+
+    // The suffix is not a digit, and must not cause overflow.
+    assert_eq!(u8::from_lexical_with_options::<FORMAT>(b"255h", &options), Ok(255));
+    assert_eq!(i8::from_lexical_with_options::<FORMAT>(b"-128h", &options), Ok(-128));
+    assert_eq!(u8::from_lexical_with_options::<FORMAT>(b"256h", &options), Err(Error::Overflow(2)));
+    assert_eq!(
+        i64::from_lexical_with_options::<FORMAT>(b"-9223372036854775808h", &options),
+        Ok(i64::MIN)
+    );
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn base_suffix_partial_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .base_suffix(num::NonZeroU8::new(b'L'))
+        .build();
+    let options = Options::new();
+
+    // The base suffix is not counted as a digit, nor is a following separator.
+    assert_eq!(u8::from_lexical_partial_with_options::<FORMAT>(b"99L0", &options), Ok((99, 3)));
+    assert_eq!(u8::from_lexical_partial_with_options::<FORMAT>(b"1L_0", &options), Ok((1, 2)));
+    assert_eq!(u8::from_lexical_partial_with_options::<FORMAT>(b"1_2L_", &options), Ok((12, 4)));
+    assert_eq!(
+        u8::from_lexical_with_options::<FORMAT>(b"1L_0", &options),
+        Err(Error::InvalidDigit(2))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn r_literal_test() {
+    use lexical_util::format::R_LITERAL;
+
+    let options = Options::new();
+    assert_eq!(i32::from_lexical_with_options::<R_LITERAL>(b"100L", &options), Ok(100));
+    assert_eq!(i32::from_lexical_with_options::<R_LITERAL>(b"-5L", &options), Ok(-5));
+    assert_eq!(i32::from_lexical_with_options::<R_LITERAL>(b"0L", &options), Ok(0));
+    assert_eq!(i32::from_lexical_with_options::<R_LITERAL>(b"100", &options), Ok(100));
+    assert_eq!(
+        i32::from_lexical_with_options::<R_LITERAL>(b"100l", &options),
+        Err(Error::InvalidDigit(3))
+    );
 }
 
 #[test]
@@ -352,6 +467,90 @@ fn base_prefix_and_suffix_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+0x", &options).is_err());
 }
 
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn required_base_prefix_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .required_base_prefix(true)
+        .build();
+    let options = Options::new();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"0x12", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"0X12", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-0x12", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"12", &options),
+        Err(Error::MissingBasePrefix(0))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"-12", &options),
+        Err(Error::MissingBasePrefix(1))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"012", &options),
+        Err(Error::MissingBasePrefix(0))
+    );
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"12", &options),
+        Err(Error::MissingBasePrefix(0))
+    );
+
+    const CASE_SENSITIVE: u128 =
+        NumberFormatBuilder::rebuild(FORMAT).case_sensitive_base_prefix(true).build();
+    assert_eq!(i32::from_lexical_with_options::<CASE_SENSITIVE>(b"0x12", &options), Ok(12));
+    assert_eq!(
+        i32::from_lexical_with_options::<CASE_SENSITIVE>(b"0X12", &options),
+        Err(Error::MissingBasePrefix(0))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn required_base_suffix_test() {
+    use core::num;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .base_suffix(num::NonZeroU8::new(b'h'))
+        .required_base_suffix(true)
+        .build();
+    let options = Options::new();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"12h", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"12H", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"0h", &options), Ok(0));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-12h", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"12", &options),
+        Err(Error::MissingBaseSuffix(2))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"0", &options),
+        Err(Error::MissingBaseSuffix(1))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"12 ", &options),
+        Err(Error::MissingBaseSuffix(2))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"12h ", &options),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(i32::from_lexical_partial_with_options::<FORMAT>(b"12h", &options), Ok((12, 3)));
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"12 ", &options),
+        Err(Error::MissingBaseSuffix(2))
+    );
+
+    const CASE_SENSITIVE: u128 =
+        NumberFormatBuilder::rebuild(FORMAT).case_sensitive_base_suffix(true).build();
+    assert_eq!(i32::from_lexical_with_options::<CASE_SENSITIVE>(b"12h", &options), Ok(12));
+    assert_eq!(
+        i32::from_lexical_with_options::<CASE_SENSITIVE>(b"12H", &options),
+        Err(Error::MissingBaseSuffix(2))
+    );
+}
+
 macro_rules! is_error {
     ($result:expr, $check:ident) => {{
         let result = $result;
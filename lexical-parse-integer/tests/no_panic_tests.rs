@@ -0,0 +1,60 @@
+// These tests only link if the parsers have no reachable panics, which
+// requires optimizations: run with `cargo test --release --features no-panic`.
+// The wrappers are never inlined, so constant arguments cannot hide a panic.
+#![cfg(all(feature = "no-panic", not(debug_assertions)))]
+
+use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+use no_panic::no_panic;
+
+#[no_panic]
+#[inline(never)]
+fn parse_u8(bytes: &[u8]) -> Result<u8> {
+    u8::from_lexical(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_i32(bytes: &[u8]) -> Result<i32> {
+    i32::from_lexical(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_u64(bytes: &[u8]) -> Result<u64> {
+    u64::from_lexical(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_i128(bytes: &[u8]) -> Result<i128> {
+    i128::from_lexical(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_partial_i64(bytes: &[u8]) -> Result<(i64, usize)> {
+    i64::from_lexical_partial(bytes)
+}
+
+#[no_panic]
+#[inline(never)]
+fn parse_options_u64(bytes: &[u8], options: &Options) -> Result<u64> {
+    u64::from_lexical_with_options::<STANDARD>(bytes, options)
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(parse_u8(b"255"), Ok(255));
+    assert!(parse_u8(b"256").is_err());
+    assert_eq!(parse_i32(b"-2147483648"), Ok(i32::MIN));
+    assert_eq!(parse_u64(b"18446744073709551615"), Ok(u64::MAX));
+    assert_eq!(parse_i128(b"-1"), Ok(-1));
+    assert_eq!(parse_partial_i64(b"-15x"), Ok((-15, 3)));
+    assert!(parse_i32(b"15x").is_err());
+    assert!(parse_i32(b"").is_err());
+
+    let options = Options::new();
+    assert_eq!(parse_options_u64(b"1234", &options), Ok(1234));
+}
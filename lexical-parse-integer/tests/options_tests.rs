@@ -15,3 +15,28 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+fn builder_tests() {
+    let builder = OptionsBuilder::new().parentheses_negative(true);
+    assert!(builder.get_parentheses_negative());
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert!(options.parentheses_negative());
+    unsafe { options.set_parentheses_negative(false) };
+    assert_eq!(options, Options::new());
+}
+
+#[test]
+fn override_test() {
+    use lexical_parse_integer::options::OptionsOverride;
+    use lexical_util::options::OverrideOptions;
+
+    let options = Options::new();
+    let overrides = OptionsOverride::new().parentheses_negative(true);
+    let result = options.with_override(&overrides).unwrap();
+    assert!(result.parentheses_negative());
+    assert!(!options.parentheses_negative());
+    assert_eq!(options.with_override(&OptionsOverride::new()), Ok(options));
+}
@@ -0,0 +1,280 @@
+#[cfg(feature = "power-of-two")]
+mod util;
+
+use lexical_parse_integer::{FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{Format, STANDARD};
+use lexical_util::strategy;
+use proptest::prelude::*;
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+const INPUTS: &[&[u8]] = &[
+    b"",
+    b"0",
+    b"1",
+    b"-1",
+    b"+1",
+    b"-",
+    b"+",
+    b"00",
+    b"01",
+    b"007",
+    b"10",
+    b"127",
+    b"128",
+    b"-128",
+    b"-129",
+    b"255",
+    b"256",
+    b"65535",
+    b"-32769",
+    b"2147483647",
+    b"-2147483648",
+    b"4294967296",
+    b"18446744073709551615",
+    b"18446744073709551616",
+    b"99999999999999999999999999999999999999999",
+    b"1a",
+    b"a1",
+    b"1.0",
+    b"1e5",
+    b"12 ",
+    b" 12",
+    b"(12)",
+    b"(-12)",
+    b"(128)",
+    b"(129)",
+    b"()",
+    b"(12",
+    b"0x",
+    b"0x1",
+    b"-0x12",
+    b"0x12h",
+    b"12h",
+    b"12hh",
+    b"0b101",
+    b"0o17",
+    b"h",
+    b"0h",
+    b"1_2",
+    b"12_",
+    b"1__2",
+    b"1_",
+    b"1_h",
+    b"ff",
+    b"FF",
+    b"zz",
+    b"0xFF",
+    b"0XFF",
+    b"1H",
+    b"1'2",
+    b"-0",
+    b"+0",
+    b"--1",
+    b"+-1",
+];
+
+/// Assert the runtime parsers match the const generic parsers for every input.
+macro_rules! assert_runtime_eq {
+    ($format:ident, $options:expr ; $($t:ty)*) => {{
+        let format = Format::new($format).unwrap();
+        let options = $options;
+        for &bytes in INPUTS {
+            $(
+                assert_eq!(
+                    <$t>::from_lexical_with_options::<$format>(bytes, &options),
+                    <$t>::from_lexical_with_format(bytes, &format, &options),
+                    "{:?}", bytes
+                );
+                assert_eq!(
+                    <$t>::from_lexical_partial_with_options::<$format>(bytes, &options),
+                    <$t>::from_lexical_partial_with_format(bytes, &format, &options),
+                    "{:?}", bytes
+                );
+            )*
+        }
+    }};
+}
+
+/// Assert the runtime parsers match for the default and accounting options.
+macro_rules! assert_format_eq {
+    ($format:ident) => {{
+        let parentheses = Options::builder().parentheses_negative(true).build().unwrap();
+        assert_runtime_eq!($format, Options::new() ; u8 i8 u16 i16 u32 i32 u64 i64 u128 i128);
+        assert_runtime_eq!($format, parentheses ; u8 i8 u32 i32 u64 i64);
+    }};
+}
+
+#[test]
+fn standard_runtime_test() {
+    let format = Format::new(STANDARD).unwrap();
+    let options = Options::new();
+    assert_eq!(i32::from_lexical_with_format(b"-1234", &format, &options), Ok(-1234));
+    assert_eq!(u8::from_lexical_with_format(b"256", &format, &options), Err(Error::Overflow(2)));
+    assert_eq!(u8::from_lexical_partial_with_format(b"25x", &format, &options), Ok((25, 2)));
+    assert_format_eq!(STANDARD);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_runtime_test() {
+    const BINARY: u128 = from_radix(2);
+    const HEX: u128 = from_radix(16);
+    let format = Format::new(HEX).unwrap();
+    assert_eq!(i32::from_lexical_with_format(b"-FF", &format, &Options::new()), Ok(-255));
+    assert_format_eq!(BINARY);
+    assert_format_eq!(HEX);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn odd_radix_runtime_test() {
+    const BASE3: u128 = from_radix(3);
+    const BASE36: u128 = from_radix(36);
+    assert_format_eq!(BASE3);
+    assert_format_eq!(BASE36);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_runtime_test() {
+    const SEPARATOR: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    const LEADING: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_leading_digit_separator(true)
+        .integer_consecutive_digit_separator(true)
+        .build();
+    const TRAILING: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_trailing_digit_separator(true)
+        .build();
+    const ALL: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .integer_leading_digit_separator(true)
+        .integer_trailing_digit_separator(true)
+        .integer_consecutive_digit_separator(true)
+        .build();
+    const SIGNS: u128 = NumberFormatBuilder::new()
+        .no_positive_mantissa_sign(true)
+        .no_integer_leading_zeros(true)
+        .build();
+    const REQUIRED_SIGN: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+
+    let format = Format::new(SEPARATOR).unwrap();
+    assert_eq!(i32::from_lexical_with_format(b"1_234", &format, &Options::new()), Ok(1234));
+    assert_eq!(
+        i32::from_lexical_with_format(b"_1", &format, &Options::new()),
+        Err(Error::InvalidDigit(0))
+    );
+
+    // Digit separators before the first significant digit are not digits.
+    let format = Format::new(LEADING).unwrap();
+    assert_eq!(i8::from_lexical_with_format(b"_12", &format, &Options::new()), Ok(12));
+    assert_eq!(i8::from_lexical_with_format(b"-__127", &format, &Options::new()), Ok(-127));
+    assert_eq!(
+        i8::from_lexical_with_format(b"__128", &format, &Options::new()),
+        Err(Error::Overflow(2))
+    );
+    let format = Format::new(ALL).unwrap();
+    assert_eq!(i8::from_lexical_with_format(b"0_0_1_2_", &format, &Options::new()), Ok(12));
+    assert_eq!(
+        u8::from_lexical_partial_with_format(b"_0_25_5x", &format, &Options::new()),
+        Ok((255, 7))
+    );
+    assert_format_eq!(SEPARATOR);
+    assert_format_eq!(LEADING);
+    assert_format_eq!(TRAILING);
+    assert_format_eq!(ALL);
+    assert_format_eq!(SIGNS);
+    assert_format_eq!(REQUIRED_SIGN);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn affix_runtime_test() {
+    const PREFIX: u128 = NumberFormatBuilder::new()
+        .radix(16)
+        .base_prefix(std::num::NonZeroU8::new(b'x'))
+        .base_suffix(std::num::NonZeroU8::new(b'h'))
+        .build();
+    const CASE_SENSITIVE: u128 = NumberFormatBuilder::rebuild(PREFIX)
+        .case_sensitive_base_prefix(true)
+        .case_sensitive_base_suffix(true)
+        .build();
+    const REQUIRED: u128 = NumberFormatBuilder::rebuild(CASE_SENSITIVE)
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .build();
+    const SEPARATOR: u128 = NumberFormatBuilder::rebuild(PREFIX)
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .integer_trailing_digit_separator(true)
+        .build();
+
+    let format = Format::new(PREFIX).unwrap();
+    assert_eq!(i32::from_lexical_with_format(b"-0x1Fh", &format, &Options::new()), Ok(-31));
+    assert_eq!(u8::from_lexical_with_format(b"0xFFh", &format, &Options::new()), Ok(255));
+    assert_format_eq!(PREFIX);
+    assert_format_eq!(CASE_SENSITIVE);
+    assert_format_eq!(SEPARATOR);
+    assert_format_eq!(REQUIRED);
+}
+
+#[test]
+fn invalid_format_test() {
+    assert!(Format::new(0).is_err());
+}
+
+#[cfg(feature = "format")]
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn runtime_proptest(s in r"[+\-(]?[1-9][0-9_]{0,24}[)h]?") {
+        const FORMAT: u128 = NumberFormatBuilder::new()
+            .digit_separator(std::num::NonZeroU8::new(b'_'))
+            .integer_internal_digit_separator(true)
+            .integer_trailing_digit_separator(true)
+            .build();
+        let format = Format::new(FORMAT).unwrap();
+        let options = Options::builder().parentheses_negative(true).build().unwrap();
+        let bytes = s.as_bytes();
+        prop_assert_eq!(
+            i64::from_lexical_partial_with_options::<FORMAT>(bytes, &options),
+            i64::from_lexical_partial_with_format(bytes, &format, &options)
+        );
+        prop_assert_eq!(
+            u32::from_lexical_with_options::<FORMAT>(bytes, &options),
+            u32::from_lexical_with_format(bytes, &format, &options)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn strategy_proptest((format, s) in strategy::format().prop_flat_map(|format| {
+        (Just(format), strategy::integer(format, 18))
+    })) {
+        let options = Options::new();
+        let result = i128::from_lexical_with_format(s.as_bytes(), &format, &options);
+        prop_assert!(result.is_ok(), "{:?} {:?}: {:?}", format, s, result);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn near_valid_proptest((format, s) in strategy::format().prop_flat_map(|format| {
+        (Just(format), strategy::near_valid(strategy::integer(format, 18)))
+    })) {
+        let options = Options::new();
+        if let Ok((_, count)) = i128::from_lexical_partial_with_format(s.as_bytes(), &format, &options) {
+            prop_assert!(count <= s.len());
+        }
+    }
+}
@@ -101,6 +101,46 @@ macro_rules! from_lexical_with_options {
                 bytes: &[u8],
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>;
+
+            /// Checked parser for a string-to-number conversion, using a
+            /// number format known at runtime.
+            ///
+            /// This method parses the entire string, returning an error if
+            /// any invalid digits are found during parsing. This is identical
+            /// to [`from_lexical_with_options`], however, the number grammar
+            /// is read from `format`, rather than the `FORMAT` const generic.
+            ///
+            /// * `bytes`   - Slice containing a numeric string.
+            /// * `format`  - Validated flags and characters designating the number grammar.
+            /// * `options` - Options to dictate number parsing.
+            ///
+            /// [`from_lexical_with_options`]: Self::from_lexical_with_options
+            fn from_lexical_with_format(
+                bytes: &[u8],
+                format: &lexical_util::format::Format,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self>;
+
+            /// Checked parser for a string-to-number conversion, using a
+            /// number format known at runtime.
+            ///
+            /// This method parses until an invalid digit is found (or the end
+            /// of the string), returning the number of processed digits
+            /// and the parsed value until that point. This is identical to
+            /// [`from_lexical_partial_with_options`], however, the number
+            /// grammar is read from `format`, rather than the `FORMAT`
+            /// const generic.
+            ///
+            /// * `bytes`   - Slice containing a numeric string.
+            /// * `format`  - Validated flags and characters designating the number grammar.
+            /// * `options` - Options to dictate number parsing.
+            ///
+            /// [`from_lexical_partial_with_options`]: Self::from_lexical_partial_with_options
+            fn from_lexical_partial_with_format(
+                bytes: &[u8],
+                format: &lexical_util::format::Format,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>;
         }
     };
 }
@@ -288,6 +328,41 @@ macro_rules! to_lexical_with_options {
                 bytes: &'a mut [u8],
                 options: &Self::Options,
             ) -> &'a mut [u8];
+
+            /// Serializer for a number-to-string conversion, using a number
+            /// format known at runtime.
+            ///
+            /// Returns a subslice of the input buffer containing the written bytes,
+            /// starting from the same address in memory as the input slice.
+            /// This is identical to [`to_lexical_with_options`], however, the
+            /// number grammar is read from `format`, rather than the `FORMAT`
+            /// const generic.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `format`  - Validated flags and characters designating the number grammar.
+            /// * `options` - Options for number formatting.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not of sufficient size. In order to
+            /// ensure the function will not panic, ensure the buffer has at
+            /// least [`WriteOptions::buffer_size_with_format`] elements.
+            ///
+            /// **Floats Only**
+            ///
+            /// Also panics if the mantissa radix is not equal to the exponent
+            /// base and the mantissa radix/exponent base combination is not
+            /// supported, as for [`to_lexical_with_options`].
+            ///
+            /// [`to_lexical_with_options`]: Self::to_lexical_with_options
+            /// [`WriteOptions::buffer_size_with_format`]: lexical_util::options::WriteOptions::buffer_size_with_format
+            fn to_lexical_with_format<'a>(
+                self,
+                bytes: &'a mut [u8],
+                format: &lexical_util::format::Format,
+                options: &Self::Options,
+            ) -> &'a mut [u8];
         }
     };
 }
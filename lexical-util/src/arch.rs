@@ -0,0 +1,62 @@
+//! Platform-specific, assembly instructions for wide multiplication.
+//!
+//! On some architectures, the compiler emits a slower sequence for the
+//! high bits of a 64-bit multiplication than the native instruction,
+//! `umulh` on aarch64 and `mulhu` on RISC-V. Other architectures use
+//! 128-bit multiplication, which the compiler handles well.
+//!
+//! The `asm!` macro was stabilized in 1.59.0, so this requires Rust 1.59+.
+
+#![cfg(feature = "asm")]
+
+#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+use core::arch::asm;
+
+/// Get the high 64 bits of the product of two 64-bit integers.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+pub fn mulhi64(x: u64, y: u64) -> u64 {
+    let hi: u64;
+    // SAFETY: `umulh` only reads and writes registers, and is valid for any input.
+    unsafe {
+        asm!(
+            "umulh {hi}, {x}, {y}",
+            hi = lateout(reg) hi,
+            x = in(reg) x,
+            y = in(reg) y,
+            options(pure, nomem, nostack, preserves_flags),
+        )
+    };
+    hi
+}
+
+/// Get the high 64 bits of the product of two 64-bit integers.
+#[inline(always)]
+#[cfg(target_arch = "riscv64")]
+pub fn mulhi64(x: u64, y: u64) -> u64 {
+    let hi: u64;
+    // SAFETY: `mulhu` only reads and writes registers, and is valid for any input.
+    unsafe {
+        asm!(
+            "mulhu {hi}, {x}, {y}",
+            hi = lateout(reg) hi,
+            x = in(reg) x,
+            y = in(reg) y,
+            options(pure, nomem, nostack, preserves_flags),
+        )
+    };
+    hi
+}
+
+/// Get the high 64 bits of the product of two 64-bit integers.
+#[inline(always)]
+#[cfg(not(any(target_arch = "aarch64", target_arch = "riscv64")))]
+pub fn mulhi64(x: u64, y: u64) -> u64 {
+    ((x as u128 * y as u128) >> 64) as u64
+}
+
+/// Multiply two 64-bit integers, returning the (low, high) components.
+#[inline(always)]
+pub fn mul64(x: u64, y: u64) -> (u64, u64) {
+    (x.wrapping_mul(y), mulhi64(x, y))
+}
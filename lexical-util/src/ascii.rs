@@ -43,3 +43,105 @@ pub const fn is_valid_letter_slice(slc: &[u8]) -> bool {
     }
     true
 }
+
+// CASE-INSENSITIVE
+// ----------------
+
+/// Determine if two characters are equal without ASCII case-sensitivity.
+///
+/// This uses the fact that [A-Z] is 0x41-0x5A, while [a-z] is 0x61-0x7A,
+/// so the xor of case-insensitive equal letters must be 0 or 32.
+#[inline(always)]
+pub const fn case_insensitive_eq(x: u8, y: u8) -> bool {
+    let xor = x ^ y;
+    xor == 0 || (xor == 0x20 && is_valid_letter(x))
+}
+
+/// Determine if two slices are equal without ASCII case-sensitivity.
+/// Modified to be used in a const fn, since for loops and iter don't work.
+pub const fn case_insensitive_eq_slice(x: &[u8], y: &[u8]) -> bool {
+    x.len() == y.len() && case_insensitive_starts_with_slice(x, y)
+}
+
+/// Check if the left slice starts with the right slice without case-sensitivity.
+/// Modified to be used in a const fn, since for loops and iter don't work.
+pub const fn case_insensitive_starts_with_slice(x: &[u8], y: &[u8]) -> bool {
+    if x.len() < y.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < y.len() {
+        if !case_insensitive_eq(x[index], y[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+// STARTS WITH
+// -----------
+
+/// Check if left iter starts with right iter.
+///
+/// This optimizes decently well, to the following ASM for pure slices:
+///
+/// ```text
+/// starts_with_slc:
+///         xor     eax, eax
+/// .LBB0_1:
+///         cmp     rcx, rax
+///         je      .LBB0_2
+///         cmp     rsi, rax
+///         je      .LBB0_5
+///         movzx   r8d, byte ptr [rdi + rax]
+///         lea     r9, [rax + 1]
+///         cmp     r8b, byte ptr [rdx + rax]
+///         mov     rax, r9
+///         je      .LBB0_1
+/// .LBB0_5:
+///         xor     eax, eax
+///         ret
+/// .LBB0_2:
+///         mov     al, 1
+///         ret
+/// ```
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn starts_with<'a, 'b, Iter1, Iter2>(mut x: Iter1, mut y: Iter2) -> bool
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'b u8>,
+{
+    loop {
+        // Only call `next()` on x if y is not None, otherwise,
+        // we may incorrectly consume an x character.
+        let yi = y.next();
+        if yi.is_none() {
+            return true;
+        } else if x.next() != yi {
+            return false;
+        }
+    }
+}
+
+/// Check if left iter starts with right iter without case-sensitivity.
+///
+/// This is used to match special strings, such as `NaN` or `inf`, and
+/// compares each character with [`case_insensitive_eq`].
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn case_insensitive_starts_with<'a, 'b, Iter1, Iter2>(mut x: Iter1, mut y: Iter2) -> bool
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'b u8>,
+{
+    loop {
+        let yi = match y.next() {
+            Some(&yi) => yi,
+            None => return true,
+        };
+        match x.next() {
+            Some(&xi) if case_insensitive_eq(xi, yi) => (),
+            _ => return false,
+        }
+    }
+}
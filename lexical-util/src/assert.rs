@@ -6,11 +6,11 @@ use crate::format::NumberFormat;
 
 // RADIX
 
-/// Check radix is in range `[2, 36]` in debug builds.
+/// Check radix is in range `[2, 62]` in debug builds.
 #[inline]
 #[cfg(feature = "radix")]
 pub fn debug_assert_radix(radix: u32) {
-    debug_assert!((2..=36).contains(&radix), "Numerical base must be from 2-36.");
+    debug_assert!((2..=62).contains(&radix), "Numerical base must be from 2-62.");
 }
 
 /// Check radix is is 10 or a power of 2.
@@ -0,0 +1,1798 @@
+//! Fixed-capacity, stack-allocated big integers.
+//!
+//! This provides a minimal stack vector of limbs, along with the
+//! arithmetic routines to use it as a big integer: addition,
+//! multiplication, shifts, comparison, and a specialized division
+//! for peeling off single digits. None of these routines allocate,
+//! and fallible operations return `None` if the result would exceed
+//! the vector's capacity.
+
+#![cfg(feature = "bigint")]
+
+#[cfg(all(
+    feature = "asm",
+    target_pointer_width = "64",
+    not(target_arch = "sparc"),
+    not(feature = "wasm")
+))]
+use crate::arch::mul64;
+use crate::digit::{char_to_digit_const, digit_to_char_const};
+use crate::num::{AsCast, AsPrimitive};
+use core::{cmp, fmt, iter, ops};
+#[cfg(not(miri))]
+use core::{mem, ptr, slice};
+
+// INDEX
+// -----
+
+/// Assert a condition when the `safe` feature is enabled, and otherwise
+/// only in debug builds.
+#[cfg(feature = "safe")]
+macro_rules! safe_assert {
+    ($cond:expr $(,)?) => {
+        assert!($cond);
+    };
+}
+
+/// Assert a condition when the `safe` feature is enabled, and otherwise
+/// only in debug builds.
+#[cfg(not(feature = "safe"))]
+macro_rules! safe_assert {
+    ($cond:expr $(,)?) => {
+        debug_assert!($cond);
+    };
+}
+
+/// Index a buffer, without bounds checking.
+#[cfg(not(feature = "safe"))]
+macro_rules! index_unchecked {
+    ($x:ident[$i:expr]) => {
+        *$x.get_unchecked($i)
+    };
+}
+
+/// Index a buffer and get a mutable reference, without bounds checking.
+#[cfg(not(feature = "safe"))]
+macro_rules! index_unchecked_mut {
+    ($x:ident[$i:expr]) => {
+        *$x.get_unchecked_mut($i)
+    };
+}
+
+/// Index a buffer, with bounds checking.
+#[cfg(feature = "safe")]
+macro_rules! index_unchecked {
+    ($x:ident[$i:expr]) => {
+        $x[$i]
+    };
+}
+
+/// Index a buffer and get a mutable reference, with bounds checking.
+#[cfg(feature = "safe")]
+macro_rules! index_unchecked_mut {
+    ($x:ident[$i:expr]) => {
+        $x[$i]
+    };
+}
+
+// VEC
+// ---
+
+/// Simple stack vector implementation.
+///
+/// When run under Miri (`cfg(miri)`), this uses an initialized buffer and
+/// safe indexing, rather than uninitialized memory and raw pointers, so
+/// verification tools can check the arithmetic without false positives.
+/// This is slower, so it is only meant for verification builds.
+#[derive(Clone)]
+pub struct StackVec<const SIZE: usize> {
+    /// The raw buffer for the elements.
+    #[cfg(not(miri))]
+    data: [mem::MaybeUninit<Limb>; SIZE],
+    /// The initialized buffer for the elements.
+    #[cfg(miri)]
+    data: [Limb; SIZE],
+    /// The number of elements in the array (we never need more than u16::MAX).
+    length: u16,
+}
+
+impl<const SIZE: usize> StackVec<SIZE> {
+    /// Construct an empty vector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            length: 0,
+            #[cfg(not(miri))]
+            data: [mem::MaybeUninit::uninit(); SIZE],
+            #[cfg(miri)]
+            data: [0; SIZE],
+        }
+    }
+
+    /// Get a mutable ptr to the current start of the big integer.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut Limb {
+        self.data.as_mut_ptr().cast::<Limb>()
+    }
+
+    /// Get a ptr to the current start of the big integer.
+    #[inline]
+    pub fn as_ptr(&self) -> *const Limb {
+        self.data.as_ptr().cast::<Limb>()
+    }
+
+    /// Construct a vector from an existing slice.
+    #[inline]
+    pub fn try_from(x: &[Limb]) -> Option<Self> {
+        let mut vec = Self::new();
+        vec.try_extend(x)?;
+        Some(vec)
+    }
+
+    /// Sets the length of a vector.
+    ///
+    /// This will explicitly set the size of the vector, without actually
+    /// modifying its buffers, so it is up to the caller to ensure that the
+    /// vector is actually the specified size.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as `len` is less than `SIZE`.
+    #[inline]
+    pub unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= u16::MAX as usize);
+        safe_assert!(len <= SIZE);
+        self.length = len as u16;
+    }
+
+    /// The number of elements stored in the vector.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    /// If the vector is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of items the vector can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    /// Append an item to the vector, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Safe if `self.len() < self.capacity()`.
+    #[inline]
+    pub unsafe fn push_unchecked(&mut self, value: Limb) {
+        safe_assert!(self.len() < self.capacity());
+        // SAFETY: safe, capacity is less than the current size.
+        #[cfg(not(miri))]
+        unsafe {
+            let len = self.len();
+            let ptr = self.as_mut_ptr().add(len);
+            ptr.write(value);
+        }
+        #[cfg(miri)]
+        {
+            self.data[self.len()] = value;
+        }
+        self.length += 1;
+    }
+
+    /// Append an item to the vector.
+    #[inline]
+    pub fn try_push(&mut self, value: Limb) -> Option<()> {
+        if self.len() < self.capacity() {
+            // SAFETY: safe, capacity is less than the current size.
+            unsafe { self.push_unchecked(value) };
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Append an item to the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is full. Use [`try_push`] to handle overflow.
+    ///
+    /// [`try_push`]: Self::try_push
+    #[inline]
+    pub fn push(&mut self, value: Limb) {
+        self.try_push(value).expect("StackVec::push() overflows the capacity.");
+    }
+
+    /// Remove an item from the end of a vector, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Safe if `self.len() > 0`.
+    #[inline]
+    pub unsafe fn pop_unchecked(&mut self) -> Limb {
+        safe_assert!(!self.is_empty());
+        // SAFETY: safe if `self.length > 0`.
+        // We have a trivial drop and copy, so this is safe.
+        self.length -= 1;
+        #[cfg(not(miri))]
+        return unsafe { ptr::read(self.as_mut_ptr().add(self.len())) };
+
+        #[cfg(miri)]
+        return self.data[self.len()];
+    }
+
+    /// Remove an item from the end of the vector and return it, or None if empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<Limb> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: safe, since `self.len() > 0`.
+            unsafe { Some(self.pop_unchecked()) }
+        }
+    }
+
+    /// Add items from a slice to the vector, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Safe if `self.len() + slc.len() <= self.capacity()`.
+    #[inline]
+    pub unsafe fn extend_unchecked(&mut self, slc: &[Limb]) {
+        let index = self.len();
+        let new_len = index + slc.len();
+        safe_assert!(self.len() + slc.len() <= self.capacity());
+        // SAFETY: safe if `self.len() + slc.len() <= self.capacity()`.
+        #[cfg(not(miri))]
+        unsafe {
+            let src = slc.as_ptr();
+            let dst = self.as_mut_ptr().add(index);
+            ptr::copy_nonoverlapping(src, dst, slc.len());
+        }
+        #[cfg(miri)]
+        self.data[index..new_len].copy_from_slice(slc);
+        unsafe { self.set_len(new_len) };
+    }
+
+    /// Copy elements from a slice and append them to the vector.
+    #[inline]
+    pub fn try_extend(&mut self, slc: &[Limb]) -> Option<()> {
+        if self.len() + slc.len() <= self.capacity() {
+            // SAFETY: safe, since `self.len() + slc.len() <= self.capacity()`.
+            unsafe { self.extend_unchecked(slc) };
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Copy elements from a slice and append them to the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements do not fit in the vector. Use [`try_extend`]
+    /// to handle overflow.
+    ///
+    /// [`try_extend`]: Self::try_extend
+    #[inline]
+    pub fn extend(&mut self, slc: &[Limb]) {
+        self.try_extend(slc).expect("StackVec::extend() overflows the capacity.");
+    }
+
+    /// Truncate vector to new length, dropping any items after `len`.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as `len <= self.capacity()`.
+    unsafe fn truncate_unchecked(&mut self, len: usize) {
+        safe_assert!(len <= self.capacity());
+        self.length = len as u16;
+    }
+
+    /// Resize the buffer, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as `len <= self.capacity()`.
+    #[inline]
+    pub unsafe fn resize_unchecked(&mut self, len: usize, value: Limb) {
+        safe_assert!(len <= self.capacity());
+        let old_len = self.len();
+        if len > old_len {
+            // We have a trivial drop, so there's no worry here.
+            // Just, don't set the length until all values have been written,
+            // so we don't accidentally read uninitialized memory.
+
+            // SAFETY: safe if `len < self.capacity()`.
+            #[cfg(not(miri))]
+            for index in 0..len - old_len {
+                unsafe {
+                    let dst = self.as_mut_ptr().add(old_len + index);
+                    ptr::write(dst, value);
+                }
+            }
+            #[cfg(miri)]
+            self.data[old_len..len].fill(value);
+            self.length = len as u16;
+        } else {
+            // SAFETY: safe since `len < self.len()`.
+            unsafe { self.truncate_unchecked(len) };
+        }
+    }
+
+    /// Try to resize the buffer.
+    ///
+    /// If the new length is smaller than the current length, truncate
+    /// the input. If it's larger, then append elements to the buffer.
+    #[inline]
+    pub fn try_resize(&mut self, len: usize, value: Limb) -> Option<()> {
+        if len > self.capacity() {
+            None
+        } else {
+            // SAFETY: safe, since `len <= self.capacity()`.
+            unsafe { self.resize_unchecked(len, value) };
+            Some(())
+        }
+    }
+
+    /// Resize the buffer.
+    ///
+    /// If the new length is smaller than the current length, truncate
+    /// the input. If it's larger, then append elements to the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is larger than the capacity. Use [`try_resize`]
+    /// to handle overflow.
+    ///
+    /// [`try_resize`]: Self::try_resize
+    #[inline]
+    pub fn resize(&mut self, len: usize, value: Limb) {
+        self.try_resize(len, value).expect("StackVec::resize() overflows the capacity.");
+    }
+
+    // HI
+
+    /// Get the high 16 bits from the vector.
+    #[inline(always)]
+    pub fn hi16(&self) -> (u16, bool) {
+        let mut rview = self.rview();
+        match (rview.next(), rview.next()) {
+            (None, _) => (0, false),
+            (Some(&r0), None) if LIMB_BITS == 32 => u32_to_hi16_1(r0.as_u32()),
+            (Some(&r0), None) => u64_to_hi16_1(r0.as_u64()),
+            (Some(&r0), Some(&r1)) if LIMB_BITS == 32 => {
+                truncated(u32_to_hi16_2(r0.as_u32(), r1.as_u32()), rview)
+            },
+            (Some(&r0), Some(&r1)) => truncated(u64_to_hi16_2(r0.as_u64(), r1.as_u64()), rview),
+        }
+    }
+
+    /// Get the high 32 bits from the vector.
+    #[inline(always)]
+    pub fn hi32(&self) -> (u32, bool) {
+        let mut rview = self.rview();
+        match (rview.next(), rview.next()) {
+            (None, _) => (0, false),
+            (Some(&r0), None) if LIMB_BITS == 32 => u32_to_hi32_1(r0.as_u32()),
+            (Some(&r0), None) => u64_to_hi32_1(r0.as_u64()),
+            (Some(&r0), Some(&r1)) if LIMB_BITS == 32 => {
+                truncated(u32_to_hi32_2(r0.as_u32(), r1.as_u32()), rview)
+            },
+            (Some(&r0), Some(&r1)) => truncated(u64_to_hi32_2(r0.as_u64(), r1.as_u64()), rview),
+        }
+    }
+
+    /// Get the high 64 bits from the vector.
+    #[inline(always)]
+    pub fn hi64(&self) -> (u64, bool) {
+        let mut rview = self.rview();
+        if LIMB_BITS == 32 {
+            match (rview.next(), rview.next(), rview.next()) {
+                (None, _, _) => (0, false),
+                (Some(&r0), None, _) => u32_to_hi64_1(r0.as_u32()),
+                (Some(&r0), Some(&r1), None) => u32_to_hi64_2(r0.as_u32(), r1.as_u32()),
+                (Some(&r0), Some(&r1), Some(&r2)) => {
+                    truncated(u32_to_hi64_3(r0.as_u32(), r1.as_u32(), r2.as_u32()), rview)
+                },
+            }
+        } else {
+            match (rview.next(), rview.next()) {
+                (None, _) => (0, false),
+                (Some(&r0), None) => u64_to_hi64_1(r0.as_u64()),
+                (Some(&r0), Some(&r1)) => truncated(u64_to_hi64_2(r0.as_u64(), r1.as_u64()), rview),
+            }
+        }
+    }
+
+    // FROM
+
+    /// Create StackVec from u16 value.
+    #[inline(always)]
+    pub fn from_u16(x: u16) -> Self {
+        let mut vec = Self::new();
+        assert!(1 <= vec.capacity());
+        // SAFETY: safe since we can always add 1 item.
+        unsafe { vec.push_unchecked(x as Limb) };
+        vec.normalize();
+        vec
+    }
+
+    /// Create StackVec from u32 value.
+    #[inline(always)]
+    pub fn from_u32(x: u32) -> Self {
+        let mut vec = Self::new();
+        assert!(1 <= vec.capacity());
+        // SAFETY: safe since we can always add 1 item.
+        unsafe { vec.push_unchecked(x as Limb) };
+        vec.normalize();
+        vec
+    }
+
+    /// Create StackVec from u64 value.
+    #[inline(always)]
+    pub fn from_u64(x: u64) -> Self {
+        let mut vec = Self::new();
+        assert!(2 <= vec.capacity());
+        if LIMB_BITS == 32 {
+            // SAFETY: safe since we can always add 2 items.
+            unsafe {
+                vec.push_unchecked(x as Limb);
+                vec.push_unchecked((x >> 32) as Limb);
+            }
+        } else {
+            // SAFETY: safe since we can always add 1 item.
+            unsafe { vec.push_unchecked(x as Limb) };
+        }
+        vec.normalize();
+        vec
+    }
+
+    // INDEX
+
+    /// Create a reverse view of the vector, from the most-significant limb.
+    #[inline]
+    pub fn rview(&self) -> ReverseView<'_, Limb> {
+        ReverseView::new(self)
+    }
+
+    // MATH
+
+    /// Normalize the integer, so any leading zero values are removed.
+    #[inline]
+    pub fn normalize(&mut self) {
+        // We don't care if this wraps: the index is bounds-checked.
+        while let Some(&value) = self.get(self.len().wrapping_sub(1)) {
+            if value == 0 {
+                self.length -= 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Get if the big integer is normalized.
+    #[inline]
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_normalized(&self) -> bool {
+        // We don't care if this wraps: the index is bounds-checked.
+        match self.get(self.len().wrapping_sub(1)) {
+            Some(&0) => false,
+            _ => true,
+        }
+    }
+
+    /// Calculate the fast quotient for a single limb-bit quotient.
+    ///
+    /// This requires a non-normalized divisor, where there at least
+    /// `integral_binary_factor` 0 bits set, to ensure at maximum a single
+    /// digit will be produced for a single base.
+    ///
+    /// Warning: This is not a general-purpose division algorithm,
+    /// it is highly specialized for peeling off singular digits.
+    /// Returns `None` if the divisor is zero or the numerator has
+    /// more limbs than the divisor.
+    #[inline]
+    pub fn quorem(&mut self, y: &Self) -> Option<Limb> {
+        large_quorem(self, y)
+    }
+
+    /// AddAssign small integer.
+    #[inline]
+    pub fn add_small(&mut self, y: Limb) -> Option<()> {
+        small_add(self, y)
+    }
+
+    /// MulAssign small integer.
+    #[inline]
+    pub fn mul_small(&mut self, y: Limb) -> Option<()> {
+        small_mul(self, y)
+    }
+
+    /// DivAssign small integer, returning the remainder.
+    #[inline]
+    pub fn div_small(&mut self, y: Limb) -> Option<Limb> {
+        small_div(self, y)
+    }
+}
+
+impl<const SIZE: usize> Default for StackVec<SIZE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize> fmt::Debug for StackVec<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Implement hexadecimal formatting of the value, from the most-significant limb.
+macro_rules! hex_impl {
+    ($trait:ident, $hi:literal, $lo:literal) => {
+        impl<const SIZE: usize> fmt::$trait for StackVec<SIZE> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("0x")?;
+                }
+                // Skip any leading zero limbs, if the vector isn't normalized.
+                let mut limbs = self.rview().skip_while(|&&limb| limb == 0);
+                match limbs.next() {
+                    Some(hi) => {
+                        write!(f, $hi, hi)?;
+                        limbs.try_for_each(|lo| write!(f, $lo, lo, LIMB_BITS / 4))
+                    },
+                    None => f.write_str("0"),
+                }
+            }
+        }
+    };
+}
+
+hex_impl!(LowerHex, "{:x}", "{:01$x}");
+hex_impl!(UpperHex, "{:X}", "{:01$X}");
+
+impl<const SIZE: usize> iter::FromIterator<Limb> for StackVec<SIZE> {
+    /// Collect limbs into a vector.
+    ///
+    /// Panics if there are more limbs than the capacity of the vector.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Limb>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        for value in iter {
+            vec.push(value);
+        }
+        vec
+    }
+}
+
+impl<const SIZE: usize> AsRef<[Limb]> for StackVec<SIZE> {
+    #[inline]
+    fn as_ref(&self) -> &[Limb] {
+        self
+    }
+}
+
+impl<const SIZE: usize> PartialEq for StackVec<SIZE> {
+    #[inline]
+    #[allow(clippy::op_ref)]
+    fn eq(&self, other: &Self) -> bool {
+        use core::ops::Deref;
+        self.len() == other.len() && self.deref() == other.deref()
+    }
+}
+
+impl<const SIZE: usize> Eq for StackVec<SIZE> {
+}
+
+impl<const SIZE: usize> cmp::PartialOrd for StackVec<SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const SIZE: usize> cmp::Ord for StackVec<SIZE> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        compare(self, other)
+    }
+}
+
+impl<const SIZE: usize> ops::Deref for StackVec<SIZE> {
+    type Target = [Limb];
+    #[inline]
+    fn deref(&self) -> &[Limb] {
+        // SAFETY: safe since `self.data[..self.len()]` must be initialized
+        // and `self.len() <= self.capacity()`.
+        #[cfg(not(miri))]
+        return unsafe {
+            let ptr = self.data.as_ptr() as *const Limb;
+            slice::from_raw_parts(ptr, self.len())
+        };
+
+        #[cfg(miri)]
+        return &self.data[..self.len()];
+    }
+}
+
+impl<const SIZE: usize> ops::DerefMut for StackVec<SIZE> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [Limb] {
+        // SAFETY: safe since `self.data[..self.len()]` must be initialized
+        // and `self.len() <= self.capacity()`.
+        #[cfg(not(miri))]
+        return unsafe {
+            let ptr = self.data.as_mut_ptr() as *mut Limb;
+            slice::from_raw_parts_mut(ptr, self.len())
+        };
+
+        #[cfg(miri)]
+        return {
+            let len = self.len();
+            &mut self.data[..len]
+        };
+    }
+}
+
+impl<const SIZE: usize> ops::MulAssign<&[Limb]> for StackVec<SIZE> {
+    /// Multiply by a big integer.
+    ///
+    /// Panics if the product overflows the capacity of the vector.
+    /// Use [`large_mul`] to handle overflow.
+    #[inline]
+    fn mul_assign(&mut self, rhs: &[Limb]) {
+        large_mul(self, rhs).unwrap();
+    }
+}
+
+// REVERSE VIEW
+
+/// Reverse, immutable view of a sequence.
+///
+/// This indexes and iterates the sequence from the end, so for the
+/// little-endian limbs of a big integer, the most-significant limb
+/// is first. It is a double-ended iterator, and indexing is relative
+/// to the items that have not yet been iterated over.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_util::bigint::ReverseView;
+///
+/// let limbs = [1, 2, 3, 4];
+/// let mut rview = ReverseView::new(&limbs);
+/// assert_eq!(rview[0], 4);
+/// assert_eq!(rview.next(), Some(&4));
+/// assert_eq!(rview.next_back(), Some(&1));
+/// assert_eq!(rview.get(1), Some(&2));
+/// assert!(rview.get_slice(..1).unwrap().eq([3].iter()));
+/// assert_eq!(rview.as_slice(), &[2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReverseView<'a, T: 'a> {
+    inner: &'a [T],
+}
+
+impl<'a, T: 'a> ReverseView<'a, T> {
+    /// Create a reverse view of a slice.
+    #[inline(always)]
+    pub const fn new(inner: &'a [T]) -> Self {
+        Self {
+            inner,
+        }
+    }
+
+    /// Get the remaining items as a slice, in forward order.
+    #[inline(always)]
+    pub const fn as_slice(&self) -> &'a [T] {
+        self.inner
+    }
+
+    /// Check if there are no remaining items.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Get a reference to a value, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Safe if forward indexing would be safe for the type,
+    /// or `index < self.inner.len()`.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, index: usize) -> &'a T {
+        safe_assert!(index < self.inner.len());
+        let len = self.inner.len();
+        unsafe { self.inner.get_unchecked(len - index - 1) }
+    }
+
+    /// Get a reference to a value.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        let len = self.inner.len();
+        // We don't care if this wraps: the index is bounds-checked.
+        self.inner.get(len.wrapping_sub(index + 1))
+    }
+
+    /// Get a view of a range of reversed indexes, or `None` if out of bounds.
+    #[inline]
+    pub fn get_slice<R: ops::RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let len = self.inner.len();
+        let start = match range.start_bound() {
+            ops::Bound::Included(&start) => start,
+            ops::Bound::Excluded(&start) => start.checked_add(1)?,
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(&end) => end.checked_add(1)?,
+            ops::Bound::Excluded(&end) => end,
+            ops::Bound::Unbounded => len,
+        };
+        if start > end || end > len {
+            return None;
+        }
+        self.inner.get(len - end..len - start).map(Self::new)
+    }
+}
+
+impl<'a, T> ops::Index<usize> for ReverseView<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        let len = self.inner.len();
+        &(*self.inner)[len - index - 1]
+    }
+}
+
+impl<'a, T> Iterator for ReverseView<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (last, rest) = self.inner.split_last()?;
+        self.inner = rest;
+        Some(last)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let len = self.inner.len();
+        // Skip the last `n` items, or all of them if there are fewer.
+        self.inner = &self.inner[..len - n.min(len)];
+        self.next()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ReverseView<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.inner.split_first()?;
+        self.inner = rest;
+        Some(first)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip the first `n` items, or all of them if there are fewer.
+        self.inner = &self.inner[n.min(self.inner.len())..];
+        self.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ReverseView<'a, T> {
+}
+
+impl<'a, T> iter::FusedIterator for ReverseView<'a, T> {
+}
+
+/// Get if the remaining items of a reverse view truncate a value.
+#[inline(always)]
+fn truncated<T>((value, is_truncated): (T, bool), mut rest: ReverseView<'_, Limb>) -> (T, bool) {
+    (value, is_truncated || rest.any(|&x| x != 0))
+}
+
+// HI
+// --
+
+/// Check if any of the remaining bits are non-zero.
+///
+/// # Safety
+///
+/// Safe as long as `rindex <= x.len()`.
+#[inline]
+pub unsafe fn nonzero(x: &[Limb], rindex: usize) -> bool {
+    safe_assert!(rindex <= x.len());
+
+    let len = x.len();
+    // SAFETY: safe if `rindex < x.len()`, since then `x.len() - rindex < x.len()`.
+    let slc = unsafe { &index_unchecked!(x[..len - rindex]) };
+    slc.iter().rev().any(|&x| x != 0)
+}
+
+// These return the high X bits and if the bits were truncated.
+
+/// Shift 32-bit integer to high 16-bits.
+#[inline]
+pub const fn u32_to_hi16_1(r0: u32) -> (u16, bool) {
+    let r0 = u32_to_hi32_1(r0).0;
+    ((r0 >> 16) as u16, r0 as u16 != 0)
+}
+
+/// Shift 2 32-bit integers to high 16-bits.
+#[inline]
+pub const fn u32_to_hi16_2(r0: u32, r1: u32) -> (u16, bool) {
+    let (r0, n) = u32_to_hi32_2(r0, r1);
+    ((r0 >> 16) as u16, n || r0 as u16 != 0)
+}
+
+/// Shift 32-bit integer to high 32-bits.
+#[inline]
+pub const fn u32_to_hi32_1(r0: u32) -> (u32, bool) {
+    let ls = r0.leading_zeros();
+    (r0 << ls, false)
+}
+
+/// Shift 2 32-bit integers to high 32-bits.
+#[inline]
+pub const fn u32_to_hi32_2(r0: u32, r1: u32) -> (u32, bool) {
+    let ls = r0.leading_zeros();
+    let rs = 32 - ls;
+    let v = match ls {
+        0 => r0,
+        _ => (r0 << ls) | (r1 >> rs),
+    };
+    let n = r1 << ls != 0;
+    (v, n)
+}
+
+/// Shift 32-bit integer to high 64-bits.
+#[inline]
+pub const fn u32_to_hi64_1(r0: u32) -> (u64, bool) {
+    u64_to_hi64_1(r0 as u64)
+}
+
+/// Shift 2 32-bit integers to high 64-bits.
+#[inline]
+pub const fn u32_to_hi64_2(r0: u32, r1: u32) -> (u64, bool) {
+    let r0 = (r0 as u64) << 32;
+    let r1 = r1 as u64;
+    u64_to_hi64_1(r0 | r1)
+}
+
+/// Shift 3 32-bit integers to high 64-bits.
+#[inline]
+pub const fn u32_to_hi64_3(r0: u32, r1: u32, r2: u32) -> (u64, bool) {
+    let r0 = r0 as u64;
+    let r1 = (r1 as u64) << 32;
+    let r2 = r2 as u64;
+    u64_to_hi64_2(r0, r1 | r2)
+}
+
+/// Shift 64-bit integer to high 16-bits.
+#[inline]
+pub const fn u64_to_hi16_1(r0: u64) -> (u16, bool) {
+    let r0 = u64_to_hi64_1(r0).0;
+    ((r0 >> 48) as u16, r0 as u16 != 0)
+}
+
+/// Shift 2 64-bit integers to high 16-bits.
+#[inline]
+pub const fn u64_to_hi16_2(r0: u64, r1: u64) -> (u16, bool) {
+    let (r0, n) = u64_to_hi64_2(r0, r1);
+    ((r0 >> 48) as u16, n || r0 as u16 != 0)
+}
+
+/// Shift 64-bit integer to high 32-bits.
+#[inline]
+pub const fn u64_to_hi32_1(r0: u64) -> (u32, bool) {
+    let r0 = u64_to_hi64_1(r0).0;
+    ((r0 >> 32) as u32, r0 as u32 != 0)
+}
+
+/// Shift 2 64-bit integers to high 32-bits.
+#[inline]
+pub const fn u64_to_hi32_2(r0: u64, r1: u64) -> (u32, bool) {
+    let (r0, n) = u64_to_hi64_2(r0, r1);
+    ((r0 >> 32) as u32, n || r0 as u32 != 0)
+}
+
+/// Shift 64-bit integer to high 64-bits.
+#[inline]
+pub const fn u64_to_hi64_1(r0: u64) -> (u64, bool) {
+    let ls = r0.leading_zeros();
+    (r0 << ls, false)
+}
+
+/// Shift 2 64-bit integers to high 64-bits.
+#[inline]
+pub const fn u64_to_hi64_2(r0: u64, r1: u64) -> (u64, bool) {
+    let ls = r0.leading_zeros();
+    let rs = 64 - ls;
+    let v = match ls {
+        0 => r0,
+        _ => (r0 << ls) | (r1 >> rs),
+    };
+    let n = r1 << ls != 0;
+    (v, n)
+}
+
+// SCALAR
+// ------
+
+/// Add two small integers and return the resulting value and if overflow happens.
+#[inline(always)]
+pub fn scalar_add(x: Limb, y: Limb) -> (Limb, bool) {
+    x.overflowing_add(y)
+}
+
+/// Multiply two small integers (with carry) (and return the overflow contribution).
+///
+/// Returns the (low, high) components.
+#[inline(always)]
+#[cfg(not(all(
+    feature = "asm",
+    target_pointer_width = "64",
+    not(target_arch = "sparc"),
+    not(feature = "wasm")
+)))]
+pub fn scalar_mul(x: Limb, y: Limb, carry: Limb) -> (Limb, Limb) {
+    // Cannot overflow, as long as wide is 2x as wide. This is because
+    // the following is always true:
+    // `Wide::MAX - (Narrow::MAX * Narrow::MAX) >= Narrow::MAX`
+    let z: Wide = (x as Wide) * (y as Wide) + (carry as Wide);
+    (z as Limb, (z >> LIMB_BITS) as Limb)
+}
+
+/// Multiply two small integers (with carry) (and return the overflow contribution),
+/// using the native instruction for the high bits, if available.
+///
+/// Returns the (low, high) components.
+#[inline(always)]
+#[cfg(all(
+    feature = "asm",
+    target_pointer_width = "64",
+    not(target_arch = "sparc"),
+    not(feature = "wasm")
+))]
+pub fn scalar_mul(x: Limb, y: Limb, carry: Limb) -> (Limb, Limb) {
+    // Cannot overflow, since `x * y + carry < 2^128`.
+    let (lo, hi) = mul64(x, y);
+    let (lo, overflow) = lo.overflowing_add(carry);
+    (lo, hi + overflow as Limb)
+}
+
+// SMALL
+// -----
+
+/// Add small integer to bigint starting from offset.
+#[inline]
+pub fn small_add_from<const SIZE: usize>(
+    x: &mut StackVec<SIZE>,
+    y: Limb,
+    start: usize,
+) -> Option<()> {
+    let mut index = start;
+    let mut carry = y;
+    while carry != 0 && index < x.len() {
+        // SAFETY: safe, since `index < x.len()`.
+        let result = scalar_add(unsafe { index_unchecked!(x[index]) }, carry);
+        unsafe { index_unchecked_mut!(x[index]) = result.0 };
+        carry = result.1 as Limb;
+        index += 1;
+    }
+    // If we carried past all the elements, add to the end of the buffer.
+    if carry != 0 {
+        x.try_push(carry)?;
+    }
+    Some(())
+}
+
+/// Add small integer to bigint.
+#[inline(always)]
+pub fn small_add<const SIZE: usize>(x: &mut StackVec<SIZE>, y: Limb) -> Option<()> {
+    small_add_from(x, y, 0)
+}
+
+/// Multiply bigint by small integer.
+#[inline]
+pub fn small_mul<const SIZE: usize>(x: &mut StackVec<SIZE>, y: Limb) -> Option<()> {
+    let mut carry = 0;
+    for xi in x.iter_mut() {
+        let result = scalar_mul(*xi, y, carry);
+        *xi = result.0;
+        carry = result.1;
+    }
+    // If we carried past all the elements, add to the end of the buffer.
+    if carry != 0 {
+        x.try_push(carry)?;
+    }
+    Some(())
+}
+
+/// Divide bigint by small integer, returning the remainder.
+///
+/// Returns `None` if `y` is 0.
+#[inline]
+pub fn small_div<const SIZE: usize>(x: &mut StackVec<SIZE>, y: Limb) -> Option<Limb> {
+    if y == 0 {
+        return None;
+    }
+    let mut rem: Wide = 0;
+    for xi in x.iter_mut().rev() {
+        let num = (rem << LIMB_BITS) | *xi as Wide;
+        *xi = (num / y as Wide) as Limb;
+        rem = num % y as Wide;
+    }
+    x.normalize();
+    Some(rem as Limb)
+}
+
+// LARGE
+// -----
+
+/// Add bigint to bigint starting from offset.
+pub fn large_add_from<const SIZE: usize>(
+    x: &mut StackVec<SIZE>,
+    y: &[Limb],
+    start: usize,
+) -> Option<()> {
+    // The effective x buffer is from `xstart..x.len()`, so we need to treat
+    // that as the current range. If the effective y buffer is longer, need
+    // to resize to that, + the start index.
+    if y.len() > x.len().saturating_sub(start) {
+        // Ensure we panic if we can't extend the buffer.
+        // This avoids any unsafe behavior afterwards.
+        x.try_resize(y.len() + start, 0)?;
+    }
+
+    // Iteratively add elements from y to x.
+    let mut carry = false;
+    for index in 0..y.len() {
+        // SAFETY: safe since `start + index < x.len()`.
+        // We panicked in `try_resize` if this wasn't true.
+        let xi = unsafe { &mut index_unchecked_mut!(x[start + index]) };
+        // SAFETY: safe since `index < y.len()`.
+        let yi = unsafe { index_unchecked!(y[index]) };
+
+        // Only one op of the two ops can overflow, since we added at max
+        // Limb::max_value() + Limb::max_value(). Add the previous carry,
+        // and store the current carry for the next.
+        let result = scalar_add(*xi, yi);
+        *xi = result.0;
+        let mut tmp = result.1;
+        if carry {
+            let result = scalar_add(*xi, 1);
+            *xi = result.0;
+            tmp |= result.1;
+        }
+        carry = tmp;
+    }
+
+    // Handle overflow.
+    if carry {
+        small_add_from(x, 1, y.len() + start)?;
+    }
+    Some(())
+}
+
+/// Add bigint to bigint.
+#[inline(always)]
+pub fn large_add<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Option<()> {
+    large_add_from(x, y, 0)
+}
+
+/// Grade-school multiplication algorithm.
+///
+/// Slow, naive algorithm, using limb-bit bases and just shifting left for
+/// each iteration. This could be optimized with numerous other algorithms,
+/// but it's extremely simple, and works in O(n*m) time, which is fine
+/// by me. Each iteration, of which there are `m` iterations, requires
+/// `n` multiplications, and `n` additions, or grade-school multiplication.
+///
+/// Don't use Karatsuba multiplication, since out implementation seems to
+/// be slower asymptotically, which is likely just due to the small sizes
+/// we deal with here. For example, running on the following data:
+///
+/// ```text
+/// const SMALL_X: &[u32] = &[
+///     766857581, 3588187092, 1583923090, 2204542082, 1564708913, 2695310100, 3676050286,
+///     1022770393, 468044626, 446028186
+/// ];
+/// const SMALL_Y: &[u32] = &[
+///     3945492125, 3250752032, 1282554898, 1708742809, 1131807209, 3171663979, 1353276095,
+///     1678845844, 2373924447, 3640713171
+/// ];
+/// const LARGE_X: &[u32] = &[
+///     3647536243, 2836434412, 2154401029, 1297917894, 137240595, 790694805, 2260404854,
+///     3872698172, 690585094, 99641546, 3510774932, 1672049983, 2313458559, 2017623719,
+///     638180197, 1140936565, 1787190494, 1797420655, 14113450, 2350476485, 3052941684,
+///     1993594787, 2901001571, 4156930025, 1248016552, 848099908, 2660577483, 4030871206,
+///     692169593, 2835966319, 1781364505, 4266390061, 1813581655, 4210899844, 2137005290,
+///     2346701569, 3715571980, 3386325356, 1251725092, 2267270902, 474686922, 2712200426,
+///     197581715, 3087636290, 1379224439, 1258285015, 3230794403, 2759309199, 1494932094,
+///     326310242
+/// ];
+/// const LARGE_Y: &[u32] = &[
+///     1574249566, 868970575, 76716509, 3198027972, 1541766986, 1095120699, 3891610505,
+///     2322545818, 1677345138, 865101357, 2650232883, 2831881215, 3985005565, 2294283760,
+///     3468161605, 393539559, 3665153349, 1494067812, 106699483, 2596454134, 797235106,
+///     705031740, 1209732933, 2732145769, 4122429072, 141002534, 790195010, 4014829800,
+///     1303930792, 3649568494, 308065964, 1233648836, 2807326116, 79326486, 1262500691,
+///     621809229, 2258109428, 3819258501, 171115668, 1139491184, 2979680603, 1333372297,
+///     1657496603, 2790845317, 4090236532, 4220374789, 601876604, 1828177209, 2372228171,
+///     2247372529
+/// ];
+/// ```
+///
+/// We get the following results:
+///
+/// ```text
+/// mul/small:long          time:   [220.23 ns 221.47 ns 222.81 ns]
+/// Found 4 outliers among 100 measurements (4.00%)
+///   2 (2.00%) high mild
+///   2 (2.00%) high severe
+/// mul/small:karatsuba     time:   [233.88 ns 234.63 ns 235.44 ns]
+/// Found 11 outliers among 100 measurements (11.00%)
+///   8 (8.00%) high mild
+///   3 (3.00%) high severe
+/// mul/large:long          time:   [1.9365 us 1.9455 us 1.9558 us]
+/// Found 12 outliers among 100 measurements (12.00%)
+///   7 (7.00%) high mild
+///   5 (5.00%) high severe
+/// mul/large:karatsuba     time:   [4.4250 us 4.4515 us 4.4812 us]
+/// ```
+///
+/// In short, Karatsuba multiplication is never worthwhile for out use-case.
+#[allow(clippy::needless_range_loop)]
+pub fn long_mul<const SIZE: usize>(x: &[Limb], y: &[Limb]) -> Option<StackVec<SIZE>> {
+    // Multiplying by zero is always zero.
+    if y.is_empty() {
+        return Some(StackVec::new());
+    }
+
+    // Using the immutable value, multiply by all the scalars in y, using
+    // the algorithm defined above. Use a single buffer to avoid
+    // frequent reallocations. Handle the first case to avoid a redundant
+    // addition, since we know y.len() >= 1.
+    let mut z = StackVec::<SIZE>::try_from(x)?;
+    // SAFETY: safe, since `y.len() > 0`.
+    let y0 = unsafe { index_unchecked!(y[0]) };
+    small_mul(&mut z, y0)?;
+
+    for index in 1..y.len() {
+        // SAFETY: safe, since `index < y.len()`.
+        let yi = unsafe { index_unchecked!(y[index]) };
+        if yi != 0 {
+            let mut zi = StackVec::<SIZE>::try_from(x)?;
+            small_mul(&mut zi, yi)?;
+            large_add_from(&mut z, &zi, index)?;
+        }
+    }
+
+    z.normalize();
+    Some(z)
+}
+
+/// Multiply bigint by bigint using grade-school multiplication algorithm.
+#[inline(always)]
+pub fn large_mul<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Option<()> {
+    // Karatsuba multiplication never makes sense, so just use grade school
+    // multiplication.
+    if y.len() == 1 {
+        // SAFETY: safe since `y.len() == 1`.
+        small_mul(x, unsafe { index_unchecked!(y[0]) })?;
+    } else {
+        *x = long_mul(y, x)?;
+    }
+    Some(())
+}
+
+/// Emit a single digit for the quotient and store the remainder in-place.
+///
+/// An extremely efficient division algorithm for small quotients, requiring
+/// you to know the full range of the quotient prior to use. For example,
+/// with a quotient that can range from [0, 10), you must have 4 leading
+/// zeros in the divisor, so we can use a single-limb division to get
+/// an accurate estimate of the quotient. Since we always underestimate
+/// the quotient, we can add 1 and then emit the digit.
+///
+/// Requires a non-normalized denominator, with at least [1-6] leading
+/// zeros, depending on the base (for example, 1 for base2, 6 for base36).
+///
+/// Returns `None` if the divisor is zero, or if the numerator has more
+/// limbs than the divisor.
+///
+/// Adapted from David M. Gay's dtoa, and therefore under an MIT license:
+///     www.netlib.org/fp/dtoa.c
+#[allow(clippy::many_single_char_names)]
+pub fn large_quorem<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Option<Limb> {
+    // If we have an empty divisor or an oversized numerator, error out early.
+    if y.is_empty() || x.len() > y.len() {
+        return None;
+    }
+    let mask = Limb::MAX as Wide;
+
+    // Numerator is smaller the denominator, quotient always 0.
+    let m = x.len();
+    let n = y.len();
+    if m < n {
+        return Some(0);
+    }
+
+    // Calculate our initial estimate for q.
+    // SAFETY: safe since `m > 0 && m == x.len()`, since `m > n && n > 0`.
+    let xm_1 = unsafe { index_unchecked!(x[m - 1]) };
+    // SAFETY: safe since `n > 0 && n == y.len()`.
+    let yn_1 = unsafe { index_unchecked!(y[n - 1]) };
+    let mut q = xm_1 / (yn_1 + 1);
+
+    // Need to calculate the remainder if we don't have a 0 quotient.
+    if q != 0 {
+        let mut borrow: Wide = 0;
+        let mut carry: Wide = 0;
+        for j in 0..m {
+            // SAFETY: safe, since `j < n && n == y.len()`.
+            let yj = unsafe { index_unchecked!(y[j]) } as Wide;
+            let p = yj * q as Wide + carry;
+            carry = p >> LIMB_BITS;
+            // SAFETY: safe, since `j < m && m == x.len()`.
+            let xj = unsafe { index_unchecked!(x[j]) } as Wide;
+            let t = xj.wrapping_sub(p & mask).wrapping_sub(borrow);
+            borrow = (t >> LIMB_BITS) & 1;
+            // SAFETY: safe, since `j < m && m == x.len()`.
+            unsafe { index_unchecked_mut!(x[j]) = t as Limb };
+        }
+        x.normalize();
+    }
+
+    // Check if we under-estimated x.
+    if compare(x, y) != cmp::Ordering::Less {
+        q += 1;
+        let mut borrow: Wide = 0;
+        let mut carry: Wide = 0;
+        for j in 0..m {
+            // SAFETY: safe, since `j < n && n == y.len()`.
+            let yj = unsafe { index_unchecked!(y[j]) } as Wide;
+            let p = yj + carry;
+            carry = p >> LIMB_BITS;
+            // SAFETY: safe, since `j < m && m == x.len()`.
+            let xj = unsafe { index_unchecked!(x[j]) } as Wide;
+            let t = xj.wrapping_sub(p & mask).wrapping_sub(borrow);
+            borrow = (t >> LIMB_BITS) & 1;
+            // SAFETY: safe, since `j < m && m == x.len()`.
+            unsafe { index_unchecked_mut!(x[j]) = t as Limb };
+        }
+        x.normalize();
+    }
+
+    Some(q)
+}
+
+/// Divide the top `n + 1` limbs of the numerator by the divisor.
+///
+/// This is a single step of Knuth's Algorithm D, where `window` holds the
+/// low `n` limbs of the numerator, `top` the high limb, and `v` is the
+/// normalized, `n`-limb divisor. The remainder is stored in place, and
+/// the quotient limb is returned.
+#[allow(clippy::many_single_char_names)]
+fn large_div_step(window: &mut [Limb], top: &mut Limb, v: &[Limb]) -> Limb {
+    let n = v.len();
+    let b = (Limb::MAX as Wide) + 1;
+    let mask = Limb::MAX as Wide;
+    let vn_1 = v[n - 1] as Wide;
+    let vn_2 = v[n - 2] as Wide;
+
+    // Estimate the quotient from the top 2 limbs, which is at most 2 too large.
+    let num = ((*top as Wide) << LIMB_BITS) | window[n - 1] as Wide;
+    let mut qhat = num / vn_1;
+    let mut rhat = num % vn_1;
+    while qhat >= b || qhat * vn_2 > ((rhat << LIMB_BITS) | window[n - 2] as Wide) {
+        qhat -= 1;
+        rhat += vn_1;
+        if rhat >= b {
+            break;
+        }
+    }
+
+    // Multiply and subtract.
+    let mut borrow: SignedWide = 0;
+    for (wi, &vi) in window.iter_mut().zip(v.iter()) {
+        let p = qhat * vi as Wide;
+        let t = *wi as SignedWide - borrow - (p & mask) as SignedWide;
+        *wi = t as Limb;
+        borrow = (p >> LIMB_BITS) as SignedWide - (t >> LIMB_BITS);
+    }
+    let t = *top as SignedWide - borrow;
+    *top = t as Limb;
+
+    // Our estimate was 1 too large: add the divisor back.
+    if t < 0 {
+        qhat -= 1;
+        let mut carry: Wide = 0;
+        for (wi, &vi) in window.iter_mut().zip(v.iter()) {
+            let t = *wi as Wide + vi as Wide + carry;
+            *wi = t as Limb;
+            carry = t >> LIMB_BITS;
+        }
+        *top = top.wrapping_add(carry as Limb);
+    }
+
+    qhat as Limb
+}
+
+/// Divide bigint by bigint, returning the remainder.
+///
+/// The quotient is stored in `x`. Returns `None` if `y` is 0.
+///
+/// This uses Knuth's Algorithm D, from "The Art of Computer Programming,
+/// Volume 2", section 4.3.1, which requires `O(m*n)` operations for an
+/// `m`-limb numerator and an `n`-limb divisor.
+#[allow(clippy::many_single_char_names)]
+pub fn large_div<const SIZE: usize>(x: &mut StackVec<SIZE>, y: &[Limb]) -> Option<StackVec<SIZE>> {
+    // Ignore any leading zeros in the divisor.
+    let n = y.iter().rposition(|&yi| yi != 0)? + 1;
+    let y = &y[..n];
+    x.normalize();
+    if compare(x, y) == cmp::Ordering::Less {
+        return Some(core::mem::take(x));
+    }
+    if n == 1 {
+        let mut rem = StackVec::try_from(&[small_div(x, y[0])?])?;
+        rem.normalize();
+        return Some(rem);
+    }
+
+    // Normalize so the divisor has its high bit set, shifting the numerator
+    // by the same amount. The numerator may need an extra limb, `top`.
+    let m = x.len();
+    let shift = y[n - 1].leading_zeros() as usize;
+    let shl = |hi: Limb, lo: Limb| match shift {
+        0 => hi,
+        _ => (hi << shift) | (lo >> (LIMB_BITS - shift)),
+    };
+    let mut v = StackVec::<SIZE>::new();
+    let mut u = StackVec::<SIZE>::new();
+    let mut prev = 0;
+    for &yi in y {
+        v.try_push(shl(yi, prev))?;
+        prev = yi;
+    }
+    prev = 0;
+    for &xi in x.iter() {
+        u.try_push(shl(xi, prev))?;
+        prev = xi;
+    }
+    let mut top = shl(0, prev);
+
+    // Calculate the quotient limbs from the highest to the lowest.
+    let mut q = StackVec::<SIZE>::new();
+    q.try_resize(m - n + 1, 0)?;
+    for j in (0..=m - n).rev() {
+        q[j] = if j + n == m {
+            large_div_step(&mut u[j..], &mut top, &v)
+        } else {
+            let (lo, hi) = u.split_at_mut(j + n);
+            large_div_step(&mut lo[j..], &mut hi[0], &v)
+        };
+    }
+    q.normalize();
+    *x = q;
+
+    // Unnormalize the remainder, which is the low `n` limbs of `u`.
+    u.try_resize(n, 0)?;
+    for i in 0..n {
+        let next = u.get(i + 1).copied().unwrap_or(0);
+        u[i] = match shift {
+            0 => u[i],
+            _ => (u[i] >> shift) | (next << (LIMB_BITS - shift)),
+        };
+    }
+    u.normalize();
+    Some(u)
+}
+
+// COMPARE
+// -------
+
+/// Compare `x` to `y`, in little-endian order.
+#[inline]
+pub fn compare(x: &[Limb], y: &[Limb]) -> cmp::Ordering {
+    match x.len().cmp(&y.len()) {
+        cmp::Ordering::Equal => {
+            let iter = x.iter().rev().zip(y.iter().rev());
+            for (&xi, yi) in iter {
+                match xi.cmp(yi) {
+                    cmp::Ordering::Equal => (),
+                    ord => return ord,
+                }
+            }
+            // Equal case.
+            cmp::Ordering::Equal
+        },
+        ord => ord,
+    }
+}
+
+// SHIFT
+// -----
+
+/// Shift-left `n` bits inside a buffer.
+#[inline]
+pub fn shl_bits<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> Option<()> {
+    debug_assert!(n != 0);
+
+    // Internally, for each item, we shift left by n, and add the previous
+    // right shifted limb-bits.
+    // For example, we transform (for u8) shifted left 2, to:
+    //      b10100100 b01000010
+    //      b10 b10010001 b00001000
+    debug_assert!(n < LIMB_BITS);
+    let rshift = LIMB_BITS - n;
+    let lshift = n;
+    let mut prev: Limb = 0;
+    for xi in x.iter_mut() {
+        let tmp = *xi;
+        *xi <<= lshift;
+        *xi |= prev >> rshift;
+        prev = tmp;
+    }
+
+    // Always push the carry, even if it creates a non-normal result.
+    let carry = prev >> rshift;
+    if carry != 0 {
+        x.try_push(carry)?;
+    }
+
+    Some(())
+}
+
+/// Shift-left `n` limbs inside a buffer.
+#[inline]
+pub fn shl_limbs<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> Option<()> {
+    debug_assert!(n != 0);
+    if n + x.len() > x.capacity() {
+        None
+    } else if !x.is_empty() {
+        let len = n + x.len();
+        // SAFE: since x is not empty, and `x.len() + n <= x.capacity()`.
+        let x_len = x.len();
+        #[cfg(not(miri))]
+        unsafe {
+            // Move the elements.
+            let ptr = x.as_mut_ptr();
+            let src = ptr;
+            let dst = ptr.add(n);
+            ptr::copy(src, dst, x_len);
+            // Write our 0s.
+            ptr::write_bytes(ptr, 0, n);
+        }
+        #[cfg(miri)]
+        {
+            x.data.copy_within(..x_len, n);
+            x.data[..n].fill(0);
+        }
+        unsafe { x.set_len(len) };
+        Some(())
+    } else {
+        Some(())
+    }
+}
+
+/// Shift-left buffer by n bits.
+#[inline]
+pub fn shl<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> Option<()> {
+    let rem = n % LIMB_BITS;
+    let div = n / LIMB_BITS;
+    if rem != 0 {
+        shl_bits(x, rem)?;
+    }
+    if div != 0 {
+        shl_limbs(x, div)?;
+    }
+    Some(())
+}
+
+/// Get number of leading zero bits in the storage.
+#[inline]
+pub fn leading_zeros(x: &[Limb]) -> u32 {
+    let length = x.len();
+    // wrapping_sub is fine, since it'll just return None.
+    if let Some(&value) = x.get(length.wrapping_sub(1)) {
+        value.leading_zeros()
+    } else {
+        0
+    }
+}
+
+/// Calculate the bit-length of the big-integer.
+#[inline]
+pub fn bit_length(x: &[Limb]) -> u32 {
+    let nlz = leading_zeros(x);
+    LIMB_BITS as u32 * x.len() as u32 - nlz
+}
+
+// DIGITS
+// ------
+
+/// Get the largest power of the radix that fits in a limb, and its exponent.
+#[inline]
+fn limb_power(radix: u32) -> (usize, Limb) {
+    let mut step = 1;
+    let mut power = radix as Limb;
+    while let Some(next) = power.checked_mul(radix as Limb) {
+        step += 1;
+        power = next;
+    }
+    (step, power)
+}
+
+/// Parse a big integer from digits in the given radix.
+///
+/// The digits must be valid for the radix, without a sign or digit
+/// separators, and are case-insensitive. Returns `None` if the radix is
+/// not in `2..=36`, if there are no digits or any invalid digits, or if
+/// the value overflows the capacity of the vector.
+///
+/// This parses as many digits as fit in a limb at a time, so it only
+/// needs a single, small multiplication for every chunk of digits.
+pub fn from_digits<const SIZE: usize>(digits: &[u8], radix: u32) -> Option<StackVec<SIZE>> {
+    if !(2..=36).contains(&radix) || digits.is_empty() {
+        return None;
+    }
+    let (step, _) = limb_power(radix);
+    let mut x = StackVec::new();
+    for chunk in digits.chunks(step) {
+        let mut value: Limb = 0;
+        for &c in chunk {
+            value = value * radix as Limb + char_to_digit_const(c, radix)? as Limb;
+        }
+        small_mul(&mut x, (radix as Limb).pow(chunk.len() as u32))?;
+        small_add(&mut x, value)?;
+    }
+    Some(x)
+}
+
+/// Write a big integer as digits in the given radix.
+///
+/// Digits above 9 are written in uppercase, and 0 is written as `0`.
+/// Returns the number of bytes written to the start of the buffer, or
+/// `None` if the radix is not in `2..=36` or the buffer is too small.
+///
+/// This extracts as many digits as fit in a limb at a time, so it only
+/// needs a single, small division for every chunk of digits.
+pub fn to_digits<const SIZE: usize>(
+    x: &StackVec<SIZE>,
+    radix: u32,
+    buffer: &mut [u8],
+) -> Option<usize> {
+    if !(2..=36).contains(&radix) {
+        return None;
+    }
+    let (step, power) = limb_power(radix);
+    let mut x = x.clone();
+    x.normalize();
+
+    // Write the chunks from the lowest digits, starting at the end of
+    // the buffer. Every chunk but the highest is padded with zeros.
+    let mut index = buffer.len();
+    loop {
+        let mut chunk = small_div(&mut x, power)?;
+        for _ in 0..step {
+            if x.is_empty() && chunk == 0 && index != buffer.len() {
+                break;
+            }
+            index = index.checked_sub(1)?;
+            buffer[index] = digit_to_char_const(u32::as_cast(chunk % radix as Limb), radix);
+            chunk /= radix as Limb;
+        }
+        if x.is_empty() {
+            break;
+        }
+    }
+
+    let count = buffer.len() - index;
+    buffer.copy_within(index.., 0);
+    Some(count)
+}
+
+/// Convert a number from one radix to another exactly.
+///
+/// The input is an optional `-` sign, followed by integer digits, and an
+/// optional `.` and fraction digits, where either the integer or the
+/// fraction digits may be empty. The value is written in the same form
+/// to the start of the buffer, with uppercase digits, and without the
+/// fraction if it is zero. Returns the number of bytes written, or `None`
+/// if either radix is not in `2..=36`, if the input is invalid, if the
+/// fraction cannot be exactly represented in the new radix, if any value
+/// overflows the capacity of the vector, or if the buffer is too small.
+///
+/// For example, `0.5` is `0.1` in binary, but `0.1` cannot be exactly
+/// represented in binary, since it is `1/10`, and `10` has a factor of `5`.
+pub fn convert_radix<const SIZE: usize>(
+    digits: &[u8],
+    from: u32,
+    to: u32,
+    buffer: &mut [u8],
+) -> Option<usize> {
+    if !(2..=36).contains(&from) || !(2..=36).contains(&to) {
+        return None;
+    }
+    let (is_negative, digits) = match digits.split_first() {
+        Some((&b'-', rest)) => (true, rest),
+        _ => (false, digits),
+    };
+    let (integer, fraction) = match digits.iter().position(|&c| c == b'.') {
+        Some(index) => (&digits[..index], &digits[index + 1..]),
+        None => (digits, &digits[digits.len()..]),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    // Trailing zeros do not change the fraction.
+    let fraction = match fraction.iter().rposition(|&c| c != b'0') {
+        Some(index) => &fraction[..=index],
+        None => &fraction[..0],
+    };
+
+    // Write the sign and the integer digits.
+    let mut index = 0;
+    if is_negative {
+        *buffer.get_mut(index)? = b'-';
+        index += 1;
+    }
+    let integer = if integer.is_empty() {
+        StackVec::<SIZE>::new()
+    } else {
+        from_digits::<SIZE>(integer, from)?
+    };
+    index += to_digits(&integer, to, buffer.get_mut(index..)?)?;
+    if fraction.is_empty() {
+        return Some(index);
+    }
+
+    // Write the fraction digits, from the numerator and denominator of
+    // `fraction / from^n`. The denominator has at most `n * log2(from)`
+    // of any prime factor, so if the fraction is exactly representable,
+    // we need at most that many digits, since the radix has the factor.
+    let mut num = from_digits::<SIZE>(fraction, from)?;
+    let mut den = StackVec::<SIZE>::from_u32(1);
+    for _ in 0..fraction.len() {
+        small_mul(&mut den, from as Limb)?;
+    }
+    *buffer.get_mut(index)? = b'.';
+    index += 1;
+    let max_digits = fraction.len() * (32 - from.leading_zeros()) as usize;
+    for _ in 0..max_digits {
+        small_mul(&mut num, to as Limb)?;
+        let rem = large_div(&mut num, &den)?;
+        let digit = num.first().copied().unwrap_or(0);
+        *buffer.get_mut(index)? = digit_to_char_const(u32::as_cast(digit), to);
+        index += 1;
+        num = rem;
+        if num.is_empty() {
+            return Some(index);
+        }
+    }
+
+    None
+}
+
+// LIMB
+// ----
+
+//  Type for a single limb of the big integer.
+//
+//  A limb is analogous to a digit in base10, except, it stores 32-bit
+//  or 64-bit numbers instead. We want types where 64-bit multiplication
+//  is well-supported by the architecture, rather than emulated in 3
+//  instructions. The quickest way to check this support is using a
+//  cross-compiler for numerous architectures, along with the following
+//  source file and command:
+//
+//  Compile with `gcc main.c -c -S -O3 -masm=intel`
+//
+//  And the source code is:
+//  ```text
+//  #include <stdint.h>
+//
+//  struct i128 {
+//      uint64_t hi;
+//      uint64_t lo;
+//  };
+//
+//  // Type your code here, or load an example.
+//  struct i128 square(uint64_t x, uint64_t y) {
+//      __int128 prod = (__int128)x * (__int128)y;
+//      struct i128 z;
+//      z.hi = (uint64_t)(prod >> 64);
+//      z.lo = (uint64_t)prod;
+//      return z;
+//  }
+//  ```
+//
+//  If the result contains `call __multi3`, then the multiplication
+//  is emulated by the compiler. Otherwise, it's natively supported.
+//
+//  This should be all-known 64-bit platforms supported by Rust.
+//      https://forge.rust-lang.org/platform-support.html
+//
+//  # Supported
+//
+//  Platforms where native 128-bit multiplication is explicitly supported:
+//      - x86_64 (Supported via `MUL`).
+//      - mips64 (Supported via `DMULTU`, which `HI` and `LO` can be read-from).
+//      - s390x (Supported via `MLGR`).
+//
+//  # Efficient
+//
+//  Platforms where native 64-bit multiplication is supported and
+//  you can extract hi-lo for 64-bit multiplications.
+//      - aarch64 (Requires `UMULH` and `MUL` to capture high and low bits).
+//      - powerpc64 (Requires `MULHDU` and `MULLD` to capture high and low bits).
+//      - riscv64 (Requires `MUL` and `MULH` to capture high and low bits).
+//
+//  # Unsupported
+//
+//  Platforms where native 128-bit multiplication is not supported,
+//  requiring software emulation.
+//      sparc64 (`UMUL` only supports double-word arguments).
+//      sparcv9 (Same as sparc64).
+//
+//  These tests are run via `xcross`, my own library for C cross-compiling,
+//  which supports numerous targets (far in excess of Rust's tier 1 support,
+//  or rust-embedded/cross's list). xcross may be found here:
+//      https://github.com/Alexhuszagh/xcross
+//
+//  To compile for the given target, run:
+//      `xcross gcc main.c -c -S -O3 --target $target`
+//
+//  All 32-bit architectures inherently do not have support. That means
+//  we can essentially look for 64-bit architectures that are not SPARC.
+//
+//  WebAssembly has native 64-bit multiplication, but 128-bit multiplication
+//  is emulated via `__multi3`, so the `wasm` feature always uses 32-bit limbs.
+
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
+pub type Limb = u64;
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
+pub type Wide = u128;
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
+pub type SignedWide = i128;
+#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm")))]
+pub const LIMB_BITS: usize = 64;
+
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
+pub type Limb = u32;
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
+pub type Wide = u64;
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
+pub type SignedWide = i64;
+#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"), not(feature = "wasm"))))]
+pub const LIMB_BITS: usize = 32;
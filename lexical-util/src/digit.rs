@@ -2,6 +2,48 @@
 //!
 //! This both contains routines to convert to and from digits,
 //! as well as iterate over digits while skipping digit separators.
+//!
+//! The conversions support radixes from 2 to 62, and are available
+//! regardless of the enabled features, for use by downstream tokenizers.
+//! Radixes up to 36 use case-insensitive letters for digits above 9,
+//! while radixes above 36 use case-sensitive digits, `0-9a-zA-Z`. Use
+//! [`char_to_digit_with_case`] and [`digit_to_char_with_case`] to choose
+//! the case of the letters for radixes up to 36.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_util::digit::{char_to_digit_with_case, digit_to_char_with_case, DigitCase};
+//!
+//! assert_eq!(char_to_digit_with_case(b'f', 16, DigitCase::Insensitive), Some(15));
+//! assert_eq!(char_to_digit_with_case(b'f', 16, DigitCase::Upper), None);
+//! assert_eq!(digit_to_char_with_case(15, 16, DigitCase::Lower), Some(b'f'));
+//! assert_eq!(digit_to_char_with_case(16, 16, DigitCase::Lower), None);
+//! ```
+
+// CASE
+// ----
+
+/// The case of letters used as digits, for radixes from 11 to 36.
+///
+/// Radixes above 36 always use case-sensitive digits, `0-9a-zA-Z`,
+/// so the case is ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DigitCase {
+    /// Only accept and write uppercase letters, `A-Z`.
+    Upper,
+    /// Only accept and write lowercase letters, `a-z`.
+    Lower,
+    /// Accept letters of either case, and write uppercase letters.
+    Insensitive,
+}
+
+impl Default for DigitCase {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::Insensitive
+    }
+}
 
 // CONST FNS
 // ---------
@@ -12,11 +54,21 @@
 
 /// Unchecked, highly optimized algorithm to convert a char to a digit.
 /// This only works if the input character is known to be a valid digit.
+///
+/// Radixes above 36 use case-sensitive digits, `0-9a-zA-Z`.
 #[inline]
 pub const fn char_to_valid_digit_const(c: u8, radix: u32) -> u32 {
     if radix <= 10 {
         // Optimize for small radixes.
         (c.wrapping_sub(b'0')) as u32
+    } else if radix > 36 {
+        let digit = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'z' => c - b'a' + 10,
+            b'A'..=b'Z' => c - b'A' + 36,
+            _ => 0xFF,
+        };
+        digit as u32
     } else {
         // Fallback, still decently fast.
         let digit = match c {
@@ -52,15 +104,19 @@ pub const fn char_is_digit_const(c: u8, radix: u32) -> bool {
 /// Convert a digit to a character with a radix known at compile time.
 ///
 /// This optimizes for cases where radix is <= 10, and uses a decent,
-/// match-based fallback algorithm.
+/// match-based fallback algorithm. Radixes above 36 use case-sensitive
+/// digits, `0-9a-zA-Z`.
 #[inline]
-#[cfg(any(feature = "write", feature = "floats"))]
 pub const fn digit_to_char_const(digit: u32, radix: u32) -> u8 {
     if radix <= 10 || digit < 10 {
         // Can short-circuit if we know the radix is small at compile time.
         digit as u8 + b'0'
-    } else {
+    } else if radix <= 36 {
         digit as u8 + b'A' - 10
+    } else if digit < 36 {
+        digit as u8 + b'a' - 10
+    } else {
+        digit as u8 + b'A' - 36
     }
 }
 
@@ -72,14 +128,16 @@ pub const fn digit_to_char_const(digit: u32, radix: u32) -> u8 {
 // improved compiler optimization passes when generics are used more sparingly.
 
 /// Convert a character to a digit.
+///
+/// Radixes above 36 use case-sensitive digits, `0-9a-zA-Z`.
 #[inline]
-#[cfg(feature = "parse")]
 pub const fn char_to_digit(c: u8, radix: u32) -> Option<u32> {
     // Fallback, still decently fast.
     let digit = match c {
         b'0'..=b'9' => c - b'0',
-        b'A'..=b'Z' => c - b'A' + 10,
         b'a'..=b'z' => c - b'a' + 10,
+        b'A'..=b'Z' if radix > 36 => c - b'A' + 36,
+        b'A'..=b'Z' => c - b'A' + 10,
         _ => 0xFF,
     } as u32;
     if digit < radix {
@@ -91,11 +149,47 @@ pub const fn char_to_digit(c: u8, radix: u32) -> Option<u32> {
 
 /// Determine if a character is a digit.
 #[inline]
-#[cfg(feature = "parse")]
 pub const fn char_is_digit(c: u8, radix: u32) -> bool {
     char_to_digit(c, radix).is_some()
 }
 
+/// Convert a character to a digit, with the given case for letters.
+///
+/// This returns `None` if the character is not a valid digit for the radix,
+/// including letters of the wrong case for radixes up to 36. Radixes above
+/// 36 use case-sensitive digits, `0-9a-zA-Z`, and ignore the case.
+#[inline]
+pub const fn char_to_digit_with_case(c: u8, radix: u32, case: DigitCase) -> Option<u32> {
+    let valid_case = match case {
+        _ if radix > 36 => true,
+        DigitCase::Upper => !c.is_ascii_lowercase(),
+        DigitCase::Lower => !c.is_ascii_uppercase(),
+        DigitCase::Insensitive => true,
+    };
+    if valid_case {
+        char_to_digit(c, radix)
+    } else {
+        None
+    }
+}
+
+/// Convert a digit to a character, with the given case for letters.
+///
+/// This returns `None` if the digit is not valid for the radix, or the
+/// radix is not in the range `[2, 62]`. Radixes above 36 use case-sensitive
+/// digits, `0-9a-zA-Z`, and ignore the case.
+#[inline]
+pub const fn digit_to_char_with_case(digit: u32, radix: u32, case: DigitCase) -> Option<u8> {
+    if radix < 2 || radix > 62 || digit >= radix {
+        return None;
+    }
+    let c = digit_to_char_const(digit, radix);
+    match case {
+        DigitCase::Lower if radix <= 36 && digit >= 10 => Some(c - b'A' + b'a'),
+        _ => Some(c),
+    }
+}
+
 /// Convert a digit to a character. This uses a pre-computed table to avoid branching.
 ///
 /// # Safety
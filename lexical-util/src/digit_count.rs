@@ -0,0 +1,255 @@
+//! Fast algorithms to calculate the number of digits in an integer.
+//!
+//! These are used to size buffers before writing integers, and are
+//! useful to calculate the space or alignment for formatted numbers.
+
+use crate::num::UnsignedInteger;
+
+/// Fast integral log2.
+///
+/// This is fairly trivial to explain, since the log2 is related to the
+/// number of bits in the value. Therefore, it has to be related to
+/// `T::BITS - ctlz(x)`. For example, `log2(2) == 1`, and `log2(1) == 0`,
+/// and `log2(3) == 1`. Therefore, we must take the log of an odd number,
+/// and subtract one.
+///
+/// This algorithm is described in detail in "Computing the number of digits
+/// of an integer quickly", available
+/// [here](https://lemire.me/blog/2021/05/28/computing-the-number-of-digits-of-an-integer-quickly/).
+#[inline]
+pub fn fast_log2<T: UnsignedInteger>(x: T) -> usize {
+    T::BITS - 1 - (x | T::ONE).leading_zeros() as usize
+}
+
+/// Calculate the fast, integral log10 of a value.
+///
+/// This is relatively easy to explain as well: we calculate the log2
+/// of the value, then multiply by an integral constant for the log10(2).
+///
+/// Note that this value is frequently off by 1, so we need to round-up
+/// accordingly. This magic number is valid at least up until `1<<18`,
+/// which works for all values, since our max log2 is 127.
+#[inline]
+pub fn fast_log10<T: UnsignedInteger>(x: T) -> usize {
+    let log2 = fast_log2(x);
+    (log2 * 1233) >> 12
+}
+
+/// Fast algorithm to calculate the number of digits in an integer.
+///
+/// We only use this for 32-bit or smaller values: for larger numbers,
+/// we first write digits until we get to 32-bits, then we call this.
+///
+/// The values are as follows:
+///
+/// - `2^32 for j = 1`
+/// - `⌈log10(2^j)⌉ * 2^128 + 2^128 – 10^(⌈log10(2j)⌉) for j from 2 to 30`
+/// - `⌈log10(2^j)⌉ for j = 31 and j = 32`
+///
+/// This algorithm is described in detail in "Computing the number of digits
+/// of an integer even faster", available
+/// [here](https://lemire.me/blog/2021/06/03/computing-the-number-of-digits-of-an-integer-even-faster/).
+#[inline]
+pub fn fast_digit_count(x: u32) -> usize {
+    const TABLE: [u64; 32] = [
+        4294967296,
+        8589934582,
+        8589934582,
+        8589934582,
+        12884901788,
+        12884901788,
+        12884901788,
+        17179868184,
+        17179868184,
+        17179868184,
+        21474826480,
+        21474826480,
+        21474826480,
+        21474826480,
+        25769703776,
+        25769703776,
+        25769703776,
+        30063771072,
+        30063771072,
+        30063771072,
+        34349738368,
+        34349738368,
+        34349738368,
+        34349738368,
+        38554705664,
+        38554705664,
+        38554705664,
+        41949672960,
+        41949672960,
+        41949672960,
+        42949672960,
+        42949672960,
+    ];
+    // This never panics, since fast_log2 will always return a value
+    // < 32. This is because the range of values from `ctlz(x | 1)` is
+    // `[0, 31]`, so `32 - 1 - ctlz(x | 1)` must be in the range `[0, 31]`.
+    let shift = TABLE[fast_log2(x)];
+    let count = (x as u64 + shift) >> 32;
+    count as usize
+}
+
+/// Slightly slower algorithm to calculate the number of digits in an integer.
+///
+/// This uses no static storage, and uses a fast log10(2) estimation
+/// to calculate the number of digits, from the log2 value.
+///
+/// This algorithm is described in detail in "Computing the number of digits
+/// of an integer even faster", available
+/// [here](https://lemire.me/blog/2021/06/03/computing-the-number-of-digits-of-an-integer-even-faster/).
+#[inline]
+pub fn fallback_digit_count<T: UnsignedInteger>(x: T, table: &[T]) -> usize {
+    // This value is always within 1: calculate if we need to round-up
+    // based on a pre-computed table.
+    let log10 = fast_log10(x);
+    let shift_up = matches!(table.get(log10), Some(&y) if x >= y);
+
+    log10 + shift_up as usize + 1
+}
+
+/// Quickly calculate the number of digits in a type.
+pub trait DigitCount: UnsignedInteger {
+    /// Get the number of digits in a value.
+    fn digit_count(self) -> usize;
+}
+
+macro_rules! digit_count_as {
+    ($($t:ty => $as:ty ; )*) => ($(
+        impl DigitCount for $t {
+            #[inline]
+            fn digit_count(self) -> usize {
+                (self as $as).digit_count()
+            }
+        }
+    )*)
+}
+
+digit_count_as! {
+    u8 => u32 ;
+    u16 => u32 ;
+}
+
+#[cfg(target_pointer_width = "16")]
+digit_count_as! { usize => u32 ; }
+
+#[cfg(target_pointer_width = "32")]
+digit_count_as! { usize => u32 ; }
+
+#[cfg(target_pointer_width = "64")]
+digit_count_as! { usize => u64 ; }
+
+impl DigitCount for u32 {
+    #[inline]
+    fn digit_count(self) -> usize {
+        fast_digit_count(self)
+    }
+}
+
+impl DigitCount for u64 {
+    #[inline]
+    fn digit_count(self) -> usize {
+        const TABLE: [u64; 19] = [
+            10,
+            100,
+            1000,
+            10000,
+            100000,
+            1000000,
+            10000000,
+            100000000,
+            1000000000,
+            10000000000,
+            100000000000,
+            1000000000000,
+            10000000000000,
+            100000000000000,
+            1000000000000000,
+            10000000000000000,
+            100000000000000000,
+            1000000000000000000,
+            10000000000000000000,
+        ];
+        fallback_digit_count(self, &TABLE)
+    }
+}
+
+impl DigitCount for u128 {
+    #[inline]
+    fn digit_count(self) -> usize {
+        const TABLE: [u128; 38] = [
+            10,
+            100,
+            1000,
+            10000,
+            100000,
+            1000000,
+            10000000,
+            100000000,
+            1000000000,
+            10000000000,
+            100000000000,
+            1000000000000,
+            10000000000000,
+            100000000000000,
+            1000000000000000,
+            10000000000000000,
+            100000000000000000,
+            1000000000000000000,
+            10000000000000000000,
+            100000000000000000000,
+            1000000000000000000000,
+            10000000000000000000000,
+            100000000000000000000000,
+            1000000000000000000000000,
+            10000000000000000000000000,
+            100000000000000000000000000,
+            1000000000000000000000000000,
+            10000000000000000000000000000,
+            100000000000000000000000000000,
+            1000000000000000000000000000000,
+            10000000000000000000000000000000,
+            100000000000000000000000000000000,
+            1000000000000000000000000000000000,
+            10000000000000000000000000000000000,
+            100000000000000000000000000000000000,
+            1000000000000000000000000000000000000,
+            10000000000000000000000000000000000000,
+            100000000000000000000000000000000000000,
+        ];
+        fallback_digit_count(self, &TABLE)
+    }
+}
+
+/// Calculate the number of digits in an integer for any radix.
+///
+/// This uses the fast algorithms for decimal, and calculates the digits
+/// directly from the bit-length for powers of two. Other radixes need
+/// a division for every digit. Zero has 1 digit.
+///
+/// # Panics
+///
+/// Panics if the radix is not in `2..=36`.
+#[inline]
+pub fn digit_count_radix<T: DigitCount>(x: T, radix: u32) -> usize {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+    if radix == 10 {
+        x.digit_count()
+    } else if radix.is_power_of_two() {
+        let bits = fast_log2(x) + 1;
+        let shift = radix.trailing_zeros() as usize;
+        (bits - 1) / shift + 1
+    } else {
+        let radix = T::as_cast(radix);
+        let mut x = x;
+        let mut count = 1;
+        while x >= radix {
+            x /= radix;
+            count += 1;
+        }
+        count
+    }
+}
@@ -11,6 +11,7 @@ use std::error;
 /// Error code during parsing, indicating failure type.
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     // PARSE ERRORS
     /// Integral overflow occurred during numeric parsing.
@@ -51,6 +52,19 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Required base prefix was not found.
+    MissingBasePrefix(usize),
+    /// Required base suffix was not found.
+    MissingBaseSuffix(usize),
+    /// The number cannot be narrowed to a smaller type without losing information.
+    InexactNarrowing(usize),
+    /// The number cannot be parsed in a const context without the slow path.
+    RequiresSlowPath(usize),
+    /// An internal invariant of the parsing algorithms did not hold.
+    ///
+    /// This is a bug, and is only returned with the `no-panic` feature,
+    /// rather than panicking.
+    Internal(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -87,6 +101,12 @@ pub enum Error {
     InvalidConsecutiveExponentDigitSeparator,
     /// Invalid flags were set without the format feature.
     InvalidFlags,
+    /// Invalid number format specification string, at the given index.
+    InvalidFormatSpec(usize),
+    /// Punctuation character is a valid digit for the radix.
+    DigitPunctuation(Punctuation),
+    /// Two punctuation characters are the same character.
+    ConflictingPunctuation(Punctuation, Punctuation),
 
     // OPTION ERRORS
     /// Invalid NaN string: must start with an `n` character.
@@ -113,6 +133,15 @@ pub enum Error {
     InvalidNegativeExponentBreak,
     /// Invalid positive exponent break: break is below 0.
     InvalidPositiveExponentBreak,
+    /// Invalid fill character for padding to a minimum width.
+    InvalidFillCharacter,
+
+    // WRITE ERRORS
+    /// The buffer is too small to hold the serialized number.
+    BufferTooSmall {
+        /// The number of bytes required to hold the serialized number.
+        needed: usize,
+    },
 
     // NOT AN ERROR
     /// An error did not actually occur, and the result was successful.
@@ -122,6 +151,64 @@ pub enum Error {
 // Ensure we don't have extra padding on the structure.
 const_assert!(mem::size_of::<Error>() <= 2 * mem::size_of::<usize>());
 
+/// Punctuation character in a number format or options.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Punctuation {
+    /// The digit separator of the number format.
+    DigitSeparator,
+    /// The base prefix of the number format.
+    BasePrefix,
+    /// The base suffix of the number format.
+    BaseSuffix,
+    /// The decimal point of the options.
+    DecimalPoint,
+    /// The exponent character of the options.
+    Exponent,
+}
+
+impl Punctuation {
+    /// Get the error for a character that is not valid ASCII, or is a sign.
+    pub const fn invalid_error(&self) -> Error {
+        match self {
+            Self::DigitSeparator => Error::InvalidDigitSeparator,
+            Self::BasePrefix => Error::InvalidBasePrefix,
+            Self::BaseSuffix => Error::InvalidBaseSuffix,
+            Self::DecimalPoint => Error::InvalidDecimalPoint,
+            Self::Exponent => Error::InvalidExponentSymbol,
+        }
+    }
+}
+
+impl fmt::Display for Punctuation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::DigitSeparator => "digit separator",
+            Self::BasePrefix => "base prefix",
+            Self::BaseSuffix => "base suffix",
+            Self::DecimalPoint => "decimal point",
+            Self::Exponent => "exponent symbol",
+        };
+        formatter.write_str(name)
+    }
+}
+
+/// The kind of error, grouping the error types.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ErrorKind {
+    /// The input was not a valid number.
+    Parse,
+    /// The number format was invalid.
+    Format,
+    /// The options were invalid.
+    Options,
+    /// The number could not be written.
+    Write,
+    /// An error did not actually occur.
+    Success,
+}
+
 macro_rules! is_error_type {
     ($name:ident, $type:ident$($t:tt)*) => (
         /// const fn check to see if an error is of a specific type.
@@ -160,6 +247,11 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::MissingBasePrefix(index) => Some(index),
+            Self::MissingBaseSuffix(index) => Some(index),
+            Self::InexactNarrowing(index) => Some(index),
+            Self::RequiresSlowPath(index) => Some(index),
+            Self::Internal(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -179,6 +271,9 @@ impl Error {
             Self::InvalidConsecutiveFractionDigitSeparator => None,
             Self::InvalidConsecutiveExponentDigitSeparator => None,
             Self::InvalidFlags => None,
+            Self::InvalidFormatSpec(index) => Some(index),
+            Self::DigitPunctuation(_) => None,
+            Self::ConflictingPunctuation(_, _) => None,
 
             // OPTION ERRORS
             Self::InvalidNanString => None,
@@ -193,12 +288,284 @@ impl Error {
             Self::InvalidFloatPrecision => None,
             Self::InvalidNegativeExponentBreak => None,
             Self::InvalidPositiveExponentBreak => None,
+            Self::InvalidFillCharacter => None,
+
+            // WRITE ERRORS
+            Self::BufferTooSmall {
+                ..
+            } => None,
 
             // NOT AN ERROR
             Self::Success => None,
         }
     }
 
+    /// Shift the index of a parsing error by `offset` bytes.
+    ///
+    /// This is used when the error occurred while parsing a subslice
+    /// of the input, such as the digits inside accounting-style parentheses.
+    /// Errors without an index are returned unchanged.
+    pub const fn shift_index(self, offset: usize) -> Self {
+        match self {
+            Self::Overflow(index) => Self::Overflow(index + offset),
+            Self::Underflow(index) => Self::Underflow(index + offset),
+            Self::InvalidDigit(index) => Self::InvalidDigit(index + offset),
+            Self::Empty(index) => Self::Empty(index + offset),
+            Self::EmptyMantissa(index) => Self::EmptyMantissa(index + offset),
+            Self::EmptyExponent(index) => Self::EmptyExponent(index + offset),
+            Self::EmptyInteger(index) => Self::EmptyInteger(index + offset),
+            Self::EmptyFraction(index) => Self::EmptyFraction(index + offset),
+            Self::InvalidPositiveMantissaSign(index) => {
+                Self::InvalidPositiveMantissaSign(index + offset)
+            },
+            Self::MissingMantissaSign(index) => Self::MissingMantissaSign(index + offset),
+            Self::InvalidExponent(index) => Self::InvalidExponent(index + offset),
+            Self::InvalidPositiveExponentSign(index) => {
+                Self::InvalidPositiveExponentSign(index + offset)
+            },
+            Self::MissingExponentSign(index) => Self::MissingExponentSign(index + offset),
+            Self::ExponentWithoutFraction(index) => Self::ExponentWithoutFraction(index + offset),
+            Self::InvalidLeadingZeros(index) => Self::InvalidLeadingZeros(index + offset),
+            Self::MissingExponent(index) => Self::MissingExponent(index + offset),
+            Self::MissingSign(index) => Self::MissingSign(index + offset),
+            Self::InvalidPositiveSign(index) => Self::InvalidPositiveSign(index + offset),
+            Self::InvalidNegativeSign(index) => Self::InvalidNegativeSign(index + offset),
+            Self::MissingBasePrefix(index) => Self::MissingBasePrefix(index + offset),
+            Self::MissingBaseSuffix(index) => Self::MissingBaseSuffix(index + offset),
+            Self::InexactNarrowing(index) => Self::InexactNarrowing(index + offset),
+            Self::RequiresSlowPath(index) => Self::RequiresSlowPath(index + offset),
+            Self::Internal(index) => Self::Internal(index + offset),
+            _ => self,
+        }
+    }
+
+    /// Get a stable, numeric code for the error type.
+    ///
+    /// The codes will never change or be reused, so they can be recorded
+    /// by FFI layers and telemetry systems. Codes are grouped by the kind
+    /// of error: `0` is success, `100-199` are parse errors, `200-299` are
+    /// number format errors, `300-399` are options errors, and `400-499`
+    /// are write errors. Any index or other data is not part of the code.
+    ///
+    /// | Code | Error |
+    /// |:-:|:-:|
+    /// | `100` | `Overflow` |
+    /// | `101` | `Underflow` |
+    /// | `102` | `InvalidDigit` |
+    /// | `103` | `Empty` |
+    /// | `104` | `EmptyMantissa` |
+    /// | `105` | `EmptyExponent` |
+    /// | `106` | `EmptyInteger` |
+    /// | `107` | `EmptyFraction` |
+    /// | `108` | `InvalidPositiveMantissaSign` |
+    /// | `109` | `MissingMantissaSign` |
+    /// | `110` | `InvalidExponent` |
+    /// | `111` | `InvalidPositiveExponentSign` |
+    /// | `112` | `MissingExponentSign` |
+    /// | `113` | `ExponentWithoutFraction` |
+    /// | `114` | `InvalidLeadingZeros` |
+    /// | `115` | `MissingExponent` |
+    /// | `116` | `MissingSign` |
+    /// | `117` | `InvalidPositiveSign` |
+    /// | `118` | `InvalidNegativeSign` |
+    /// | `119` | `MissingBasePrefix` |
+    /// | `120` | `MissingBaseSuffix` |
+    /// | `121` | `InexactNarrowing` |
+    /// | `122` | `RequiresSlowPath` |
+    /// | `123` | `Internal` |
+    /// | `200` | `InvalidMantissaRadix` |
+    /// | `201` | `InvalidExponentBase` |
+    /// | `202` | `InvalidExponentRadix` |
+    /// | `203` | `InvalidDigitSeparator` |
+    /// | `204` | `InvalidDecimalPoint` |
+    /// | `205` | `InvalidExponentSymbol` |
+    /// | `206` | `InvalidBasePrefix` |
+    /// | `207` | `InvalidBaseSuffix` |
+    /// | `208` | `InvalidPunctuation` |
+    /// | `209` | `InvalidExponentFlags` |
+    /// | `210` | `InvalidMantissaSign` |
+    /// | `211` | `InvalidExponentSign` |
+    /// | `212` | `InvalidSpecial` |
+    /// | `213` | `InvalidConsecutiveIntegerDigitSeparator` |
+    /// | `214` | `InvalidConsecutiveFractionDigitSeparator` |
+    /// | `215` | `InvalidConsecutiveExponentDigitSeparator` |
+    /// | `216` | `InvalidFlags` |
+    /// | `217` | `InvalidFormatSpec` |
+    /// | `218` | `DigitPunctuation` |
+    /// | `219` | `ConflictingPunctuation` |
+    /// | `300` | `InvalidNanString` |
+    /// | `301` | `NanStringTooLong` |
+    /// | `302` | `InvalidInfString` |
+    /// | `303` | `InfStringTooLong` |
+    /// | `304` | `InvalidInfinityString` |
+    /// | `305` | `InfinityStringTooLong` |
+    /// | `306` | `InfinityStringTooShort` |
+    /// | `307` | `InvalidFloatParseAlgorithm` |
+    /// | `308` | `InvalidRadix` |
+    /// | `309` | `InvalidFloatPrecision` |
+    /// | `310` | `InvalidNegativeExponentBreak` |
+    /// | `311` | `InvalidPositiveExponentBreak` |
+    /// | `312` | `InvalidFillCharacter` |
+    /// | `400` | `BufferTooSmall` |
+    /// | `0` | `Success` |
+    pub const fn code(&self) -> u16 {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow(_) => 100,
+            Self::Underflow(_) => 101,
+            Self::InvalidDigit(_) => 102,
+            Self::Empty(_) => 103,
+            Self::EmptyMantissa(_) => 104,
+            Self::EmptyExponent(_) => 105,
+            Self::EmptyInteger(_) => 106,
+            Self::EmptyFraction(_) => 107,
+            Self::InvalidPositiveMantissaSign(_) => 108,
+            Self::MissingMantissaSign(_) => 109,
+            Self::InvalidExponent(_) => 110,
+            Self::InvalidPositiveExponentSign(_) => 111,
+            Self::MissingExponentSign(_) => 112,
+            Self::ExponentWithoutFraction(_) => 113,
+            Self::InvalidLeadingZeros(_) => 114,
+            Self::MissingExponent(_) => 115,
+            Self::MissingSign(_) => 116,
+            Self::InvalidPositiveSign(_) => 117,
+            Self::InvalidNegativeSign(_) => 118,
+            Self::MissingBasePrefix(_) => 119,
+            Self::MissingBaseSuffix(_) => 120,
+            Self::InexactNarrowing(_) => 121,
+            Self::RequiresSlowPath(_) => 122,
+            Self::Internal(_) => 123,
+
+            // NUMBER FORMAT ERRORS
+            Self::InvalidMantissaRadix => 200,
+            Self::InvalidExponentBase => 201,
+            Self::InvalidExponentRadix => 202,
+            Self::InvalidDigitSeparator => 203,
+            Self::InvalidDecimalPoint => 204,
+            Self::InvalidExponentSymbol => 205,
+            Self::InvalidBasePrefix => 206,
+            Self::InvalidBaseSuffix => 207,
+            Self::InvalidPunctuation => 208,
+            Self::InvalidExponentFlags => 209,
+            Self::InvalidMantissaSign => 210,
+            Self::InvalidExponentSign => 211,
+            Self::InvalidSpecial => 212,
+            Self::InvalidConsecutiveIntegerDigitSeparator => 213,
+            Self::InvalidConsecutiveFractionDigitSeparator => 214,
+            Self::InvalidConsecutiveExponentDigitSeparator => 215,
+            Self::InvalidFlags => 216,
+            Self::InvalidFormatSpec(_) => 217,
+            Self::DigitPunctuation(_) => 218,
+            Self::ConflictingPunctuation(_, _) => 219,
+
+            // OPTION ERRORS
+            Self::InvalidNanString => 300,
+            Self::NanStringTooLong => 301,
+            Self::InvalidInfString => 302,
+            Self::InfStringTooLong => 303,
+            Self::InvalidInfinityString => 304,
+            Self::InfinityStringTooLong => 305,
+            Self::InfinityStringTooShort => 306,
+            Self::InvalidFloatParseAlgorithm => 307,
+            Self::InvalidRadix => 308,
+            Self::InvalidFloatPrecision => 309,
+            Self::InvalidNegativeExponentBreak => 310,
+            Self::InvalidPositiveExponentBreak => 311,
+            Self::InvalidFillCharacter => 312,
+
+            // WRITE ERRORS
+            Self::BufferTooSmall {
+                ..
+            } => 400,
+
+            // NOT AN ERROR
+            Self::Success => 0,
+        }
+    }
+
+    /// Get the kind of error, from the group of the error code.
+    pub const fn kind(&self) -> ErrorKind {
+        match self.code() / 100 {
+            0 => ErrorKind::Success,
+            1 => ErrorKind::Parse,
+            2 => ErrorKind::Format,
+            3 => ErrorKind::Options,
+            _ => ErrorKind::Write,
+        }
+    }
+
+    /// Get a hint for what was expected at the index of a parsing error.
+    ///
+    /// This is only provided for errors where something required was
+    /// missing, IE, `MissingSign` expects a `+` or `-` sign.
+    pub const fn expected(&self) -> Option<&'static str> {
+        match self {
+            Self::Empty(_) => Some("a number"),
+            Self::EmptyMantissa(_) | Self::EmptyInteger(_) | Self::EmptyFraction(_) => {
+                Some("a digit")
+            },
+            Self::EmptyExponent(_) => Some("an exponent digit"),
+            Self::MissingMantissaSign(_) | Self::MissingExponentSign(_) | Self::MissingSign(_) => {
+                Some("a `+` or `-` sign")
+            },
+            Self::ExponentWithoutFraction(_) => Some("a fraction"),
+            Self::MissingExponent(_) => Some("an exponent"),
+            Self::MissingBasePrefix(_) => Some("a base prefix"),
+            Self::MissingBaseSuffix(_) => Some("a base suffix"),
+            _ => None,
+        }
+    }
+
+    /// Get a short description of a parsing error, if the error is unexpected
+    /// input, so the offending character can be included in the message.
+    const fn parse_description(&self) -> (&'static str, bool) {
+        match self {
+            Self::Overflow(_) => ("numeric overflow", false),
+            Self::Underflow(_) => ("numeric underflow", false),
+            Self::InvalidDigit(_) => ("invalid digit", true),
+            Self::Empty(_) => ("empty input", false),
+            Self::EmptyMantissa(_) => ("no significant digits", false),
+            Self::EmptyExponent(_) => ("empty exponent", false),
+            Self::EmptyInteger(_) => ("no integer digits", false),
+            Self::EmptyFraction(_) => ("no fraction digits", false),
+            Self::InvalidPositiveMantissaSign(_) => ("invalid positive sign", true),
+            Self::MissingMantissaSign(_) => ("missing sign", false),
+            Self::InvalidExponent(_) => ("exponent not allowed", true),
+            Self::InvalidPositiveExponentSign(_) => ("invalid positive exponent sign", true),
+            Self::MissingExponentSign(_) => ("missing exponent sign", false),
+            Self::ExponentWithoutFraction(_) => ("exponent without a fraction", true),
+            Self::InvalidLeadingZeros(_) => ("invalid leading zero", true),
+            Self::MissingExponent(_) => ("missing exponent", false),
+            Self::MissingSign(_) => ("missing sign", false),
+            Self::InvalidPositiveSign(_) => ("invalid positive sign", true),
+            Self::InvalidNegativeSign(_) => ("invalid negative sign for an unsigned type", true),
+            Self::MissingBasePrefix(_) => ("missing base prefix", false),
+            Self::MissingBaseSuffix(_) => ("missing base suffix", false),
+            Self::InexactNarrowing(_) => ("inexact narrowing conversion", false),
+            Self::RequiresSlowPath(_) => ("requires the slow path", false),
+            Self::Internal(_) => ("internal error", false),
+            _ => ("", false),
+        }
+    }
+
+    /// Add the input that was parsed, for contextual error messages.
+    ///
+    /// This allows the error message to include the character at the
+    /// index of the error, IE, `invalid digit 'x' at index 4`.
+    ///
+    /// ```rust
+    /// # use lexical_util::error::Error;
+    /// let bytes = b"1234x";
+    /// let error = Error::InvalidDigit(4);
+    /// assert_eq!(error.context(bytes).to_string(), "invalid digit 'x' at index 4");
+    /// ```
+    pub const fn context(self, bytes: &[u8]) -> ErrorContext<'_> {
+        ErrorContext {
+            error: self,
+            bytes,
+        }
+    }
+
     is_error_type!(is_overflow, Overflow(_));
     is_error_type!(is_underflow, Underflow(_));
     is_error_type!(is_invalid_digit, InvalidDigit(_));
@@ -218,6 +585,11 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_missing_base_prefix, MissingBasePrefix(_));
+    is_error_type!(is_missing_base_suffix, MissingBaseSuffix(_));
+    is_error_type!(is_inexact_narrowing, InexactNarrowing(_));
+    is_error_type!(is_requires_slow_path, RequiresSlowPath(_));
+    is_error_type!(is_internal, Internal(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -244,6 +616,9 @@ impl Error {
         InvalidConsecutiveExponentDigitSeparator
     );
     is_error_type!(is_invalid_flags, InvalidFlags);
+    is_error_type!(is_invalid_format_spec, InvalidFormatSpec(_));
+    is_error_type!(is_digit_punctuation, DigitPunctuation(_));
+    is_error_type!(is_conflicting_punctuation, ConflictingPunctuation(_, _));
     is_error_type!(is_invalid_nan_string, InvalidNanString);
     is_error_type!(is_nan_string_too_long, NanStringTooLong);
     is_error_type!(is_invalid_inf_string, InvalidInfString);
@@ -256,6 +631,8 @@ impl Error {
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
     is_error_type!(is_invalid_negative_exponent_break, InvalidNegativeExponentBreak);
     is_error_type!(is_invalid_positive_exponent_break, InvalidPositiveExponentBreak);
+    is_error_type!(is_invalid_fill_character, InvalidFillCharacter);
+    is_error_type!(is_buffer_too_small, BufferTooSmall { .. });
     is_error_type!(is_success, Success);
 }
 
@@ -271,6 +648,9 @@ macro_rules! format_message {
     ($formatter:ident, $message:literal) => {
         write!($formatter, "lexical number format error: {}", $message)
     };
+    ($formatter:ident, $message:literal, $index:ident) => {
+        write!($formatter, "lexical number format error: {} at index {}", $message, $index)
+    };
 }
 
 /// Add an error message for options errors.
@@ -280,6 +660,13 @@ macro_rules! options_message {
     };
 }
 
+/// Add an error message for write errors.
+macro_rules! write_message {
+    ($formatter:ident, $message:literal, $needed:ident) => {
+        write!($formatter, "lexical write error: {}, {} bytes needed", $message, $needed)
+    };
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -303,6 +690,11 @@ impl fmt::Display for Error {
             Self::MissingSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for integer'", index),
             Self::InvalidPositiveSign(index) => write_parse_error!(formatter, "'invalid `+` sign for an integer was found'", index),
             Self::InvalidNegativeSign(index) => write_parse_error!(formatter, "'invalid `-` sign for an unsigned type was found'", index),
+            Self::MissingBasePrefix(index) => write_parse_error!(formatter, "'required base prefix was not found'", index),
+            Self::MissingBaseSuffix(index) => write_parse_error!(formatter, "'required base suffix was not found'", index),
+            Self::InexactNarrowing(index) => write_parse_error!(formatter, "'number cannot be narrowed without losing information'", index),
+            Self::RequiresSlowPath(index) => write_parse_error!(formatter, "'number cannot be parsed at compile time without the slow path'", index),
+            Self::Internal(index) => write_parse_error!(formatter, "'internal invariant of the parser did not hold'", index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => format_message!(formatter, "'invalid radix for mantissa digits'"),
@@ -322,6 +714,9 @@ impl fmt::Display for Error {
             Self::InvalidConsecutiveFractionDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the fraction without setting a valid location'"),
             Self::InvalidConsecutiveExponentDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the exponent without setting a valid location'"),
             Self::InvalidFlags => format_message!(formatter, "'invalid flags enabled without the format feature'"),
+            Self::InvalidFormatSpec(index) => format_message!(formatter, "'invalid format specification'", index),
+            Self::DigitPunctuation(punctuation) => write!(formatter, "lexical number format error: 'invalid {}: must not be a valid digit'", punctuation),
+            Self::ConflictingPunctuation(x, y) => write!(formatter, "lexical number format error: 'invalid punctuation: the {} and the {} are the same character'", x, y),
 
             // OPTION ERRORS
             Self::InvalidNanString => options_message!(formatter, "'NaN string must started with `n`'"),
@@ -336,6 +731,10 @@ impl fmt::Display for Error {
             Self::InvalidFloatPrecision => options_message!(formatter, "'invalid float precision: min digits is larger than max digits'"),
             Self::InvalidNegativeExponentBreak => options_message!(formatter, "'invalid negative exponent break: value is above 0'"),
             Self::InvalidPositiveExponentBreak => options_message!(formatter, "'invalid positive exponent break: value is below 0'"),
+            Self::InvalidFillCharacter => options_message!(formatter, "'invalid fill character: must be ASCII'"),
+
+            // WRITE ERRORS
+            Self::BufferTooSmall { needed } => write_message!(formatter, "'buffer is too small'", needed),
 
             // NOT AN ERROR
             Self::Success => write!(formatter, "'not actually an error'"),
@@ -346,3 +745,145 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl error::Error for Error {
 }
+
+/// An error with the input it occurred in, for contextual error messages.
+///
+/// For parsing errors, the message includes the byte index, the offending
+/// character when the input is invalid, and a hint for what was expected
+/// when something required is missing. Other errors use the message of
+/// the [`Error`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ErrorContext<'a> {
+    error: Error,
+    bytes: &'a [u8],
+}
+
+impl<'a> ErrorContext<'a> {
+    /// Get the underlying error.
+    #[inline(always)]
+    pub const fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Get the input the error occurred in.
+    #[inline(always)]
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the kind of error.
+    #[inline(always)]
+    pub const fn kind(&self) -> ErrorKind {
+        self.error.kind()
+    }
+
+    /// Get the byte index of the error, if it has one.
+    #[inline(always)]
+    pub fn index(&self) -> Option<usize> {
+        self.error.index().copied()
+    }
+
+    /// Get the character at the index of the error, if it is in the input.
+    #[inline(always)]
+    pub fn found(&self) -> Option<u8> {
+        self.index().and_then(|index| self.bytes.get(index).copied())
+    }
+
+    /// Get a hint for what was expected at the index of the error.
+    #[inline(always)]
+    pub const fn expected(&self) -> Option<&'static str> {
+        self.error.expected()
+    }
+}
+
+impl<'a> fmt::Display for ErrorContext<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (description, is_unexpected) = self.error.parse_description();
+        let index = match self.index() {
+            Some(index) if self.kind() == ErrorKind::Parse => index,
+            _ => return fmt::Display::fmt(&self.error, formatter),
+        };
+        formatter.write_str(description)?;
+        match self.found() {
+            Some(c) if is_unexpected && c.is_ascii_graphic() => {
+                write!(formatter, " '{}'", c as char)?
+            },
+            Some(c) if is_unexpected => write!(formatter, " '\\x{:02x}'", c)?,
+            _ => (),
+        }
+        write!(formatter, " at index {}", index)?;
+        if let Some(expected) = self.expected() {
+            write!(formatter, ", expected {}", expected)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> error::Error for ErrorContext<'a> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A parsing error with the value parsed before the error occurred.
+///
+/// This allows best-effort recovery from invalid or overflowing input,
+/// without parsing the input again. The value is the number parsed from
+/// the longest valid prefix of the input, if any, and the count is the
+/// number of bytes in that prefix.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PartialError<T> {
+    error: Error,
+    value: Option<T>,
+    count: usize,
+}
+
+impl<T> PartialError<T> {
+    /// Create an error with the partially parsed value.
+    #[inline(always)]
+    pub const fn new(error: Error, value: Option<T>, count: usize) -> Self {
+        Self {
+            error,
+            value,
+            count,
+        }
+    }
+
+    /// Get the underlying error.
+    #[inline(always)]
+    pub const fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Get the value parsed before the error occurred, if any.
+    #[inline(always)]
+    pub const fn value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Get the number of bytes parsed before the error occurred.
+    #[inline(always)]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Consume the error, returning the partially parsed value.
+    #[inline(always)]
+    pub fn into_value(self) -> Option<T> {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for PartialError<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}, after parsing {} bytes", self.error, self.count)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> error::Error for PartialError<T> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -711,6 +711,63 @@ macro_rules! from_flag {
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
 /// for the packed struct.
+/// Get the error type from a format packed struct.
+///
+/// This is the validation used by [`NumberFormat::error`], for formats
+/// only known at runtime.
+#[allow(clippy::if_same_then_else)]
+pub(crate) const fn packed_error(format: u128) -> Error {
+    if !flags::is_valid_radix(flags::mantissa_radix(format)) {
+        Error::InvalidMantissaRadix
+    } else if !flags::is_valid_radix(flags::exponent_base(format)) {
+        Error::InvalidExponentBase
+    } else if !flags::is_valid_radix(flags::exponent_radix(format)) {
+        Error::InvalidExponentRadix
+    } else if !flags::is_valid_digit_separator(format) {
+        Error::InvalidDigitSeparator
+    } else if !flags::is_valid_base_prefix(format) {
+        Error::InvalidBasePrefix
+    } else if !flags::is_valid_base_suffix(format) {
+        Error::InvalidBaseSuffix
+    } else if !flags::is_valid_punctuation(format) {
+        Error::InvalidPunctuation
+    } else if !flags::is_valid_exponent_flags(format) {
+        Error::InvalidExponentFlags
+    } else if has_flag(format, flags::NO_POSITIVE_MANTISSA_SIGN)
+        && has_flag(format, flags::REQUIRED_MANTISSA_SIGN)
+    {
+        Error::InvalidMantissaSign
+    } else if has_flag(format, flags::NO_POSITIVE_EXPONENT_SIGN)
+        && has_flag(format, flags::REQUIRED_EXPONENT_SIGN)
+    {
+        Error::InvalidExponentSign
+    } else if has_flag(format, flags::NO_SPECIAL) && has_flag(format, flags::CASE_SENSITIVE_SPECIAL) {
+        Error::InvalidSpecial
+    } else if has_flag(format, flags::NO_SPECIAL) && has_flag(format, flags::SPECIAL_DIGIT_SEPARATOR) {
+        Error::InvalidSpecial
+    } else if format & flags::INTEGER_DIGIT_SEPARATOR_FLAG_MASK
+        == flags::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR
+    {
+        Error::InvalidConsecutiveIntegerDigitSeparator
+    } else if format & flags::FRACTION_DIGIT_SEPARATOR_FLAG_MASK
+        == flags::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR
+    {
+        Error::InvalidConsecutiveFractionDigitSeparator
+    } else if format & flags::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK
+        == flags::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
+    {
+        Error::InvalidConsecutiveExponentDigitSeparator
+    } else {
+        Error::Success
+    }
+}
+
+/// Determine if the flag is set in the format packed struct.
+#[inline(always)]
+const fn has_flag(format: u128, flag: u128) -> bool {
+    format & flag != 0
+}
+
 #[doc(hidden)]
 pub struct NumberFormat<const FORMAT: u128>;
 
@@ -731,41 +788,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     }
 
     /// Get the error type from the format.
-    #[allow(clippy::if_same_then_else)]
     pub const fn error(&self) -> Error {
-        if !flags::is_valid_radix(self.mantissa_radix()) {
-            Error::InvalidMantissaRadix
-        } else if !flags::is_valid_radix(self.exponent_base()) {
-            Error::InvalidExponentBase
-        } else if !flags::is_valid_radix(self.exponent_radix()) {
-            Error::InvalidExponentRadix
-        } else if !flags::is_valid_digit_separator(FORMAT) {
-            Error::InvalidDigitSeparator
-        } else if !flags::is_valid_base_prefix(FORMAT) {
-            Error::InvalidBasePrefix
-        } else if !flags::is_valid_base_suffix(FORMAT) {
-            Error::InvalidBaseSuffix
-        } else if !flags::is_valid_punctuation(FORMAT) {
-            Error::InvalidPunctuation
-        } else if !flags::is_valid_exponent_flags(FORMAT) {
-            Error::InvalidExponentFlags
-        } else if self.no_positive_mantissa_sign() && self.required_mantissa_sign() {
-            Error::InvalidMantissaSign
-        } else if self.no_positive_exponent_sign() && self.required_exponent_sign() {
-            Error::InvalidExponentSign
-        } else if self.no_special() && self.case_sensitive_special() {
-            Error::InvalidSpecial
-        } else if self.no_special() && self.special_digit_separator() {
-            Error::InvalidSpecial
-        } else if self.integer_digit_separator_flags() == flags::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveIntegerDigitSeparator
-        } else if self.fraction_digit_separator_flags() == flags::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveFractionDigitSeparator
-        } else if self.exponent_digit_separator_flags() == flags::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveExponentDigitSeparator
-        } else {
-            Error::Success
-        }
+        packed_error(FORMAT)
     }
 
     // NON-DIGIT SEPARATOR FLAGS & MASKS
@@ -941,6 +965,24 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If a base prefix is required before the significant digits.
+    pub const REQUIRED_BASE_PREFIX: bool = from_flag!(FORMAT, REQUIRED_BASE_PREFIX);
+
+    /// Get if a base prefix is required before the significant digits.
+    #[inline(always)]
+    pub const fn required_base_prefix(&self) -> bool {
+        Self::REQUIRED_BASE_PREFIX
+    }
+
+    /// If a base suffix is required after the significant digits.
+    pub const REQUIRED_BASE_SUFFIX: bool = from_flag!(FORMAT, REQUIRED_BASE_SUFFIX);
+
+    /// Get if a base suffix is required after the significant digits.
+    #[inline(always)]
+    pub const fn required_base_suffix(&self) -> bool {
+        Self::REQUIRED_BASE_SUFFIX
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -1288,6 +1330,18 @@ pub const PYTHON3_LITERAL: u128 = PYTHON36_LITERAL;
 pub const PYTHON3_STRING: u128 = NumberFormatBuilder::new().build();
 const_assert!(NumberFormat::<{ PYTHON3_STRING }> {}.is_valid());
 
+// PYTHON REPR [012345678MN]
+/// Number format to write a float like Python's `repr`.
+///
+/// This should be used with the write options of the same name.
+#[rustfmt::skip]
+pub const PYTHON_REPR: u128 = NumberFormatBuilder::new()
+    .required_exponent_digits(false)
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build();
+const_assert!(NumberFormat::<{ PYTHON_REPR }> {}.is_valid());
+
 // PYTHON3.6+ LITERAL [013456N-_]
 /// Number format for a Python3.6 or higher literal floating-point number.
 #[rustfmt::skip]
@@ -1550,6 +1604,18 @@ const_assert!(NumberFormat::<{ C18_LITERAL }> {}.is_valid());
 pub const C18_STRING: u128 = NumberFormatBuilder::new().build();
 const_assert!(NumberFormat::<{ C18_STRING }> {}.is_valid());
 
+// C PRINTF G [012345678MN]
+/// Number format to write a float like C's `printf("%g")`.
+///
+/// This should be used with the write options of the same name.
+#[rustfmt::skip]
+pub const C_PRINTF_G: u128 = NumberFormatBuilder::new()
+    .required_exponent_digits(false)
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build();
+const_assert!(NumberFormat::<{ C_PRINTF_G }> {}.is_valid());
+
 // C18 HEX LITERAL [01345678MN]
 /// Number format for a C++98 literal hexadecimal floating-point number.
 #[rustfmt::skip]
@@ -1803,6 +1869,18 @@ pub const GO_STRING: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ GO_STRING }> {}.is_valid());
 
+// GO FORMAT FLOAT [012345678MN]
+/// Number format to write a float like Go's `strconv.FormatFloat(f, 'g', -1, 64)`.
+///
+/// This should be used with the write options of the same name.
+#[rustfmt::skip]
+pub const GO_FORMAT_FLOAT: u128 = NumberFormatBuilder::new()
+    .required_exponent_digits(false)
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build();
+const_assert!(NumberFormat::<{ GO_FORMAT_FLOAT }> {}.is_valid());
+
 // HASKELL LITERAL [456MN]
 /// Number format for a Haskell literal floating-point number.
 #[rustfmt::skip]
@@ -1845,6 +1923,19 @@ pub const JAVASCRIPT_STRING: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ JAVASCRIPT_STRING }> {}.is_valid());
 
+// JAVASCRIPT TO STRING [012345678MN]
+/// Number format to write a float like Javascript's `Number.prototype.toString`.
+///
+/// This should be used with the write options of the same name.
+#[rustfmt::skip]
+pub const JAVASCRIPT_TO_STRING: u128 = NumberFormatBuilder::new()
+    .required_exponent_digits(false)
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build();
+
+const_assert!(NumberFormat::<{ JAVASCRIPT_TO_STRING }> {}.is_valid());
+
 // PERL LITERAL [0134569ABDEFGHIJKMN-_]
 /// Number format for a Perl literal floating-point number.
 #[rustfmt::skip]
@@ -1905,10 +1996,13 @@ pub const JAVA_STRING: u128 = NumberFormatBuilder::new()
 const_assert!(NumberFormat::<{ JAVA_STRING }> {}.is_valid());
 
 // R LITERAL [01345678MN]
+// Note: R accepts the `L` integer suffix on any number, IE, `1e5L`.
 /// Number format for a R literal floating-point number.
 #[rustfmt::skip]
 pub const R_LITERAL: u128 = NumberFormatBuilder::new()
+    .base_suffix(num::NonZeroU8::new(b'L'))
     .case_sensitive_special(true)
+    .case_sensitive_base_suffix(true)
     .build();
 
 const_assert!(NumberFormat::<{ R_LITERAL }> {}.is_valid());
@@ -2533,6 +2627,10 @@ const_assert!(NumberFormat::<{ OCTAVE_STRING }> {}.is_valid());
 // MATLAB LITERAL [013456789ABDFGHIJKMN-_]
 // Note: Matlab accepts both NaN and nan, Inf and inf.
 /// Number format for an Matlab literal floating-point number.
+///
+/// Imaginary literals, such as `1i` or `2.5e3j`, are not valid floats:
+/// the partial parsers stop at the imaginary unit, so it can be
+/// handled by the caller.
 #[rustfmt::skip]
 pub const MATLAB_LITERAL: u128 = NumberFormatBuilder::new()
     .digit_separator(num::NonZeroU8::new(b'_'))
@@ -2652,6 +2750,30 @@ pub const POSTGRESQL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ POSTGRESQL }> {}.is_valid());
 
+// POSTGRESQL16 LITERAL [0134569ABMN-_]
+/// Number format for a PostgreSQL 16+ literal floating-point number.
+///
+/// PostgreSQL 16 added underscores as digit separators, which must
+/// be between digits, IE, `1_000.000_1`.
+#[rustfmt::skip]
+pub const POSTGRESQL16_LITERAL: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b'_'))
+    .no_special(true)
+    .internal_digit_separator(true)
+    .build();
+
+const_assert!(NumberFormat::<{ POSTGRESQL16_LITERAL }> {}.is_valid());
+
+// POSTGRESQL STRING [0134567MN]
+/// Number format to parse a PostgreSQL float from string.
+///
+/// This accepts the special values of the `float8` input function,
+/// IE, `'NaN'`, `'Infinity'` and `'-inf'`, case-insensitively.
+#[rustfmt::skip]
+pub const POSTGRESQL_STRING: u128 = NumberFormatBuilder::new().build();
+
+const_assert!(NumberFormat::<{ POSTGRESQL_STRING }> {}.is_valid());
+
 // MYSQL [013456MN]
 /// Number format for a MySQL literal floating-point number.
 #[rustfmt::skip]
@@ -2671,6 +2793,19 @@ pub const MONGODB: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ MONGODB }> {}.is_valid());
 
+// SCIENTIFIC FIXED
+/// Number format to write a float like C's `printf("%e")`.
+///
+/// This should be used with the write options of the same name.
+#[rustfmt::skip]
+pub const SCIENTIFIC_FIXED: u128 = NumberFormatBuilder::new()
+    .required_exponent_notation(true)
+    .required_exponent_sign(true)
+    .case_sensitive_special(true)
+    .build();
+
+const_assert!(NumberFormat::<{ SCIENTIFIC_FIXED }> {}.is_valid());
+
 // HIDDEN DEFAULTS AND INTERFACES
 
 /// Number format when no flags are set.
@@ -16,6 +16,7 @@
 #![cfg_attr(feature = "format", doc = " - [PYTHON_STRING](crate::format::PYTHON_STRING)")]
 #![cfg_attr(feature = "format", doc = " - [PYTHON3_LITERAL](crate::format::PYTHON3_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [PYTHON3_STRING](crate::format::PYTHON3_STRING)")]
+#![cfg_attr(feature = "format", doc = " - [PYTHON_REPR](crate::format::PYTHON_REPR)")]
 #![cfg_attr(feature = "format", doc = " - [PYTHON36_LITERAL](crate::format::PYTHON36_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [PYTHON35_LITERAL](crate::format::PYTHON35_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [PYTHON2_LITERAL](crate::format::PYTHON2_LITERAL)")]
@@ -68,6 +69,7 @@
 #![cfg_attr(feature = "format", doc = " - [CXX98_STRING](crate::format::CXX98_STRING)")]
 #![cfg_attr(feature = "format", doc = " - [C_LITERAL](crate::format::C_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [C_STRING](crate::format::C_STRING)")]
+#![cfg_attr(feature = "format", doc = " - [C_PRINTF_G](crate::format::C_PRINTF_G)")]
 #![cfg_attr(
     all(feature = "format", feature = "power-of-two"),
     doc = " - [C_HEX_LITERAL](crate::format::C_HEX_LITERAL)"
@@ -128,10 +130,15 @@
 #![cfg_attr(feature = "format", doc = " - [SWIFT_STRING](crate::format::SWIFT_STRING)")]
 #![cfg_attr(feature = "format", doc = " - [GO_LITERAL](crate::format::GO_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [GO_STRING](crate::format::GO_STRING)")]
+#![cfg_attr(feature = "format", doc = " - [GO_FORMAT_FLOAT](crate::format::GO_FORMAT_FLOAT)")]
 #![cfg_attr(feature = "format", doc = " - [HASKELL_LITERAL](crate::format::HASKELL_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [HASKELL_STRING](crate::format::HASKELL_STRING)")]
 #![cfg_attr(feature = "format", doc = " - [JAVASCRIPT_LITERAL](crate::format::JAVASCRIPT_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [JAVASCRIPT_STRING](crate::format::JAVASCRIPT_STRING)")]
+#![cfg_attr(
+    feature = "format",
+    doc = " - [JAVASCRIPT_TO_STRING](crate::format::JAVASCRIPT_TO_STRING)"
+)]
 #![cfg_attr(feature = "format", doc = " - [PERL_LITERAL](crate::format::PERL_LITERAL)")]
 #![cfg_attr(feature = "format", doc = " - [PERL_STRING](crate::format::PERL_STRING)")]
 #![cfg_attr(feature = "format", doc = " - [PHP_LITERAL](crate::format::PHP_LITERAL)")]
@@ -222,8 +229,11 @@
 #![cfg_attr(feature = "format", doc = " - [XML](crate::format::XML)")]
 #![cfg_attr(feature = "format", doc = " - [SQLITE](crate::format::SQLITE)")]
 #![cfg_attr(feature = "format", doc = " - [POSTGRESQL](crate::format::POSTGRESQL)")]
+#![cfg_attr(feature = "format", doc = " - [POSTGRESQL16_LITERAL](crate::format::POSTGRESQL16_LITERAL)")]
+#![cfg_attr(feature = "format", doc = " - [POSTGRESQL_STRING](crate::format::POSTGRESQL_STRING)")]
 #![cfg_attr(feature = "format", doc = " - [MYSQL](crate::format::MYSQL)")]
 #![cfg_attr(feature = "format", doc = " - [MONGODB](crate::format::MONGODB)")]
+#![cfg_attr(feature = "format", doc = " - [SCIENTIFIC_FIXED](crate::format::SCIENTIFIC_FIXED)")]
 //!
 //! # Syntax Flags
 //!
@@ -248,6 +258,8 @@
 //! - [CASE_SENSITIVE_EXPONENT](crate::format::CASE_SENSITIVE_EXPONENT)
 //! - [CASE_SENSITIVE_BASE_PREFIX](crate::format::CASE_SENSITIVE_BASE_PREFIX)
 //! - [CASE_SENSITIVE_BASE_SUFFIX](crate::format::CASE_SENSITIVE_BASE_SUFFIX)
+//! - [REQUIRED_BASE_PREFIX](crate::format::REQUIRED_BASE_PREFIX)
+//! - [REQUIRED_BASE_SUFFIX](crate::format::REQUIRED_BASE_SUFFIX)
 //!
 //! # Digit Separator Flags
 //!
@@ -312,14 +324,31 @@
 //! - [is_valid_base_prefix](is_valid_base_prefix)
 //! - [is_valid_base_suffix](is_valid_base_suffix)
 //! - [is_valid_punctuation](is_valid_punctuation)
+//! - [is_valid_options_punctuation](is_valid_options_punctuation)
+//! - [punctuation_error](punctuation_error)
+//! - [options_punctuation_error](options_punctuation_error)
 //! - [is_valid_radix](is_valid_radix)
+//! - [is_valid_float_radix](is_valid_float_radix)
+//!
+//! # Runtime Formats
+//!
+//! A format packed struct validated at runtime, for formats that are
+//! not known at compile time.
+//!
+//! - [Format](crate::format::Format)
+//! - [SeparatorPolicy](crate::format::SeparatorPolicy), where digit
+//!   separators may occur in each component.
+//! - [FormatSpec](crate::format::FormatSpec), parsed from a textual
+//!   specification.
 
 #[cfg(feature = "format")]
 pub use crate::feature_format::*;
 pub use crate::format_builder::*;
 pub use crate::format_flags::*;
+pub use crate::format_spec::FormatSpec;
 #[cfg(not(feature = "format"))]
 pub use crate::not_feature_format::*;
+pub use crate::runtime_format::{Format, SeparatorPolicy};
 
 use crate::error::Error;
 use static_assertions::const_assert;
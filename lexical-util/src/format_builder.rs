@@ -1,6 +1,14 @@
 //! Builder for the number format.
 
+use crate::error::Error;
+#[cfg(feature = "format")]
+use crate::feature_format::packed_error;
 use crate::format_flags as flags;
+#[cfg(not(feature = "format"))]
+use crate::not_feature_format::packed_error;
+use crate::result::Result;
+#[cfg(feature = "format")]
+use crate::runtime_format::SeparatorPolicy;
 use core::{mem, num};
 use static_assertions::const_assert;
 
@@ -73,6 +81,8 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`                 - If exponent characters are case-sensitive.
 /// * `case_sensitive_base_prefix`              - If base prefixes are case-sensitive.
 /// * `case_sensitive_base_suffix`              - If base suffixes are case-sensitive.
+/// * `required_base_prefix`                    - If a base prefix is required.
+/// * `required_base_suffix`                    - If a base suffix is required.
 /// * `integer_internal_digit_separator`        - If digit separators are allowed between integer digits.
 /// * `fraction_internal_digit_separator`       - If digit separators are allowed between fraction digits.
 /// * `exponent_internal_digit_separator`       - If digit separators are allowed between exponent digits.
@@ -147,6 +157,8 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`
 /// * `case_sensitive_base_prefix`
 /// * `case_sensitive_base_suffix`
+/// * `required_base_prefix`
+/// * `required_base_suffix`
 /// * `integer_internal_digit_separator`
 /// * `fraction_internal_digit_separator`
 /// * `exponent_internal_digit_separator`
@@ -184,6 +196,8 @@ pub struct NumberFormatBuilder {
     case_sensitive_exponent: bool,
     case_sensitive_base_prefix: bool,
     case_sensitive_base_suffix: bool,
+    required_base_prefix: bool,
+    required_base_suffix: bool,
     integer_internal_digit_separator: bool,
     fraction_internal_digit_separator: bool,
     exponent_internal_digit_separator: bool,
@@ -230,6 +244,8 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: false,
             case_sensitive_base_prefix: false,
             case_sensitive_base_suffix: false,
+            required_base_prefix: false,
+            required_base_suffix: false,
             integer_internal_digit_separator: false,
             fraction_internal_digit_separator: false,
             exponent_internal_digit_separator: false,
@@ -429,6 +445,18 @@ impl NumberFormatBuilder {
         self.case_sensitive_base_suffix
     }
 
+    /// Get if a base prefix is required.
+    #[inline(always)]
+    pub const fn get_required_base_prefix(&self) -> bool {
+        self.required_base_prefix
+    }
+
+    /// Get if a base suffix is required.
+    #[inline(always)]
+    pub const fn get_required_base_suffix(&self) -> bool {
+        self.required_base_suffix
+    }
+
     /// Get if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn get_integer_internal_digit_separator(&self) -> bool {
@@ -557,8 +585,11 @@ impl NumberFormatBuilder {
     }
 
     /// Set the optional character for the base suffix.
+    ///
+    /// The suffix may also be a type suffix for decimal numbers,
+    /// such as the `L` in R's `100L`.
     #[inline(always)]
-    #[cfg(all(feature = "power-of-two", feature = "format"))]
+    #[cfg(feature = "format")]
     pub const fn base_suffix(mut self, base_suffix: OptionU8) -> Self {
         self.base_suffix = base_suffix;
         self
@@ -713,12 +744,34 @@ impl NumberFormatBuilder {
 
     /// Set if base suffixes are case-sensitive.
     #[inline(always)]
-    #[cfg(all(feature = "power-of-two", feature = "format"))]
+    #[cfg(feature = "format")]
     pub const fn case_sensitive_base_suffix(mut self, flag: bool) -> Self {
         self.case_sensitive_base_suffix = flag;
         self
     }
 
+    /// Set if a base prefix is required.
+    ///
+    /// The base prefix character must also be set. Parsers reject numbers
+    /// without the prefix, and writers always write it.
+    #[inline(always)]
+    #[cfg(all(feature = "power-of-two", feature = "format"))]
+    pub const fn required_base_prefix(mut self, flag: bool) -> Self {
+        self.required_base_prefix = flag;
+        self
+    }
+
+    /// Set if a base suffix is required.
+    ///
+    /// The base suffix character must also be set. Parsers reject numbers
+    /// without the suffix, and writers always write it.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn required_base_suffix(mut self, flag: bool) -> Self {
+        self.required_base_suffix = flag;
+        self
+    }
+
     /// Set if digit separators are allowed between integer digits.
     #[inline(always)]
     #[cfg(feature = "format")]
@@ -907,6 +960,39 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set where digit separators may occur in the integer digits.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn integer_separator_policy(mut self, policy: SeparatorPolicy) -> Self {
+        self = self.integer_internal_digit_separator(policy.internal);
+        self = self.integer_leading_digit_separator(policy.leading);
+        self = self.integer_trailing_digit_separator(policy.trailing);
+        self = self.integer_consecutive_digit_separator(policy.consecutive);
+        self
+    }
+
+    /// Set where digit separators may occur in the fraction digits.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn fraction_separator_policy(mut self, policy: SeparatorPolicy) -> Self {
+        self = self.fraction_internal_digit_separator(policy.internal);
+        self = self.fraction_leading_digit_separator(policy.leading);
+        self = self.fraction_trailing_digit_separator(policy.trailing);
+        self = self.fraction_consecutive_digit_separator(policy.consecutive);
+        self
+    }
+
+    /// Set where digit separators may occur in the exponent digits.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn exponent_separator_policy(mut self, policy: SeparatorPolicy) -> Self {
+        self = self.exponent_internal_digit_separator(policy.internal);
+        self = self.exponent_leading_digit_separator(policy.leading);
+        self = self.exponent_trailing_digit_separator(policy.trailing);
+        self = self.exponent_consecutive_digit_separator(policy.consecutive);
+        self
+    }
+
     // BUILDER
 
     /// Create 128-bit, packed number format struct from builder options.
@@ -938,6 +1024,8 @@ impl NumberFormatBuilder {
             self.case_sensitive_exponent, CASE_SENSITIVE_EXPONENT ;
             self.case_sensitive_base_prefix, CASE_SENSITIVE_BASE_PREFIX ;
             self.case_sensitive_base_suffix, CASE_SENSITIVE_BASE_SUFFIX ;
+            self.required_base_prefix, REQUIRED_BASE_PREFIX ;
+            self.required_base_suffix, REQUIRED_BASE_SUFFIX ;
             self.integer_internal_digit_separator, INTEGER_INTERNAL_DIGIT_SEPARATOR ;
             self.fraction_internal_digit_separator, FRACTION_INTERNAL_DIGIT_SEPARATOR ;
             self.exponent_internal_digit_separator, EXPONENT_INTERNAL_DIGIT_SEPARATOR ;
@@ -965,7 +1053,87 @@ impl NumberFormatBuilder {
         format
     }
 
+    /// Create 128-bit, packed number format struct, validating it.
+    ///
+    /// Returns the same error as [`NumberFormat::error`], except invalid
+    /// punctuation is reported with the specific character, such as
+    /// [`Error::DigitPunctuation`] if the digit separator is a valid digit,
+    /// or [`Error::ConflictingPunctuation`] if the digit separator and the
+    /// base prefix are the same character.
+    ///
+    /// ```rust
+    /// # use lexical_util::format::{NumberFormatBuilder, STANDARD};
+    /// assert_eq!(NumberFormatBuilder::new().build_checked(), Ok(STANDARD));
+    /// ```
+    ///
+    /// [`NumberFormat::error`]: crate::format::NumberFormat::error
+    #[inline]
+    pub const fn build_checked(&self) -> Result<u128> {
+        let format = self.build();
+        let error = match packed_error(format) {
+            Error::InvalidDigitSeparator
+            | Error::InvalidBasePrefix
+            | Error::InvalidBaseSuffix
+            | Error::InvalidPunctuation => match flags::punctuation_error(format) {
+                Error::Success => packed_error(format),
+                error => error,
+            },
+            error => error,
+        };
+        if error.is_success() {
+            Ok(format)
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Create builder from an existing packed format, to edit and re-pack it.
+    ///
+    /// This is the exact inverse of [`build`]: every field is read from the
+    /// packed struct, so `from_packed(format).build() == format`. Unlike
+    /// [`rebuild`], the exponent base and exponent radix are only set if
+    /// they were set when the format was built, so changing the mantissa
+    /// radix of a format with the default exponent base and radix also
+    /// changes the exponent base and radix.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "format")] {
+    /// use core::num::NonZeroU8;
+    /// use lexical_util::format::{NumberFormat, NumberFormatBuilder, STANDARD};
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::from_packed(STANDARD)
+    ///     .digit_separator(NonZeroU8::new(b'_'))
+    ///     .internal_digit_separator(true)
+    ///     .build();
+    /// assert!(NumberFormat::<FORMAT> {}.is_valid());
+    /// assert_eq!(NumberFormatBuilder::from_packed(FORMAT).build(), FORMAT);
+    /// # }
+    /// ```
+    ///
+    /// The format is not validated: call `is_valid` on [`NumberFormat`],
+    /// or create a [`Format`], on the edited format.
+    ///
+    /// [`build`]: Self::build
+    /// [`rebuild`]: Self::rebuild
+    /// [`NumberFormat`]: crate::format::NumberFormat
+    /// [`Format`]: crate::format::Format
+    #[inline]
+    pub const fn from_packed(format: u128) -> Self {
+        let mut builder = Self::rebuild(format);
+        let exponent_base = (format & flags::EXPONENT_BASE) >> flags::EXPONENT_BASE_SHIFT;
+        let exponent_radix = (format & flags::EXPONENT_RADIX) >> flags::EXPONENT_RADIX_SHIFT;
+        builder.exponent_base = num::NonZeroU8::new(exponent_base as u8);
+        builder.exponent_radix = num::NonZeroU8::new(exponent_radix as u8);
+        builder
+    }
+
     /// Re-create builder from format.
+    ///
+    /// The exponent base and exponent radix default to the mantissa radix
+    /// of the format. Use [`from_packed`] to edit the mantissa radix of
+    /// an existing format.
+    ///
+    /// [`from_packed`]: Self::from_packed
     #[inline]
     pub const fn rebuild(format: u128) -> Self {
         NumberFormatBuilder {
@@ -993,6 +1161,8 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: has_flag!(format, CASE_SENSITIVE_EXPONENT),
             case_sensitive_base_prefix: has_flag!(format, CASE_SENSITIVE_BASE_PREFIX),
             case_sensitive_base_suffix: has_flag!(format, CASE_SENSITIVE_BASE_SUFFIX),
+            required_base_prefix: has_flag!(format, REQUIRED_BASE_PREFIX),
+            required_base_suffix: has_flag!(format, REQUIRED_BASE_SUFFIX),
             integer_internal_digit_separator: has_flag!(format, INTEGER_INTERNAL_DIGIT_SEPARATOR),
             fraction_internal_digit_separator: has_flag!(format, FRACTION_INTERNAL_DIGIT_SEPARATOR),
             exponent_internal_digit_separator: has_flag!(format, EXPONENT_INTERNAL_DIGIT_SEPARATOR),
@@ -1025,3 +1195,72 @@ impl Default for NumberFormatBuilder {
         Self::new()
     }
 }
+
+// ARBITRARY
+
+/// Generate an optional ASCII character for the punctuation.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_char(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<OptionU8> {
+    match u.arbitrary()? {
+        true => Ok(num::NonZeroU8::new(u.int_in_range(1..=0x7F)?)),
+        false => Ok(None),
+    }
+}
+
+/// Generate an optional radix, in the range `[2, 36]`.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_radix(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<OptionU8> {
+    match u.arbitrary()? {
+        true => Ok(num::NonZeroU8::new(u.int_in_range(2..=36)?)),
+        false => Ok(None),
+    }
+}
+
+// The builder state may be invalid, so the validation is also fuzzed.
+// Radixes and punctuation are limited to values that can be valid.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NumberFormatBuilder {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            digit_separator: arbitrary_char(u)?,
+            base_prefix: arbitrary_char(u)?,
+            base_suffix: arbitrary_char(u)?,
+            mantissa_radix: u.int_in_range(2..=36)?,
+            exponent_base: arbitrary_radix(u)?,
+            exponent_radix: arbitrary_radix(u)?,
+            required_integer_digits: u.arbitrary()?,
+            required_fraction_digits: u.arbitrary()?,
+            required_exponent_digits: u.arbitrary()?,
+            required_mantissa_digits: u.arbitrary()?,
+            no_positive_mantissa_sign: u.arbitrary()?,
+            required_mantissa_sign: u.arbitrary()?,
+            no_exponent_notation: u.arbitrary()?,
+            no_positive_exponent_sign: u.arbitrary()?,
+            required_exponent_sign: u.arbitrary()?,
+            no_exponent_without_fraction: u.arbitrary()?,
+            no_special: u.arbitrary()?,
+            case_sensitive_special: u.arbitrary()?,
+            no_integer_leading_zeros: u.arbitrary()?,
+            no_float_leading_zeros: u.arbitrary()?,
+            required_exponent_notation: u.arbitrary()?,
+            case_sensitive_exponent: u.arbitrary()?,
+            case_sensitive_base_prefix: u.arbitrary()?,
+            case_sensitive_base_suffix: u.arbitrary()?,
+            required_base_prefix: u.arbitrary()?,
+            required_base_suffix: u.arbitrary()?,
+            integer_internal_digit_separator: u.arbitrary()?,
+            fraction_internal_digit_separator: u.arbitrary()?,
+            exponent_internal_digit_separator: u.arbitrary()?,
+            integer_leading_digit_separator: u.arbitrary()?,
+            fraction_leading_digit_separator: u.arbitrary()?,
+            exponent_leading_digit_separator: u.arbitrary()?,
+            integer_trailing_digit_separator: u.arbitrary()?,
+            fraction_trailing_digit_separator: u.arbitrary()?,
+            exponent_trailing_digit_separator: u.arbitrary()?,
+            integer_consecutive_digit_separator: u.arbitrary()?,
+            fraction_consecutive_digit_separator: u.arbitrary()?,
+            exponent_consecutive_digit_separator: u.arbitrary()?,
+            special_digit_separator: u.arbitrary()?,
+        })
+    }
+}
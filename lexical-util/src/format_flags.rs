@@ -25,7 +25,7 @@
 //!
 //! 16  17  18  19  20  21  22  23  24  25  26  27  28  29  30  31  32
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-//! |e/P|e/S|                                                       |
+//! |e/P|e/S|P/R|S/R|                                               |
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
 //!
 //! 32  33  34  35  36  37  38  39  40  41 42  43  44  45  46  47   48
@@ -58,6 +58,8 @@
 //!         e/C = Case-sensitive exponent character.
 //!         e/P = Case-sensitive base prefix.
 //!         e/S = Case-sensitive base suffix.
+//!         P/R = Required base prefix.
+//!         S/R = Required base suffix.
 //!
 //!     Digit Separator Flags:
 //!         I/I = Integer internal digit separator.
@@ -205,6 +207,7 @@
 
 #![cfg_attr(rustfmt, rustfmt::skip)]
 
+use crate::error::{Error, Punctuation};
 use static_assertions::const_assert;
 
 // ASSERTIONS
@@ -295,8 +298,9 @@ pub const CASE_SENSITIVE_SPECIAL: u128 = 1 << 11;
 /// If the value is a literal, then this distinction applies
 /// when the value is treated like an integer literal, typically
 /// when there is no decimal point. If the value is parsed,
-/// then this distinction applies when the value as parsed
-/// as an integer.
+/// then this distinction applies when the value is parsed
+/// as an integer, or as a float without a decimal point or
+/// exponent, so `01` is invalid but `01.5` may be valid.
 ///
 /// # Warning
 ///
@@ -311,8 +315,9 @@ pub const NO_INTEGER_LEADING_ZEROS: u128 = 1 << 12;
 /// If the value is a literal, then this distinction applies
 /// when the value is treated like an integer float, typically
 /// when there is a decimal point. If the value is parsed,
-/// then this distinction applies when the value as parsed
-/// as a float.
+/// then this distinction applies when the value is parsed
+/// as a float with a decimal point or exponent, so `01.5`
+/// is invalid but `01` may be valid.
 ///
 /// # Warning
 ///
@@ -337,6 +342,18 @@ pub const CASE_SENSITIVE_BASE_PREFIX: u128 = 1 << 16;
 /// Base suffixes are case-sensitive.
 pub const CASE_SENSITIVE_BASE_SUFFIX: u128 = 1 << 17;
 
+/// A base prefix is required before the significant digits.
+///
+/// The number format must have a base prefix character. Writers
+/// always write the base prefix for these formats.
+pub const REQUIRED_BASE_PREFIX: u128 = 1 << 18;
+
+/// A base suffix is required after the significant digits.
+///
+/// The number format must have a base suffix character. Writers
+/// always write the base suffix for these formats.
+pub const REQUIRED_BASE_SUFFIX: u128 = 1 << 19;
+
 // Non-digit separator flags.
 const_assert!(REQUIRED_INTEGER_DIGITS == 1);
 check_subsequent_flags!(REQUIRED_INTEGER_DIGITS, REQUIRED_FRACTION_DIGITS);
@@ -357,6 +374,8 @@ check_subsequent_flags!(NO_FLOAT_LEADING_ZEROS, REQUIRED_EXPONENT_NOTATION);
 check_subsequent_flags!(REQUIRED_EXPONENT_NOTATION, CASE_SENSITIVE_EXPONENT);
 check_subsequent_flags!(CASE_SENSITIVE_EXPONENT, CASE_SENSITIVE_BASE_PREFIX);
 check_subsequent_flags!(CASE_SENSITIVE_BASE_PREFIX, CASE_SENSITIVE_BASE_SUFFIX);
+check_subsequent_flags!(CASE_SENSITIVE_BASE_SUFFIX, REQUIRED_BASE_PREFIX);
+check_subsequent_flags!(REQUIRED_BASE_PREFIX, REQUIRED_BASE_SUFFIX);
 
 // DIGIT SEPARATOR FLAGS & MASKS
 // -----------------------------
@@ -523,6 +542,8 @@ pub const FLAG_MASK: u128 =
     CASE_SENSITIVE_EXPONENT |
     CASE_SENSITIVE_BASE_PREFIX |
     CASE_SENSITIVE_BASE_SUFFIX |
+    REQUIRED_BASE_PREFIX |
+    REQUIRED_BASE_SUFFIX |
     INTERNAL_DIGIT_SEPARATOR |
     LEADING_DIGIT_SEPARATOR |
     TRAILING_DIGIT_SEPARATOR |
@@ -668,38 +689,62 @@ pub const fn is_valid_exponent_flags(format: u128) -> bool {
     format & NO_EXPONENT_NOTATION == 0 || format & REQUIRED_EXPONENT_NOTATION == 0
 }
 
+/// Determine if a control character is a digit in the mantissa or exponent radix.
+///
+/// Both radixes must be checked, rather than the larger radix: radixes
+/// above 36 use case-sensitive digits, so an uppercase letter may be a
+/// digit in the smaller radix and not the larger one.
+#[inline]
+const fn is_control_digit(format: u128, value: u8) -> bool {
+    use crate::digit::char_is_digit_const;
+    char_is_digit_const(value, mantissa_radix(format))
+        || char_is_digit_const(value, exponent_radix(format))
+}
+
 /// Determine if an optional control character is valid.
 #[inline]
-const fn is_valid_optional_control_radix(radix: u32, value: u8) -> bool {
+const fn is_valid_optional_control(format: u128, value: u8) -> bool {
     // Validate the character isn't a digit or sign character, and is valid ASCII.
     use crate::ascii::is_valid_ascii;
-    use crate::digit::char_is_digit_const;
-    !char_is_digit_const(value, radix) &&
+    !is_control_digit(format, value) &&
         value != b'+' &&
         value != b'-' &&
         (is_valid_ascii(value) || value == 0)
 }
 
-/// Determine if an optional control character is valid.
+/// Get the error for an optional control character.
 #[inline]
-const fn is_valid_optional_control(format: u128, value: u8) -> bool {
-    // Need to get the larger of the two radix values, since these
-    // will be the characters that define the valid digits.
-    // const fn doesn't support max as of 1.55 nightly.
-    let mradix = mantissa_radix(format);
-    let eradix = exponent_radix(format);
-    let radix = if mradix > eradix {
-        mradix
+const fn optional_control_error(format: u128, value: u8, punctuation: Punctuation) -> Error {
+    use crate::ascii::is_valid_ascii;
+    if value == 0 {
+        Error::Success
+    } else if is_control_digit(format, value) {
+        Error::DigitPunctuation(punctuation)
+    } else if value == b'+' || value == b'-' || !is_valid_ascii(value) {
+        punctuation.invalid_error()
     } else {
-        eradix
-    };
-    is_valid_optional_control_radix(radix, value)
+        Error::Success
+    }
 }
 
-/// Determine if an control character is valid.
+/// Get the error for a control character, which must be set.
 #[inline]
-const fn is_valid_control(format: u128, value: u8) -> bool {
-    value != 0 && is_valid_optional_control(format, value)
+const fn control_error(format: u128, value: u8, punctuation: Punctuation) -> Error {
+    if value == 0 {
+        punctuation.invalid_error()
+    } else {
+        optional_control_error(format, value, punctuation)
+    }
+}
+
+/// Get the error if both optional control characters are set and the same.
+#[inline]
+const fn conflict_error(x: (u8, Punctuation), y: (u8, Punctuation)) -> Error {
+    if x.0 != 0 && x.0 == y.0 {
+        Error::ConflictingPunctuation(x.1, y.1)
+    } else {
+        Error::Success
+    }
 }
 
 /// Determine if the digit separator is valid.
@@ -715,10 +760,13 @@ pub const fn is_valid_digit_separator(format: u128) -> bool {
 }
 
 /// Determine if the base prefix character is valid.
+/// The base prefix must be set if it is required.
 #[inline]
 pub const fn is_valid_base_prefix(format: u128) -> bool {
     let value = base_prefix(format);
-    if cfg!(feature = "format") {
+    if format & REQUIRED_BASE_PREFIX != 0 && value == 0 {
+        false
+    } else if cfg!(feature = "format") {
         is_valid_optional_control(format, value)
     } else {
         value == 0
@@ -726,10 +774,13 @@ pub const fn is_valid_base_prefix(format: u128) -> bool {
 }
 
 /// Determine if the base suffix character is valid.
+/// The base suffix must be set if it is required.
 #[inline]
 pub const fn is_valid_base_suffix(format: u128) -> bool {
     let value = base_suffix(format);
-    if cfg!(feature = "format") {
+    if format & REQUIRED_BASE_SUFFIX != 0 && value == 0 {
+        false
+    } else if cfg!(feature = "format") {
         is_valid_optional_control(format, value)
     } else {
         value == 0
@@ -763,40 +814,132 @@ pub const fn is_valid_punctuation(format: u128) -> bool {
 
 /// Determine if all of the "punctuation" characters for the options API are valid.
 #[inline]
-#[allow(clippy::if_same_then_else, clippy::needless_bool)]
 pub const fn is_valid_options_punctuation(format: u128, exponent: u8, decimal_point: u8) -> bool {
-    // All the checks against optional characters with mandatory are fine:
-    // if they're not 0, then they can't overlap, and mandatory can't be 0.
-    if !is_valid_control(format, decimal_point) || !is_valid_control(format, exponent) {
-        // Must be in the valid range.
-        false
-    } else if decimal_point == exponent {
-        // Can't have overlapping characters.
-        false
-    } else if cfg!(feature = "format") && digit_separator(format) == decimal_point {
-        false
-    } else if cfg!(feature = "format") && digit_separator(format) == exponent {
-        false
-    } else if cfg!(feature = "format") && base_prefix(format) == decimal_point {
-        false
-    } else if cfg!(feature = "format") && base_prefix(format) == exponent {
-        false
-    } else if cfg!(feature = "format") && base_suffix(format) == decimal_point {
-        false
-    } else if cfg!(feature = "format") && base_suffix(format) == exponent {
-        false
-    } else {
-        true
+    options_punctuation_error(format, exponent, decimal_point).is_success()
+}
+
+/// Get the error for the "punctuation" characters of the format packed struct.
+///
+/// Unlike [`is_valid_punctuation`], this reports which character is
+/// invalid: [`Error::DigitPunctuation`] if it is a valid digit for the
+/// radix, [`Error::ConflictingPunctuation`] if two characters are the
+/// same, or the specific error for the character, such as
+/// [`Error::InvalidDigitSeparator`], if it is a sign or not ASCII.
+/// Returns [`Error::Success`] if all characters are valid.
+///
+/// ```rust
+/// # use lexical_util::error::Error;
+/// # use lexical_util::format::{punctuation_error, STANDARD};
+/// assert_eq!(punctuation_error(STANDARD), Error::Success);
+/// ```
+#[inline]
+pub const fn punctuation_error(format: u128) -> Error {
+    let separator = (digit_separator(format), Punctuation::DigitSeparator);
+    let prefix = (base_prefix(format), Punctuation::BasePrefix);
+    let suffix = (base_suffix(format), Punctuation::BaseSuffix);
+    let characters = [separator, prefix, suffix];
+    let mut index = 0;
+    while index < characters.len() {
+        let (value, punctuation) = characters[index];
+        let error = if cfg!(feature = "format") {
+            optional_control_error(format, value, punctuation)
+        } else if value != 0 {
+            punctuation.invalid_error()
+        } else {
+            Error::Success
+        };
+        if !error.is_success() {
+            return error;
+        }
+        index += 1;
     }
+
+    let error = conflict_error(separator, prefix);
+    if !error.is_success() {
+        return error;
+    }
+    let error = conflict_error(separator, suffix);
+    if !error.is_success() {
+        return error;
+    }
+    conflict_error(prefix, suffix)
+}
+
+/// Get the error for the "punctuation" characters for the options API.
+///
+/// Like [`punctuation_error`], this reports which character is invalid,
+/// including if the exponent or decimal point is the same as another
+/// character. Returns [`Error::Success`] if all characters are valid.
+///
+/// ```rust
+/// # use lexical_util::error::{Error, Punctuation};
+/// # use lexical_util::format::{options_punctuation_error, STANDARD};
+/// assert_eq!(options_punctuation_error(STANDARD, b'e', b'.'), Error::Success);
+/// assert_eq!(
+///     options_punctuation_error(STANDARD, b'e', b'e'),
+///     Error::ConflictingPunctuation(Punctuation::DecimalPoint, Punctuation::Exponent)
+/// );
+/// assert_eq!(
+///     options_punctuation_error(STANDARD, b'1', b'.'),
+///     Error::DigitPunctuation(Punctuation::Exponent)
+/// );
+/// ```
+#[inline]
+pub const fn options_punctuation_error(format: u128, exponent: u8, decimal_point: u8) -> Error {
+    let decimal_point = (decimal_point, Punctuation::DecimalPoint);
+    let exponent = (exponent, Punctuation::Exponent);
+    let error = control_error(format, decimal_point.0, decimal_point.1);
+    if !error.is_success() {
+        return error;
+    }
+    let error = control_error(format, exponent.0, exponent.1);
+    if !error.is_success() {
+        return error;
+    }
+    let error = conflict_error(decimal_point, exponent);
+    if !error.is_success() || cfg!(not(feature = "format")) {
+        return error;
+    }
+
+    let characters = [
+        (digit_separator(format), Punctuation::DigitSeparator),
+        (base_prefix(format), Punctuation::BasePrefix),
+        (base_suffix(format), Punctuation::BaseSuffix),
+    ];
+    let mut index = 0;
+    while index < characters.len() {
+        let error = conflict_error(characters[index], decimal_point);
+        if !error.is_success() {
+            return error;
+        }
+        let error = conflict_error(characters[index], exponent);
+        if !error.is_success() {
+            return error;
+        }
+        index += 1;
+    }
+    Error::Success
 }
 
 /// Determine if the radix is valid.
+///
+/// Radixes from 37 to 62 use case-sensitive digits, `0-9a-zA-Z`,
+/// and are only supported for integers, and for parsing floats with
+/// tables generated by the build script.
 pub const fn is_valid_radix(radix: u32) -> bool {
     if cfg!(feature = "radix") {
-        radix >= 2 && radix <= 36
+        radix >= 2 && radix <= 62
     } else if cfg!(feature = "power-of-two") {
         matches!(radix, 2 | 4 | 8 | 10 | 16 | 32)
     } else {
         radix == 10
     }
 }
+
+/// Determine if the radix is valid for floats.
+///
+/// This excludes radixes from 37 to 62, which `lexical-parse-float`
+/// only supports if the tables for the radix are generated at build time.
+pub const fn is_valid_float_radix(radix: u32) -> bool {
+    radix <= 36 && is_valid_radix(radix)
+}
@@ -0,0 +1,396 @@
+//! Parse number formats from a textual specification.
+//!
+//! A specification is a comma-separated list of items, so a number format
+//! can be exposed to end users, for example, in a configuration file:
+//!
+//! ```text
+//! json, digit_separator='_', internal_digit_separator, decimal_point=','
+//! ```
+//!
+//! Each item is one of:
+//!
+//! - A pre-defined format, such as `standard` or `json`, which is only
+//!   allowed as the first item.
+//! - A format flag, such as `no_special` or `required_exponent_sign=false`,
+//!   named like the [`NumberFormatBuilder`] methods.
+//! - A setting, such as `radix=16` or `exponent='p'`.
+//!
+//! Names are case-insensitive and whitespace around items is ignored.
+//! Values may be quoted with `'` or `"`, which is required for values
+//! containing a `,` or whitespace. The settings are:
+//!
+//! - `radix`: the radix for the mantissa and exponent, and the exponent base.
+//! - `mantissa_radix`, `exponent_base`, `exponent_radix`: a number.
+//! - `digit_separator`, `base_prefix`, `base_suffix`: an ASCII character.
+//! - `decimal_point`, `exponent`: an ASCII character, stored in the spec
+//!   for the options API.
+//! - `nan_string`, `inf_string`, `infinity_string`: a string, stored in the
+//!   spec for the options API.
+//!
+//! [`NumberFormatBuilder`]: crate::format::NumberFormatBuilder
+
+use crate::error::Error;
+#[cfg(feature = "format")]
+use crate::feature_format as presets;
+use crate::format::STANDARD;
+use crate::format_flags as flags;
+use crate::result::Result;
+use crate::runtime_format::Format;
+
+/// Find a format flag, or group of format flags, from the name.
+macro_rules! find_flag {
+    ($name:ident ; $($flag:ident)* ; $($alias:literal => $mask:ident)*) => {{
+        $(
+            if $name.eq_ignore_ascii_case(stringify!($flag).as_bytes()) {
+                return Some(flags::$flag);
+            }
+        )*
+        $(
+            if $name.eq_ignore_ascii_case($alias.as_bytes()) {
+                return Some(flags::$mask);
+            }
+        )*
+        None
+    }};
+}
+
+/// Find a pre-defined format from the name.
+#[cfg(feature = "format")]
+macro_rules! find_preset {
+    ($name:ident ; $($preset:ident)* ; power_of_two: $($hex:ident)*) => {{
+        $(
+            if $name.eq_ignore_ascii_case(stringify!($preset).as_bytes()) {
+                return Some(presets::$preset);
+            }
+        )*
+        $(
+            #[cfg(feature = "power-of-two")]
+            if $name.eq_ignore_ascii_case(stringify!($hex).as_bytes()) {
+                return Some(presets::$hex);
+            }
+        )*
+    }};
+}
+
+/// The fields in the packed struct, with the mask, shift, and if the value is a character.
+///
+/// The radix fields are first, since they are all set by `radix`.
+const FIELDS: [(&str, u128, i32, bool); 6] = [
+    ("mantissa_radix", flags::MANTISSA_RADIX, flags::MANTISSA_RADIX_SHIFT, false),
+    ("exponent_base", flags::EXPONENT_BASE, flags::EXPONENT_BASE_SHIFT, false),
+    ("exponent_radix", flags::EXPONENT_RADIX, flags::EXPONENT_RADIX_SHIFT, false),
+    ("digit_separator", flags::DIGIT_SEPARATOR, flags::DIGIT_SEPARATOR_SHIFT, true),
+    ("base_prefix", flags::BASE_PREFIX, flags::BASE_PREFIX_SHIFT, true),
+    ("base_suffix", flags::BASE_SUFFIX, flags::BASE_SUFFIX_SHIFT, true),
+];
+
+/// Get the mask for a format flag, or group of format flags.
+fn flag_mask(name: &[u8]) -> Option<u128> {
+    find_flag!(
+        name ;
+        REQUIRED_INTEGER_DIGITS REQUIRED_FRACTION_DIGITS REQUIRED_EXPONENT_DIGITS
+        REQUIRED_MANTISSA_DIGITS REQUIRED_DIGITS NO_POSITIVE_MANTISSA_SIGN
+        REQUIRED_MANTISSA_SIGN NO_EXPONENT_NOTATION NO_POSITIVE_EXPONENT_SIGN
+        REQUIRED_EXPONENT_SIGN NO_EXPONENT_WITHOUT_FRACTION NO_SPECIAL CASE_SENSITIVE_SPECIAL
+        NO_INTEGER_LEADING_ZEROS NO_FLOAT_LEADING_ZEROS REQUIRED_EXPONENT_NOTATION
+        CASE_SENSITIVE_EXPONENT CASE_SENSITIVE_BASE_PREFIX CASE_SENSITIVE_BASE_SUFFIX
+        REQUIRED_BASE_PREFIX REQUIRED_BASE_SUFFIX
+        INTEGER_INTERNAL_DIGIT_SEPARATOR FRACTION_INTERNAL_DIGIT_SEPARATOR
+        EXPONENT_INTERNAL_DIGIT_SEPARATOR INTEGER_LEADING_DIGIT_SEPARATOR
+        FRACTION_LEADING_DIGIT_SEPARATOR EXPONENT_LEADING_DIGIT_SEPARATOR
+        INTEGER_TRAILING_DIGIT_SEPARATOR FRACTION_TRAILING_DIGIT_SEPARATOR
+        EXPONENT_TRAILING_DIGIT_SEPARATOR INTEGER_CONSECUTIVE_DIGIT_SEPARATOR
+        FRACTION_CONSECUTIVE_DIGIT_SEPARATOR EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
+        INTERNAL_DIGIT_SEPARATOR LEADING_DIGIT_SEPARATOR TRAILING_DIGIT_SEPARATOR
+        CONSECUTIVE_DIGIT_SEPARATOR SPECIAL_DIGIT_SEPARATOR ;
+        "digit_separator_flags" => DIGIT_SEPARATOR_FLAG_MASK
+        "integer_digit_separator_flags" => INTEGER_DIGIT_SEPARATOR_FLAG_MASK
+        "fraction_digit_separator_flags" => FRACTION_DIGIT_SEPARATOR_FLAG_MASK
+        "exponent_digit_separator_flags" => EXPONENT_DIGIT_SEPARATOR_FLAG_MASK
+    )
+}
+
+/// Get the packed struct for a pre-defined format.
+fn preset(name: &[u8]) -> Option<u128> {
+    if name.eq_ignore_ascii_case(b"standard") {
+        return Some(STANDARD);
+    }
+    #[cfg(feature = "format")]
+    find_preset!(
+        name ;
+        RUST_LITERAL RUST_STRING PYTHON_LITERAL PYTHON_STRING PYTHON3_LITERAL PYTHON3_STRING
+        PYTHON_REPR PYTHON36_LITERAL PYTHON35_LITERAL PYTHON2_LITERAL PYTHON2_STRING
+        CXX_LITERAL CXX_STRING CXX20_LITERAL CXX20_STRING CXX17_LITERAL CXX17_STRING
+        CXX14_LITERAL CXX14_STRING CXX11_LITERAL CXX11_STRING CXX03_LITERAL CXX03_STRING
+        CXX98_LITERAL CXX98_STRING C_LITERAL C_STRING C18_LITERAL C18_STRING C_PRINTF_G
+        C11_LITERAL C11_STRING C99_LITERAL C99_STRING C90_LITERAL C90_STRING C89_LITERAL
+        C89_STRING RUBY_LITERAL RUBY_STRING SWIFT_LITERAL SWIFT_STRING GO_LITERAL GO_STRING
+        GO_FORMAT_FLOAT HASKELL_LITERAL HASKELL_STRING JAVASCRIPT_LITERAL JAVASCRIPT_STRING
+        JAVASCRIPT_TO_STRING PERL_LITERAL PERL_STRING PHP_LITERAL PHP_STRING JAVA_LITERAL
+        JAVA_STRING R_LITERAL R_STRING KOTLIN_LITERAL KOTLIN_STRING JULIA_LITERAL
+        JULIA_STRING CSHARP_LITERAL CSHARP_STRING CSHARP7_LITERAL CSHARP7_STRING
+        CSHARP6_LITERAL CSHARP6_STRING CSHARP5_LITERAL CSHARP5_STRING CSHARP4_LITERAL
+        CSHARP4_STRING CSHARP3_LITERAL CSHARP3_STRING CSHARP2_LITERAL CSHARP2_STRING
+        CSHARP1_LITERAL CSHARP1_STRING KAWA_LITERAL KAWA_STRING GAMBITC_LITERAL
+        GAMBITC_STRING GUILE_LITERAL GUILE_STRING CLOJURE_LITERAL CLOJURE_STRING
+        ERLANG_LITERAL ERLANG_STRING ELM_LITERAL ELM_STRING SCALA_LITERAL SCALA_STRING
+        ELIXIR_LITERAL ELIXIR_STRING FORTRAN_LITERAL FORTRAN_STRING D_LITERAL D_STRING
+        COFFEESCRIPT_LITERAL COFFEESCRIPT_STRING COBOL_LITERAL COBOL_STRING FSHARP_LITERAL
+        FSHARP_STRING VB_LITERAL VB_STRING OCAML_LITERAL OCAML_STRING OBJECTIVEC_LITERAL
+        OBJECTIVEC_STRING REASONML_LITERAL REASONML_STRING OCTAVE_LITERAL OCTAVE_STRING
+        MATLAB_LITERAL MATLAB_STRING ZIG_LITERAL ZIG_STRING SAGE_LITERAL SAGE_STRING JSON
+        TOML YAML XML SQLITE POSTGRESQL POSTGRESQL16_LITERAL POSTGRESQL_STRING MYSQL MONGODB
+        SCIENTIFIC_FIXED PERMISSIVE IGNORE ;
+        power_of_two: CXX_HEX_LITERAL CXX_HEX_STRING CXX20_HEX_LITERAL CXX20_HEX_STRING
+        CXX17_HEX_LITERAL CXX17_HEX_STRING CXX14_HEX_STRING CXX11_HEX_STRING C_HEX_LITERAL
+        C_HEX_STRING C18_HEX_LITERAL C18_HEX_STRING C11_HEX_LITERAL C11_HEX_STRING
+        C99_HEX_LITERAL C99_HEX_STRING C90_HEX_STRING C89_HEX_STRING RUBY_OCTAL_LITERAL
+        JULIA_HEX_LITERAL JULIA_HEX_STRING
+    );
+    None
+}
+
+/// Number format and punctuation parsed from a textual specification.
+///
+/// The number format is validated like [`Format::new`], while the
+/// punctuation and special strings are only stored, and validated when
+/// building the parse or write options.
+///
+/// ```rust
+/// # use lexical_util::format::{FormatSpec, STANDARD};
+/// let spec = FormatSpec::parse("standard, decimal_point=',', nan_string=NaN").unwrap();
+/// assert_eq!(spec.format().packed(), STANDARD);
+/// assert_eq!(spec.decimal_point(), Some(b','));
+/// assert_eq!(spec.exponent(), None);
+/// assert_eq!(spec.nan_string(), Some(&b"NaN"[..]));
+///
+/// assert!(FormatSpec::parse("standard, unknown").unwrap_err().is_invalid_format_spec());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FormatSpec<'a> {
+    format: Format,
+    decimal_point: Option<u8>,
+    exponent: Option<u8>,
+    nan_string: Option<&'a [u8]>,
+    inf_string: Option<&'a [u8]>,
+    infinity_string: Option<&'a [u8]>,
+}
+
+impl<'a> FormatSpec<'a> {
+    // CONSTRUCTORS
+
+    /// Parse the number format from the specification.
+    ///
+    /// An empty specification is the [`STANDARD`] format. Returns
+    /// [`Error::InvalidFormatSpec`] with the index of the invalid item
+    /// or value if the specification cannot be parsed, or the error
+    /// from [`Format::new`] if the number format is invalid.
+    pub fn parse(spec: &'a str) -> Result<Self> {
+        let bytes = spec.as_bytes();
+        let mut format = STANDARD;
+        let mut result = Self {
+            format: Format::new(STANDARD)?,
+            decimal_point: None,
+            exponent: None,
+            nan_string: None,
+            inf_string: None,
+            infinity_string: None,
+        };
+
+        let mut index = skip_whitespace(bytes, 0);
+        let mut is_first = true;
+        while index < bytes.len() {
+            let start = index;
+            while index < bytes.len() && is_name_char(bytes[index]) {
+                index += 1;
+            }
+            let name = &bytes[start..index];
+            if name.is_empty() {
+                return Err(Error::InvalidFormatSpec(start));
+            }
+            index = skip_whitespace(bytes, index);
+            let value = if index < bytes.len() && bytes[index] == b'=' {
+                index = skip_whitespace(bytes, index + 1);
+                let (value, end) = parse_value(bytes, index)?;
+                let value_start = index;
+                index = end;
+                Some((value, value_start))
+            } else {
+                None
+            };
+            index = skip_whitespace(bytes, index);
+            if index < bytes.len() {
+                if bytes[index] != b',' {
+                    return Err(Error::InvalidFormatSpec(index));
+                }
+                index = skip_whitespace(bytes, index + 1);
+                if index == bytes.len() {
+                    return Err(Error::InvalidFormatSpec(index));
+                }
+            }
+
+            match (value, preset(name), flag_mask(name)) {
+                (None, Some(packed), _) if is_first => format = packed,
+                (None, _, Some(mask)) => format |= mask,
+                (Some((value, value_start)), _, Some(mask)) => match parse_bool(value) {
+                    Some(true) => format |= mask,
+                    Some(false) => format &= !mask,
+                    None => return Err(Error::InvalidFormatSpec(value_start)),
+                },
+                (Some((value, value_start)), _, None) => {
+                    let error = Error::InvalidFormatSpec(value_start);
+                    if name.eq_ignore_ascii_case(b"radix") {
+                        let radix = parse_number(value).ok_or(error)?;
+                        for &(_, mask, shift, _) in FIELDS[..3].iter() {
+                            format = set_field(format, mask, shift, radix);
+                        }
+                    } else if let Some(&(_, mask, shift, is_char)) =
+                        FIELDS.iter().find(|x| name.eq_ignore_ascii_case(x.0.as_bytes()))
+                    {
+                        let value = match is_char {
+                            true => parse_char(value),
+                            false => parse_number(value),
+                        };
+                        format = set_field(format, mask, shift, value.ok_or(error)?);
+                    } else if name.eq_ignore_ascii_case(b"decimal_point") {
+                        result.decimal_point = Some(parse_char(value).ok_or(error)?);
+                    } else if name.eq_ignore_ascii_case(b"exponent") {
+                        result.exponent = Some(parse_char(value).ok_or(error)?);
+                    } else if name.eq_ignore_ascii_case(b"nan_string") {
+                        result.nan_string = Some(value);
+                    } else if name.eq_ignore_ascii_case(b"inf_string") {
+                        result.inf_string = Some(value);
+                    } else if name.eq_ignore_ascii_case(b"infinity_string") {
+                        result.infinity_string = Some(value);
+                    } else {
+                        return Err(Error::InvalidFormatSpec(start));
+                    }
+                },
+                _ => return Err(Error::InvalidFormatSpec(start)),
+            }
+            is_first = false;
+        }
+
+        // The digit separator is ignored without any digit separator flags.
+        if format & flags::DIGIT_SEPARATOR_FLAG_MASK == 0 {
+            format &= !flags::DIGIT_SEPARATOR;
+        }
+        result.format = Format::new(format)?;
+        Ok(result)
+    }
+
+    // GETTERS
+
+    /// Get the validated number format.
+    #[inline(always)]
+    pub const fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Get the character to separate the integer from the fraction components, if set.
+    #[inline(always)]
+    pub const fn decimal_point(&self) -> Option<u8> {
+        self.decimal_point
+    }
+
+    /// Get the character to designate the exponent component of a float, if set.
+    #[inline(always)]
+    pub const fn exponent(&self) -> Option<u8> {
+        self.exponent
+    }
+
+    /// Get the string representation for `NaN`, if set.
+    #[inline(always)]
+    pub const fn nan_string(&self) -> Option<&'a [u8]> {
+        self.nan_string
+    }
+
+    /// Get the short string representation for `Infinity`, if set.
+    #[inline(always)]
+    pub const fn inf_string(&self) -> Option<&'a [u8]> {
+        self.inf_string
+    }
+
+    /// Get the long string representation for `Infinity`, if set.
+    #[inline(always)]
+    pub const fn infinity_string(&self) -> Option<&'a [u8]> {
+        self.infinity_string
+    }
+}
+
+/// Determine if the character can be part of a name.
+#[inline]
+fn is_name_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+/// Skip ASCII whitespace, returning the index of the next character.
+#[inline]
+fn skip_whitespace(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+        index += 1;
+    }
+    index
+}
+
+/// Parse a quoted or bare value, returning the value and the end index.
+fn parse_value(bytes: &[u8], start: usize) -> Result<(&[u8], usize)> {
+    match bytes.get(start) {
+        Some(&quote) if quote == b'\'' || quote == b'"' => {
+            let value = &bytes[start + 1..];
+            match value.iter().position(|&c| c == quote) {
+                Some(length) => Ok((&value[..length], start + length + 2)),
+                None => Err(Error::InvalidFormatSpec(start)),
+            }
+        },
+        _ => {
+            let mut end = start;
+            while end < bytes.len() && bytes[end] != b',' && !bytes[end].is_ascii_whitespace() {
+                end += 1;
+            }
+            if end == start {
+                Err(Error::InvalidFormatSpec(start))
+            } else {
+                Ok((&bytes[start..end], end))
+            }
+        },
+    }
+}
+
+/// Parse a boolean value for a format flag.
+fn parse_bool(value: &[u8]) -> Option<bool> {
+    if value.eq_ignore_ascii_case(b"true") {
+        Some(true)
+    } else if value.eq_ignore_ascii_case(b"false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Parse a decimal number that fits in a byte.
+fn parse_number(value: &[u8]) -> Option<u8> {
+    if value.is_empty() {
+        return None;
+    }
+    let mut number: u8 = 0;
+    for &c in value {
+        let digit = (c as char).to_digit(10)? as u8;
+        number = number.checked_mul(10)?.checked_add(digit)?;
+    }
+    Some(number)
+}
+
+/// Parse a single ASCII character.
+fn parse_char(value: &[u8]) -> Option<u8> {
+    match value {
+        &[c] if c.is_ascii() => Some(c),
+        _ => None,
+    }
+}
+
+/// Replace a field in the packed struct.
+#[inline]
+const fn set_field(format: u128, mask: u128, shift: i32, value: u8) -> u128 {
+    (format & !mask) | ((value as u128) << shift)
+}
@@ -10,7 +10,32 @@
 pub use crate::noskip::{AsBytes, Bytes};
 
 #[cfg(feature = "format")]
-pub use crate::skip::{AsBytes, Bytes};
+pub use crate::skip::{AsBytes, Bytes, SkipSet};
+
+// Re-export our runtime digit iterator.
+pub use crate::runtime_skip::{FormatBytes, NumberComponent};
+
+/// A checkpoint of the position of a byte iterator.
+///
+/// This is created by `mark` and restored by `reset`, allowing parsers
+/// to backtrack without re-slicing and re-validating the input. A mark
+/// should only be restored by the iterator that created it, or another
+/// iterator over the same bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mark {
+    /// The index of the iterator in the slice.
+    pub(crate) index: usize,
+    /// The count of values returned by the iterator.
+    pub(crate) count: usize,
+}
+
+impl Mark {
+    /// Get the index of the iterator in the slice at the checkpoint.
+    #[inline(always)]
+    pub const fn cursor(&self) -> usize {
+        self.index
+    }
+}
 
 /// Iterator over a contiguous block of bytes.
 ///
@@ -46,6 +71,16 @@ pub trait BytesIter<'a>: Iterator<Item = &'a u8> {
     /// Get the current number of values returned by the iterator.
     fn current_count(&self) -> usize;
 
+    /// Get a checkpoint of the iterator, to rewind to with [`reset`](Self::reset).
+    fn mark(&self) -> Mark;
+
+    /// Rewind the iterator to a checkpoint from [`mark`](Self::mark).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is past the end of the slice.
+    fn reset(&mut self, mark: Mark);
+
     /// Get if the iterator cannot return any more elements.
     ///
     /// This may advance the internal iterator state, but not
@@ -95,7 +130,7 @@ pub trait BytesIter<'a>: Iterator<Item = &'a u8> {
     #[inline]
     fn case_insensitive_peek_is(&mut self, value: u8) -> bool {
         if let Some(&c) = self.peek() {
-            c.to_ascii_lowercase() == value.to_ascii_lowercase()
+            crate::ascii::case_insensitive_eq(c, value)
         } else {
             false
         }
@@ -15,6 +15,16 @@
 //! * `parse-integers` - Add support for parsing integers.
 //! * `parse-floats` - Add support for parsing floats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `bigint` - Add fixed-capacity, stack-allocated big integers.
+//! * `wasm` - Use 32-bit limbs for big integers, avoiding emulated 128-bit
+//!   multiplication on WebAssembly.
+//! * `safe` - Bounds-check the unchecked operations in the big integers.
+//! * `asm` - Use inline assembly for wide multiplication on aarch64 and
+//!   RISC-V. This requires Rust 1.59+.
+//! * `arbitrary` - Implement `Arbitrary` for formats and options, for fuzzing.
+//! * `proptest` - Add property-testing strategies for formats and numeric strings.
+//!   This requires the `std` feature.
+//! * `defmt` - Implement `defmt::Format` for the error types.
 //!
 //! # Note
 //!
@@ -42,11 +52,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod algorithm;
+pub mod arch;
 pub mod ascii;
 pub mod assert;
 pub mod bf16;
+pub mod bigint;
 pub mod constants;
 pub mod digit;
+pub mod digit_count;
 pub mod div128;
 pub mod error;
 pub mod extended_float;
@@ -58,11 +71,19 @@ pub mod num;
 pub mod options;
 pub mod result;
 pub mod step;
+#[cfg(all(feature = "proptest", feature = "std"))]
+pub mod strategy;
 
 mod api;
 mod feature_format;
 mod format_builder;
 mod format_flags;
+mod format_spec;
 mod noskip;
 mod not_feature_format;
+mod runtime_format;
+mod runtime_skip;
 mod skip;
+
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
@@ -5,7 +5,8 @@
 
 #![cfg(all(feature = "parse", not(feature = "format")))]
 
-use crate::iterator::BytesIter;
+use crate::ascii::case_insensitive_eq;
+use crate::iterator::{BytesIter, Mark};
 use core::{mem, ptr};
 
 // AS DIGITS
@@ -92,6 +93,26 @@ impl<'a, const __: u128> Bytes<'a, __> {
         self.index
     }
 
+    /// Get a checkpoint of the iterator, to rewind to with [`reset`](Self::reset).
+    #[inline]
+    pub fn mark(&self) -> Mark {
+        Mark {
+            index: self.index,
+            count: self.index,
+        }
+    }
+
+    /// Rewind the iterator to a checkpoint from [`mark`](Self::mark).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is past the end of the slice.
+    #[inline]
+    pub fn reset(&mut self, mark: Mark) {
+        assert!(mark.index <= self.length(), "Mark must be within the slice.");
+        self.index = mark.index;
+    }
+
     /// Get if the buffer underlying the iterator is empty.
     /// Same as `is_consumed`.
     #[inline]
@@ -144,7 +165,7 @@ impl<'a, const __: u128> Bytes<'a, __> {
     #[inline]
     pub fn case_insensitive_first_is(&mut self, value: u8) -> bool {
         if let Some(&c) = self.slc.get(self.index) {
-            c.to_ascii_lowercase() == value.to_ascii_lowercase()
+            case_insensitive_eq(c, value)
         } else {
             false
         }
@@ -254,6 +275,16 @@ impl<'a: 'b, 'b, const __: u128> BytesIter<'a> for BytesIterator<'a, 'b, __> {
         self.byte.current_count()
     }
 
+    #[inline]
+    fn mark(&self) -> Mark {
+        self.byte.mark()
+    }
+
+    #[inline]
+    fn reset(&mut self, mark: Mark) {
+        self.byte.reset(mark)
+    }
+
     #[inline]
     fn is_consumed(&mut self) -> bool {
         Self::is_done(self)
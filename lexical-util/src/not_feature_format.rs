@@ -30,31 +30,60 @@ use crate::format_flags as flags;
 ///     17. case_sensitive_exponent
 ///     18. case_sensitive_base_prefix
 ///     19. case_sensitive_base_suffix
-///     20. integer_internal_digit_separator
-///     21. fraction_internal_digit_separator
-///     22. exponent_internal_digit_separator
-///     23. internal_digit_separator
-///     24. integer_leading_digit_separator
-///     25. fraction_leading_digit_separator
-///     26. exponent_leading_digit_separator
-///     27. leading_digit_separator
-///     28. integer_trailing_digit_separator
-///     29. fraction_trailing_digit_separator
-///     30. exponent_trailing_digit_separator
-///     31. trailing_digit_separator
-///     32. integer_consecutive_digit_separator
-///     33. fraction_consecutive_digit_separator
-///     34. exponent_consecutive_digit_separator
-///     35. consecutive_digit_separator
-///     36. special_digit_separator
-///     37. digit_separator
-///     38. base_prefix
-///     39. base_suffix
-///     40. exponent_base
-///     41. exponent_radix
+///     20. required_base_prefix
+///     21. required_base_suffix
+///     22. integer_internal_digit_separator
+///     23. fraction_internal_digit_separator
+///     24. exponent_internal_digit_separator
+///     25. internal_digit_separator
+///     26. integer_leading_digit_separator
+///     27. fraction_leading_digit_separator
+///     28. exponent_leading_digit_separator
+///     29. leading_digit_separator
+///     30. integer_trailing_digit_separator
+///     31. fraction_trailing_digit_separator
+///     32. exponent_trailing_digit_separator
+///     33. trailing_digit_separator
+///     34. integer_consecutive_digit_separator
+///     35. fraction_consecutive_digit_separator
+///     36. exponent_consecutive_digit_separator
+///     37. consecutive_digit_separator
+///     38. special_digit_separator
+///     39. digit_separator
+///     40. base_prefix
+///     41. base_suffix
+///     42. exponent_base
+///     43. exponent_radix
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
 /// for the packed struct.
+/// Get the error type from a format packed struct.
+///
+/// This is the validation used by [`NumberFormat::error`], for formats
+/// only known at runtime.
+pub(crate) const fn packed_error(format: u128) -> Error {
+    let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
+    if !flags::is_valid_radix(flags::mantissa_radix(format)) {
+        Error::InvalidMantissaRadix
+    } else if !flags::is_valid_radix(flags::exponent_base(format)) {
+        Error::InvalidExponentBase
+    } else if !flags::is_valid_radix(flags::exponent_radix(format)) {
+        Error::InvalidExponentRadix
+    } else if !flags::is_valid_digit_separator(format) {
+        Error::InvalidDigitSeparator
+    } else if !flags::is_valid_base_prefix(format) {
+        Error::InvalidBasePrefix
+    } else if !flags::is_valid_base_suffix(format) {
+        Error::InvalidBaseSuffix
+    } else if !flags::is_valid_punctuation(format) {
+        Error::InvalidPunctuation
+    } else if format & flags::FLAG_MASK != valid_flags {
+        Error::InvalidFlags
+    } else {
+        Error::Success
+    }
+}
+
 #[doc(hidden)]
 pub struct NumberFormat<const FORMAT: u128>;
 
@@ -75,26 +104,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
 
     /// Get the error type from the format.
     pub const fn error(&self) -> Error {
-        let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
-        if !flags::is_valid_radix(self.mantissa_radix()) {
-            Error::InvalidMantissaRadix
-        } else if !flags::is_valid_radix(self.exponent_base()) {
-            Error::InvalidExponentBase
-        } else if !flags::is_valid_radix(self.exponent_radix()) {
-            Error::InvalidExponentRadix
-        } else if !flags::is_valid_digit_separator(FORMAT) {
-            Error::InvalidDigitSeparator
-        } else if !flags::is_valid_base_prefix(FORMAT) {
-            Error::InvalidBasePrefix
-        } else if !flags::is_valid_base_suffix(FORMAT) {
-            Error::InvalidBaseSuffix
-        } else if !flags::is_valid_punctuation(FORMAT) {
-            Error::InvalidPunctuation
-        } else if self.flags() != valid_flags {
-            Error::InvalidFlags
-        } else {
-            Error::Success
-        }
+        packed_error(FORMAT)
     }
 
     // NON-DIGIT SEPARATOR FLAGS & MASKS
@@ -270,6 +280,24 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If a base prefix is required before the significant digits.
+    pub const REQUIRED_BASE_PREFIX: bool = false;
+
+    /// Get if a base prefix is required before the significant digits.
+    #[inline(always)]
+    pub const fn required_base_prefix(&self) -> bool {
+        Self::REQUIRED_BASE_PREFIX
+    }
+
+    /// If a base suffix is required after the significant digits.
+    pub const REQUIRED_BASE_SUFFIX: bool = false;
+
+    /// Get if a base suffix is required after the significant digits.
+    #[inline(always)]
+    pub const fn required_base_suffix(&self) -> bool {
+        Self::REQUIRED_BASE_SUFFIX
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -735,6 +735,53 @@ pub trait Float: Number + ops::Neg<Output = Self> {
         Self::from_bits(self.to_bits() - Self::Unsigned::ONE)
     }
 
+    /// Get the next representable float towards positive infinity.
+    ///
+    /// This is IEEE 754's `nextUp`: NaN and positive infinity are returned
+    /// unchanged, either zero returns the smallest positive denormal, and
+    /// negative infinity returns the most negative finite float.
+    #[inline]
+    fn next_up(self) -> Self {
+        let bits = self.to_bits();
+        if self.is_nan() || bits == Self::INFINITY_BITS {
+            self
+        } else if self == Self::ZERO {
+            Self::from_bits(Self::Unsigned::ONE)
+        } else if self.is_sign_negative() {
+            Self::from_bits(bits - Self::Unsigned::ONE)
+        } else {
+            Self::from_bits(bits + Self::Unsigned::ONE)
+        }
+    }
+
+    /// Get the next representable float towards negative infinity.
+    ///
+    /// This is IEEE 754's `nextDown`, or `-(-self).next_up()`.
+    #[inline]
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    /// Get the unit in the last place of the float.
+    ///
+    /// This is the distance from the absolute value of the float to the
+    /// next float with a larger magnitude, or for the largest finite
+    /// float, to the previous float. NaN returns NaN, and infinity returns
+    /// positive infinity.
+    #[inline]
+    fn ulp(self) -> Self {
+        let abs = Self::from_bits(self.to_bits() & !Self::SIGN_MASK);
+        if abs.is_nan() {
+            self
+        } else if abs.is_inf() {
+            abs
+        } else if abs == Self::MAX {
+            abs - abs.next_down()
+        } else {
+            abs.next_up() - abs
+        }
+    }
+
     /// Round a positive number to even.
     #[inline]
     fn round_positive_even(self) -> Self {
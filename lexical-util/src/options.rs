@@ -2,6 +2,10 @@
 
 #[cfg(feature = "write")]
 use crate::constants::FormattedSize;
+#[cfg(feature = "write")]
+use crate::format::Format;
+#[cfg(any(feature = "parse", feature = "write"))]
+use crate::result::Result;
 
 // TRAITS
 // ------
@@ -14,6 +18,9 @@ pub trait WriteOptions: Default {
 
     /// Get an upper bound on the buffer size.
     fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize;
+
+    /// Get an upper bound on the buffer size, for a number format known at runtime.
+    fn buffer_size_with_format<T: FormattedSize>(&self, format: &Format) -> usize;
 }
 
 /// Shared trait for all parser options.
@@ -23,6 +30,115 @@ pub trait ParseOptions: Default {
     fn is_valid(&self) -> bool;
 }
 
+/// Shared trait for options that can be overlaid with per-call overrides.
+///
+/// This allows changing a few options for a single conversion, such as
+/// the precision, without creating new options with a builder.
+#[cfg(any(feature = "parse", feature = "write"))]
+pub trait OverrideOptions: Sized {
+    /// The overrides for a subset of the options.
+    type Override;
+
+    /// Create options with the overrides applied, validating the result.
+    fn with_override(&self, overrides: &Self::Override) -> Result<Self>;
+}
+
+/// Define the `OptionsOverride` struct and implement `OverrideOptions`.
+///
+/// This must be called in the module defining `Options` and
+/// `OptionsBuilder`, with the name, type and documentation of every
+/// field of `Options`.
+#[macro_export]
+#[doc(hidden)]
+#[cfg(any(feature = "parse", feature = "write"))]
+macro_rules! options_override {
+    ($($(#[$meta:meta])* $field:ident : $t:ty ;)*) => {
+        /// Overrides for a subset of [`Options`], applied per call.
+        ///
+        /// Every field is unset by default, and unset fields use the
+        /// value from the options the overrides are applied to.
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+        pub struct OptionsOverride {
+            $($field: Option<$t>,)*
+        }
+
+        impl OptionsOverride {
+            /// Create new overrides, without any fields set.
+            #[inline(always)]
+            pub const fn new() -> Self {
+                Self {
+                    $($field: None,)*
+                }
+            }
+
+            /// Determine if no fields are set.
+            #[inline(always)]
+            pub const fn is_empty(&self) -> bool {
+                true $(&& self.$field.is_none())*
+            }
+
+            $(
+                $(#[$meta])*
+                #[inline(always)]
+                pub const fn $field(mut self, $field: $t) -> Self {
+                    self.$field = Some($field);
+                    self
+                }
+            )*
+        }
+
+        impl lexical_util::options::OverrideOptions for Options {
+            type Override = OptionsOverride;
+
+            #[inline]
+            fn with_override(
+                &self,
+                overrides: &Self::Override,
+            ) -> lexical_util::result::Result<Self> {
+                let mut options = self.clone();
+                if overrides.is_empty() {
+                    return Ok(options);
+                }
+                $(
+                    if let Some($field) = overrides.$field {
+                        options.$field = $field;
+                    }
+                )*
+                options.rebuild().build()
+            }
+        }
+    };
+}
+
+// ARBITRARY
+// ---------
+
+/// Generate an arbitrary special string for the options.
+///
+/// The strings are from a fixed set of valid and invalid candidates,
+/// since the options require static strings.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+pub fn arbitrary_special(
+    u: &mut arbitrary::Unstructured<'_>,
+) -> arbitrary::Result<Option<&'static [u8]>> {
+    const CANDIDATES: [Option<&[u8]>; 12] = [
+        None,
+        Some(b"NaN"),
+        Some(b"nan"),
+        Some(b"inf"),
+        Some(b"Inf"),
+        Some(b"infinity"),
+        Some(b"Infinity"),
+        Some(b"null"),
+        Some(b"#DIV/0!"),
+        Some(b""),
+        Some(b"1.0"),
+        Some(b"not a number, or anything resembling a number!!!!!"),
+    ];
+    Ok(*u.choose(&CANDIDATES)?)
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -46,6 +162,8 @@ literal!(RUST_LITERAL, None);
 // RUST_STRING
 literal!(PYTHON_LITERAL, None);
 // PYTHON_STRING
+literal!(PYTHON_REPR_NAN, b"nan");
+literal!(PYTHON_REPR_INF, b"inf");
 literal!(CXX_LITERAL_NAN, b"NAN");
 literal!(CXX_LITERAL_INF, b"INFINITY");
 literal!(CXX_LITERAL_INFINITY, b"INFINITY");
@@ -54,11 +172,15 @@ literal!(C_LITERAL_NAN, b"NAN");
 literal!(C_LITERAL_INF, b"INFINITY");
 literal!(C_LITERAL_INFINITY, b"INFINITY");
 // C_STRING
+literal!(C_PRINTF_G_NAN, b"nan");
+literal!(C_PRINTF_G_INF, b"inf");
 literal!(RUBY, None);
 literal!(SWIFT_LITERAL, None);
 // SWIFT_STRING
 literal!(GO_LITERAL, None);
 // GO_STRING
+literal!(GO_FORMAT_FLOAT_NAN, b"NaN");
+literal!(GO_FORMAT_FLOAT_INF, b"Inf");
 literal!(HASKELL_LITERAL, None);
 literal!(HASKELL_STRING_INF, b"Infinity");
 literal!(HASKELL_STRING_INFINITY, b"Infinity");
@@ -139,6 +261,12 @@ literal!(XML_INF, None);
 literal!(XML_INFINITY, None);
 literal!(SQLITE, None);
 literal!(POSTGRESQL, None);
+literal!(POSTGRESQL_STRING_NAN, b"NaN");
+literal!(POSTGRESQL_STRING_INF, b"Inf");
+literal!(POSTGRESQL_STRING_INFINITY, b"Infinity");
 literal!(MYSQL, None);
 literal!(MONGODB_INF, b"Infinity");
 literal!(MONGODB_INFINITY, b"Infinity");
+literal!(C_LOCALE_NAN, b"nan");
+literal!(C_LOCALE_INF, b"inf");
+literal!(C_LOCALE_INFINITY, b"infinity");
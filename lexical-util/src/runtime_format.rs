@@ -0,0 +1,578 @@
+//! Number format packed struct validated at runtime.
+//!
+//! [`NumberFormat`](crate::format::NumberFormat) reads the format from a
+//! const generic, so every format must be known at compile time. [`Format`]
+//! holds the same packed struct as a value, so formats chosen at runtime,
+//! such as per-column formats read from a configuration file, can be
+//! validated once and then passed to the runtime parsers and writers.
+
+#[cfg(feature = "format")]
+use crate::feature_format::packed_error;
+use crate::format_flags as flags;
+#[cfg(not(feature = "format"))]
+use crate::not_feature_format::packed_error;
+use crate::result::Result;
+
+/// Generate the getter for a flag in the packed struct.
+macro_rules! flag_getter {
+    ($(#[$meta:meta])* $name:ident, $flag:ident) => {
+        $(#[$meta])*
+        #[inline(always)]
+        pub const fn $name(&self) -> bool {
+            self.format & flags::$flag != 0
+        }
+    };
+}
+
+/// Set or clear a flag in the packed struct.
+macro_rules! flag_setter {
+    ($(#[$meta:meta])* $name:ident, $flag:ident) => {
+        $(#[$meta])*
+        #[inline]
+        pub const fn $name(&self, flag: bool) -> Result<Self> {
+            if flag {
+                Self::new(self.format | flags::$flag)
+            } else {
+                Self::new(self.format & !flags::$flag)
+            }
+        }
+    };
+}
+
+/// Get the digit separator flags for a component as a policy.
+macro_rules! policy_getter {
+    ($(#[$meta:meta])* $name:ident, $i:ident, $l:ident, $t:ident, $c:ident) => {
+        $(#[$meta])*
+        #[inline(always)]
+        pub const fn $name(&self) -> SeparatorPolicy {
+            SeparatorPolicy {
+                internal: self.format & flags::$i != 0,
+                leading: self.format & flags::$l != 0,
+                trailing: self.format & flags::$t != 0,
+                consecutive: self.format & flags::$c != 0,
+            }
+        }
+    };
+}
+
+/// Replace the digit separator flags for a component with a policy.
+macro_rules! policy_setter {
+    ($(#[$meta:meta])* $name:ident, $mask:ident, $i:ident, $l:ident, $t:ident, $c:ident) => {
+        $(#[$meta])*
+        #[inline]
+        pub const fn $name(&self, policy: SeparatorPolicy) -> Result<Self> {
+            let mut format = self.format & !flags::$mask;
+            if policy.internal {
+                format |= flags::$i;
+            }
+            if policy.leading {
+                format |= flags::$l;
+            }
+            if policy.trailing {
+                format |= flags::$t;
+            }
+            if policy.consecutive {
+                format |= flags::$c;
+            }
+            Self::new(format)
+        }
+    };
+}
+
+/// Where digit separators may occur within a number component.
+///
+/// Each component of a number, the integer, fraction and exponent
+/// digits, has its own policy, so for example grouping may be allowed
+/// in the integer digits only. See [`NumberFormatBuilder`] for a
+/// description of the internal, leading, trailing and consecutive
+/// digit separator rules.
+///
+/// ```rust
+/// # use lexical_util::format::SeparatorPolicy;
+/// let policy = SeparatorPolicy::INTERNAL;
+/// assert!(policy.internal);
+/// assert!(!policy.leading);
+/// assert!(policy.is_enabled());
+/// assert!(!SeparatorPolicy::NONE.is_enabled());
+/// ```
+///
+/// [`NumberFormatBuilder`]: crate::format::NumberFormatBuilder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SeparatorPolicy {
+    /// If digit separators are allowed between digits.
+    pub internal: bool,
+    /// If a digit separator is allowed before any digits.
+    pub leading: bool,
+    /// If a digit separator is allowed after any digits.
+    pub trailing: bool,
+    /// If multiple consecutive digit separators are allowed.
+    pub consecutive: bool,
+}
+
+impl SeparatorPolicy {
+    /// Digit separators are not allowed.
+    pub const NONE: Self = Self {
+        internal: false,
+        leading: false,
+        trailing: false,
+        consecutive: false,
+    };
+
+    /// Single digit separators are allowed between digits, such as `1_000`.
+    pub const INTERNAL: Self = Self {
+        internal: true,
+        leading: false,
+        trailing: false,
+        consecutive: false,
+    };
+
+    /// Digit separators are allowed anywhere, such as `_1__000_`.
+    pub const ALL: Self = Self {
+        internal: true,
+        leading: true,
+        trailing: true,
+        consecutive: true,
+    };
+
+    /// Get if digit separators are allowed anywhere in the component.
+    #[inline(always)]
+    pub const fn is_enabled(&self) -> bool {
+        self.internal || self.leading || self.trailing || self.consecutive
+    }
+}
+
+/// Validated number format packed struct, known at runtime.
+///
+/// The packed struct is built with [`NumberFormatBuilder`], exactly like
+/// the `FORMAT` const generic. The format is validated on creation, so
+/// an invalid format is reported once, rather than on every conversion.
+///
+/// ```rust
+/// # use lexical_util::format::{Format, STANDARD};
+/// let format = Format::new(STANDARD).unwrap();
+/// assert_eq!(format.packed(), STANDARD);
+/// assert_eq!(format.radix(), 10);
+/// ```
+///
+/// [`NumberFormatBuilder`]: crate::format::NumberFormatBuilder
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Format {
+    format: u128,
+}
+
+impl Format {
+    // CONSTRUCTORS
+
+    /// Create a runtime format from the packed struct, validating it.
+    ///
+    /// Returns the same error as [`NumberFormat::error`] if the format
+    /// is invalid.
+    ///
+    /// [`NumberFormat::error`]: crate::format::NumberFormat::error
+    #[inline]
+    pub const fn new(format: u128) -> Result<Self> {
+        let error = packed_error(format);
+        if error.is_success() {
+            Ok(Self {
+                format,
+            })
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Get the packed struct for the format.
+    #[inline(always)]
+    pub const fn packed(&self) -> u128 {
+        self.format
+    }
+
+    // NON-DIGIT SEPARATOR FLAGS
+
+    flag_getter!(
+        /// Get if digits are required before the decimal point.
+        required_integer_digits,
+        REQUIRED_INTEGER_DIGITS
+    );
+    flag_getter!(
+        /// Get if digits are required after the decimal point.
+        required_fraction_digits,
+        REQUIRED_FRACTION_DIGITS
+    );
+    flag_getter!(
+        /// Get if digits are required after the exponent character.
+        required_exponent_digits,
+        REQUIRED_EXPONENT_DIGITS
+    );
+    flag_getter!(
+        /// Get if significant digits are required.
+        required_mantissa_digits,
+        REQUIRED_MANTISSA_DIGITS
+    );
+    flag_getter!(
+        /// Get if a positive sign before the mantissa is not allowed.
+        no_positive_mantissa_sign,
+        NO_POSITIVE_MANTISSA_SIGN
+    );
+    flag_getter!(
+        /// Get if a sign symbol before the mantissa is required.
+        required_mantissa_sign,
+        REQUIRED_MANTISSA_SIGN
+    );
+    flag_getter!(
+        /// Get if exponent notation is not allowed.
+        no_exponent_notation,
+        NO_EXPONENT_NOTATION
+    );
+    flag_getter!(
+        /// Get if a positive sign before the exponent is not allowed.
+        no_positive_exponent_sign,
+        NO_POSITIVE_EXPONENT_SIGN
+    );
+    flag_getter!(
+        /// Get if a sign symbol before the exponent is required.
+        required_exponent_sign,
+        REQUIRED_EXPONENT_SIGN
+    );
+    flag_getter!(
+        /// Get if an exponent without fraction is not allowed.
+        no_exponent_without_fraction,
+        NO_EXPONENT_WITHOUT_FRACTION
+    );
+    flag_getter!(
+        /// Get if special (non-finite) values are not allowed.
+        no_special,
+        NO_SPECIAL
+    );
+    flag_getter!(
+        /// Get if special (non-finite) values are case-sensitive.
+        case_sensitive_special,
+        CASE_SENSITIVE_SPECIAL
+    );
+    flag_getter!(
+        /// Get if leading zeros before an integer are not allowed.
+        no_integer_leading_zeros,
+        NO_INTEGER_LEADING_ZEROS
+    );
+    flag_getter!(
+        /// Get if leading zeros before a float are not allowed.
+        no_float_leading_zeros,
+        NO_FLOAT_LEADING_ZEROS
+    );
+    flag_getter!(
+        /// Get if exponent notation is required.
+        required_exponent_notation,
+        REQUIRED_EXPONENT_NOTATION
+    );
+    flag_getter!(
+        /// Get if exponent characters are case-sensitive.
+        case_sensitive_exponent,
+        CASE_SENSITIVE_EXPONENT
+    );
+    flag_getter!(
+        /// Get if base prefixes are case-sensitive.
+        case_sensitive_base_prefix,
+        CASE_SENSITIVE_BASE_PREFIX
+    );
+    flag_getter!(
+        /// Get if base suffixes are case-sensitive.
+        case_sensitive_base_suffix,
+        CASE_SENSITIVE_BASE_SUFFIX
+    );
+    flag_getter!(
+        /// Get if a base prefix is required before the significant digits.
+        required_base_prefix,
+        REQUIRED_BASE_PREFIX
+    );
+    flag_getter!(
+        /// Get if a base suffix is required after the significant digits.
+        required_base_suffix,
+        REQUIRED_BASE_SUFFIX
+    );
+
+    // DIGIT SEPARATOR FLAGS
+
+    flag_getter!(
+        /// Get if digit separators are allowed between integer digits.
+        integer_internal_digit_separator,
+        INTEGER_INTERNAL_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if digit separators are allowed between fraction digits.
+        fraction_internal_digit_separator,
+        FRACTION_INTERNAL_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if digit separators are allowed between exponent digits.
+        exponent_internal_digit_separator,
+        EXPONENT_INTERNAL_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if a digit separator is allowed before any integer digits.
+        integer_leading_digit_separator,
+        INTEGER_LEADING_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if a digit separator is allowed before any fraction digits.
+        fraction_leading_digit_separator,
+        FRACTION_LEADING_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if a digit separator is allowed before any exponent digits.
+        exponent_leading_digit_separator,
+        EXPONENT_LEADING_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if a digit separator is allowed after any integer digits.
+        integer_trailing_digit_separator,
+        INTEGER_TRAILING_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if a digit separator is allowed after any fraction digits.
+        fraction_trailing_digit_separator,
+        FRACTION_TRAILING_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if a digit separator is allowed after any exponent digits.
+        exponent_trailing_digit_separator,
+        EXPONENT_TRAILING_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if multiple consecutive integer digit separators are allowed.
+        integer_consecutive_digit_separator,
+        INTEGER_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if multiple consecutive fraction digit separators are allowed.
+        fraction_consecutive_digit_separator,
+        FRACTION_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if multiple consecutive exponent digit separators are allowed.
+        exponent_consecutive_digit_separator,
+        EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    flag_getter!(
+        /// Get if any digit separators are allowed in special (non-finite) values.
+        special_digit_separator,
+        SPECIAL_DIGIT_SEPARATOR
+    );
+
+    // CHARACTERS
+
+    /// Get the digit separator character.
+    ///
+    /// If the digit separator is 0, digit separators are not allowed.
+    #[inline(always)]
+    pub const fn digit_separator(&self) -> u8 {
+        if cfg!(feature = "format") {
+            flags::digit_separator(self.format)
+        } else {
+            0
+        }
+    }
+
+    /// Get the character for the base prefix.
+    ///
+    /// If the base prefix is 0, base prefixes are not allowed.
+    #[inline(always)]
+    pub const fn base_prefix(&self) -> u8 {
+        if cfg!(feature = "format") {
+            flags::base_prefix(self.format)
+        } else {
+            0
+        }
+    }
+
+    /// Get the character for the base suffix.
+    ///
+    /// If the base suffix is 0, base suffixes are not allowed.
+    #[inline(always)]
+    pub const fn base_suffix(&self) -> u8 {
+        if cfg!(feature = "format") {
+            flags::base_suffix(self.format)
+        } else {
+            0
+        }
+    }
+
+    // RADIX
+
+    /// Get the radix for the mantissa digits.
+    #[inline(always)]
+    pub const fn mantissa_radix(&self) -> u32 {
+        flags::mantissa_radix(self.format)
+    }
+
+    /// Get the radix for the significant digits.
+    #[inline(always)]
+    pub const fn radix(&self) -> u32 {
+        self.mantissa_radix()
+    }
+
+    /// Get the base for the exponent.
+    ///
+    /// IE, a base of 2 means we have `mantissa * 2^exponent`.
+    /// If not provided, it defaults to `radix`.
+    #[inline(always)]
+    pub const fn exponent_base(&self) -> u32 {
+        flags::exponent_base(self.format)
+    }
+
+    /// Get the radix for the exponent digits.
+    #[inline(always)]
+    pub const fn exponent_radix(&self) -> u32 {
+        flags::exponent_radix(self.format)
+    }
+
+    // SEPARATOR POLICIES
+
+    policy_getter!(
+        /// Get where digit separators may occur in the integer digits.
+        integer_separator_policy,
+        INTEGER_INTERNAL_DIGIT_SEPARATOR,
+        INTEGER_LEADING_DIGIT_SEPARATOR,
+        INTEGER_TRAILING_DIGIT_SEPARATOR,
+        INTEGER_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    policy_getter!(
+        /// Get where digit separators may occur in the fraction digits.
+        fraction_separator_policy,
+        FRACTION_INTERNAL_DIGIT_SEPARATOR,
+        FRACTION_LEADING_DIGIT_SEPARATOR,
+        FRACTION_TRAILING_DIGIT_SEPARATOR,
+        FRACTION_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    policy_getter!(
+        /// Get where digit separators may occur in the exponent digits.
+        exponent_separator_policy,
+        EXPONENT_INTERNAL_DIGIT_SEPARATOR,
+        EXPONENT_LEADING_DIGIT_SEPARATOR,
+        EXPONENT_TRAILING_DIGIT_SEPARATOR,
+        EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+
+    policy_setter!(
+        /// Create a format with a new digit separator policy for the integer digits.
+        ///
+        /// Returns the same error as [`Format::new`] if the policy is
+        /// invalid for the format, for example, consecutive digit separators
+        /// without any other digit separators.
+        ///
+        /// ```rust
+        /// # #[cfg(feature = "format")] {
+        /// # use core::num::NonZeroU8;
+        /// # use lexical_util::format::{Format, NumberFormatBuilder, SeparatorPolicy};
+        /// let packed = NumberFormatBuilder::new()
+        ///     .digit_separator(NonZeroU8::new(b','))
+        ///     .build();
+        /// let format = Format::new(packed).unwrap();
+        /// let format = format.with_integer_separator_policy(SeparatorPolicy::INTERNAL).unwrap();
+        /// assert_eq!(format.integer_separator_policy(), SeparatorPolicy::INTERNAL);
+        /// assert_eq!(format.fraction_separator_policy(), SeparatorPolicy::NONE);
+        /// # }
+        /// ```
+        with_integer_separator_policy,
+        INTEGER_DIGIT_SEPARATOR_FLAG_MASK,
+        INTEGER_INTERNAL_DIGIT_SEPARATOR,
+        INTEGER_LEADING_DIGIT_SEPARATOR,
+        INTEGER_TRAILING_DIGIT_SEPARATOR,
+        INTEGER_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    policy_setter!(
+        /// Create a format with a new digit separator policy for the fraction digits.
+        ///
+        /// Returns the same error as [`Format::new`] if the policy is
+        /// invalid for the format.
+        with_fraction_separator_policy,
+        FRACTION_DIGIT_SEPARATOR_FLAG_MASK,
+        FRACTION_INTERNAL_DIGIT_SEPARATOR,
+        FRACTION_LEADING_DIGIT_SEPARATOR,
+        FRACTION_TRAILING_DIGIT_SEPARATOR,
+        FRACTION_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+    policy_setter!(
+        /// Create a format with a new digit separator policy for the exponent digits.
+        ///
+        /// Returns the same error as [`Format::new`] if the policy is
+        /// invalid for the format.
+        with_exponent_separator_policy,
+        EXPONENT_DIGIT_SEPARATOR_FLAG_MASK,
+        EXPONENT_INTERNAL_DIGIT_SEPARATOR,
+        EXPONENT_LEADING_DIGIT_SEPARATOR,
+        EXPONENT_TRAILING_DIGIT_SEPARATOR,
+        EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
+    );
+
+    // DIGIT REQUIREMENTS
+
+    flag_setter!(
+        /// Create a format that does or does not require digits before the decimal point.
+        ///
+        /// If not required, floats such as `.5` are valid, and writers
+        /// may trim the integer zero with `trim_integer_zero`. Returns
+        /// the same error as [`Format::new`] if the flag is invalid for
+        /// the format, which is always the case when setting the flag
+        /// without the `format` feature.
+        ///
+        /// ```rust
+        /// # #[cfg(feature = "format")] {
+        /// # use lexical_util::format::{Format, STANDARD};
+        /// let format = Format::new(STANDARD).unwrap();
+        /// let format = format.with_required_integer_digits(true).unwrap();
+        /// assert!(format.required_integer_digits());
+        /// assert!(!format.required_fraction_digits());
+        /// # }
+        /// ```
+        with_required_integer_digits,
+        REQUIRED_INTEGER_DIGITS
+    );
+    flag_setter!(
+        /// Create a format that does or does not require digits after the decimal point.
+        ///
+        /// If not required, floats such as `5.` are valid. Returns the
+        /// same error as [`Format::new`] if the flag is invalid for the
+        /// format.
+        with_required_fraction_digits,
+        REQUIRED_FRACTION_DIGITS
+    );
+
+    // FLAGS
+
+    /// Get the flags from the number format.
+    #[inline(always)]
+    pub const fn flags(&self) -> u128 {
+        self.format & flags::FLAG_MASK
+    }
+
+    /// Get the digit separator flags from the number format.
+    #[inline(always)]
+    pub const fn digit_separator_flags(&self) -> u128 {
+        self.format & flags::DIGIT_SEPARATOR_FLAG_MASK
+    }
+}
+
+impl Default for Format {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            format: crate::format::STANDARD,
+        }
+    }
+}
+
+// Only valid formats are generated: invalid builder states are rejected.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Format {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let builder: crate::format::NumberFormatBuilder = u.arbitrary()?;
+        Self::new(builder.build()).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl From<Format> for u128 {
+    #[inline(always)]
+    fn from(format: Format) -> Self {
+        format.format
+    }
+}
@@ -0,0 +1,261 @@
+//! An iterator that skips digit separators for a runtime format.
+//!
+//! This has the same behavior as the iterators in `skip`, however,
+//! the digit separator and the contexts it may occur in are read from
+//! a [`Format`] at runtime, rather than the `FORMAT` const generic.
+//! See the `skip` module for a description of the leading, internal,
+//! trailing and consecutive digit separator rules.
+//!
+//! Unlike the `skip` iterators, every consumed byte that is not a digit
+//! separator is counted, so [`FormatBytes::current_count`] is always the
+//! number of consumed digits and symbols, whatever the component.
+
+#![cfg(feature = "parse")]
+
+use crate::ascii::case_insensitive_eq;
+use crate::digit::char_is_digit_const;
+use crate::iterator::Mark;
+use crate::runtime_format::{Format, SeparatorPolicy};
+
+/// The component of a number being iterated over.
+///
+/// Each component has its own rules for where digit separators may occur.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumberComponent {
+    /// The digits before the decimal point.
+    Integer,
+    /// The digits after the decimal point.
+    Fraction,
+    /// The digits after the exponent character.
+    Exponent,
+    /// A special (non-finite) value, such as `NaN`.
+    Special,
+}
+
+/// Slice iterator that skips digit separators for a runtime format.
+#[derive(Clone)]
+pub struct FormatBytes<'a> {
+    /// The raw slice for the iterator.
+    slc: &'a [u8],
+    /// Current index of the iterator in the slice.
+    index: usize,
+    /// The current count of values returned by the iterator.
+    /// This is only used if the iterator is not contiguous.
+    count: usize,
+    /// The format for the digit separator rules.
+    format: Format,
+}
+
+impl<'a> FormatBytes<'a> {
+    /// Create new byte object.
+    #[inline]
+    pub fn new(slc: &'a [u8], format: Format) -> Self {
+        Self {
+            slc,
+            index: 0,
+            count: 0,
+            format,
+        }
+    }
+
+    /// Get if each yielded value is adjacent in memory.
+    #[inline]
+    pub fn is_contiguous(&self) -> bool {
+        self.format.digit_separator() == 0
+    }
+
+    /// Get a slice to the current start of the iterator.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [u8] {
+        &self.slc[self.index..]
+    }
+
+    /// Get the total number of elements in the underlying slice.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.slc.len()
+    }
+
+    /// Get the current index of the iterator in the slice.
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.index
+    }
+
+    /// Get the current number of values returned by the iterator.
+    #[inline]
+    pub fn current_count(&self) -> usize {
+        if self.is_contiguous() {
+            self.index
+        } else {
+            self.count
+        }
+    }
+
+    /// Get a checkpoint of the iterator, to rewind to with [`reset`](Self::reset).
+    #[inline]
+    pub fn mark(&self) -> Mark {
+        Mark {
+            index: self.index,
+            count: self.count,
+        }
+    }
+
+    /// Rewind the iterator to a checkpoint from [`mark`](Self::mark).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is past the end of the slice.
+    #[inline]
+    pub fn reset(&mut self, mark: Mark) {
+        assert!(mark.index <= self.length(), "Mark must be within the slice.");
+        self.index = mark.index;
+        self.count = mark.count;
+    }
+
+    /// Get if the buffer underlying the iterator is empty.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.index >= self.slc.len()
+    }
+
+    /// Check if the next element is a given value.
+    #[inline]
+    pub fn first_is(&self, value: u8) -> bool {
+        self.slc.get(self.index) == Some(&value)
+    }
+
+    /// Check if the next element is a given value without case sensitivity.
+    #[inline]
+    pub fn case_insensitive_first_is(&self, value: u8) -> bool {
+        match self.slc.get(self.index) {
+            Some(&c) => case_insensitive_eq(c, value),
+            None => false,
+        }
+    }
+
+    /// Advance the byte by 1 element.
+    ///
+    /// The current element must not be a digit separator.
+    #[inline]
+    pub fn step(&mut self) {
+        debug_assert!(!self.is_done());
+        debug_assert!(!self.is_digit_separator(self.slc[self.index]));
+        self.index += 1;
+        if !self.is_contiguous() {
+            self.count += 1;
+        }
+    }
+
+    /// Determine if the character is a digit separator.
+    #[inline]
+    pub fn is_digit_separator(&self, value: u8) -> bool {
+        let digit_separator = self.format.digit_separator();
+        digit_separator != 0 && value == digit_separator
+    }
+
+    /// Determine if the character is a digit in the component.
+    #[inline]
+    fn is_digit(&self, value: u8, component: NumberComponent) -> bool {
+        match component {
+            NumberComponent::Integer | NumberComponent::Fraction => {
+                char_is_digit_const(value, self.format.mantissa_radix())
+            },
+            NumberComponent::Exponent => char_is_digit_const(value, self.format.exponent_radix()),
+            NumberComponent::Special => false,
+        }
+    }
+
+    /// Get the digit separator policy for the component.
+    #[inline]
+    fn policy(&self, component: NumberComponent) -> SeparatorPolicy {
+        match component {
+            NumberComponent::Integer => self.format.integer_separator_policy(),
+            NumberComponent::Fraction => self.format.fraction_separator_policy(),
+            NumberComponent::Exponent => self.format.exponent_separator_policy(),
+            NumberComponent::Special if self.format.special_digit_separator() => {
+                SeparatorPolicy::ALL
+            },
+            NumberComponent::Special => SeparatorPolicy::NONE,
+        }
+    }
+
+    /// Determine if the digit separator at the cursor is leading.
+    #[inline]
+    fn is_l(&self, component: NumberComponent) -> bool {
+        let mut index = self.index;
+        while index > 0 && self.is_digit_separator(self.slc[index - 1]) {
+            index -= 1;
+        }
+        index == 0 || !self.is_digit(self.slc[index - 1], component)
+    }
+
+    /// Determine if the digit separator at the cursor is trailing.
+    #[inline]
+    fn is_t(&self, component: NumberComponent) -> bool {
+        let mut index = self.index;
+        while index < self.slc.len()
+            && matches!(self.slc.get(index + 1), Some(&x) if self.is_digit_separator(x))
+        {
+            index += 1;
+        }
+        index == self.slc.len()
+            || !matches!(self.slc.get(index + 1), Some(&x) if self.is_digit(x, component))
+    }
+
+    /// Peek the next value of the component, without consuming it.
+    ///
+    /// This consumes any digit separators that are valid at the cursor.
+    #[inline]
+    pub fn peek(&mut self, component: NumberComponent) -> Option<u8> {
+        let value = *self.slc.get(self.index)?;
+        let policy = self.policy(component);
+        let (i, l, t) = (policy.internal, policy.leading, policy.trailing);
+        let is_skip = match (i, l, t) {
+            (false, false, false) => false,
+            (true, true, true) => true,
+            (true, false, false) => !self.is_l(component) && !self.is_t(component),
+            (false, true, false) => self.is_l(component),
+            (false, false, true) => self.is_t(component),
+            (true, true, false) => self.is_l(component) || !self.is_t(component),
+            (true, false, true) => self.is_t(component) || !self.is_l(component),
+            (false, true, true) => self.is_l(component) || self.is_t(component),
+        };
+        if policy.is_enabled() && self.is_digit_separator(value) && is_skip {
+            // Skip every adjacent digit separator.
+            while matches!(self.slc.get(self.index), Some(&x) if self.is_digit_separator(x)) {
+                self.index += 1;
+            }
+            self.slc.get(self.index).copied()
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Get and consume the next value of the component.
+    #[inline]
+    pub fn next(&mut self, component: NumberComponent) -> Option<u8> {
+        let value = self.peek(component)?;
+        self.index += 1;
+        if !self.is_contiguous() {
+            self.count += 1;
+        }
+        Some(value)
+    }
+
+    /// Get if the component cannot return any more elements.
+    #[inline]
+    pub fn is_consumed(&mut self, component: NumberComponent) -> bool {
+        self.peek(component).is_none()
+    }
+
+    /// Skip zeros from the start of the component.
+    #[inline]
+    pub fn skip_zeros(&mut self, component: NumberComponent) -> usize {
+        let start = self.index;
+        while self.peek(component) == Some(b'0') {
+            self.next(component);
+        }
+        self.index - start
+    }
+}
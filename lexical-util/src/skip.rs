@@ -34,13 +34,24 @@
 //! represents the shorthand name of the consumer, in sorted order.
 //!  For example, `next_ilt` means that consumer can skip internal,
 //! leading, and trailing digit separators, but not consecutive ones.
+//!
+//! # Skip Sets
+//!
+//! In addition to the digit separator, [`Bytes`] can skip a caller-provided
+//! [`SkipSet`] of bytes, such as ignored characters or currency symbols.
+//! Unlike digit separators, bytes in the skip set are skipped anywhere in
+//! the number, in any component, and are not counted as consumed digits.
+//! They are also ignored when determining if a digit separator is leading,
+//! internal, or trailing, so `1$_2` has an internal digit separator when
+//! `$` is skipped.
 
 #![cfg(all(feature = "format", feature = "parse"))]
 
+use crate::ascii::case_insensitive_eq;
 use crate::digit::char_is_digit_const;
 use crate::format::NumberFormat;
 use crate::format_flags as flags;
-use crate::iterator::BytesIter;
+use crate::iterator::{BytesIter, Mark};
 use core::{mem, ptr};
 
 // PEEK
@@ -66,9 +77,7 @@ macro_rules! is_l {
     ($self:ident) => {{
         // Consume any digit separators before the current one.
         let mut index = $self.byte.index;
-        while index > 0
-            && $self.byte.slc.get(index - 1).map_or(false, |&x| $self.is_digit_separator(x))
-        {
+        while index > 0 && $self.byte.slc.get(index - 1).map_or(false, |&x| $self.is_skippable(x)) {
             index -= 1;
         }
 
@@ -88,7 +97,7 @@ macro_rules! is_t {
         // Consume any digit separators after the current one.
         let mut index = $self.byte.index;
         while index < $self.byte.slc.len()
-            && $self.byte.slc.get(index + 1).map_or(false, |&x| $self.is_digit_separator(x))
+            && $self.byte.slc.get(index + 1).map_or(false, |&x| $self.is_skippable(x))
         {
             index += 1;
         }
@@ -299,6 +308,86 @@ macro_rules! peek_iltc {
     }};
 }
 
+// SKIP SET
+// --------
+
+/// A set of bytes to skip while iterating, in addition to the digit separator.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "format", feature = "parse"))] {
+/// use lexical_util::format::STANDARD;
+/// use lexical_util::iterator::{Bytes, SkipSet};
+///
+/// const SKIP: SkipSet = SkipSet::from_bytes(b"$,");
+/// let mut bytes = Bytes::<{ STANDARD }>::with_skip_set(b"$1,234", SKIP);
+/// assert!(bytes.integer_iter().eq(b"1234".iter()));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SkipSet {
+    /// Bits for the bytes `[0, 128)`.
+    lo: u128,
+    /// Bits for the bytes `[128, 256)`.
+    hi: u128,
+}
+
+impl SkipSet {
+    /// Create an empty skip set.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            lo: 0,
+            hi: 0,
+        }
+    }
+
+    /// Create a skip set from a list of bytes.
+    #[inline]
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        let mut set = Self::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            set = set.with(bytes[index]);
+            index += 1;
+        }
+        set
+    }
+
+    /// Add a byte to the skip set.
+    #[inline(always)]
+    pub const fn with(self, byte: u8) -> Self {
+        if byte < 128 {
+            Self {
+                lo: self.lo | (1 << byte),
+                hi: self.hi,
+            }
+        } else {
+            Self {
+                lo: self.lo,
+                hi: self.hi | (1 << (byte - 128)),
+            }
+        }
+    }
+
+    /// Determine if the byte is in the skip set.
+    #[inline(always)]
+    pub const fn contains(&self, byte: u8) -> bool {
+        if byte < 128 {
+            self.lo & (1 << byte) != 0
+        } else {
+            self.hi & (1 << (byte - 128)) != 0
+        }
+    }
+
+    /// Determine if the skip set is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.lo == 0 && self.hi == 0
+    }
+}
+
 // AS DIGITS
 // ---------
 
@@ -340,6 +429,8 @@ pub struct Bytes<'a, const FORMAT: u128> {
     /// The current count of values returned by the iterator.
     /// This is only used if the iterator is not contiguous.
     count: usize,
+    /// Bytes to skip in addition to the digit separator.
+    skip: SkipSet,
 }
 
 impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
@@ -349,13 +440,26 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// Create new byte object.
     #[inline]
     pub fn new(slc: &'a [u8]) -> Self {
+        Self::with_skip_set(slc, SkipSet::new())
+    }
+
+    /// Create new byte object that also skips the bytes in the skip set.
+    #[inline]
+    pub fn with_skip_set(slc: &'a [u8], skip: SkipSet) -> Self {
         Self {
             slc,
             index: 0,
             count: 0,
+            skip,
         }
     }
 
+    /// Get the bytes skipped in addition to the digit separator.
+    #[inline]
+    pub fn skip_set(&self) -> SkipSet {
+        self.skip
+    }
+
     /// Get a ptr to the current start of the iterator.
     #[inline]
     pub fn as_ptr(&self) -> *const u8 {
@@ -397,13 +501,34 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     pub fn current_count(&self) -> usize {
         // If the buffer is contiguous, then we don't need to track the
         // number of values: the current index is enough.
-        if Self::IS_CONTIGUOUS {
+        if self.is_contiguous() {
             self.index
         } else {
             self.count
         }
     }
 
+    /// Get a checkpoint of the iterator, to rewind to with [`reset`](Self::reset).
+    #[inline]
+    pub fn mark(&self) -> Mark {
+        Mark {
+            index: self.index,
+            count: self.count,
+        }
+    }
+
+    /// Rewind the iterator to a checkpoint from [`mark`](Self::mark).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the checkpoint is past the end of the slice.
+    #[inline]
+    pub fn reset(&mut self, mark: Mark) {
+        assert!(mark.index <= self.length(), "Mark must be within the slice.");
+        self.index = mark.index;
+        self.count = mark.count;
+    }
+
     /// Get if the buffer underlying the iterator is empty.
     ///
     /// This might not be the same thing as `is_consumed`: `is_consumed`
@@ -416,10 +541,13 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
         self.index >= self.slc.len()
     }
 
-    // Determine if the abstraction is contiguous.
+    /// Determine if the abstraction is contiguous.
+    ///
+    /// This is only true if the format has no digit separator and the
+    /// skip set is empty.
     #[inline]
     pub fn is_contiguous(&self) -> bool {
-        Self::IS_CONTIGUOUS
+        Self::IS_CONTIGUOUS && self.skip.is_empty()
     }
 
     /// Read a value of a difference type from the iterator.
@@ -431,7 +559,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// many bytes as the size of V.
     #[inline]
     pub unsafe fn read_unchecked<V>(&self) -> V {
-        debug_assert!(Self::IS_CONTIGUOUS);
+        debug_assert!(self.is_contiguous());
         debug_assert!(self.as_slice().len() >= mem::size_of::<V>());
 
         let slc = self.as_slice();
@@ -443,7 +571,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// This advances the internal state of the iterator.
     #[inline]
     pub fn read<V>(&self) -> Option<V> {
-        if Self::IS_CONTIGUOUS && self.as_slice().len() >= mem::size_of::<V>() {
+        if self.is_contiguous() && self.as_slice().len() >= mem::size_of::<V>() {
             // SAFETY: safe since we've guaranteed the buffer is greater than
             // the number of elements read.
             unsafe { Some(self.read_unchecked()) }
@@ -470,7 +598,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
         // Don't assert not a digit separator, since this can occur when
         // a different component does not allow digit separators there.
         if let Some(&c) = self.slc.get(self.index) {
-            c.to_ascii_lowercase() == value.to_ascii_lowercase()
+            case_insensitive_eq(c, value)
         } else {
             false
         }
@@ -516,23 +644,24 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// is safe.
     #[inline]
     pub unsafe fn step_by_unchecked(&mut self, count: usize) {
-        if Self::IS_CONTIGUOUS {
+        if self.is_contiguous() {
             // Contiguous, can skip most of these checks.
             debug_assert!(self.as_slice().len() >= count);
         } else {
             // Since this isn't contiguous, it only works
             // if the value is in the range `[0, 1]`.
             // We also need to make sure the **current** value
-            // isn't a digit separator.
+            // isn't a digit separator or a skipped byte.
             let format = NumberFormat::<{ FORMAT }> {};
             debug_assert!(self.as_slice().len() >= count);
             debug_assert!(count == 0 || count == 1);
             debug_assert!(
                 count == 0 || self.slc.get(self.index) != Some(&format.digit_separator())
             );
+            debug_assert!(count == 0 || !self.skip.contains(self.slc[self.index]));
         }
         self.index += count;
-        if !Self::IS_CONTIGUOUS {
+        if !self.is_contiguous() {
             // Only increment the count if it's not contiguous, otherwise,
             // this is an unnecessary performance penalty.
             self.count += count;
@@ -555,6 +684,19 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
 // ITERATOR HELPERS
 // ----------------
 
+/// Peek the next value, skipping digit separators and the skip set.
+macro_rules! skip_set_peek {
+    ($self:ident) => {{
+        loop {
+            let value = $self.peek_separators()?;
+            if !$self.byte.skip.contains(*value) {
+                return Some(value);
+            }
+            $self.byte.index += 1;
+        }
+    }};
+}
+
 /// Create skip iterator definition.
 macro_rules! skip_iterator {
     ($iterator:ident, $doc:literal) => {
@@ -580,6 +722,12 @@ macro_rules! is_digit_separator {
                 value == digit_separator
             }
         }
+
+        /// Determine if the character is a digit separator or in the skip set.
+        #[inline]
+        pub fn is_skippable(&self, value: u8) -> bool {
+            self.is_digit_separator(value) || self.byte.skip.contains(value)
+        }
     };
 }
 
@@ -610,9 +758,11 @@ macro_rules! skip_iterator_iterator_impl {
                 let value = self.peek()?;
                 // Increment the index so we know not to re-fetch it.
                 self.byte.index += 1;
-                if !Self::IS_CONTIGUOUS {
+                if !self.byte.is_contiguous() {
                     // Only increment the count if it's not contiguous, otherwise,
-                    // this is an unnecessary performance penalty.
+                    // this is an unnecessary performance penalty. This must
+                    // match the bytes, not the component: the component may
+                    // not allow digit separators while others do.
                     self.byte.count += 1;
                 }
                 Some(value)
@@ -661,6 +811,16 @@ macro_rules! skip_iterator_byteiter_base {
             self.byte.current_count()
         }
 
+        #[inline]
+        fn mark(&self) -> Mark {
+            self.byte.mark()
+        }
+
+        #[inline]
+        fn reset(&mut self, mark: Mark) {
+            self.byte.reset(mark)
+        }
+
         #[inline]
         fn is_consumed(&mut self) -> bool {
             self.peek().is_none()
@@ -673,7 +833,7 @@ macro_rules! skip_iterator_byteiter_base {
 
         #[inline]
         fn is_contiguous(&self) -> bool {
-            Self::IS_CONTIGUOUS
+            Self::IS_CONTIGUOUS && self.byte.skip.is_empty()
         }
 
         #[inline]
@@ -711,6 +871,14 @@ macro_rules! skip_iterator_byteiter_impl {
             /// Peek the next value of the iterator, without consuming it.
             #[inline]
             fn peek(&mut self) -> Option<<Self as Iterator>::Item> {
+                skip_set_peek!(self)
+            }
+        }
+
+        impl<'a: 'b, 'b, const FORMAT: u128> $iterator<'a, 'b, FORMAT> {
+            /// Peek the next value, only skipping digit separators.
+            #[inline]
+            fn peek_separators(&mut self) -> Option<&'a u8> {
                 let format = NumberFormat::<{ FORMAT }> {};
                 const IL: u128 = flags::$i | flags::$l;
                 const IT: u128 = flags::$i | flags::$t;
@@ -803,14 +971,10 @@ skip_iterator_iterator_impl!(SpecialBytesIterator);
 
 impl<'a: 'b, 'b, const FORMAT: u128> SpecialBytesIterator<'a, 'b, FORMAT> {
     is_digit_separator!(FORMAT);
-}
 
-impl<'a: 'b, 'b, const FORMAT: u128> BytesIter<'a> for SpecialBytesIterator<'a, 'b, FORMAT> {
-    skip_iterator_byteiter_base!(FORMAT, SPECIAL_DIGIT_SEPARATOR);
-
-    /// Peek the next value of the iterator, without consuming it.
+    /// Peek the next value, only skipping digit separators.
     #[inline]
-    fn peek(&mut self) -> Option<<Self as Iterator>::Item> {
+    fn peek_separators(&mut self) -> Option<&'a u8> {
         let format = NumberFormat::<{ FORMAT }> {};
         if format.special_digit_separator() {
             peek_iltc!(self)
@@ -819,3 +983,13 @@ impl<'a: 'b, 'b, const FORMAT: u128> BytesIter<'a> for SpecialBytesIterator<'a,
         }
     }
 }
+
+impl<'a: 'b, 'b, const FORMAT: u128> BytesIter<'a> for SpecialBytesIterator<'a, 'b, FORMAT> {
+    skip_iterator_byteiter_base!(FORMAT, SPECIAL_DIGIT_SEPARATOR);
+
+    /// Peek the next value of the iterator, without consuming it.
+    #[inline]
+    fn peek(&mut self) -> Option<<Self as Iterator>::Item> {
+        skip_set_peek!(self)
+    }
+}
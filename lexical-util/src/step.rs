@@ -58,7 +58,7 @@ pub const fn min_step(radix: u32, bits: usize, is_signed: bool) -> usize {
             34 => min_step_34(bits, is_signed),
             35 => min_step_35(bits, is_signed),
             36 => min_step_36(bits, is_signed),
-            _ => 1,
+            _ => min_step_generic(radix, bits, is_signed),
         }
     } else if cfg!(feature = "power-of-two") {
         match radix {
@@ -118,7 +118,7 @@ pub const fn max_step(radix: u32, bits: usize, is_signed: bool) -> usize {
             34 => max_step_34(bits, is_signed),
             35 => max_step_35(bits, is_signed),
             36 => max_step_36(bits, is_signed),
-            _ => 1,
+            _ => max_step_generic(radix, bits, is_signed),
         }
     } else if cfg!(feature = "power-of-two") {
         match radix {
@@ -142,6 +142,58 @@ pub const fn u64_step(radix: u32) -> usize {
     min_step(radix, 64, false)
 }
 
+/// Get the maximum value for a type with the given bits and sign.
+#[inline(always)]
+const fn max_value(bits: usize, is_signed: bool) -> u128 {
+    u128::MAX >> (128 - bits + is_signed as usize)
+}
+
+/// Calculate the minimum step for radixes without pre-computed constants.
+///
+/// This is used for radixes from 37 to 62, which are only supported
+/// for integers, and is evaluated at compile time.
+#[inline]
+const fn min_step_generic(radix: u32, bits: usize, is_signed: bool) -> usize {
+    if radix < 2 || bits == 0 || bits > 128 {
+        return 1;
+    }
+    let max = max_value(bits, is_signed);
+    let radix = radix as u128;
+    let mut step = 0;
+    let mut power: u128 = 1;
+    while let Some(next) = power.checked_mul(radix) {
+        if next - 1 > max {
+            break;
+        }
+        power = next;
+        step += 1;
+    }
+    step
+}
+
+/// Calculate the maximum step for radixes without pre-computed constants.
+///
+/// This is used for radixes from 37 to 62, which are only supported
+/// for integers, and is evaluated at compile time.
+#[inline]
+const fn max_step_generic(radix: u32, bits: usize, is_signed: bool) -> usize {
+    if radix < 2 || bits == 0 || bits > 128 {
+        return 1;
+    }
+    let max = max_value(bits, is_signed);
+    let radix = radix as u128;
+    let mut step = 0;
+    let mut power: u128 = 1;
+    while power - 1 < max {
+        step += 1;
+        power = match power.checked_mul(radix) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    step
+}
+
 // AUTO-GENERATED
 // These functions were auto-generated by `etc/step.py`.
 // Do not edit them unless there is a good reason to.
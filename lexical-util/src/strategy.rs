@@ -0,0 +1,291 @@
+//! Property-testing strategies for number formats and numeric strings.
+//!
+//! These generate random, valid number formats, and numeric strings
+//! that are valid for a number format, so integrations can be property
+//! tested against formats other than the default. [`near_valid`]
+//! mutates the generated strings, to also test the handling of
+//! invalid input.
+//!
+//! ```rust
+//! # use lexical_util::format::Format;
+//! # use lexical_util::strategy;
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//! let integer = strategy::integer(Format::default(), 9);
+//! let string = integer.new_tree(&mut runner).unwrap().current();
+//! assert!(string.parse::<i32>().is_ok());
+//! ```
+
+use proptest::prelude::*;
+use proptest::{collection, option, sample};
+
+use crate::format::Format;
+use crate::format_flags as flags;
+
+/// Maximum number of integer or fraction digits in generated floats.
+const MAX_FLOAT_DIGITS: usize = 20;
+
+/// Maximum number of digits in generated exponents.
+const MAX_EXPONENT_DIGITS: usize = 3;
+
+/// Generate random, valid number formats.
+///
+/// Only the flags and radixes enabled by the crate features are set,
+/// and the digit separator, base prefix and base suffix are chosen
+/// from common punctuation that isn't a digit in the radix.
+pub fn format() -> impl Strategy<Value = Format> {
+    // Combine 2 random values, so each flag is only set 1/4 of the time,
+    // since many combinations of flags are invalid.
+    let bits = (any::<u64>(), any::<u64>()).prop_map(|(x, y)| x & y);
+    let separator = option::of(sample::select(&b"_',"[..]));
+    let radix = radix()
+        .prop_flat_map(|radix| (Just(radix), control(radix, b"xobdh"), control(radix, b"hdL")));
+    (bits, radix, separator).prop_filter_map(
+        "invalid number format",
+        |(bits, (radix, prefix, suffix), separator)| {
+            let mut format = (radix as u128) << flags::MANTISSA_RADIX_SHIFT;
+            if cfg!(feature = "format") {
+                format |= bits as u128 & flags::FLAG_MASK;
+                match separator {
+                    Some(c) => format |= (c as u128) << flags::DIGIT_SEPARATOR_SHIFT,
+                    None => format &= !flags::DIGIT_SEPARATOR_FLAG_MASK,
+                }
+                match prefix {
+                    Some(c) => format |= (c as u128) << flags::BASE_PREFIX_SHIFT,
+                    None => format &= !flags::REQUIRED_BASE_PREFIX,
+                }
+                match suffix {
+                    Some(c) => format |= (c as u128) << flags::BASE_SUFFIX_SHIFT,
+                    None => format &= !flags::REQUIRED_BASE_SUFFIX,
+                }
+            } else {
+                format |= bits as u128 & flags::REQUIRED_DIGITS;
+            }
+            Format::new(format).ok()
+        },
+    )
+}
+
+/// Generate valid integer strings for the number format.
+///
+/// The strings have between 1 and `max_digits` digits, so the values
+/// may overflow narrow integer types. Digit separators are only placed
+/// between digits.
+pub fn integer(format: Format, max_digits: usize) -> impl Strategy<Value = String> {
+    let separator = separator(format.digit_separator(), format.integer_internal_digit_separator());
+    let digits = digits(
+        format.mantissa_radix(),
+        1,
+        max_digits,
+        format.no_integer_leading_zeros(),
+        separator,
+    );
+    let sign = sign(format.required_mantissa_sign(), format.no_positive_mantissa_sign());
+    (sign, base_prefix(format), digits, base_suffix(format))
+        .prop_map(|(sign, prefix, digits, suffix)| [sign, &prefix, &digits, &suffix].concat())
+}
+
+/// Generate valid float strings for the number format.
+///
+/// The decimal point and exponent character must match the options
+/// used to parse the strings. Special values, such as `NaN`, are not
+/// generated, since the special strings are set by the options.
+pub fn float(format: Format, decimal_point: u8, exponent: u8) -> impl Strategy<Value = String> {
+    let radix = format.mantissa_radix();
+    let no_leading_zeros = format.no_integer_leading_zeros() || format.no_float_leading_zeros();
+    let integer = digits(
+        radix,
+        format.required_integer_digits() as usize,
+        MAX_FLOAT_DIGITS,
+        no_leading_zeros,
+        separator(format.digit_separator(), format.integer_internal_digit_separator()),
+    );
+    let fraction = digits(
+        radix,
+        format.required_fraction_digits() as usize,
+        MAX_FLOAT_DIGITS,
+        false,
+        separator(format.digit_separator(), format.fraction_internal_digit_separator()),
+    );
+    // Only the decimal digits are used, so they are never confused
+    // with the exponent character.
+    let exponent_digits = digits(
+        radix.min(10),
+        format.required_exponent_digits() as usize,
+        MAX_EXPONENT_DIGITS,
+        false,
+        separator(format.digit_separator(), format.exponent_internal_digit_separator()),
+    );
+    let exponent_sign = sign(format.required_exponent_sign(), format.no_positive_exponent_sign());
+    let mantissa = (
+        sign(format.required_mantissa_sign(), format.no_positive_mantissa_sign()),
+        base_prefix(format),
+        integer,
+        any::<bool>(),
+        fraction,
+    );
+    let notation = (any::<bool>(), any::<bool>(), exponent_sign, exponent_digits);
+    (mantissa, notation, base_suffix(format)).prop_map(move |(mantissa, notation, suffix)| {
+        let (sign, prefix, mut integer, has_fraction, fraction) = mantissa;
+        let (has_exponent, uppercase, exponent_sign, exponent_digits) = notation;
+        let has_exponent =
+            !format.no_exponent_notation() && (has_exponent || format.required_exponent_notation());
+        let has_fraction = has_fraction || (has_exponent && format.no_exponent_without_fraction());
+        if integer.is_empty() && (!has_fraction || fraction.is_empty()) {
+            integer.push('0');
+        }
+
+        let mut string = [sign, &prefix, &integer].concat();
+        if has_fraction {
+            string.push(decimal_point as char);
+            string.push_str(&fraction);
+        }
+        if has_exponent {
+            let exponent = match uppercase && !format.case_sensitive_exponent() {
+                true => exponent.to_ascii_uppercase(),
+                false => exponent,
+            };
+            string.push(exponent as char);
+            string.push_str(exponent_sign);
+            string.push_str(&exponent_digits);
+        }
+        string.push_str(&suffix);
+        string
+    })
+}
+
+/// Mutate the generated strings, to create near-valid numeric strings.
+///
+/// A single character is removed, duplicated, or replaced with a digit
+/// or punctuation, so the strings are often, but not always, invalid.
+pub fn near_valid<S>(strategy: S) -> impl Strategy<Value = String>
+where
+    S: Strategy<Value = String>,
+{
+    let replacement = sample::select(&b"0159aAeE+-._',xhL \x00"[..]);
+    (strategy, any::<sample::Index>(), 0..3u8, replacement).prop_map(
+        |(mut string, index, mutation, c)| {
+            if string.is_empty() {
+                string.push(c as char);
+                return string;
+            }
+            // The generated strings are ASCII, so any index is a character boundary.
+            let index = index.index(string.len());
+            match mutation {
+                0 => {
+                    string.remove(index);
+                },
+                1 => {
+                    let duplicate = string.as_bytes()[index] as char;
+                    string.insert(index, duplicate);
+                },
+                _ => {
+                    string.remove(index);
+                    string.insert(index, c as char);
+                },
+            }
+            string
+        },
+    )
+}
+
+// HELPERS
+// -------
+
+/// Generate a radix enabled by the crate features.
+fn radix() -> BoxedStrategy<u32> {
+    if cfg!(feature = "radix") {
+        (2u32..=36).boxed()
+    } else if cfg!(feature = "power-of-two") {
+        sample::select(&[2u32, 4, 8, 10, 16, 32][..]).boxed()
+    } else {
+        Just(10u32).boxed()
+    }
+}
+
+/// Generate an optional control character that isn't a digit in the radix.
+fn control(radix: u32, choices: &[u8]) -> BoxedStrategy<Option<u8>> {
+    let choices: Vec<u8> =
+        choices.iter().copied().filter(|&c| !(c as char).is_digit(radix)).collect();
+    match choices.is_empty() {
+        true => Just(None).boxed(),
+        false => option::of(sample::select(choices)).boxed(),
+    }
+}
+
+/// Get the digit separator to place between digits, if enabled.
+fn separator(separator: u8, internal: bool) -> Option<char> {
+    match separator != 0 && internal {
+        true => Some(separator as char),
+        false => None,
+    }
+}
+
+/// Generate a sign allowed by the sign flags.
+fn sign(required: bool, no_positive: bool) -> impl Strategy<Value = &'static str> {
+    let signs: &'static [&'static str] = match (required, no_positive) {
+        (true, _) => &["+", "-"],
+        (false, true) => &["", "-"],
+        (false, false) => &["", "+", "-"],
+    };
+    sample::select(signs)
+}
+
+/// Generate between `min` and `max` digits in the radix.
+fn digits(
+    radix: u32,
+    min: usize,
+    max: usize,
+    no_leading_zeros: bool,
+    separator: Option<char>,
+) -> impl Strategy<Value = String> {
+    collection::vec((0..radix, any::<bool>()), min..=max.max(min)).prop_map(move |digits| {
+        let mut string = String::new();
+        for (index, &(digit, separate)) in digits.iter().enumerate() {
+            let digit = match index == 0 && digit == 0 && no_leading_zeros && digits.len() > 1 {
+                true => 1,
+                false => digit,
+            };
+            if let (true, Some(c)) = (index != 0 && separate, separator) {
+                string.push(c);
+            }
+            // Digits are always less than the radix.
+            string.push(char::from_digit(digit, radix).unwrap_or('0'));
+        }
+        string
+    })
+}
+
+/// Generate an optional base prefix or suffix, in any allowed case.
+fn affix(
+    leading: &str,
+    affix: u8,
+    case_sensitive: bool,
+    required: bool,
+) -> impl Strategy<Value = String> {
+    let mut affixes = Vec::new();
+    if affix == 0 || !required {
+        affixes.push(String::new());
+    }
+    if affix != 0 {
+        affixes.push(format!("{}{}", leading, affix as char));
+        if !case_sensitive && affix.is_ascii_alphabetic() {
+            affixes.push(format!("{}{}", leading, (affix ^ 0x20) as char));
+        }
+    }
+    sample::select(affixes)
+}
+
+/// Generate the base prefix, including the leading zero.
+fn base_prefix(format: Format) -> impl Strategy<Value = String> {
+    let prefix = format.base_prefix();
+    affix("0", prefix, format.case_sensitive_base_prefix(), format.required_base_prefix())
+}
+
+/// Generate the base suffix.
+fn base_suffix(format: Format) -> impl Strategy<Value = String> {
+    let suffix = format.base_suffix();
+    affix("", suffix, format.case_sensitive_base_suffix(), format.required_base_suffix())
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "asm")]
+
+use lexical_util::arch::{mul64, mulhi64};
+use quickcheck::quickcheck;
+
+fn mul128(x: u64, y: u64) -> (u64, u64) {
+    let z = x as u128 * y as u128;
+    (z as u64, (z >> 64) as u64)
+}
+
+#[test]
+fn mul64_test() {
+    let values = [0, 1, 2, 5, 0xFFFF_FFFF, 0x1_0000_0000, 0x8000_0000_0000_0000, u64::MAX];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            assert_eq!(mul64(x, y), mul128(x, y));
+            assert_eq!(mulhi64(x, y), mul128(x, y).1);
+        }
+    }
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn mul64_quickcheck(x: u64, y: u64) -> bool {
+        mul64(x, y) == mul128(x, y)
+    }
+}
@@ -49,3 +49,51 @@ fn is_valid_letter_slice_test() {
     assert_eq!(ascii::is_valid_letter_slice(b" 09a"), false);
     assert_eq!(ascii::is_valid_letter_slice(b"aZAz"), true);
 }
+
+#[test]
+fn case_insensitive_eq_test() {
+    assert!(ascii::case_insensitive_eq(b'a', b'a'));
+    assert!(ascii::case_insensitive_eq(b'a', b'A'));
+    assert!(ascii::case_insensitive_eq(b'Z', b'z'));
+    assert!(!ascii::case_insensitive_eq(b'a', b'b'));
+    assert!(ascii::case_insensitive_eq(b'0', b'0'));
+    // Only letters differ by case, even if they differ by `0x20`.
+    assert!(!ascii::case_insensitive_eq(b'@', b'`'));
+    assert!(!ascii::case_insensitive_eq(b'[', b'{'));
+    assert!(!ascii::case_insensitive_eq(b'\x00', b' '));
+    for x in 0..=255u8 {
+        for y in 0..=255u8 {
+            assert_eq!(ascii::case_insensitive_eq(x, y), x.eq_ignore_ascii_case(&y));
+        }
+    }
+}
+
+#[test]
+fn case_insensitive_eq_slice_test() {
+    assert!(ascii::case_insensitive_eq_slice(b"NaN", b"nan"));
+    assert!(ascii::case_insensitive_eq_slice(b"Infinity", b"INFINITY"));
+    assert!(!ascii::case_insensitive_eq_slice(b"inf", b"infinity"));
+    assert!(ascii::case_insensitive_eq_slice(b"", b""));
+}
+
+#[test]
+fn starts_with_test() {
+    assert!(!ascii::starts_with(b"NaN".iter(), b"nAN".iter()));
+    assert!(ascii::starts_with(b"nAN1".iter(), b"nAN".iter()));
+    assert!(!ascii::starts_with(b"nA".iter(), b"nAN".iter()));
+}
+
+#[test]
+fn case_insensitive_starts_with_test() {
+    assert!(ascii::case_insensitive_starts_with(b"NaN1".iter(), b"nAN".iter()));
+    assert!(!ascii::case_insensitive_starts_with(b"nA".iter(), b"nAN".iter()));
+    assert!(!ascii::case_insensitive_starts_with(b"@".iter(), b"`".iter()));
+    assert!(ascii::case_insensitive_starts_with_slice(b"Infinity", b"inf"));
+    assert!(!ascii::case_insensitive_starts_with_slice(b"in", b"inf"));
+
+    const IS_NAN: [bool; 2] = [
+        ascii::case_insensitive_starts_with_slice(b"NAN(1)", b"nan"),
+        ascii::case_insensitive_starts_with_slice(b"NA", b"nan"),
+    ];
+    assert_eq!(IS_NAN, [true, false]);
+}
@@ -0,0 +1,424 @@
+#![cfg(feature = "bigint")]
+
+use core::cmp;
+use lexical_util::bigint::{self, Limb, ReverseView, StackVec, LIMB_BITS};
+use quickcheck::quickcheck;
+
+type VecType = StackVec<20>;
+
+#[test]
+fn from_test() {
+    let x = VecType::from_u64(0);
+    assert!(x.is_empty());
+
+    let x = VecType::from_u64(u64::MAX);
+    assert_eq!(x.hi64(), (u64::MAX, false));
+    if LIMB_BITS == 32 {
+        assert_eq!(&*x, &[Limb::MAX, Limb::MAX]);
+    } else {
+        assert_eq!(&*x, &[Limb::MAX]);
+    }
+}
+
+#[test]
+fn stackvec_test() {
+    let mut x = StackVec::<4>::default();
+    x.push(1);
+    x.extend(&[2, 3]);
+    assert_eq!(x.as_ref(), &[1, 2, 3]);
+    assert_eq!(format!("{:?}", x), "[1, 2, 3]");
+    x.resize(4, 5);
+    assert_eq!(&*x, &[1, 2, 3, 5]);
+    x.resize(1, 0);
+    assert_eq!(&*x, &[1]);
+
+    let y: StackVec<4> = vec![1, 2, 3].into_iter().collect();
+    assert_eq!(&*y, &[1, 2, 3]);
+    assert_eq!(format!("{:?}", StackVec::<4>::new()), "[]");
+}
+
+#[test]
+fn hex_test() {
+    assert_eq!(format!("{:x}", VecType::new()), "0");
+    assert_eq!(format!("{:#x}", VecType::new()), "0x0");
+    assert_eq!(format!("{:x}", VecType::from_u64(0xABCDEF)), "abcdef");
+    assert_eq!(format!("{:X}", VecType::from_u64(0xABCDEF)), "ABCDEF");
+
+    let mut x = VecType::from_u64(1);
+    bigint::shl(&mut x, 128).unwrap();
+    x.add_small(0xA).unwrap();
+    assert_eq!(format!("{:#x}", x), "0x10000000000000000000000000000000a");
+
+    // Leading zero limbs are skipped.
+    let x = VecType::try_from(&[0xB, 0]).unwrap();
+    assert_eq!(format!("{:X}", x), "B");
+}
+
+#[test]
+#[should_panic]
+fn stackvec_push_overflow_test() {
+    let mut x = StackVec::<2>::try_from(&[1, 2]).unwrap();
+    x.push(3);
+}
+
+#[test]
+#[should_panic]
+fn stackvec_extend_overflow_test() {
+    let mut x = StackVec::<2>::new();
+    x.extend(&[1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn stackvec_resize_overflow_test() {
+    let mut x = StackVec::<2>::new();
+    x.resize(3, 0);
+}
+
+#[test]
+#[should_panic]
+fn stackvec_collect_overflow_test() {
+    let _: StackVec<2> = (0..3).collect();
+}
+
+#[test]
+fn arithmetic_test() {
+    // (2^64 - 1) * 10^2 + 5
+    let mut x = VecType::from_u64(u64::MAX);
+    x.mul_small(10).unwrap();
+    x.mul_small(10).unwrap();
+    x.add_small(5).unwrap();
+    let expected = u64::MAX as u128 * 100 + 5;
+    assert_eq!(bigint::bit_length(&x), 71);
+    assert_eq!(x.hi64(), ((expected >> 7) as u64, true));
+
+    let y = VecType::from_u64(u64::MAX);
+    let mut z = x.clone();
+    z *= &y;
+    assert!(z > x);
+    assert_eq!(bigint::compare(&z, &x), cmp::Ordering::Greater);
+    assert_eq!(bigint::bit_length(&z), 64 + 71);
+
+    bigint::shl(&mut x, 64).unwrap();
+    assert_eq!(bigint::bit_length(&x), 64 + 71);
+}
+
+#[test]
+fn overflow_test() {
+    let mut x = StackVec::<2>::from_u64(u64::MAX);
+    assert_eq!(x.try_extend(&[1, 2, 3]), None);
+    assert_eq!(bigint::shl(&mut x, 128), None);
+
+    let mut x = StackVec::<2>::from_u64(u64::MAX);
+    let y = x.clone();
+    if LIMB_BITS == 32 {
+        assert_eq!(bigint::large_mul(&mut x, &y), None);
+    } else {
+        assert_eq!(bigint::large_mul(&mut x, &y), Some(()));
+        assert_eq!(bigint::large_mul(&mut x, &y), None);
+    }
+}
+
+#[test]
+fn reverse_view_test() {
+    let limbs: [Limb; 5] = [1, 2, 3, 4, 5];
+    let rview = ReverseView::new(&limbs);
+    assert_eq!(rview.len(), 5);
+    assert!(rview.clone().eq([5, 4, 3, 2, 1].iter()));
+    assert!(rview.clone().rev().eq(limbs.iter()));
+    assert_eq!(rview.clone().nth(1), Some(&4));
+    assert_eq!(rview.clone().nth(5), None);
+    assert_eq!(rview.clone().nth_back(1), Some(&2));
+    assert_eq!(rview.clone().last(), Some(&1));
+    assert_eq!(rview.clone().count(), 5);
+
+    let mut iter = rview.clone();
+    assert_eq!(iter.next(), Some(&5));
+    assert_eq!(iter.next_back(), Some(&1));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter[0], 4);
+    assert_eq!(iter.get(2), Some(&2));
+    assert_eq!(iter.get(3), None);
+    assert_eq!(iter.as_slice(), &[2, 3, 4]);
+    assert_eq!(iter.nth(5), None);
+    assert!(iter.is_empty());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert!(rview.get_slice(1..3).unwrap().eq([4, 3].iter()));
+    assert!(rview.get_slice(..=1).unwrap().eq([5, 4].iter()));
+    assert!(rview.get_slice(3..).unwrap().eq([2, 1].iter()));
+    assert!(rview.get_slice(5..).unwrap().is_empty());
+    assert!(rview.get_slice(..6).is_none());
+    assert!(rview.get_slice(6..).is_none());
+}
+
+#[test]
+fn hi_test() {
+    let x = VecType::try_from(&[0, 0, 0, 1 << (LIMB_BITS - 1)]).unwrap();
+    assert_eq!(x.hi16(), (1 << 15, false));
+    assert_eq!(x.hi32(), (1 << 31, false));
+    assert_eq!(x.hi64(), (1 << 63, false));
+
+    let x = VecType::try_from(&[1, 0, 0, 1 << (LIMB_BITS - 1)]).unwrap();
+    assert_eq!(x.hi16(), (1 << 15, true));
+    assert_eq!(x.hi32(), (1 << 31, true));
+    assert_eq!(x.hi64(), (1 << 63, true));
+
+    let x = VecType::try_from(&[3]).unwrap();
+    assert_eq!(x.hi16(), (0xC000, false));
+    assert_eq!(x.hi64(), (0xC000000000000000, false));
+    assert_eq!(VecType::new().hi64(), (0, false));
+}
+
+#[test]
+fn quorem_test() {
+    // 9 * 2^60 + 1 divided by 2^60, with a denominator with leading zeros.
+    let mut x = VecType::from_u64((9 << 60) + 1);
+    let y = VecType::from_u64(1 << 60);
+    assert_eq!(x.quorem(&y), Some(9));
+    assert_eq!(&*x, &*VecType::from_u64(1));
+
+    // Zero divisors and oversized numerators are errors.
+    let mut x = VecType::from_u64(1);
+    assert_eq!(x.quorem(&VecType::new()), None);
+    let mut x = VecType::from_u64(u64::MAX);
+    x.mul_small(3).unwrap();
+    assert_eq!(x.quorem(&VecType::from_u32(1)), None);
+}
+
+#[test]
+fn small_div_test() {
+    let mut x = VecType::from_u64(u64::MAX);
+    x.mul_small(10).unwrap();
+    x.add_small(7).unwrap();
+    assert_eq!(x.div_small(10), Some(7));
+    assert_eq!(&*x, &*VecType::from_u64(u64::MAX));
+    assert_eq!(x.div_small(0), None);
+}
+
+#[test]
+fn large_div_test() {
+    // Division by zero.
+    let mut x = VecType::from_u64(5);
+    assert!(bigint::large_div(&mut x, &[]).is_none());
+    assert!(bigint::large_div(&mut x, &[0, 0]).is_none());
+
+    // Numerator smaller than the divisor.
+    let y = VecType::from_u64(u64::MAX);
+    let rem = bigint::large_div(&mut x, &y).unwrap();
+    assert!(x.is_empty());
+    assert_eq!(&*rem, &*VecType::from_u64(5));
+
+    // (2^64 - 1)^3 / (2^64 - 1)^2 + 3.
+    let mut x = y.clone();
+    x *= &y;
+    x *= &y;
+    let mut y2 = y.clone();
+    y2 *= &y;
+    x.add_small(3).unwrap();
+    let rem = bigint::large_div(&mut x, &y2).unwrap();
+    assert_eq!(&*x, &*y);
+    assert_eq!(&*rem, &*VecType::from_u64(3));
+}
+
+fn vec_from_u64(x: &[u64]) -> VecType {
+    let mut vec = VecType::new();
+    for (i, &xi) in x.iter().enumerate() {
+        let mut xi = VecType::from_u64(xi);
+        bigint::shl(&mut xi, 64 * i).unwrap();
+        bigint::large_add(&mut vec, &xi).unwrap();
+    }
+    vec
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn large_div_quickcheck(x: Vec<u64>, y: Vec<u64>) -> bool {
+        let x = vec_from_u64(&x[..x.len().min(6)]);
+        let y = vec_from_u64(&y[..y.len().min(4)]);
+        let mut q = x.clone();
+        match bigint::large_div(&mut q, &y) {
+            None => y.is_empty(),
+            Some(r) => {
+                // Check `x == q * y + r` and `r < y`.
+                let mut result = q.clone();
+                result *= &y;
+                bigint::large_add(&mut result, &r).unwrap();
+                r < y && result == x
+            },
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn large_div_edge_test() {
+    // Limbs near the boundaries, which exercise the quotient corrections.
+    let limbs = [0, 1, 2, 1 << 63, (1 << 63) - 1, u64::MAX - 1, u64::MAX];
+    let check = |x: &[u64], y: &[u64]| {
+        let x = vec_from_u64(x);
+        let y = vec_from_u64(y);
+        let mut q = x.clone();
+        let r = bigint::large_div(&mut q, &y).unwrap();
+        let mut result = q.clone();
+        result *= &y;
+        bigint::large_add(&mut result, &r).unwrap();
+        assert!(r < y && result == x);
+    };
+    for &x0 in limbs.iter() {
+        for &x1 in limbs.iter() {
+            for &x2 in limbs.iter() {
+                for &y0 in limbs.iter() {
+                    for &y1 in limbs[1..].iter() {
+                        check(&[x0, x1, x2, x1], &[y0, y1]);
+                        check(&[x0, x1, x2, x0], &[y1, y0, y1]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn to_string(x: &VecType, radix: u32) -> String {
+    let mut buffer = [0u8; 1024];
+    let count = bigint::to_digits(x, radix, &mut buffer).unwrap();
+    String::from_utf8(buffer[..count].to_vec()).unwrap()
+}
+
+#[test]
+fn from_digits_test() {
+    let x = bigint::from_digits::<20>(b"340282366920938463463374607431768211455", 10).unwrap();
+    assert_eq!(x.hi64(), (u64::MAX, true));
+    assert_eq!(bigint::bit_length(&x), 128);
+    assert!(bigint::from_digits::<20>(b"0", 10).unwrap().is_empty());
+    assert!(bigint::from_digits::<20>(b"000", 10).unwrap().is_empty());
+    assert_eq!(&*bigint::from_digits::<20>(b"fF", 16).unwrap(), &[0xff]);
+
+    assert!(bigint::from_digits::<20>(b"", 10).is_none());
+    assert!(bigint::from_digits::<20>(b"12a", 10).is_none());
+    assert!(bigint::from_digits::<20>(b"102", 2).is_none());
+    assert!(bigint::from_digits::<20>(b"1", 1).is_none());
+    assert!(bigint::from_digits::<20>(b"1", 37).is_none());
+    assert!(bigint::from_digits::<1>(b"18446744073709551616", 10).is_none());
+}
+
+#[test]
+fn to_digits_test() {
+    assert_eq!(to_string(&VecType::new(), 10), "0");
+    assert_eq!(to_string(&VecType::from_u64(1), 10), "1");
+    assert_eq!(
+        to_string(&VecType::from_u64(10_000_000_000_000_000_000), 10),
+        "10000000000000000000"
+    );
+    assert_eq!(to_string(&VecType::from_u64(u64::MAX), 10), u64::MAX.to_string());
+    assert_eq!(to_string(&VecType::from_u64(u64::MAX), 16), "FFFFFFFFFFFFFFFF");
+    assert_eq!(to_string(&VecType::from_u64(35), 36), "Z");
+
+    // Non-normalized values.
+    let x = VecType::try_from(&[5, 0, 0]).unwrap();
+    assert_eq!(to_string(&x, 2), "101");
+
+    let mut buffer = [0u8; 3];
+    let x = VecType::from_u64(1000);
+    assert_eq!(bigint::to_digits(&x, 10, &mut buffer), None);
+    assert_eq!(bigint::to_digits(&x, 37, &mut buffer), None);
+    assert_eq!(bigint::to_digits(&VecType::from_u64(999), 10, &mut buffer), Some(3));
+    assert_eq!(&buffer, b"999");
+}
+
+#[test]
+fn digits_roundtrip_test() {
+    // A 400-digit value, with runs of zeros across the chunk boundaries.
+    let mut digits = String::new();
+    for i in 0..40 {
+        digits.push_str(if i % 3 == 0 {
+            "1000000000"
+        } else {
+            "9876543210"
+        });
+    }
+    for &radix in [10, 2, 7, 16, 36].iter() {
+        let x = bigint::from_digits::<64>(digits.as_bytes(), 10).unwrap();
+        let mut buffer = [0u8; 2048];
+        let count = bigint::to_digits(&x, radix, &mut buffer).unwrap();
+        let y = bigint::from_digits::<64>(&buffer[..count], radix).unwrap();
+        assert!(x == y);
+        if radix == 10 {
+            assert_eq!(&buffer[..count], digits.as_bytes());
+        }
+    }
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn digits_quickcheck(x: u128) -> bool {
+        let vec = bigint::from_digits::<20>(x.to_string().as_bytes(), 10).unwrap();
+        let hex = bigint::from_digits::<20>(format!("{:x}", x).as_bytes(), 16).unwrap();
+        vec == hex
+            && to_string(&vec, 10) == x.to_string()
+            && to_string(&vec, 16) == format!("{:X}", x)
+            && to_string(&vec, 2) == format!("{:b}", x)
+    }
+}
+
+fn convert(digits: &str, from: u32, to: u32) -> Option<String> {
+    let mut buffer = [0u8; 2048];
+    let count = bigint::convert_radix::<64>(digits.as_bytes(), from, to, &mut buffer)?;
+    Some(String::from_utf8(buffer[..count].to_vec()).unwrap())
+}
+
+#[test]
+fn convert_radix_test() {
+    assert_eq!(convert("255", 10, 16).as_deref(), Some("FF"));
+    assert_eq!(convert("-255", 10, 2).as_deref(), Some("-11111111"));
+    assert_eq!(convert("0", 10, 16).as_deref(), Some("0"));
+    assert_eq!(convert("0.5", 10, 2).as_deref(), Some("0.1"));
+    assert_eq!(convert(".5", 10, 2).as_deref(), Some("0.1"));
+    assert_eq!(convert("1.", 10, 2).as_deref(), Some("1"));
+    assert_eq!(convert("1.500", 10, 16).as_deref(), Some("1.8"));
+    assert_eq!(convert("-1.75", 10, 16).as_deref(), Some("-1.C"));
+    assert_eq!(convert("0.1", 2, 10).as_deref(), Some("0.5"));
+    assert_eq!(convert("0.1", 3, 9).as_deref(), Some("0.3"));
+    assert_eq!(convert("0.0001", 2, 10).as_deref(), Some("0.0625"));
+    assert_eq!(convert("z.i", 36, 10).as_deref(), Some("35.5"));
+
+    // Not exactly representable.
+    assert_eq!(convert("0.1", 10, 2), None);
+    assert_eq!(convert("0.1", 3, 10), None);
+
+    // Invalid input.
+    assert_eq!(convert("", 10, 2), None);
+    assert_eq!(convert(".", 10, 2), None);
+    assert_eq!(convert("-", 10, 2), None);
+    assert_eq!(convert("1.2.3", 10, 2), None);
+    assert_eq!(convert("12", 2, 10), None);
+    assert_eq!(convert("1", 10, 37), None);
+
+    // Buffer too small.
+    let mut buffer = [0u8; 2];
+    assert_eq!(bigint::convert_radix::<20>(b"255", 10, 10, &mut buffer), None);
+    assert_eq!(bigint::convert_radix::<20>(b"0.5", 10, 2, &mut buffer), None);
+}
+
+#[test]
+fn convert_radix_large_test() {
+    // A 500-digit integer, as well as a 301-digit binary fraction,
+    // which has a 301-digit decimal fraction.
+    let integer = "31415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679821480865132823066470938446095505822317253594081284811174502841027019385211055596446229489549303819644288109756659334461284756482337867831652712019091456485669234603486104543266482133936072602491412737245870066063155881748815209209628292540917153643678925903600113305305488204665213841469519415116094330572703657595919530921861173819326117931051185480744623799627495673518857527248912279381830119491";
+    let hex = convert(integer, 10, 16).unwrap();
+    assert_eq!(convert(&hex, 16, 10).as_deref(), Some(integer));
+
+    let mut fraction = String::from("0.");
+    for i in 0..300 {
+        fraction.push(if i % 7 == 0 {
+            '1'
+        } else {
+            '0'
+        });
+    }
+    fraction.push('1');
+    let decimal = convert(&fraction, 2, 10).unwrap();
+    assert_eq!(decimal.len(), 2 + 301);
+    assert_eq!(convert(&decimal, 10, 2).as_deref(), Some(&*fraction));
+}
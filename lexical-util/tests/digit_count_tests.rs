@@ -0,0 +1,56 @@
+use lexical_util::digit_count::{digit_count_radix, DigitCount};
+use quickcheck::quickcheck;
+
+#[test]
+fn digit_count_test() {
+    assert_eq!(0u8.digit_count(), 1);
+    assert_eq!(255u8.digit_count(), 3);
+    assert_eq!(9999u16.digit_count(), 4);
+    assert_eq!(u16::MAX.digit_count(), 5);
+    assert_eq!(u32::MAX.digit_count(), 10);
+    assert_eq!(u64::MAX.digit_count(), 20);
+    assert_eq!(u128::MAX.digit_count(), 39);
+    assert_eq!(usize::MAX.digit_count(), usize::MAX.to_string().len());
+}
+
+#[test]
+fn digit_count_radix_test() {
+    assert_eq!(digit_count_radix(0u32, 2), 1);
+    assert_eq!(digit_count_radix(1u32, 2), 1);
+    assert_eq!(digit_count_radix(255u32, 2), 8);
+    assert_eq!(digit_count_radix(256u32, 2), 9);
+    assert_eq!(digit_count_radix(255u32, 16), 2);
+    assert_eq!(digit_count_radix(256u32, 16), 3);
+    assert_eq!(digit_count_radix(u64::MAX, 8), 22);
+    assert_eq!(digit_count_radix(u128::MAX, 32), 26);
+    assert_eq!(digit_count_radix(999u32, 10), 3);
+    assert_eq!(digit_count_radix(26u32, 3), 3);
+    assert_eq!(digit_count_radix(27u32, 3), 4);
+    assert_eq!(digit_count_radix(35u8, 36), 1);
+    assert_eq!(digit_count_radix(36u8, 36), 2);
+}
+
+#[test]
+#[should_panic]
+fn digit_count_radix_invalid_test() {
+    digit_count_radix(1u32, 37);
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn u64_digit_count_quickcheck(x: u64) -> bool {
+        x.digit_count() == x.to_string().len()
+    }
+
+    #[cfg_attr(miri, ignore)]
+    fn u128_digit_count_radix_quickcheck(x: u128, radix: u32) -> bool {
+        let radix = radix % 35 + 2;
+        let mut count = 1;
+        let mut y = x;
+        while y >= radix as u128 {
+            y /= radix as u128;
+            count += 1;
+        }
+        digit_count_radix(x, radix) == count
+    }
+}
@@ -1,6 +1,4 @@
-#![cfg(any(feature = "parse", feature = "write"))]
-
-use lexical_util::digit;
+use lexical_util::digit::{self, DigitCase};
 
 #[cfg(feature = "parse")]
 fn char_to_digit(c: u8, radix: u32, expected: Option<u32>) {
@@ -108,3 +106,69 @@ fn digit_to_char_const_test() {
     digit_to_char(10, 36, b'A');
     digit_to_char(11, 36, b'B');
 }
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix62_test() {
+    #[cfg(feature = "parse")]
+    {
+        char_to_digit(b'9', 62, Some(9));
+        char_to_digit(b'a', 62, Some(10));
+        char_to_digit(b'z', 62, Some(35));
+        char_to_digit(b'A', 62, Some(36));
+        char_to_digit(b'Z', 62, Some(61));
+        char_to_digit(b'Z', 61, None);
+        char_to_digit(b'-', 62, None);
+    }
+
+    #[cfg(feature = "write")]
+    {
+        assert_eq!(digit::digit_to_char_const(9, 62), b'9');
+        assert_eq!(digit::digit_to_char_const(10, 62), b'a');
+        assert_eq!(digit::digit_to_char_const(35, 62), b'z');
+        assert_eq!(digit::digit_to_char_const(36, 62), b'A');
+        assert_eq!(digit::digit_to_char_const(61, 62), b'Z');
+    }
+}
+
+#[test]
+fn char_to_digit_with_case_test() {
+    assert_eq!(digit::char_to_digit_with_case(b'7', 8, DigitCase::Upper), Some(7));
+    assert_eq!(digit::char_to_digit_with_case(b'8', 8, DigitCase::Upper), None);
+    assert_eq!(digit::char_to_digit_with_case(b'F', 16, DigitCase::Upper), Some(15));
+    assert_eq!(digit::char_to_digit_with_case(b'f', 16, DigitCase::Upper), None);
+    assert_eq!(digit::char_to_digit_with_case(b'F', 16, DigitCase::Lower), None);
+    assert_eq!(digit::char_to_digit_with_case(b'f', 16, DigitCase::Lower), Some(15));
+    assert_eq!(digit::char_to_digit_with_case(b'F', 16, DigitCase::Insensitive), Some(15));
+    assert_eq!(digit::char_to_digit_with_case(b'f', 16, DigitCase::Insensitive), Some(15));
+    assert_eq!(digit::char_to_digit_with_case(b'g', 16, DigitCase::Insensitive), None);
+    assert_eq!(digit::char_to_digit_with_case(b'z', 36, DigitCase::Lower), Some(35));
+
+    // Radixes above 36 are always case-sensitive.
+    assert_eq!(digit::char_to_digit_with_case(b'z', 62, DigitCase::Upper), Some(35));
+    assert_eq!(digit::char_to_digit_with_case(b'Z', 62, DigitCase::Lower), Some(61));
+    assert_eq!(digit::char_to_digit_with_case(b'Z', 61, DigitCase::Insensitive), None);
+}
+
+#[test]
+fn digit_to_char_with_case_test() {
+    assert_eq!(digit::digit_to_char_with_case(7, 8, DigitCase::Lower), Some(b'7'));
+    assert_eq!(digit::digit_to_char_with_case(8, 8, DigitCase::Lower), None);
+    assert_eq!(digit::digit_to_char_with_case(15, 16, DigitCase::Upper), Some(b'F'));
+    assert_eq!(digit::digit_to_char_with_case(15, 16, DigitCase::Lower), Some(b'f'));
+    assert_eq!(digit::digit_to_char_with_case(15, 16, DigitCase::Insensitive), Some(b'F'));
+    assert_eq!(digit::digit_to_char_with_case(35, 36, DigitCase::Lower), Some(b'z'));
+    assert_eq!(digit::digit_to_char_with_case(35, 62, DigitCase::Upper), Some(b'z'));
+    assert_eq!(digit::digit_to_char_with_case(61, 62, DigitCase::Lower), Some(b'Z'));
+    assert_eq!(digit::digit_to_char_with_case(0, 1, DigitCase::Upper), None);
+    assert_eq!(digit::digit_to_char_with_case(62, 63, DigitCase::Upper), None);
+
+    for radix in 2..=62 {
+        for case in [DigitCase::Upper, DigitCase::Lower, DigitCase::Insensitive].iter() {
+            for value in 0..radix {
+                let c = digit::digit_to_char_with_case(value, radix, *case).unwrap();
+                assert_eq!(digit::char_to_digit_with_case(c, radix, *case), Some(value));
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use lexical_util::error::{Error, ErrorKind, Punctuation};
+
+#[cfg(feature = "defmt")]
+fn is_defmt_format<T: defmt::Format>(_: T) -> bool {
+    true
+}
+
+#[test]
+fn code_test() {
+    assert_eq!(Error::Success.code(), 0);
+    assert_eq!(Error::Overflow(3).code(), 100);
+    assert_eq!(Error::Overflow(5).code(), 100);
+    assert_eq!(Error::InvalidDigit(4).code(), 102);
+    assert_eq!(Error::MissingBaseSuffix(0).code(), 120);
+    assert_eq!(Error::InexactNarrowing(0).code(), 121);
+    assert_eq!(Error::RequiresSlowPath(0).code(), 122);
+    assert_eq!(Error::Internal(0).code(), 123);
+    assert_eq!(Error::InvalidMantissaRadix.code(), 200);
+    assert_eq!(Error::DigitPunctuation(Punctuation::DecimalPoint).code(), 218);
+    assert_eq!(
+        Error::ConflictingPunctuation(Punctuation::DigitSeparator, Punctuation::BasePrefix).code(),
+        219
+    );
+    assert_eq!(Error::InvalidNanString.code(), 300);
+    assert_eq!(Error::InvalidFillCharacter.code(), 312);
+    assert_eq!(
+        Error::BufferTooSmall {
+            needed: 24,
+        }
+        .code(),
+        400
+    );
+}
+
+#[test]
+#[cfg(feature = "defmt")]
+fn defmt_test() {
+    assert!(is_defmt_format(Error::InvalidDigit(4)));
+    assert!(is_defmt_format(Error::BufferTooSmall {
+        needed: 24,
+    }));
+    assert!(is_defmt_format(Error::DigitPunctuation(Punctuation::DecimalPoint)));
+}
+
+#[test]
+fn kind_test() {
+    assert_eq!(Error::Success.kind(), ErrorKind::Success);
+    assert_eq!(Error::InvalidDigit(4).kind(), ErrorKind::Parse);
+    assert_eq!(Error::InvalidPunctuation.kind(), ErrorKind::Format);
+    assert_eq!(Error::InvalidRadix.kind(), ErrorKind::Options);
+    assert_eq!(
+        Error::BufferTooSmall {
+            needed: 24,
+        }
+        .kind(),
+        ErrorKind::Write
+    );
+}
+
+#[test]
+fn expected_test() {
+    assert_eq!(Error::InvalidDigit(4).expected(), None);
+    assert_eq!(Error::MissingSign(0).expected(), Some("a `+` or `-` sign"));
+    assert_eq!(Error::EmptyExponent(2).expected(), Some("an exponent digit"));
+    assert_eq!(Error::MissingBaseSuffix(3).expected(), Some("a base suffix"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn context_test() {
+    let context = Error::InvalidDigit(4).context(b"1234x");
+    assert_eq!(context.error(), Error::InvalidDigit(4));
+    assert_eq!(context.kind(), ErrorKind::Parse);
+    assert_eq!(context.index(), Some(4));
+    assert_eq!(context.found(), Some(b'x'));
+    assert_eq!(context.to_string(), "invalid digit 'x' at index 4");
+
+    let context = Error::InvalidDigit(1).context(b"1\x00");
+    assert_eq!(context.to_string(), "invalid digit '\\x00' at index 1");
+
+    let context = Error::MissingSign(0).context(b"12");
+    assert_eq!(context.found(), Some(b'1'));
+    assert_eq!(context.to_string(), "missing sign at index 0, expected a `+` or `-` sign");
+
+    let context = Error::EmptyExponent(2).context(b"1e");
+    assert_eq!(context.found(), None);
+    assert_eq!(context.to_string(), "empty exponent at index 2, expected an exponent digit");
+
+    let context = Error::Overflow(2).context(b"256");
+    assert_eq!(context.to_string(), "numeric overflow at index 2");
+
+    let context = Error::InvalidRadix.context(b"");
+    assert_eq!(context.index(), None);
+    assert_eq!(context.to_string(), Error::InvalidRadix.to_string());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn std_error_test() {
+    use std::error::Error as StdError;
+
+    let context = Error::InvalidDigit(4).context(b"1234x");
+    let source = context.source().map(|x| x.to_string());
+    assert_eq!(source, Some(Error::InvalidDigit(4).to_string()));
+}
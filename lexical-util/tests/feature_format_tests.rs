@@ -29,6 +29,10 @@ fn ignore_test() {
     assert_eq!(fmt.case_sensitive_base_prefix(), false);
     #[cfg(feature = "power-of-two")]
     assert_eq!(fmt.case_sensitive_base_suffix(), false);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(fmt.required_base_prefix(), false);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(fmt.required_base_suffix(), false);
     assert_eq!(fmt.integer_internal_digit_separator(), true);
     assert_eq!(fmt.fraction_internal_digit_separator(), true);
     assert_eq!(fmt.exponent_internal_digit_separator(), true);
@@ -118,6 +122,33 @@ fn flags_test() {
     test_flag!(special_digit_separator, SPECIAL_DIGIT_SEPARATOR);
 }
 
+#[test]
+#[cfg(feature = "power-of-two")]
+fn required_base_affix_test() {
+    const FORMAT: u128 = format::NumberFormatBuilder::new()
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .base_suffix(num::NonZeroU8::new(b'h'))
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .build();
+    let fmt = format::NumberFormat::<FORMAT> {};
+    assert_eq!(fmt.is_valid(), true);
+    assert_eq!(fmt.required_base_prefix(), true);
+    assert_eq!(fmt.required_base_suffix(), true);
+    assert_eq!(format::NumberFormatBuilder::from_packed(FORMAT).build(), FORMAT);
+
+    // The base prefix and suffix characters must be set if required.
+    const NO_PREFIX: u128 = format::NumberFormatBuilder::new().required_base_prefix(true).build();
+    let fmt = format::NumberFormat::<NO_PREFIX> {};
+    assert_eq!(fmt.is_valid(), false);
+    assert_eq!(fmt.error(), lexical_util::error::Error::InvalidBasePrefix);
+
+    const NO_SUFFIX: u128 = format::NumberFormatBuilder::new().required_base_suffix(true).build();
+    let fmt = format::NumberFormat::<NO_SUFFIX> {};
+    assert_eq!(fmt.is_valid(), false);
+    assert_eq!(fmt.error(), lexical_util::error::Error::InvalidBaseSuffix);
+}
+
 #[test]
 fn constants_test() {
     // Don't check the actual values: just check they're defined.
@@ -127,6 +158,7 @@ fn constants_test() {
     let _: u128 = format::PYTHON_STRING;
     let _: u128 = format::PYTHON3_LITERAL;
     let _: u128 = format::PYTHON3_STRING;
+    let _: u128 = format::PYTHON_REPR;
     let _: u128 = format::PYTHON36_LITERAL;
     let _: u128 = format::PYTHON35_LITERAL;
     let _: u128 = format::PYTHON2_LITERAL;
@@ -143,6 +175,7 @@ fn constants_test() {
     let _: u128 = format::CXX98_STRING;
     let _: u128 = format::C18_LITERAL;
     let _: u128 = format::C18_STRING;
+    let _: u128 = format::C_PRINTF_G;
     let _: u128 = format::C11_LITERAL;
     let _: u128 = format::C11_STRING;
     let _: u128 = format::C99_LITERAL;
@@ -157,10 +190,12 @@ fn constants_test() {
     let _: u128 = format::SWIFT_STRING;
     let _: u128 = format::GO_LITERAL;
     let _: u128 = format::GO_STRING;
+    let _: u128 = format::GO_FORMAT_FLOAT;
     let _: u128 = format::HASKELL_LITERAL;
     let _: u128 = format::HASKELL_STRING;
     let _: u128 = format::JAVASCRIPT_LITERAL;
     let _: u128 = format::JAVASCRIPT_STRING;
+    let _: u128 = format::JAVASCRIPT_TO_STRING;
     let _: u128 = format::PERL_LITERAL;
     let _: u128 = format::PERL_STRING;
     let _: u128 = format::PHP_LITERAL;
@@ -235,6 +270,9 @@ fn constants_test() {
     let _: u128 = format::XML;
     let _: u128 = format::SQLITE;
     let _: u128 = format::POSTGRESQL;
+    let _: u128 = format::POSTGRESQL16_LITERAL;
+    let _: u128 = format::POSTGRESQL_STRING;
     let _: u128 = format::MYSQL;
     let _: u128 = format::MONGODB;
+    let _: u128 = format::SCIENTIFIC_FIXED;
 }
@@ -58,3 +58,194 @@ fn from_radix_test() {
     assert_eq!(format.exponent_base(), 32);
     assert_eq!(format.exponent_radix(), 32);
 }
+
+#[test]
+fn from_packed_test() {
+    use lexical_util::format::STANDARD;
+
+    assert_eq!(NumberFormatBuilder::from_packed(STANDARD).build(), STANDARD);
+    const DECIMAL: u128 = NumberFormatBuilder::decimal();
+    assert_eq!(NumberFormatBuilder::from_packed(DECIMAL).build(), DECIMAL);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn from_packed_radix_test() {
+    use lexical_util::format::STANDARD;
+
+    // The default exponent base and radix follow the mantissa radix.
+    const HEX: u128 = NumberFormatBuilder::from_packed(STANDARD).radix(16).build();
+    let format = NumberFormat::<HEX> {};
+    assert!(format.is_valid());
+    assert_eq!(format.mantissa_radix(), 16);
+    assert_eq!(format.exponent_base(), 16);
+    assert_eq!(format.exponent_radix(), 16);
+    assert_eq!(HEX, NumberFormatBuilder::new().radix(16).build());
+
+    // Explicit exponent bases and radixes are kept.
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    const EDITED: u128 = NumberFormatBuilder::from_packed(BINARY).radix(8).build();
+    let format = NumberFormat::<EDITED> {};
+    assert!(format.is_valid());
+    assert_eq!(format.mantissa_radix(), 8);
+    assert_eq!(format.exponent_base(), 2);
+    assert_eq!(format.exponent_radix(), 2);
+    assert_eq!(NumberFormatBuilder::from_packed(BINARY).build(), BINARY);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn from_packed_format_test() {
+    use core::num::NonZeroU8;
+    use lexical_util::format::{JSON, RUST_LITERAL, STANDARD};
+
+    assert_eq!(NumberFormatBuilder::from_packed(JSON).build(), JSON);
+    assert_eq!(NumberFormatBuilder::from_packed(RUST_LITERAL).build(), RUST_LITERAL);
+
+    const SEPARATOR: u128 = NumberFormatBuilder::from_packed(STANDARD)
+        .digit_separator(NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let format = NumberFormat::<SEPARATOR> {};
+    assert!(format.is_valid());
+    assert_eq!(format.digit_separator(), b'_');
+    assert!(format.integer_internal_digit_separator());
+    assert!(!format.no_special());
+
+    const NO_SPECIAL: u128 = NumberFormatBuilder::from_packed(SEPARATOR).no_special(true).build();
+    let format = NumberFormat::<NO_SPECIAL> {};
+    assert!(format.is_valid());
+    assert_eq!(format.digit_separator(), b'_');
+    assert!(format.no_special());
+    assert_eq!(NO_SPECIAL & !lexical_util::format::NO_SPECIAL, SEPARATOR);
+}
+
+#[test]
+fn build_checked_test() {
+    use lexical_util::error::Error;
+    use lexical_util::format::STANDARD;
+
+    assert_eq!(NumberFormatBuilder::new().build_checked(), Ok(STANDARD));
+    assert_eq!(
+        NumberFormatBuilder::from_packed(0).build_checked(),
+        Err(Error::InvalidMantissaRadix)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn build_checked_format_test() {
+    use core::num::NonZeroU8;
+    use lexical_util::error::{Error, Punctuation};
+
+    let builder = |digit_separator: u8| {
+        NumberFormatBuilder::new()
+            .digit_separator(NonZeroU8::new(digit_separator))
+            .internal_digit_separator(true)
+    };
+    assert!(builder(b'_').build_checked().is_ok());
+    assert_eq!(
+        builder(b'1').build_checked(),
+        Err(Error::DigitPunctuation(Punctuation::DigitSeparator))
+    );
+    assert_eq!(builder(b'-').build_checked(), Err(Error::InvalidDigitSeparator));
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(
+        builder(b'_').base_prefix(NonZeroU8::new(b'_')).build_checked(),
+        Err(Error::ConflictingPunctuation(Punctuation::DigitSeparator, Punctuation::BasePrefix))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "radix"))]
+fn build_checked_mixed_case_radix_test() {
+    use core::num::NonZeroU8;
+    use lexical_util::error::{Error, Punctuation};
+    use lexical_util::format::Format;
+
+    // `T` is a digit in base 32, but not in the case-sensitive base 55.
+    let builder = NumberFormatBuilder::new()
+        .mantissa_radix(32)
+        .exponent_base(NonZeroU8::new(8))
+        .exponent_radix(NonZeroU8::new(55))
+        .base_prefix(NonZeroU8::new(b'T'));
+    assert_eq!(builder.build_checked(), Err(Error::DigitPunctuation(Punctuation::BasePrefix)));
+    assert_eq!(Format::new(builder.build()).err(), Some(Error::InvalidBasePrefix));
+    assert!(builder.base_prefix(NonZeroU8::new(b'#')).build_checked().is_ok());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn separator_policy_test() {
+    use core::num::NonZeroU8;
+    use lexical_util::format::{Format, SeparatorPolicy};
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_separator_policy(SeparatorPolicy::INTERNAL)
+        .exponent_separator_policy(SeparatorPolicy::ALL)
+        .build();
+    let format = NumberFormat::<FORMAT> {};
+    assert!(format.is_valid());
+    assert!(format.integer_internal_digit_separator());
+    assert!(!format.integer_leading_digit_separator());
+    assert!(!format.fraction_internal_digit_separator());
+    assert!(format.exponent_consecutive_digit_separator());
+
+    let format = Format::new(FORMAT).unwrap();
+    assert_eq!(format.integer_separator_policy(), SeparatorPolicy::INTERNAL);
+    assert_eq!(format.fraction_separator_policy(), SeparatorPolicy::NONE);
+    assert_eq!(format.exponent_separator_policy(), SeparatorPolicy::ALL);
+    let format = format.with_exponent_separator_policy(SeparatorPolicy::NONE).unwrap();
+    assert_eq!(format.exponent_separator_policy(), SeparatorPolicy::NONE);
+    assert_eq!(format.integer_separator_policy(), SeparatorPolicy::INTERNAL);
+}
+
+#[test]
+fn required_digits_test() {
+    use lexical_util::format::{Format, STANDARD};
+
+    let format = Format::new(STANDARD).unwrap();
+    assert!(!format.required_integer_digits());
+    assert!(!format.required_fraction_digits());
+    assert_eq!(format.with_required_integer_digits(false), Ok(format));
+    assert_eq!(format.with_required_fraction_digits(false), Ok(format));
+    #[cfg(not(feature = "format"))]
+    assert!(format.with_required_integer_digits(true).is_err());
+
+    #[cfg(feature = "format")]
+    {
+        let format = format.with_required_integer_digits(true).unwrap();
+        assert!(format.required_integer_digits());
+        assert!(!format.required_fraction_digits());
+        let format = format.with_required_fraction_digits(true).unwrap();
+        assert!(format.required_fraction_digits());
+        let format = format.with_required_integer_digits(false).unwrap();
+        assert!(!format.required_integer_digits());
+        assert!(format.required_fraction_digits());
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_test() {
+    use lexical_util::arbitrary::{Arbitrary, Unstructured};
+    use lexical_util::format::Format;
+
+    // Pseudo-random input, so the generated values vary between iterations.
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for _ in 0..100 {
+        let bytes: Vec<u8> = (0..256)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        let builder = NumberFormatBuilder::arbitrary(&mut u).unwrap();
+        let _ = builder.build_checked();
+        if let Ok(format) = Format::arbitrary(&mut u) {
+            assert_eq!(Format::new(format.packed()), Ok(format));
+        }
+    }
+}
@@ -1,6 +1,5 @@
 #[cfg(feature = "format")]
 use core::num;
-#[cfg(feature = "format")]
 use lexical_util::format;
 
 #[cfg(feature = "format")]
@@ -56,3 +55,86 @@ fn test_is_valid_punctuation() {
     assert_eq!(is_valid_punctuation(b'\'', b'h', 0), true);
     assert_eq!(is_valid_punctuation(b'\'', b'h', b'h'), false);
 }
+
+#[test]
+fn test_is_valid_radix() {
+    assert!(format::is_valid_radix(10));
+    assert_eq!(format::is_valid_radix(16), cfg!(feature = "power-of-two"));
+    assert_eq!(format::is_valid_radix(36), cfg!(feature = "radix"));
+    assert_eq!(format::is_valid_radix(62), cfg!(feature = "radix"));
+    assert!(!format::is_valid_radix(63));
+    assert_eq!(format::is_valid_float_radix(36), cfg!(feature = "radix"));
+    assert!(!format::is_valid_float_radix(62));
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn test_punctuation_error() {
+    use lexical_util::error::{Error, Punctuation};
+
+    let error = |digit_separator: u8, base_prefix: u8, base_suffix: u8| {
+        let fmt = format::NumberFormatBuilder::new()
+            .digit_separator(num::NonZeroU8::new(digit_separator))
+            .digit_separator_flags(true)
+            .base_prefix(num::NonZeroU8::new(base_prefix))
+            .base_suffix(num::NonZeroU8::new(base_suffix))
+            .build();
+        format::punctuation_error(fmt)
+    };
+    assert_eq!(error(b'_', b'h', 0), Error::Success);
+    assert_eq!(error(b'0', b'h', 0), Error::DigitPunctuation(Punctuation::DigitSeparator));
+    assert_eq!(error(b'_', b'1', 0), Error::DigitPunctuation(Punctuation::BasePrefix));
+    assert_eq!(error(b'+', b'h', 0), Error::InvalidDigitSeparator);
+    assert_eq!(error(b'_', b'h', 128), Error::InvalidBaseSuffix);
+    assert_eq!(
+        error(b'_', b'_', 0),
+        Error::ConflictingPunctuation(Punctuation::DigitSeparator, Punctuation::BasePrefix)
+    );
+    assert_eq!(
+        error(b'\'', b'h', b'h'),
+        Error::ConflictingPunctuation(Punctuation::BasePrefix, Punctuation::BaseSuffix)
+    );
+}
+
+#[test]
+fn test_options_punctuation_error() {
+    use lexical_util::error::{Error, Punctuation};
+
+    let standard = format::STANDARD;
+    assert_eq!(format::options_punctuation_error(standard, b'e', b'.'), Error::Success);
+    assert_eq!(format::options_punctuation_error(standard, b'^', b','), Error::Success);
+    assert_eq!(format::options_punctuation_error(standard, 0, b'.'), Error::InvalidExponentSymbol);
+    assert_eq!(format::options_punctuation_error(standard, b'e', 128), Error::InvalidDecimalPoint);
+    assert_eq!(
+        format::options_punctuation_error(standard, b'e', b'5'),
+        Error::DigitPunctuation(Punctuation::DecimalPoint)
+    );
+    assert_eq!(
+        format::options_punctuation_error(standard, b'.', b'.'),
+        Error::ConflictingPunctuation(Punctuation::DecimalPoint, Punctuation::Exponent)
+    );
+    assert!(!format::is_valid_options_punctuation(standard, b'.', b'.'));
+
+    #[cfg(feature = "power-of-two")]
+    {
+        let hex = format::NumberFormatBuilder::hexadecimal();
+        assert_eq!(
+            format::options_punctuation_error(hex, b'e', b'.'),
+            Error::DigitPunctuation(Punctuation::Exponent)
+        );
+        assert_eq!(format::options_punctuation_error(hex, b'^', b'.'), Error::Success);
+    }
+
+    #[cfg(feature = "format")]
+    {
+        let fmt = from_digit_separator(b',');
+        assert_eq!(
+            format::options_punctuation_error(fmt, b'e', b','),
+            Error::ConflictingPunctuation(Punctuation::DigitSeparator, Punctuation::DecimalPoint)
+        );
+        assert_eq!(
+            format::options_punctuation_error(fmt, b',', b'.'),
+            Error::ConflictingPunctuation(Punctuation::DigitSeparator, Punctuation::Exponent)
+        );
+    }
+}
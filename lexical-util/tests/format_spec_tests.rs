@@ -0,0 +1,112 @@
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{FormatSpec, STANDARD};
+
+#[test]
+fn empty_test() {
+    let spec = FormatSpec::parse("").unwrap();
+    assert_eq!(spec.format().packed(), STANDARD);
+    assert_eq!(spec.decimal_point(), None);
+    assert_eq!(spec.exponent(), None);
+    assert_eq!(spec.nan_string(), None);
+    assert_eq!(FormatSpec::parse("  ").unwrap(), spec);
+    assert_eq!(FormatSpec::parse("Standard").unwrap(), spec);
+}
+
+#[test]
+fn options_test() {
+    let spec = FormatSpec::parse(
+        " decimal_point = ',' , EXPONENT=^, nan_string=\"not a number\", inf_string=inf, infinity_string='infinity'",
+    )
+    .unwrap();
+    assert_eq!(spec.format().packed(), STANDARD);
+    assert_eq!(spec.decimal_point(), Some(b','));
+    assert_eq!(spec.exponent(), Some(b'^'));
+    assert_eq!(spec.nan_string(), Some(&b"not a number"[..]));
+    assert_eq!(spec.inf_string(), Some(&b"inf"[..]));
+    assert_eq!(spec.infinity_string(), Some(&b"infinity"[..]));
+}
+
+#[test]
+fn invalid_test() {
+    assert_eq!(FormatSpec::parse("unknown").unwrap_err().index(), Some(&0));
+    assert_eq!(FormatSpec::parse("standard, ,").unwrap_err().index(), Some(&10));
+    assert_eq!(FormatSpec::parse("standard,").unwrap_err().index(), Some(&9));
+    assert_eq!(FormatSpec::parse("standard standard").unwrap_err().index(), Some(&9));
+    assert_eq!(FormatSpec::parse("standard, standard").unwrap_err().index(), Some(&10));
+    assert_eq!(FormatSpec::parse("exponent=").unwrap_err().index(), Some(&9));
+    assert_eq!(FormatSpec::parse("exponent=ee").unwrap_err().index(), Some(&9));
+    assert_eq!(FormatSpec::parse("exponent='e").unwrap_err().index(), Some(&9));
+    assert_eq!(FormatSpec::parse("radix=256").unwrap_err().index(), Some(&6));
+    assert_eq!(FormatSpec::parse("radix=''").unwrap_err().index(), Some(&6));
+    assert_eq!(FormatSpec::parse("no_special=yes").unwrap_err().index(), Some(&11));
+    assert_eq!(FormatSpec::parse("unknown=1").unwrap_err().index(), Some(&0));
+    assert!(FormatSpec::parse("radix=1").unwrap_err().is_invalid_mantissa_radix());
+}
+
+#[test]
+#[cfg(not(feature = "format"))]
+fn not_format_test() {
+    assert!(FormatSpec::parse("json").unwrap_err().is_invalid_format_spec());
+    assert!(FormatSpec::parse("no_special").unwrap_err().is_invalid_flags());
+    assert!(FormatSpec::parse("no_special=false").is_ok());
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_test() {
+    let spec = FormatSpec::parse("radix=16, exponent_base=2, exponent=p").unwrap();
+    let format = spec.format();
+    assert_eq!(format.mantissa_radix(), 16);
+    assert_eq!(format.exponent_base(), 2);
+    assert_eq!(format.exponent_radix(), 16);
+    assert_eq!(spec.exponent(), Some(b'p'));
+
+    let format = FormatSpec::parse("mantissa_radix=2").unwrap().format();
+    assert_eq!(format.mantissa_radix(), 2);
+    assert_eq!(format.exponent_base(), 2);
+    assert_eq!(format.exponent_radix(), 2);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_test() {
+    use lexical_util::format::{JSON, RUST_LITERAL};
+
+    assert_eq!(FormatSpec::parse("json").unwrap().format().packed(), JSON);
+    assert_eq!(FormatSpec::parse("Rust_Literal").unwrap().format().packed(), RUST_LITERAL);
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .internal_digit_separator(true)
+        .required_exponent_sign(true)
+        .no_special(true)
+        .build();
+    let spec = FormatSpec::parse(
+        "digit_separator=',', internal_digit_separator, required_exponent_sign=true, no_special",
+    )
+    .unwrap();
+    assert_eq!(spec.format().packed(), FORMAT);
+
+    // Flags are applied to the preset, and the separator requires a location.
+    let spec = FormatSpec::parse("json, no_special=false, digit_separator='_'").unwrap();
+    assert_eq!(
+        spec.format().packed(),
+        NumberFormatBuilder::from_packed(JSON).no_special(false).build()
+    );
+    let spec = FormatSpec::parse("digit_separator='_', digit_separator_flags").unwrap();
+    assert_eq!(spec.format().digit_separator(), b'_');
+    assert!(spec.format().special_digit_separator());
+
+    assert!(FormatSpec::parse("digit_separator='1', internal_digit_separator")
+        .unwrap_err()
+        .is_invalid_digit_separator());
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn hex_format_test() {
+    use lexical_util::format::C_HEX_LITERAL;
+
+    assert_eq!(FormatSpec::parse("c_hex_literal").unwrap().format().packed(), C_HEX_LITERAL);
+}
@@ -114,3 +114,73 @@ fn skip_iterator_test() {
     assert_eq!(iter.as_slice(), &digits[digits.len()..]);
     assert_eq!(iter.as_ptr(), digits[digits.len()..].as_ptr());
 }
+
+#[test]
+fn mark_reset_test() {
+    use lexical_util::format::{Format, STANDARD};
+    use lexical_util::iterator::{FormatBytes, NumberComponent};
+
+    let digits = b"12345";
+    let mut byte = digits.bytes::<{ STANDARD }>();
+    let mark = byte.mark();
+    assert_eq!(mark.cursor(), 0);
+    assert!(byte.integer_iter().take(3).eq(b"123".iter()));
+    assert_eq!(byte.cursor(), 3);
+    byte.reset(mark);
+    assert_eq!(byte.cursor(), 0);
+    assert_eq!(byte.current_count(), 0);
+
+    let mut iter = byte.integer_iter();
+    iter.next();
+    let mark = iter.mark();
+    assert!(iter.by_ref().eq(b"2345".iter()));
+    iter.reset(mark);
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.current_count(), 1);
+    assert_eq!(iter.next(), Some(&b'2'));
+
+    let format = Format::new(STANDARD).unwrap();
+    let mut byte = FormatBytes::new(digits, format);
+    let mark = byte.mark();
+    assert_eq!(byte.next(NumberComponent::Integer), Some(b'1'));
+    byte.reset(mark);
+    assert_eq!(byte.next(NumberComponent::Integer), Some(b'1'));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn skip_mark_reset_test() {
+    use core::num;
+    use lexical_util::format::NumberFormatBuilder;
+
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .digit_separator_flags(true)
+        .build();
+
+    // The count of returned values is restored with the cursor.
+    let digits = b"1_2_3_4";
+    let mut byte = digits.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    iter.next();
+    iter.next();
+    let mark = iter.mark();
+    assert_eq!((iter.cursor(), iter.current_count()), (3, 2));
+    assert!(iter.by_ref().eq(b"34".iter()));
+    assert_eq!((iter.cursor(), iter.current_count()), (7, 4));
+    iter.reset(mark);
+    assert_eq!((iter.cursor(), iter.current_count()), (3, 2));
+    assert!(iter.eq(b"34".iter()));
+}
+
+#[test]
+#[should_panic]
+fn reset_out_of_range_test() {
+    use lexical_util::format::STANDARD;
+
+    let mut long = b"12345".bytes::<{ STANDARD }>();
+    long.integer_iter().count();
+    let mark = long.mark();
+    let mut short = b"1".bytes::<{ STANDARD }>();
+    short.reset(mark);
+}
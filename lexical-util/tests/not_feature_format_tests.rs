@@ -33,6 +33,8 @@ fn format_properties_test() {
     assert_eq!(format.case_sensitive_exponent(), false);
     assert_eq!(format.case_sensitive_base_prefix(), false);
     assert_eq!(format.case_sensitive_base_suffix(), false);
+    assert!(!format.required_base_prefix());
+    assert!(!format.required_base_suffix());
     assert_eq!(format.integer_internal_digit_separator(), false);
     assert_eq!(format.fraction_internal_digit_separator(), false);
     assert_eq!(format.exponent_internal_digit_separator(), false);
@@ -252,3 +252,34 @@ fn float_test() {
     assert_eq!(f.prev_positive().next_positive(), f);
     assert_ne!(f.round_positive_even(), f.next());
 }
+
+#[test]
+#[cfg(feature = "floats")]
+fn float_ulp_test() {
+    use lexical_util::num::Float;
+
+    // Use the trait methods, rather than any inherent methods on the floats.
+    assert_eq!(Float::next_up(1.0f64), 1.0000000000000002);
+    assert_eq!(Float::next_up(-1.0f64), -0.9999999999999999);
+    assert_eq!(Float::next_up(0.0f64), 5e-324);
+    assert_eq!(Float::next_up(-0.0f64), 5e-324);
+    assert_eq!(Float::next_up(-5e-324f64).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(Float::next_up(f64::MAX), f64::INFINITY);
+    assert_eq!(Float::next_up(f64::INFINITY), f64::INFINITY);
+    assert_eq!(Float::next_up(f64::NEG_INFINITY), f64::MIN);
+    assert!(Float::next_up(f64::NAN).is_nan());
+
+    assert_eq!(Float::next_down(1.0f32), 0.99999994);
+    assert_eq!(Float::next_down(0.0f32), -1e-45);
+    assert_eq!(Float::next_down(f32::MIN), f32::NEG_INFINITY);
+    assert_eq!(Float::next_down(f32::INFINITY), f32::MAX);
+    assert!(Float::next_down(f32::NAN).is_nan());
+
+    assert_eq!(Float::ulp(1.0f64), f64::EPSILON);
+    assert_eq!(Float::ulp(-1.0f64), f64::EPSILON);
+    assert_eq!(Float::ulp(0.0f64), 5e-324);
+    assert_eq!(Float::ulp(f64::MAX), 2f64.powi(971));
+    assert_eq!(Float::ulp(f64::NEG_INFINITY), f64::INFINITY);
+    assert!(Float::ulp(f64::NAN).is_nan());
+    assert_eq!(Float::ulp(1.0f32), f32::EPSILON);
+}
@@ -1,8 +1,8 @@
 #![cfg(all(feature = "format", feature = "parse"))]
 
 use core::num;
-use lexical_util::format::{NumberFormat, NumberFormatBuilder};
-use lexical_util::iterator::AsBytes;
+use lexical_util::format::{NumberFormat, NumberFormatBuilder, STANDARD};
+use lexical_util::iterator::{AsBytes, Bytes, BytesIter, SkipSet};
 use static_assertions::const_assert;
 
 fn skip_iter_eq<const FORMAT: u128>(input: &[u8], output: &[u8]) {
@@ -602,3 +602,56 @@ fn test_skip_iter_iltc() {
     skip_iter_eq::<{ FORMAT }>(b"_4_5_.56", b"45.56");
     skip_iter_eq::<{ FORMAT }>(b"__4__5__.56", b"45.56");
 }
+
+fn skip_set_eq<const FORMAT: u128>(skip: SkipSet, input: &[u8], output: &[u8]) {
+    let mut input = Bytes::<{ FORMAT }>::with_skip_set(input, skip);
+    assert!(input.integer_iter().eq(output.iter()));
+}
+
+#[test]
+fn skip_set_test() {
+    const SKIP: SkipSet = SkipSet::from_bytes(b"$, ");
+    assert!(SKIP.contains(b'$') && SKIP.contains(b' ') && !SKIP.contains(b'_'));
+    assert!(SkipSet::new().is_empty() && !SKIP.is_empty());
+    assert!(SkipSet::new().with(0xFF).contains(0xFF));
+    assert!(!SkipSet::new().with(0xFF).contains(0x7F));
+
+    skip_set_eq::<{ STANDARD }>(SKIP, b"$1,234", b"1234");
+    skip_set_eq::<{ STANDARD }>(SKIP, b"1 2 3 $", b"123");
+    skip_set_eq::<{ STANDARD }>(SKIP, b"$$,,12.5", b"12.5");
+    skip_set_eq::<{ STANDARD }>(SKIP, b"1_2", b"1_2");
+    skip_set_eq::<{ STANDARD }>(SkipSet::new(), b"$1,234", b"$1,234");
+}
+
+#[test]
+fn skip_set_digit_separator_test() {
+    // Skipped bytes are ignored when determining if a digit separator is internal.
+    pub const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    const SKIP: SkipSet = SkipSet::from_bytes(b"$");
+
+    skip_set_eq::<{ FORMAT }>(SKIP, b"1_2", b"12");
+    skip_set_eq::<{ FORMAT }>(SKIP, b"1$_2", b"12");
+    skip_set_eq::<{ FORMAT }>(SKIP, b"1_$2", b"12");
+    skip_set_eq::<{ FORMAT }>(SKIP, b"$_12", b"_12");
+    skip_set_eq::<{ FORMAT }>(SKIP, b"12_$", b"12_");
+}
+
+#[test]
+fn skip_set_count_test() {
+    const SKIP: SkipSet = SkipSet::from_bytes(b",");
+    let mut bytes = Bytes::<{ STANDARD }>::with_skip_set(b"1,234.5", SKIP);
+    assert!(!bytes.is_contiguous());
+    assert_eq!(bytes.skip_set(), SKIP);
+    let mut iter = bytes.integer_iter();
+    assert!(!iter.is_contiguous());
+    assert_eq!(iter.read::<u32>(), None);
+    assert_eq!(iter.by_ref().take(4).count(), 4);
+    assert_eq!(bytes.cursor(), 5);
+    assert_eq!(bytes.current_count(), 4);
+
+    let bytes = Bytes::<{ STANDARD }>::new(b"1,234.5");
+    assert!(bytes.is_contiguous());
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "proptest")]
+
+use lexical_util::format::{Format, STANDARD};
+use lexical_util::strategy;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn format_proptest(format in strategy::format()) {
+        prop_assert_eq!(Format::new(format.packed()), Ok(format));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn integer_proptest(s in strategy::integer(Format::new(STANDARD).unwrap(), 18)) {
+        prop_assert!(s.parse::<i64>().is_ok(), "{:?}", s);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn float_proptest(s in strategy::float(Format::new(STANDARD).unwrap(), b'.', b'e')) {
+        prop_assert!(s.parse::<f64>().is_ok(), "{:?}", s);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn near_valid_proptest(
+        (s, t) in strategy::integer(Format::new(STANDARD).unwrap(), 18)
+            .prop_flat_map(|s| (Just(s.clone()), strategy::near_valid(Just(s))))
+    ) {
+        prop_assert!(t.is_ascii());
+        prop_assert!(s.len() - 1 <= t.len() && t.len() <= s.len() + 1);
+    }
+}
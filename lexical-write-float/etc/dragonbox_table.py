@@ -0,0 +1,169 @@
+#!/usr/bin/env python3
+
+"""
+Generate the powers of five for the Dragonbox algorithm, for use in
+floating point to decimal conversions.
+
+Specifically, computes and outputs (as Rust code) a table of 5^k for
+the range of exponents k required for each float type. The output is one
+array of significands per float type, normalized and rounded up.
+
+For the `wasm` feature, every 27th power of f64 is also output, along with
+small powers of five and the corrections required to recover the other
+powers exactly. This reduces the size of the f64 table from 9.9KB to
+under 1KB.
+"""
+
+HEADER = """
+//! Pre-computed powers for the Dragonbox algorithm.
+//!
+//! These tables enable fast scaling of the significant digits
+//! of a float to the decimal exponent, with minimal rounding
+//! errors, in a 128 or 192-bit representation.
+//!
+//! DO NOT MODIFY: Generated by `etc/dragonbox_table.py`
+//!
+//! This adapted from the Rust implementation, based on the fast-float-rust
+//! implementation, and is similarly subject to an Apache2.0/MIT license.
+
+#![cfg(not(feature = "compact"))]
+#![doc(hidden)]
+"""
+
+F32_DOC = """
+/// Pre-computed powers-of-5 for the dragonbox algorithm for f32.
+/// This is very similar to the high bits for `DRAGONBOX64_POWERS_OF_FIVE`,
+/// with rounding obviously for the bits.
+"""
+
+F64_DOC = """
+/// Pre-computed powers-of-5 for the dragonbox algorithm for f64.
+/// This is very similar to the Lemire table, however, a few slight
+/// differences exist: this goes from `5^-292` to `5^326`, a different
+/// range than Lemire which goes from `5^-342 to 5^308`.
+"""
+
+# The number of powers between each compressed power. This is the largest
+# power of 5 that fits in a 64-bit integer, so every power can be recovered
+# with a single 64-bit multiplication of each half of a compressed power.
+COMPRESSION_RATIO = 27
+
+
+def main():
+    print(HEADER.strip())
+    print()
+    print('pub const SMALLEST_F32_POW5: i32 = -31;')
+    print('pub const LARGEST_F32_POW5: i32 = 46;')
+    print('pub const N32_POWERS_OF_FIVE: usize = ', end='')
+    print('(LARGEST_F32_POW5 - SMALLEST_F32_POW5 + 1) as usize;')
+    print('pub const SMALLEST_F64_POW5: i32 = -292;')
+    print('pub const LARGEST_F64_POW5: i32 = 326;')
+    print('pub const N64_POWERS_OF_FIVE: usize = ', end='')
+    print('(LARGEST_F64_POW5 - SMALLEST_F64_POW5 + 1) as usize;')
+    print()
+    print_f32_powers(-31, 46)
+    print()
+    powers = [(normalized_power(k, 128), k) for k in range(-292, 327)]
+    print_f64_powers(powers)
+    print()
+    print_compressed_powers(powers)
+
+
+def normalized_power(k, bits):
+    # Get 5^k, normalized to `bits` and rounded up.
+    if k >= 0:
+        num = 5**k
+        den = 1
+    else:
+        num = 1
+        den = 5**-k
+    # Find the exponent, so `2^exp <= 5^k < 2^(exp + 1)`.
+    exp = num.bit_length() - den.bit_length()
+    if num << max(-exp, 0) < den << max(exp, 0):
+        exp -= 1
+    shift = bits - 1 - exp
+    num <<= max(shift, 0)
+    den <<= max(-shift, 0)
+    return -(-num // den)
+
+
+def print_f32_powers(smallest, largest):
+    print(F32_DOC.strip())
+    typ = '[u64; N32_POWERS_OF_FIVE]'
+    print('pub const DRAGONBOX32_POWERS_OF_FIVE: {} = ['.format(typ))
+    for k in range(smallest, largest + 1):
+        value = '    0x{:016x}, '.format(normalized_power(k, 64))
+        print(value + '// 5^{}'.format(k))
+    print('];')
+
+
+def print_power(c, exp):
+    hi = '0x{:016x}'.format(c >> 64)
+    lo = '0x{:016x}'.format(c & ((1 << 64) - 1))
+    print('    ({}, {}), // 5^{}'.format(hi, lo, exp))
+
+
+def print_f64_powers(powers):
+    print(F64_DOC.strip())
+    print('#[cfg(not(feature = "wasm"))]')
+    typ = '[(u64, u64); N64_POWERS_OF_FIVE]'
+    print('pub const DRAGONBOX64_POWERS_OF_FIVE: {} = ['.format(typ))
+    for c, exp in powers:
+        print_power(c, exp)
+    print('];')
+
+
+def recover_power(compressed, offset):
+    # Multiply by the small power and keep the high 128 bits.
+    product = compressed * 5**offset
+    return product >> (product.bit_length() - 128)
+
+
+def print_compressed_powers(powers):
+    # Store every 27th power, and the difference between the recovered
+    # and the exact powers, plus 1, in 2 bits.
+    compressed = powers[::COMPRESSION_RATIO]
+    corrections = []
+    for index, (c, _) in enumerate(powers):
+        base = compressed[index // COMPRESSION_RATIO][0]
+        correction = c - recover_power(base, index % COMPRESSION_RATIO) + 1
+        assert 0 <= correction < 4
+        corrections.append(correction)
+
+    print('/// The number of powers of five between each compressed power.')
+    print('#[cfg(feature = "wasm")]')
+    print('pub const DRAGONBOX64_STEP: usize = {};'.format(COMPRESSION_RATIO))
+    print()
+    print('/// Every 27th power of five for f64, to recover the other powers.')
+    print('#[cfg(feature = "wasm")]')
+    typ = '[(u64, u64); {}]'.format(len(compressed))
+    print('pub const DRAGONBOX64_COMPRESSED_POWERS_OF_FIVE: {} = ['.format(typ))
+    for c, exp in compressed:
+        print_power(c, exp)
+    print('];')
+    print()
+    print('/// The small powers of five to recover the other powers.')
+    print('#[cfg(feature = "wasm")]')
+    print('pub const DRAGONBOX64_SMALL_POWERS_OF_FIVE: [u64; DRAGONBOX64_STEP] = [')
+    for exp in range(COMPRESSION_RATIO):
+        print('    {},'.format(5**exp))
+    print('];')
+    print()
+    print('/// The difference between the exact and recovered powers, plus 1,')
+    print('/// packed into 2 bits for each power.')
+    print('#[cfg(feature = "wasm")]')
+    words = []
+    for index in range(0, len(corrections), 32):
+        word = 0
+        for bit, correction in enumerate(corrections[index:index + 32]):
+            word |= correction << (2 * bit)
+        words.append(word)
+    typ = '[u64; {}]'.format(len(words))
+    print('pub const DRAGONBOX64_CORRECTIONS: {} = ['.format(typ))
+    for word in words:
+        print('    0x{:016x},'.format(word))
+    print('];')
+
+
+if __name__ == '__main__':
+    main()
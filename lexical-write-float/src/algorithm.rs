@@ -54,7 +54,24 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
 
-    let fp = to_decimal(float);
+    let mut fp = to_decimal(float);
+    if (options.exact_rounding() || options.exact_digits()) && fp.mant != 0 {
+        let (mant, zeros) = F::remove_trailing_zeros(fp.mant);
+        let exp = fp.exp + zeros;
+        let rounded = shared::round_exact_halfway(float, mant, exp, options);
+        let (rounded, rounded_exp) = shared::round_exact_digits(float, rounded, exp, options);
+        if rounded != mant || rounded_exp != exp {
+            fp = extended_float(rounded, rounded_exp);
+        }
+    }
+    // Round to the maximum number of significant digits first, so the
+    // notation is chosen from the rounded exponent, IE, `999999.5` with
+    // 6 significant digits is written as `1e6`.
+    if let Some(max_digits) = options.max_significant_digits() {
+        if max_digits.get() < F::digit_count(fp.mant) {
+            fp = round_significant_digits::<F>(fp, options);
+        }
+    }
     let digit_count = F::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
 
@@ -65,6 +82,7 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
 
     write_float!(
         FORMAT,
+        float,
         sci_exp,
         options,
         write_float_scientific,
@@ -75,6 +93,26 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     )
 }
 
+/// Round the significant digits to the maximum number of digits.
+///
+/// This removes any trailing zeros from the rounded significant digits.
+#[inline]
+pub fn round_significant_digits<F: DragonboxFloat>(
+    fp: ExtendedFloat80,
+    options: &Options,
+) -> ExtendedFloat80 {
+    let mut digits = [b'0'; 32];
+    // SAFETY: safe since `digits.len() == 32`, which can hold any `u64`.
+    let digit_count = unsafe { F::write_digits(&mut digits, fp.mant) };
+    // SAFETY: safe since `digit_count <= digits.len()`.
+    let (count, carried) =
+        unsafe { shared::truncate_and_round_decimal(&mut digits, digit_count, options) };
+    let mant = digits[..count].iter().fold(0u64, |acc, &c| acc * 10 + (c - b'0') as u64);
+    let exp = fp.exp + (digit_count - count) as i32 + carried as i32;
+    let (mant, zeros) = F::remove_trailing_zeros(mant);
+    extended_float(mant, exp + zeros)
+}
+
 /// Write float to string in scientific notation.
 ///
 /// # Safety
@@ -115,7 +153,10 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
         index_unchecked_mut!(bytes[0] = bytes[1]);
         index_unchecked_mut!(bytes[1]) = decimal_point;
 
-        if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+        if !format.no_exponent_without_fraction()
+            && digit_count == 1
+            && (options.trim_floats() || options.trim_scientific())
+        {
             cursor = 1;
         } else if digit_count < exact_count {
             // Adjust the number of digits written, by appending zeros.
@@ -135,7 +176,7 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
@@ -547,24 +588,14 @@ pub fn compute_nearest_normal<F: RawFloat>(float: F) -> ExtendedFloat80 {
         // Due to the more complex logic in the new dragonbox algorithm,
         // it's much easier logically to store if we should short circuit,
         // the default, and only mark
+        // If the left endpoint is not included, the condition for
+        // success is z^(f) < delta^(f) (odd parity). Otherwise, we also
+        // succeed if x is an integer, since then z^(f) == delta^(f).
         let two_fl = two_fc - 1;
         let include_left = interval_type.include_left_endpoint();
-
-        if !include_left || exponent < F::FC_PM_HALF_LOWER || exponent > F::DIV_BY_5_THRESHOLD {
-            // If the left endpoint is not included, the condition for
-            // success is z^(f) < delta^(f) (odd parity).
-            // Otherwise, the inequalities on exponent ensure that
-            // x is not an integer, so if z^(f) >= delta^(f) (even parity), we in fact
-            // have strict inequality.
-            let parity = F::compute_mul_parity(two_fl, &pow5, beta).0;
-            if !parity {
-                should_short_circuit = false;
-            }
-        } else {
-            let (xi_parity, x_is_integer) = F::compute_mul_parity(two_fl, &pow5, beta);
-            if !xi_parity && !x_is_integer {
-                should_short_circuit = false
-            }
+        let (xi_parity, x_is_integer) = F::compute_mul_parity(two_fl, &pow5, beta);
+        if !(xi_parity || (x_is_integer && include_left)) {
+            should_short_circuit = false;
         }
     }
 
@@ -786,10 +817,10 @@ pub fn compute_right_closed_directed<F: RawFloat>(float: F, shorter: bool) -> Ex
 ///
 /// # Safety
 ///
-/// Safe if `bytes.len() >= 10`, since `u32::MAX` is at most 10 digits.
+/// Safe if `bytes` can hold the digits of `mantissa`, at most 10 digits.
 #[inline]
 pub unsafe fn write_digits_u32(bytes: &mut [u8], mantissa: u32) -> usize {
-    debug_assert!(bytes.len() >= 10);
+    debug_assert!(bytes.len() >= mantissa.digit_count());
     unsafe { mantissa.write_mantissa::<u32, { STANDARD }>(bytes) }
 }
 
@@ -800,11 +831,11 @@ pub unsafe fn write_digits_u32(bytes: &mut [u8], mantissa: u32) -> usize {
 ///
 /// # Safety
 ///
-/// Safe if `bytes.len() >= 20`, since `u64::MAX` is at most 20 digits.
+/// Safe if `bytes` can hold the digits of `mantissa`, at most 20 digits.
 #[inline]
 #[allow(clippy::branches_sharing_code)]
 pub unsafe fn write_digits_u64(bytes: &mut [u8], mantissa: u64) -> usize {
-    debug_assert!(bytes.len() >= 20);
+    debug_assert!(bytes.len() >= mantissa.digit_count());
     unsafe { mantissa.write_mantissa::<u64, { STANDARD }>(bytes) }
 }
 
@@ -857,25 +888,50 @@ pub fn is_left_endpoint<F: Float>(exponent: i32) -> bool {
 // MUL
 // ---
 
+/// Multiply two 64-bit integers, returning the high and low 64 bits.
 #[inline(always)]
-pub const fn umul128_upper64(x: u64, y: u64) -> u64 {
+#[cfg(not(feature = "wasm"))]
+pub const fn umul128(x: u64, y: u64) -> (u64, u64) {
     let p = x as u128 * y as u128;
-    (p >> 64) as u64
+    ((p >> 64) as u64, p as u64)
+}
+
+/// Multiply two 64-bit integers, returning the high and low 64 bits.
+///
+/// This avoids 128-bit multiplication, which is emulated on WebAssembly.
+#[inline(always)]
+#[cfg(feature = "wasm")]
+pub const fn umul128(x: u64, y: u64) -> (u64, u64) {
+    let x1 = x >> 32;
+    let x0 = x & 0xFFFF_FFFF;
+    let y1 = y >> 32;
+    let y0 = y & 0xFFFF_FFFF;
+
+    let w0 = x0 * y0;
+    let t = x1 * y0 + (w0 >> 32);
+    let w1 = (t & 0xFFFF_FFFF) + x0 * y1;
+    let hi = x1 * y1 + (t >> 32) + (w1 >> 32);
+    (hi, x.wrapping_mul(y))
+}
+
+#[inline(always)]
+pub const fn umul128_upper64(x: u64, y: u64) -> u64 {
+    umul128(x, y).0
 }
 
 #[inline(always)]
 pub const fn umul192_upper128(x: u64, hi: u64, lo: u64) -> (u64, u64) {
-    let mut r = x as u128 * hi as u128;
-    r += umul128_upper64(x, lo) as u128;
-    ((r >> 64) as u64, r as u64)
+    let (r_hi, r_lo) = umul128(x, hi);
+    let (r_lo, carry) = r_lo.overflowing_add(umul128_upper64(x, lo));
+    (r_hi + carry as u64, r_lo)
 }
 
 #[inline(always)]
 pub const fn umul192_lower128(x: u64, yhi: u64, ylo: u64) -> (u64, u64) {
     let hi = x.wrapping_mul(yhi);
-    let hi_lo = x as u128 * ylo as u128;
+    let (hi_lo_hi, hi_lo_lo) = umul128(x, ylo);
     // NOTE: This can wrap exactly to 0, and this is desired.
-    (hi.wrapping_add((hi_lo >> 64) as u64), hi_lo as u64)
+    (hi.wrapping_add(hi_lo_hi), hi_lo_lo)
 }
 
 #[inline(always)]
@@ -1014,6 +1070,8 @@ impl RoundMode {
         match self {
             RoundMode::Round => significand % 2 != 0,
             RoundMode::Truncate => true,
+            RoundMode::RoundHalfAway => false,
+            RoundMode::RoundToOdd => significand & 1 == 0,
         }
     }
 }
@@ -1258,7 +1316,7 @@ impl DragonboxFloat for f32 {
     #[inline(always)]
     unsafe fn write_digits(bytes: &mut [u8], mantissa: u64) -> usize {
         debug_assert!(mantissa <= u32::MAX as u64);
-        // SAFETY: safe is `bytes.len() >= 10`.
+        // SAFETY: safe if `bytes` can hold the digits of `mantissa`.
         unsafe { write_digits_u32(bytes, mantissa as u32) }
     }
 
@@ -1297,7 +1355,8 @@ impl DragonboxFloat for f32 {
 
         let r = umul96_lower64(two_f, *pow5);
         let parity = (r >> (64 - beta)) & 1;
-        let is_integer = r >> (32 - beta);
+        // Only the lower 32 bits are fractional, the parity bit is not.
+        let is_integer = (r >> (32 - beta)) as u32;
         (parity != 0, is_integer == 0)
     }
 
@@ -1355,6 +1414,60 @@ impl DragonboxFloat for f32 {
     }
 }
 
+/// Get the pre-computed power of five for f64 at the index.
+///
+/// # Safety
+///
+/// Safe as long as the index is less than `N64_POWERS_OF_FIVE`.
+#[inline(always)]
+#[cfg(not(feature = "wasm"))]
+unsafe fn dragonbox64_power(index: usize) -> (u64, u64) {
+    // SAFETY: safe if the index is in the correct range.
+    unsafe { index_unchecked!(DRAGONBOX64_POWERS_OF_FIVE[index]) }
+}
+
+/// Get the pre-computed power of five for f64 at the index.
+///
+/// This recovers the power from the nearest smaller compressed power,
+/// multiplied by a small power of five, and then corrects the rounding
+/// error from the truncated bits.
+///
+/// # Safety
+///
+/// Safe as long as the index is less than `N64_POWERS_OF_FIVE`.
+#[inline(always)]
+#[cfg(feature = "wasm")]
+unsafe fn dragonbox64_power(index: usize) -> (u64, u64) {
+    let base = index / DRAGONBOX64_STEP;
+    let offset = index % DRAGONBOX64_STEP;
+    // SAFETY: safe if the index is in the correct range.
+    let (hi, lo) = unsafe { index_unchecked!(DRAGONBOX64_COMPRESSED_POWERS_OF_FIVE[base]) };
+    if offset == 0 {
+        return (hi, lo);
+    }
+
+    // Calculate the 192-bit product, and keep the high 128 bits. The
+    // high word is always non-zero and less than `2^63`, so the shift
+    // is always in the range `[1, 63]`.
+    // SAFETY: safe since `offset < DRAGONBOX64_STEP`.
+    let pow5 = unsafe { index_unchecked!(DRAGONBOX64_SMALL_POWERS_OF_FIVE[offset]) };
+    let (hi_hi, hi_lo) = umul128(hi, pow5);
+    let (lo_hi, lo_lo) = umul128(lo, pow5);
+    let (mid, carry) = hi_lo.overflowing_add(lo_hi);
+    let upper = hi_hi + carry as u64;
+    let shift = upper.leading_zeros();
+    let hi = (upper << shift) | (mid >> (64 - shift));
+    let lo = (mid << shift) | (lo_lo >> (64 - shift));
+
+    // Apply the correction, which is stored with a bias of 1.
+    // SAFETY: safe if the index is in the correct range.
+    let corrections = unsafe { index_unchecked!(DRAGONBOX64_CORRECTIONS[index / 32]) };
+    let correction = (corrections >> (2 * (index % 32))) & 3;
+    let (lo, carry) = lo.overflowing_add(correction);
+    let (lo, borrow) = lo.overflowing_sub(1);
+    (hi + carry as u64 - borrow as u64, lo)
+}
+
 impl DragonboxFloat for f64 {
     const KAPPA: u32 = 2;
     const DECIMAL_DIGITS: usize = 17;
@@ -1368,7 +1481,7 @@ impl DragonboxFloat for f64 {
 
     #[inline(always)]
     unsafe fn write_digits(bytes: &mut [u8], mantissa: u64) -> usize {
-        // SAFETY: safe if `bytes.len() >= 20`.
+        // SAFETY: safe if `bytes` can hold the digits of `mantissa`.
         unsafe { write_digits_u64(bytes, mantissa) }
     }
 
@@ -1377,7 +1490,7 @@ impl DragonboxFloat for f64 {
         debug_assert!((SMALLEST_F64_POW5..=LARGEST_F64_POW5).contains(&exponent));
         let index = (exponent - SMALLEST_F64_POW5) as usize;
         // SAFETY: safe if the exponent is in the correct range.
-        unsafe { index_unchecked!(DRAGONBOX64_POWERS_OF_FIVE[index]) }
+        unsafe { dragonbox64_power(index) }
     }
 
     #[inline(always)]
@@ -1430,12 +1543,10 @@ impl DragonboxFloat for f64 {
 
         // This magic number is ceil(2^90 / 10^8).
         let magic_number = 12379400392853802749u64;
-        let nm = mantissa as u128 * magic_number as u128;
+        let (high, low) = umul128(mantissa, magic_number);
 
         // Is n is divisible by 10^8?
-        let high = (nm >> 64) as u64;
         let mask = (1 << (90 - 64)) - 1;
-        let low = nm as u64;
         if high & mask == 0 && low < magic_number {
             // If yes, work with the quotient.
             let mut n = (high >> (90 - 64)) as u32;
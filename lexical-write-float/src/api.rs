@@ -3,13 +3,16 @@
 #![doc(hidden)]
 
 use crate::options::Options;
+use crate::runtime;
+use crate::shared::{group_integer_digits, pad_width};
 use crate::write::WriteFloat;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::constants::FormattedSize;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::format::{is_valid_options_punctuation, Format, NumberFormat, STANDARD};
+use lexical_util::num::Float;
 use lexical_util::options::WriteOptions;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
@@ -69,6 +72,8 @@ macro_rules! float_to_lexical {
                 // SAFETY: safe if `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
                 unsafe {
                     let len = self.write_float::<{ FORMAT }>(bytes, &options);
+                    let len = group_integer_digits::<{ FORMAT }>(bytes, len, self.is_special(), &options);
+                    let len = pad_width::<{ FORMAT }>(bytes, len, self.is_special(), &options);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -84,6 +89,18 @@ macro_rules! float_to_lexical {
                 // SAFETY: safe since `check_buffer::<FORMAT>(bytes.len(), &options)` passes.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn to_lexical_with_format<'a>(
+                self,
+                bytes: &'a mut [u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                let len = runtime::write_float(self, bytes, format, options);
+                &mut bytes[..len]
+            }
         }
     )*)
 }
@@ -60,7 +60,8 @@ where
     //      formatting write control.
     let mantissa = float.mantissa();
     let radix = format.mantissa_radix();
-    let (mantissa, mantissa_bits) = truncate_and_round(mantissa, radix, options);
+    let (mantissa, exp) = truncate_and_round(mantissa, float.exponent(), radix, options);
+    let mantissa_bits = significant_bits(mantissa);
 
     // See if we should use an exponent if the number was represented
     // in scientific notation, AKA, `I.FFFF^EEE`. If the exponent is above
@@ -76,7 +77,6 @@ where
     // exponent relative to the number of leading or trailing 0s
     // it would introduce, that is, scaled to bits/digit. The min exp must
     // be less than, and the max must be above 0.
-    let exp = float.exponent();
     let mut sci_exp = exp + mantissa_bits as i32 - 1;
 
     // Normalize the exponent if we have an actual zero.
@@ -86,6 +86,7 @@ where
 
     write_float!(
         FORMAT,
+        float,
         sci_exp,
         options,
         write_float_scientific,
@@ -121,7 +122,6 @@ where
 {
     // Just decent size bounds checks to ensure we have a lot of space.
     assert!(M::FORMATTED_SIZE < BUFFER_SIZE - 2);
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
@@ -152,7 +152,10 @@ where
 
     // Write any trailing digits to the output.
     // SAFETY: bytes since cannot be empty.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -175,9 +178,7 @@ where
     // Now, write our scientific notation.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
     // SAFETY: safe if the buffer is large enough to hold the maximum written float.
-    unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
-    };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options) };
 
     cursor
 }
@@ -208,7 +209,6 @@ where
 
     // Just decent size bounds checks to ensure we have a lot of space.
     assert!(M::FORMATTED_SIZE < BUFFER_SIZE - 2);
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
@@ -759,13 +759,14 @@ pub fn scale_sci_exp(sci_exp: i32, bits_per_digit: i32) -> i32 {
 // ---------
 
 /// Round mantissa to the nearest value, returning only the number
-/// of significant digits. Also returns the number of bits of the mantissa.
+/// of significant digits. Also returns the binary exponent of the
+/// shifted mantissa.
 #[inline]
-pub fn truncate_and_round<M>(mantissa: M, radix: u32, options: &Options) -> (M, usize)
+pub fn truncate_and_round<M>(mantissa: M, exp: i32, radix: u32, options: &Options) -> (M, i32)
 where
     M: UnsignedInteger,
 {
-    let mut mantissa_bits = significant_bits(mantissa) as usize;
+    let mantissa_bits = significant_bits(mantissa) as usize;
     let bits_per_digit = fast_log2(radix);
 
     // Get the number of max digits, and then calculate if we need to round.
@@ -773,33 +774,42 @@ where
     if let Some(digits) = options.max_significant_digits() {
         max_digits = digits.get();
     }
-    let max_bits = max_digits.saturating_mul(bits_per_digit as usize);
+    // The digits are aligned to the exponent, so the leading digit may
+    // have fewer bits than the rest: IE, `0b1100` is `14` in radix 8,
+    // and the leading digit has only 1 bit.
+    let sci_exp = exp + mantissa_bits as i32 - 1;
+    let leading_bits = calculate_shl(sci_exp, bits_per_digit) as usize + 1;
+    let max_bits =
+        (max_digits - 1).saturating_mul(bits_per_digit as usize).saturating_add(leading_bits);
     let mut shifted_mantissa = mantissa;
+    let mut shifted_exp = exp;
 
     // Need to truncate the number of significant digits.
     if max_bits < mantissa_bits {
         let shr = (mantissa_bits - max_bits) as i32;
         shifted_mantissa = mantissa >> shr;
+        shifted_exp += shr;
 
         // We need to round-nearest, tie-even, so we need to handle
         // the truncation **here**. If the representation is above
         // halfway at all, we need to round up, even if 1 bit.
-        if options.round_mode() == RoundMode::Round {
-            let mask = (M::ONE << shr) - M::ONE;
+        let mask = (M::ONE << shr) - M::ONE;
+        let round_mode = options.round_mode();
+        if round_mode == RoundMode::Round || round_mode == RoundMode::RoundHalfAway {
             let halfway = M::ONE << (shr - 1);
             let above_halfway = (mantissa & mask) > halfway;
             let is_halfway = (mantissa & mask) == halfway;
             let is_odd = shifted_mantissa & M::ONE == M::ONE;
-
-            // Round-up and calculate if we carry over 1-bit.
-            // The built-in ctlz is very fast, so use that.
-            // Add 1 to the mantissa bits if we carry.
-            let initial_bits = shifted_mantissa.leading_zeros();
-            shifted_mantissa += as_cast((above_halfway || (is_odd & is_halfway)) as u32);
-            let final_bits = shifted_mantissa.leading_zeros();
-            mantissa_bits += (final_bits - initial_bits) as usize;
+            let tie_up = is_odd || round_mode == RoundMode::RoundHalfAway;
+
+            // Round-up: any carry is handled by the caller, since the
+            // number of significant bits is calculated from the mantissa.
+            shifted_mantissa += as_cast((above_halfway || (tie_up & is_halfway)) as u32);
+        } else if round_mode == RoundMode::RoundToOdd && (mantissa & mask) != M::ZERO {
+            // Setting the lowest bit makes the last digit odd, and never carries.
+            shifted_mantissa |= M::ONE;
         }
     }
 
-    (shifted_mantissa, mantissa_bits)
+    (shifted_mantissa, shifted_exp)
 }
@@ -0,0 +1,301 @@
+//! Write floats in bounded-size chunks.
+//!
+//! Floats written with many significant digits, without exponent notation,
+//! or padded to a large width may require thousands of bytes. These are
+//! written through a small, fixed-size buffer, and each full chunk is
+//! passed to a callback, such as to write to a serial port.
+//!
+//! The float is first written to an internal buffer without the padding
+//! or the digit separators, and with only as many trailing zeros, from the
+//! minimum number of significant digits, as fit. The remaining trailing
+//! zeros, the digit separators and the padding are generated while writing
+//! the chunks.
+
+#![doc(hidden)]
+
+use crate::api::ToLexicalWithOptions;
+use crate::options::{Alignment, Options};
+use crate::shared;
+use lexical_util::digit::char_is_digit_const;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::Float;
+use lexical_write_integer::write::digit_separators;
+
+/// Size of the internal buffer for the significant digits.
+///
+/// This must hold any float written without a minimum number of
+/// significant digits, padding, or digit separators: up to `1074`
+/// leading zeros and `64` significant digits for a radix of `3`.
+const SCRATCH_SIZE: usize = 1280;
+
+/// Bytes to write, buffered into full chunks.
+struct Chunks<'a, W> {
+    buffer: &'a mut [u8],
+    index: usize,
+    count: usize,
+    write: W,
+}
+
+impl<'a, W> Chunks<'a, W> {
+    /// Pass the buffered bytes to the callback.
+    #[inline]
+    fn flush<E>(&mut self) -> Result<(), E>
+    where
+        W: FnMut(&[u8]) -> Result<(), E>,
+    {
+        if self.index != 0 {
+            (self.write)(&self.buffer[..self.index])?;
+            self.index = 0;
+        }
+        Ok(())
+    }
+
+    /// Write a single byte.
+    #[inline]
+    fn push<E>(&mut self, byte: u8) -> Result<(), E>
+    where
+        W: FnMut(&[u8]) -> Result<(), E>,
+    {
+        if self.index == self.buffer.len() {
+            self.flush()?;
+        }
+        self.buffer[self.index] = byte;
+        self.index += 1;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Write all the bytes in a slice.
+    #[inline]
+    fn extend<E>(&mut self, mut bytes: &[u8]) -> Result<(), E>
+    where
+        W: FnMut(&[u8]) -> Result<(), E>,
+    {
+        while !bytes.is_empty() {
+            if self.index == self.buffer.len() {
+                self.flush()?;
+            }
+            let length = bytes.len().min(self.buffer.len() - self.index);
+            self.buffer[self.index..self.index + length].copy_from_slice(&bytes[..length]);
+            self.index += length;
+            self.count += length;
+            bytes = &bytes[length..];
+        }
+        Ok(())
+    }
+
+    /// Write a byte repeated `count` times.
+    #[inline]
+    fn fill<E>(&mut self, byte: u8, mut count: usize) -> Result<(), E>
+    where
+        W: FnMut(&[u8]) -> Result<(), E>,
+    {
+        while count != 0 {
+            if self.index == self.buffer.len() {
+                self.flush()?;
+            }
+            let length = count.min(self.buffer.len() - self.index);
+            self.buffer[self.index..self.index + length].fill(byte);
+            self.index += length;
+            self.count += length;
+            count -= length;
+        }
+        Ok(())
+    }
+}
+
+/// Write the float to the scratch buffer, without padding or digit separators.
+///
+/// Returns the number of bytes written, and the number of trailing zeros
+/// that did not fit, which must be inserted at the end of the digits.
+fn write_scratch<F, const FORMAT: u128>(
+    float: F,
+    scratch: &mut [u8],
+    options: &Options,
+) -> (usize, usize)
+where
+    F: ToLexicalWithOptions<Options = Options>,
+{
+    let mut options = options.clone();
+    // SAFETY: always safe, since removing the padding and grouping is valid.
+    unsafe {
+        options.set_min_width(None);
+        options.set_digit_grouping(None);
+    }
+    if options.buffer_size_const::<FORMAT>() <= scratch.len() {
+        return (float.to_lexical_with_options::<FORMAT>(scratch, &options).len(), 0);
+    }
+
+    // Find the most significant digits that fit, which is at least
+    // the number of digits written before any trailing zeros.
+    let min_digits = options.min_significant_digits().map_or(1, |x| x.get());
+    let mut fits = 1;
+    let mut overflows = min_digits;
+    while overflows - fits > 1 {
+        let digits = fits + (overflows - fits) / 2;
+        // SAFETY: always safe, since fewer significant digits are valid.
+        unsafe { options.set_min_significant_digits(core::num::NonZeroUsize::new(digits)) };
+        if options.buffer_size_const::<FORMAT>() <= scratch.len() {
+            fits = digits;
+        } else {
+            overflows = digits;
+        }
+    }
+
+    // Every additional significant digit adds a trailing zero, unless
+    // the trailing zeros are trimmed, so check if one digit fewer is shorter.
+    // SAFETY: always safe, since fewer significant digits are valid.
+    unsafe { options.set_min_significant_digits(core::num::NonZeroUsize::new(fits - 1)) };
+    let shorter = float.to_lexical_with_options::<FORMAT>(scratch, &options).len();
+    // SAFETY: always safe, since fewer significant digits are valid.
+    unsafe { options.set_min_significant_digits(core::num::NonZeroUsize::new(fits)) };
+    let length = float.to_lexical_with_options::<FORMAT>(scratch, &options).len();
+    if length == shorter {
+        (length, 0)
+    } else {
+        (length, min_digits - fits)
+    }
+}
+
+/// Write a float in chunks of at most `buffer.len()` bytes.
+///
+/// Each chunk is passed to `write`, and the chunks together are the same
+/// bytes as [`to_lexical_with_options`], so thousands of digits can be
+/// written using only a small buffer. Returns the total number of bytes
+/// written, or the first error returned by `write`.
+///
+/// # Panics
+///
+/// Panics if `buffer` is empty, or if the number format or options
+/// are invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::num::NonZeroUsize;
+/// # use lexical_util::format::STANDARD;
+/// # use lexical_write_float::{write_chunked, Options};
+/// let options = Options::builder()
+///     .min_significant_digits(NonZeroUsize::new(2000))
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; 16];
+/// let mut digits = Vec::new();
+/// let count = write_chunked::<_, _, (), { STANDARD }>(1.5f64, &mut buffer, &options, |chunk| {
+///     assert!(chunk.len() <= 16);
+///     digits.extend_from_slice(chunk);
+///     Ok(())
+/// });
+/// assert_eq!(count, Ok(2001));
+/// assert!(digits.starts_with(b"1.5000"));
+/// ```
+///
+/// [`to_lexical_with_options`]: crate::ToLexicalWithOptions::to_lexical_with_options
+pub fn write_chunked<F, W, E, const FORMAT: u128>(
+    float: F,
+    buffer: &mut [u8],
+    options: &Options,
+    write: W,
+) -> Result<usize, E>
+where
+    F: ToLexicalWithOptions<Options = Options> + Float,
+    W: FnMut(&[u8]) -> Result<(), E>,
+{
+    assert!(!buffer.is_empty());
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut scratch = [0u8; SCRATCH_SIZE];
+    let (length, zeros) = write_scratch::<F, FORMAT>(float, &mut scratch, options);
+    let bytes = &scratch[..length];
+
+    // Find the sign and base prefix, the integer digits, and the end of
+    // the significant digits, where any remaining trailing zeros go.
+    let is_special = float.is_special();
+    let (start, integer, end) = if is_special {
+        (0, 0, length)
+    } else {
+        let radix = format.mantissa_radix();
+        let decimal_point = options.decimal_point();
+        // SAFETY: safe since `bytes` is not empty.
+        let start = unsafe { shared::digits_start::<FORMAT>(bytes, options) };
+        let digits = &bytes[start..];
+        let integer = digits.iter().take_while(|&&c| char_is_digit_const(c, radix)).count();
+        let significant = digits
+            .iter()
+            .take_while(|&&c| c == decimal_point || char_is_digit_const(c, radix))
+            .count();
+        (start, integer, start + significant)
+    };
+    // The trailing zeros are integer digits if there is no fraction.
+    let integer_zeros = if start + integer == end {
+        zeros
+    } else {
+        0
+    };
+    let integer_count = integer + integer_zeros;
+
+    // Get the digit separators for the integer digits.
+    let separator = NumberFormat::<FORMAT>::DIGIT_SEPARATOR;
+    let (primary, secondary) = match options.digit_grouping() {
+        Some(primary) if separator != 0 && !is_special => {
+            (primary.get(), options.secondary_digit_grouping().map_or(primary.get(), |x| x.get()))
+        },
+        _ => (0, 0),
+    };
+    let separators = if primary != 0 {
+        digit_separators(integer_count, primary, secondary)
+    } else {
+        0
+    };
+
+    // Get the padding to the minimum width.
+    let count = length + zeros + separators;
+    let padding = match options.min_width() {
+        Some(width) if width.get() > count => width.get() - count,
+        _ => 0,
+    };
+    let zero_fill = options.fill() == b'0' && !is_special;
+    let fill = if options.fill() == b'0' {
+        b' '
+    } else {
+        options.fill()
+    };
+
+    let mut chunks = Chunks {
+        buffer,
+        index: 0,
+        count: 0,
+        write,
+    };
+    if !zero_fill && options.alignment() == Alignment::Right {
+        chunks.fill(fill, padding)?;
+    }
+    chunks.extend(&bytes[..start])?;
+    if zero_fill {
+        chunks.fill(b'0', padding)?;
+    }
+
+    // Write the integer digits, with a separator before each group.
+    for index in 0..integer_count {
+        let digit = if index < integer {
+            bytes[start + index]
+        } else {
+            b'0'
+        };
+        chunks.push(digit)?;
+        let remaining = integer_count - index - 1;
+        if primary != 0 && remaining >= primary && (remaining - primary) % secondary == 0 {
+            chunks.push(separator)?;
+        }
+    }
+
+    // Write the fraction and any remaining trailing zeros, then the exponent.
+    chunks.extend(&bytes[start + integer..end])?;
+    chunks.fill(b'0', zeros - integer_zeros)?;
+    chunks.extend(&bytes[end..])?;
+    if !zero_fill && options.alignment() == Alignment::Left {
+        chunks.fill(fill, padding)?;
+    }
+    chunks.flush()?;
+
+    Ok(chunks.count)
+}
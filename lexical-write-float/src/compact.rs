@@ -32,8 +32,9 @@ use lexical_util::bf16::bf16;
 use lexical_util::digit::digit_to_char_const;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::{NumberFormat, STANDARD};
 use lexical_util::num::{AsPrimitive, Float};
+use lexical_write_integer::write::WriteInteger;
 
 /// Compact float-to-string algorithm for decimal strings.
 ///
@@ -75,8 +76,16 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
         // SAFETY: safe since `digits.len()` is large enough to always hold
         // the generated digits, which is always <= 18.
         unsafe {
-            let (start, k) = grisu(float, &mut digits);
+            let (mut start, mut k) = grisu(float, &mut digits);
+            if options.exact_rounding() || options.exact_digits() {
+                round_exact_halfway(float, &mut digits, start, k, options);
+                let (count, exp) = round_exact_digits(float, &mut digits, start, k, options);
+                start = count;
+                k = exp;
+            }
             let (end, carried) = shared::truncate_and_round_decimal(&mut digits, start, options);
+            // Truncating the digits may leave trailing zeros, IE, `0.30000000000000004`.
+            let end = end - rtrim_char_count(&index_unchecked!(digits[..end]), b'0');
             (end, k + start as i32 - end as i32, carried)
         }
     };
@@ -84,6 +93,7 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     let sci_exp = kappa + digit_count as i32 - 1 + carried as i32;
     write_float!(
         FORMAT,
+        float,
         sci_exp,
         options,
         write_float_scientific,
@@ -126,7 +136,10 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
         index_unchecked_mut!(bytes[1]) = decimal_point;
 
         // SAFETY: safe if bytes is large enough to store all significant digits.
-        if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+        if !format.no_exponent_without_fraction()
+            && digit_count == 1
+            && (options.trim_floats() || options.trim_scientific())
+        {
             // No more digits and need to trim floats.
             cursor = 1;
         } else if digit_count < exact_count {
@@ -155,7 +168,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store the largest float.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
@@ -448,6 +461,67 @@ pub unsafe fn grisu<F: Float>(float: F, digits: &mut [u8]) -> (usize, i32) {
     unsafe { generate_digits(&w, &upper, &lower, digits, k) }
 }
 
+/// Adjust the generated digits so halfway cases round using the exact value.
+///
+/// # Safety
+///
+/// Safe as long as `count <= digits.len()`.
+#[inline]
+unsafe fn round_exact_halfway<F: Float>(
+    float: F,
+    digits: &mut [u8],
+    count: usize,
+    k: i32,
+    options: &Options,
+) {
+    // SAFETY: safe if `count <= digits.len()`.
+    let digits = unsafe { &mut index_unchecked_mut!(digits[..count]) };
+    let zeros = rtrim_char_count(digits, b'0');
+    let digits = &mut digits[..count - zeros];
+    if digits.len() > 19 {
+        return;
+    }
+    let mantissa = digits.iter().fold(0u64, |acc, &c| acc * 10 + (c - b'0') as u64);
+    let rounded = shared::round_exact_halfway(float, mantissa, k + zeros as i32, options);
+    if let Some(last) = digits.last_mut() {
+        // Only ever adjusts a trailing `5` by 1.
+        *last = digit_to_char_const((rounded % 10) as u32, 10);
+    }
+}
+
+/// Replace the generated digits with the exact digits, if required.
+///
+/// Returns the number of digits and the exponent of the last digit.
+///
+/// # Safety
+///
+/// Safe as long as `count <= digits.len()` and `digits.len() >= 20`.
+#[inline]
+unsafe fn round_exact_digits<F: Float>(
+    float: F,
+    digits: &mut [u8],
+    count: usize,
+    k: i32,
+    options: &Options,
+) -> (usize, i32) {
+    // SAFETY: safe if `count <= digits.len()`.
+    let trimmed = unsafe { &index_unchecked!(digits[..count]) };
+    let zeros = rtrim_char_count(trimmed, b'0');
+    let trimmed = &trimmed[..count - zeros];
+    if trimmed.len() > 19 {
+        return (count, k);
+    }
+    let mantissa = trimmed.iter().fold(0u64, |acc, &c| acc * 10 + (c - b'0') as u64);
+    let exp = k + zeros as i32;
+    let (rounded, rounded_exp) = shared::round_exact_digits(float, mantissa, exp, options);
+    if rounded == mantissa && rounded_exp == exp {
+        return (count, k);
+    }
+    // SAFETY: safe since `digits.len() >= 20`, and `rounded` has at most 17 digits.
+    let count = unsafe { rounded.write_mantissa::<u64, { STANDARD }>(digits) };
+    (count, rounded_exp)
+}
+
 // EXTENDED FLOAT
 
 /// Create extended float from native float.
@@ -21,6 +21,7 @@ use crate::binary::{
     calculate_shl,
     fast_ceildiv,
     fast_log2,
+    significant_bits,
     truncate_and_round,
     write_float_negative_exponent,
     write_float_positive_exponent,
@@ -82,7 +83,8 @@ where
     //      formatting write control.
     let mantissa = float.mantissa();
     let radix = format.mantissa_radix();
-    let (mantissa, mantissa_bits) = truncate_and_round(mantissa, radix, options);
+    let (mantissa, exp) = truncate_and_round(mantissa, float.exponent(), radix, options);
+    let mantissa_bits = significant_bits(mantissa);
 
     // See if we should use an exponent if the number was represented
     // in scientific notation, AKA, `I.FFFF^EEE`. If the exponent is above
@@ -98,7 +100,6 @@ where
     // exponent relative to the number of leading or trailing 0s
     // it would introduce, that is, scaled to bits/digit. The min exp must
     // be less than, and the max must be above 0.
-    let exp = float.exponent();
     let mut sci_exp = exp + mantissa_bits as i32 - 1;
 
     // Normalize the exponent if we have an actual zero.
@@ -108,6 +109,7 @@ where
 
     write_float!(
         FORMAT,
+        float,
         sci_exp,
         options,
         write_float_scientific,
@@ -142,7 +144,6 @@ where
 {
     // Just decent size bounds checks to ensure we have a lot of space.
     assert!(M::FORMATTED_SIZE < BUFFER_SIZE - 2);
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
@@ -175,7 +176,10 @@ where
 
     // Write any trailing digits to the output.
     // SAFETY: safe if the buffer is large enough to hold the significant digits.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -198,9 +202,7 @@ where
     // Now, write our scientific notation.
     // SAFETY: safe if bytes is large enough to store all digits.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
-    unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
-    };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options) };
 
     cursor
 }
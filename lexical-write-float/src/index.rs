@@ -7,14 +7,24 @@
 #![cfg_attr(not(feature = "power-of-two"), allow(unused_macros))]
 #![doc(hidden)]
 
-/// Enable an assertion only when the `safe` feature is enabled.
+/// Assert a condition when the `safe` feature is enabled, and otherwise
+/// only in debug builds.
+#[cfg(feature = "safe")]
 macro_rules! safe_assert {
     ($cond:expr $(,)?) => {
-        #[cfg(feature = "safe")]
         assert!($cond);
     };
 }
 
+/// Assert a condition when the `safe` feature is enabled, and otherwise
+/// only in debug builds.
+#[cfg(not(feature = "safe"))]
+macro_rules! safe_assert {
+    ($cond:expr $(,)?) => {
+        debug_assert!($cond);
+    };
+}
+
 /// Index a buffer, without bounds checking.
 #[cfg(not(feature = "safe"))]
 macro_rules! index_unchecked {
@@ -35,7 +35,11 @@
 //! * `power-of-two` - Add support for wring power-of-two float strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `wasm` - Optimize for WebAssembly, with smaller pre-computed tables.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `no-panic` - Remove all reachable panics from the writers, other than
+//!   for buffers that are too small.
+//! * `arbitrary` - Implement `Arbitrary` for options and rounding modes, for fuzzing.
 //!
 //! # Note
 //!
@@ -75,11 +79,13 @@ mod shared;
 
 pub mod algorithm;
 pub mod binary;
+pub mod chunked;
 pub mod compact;
 pub mod float;
 pub mod hex;
 pub mod options;
 pub mod radix;
+pub mod runtime;
 pub mod table;
 pub mod write;
 
@@ -89,12 +95,13 @@ mod table_grisu;
 
 // Re-exports
 pub use self::api::{ToLexical, ToLexicalWithOptions};
+pub use self::chunked::write_chunked;
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::options::{Alignment, Options, OptionsBuilder, OptionsOverride, RoundMode};
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormatBuilder};
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{OverrideOptions, WriteOptions};
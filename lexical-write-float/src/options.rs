@@ -1,10 +1,12 @@
 //! Configuration options for writing floats.
 
 use core::{mem, num};
-use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
+#[cfg(feature = "arbitrary")]
+use lexical_util::arbitrary::{self, Arbitrary, Unstructured};
+use lexical_util::ascii::{is_valid_ascii, is_valid_ascii_slice};
 use lexical_util::constants::FormattedSize;
 use lexical_util::error::Error;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::{options_punctuation_error, Format, FormatSpec, NumberFormat};
 use lexical_util::options::{self, WriteOptions};
 use lexical_util::result::Result;
 use static_assertions::const_assert;
@@ -23,9 +25,49 @@ const_assert!(mem::size_of::<OptionI32>() == mem::size_of::<i32>());
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum RoundMode {
     /// Round to the nearest float string with the given number of significant digits.
+    ///
+    /// Halfway cases are rounded to the nearest even digit (round-half-even),
+    /// as in IEEE-754.
     Round,
     /// Truncate the float string with the given number of significant digits.
     Truncate,
+    /// Round to the nearest float string with the given number of significant digits.
+    ///
+    /// Halfway cases are rounded away from zero (round-half-away), as is
+    /// common in financial and SQL contexts.
+    RoundHalfAway,
+    /// Truncate the float string with the given number of significant digits,
+    /// and if any digits were truncated, make the last digit odd (round-to-odd).
+    ///
+    /// This avoids double-rounding errors if the result is later rounded
+    /// to fewer digits. If the last digit is the largest even digit of an
+    /// odd radix, it is left unchanged.
+    RoundToOdd,
+}
+
+/// Enumeration for how to align floats padded to a minimum width.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    /// Write the float first, followed by the fill characters.
+    Left,
+    /// Write the fill characters first, followed by the float.
+    Right,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for RoundMode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let modes = [Self::Round, Self::Truncate, Self::RoundHalfAway, Self::RoundToOdd];
+        Ok(*u.choose(&modes)?)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Alignment {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let alignments = [Self::Left, Self::Right];
+        Ok(*u.choose(&alignments)?)
+    }
 }
 
 /// Maximum length for a special string.
@@ -53,10 +95,38 @@ pub struct OptionsBuilder {
     /// This is ignored if the exponent base is not the same as the mantissa radix.
     /// If not provided, use the algorithm's default.
     negative_exponent_break: OptionI32,
+    /// Minimum number of exponent digits to write.
+    min_exponent_digits: OptionUsize,
+    /// Always write subnormal floats in scientific notation.
+    subnormal_scientific: bool,
     /// Rounding mode for writing digits with precision control.
     round_mode: RoundMode,
+    /// Round to the maximum number of significant digits using the exact value
+    /// of the float, rather than its shortest representation.
+    exact_rounding: bool,
+    /// Write the exact value of the float rounded to the maximum number of
+    /// significant digits, like C's `printf`.
+    exact_digits: bool,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Trim the trailing ".0" from integral floats in scientific notation.
+    trim_scientific: bool,
+    /// Trim the leading "0" from floats between -1 and 1, IE, `.5`.
+    trim_integer_zero: bool,
+    /// Write a leading space in place of the sign for non-negative floats.
+    space_positive: bool,
+    /// Write negative floats in parentheses instead of with a leading `-`.
+    parentheses_negative: bool,
+    /// Write a leading `-` for NaN values with the sign bit set.
+    nan_sign: bool,
+    /// Write a negative sign for negative zero.
+    signed_zero: bool,
+    /// Write a leading `+` for positive infinity.
+    positive_inf_sign: bool,
+    /// Write the base prefix, IE, `0x`, before the significant digits.
+    base_prefix: bool,
+    /// Write the base prefix character in uppercase, IE, `0X`.
+    uppercase_base_prefix: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -65,6 +135,16 @@ pub struct OptionsBuilder {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// Minimum width to pad the written float to.
+    min_width: OptionUsize,
+    /// Alignment of the float when padded to the minimum width.
+    alignment: Alignment,
+    /// Character to pad the float to the minimum width.
+    fill: u8,
+    /// Number of integer digits between digit separators.
+    digit_grouping: OptionUsize,
+    /// Number of integer digits between digit separators after the first group.
+    secondary_digit_grouping: OptionUsize,
 }
 
 impl OptionsBuilder {
@@ -77,12 +157,30 @@ impl OptionsBuilder {
             min_significant_digits: None,
             positive_exponent_break: None,
             negative_exponent_break: None,
+            min_exponent_digits: None,
+            subnormal_scientific: false,
             round_mode: RoundMode::Round,
+            exact_rounding: false,
+            exact_digits: false,
             trim_floats: false,
+            trim_scientific: false,
+            trim_integer_zero: false,
+            space_positive: false,
+            parentheses_negative: false,
+            nan_sign: false,
+            signed_zero: false,
+            positive_inf_sign: false,
+            base_prefix: false,
+            uppercase_base_prefix: false,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
+            min_width: None,
+            alignment: Alignment::Right,
+            fill: b' ',
+            digit_grouping: None,
+            secondary_digit_grouping: None,
         }
     }
 
@@ -112,18 +210,96 @@ impl OptionsBuilder {
         self.negative_exponent_break
     }
 
+    /// Get the minimum number of exponent digits to write.
+    #[inline(always)]
+    pub const fn get_min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if we should always write subnormal floats in scientific notation.
+    #[inline(always)]
+    pub const fn get_subnormal_scientific(&self) -> bool {
+        self.subnormal_scientific
+    }
+
     /// Get the rounding mode for writing digits with precision control.
     #[inline(always)]
     pub const fn get_round_mode(&self) -> RoundMode {
         self.round_mode
     }
 
+    /// Get if we should round to the maximum significant digits exactly.
+    #[inline(always)]
+    pub const fn get_exact_rounding(&self) -> bool {
+        self.exact_rounding
+    }
+
+    /// Get if we should write the exact digits, rounded to the maximum significant digits.
+    #[inline(always)]
+    pub const fn get_exact_digits(&self) -> bool {
+        self.exact_digits
+    }
+
     /// Get if we should trim a trailing `".0"` from floats.
     #[inline(always)]
     pub const fn get_trim_floats(&self) -> bool {
         self.trim_floats
     }
 
+    /// Get if we should trim a trailing `".0"` from floats in scientific notation.
+    #[inline(always)]
+    pub const fn get_trim_scientific(&self) -> bool {
+        self.trim_scientific
+    }
+
+    /// Get if we should trim a leading `"0"` from floats between -1 and 1.
+    #[inline(always)]
+    pub const fn get_trim_integer_zero(&self) -> bool {
+        self.trim_integer_zero
+    }
+
+    /// Get if we should write a leading space for non-negative floats.
+    #[inline(always)]
+    pub const fn get_space_positive(&self) -> bool {
+        self.space_positive
+    }
+
+    /// Get if we should write negative floats in parentheses.
+    #[inline(always)]
+    pub const fn get_parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
+    /// Get if we should write the sign of negative NaN values.
+    #[inline(always)]
+    pub const fn get_nan_sign(&self) -> bool {
+        self.nan_sign
+    }
+
+    /// Get if we should write a negative sign for negative zero.
+    #[inline(always)]
+    pub const fn get_signed_zero(&self) -> bool {
+        self.signed_zero
+    }
+
+    /// Get if we should write a leading `+` for positive infinity.
+    #[inline(always)]
+    pub const fn get_positive_inf_sign(&self) -> bool {
+        self.positive_inf_sign
+    }
+
+    /// Get if we should write the base prefix before the significant digits.
+    #[inline(always)]
+    pub const fn get_base_prefix(&self) -> bool {
+        self.base_prefix
+    }
+
+    /// Get if we should write the base prefix character in uppercase.
+    #[inline(always)]
+    pub const fn get_uppercase_base_prefix(&self) -> bool {
+        self.uppercase_base_prefix
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn get_exponent(&self) -> u8 {
@@ -148,6 +324,36 @@ impl OptionsBuilder {
         self.inf_string
     }
 
+    /// Get the minimum width to pad the written float to.
+    #[inline(always)]
+    pub const fn get_min_width(&self) -> OptionUsize {
+        self.min_width
+    }
+
+    /// Get the alignment of the float when padded to the minimum width.
+    #[inline(always)]
+    pub const fn get_alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Get the character to pad the float to the minimum width.
+    #[inline(always)]
+    pub const fn get_fill(&self) -> u8 {
+        self.fill
+    }
+
+    /// Get the number of integer digits between digit separators.
+    #[inline(always)]
+    pub const fn get_digit_grouping(&self) -> OptionUsize {
+        self.digit_grouping
+    }
+
+    /// Get the number of integer digits between digit separators after the first group.
+    #[inline(always)]
+    pub const fn get_secondary_digit_grouping(&self) -> OptionUsize {
+        self.secondary_digit_grouping
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -178,6 +384,28 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the minimum number of exponent digits to write.
+    ///
+    /// The exponent is padded with leading zeros if it has fewer digits,
+    /// like `1e-05`.
+    #[inline(always)]
+    pub const fn min_exponent_digits(mut self, min_exponent_digits: OptionUsize) -> Self {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
+    /// Set if we should always write subnormal floats in scientific notation.
+    ///
+    /// This ignores the exponent breaks for subnormal floats, which otherwise
+    /// may be written with hundreds of leading zeros if scientific notation
+    /// is disabled via `negative_exponent_break`. This has no effect if the
+    /// number format disables exponent notation.
+    #[inline(always)]
+    pub const fn subnormal_scientific(mut self, subnormal_scientific: bool) -> Self {
+        self.subnormal_scientific = subnormal_scientific;
+        self
+    }
+
     /// Set the rounding mode for writing digits with precision control.
     #[inline(always)]
     pub const fn round_mode(mut self, round_mode: RoundMode) -> Self {
@@ -185,6 +413,34 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we should round to the maximum significant digits exactly.
+    ///
+    /// By default, the shortest representation is rounded to `max_significant_digits`,
+    /// which may round incorrectly near halfway cases. With exact rounding, the
+    /// shortest round-trip representation is written if it has at most
+    /// `max_significant_digits`, otherwise, the float is correctly rounded to
+    /// `max_significant_digits`.
+    #[inline(always)]
+    pub const fn exact_rounding(mut self, exact_rounding: bool) -> Self {
+        self.exact_rounding = exact_rounding;
+        self
+    }
+
+    /// Set if we should write the exact digits, rounded to the maximum significant digits.
+    ///
+    /// By default, the shortest round-trip representation is written if it
+    /// has at most `max_significant_digits`. With exact digits, the float is
+    /// always correctly rounded from its exact value to `max_significant_digits`,
+    /// like C's `printf`, IE, `0.1` with 17 digits is `0.10000000000000001`.
+    /// The exact digits are only written for up to 9 significant digits for
+    /// `f32` and 17 for `f64`, otherwise, the float is rounded as with
+    /// [`exact_rounding`](Self::exact_rounding).
+    #[inline(always)]
+    pub const fn exact_digits(mut self, exact_digits: bool) -> Self {
+        self.exact_digits = exact_digits;
+        self
+    }
+
     /// Set if we should trim a trailing `".0"` from floats.
     #[inline(always)]
     pub const fn trim_floats(mut self, trim_floats: bool) -> Self {
@@ -192,6 +448,100 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we should trim a trailing `".0"` from floats in scientific notation.
+    ///
+    /// This only applies if `trim_floats` is false, allowing integral floats
+    /// to keep the trailing `".0"` in fixed notation, like `1.0` and `1e+16`.
+    #[inline(always)]
+    pub const fn trim_scientific(mut self, trim_scientific: bool) -> Self {
+        self.trim_scientific = trim_scientific;
+        self
+    }
+
+    /// Set if we should trim a leading `"0"` from floats between -1 and 1.
+    ///
+    /// If set, `0.5` is written as `.5`. The zero is always written if
+    /// the number format requires integer digits, and zero itself is
+    /// always written as `0.0`, or `0` with `trim_floats`.
+    #[inline(always)]
+    pub const fn trim_integer_zero(mut self, trim_integer_zero: bool) -> Self {
+        self.trim_integer_zero = trim_integer_zero;
+        self
+    }
+
+    /// Set if we should write a leading space for non-negative floats.
+    ///
+    /// This matches the space flag of `printf` (`% f`), and is used for
+    /// column-aligned output. A required mantissa sign (`+`) takes
+    /// precedence over the space.
+    #[inline(always)]
+    pub const fn space_positive(mut self, space_positive: bool) -> Self {
+        self.space_positive = space_positive;
+        self
+    }
+
+    /// Set if we should write negative floats in parentheses.
+    ///
+    /// This is the accounting style for negative values, IE, `(1.5)` rather
+    /// than `-1.5`, as used in financial exports.
+    #[inline(always)]
+    pub const fn parentheses_negative(mut self, parentheses_negative: bool) -> Self {
+        self.parentheses_negative = parentheses_negative;
+        self
+    }
+
+    /// Set if we should write the sign of negative NaN values.
+    ///
+    /// By default, the sign of NaN is ignored, so `-NaN` is written as `NaN`.
+    /// This preserves the sign bit, for bit-exact serialization of floats.
+    #[inline(always)]
+    pub const fn nan_sign(mut self, nan_sign: bool) -> Self {
+        self.nan_sign = nan_sign;
+        self
+    }
+
+    /// Set if we should write a negative sign for negative zero.
+    ///
+    /// If false, `-0.0` is written identically to `0.0`.
+    #[inline(always)]
+    pub const fn signed_zero(mut self, signed_zero: bool) -> Self {
+        self.signed_zero = signed_zero;
+        self
+    }
+
+    /// Set if we should write a leading `+` for positive infinity.
+    ///
+    /// This matches Go's `+Inf`, and has no effect on finite floats or NaN.
+    #[inline(always)]
+    pub const fn positive_inf_sign(mut self, positive_inf_sign: bool) -> Self {
+        self.positive_inf_sign = positive_inf_sign;
+        self
+    }
+
+    /// Set if we should write the base prefix before the significant digits.
+    ///
+    /// The base prefix is `0` followed by the base prefix character of the
+    /// number format, or if the number format has none, `b`, `o`, or `x`
+    /// for binary, octal, and hexadecimal floats, respectively. It is
+    /// written after any sign, and is never written for special values.
+    #[inline(always)]
+    pub const fn base_prefix(mut self, base_prefix: bool) -> Self {
+        self.base_prefix = base_prefix;
+        self
+    }
+
+    /// Set if we should write the base prefix character in uppercase.
+    ///
+    /// If set, hexadecimal floats are written with a `0X` prefix rather
+    /// than `0x`. This has no effect unless `base_prefix` is set, or the
+    /// number format requires a base prefix, and has no effect if the
+    /// number format's base prefix is case-sensitive.
+    #[inline(always)]
+    pub const fn uppercase_base_prefix(mut self, uppercase_base_prefix: bool) -> Self {
+        self.uppercase_base_prefix = uppercase_base_prefix;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(mut self, exponent: u8) -> Self {
@@ -207,6 +557,10 @@ impl OptionsBuilder {
     }
 
     /// Set the string representation for `NaN`.
+    ///
+    /// This may be any ASCII string of at most 50 characters, such as
+    /// `null` or `#DIV/0!`, and is written verbatim. `None` disables
+    /// writing `NaN`, causing a panic if a `NaN` is written.
     #[inline(always)]
     pub const fn nan_string(mut self, nan_string: Option<&'static [u8]>) -> Self {
         self.nan_string = nan_string;
@@ -214,54 +568,115 @@ impl OptionsBuilder {
     }
 
     /// Set the string representation for `Infinity`.
+    ///
+    /// This may be any ASCII string of at most 50 characters, such as
+    /// `Infinity` or `#DIV/0!`, and is written verbatim after the sign.
+    /// `None` disables writing `Infinity`, causing a panic if an infinite
+    /// value is written.
     #[inline(always)]
     pub const fn inf_string(mut self, inf_string: Option<&'static [u8]>) -> Self {
         self.inf_string = inf_string;
         self
     }
 
+    /// Set the minimum width to pad the written float to.
+    ///
+    /// If the float is shorter than `min_width`, it is padded with the `fill`
+    /// character according to the `alignment`. This is accounted for in `buffer_size`.
+    #[inline(always)]
+    pub const fn min_width(mut self, min_width: OptionUsize) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set the alignment of the float when padded to the minimum width.
+    #[inline(always)]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the character to pad the float to the minimum width.
+    ///
+    /// If the fill character is `0`, the zeros are written after any sign,
+    /// ignoring the alignment, like `-001.5`. NaN and infinity are instead
+    /// padded with spaces.
+    #[inline(always)]
+    pub const fn fill(mut self, fill: u8) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Set the number of integer digits between digit separators.
+    ///
+    /// The digit separator is taken from the number format, so only the
+    /// integer digits of the significant digits are grouped, IE, `1234567.5`
+    /// with 3 digits per group and a `,` separator is written as
+    /// `1,234,567.5`. If the number format has no digit separator, this
+    /// has no effect.
+    #[inline(always)]
+    pub const fn digit_grouping(mut self, digit_grouping: OptionUsize) -> Self {
+        self.digit_grouping = digit_grouping;
+        self
+    }
+
+    /// Set the number of integer digits between digit separators after the first group.
+    ///
+    /// This allows grouping patterns where the first group, from the right,
+    /// has a different size than the following groups, IE, the Indian
+    /// numbering system with 3 digits and then 2 digits per group writes
+    /// `1234567.5` as `12,34,567.5`. If `None`, all groups have
+    /// `digit_grouping` digits. If `digit_grouping` is `None`, this has
+    /// no effect.
+    #[inline(always)]
+    pub const fn secondary_digit_grouping(mut self, secondary_digit_grouping: OptionUsize) -> Self {
+        self.secondary_digit_grouping = secondary_digit_grouping;
+        self
+    }
+
+    /// Set the punctuation and special strings from a format specification.
+    ///
+    /// Only the values set in the specification are changed.
+    /// The long string for `Infinity` is not used when writing floats.
+    #[inline(always)]
+    pub const fn format_spec(mut self, spec: &FormatSpec<'static>) -> Self {
+        if let Some(decimal_point) = spec.decimal_point() {
+            self.decimal_point = decimal_point;
+        }
+        if let Some(exponent) = spec.exponent() {
+            self.exponent = exponent;
+        }
+        if let Some(nan_string) = spec.nan_string() {
+            self.nan_string = Some(nan_string);
+        }
+        if let Some(inf_string) = spec.inf_string() {
+            self.inf_string = Some(inf_string);
+        }
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
     #[inline(always)]
-    #[allow(clippy::if_same_then_else, clippy::needless_bool)]
     pub const fn nan_str_is_valid(&self) -> bool {
         if self.nan_string.is_none() {
             return true;
         }
 
         let nan = unwrap_str(self.nan_string);
-        let length = nan.len();
-        if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
-            false
-        } else if !matches!(nan[0], b'N' | b'n') {
-            false
-        } else if !is_valid_letter_slice(nan) {
-            false
-        } else {
-            true
-        }
+        nan.len() <= MAX_SPECIAL_STRING_LENGTH && is_valid_ascii_slice(nan)
     }
 
     /// Determine if `inf_str` is valid.
     #[inline(always)]
-    #[allow(clippy::if_same_then_else, clippy::needless_bool)]
     pub const fn inf_str_is_valid(&self) -> bool {
         if self.inf_string.is_none() {
             return true;
         }
 
         let inf = unwrap_str(self.inf_string);
-        let length = inf.len();
-        if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
-            false
-        } else if !matches!(inf[0], b'I' | b'i') {
-            false
-        } else if !is_valid_letter_slice(inf) {
-            false
-        } else {
-            true
-        }
+        inf.len() <= MAX_SPECIAL_STRING_LENGTH && is_valid_ascii_slice(inf)
     }
 
     /// Check if the builder state is valid.
@@ -276,6 +691,8 @@ impl OptionsBuilder {
             false
         } else if !self.inf_str_is_valid() {
             false
+        } else if !is_valid_ascii(self.fill) {
+            false
         } else {
             true
         }
@@ -295,12 +712,30 @@ impl OptionsBuilder {
             min_significant_digits: self.min_significant_digits,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
+            min_exponent_digits: self.min_exponent_digits,
+            subnormal_scientific: self.subnormal_scientific,
             round_mode: self.round_mode,
+            exact_rounding: self.exact_rounding,
+            exact_digits: self.exact_digits,
             trim_floats: self.trim_floats,
+            trim_scientific: self.trim_scientific,
+            trim_integer_zero: self.trim_integer_zero,
+            space_positive: self.space_positive,
+            parentheses_negative: self.parentheses_negative,
+            nan_sign: self.nan_sign,
+            signed_zero: self.signed_zero,
+            positive_inf_sign: self.positive_inf_sign,
+            base_prefix: self.base_prefix,
+            uppercase_base_prefix: self.uppercase_base_prefix,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            min_width: self.min_width,
+            alignment: self.alignment,
+            fill: self.fill,
+            digit_grouping: self.digit_grouping,
+            secondary_digit_grouping: self.secondary_digit_grouping,
         }
     }
 
@@ -310,9 +745,7 @@ impl OptionsBuilder {
     pub const fn build(&self) -> Result<Options> {
         if self.nan_string.is_some() {
             let nan = unwrap_str(self.nan_string);
-            if nan.is_empty() || !matches!(nan[0], b'N' | b'n') {
-                return Err(Error::InvalidNanString);
-            } else if !is_valid_letter_slice(nan) {
+            if !is_valid_ascii_slice(nan) {
                 return Err(Error::InvalidNanString);
             } else if nan.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::NanStringTooLong);
@@ -321,9 +754,7 @@ impl OptionsBuilder {
 
         if self.inf_string.is_some() {
             let inf = unwrap_str(self.inf_string);
-            if inf.is_empty() || !matches!(inf[0], b'I' | b'i') {
-                return Err(Error::InvalidInfString);
-            } else if !is_valid_letter_slice(inf) {
+            if !is_valid_ascii_slice(inf) {
                 return Err(Error::InvalidInfString);
             } else if inf.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::InfStringTooLong);
@@ -342,11 +773,30 @@ impl OptionsBuilder {
             Err(Error::InvalidExponentSymbol)
         } else if !is_valid_ascii(self.decimal_point) {
             Err(Error::InvalidDecimalPoint)
+        } else if !is_valid_ascii(self.fill) {
+            Err(Error::InvalidFillCharacter)
         } else {
             // SAFETY: always safe, since it must be valid.
             Ok(unsafe { self.build_unchecked() })
         }
     }
+
+    /// Build the Options struct, validating the punctuation against a format.
+    ///
+    /// This is the same as [`build`](Self::build), but also rejects an
+    /// exponent or decimal point that is a valid digit for the format, or
+    /// that conflicts with the digit separator, base prefix or base suffix,
+    /// which would otherwise fail when writing with the format.
+    pub const fn build_with_format(&self, format: &Format) -> Result<Options> {
+        let options = match self.build() {
+            Ok(options) => options,
+            Err(error) => return Err(error),
+        };
+        match options_punctuation_error(format.packed(), self.exponent, self.decimal_point) {
+            Error::Success => Ok(options),
+            error => Err(error),
+        }
+    }
 }
 
 impl Default for OptionsBuilder {
@@ -389,10 +839,38 @@ pub struct Options {
     /// This is ignored if the exponent base is not the same as the mantissa radix.
     /// If not provided, use the algorithm's default.
     negative_exponent_break: OptionI32,
+    /// Minimum number of exponent digits to write.
+    min_exponent_digits: OptionUsize,
+    /// Always write subnormal floats in scientific notation.
+    subnormal_scientific: bool,
     /// Rounding mode for writing digits with precision control.
     round_mode: RoundMode,
+    /// Round to the maximum number of significant digits using the exact value
+    /// of the float, rather than its shortest representation.
+    exact_rounding: bool,
+    /// Write the exact value of the float rounded to the maximum number of
+    /// significant digits, like C's `printf`.
+    exact_digits: bool,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Trim the trailing ".0" from integral floats in scientific notation.
+    trim_scientific: bool,
+    /// Trim the leading "0" from floats between -1 and 1, IE, `.5`.
+    trim_integer_zero: bool,
+    /// Write a leading space in place of the sign for non-negative floats.
+    space_positive: bool,
+    /// Write negative floats in parentheses instead of with a leading `-`.
+    parentheses_negative: bool,
+    /// Write a leading `-` for NaN values with the sign bit set.
+    nan_sign: bool,
+    /// Write a negative sign for negative zero.
+    signed_zero: bool,
+    /// Write a leading `+` for positive infinity.
+    positive_inf_sign: bool,
+    /// Write the base prefix, IE, `0x`, before the significant digits.
+    base_prefix: bool,
+    /// Write the base prefix character in uppercase, IE, `0X`.
+    uppercase_base_prefix: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -401,6 +879,16 @@ pub struct Options {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// Minimum width to pad the written float to.
+    min_width: OptionUsize,
+    /// Alignment of the float when padded to the minimum width.
+    alignment: Alignment,
+    /// Character to pad the float to the minimum width.
+    fill: u8,
+    /// Number of integer digits between digit separators.
+    digit_grouping: OptionUsize,
+    /// Number of integer digits between digit separators after the first group.
+    secondary_digit_grouping: OptionUsize,
 }
 
 impl Options {
@@ -460,18 +948,96 @@ impl Options {
         self.negative_exponent_break
     }
 
+    /// Get the minimum number of exponent digits to write.
+    #[inline(always)]
+    pub const fn min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if we should always write subnormal floats in scientific notation.
+    #[inline(always)]
+    pub const fn subnormal_scientific(&self) -> bool {
+        self.subnormal_scientific
+    }
+
     /// Get the rounding mode for writing digits with precision control.
     #[inline(always)]
     pub const fn round_mode(&self) -> RoundMode {
         self.round_mode
     }
 
+    /// Get if we should round to the maximum significant digits exactly.
+    #[inline(always)]
+    pub const fn exact_rounding(&self) -> bool {
+        self.exact_rounding
+    }
+
+    /// Get if we should write the exact digits, rounded to the maximum significant digits.
+    #[inline(always)]
+    pub const fn exact_digits(&self) -> bool {
+        self.exact_digits
+    }
+
     /// Get if we should trim a trailing `".0"` from floats.
     #[inline(always)]
     pub const fn trim_floats(&self) -> bool {
         self.trim_floats
     }
 
+    /// Get if we should trim a trailing `".0"` from floats in scientific notation.
+    #[inline(always)]
+    pub const fn trim_scientific(&self) -> bool {
+        self.trim_scientific
+    }
+
+    /// Get if we should trim a leading `"0"` from floats between -1 and 1.
+    #[inline(always)]
+    pub const fn trim_integer_zero(&self) -> bool {
+        self.trim_integer_zero
+    }
+
+    /// Get if we should write a leading space for non-negative floats.
+    #[inline(always)]
+    pub const fn space_positive(&self) -> bool {
+        self.space_positive
+    }
+
+    /// Get if we should write negative floats in parentheses.
+    #[inline(always)]
+    pub const fn parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
+    /// Get if we should write the sign of negative NaN values.
+    #[inline(always)]
+    pub const fn nan_sign(&self) -> bool {
+        self.nan_sign
+    }
+
+    /// Get if we should write a negative sign for negative zero.
+    #[inline(always)]
+    pub const fn signed_zero(&self) -> bool {
+        self.signed_zero
+    }
+
+    /// Get if we should write a leading `+` for positive infinity.
+    #[inline(always)]
+    pub const fn positive_inf_sign(&self) -> bool {
+        self.positive_inf_sign
+    }
+
+    /// Get if we should write the base prefix before the significant digits.
+    #[inline(always)]
+    pub const fn base_prefix(&self) -> bool {
+        self.base_prefix
+    }
+
+    /// Get if we should write the base prefix character in uppercase.
+    #[inline(always)]
+    pub const fn uppercase_base_prefix(&self) -> bool {
+        self.uppercase_base_prefix
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(&self) -> u8 {
@@ -496,6 +1062,179 @@ impl Options {
         self.inf_string
     }
 
+    /// Get the minimum width to pad the written float to.
+    #[inline(always)]
+    pub const fn min_width(&self) -> OptionUsize {
+        self.min_width
+    }
+
+    /// Get the alignment of the float when padded to the minimum width.
+    #[inline(always)]
+    pub const fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Get the character to pad the float to the minimum width.
+    #[inline(always)]
+    pub const fn fill(&self) -> u8 {
+        self.fill
+    }
+
+    /// Get the number of integer digits between digit separators.
+    #[inline(always)]
+    pub const fn digit_grouping(&self) -> OptionUsize {
+        self.digit_grouping
+    }
+
+    /// Get the number of integer digits between digit separators after the first group.
+    #[inline(always)]
+    pub const fn secondary_digit_grouping(&self) -> OptionUsize {
+        self.secondary_digit_grouping
+    }
+
+    // SIZING
+
+    /// Get the maximum number of bytes required to write a float with
+    /// these options and number format.
+    ///
+    /// This accounts for the sign and base prefix, the significant digits,
+    /// the leading or trailing zeros up to the exponent breaks, the
+    /// exponent, digit separators, the special strings and the minimum
+    /// width. The bound holds for every float type, so it can size a
+    /// buffer at compile time, and is the same as
+    /// [`buffer_size`][WriteOptions::buffer_size].
+    ///
+    /// ```rust
+    /// # use lexical_util::format::STANDARD;
+    /// # use lexical_write_float::{Options, ToLexicalWithOptions};
+    /// const OPTIONS: Options = Options::new();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<STANDARD>();
+    ///
+    /// let mut buffer = [0u8; SIZE];
+    /// let digits = f64::MIN_POSITIVE.to_lexical_with_options::<STANDARD>(&mut buffer, &OPTIONS);
+    /// assert_eq!(digits, b"2.2250738585072014e-308");
+    /// ```
+    pub const fn buffer_size_const<const FORMAT: u128>(&self) -> usize {
+        let format = NumberFormat::<{ FORMAT }> {};
+        self.buffer_size_impl(
+            format.radix(),
+            format.exponent_base(),
+            format.exponent_radix(),
+            format.no_exponent_notation(),
+            format.required_base_prefix(),
+            format.required_base_suffix(),
+        )
+    }
+
+    /// Get the maximum number of bytes required to write a float with
+    /// these options and the radixes and notation of a number format.
+    const fn buffer_size_impl(
+        &self,
+        radix: u32,
+        exponent_base: u32,
+        exponent_radix: u32,
+        no_exponent_notation: bool,
+        required_base_prefix: bool,
+        required_base_suffix: bool,
+    ) -> usize {
+
+        // Significant digits: the shortest representation for decimal,
+        // the shifted mantissa for powers of two and the digits until
+        // the precision of the float is exhausted for other radixes.
+        let digits = if radix == 10 {
+            17
+        } else if radix.is_power_of_two() {
+            let bits_per_digit = radix.trailing_zeros() as usize;
+            (f64::MANTISSA_DIGITS as usize + 2 * bits_per_digit - 2) / bits_per_digit
+        } else {
+            64
+        };
+        let digits = match self.max_significant_digits {
+            Some(max_digits) if max_digits.get() < digits => max_digits.get(),
+            _ => digits,
+        };
+        let digits = max_usize(digits, unwrap_or_zero_usize(self.min_significant_digits));
+
+        // Integer digits and leading zeros, up to the exponent breaks, or
+        // the largest and smallest floats without exponent notation.
+        // Rounding may carry into another integer digit.
+        let max_integer = max_float_digits(radix, f64::MAX_EXP as usize);
+        let max_zeros = max_float_digits(radix, DENORMAL_EXP);
+        let (integer, zeros) = if no_exponent_notation {
+            (max_integer, max_zeros)
+        } else {
+            let max_exp = match self.positive_exponent_break {
+                Some(x) => x.get(),
+                None => 9,
+            };
+            let min_exp = match self.negative_exponent_break {
+                Some(x) => x.get(),
+                None => -5,
+            };
+            let integer = if max_exp > 0 {
+                max_exp as usize + 2
+            } else {
+                2
+            };
+            let zeros = if min_exp < 0 {
+                min_exp.wrapping_neg() as usize
+            } else {
+                0
+            };
+            (min_usize(integer, max_integer), min_usize(zeros, max_zeros))
+        };
+
+        // The body is the longest of scientific notation, or the integer
+        // or fraction digits, with the decimal point and a trailing zero.
+        // Non-decimal radixes pad the significant digits after any zeros
+        // filling the integer digits.
+        let positive = if radix == 10 {
+            max_usize(integer, digits) + 2
+        } else {
+            integer + digits + 2
+        };
+        let negative = zeros + digits + 2;
+        let mut body = max_usize(positive, negative);
+        if !no_exponent_notation {
+            let max_exp = max_float_digits(exponent_base, DENORMAL_EXP);
+            let exp_digits = digit_count(max_exp, exponent_radix);
+            let exp_digits = max_usize(exp_digits, unwrap_or_zero_usize(self.min_exponent_digits));
+            // Decimal point, trailing zero, exponent symbol and sign.
+            let scientific = max_usize(digits, 2) + 3 + exp_digits;
+            body = max_usize(body, scientific);
+        }
+
+        // Only the integer digits are grouped.
+        let separators = match self.digit_grouping {
+            Some(primary) => {
+                let group = match self.secondary_digit_grouping {
+                    Some(secondary) => min_usize(primary.get(), secondary.get()),
+                    None => primary.get(),
+                };
+                (integer - 1) / group
+            },
+            None => 0,
+        };
+
+        // Add 1 for the sign, 1 for the closing parenthesis, 2 for the
+        // base prefix and 1 for the base suffix.
+        let parentheses = self.parentheses_negative as usize;
+        let prefix = 2 * (self.base_prefix || required_base_prefix) as usize;
+        let suffix = required_base_suffix as usize;
+        let count = 1 + parentheses + prefix + suffix + body + separators;
+
+        // Custom special strings may be longer than the significant digits,
+        // and infinity may also have a sign and parentheses.
+        let nan_length = unwrap_str(self.nan_string).len();
+        let inf_length = unwrap_str(self.inf_string).len();
+        let special = max_usize(nan_length, inf_length) + 1 + parentheses;
+
+        // The float is padded to the minimum width.
+        let width = unwrap_or_zero_usize(self.min_width);
+
+        max_usize(max_usize(count, special), width)
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -540,6 +1279,26 @@ impl Options {
         self.negative_exponent_break = negative_exponent_break;
     }
 
+    /// Set the minimum number of exponent digits to write.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_min_exponent_digits(&mut self, min_exponent_digits: OptionUsize) {
+        self.min_exponent_digits = min_exponent_digits;
+    }
+
+    /// Set if we should always write subnormal floats in scientific notation.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_subnormal_scientific(&mut self, subnormal_scientific: bool) {
+        self.subnormal_scientific = subnormal_scientific;
+    }
+
     /// Set the rounding mode for writing digits with precision control.
     ///
     /// # Safety
@@ -550,6 +1309,26 @@ impl Options {
         self.round_mode = round_mode;
     }
 
+    /// Set if we should round to the maximum significant digits exactly.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_exact_rounding(&mut self, exact_rounding: bool) {
+        self.exact_rounding = exact_rounding;
+    }
+
+    /// Set if we should write the exact digits, rounded to the maximum significant digits.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_exact_digits(&mut self, exact_digits: bool) {
+        self.exact_digits = exact_digits;
+    }
+
     /// Set if we should trim a trailing `".0"` from floats.
     /// Unsafe, use the builder API for option validation.
     ///
@@ -561,6 +1340,96 @@ impl Options {
         self.trim_floats = trim_floats;
     }
 
+    /// Set if we should trim a trailing `".0"` from floats in scientific notation.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_trim_scientific(&mut self, trim_scientific: bool) {
+        self.trim_scientific = trim_scientific;
+    }
+
+    /// Set if we should trim a leading `"0"` from floats between -1 and 1.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_trim_integer_zero(&mut self, trim_integer_zero: bool) {
+        self.trim_integer_zero = trim_integer_zero;
+    }
+
+    /// Set if we should write a leading space for non-negative floats.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_space_positive(&mut self, space_positive: bool) {
+        self.space_positive = space_positive;
+    }
+
+    /// Set if we should write negative floats in parentheses.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_parentheses_negative(&mut self, parentheses_negative: bool) {
+        self.parentheses_negative = parentheses_negative;
+    }
+
+    /// Set if we should write the sign of negative NaN values.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_nan_sign(&mut self, nan_sign: bool) {
+        self.nan_sign = nan_sign;
+    }
+
+    /// Set if we should write a negative sign for negative zero.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_signed_zero(&mut self, signed_zero: bool) {
+        self.signed_zero = signed_zero;
+    }
+
+    /// Set if we should write a leading `+` for positive infinity.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_positive_inf_sign(&mut self, positive_inf_sign: bool) {
+        self.positive_inf_sign = positive_inf_sign;
+    }
+
+    /// Set if we should write the base prefix before the significant digits.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_base_prefix(&mut self, base_prefix: bool) {
+        self.base_prefix = base_prefix;
+    }
+
+    /// Set if we should write the base prefix character in uppercase.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_uppercase_base_prefix(&mut self, uppercase_base_prefix: bool) {
+        self.uppercase_base_prefix = uppercase_base_prefix;
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// # Safety
@@ -609,6 +1478,56 @@ impl Options {
         self.inf_string = inf_string
     }
 
+    /// Set the minimum width to pad the written float to.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_min_width(&mut self, min_width: OptionUsize) {
+        self.min_width = min_width;
+    }
+
+    /// Set the alignment of the float when padded to the minimum width.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_alignment(&mut self, alignment: Alignment) {
+        self.alignment = alignment;
+    }
+
+    /// Set the character to pad the float to the minimum width.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_fill(&mut self, fill: u8) {
+        self.fill = fill;
+    }
+
+    /// Set the number of integer digits between digit separators.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_digit_grouping(&mut self, digit_grouping: OptionUsize) {
+        self.digit_grouping = digit_grouping;
+    }
+
+    /// Set the number of integer digits between digit separators after the first group.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_secondary_digit_grouping(&mut self, secondary_digit_grouping: OptionUsize) {
+        self.secondary_digit_grouping = secondary_digit_grouping;
+    }
+
     // BUILDERS
 
     /// Get WriteFloatOptionsBuilder as a static function.
@@ -625,12 +1544,30 @@ impl Options {
             min_significant_digits: self.min_significant_digits,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
+            min_exponent_digits: self.min_exponent_digits,
+            subnormal_scientific: self.subnormal_scientific,
             round_mode: self.round_mode,
+            exact_rounding: self.exact_rounding,
+            exact_digits: self.exact_digits,
             trim_floats: self.trim_floats,
+            trim_scientific: self.trim_scientific,
+            trim_integer_zero: self.trim_integer_zero,
+            space_positive: self.space_positive,
+            parentheses_negative: self.parentheses_negative,
+            nan_sign: self.nan_sign,
+            signed_zero: self.signed_zero,
+            positive_inf_sign: self.positive_inf_sign,
+            base_prefix: self.base_prefix,
+            uppercase_base_prefix: self.uppercase_base_prefix,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            min_width: self.min_width,
+            alignment: self.alignment,
+            fill: self.fill,
+            digit_grouping: self.digit_grouping,
+            secondary_digit_grouping: self.secondary_digit_grouping,
         }
     }
 }
@@ -650,66 +1587,19 @@ impl WriteOptions for Options {
 
     #[inline(always)]
     fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        let format = NumberFormat::<{ FORMAT }> {};
-
-        // At least 2 for the decimal point and sign.
-        let mut count: usize = 2;
-
-        // First need to calculate maximum number of digits from leading or
-        // trailing zeros, IE, the exponent break.
-        if !format.no_exponent_notation() {
-            let min_exp = self.negative_exponent_break().map_or(-5, |x| x.get());
-            let max_exp = self.positive_exponent_break().map_or(9, |x| x.get());
-            let exp = min_exp.abs().max(max_exp) as usize;
-            if cfg!(feature = "power-of-two") && exp < 13 {
-                // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
-                count += 13;
-            } else if exp < 5 {
-                // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
-                count += 5;
-            } else {
-                // More leading or trailing zeros than the exponent digits.
-                count += exp;
-            }
-        } else if cfg!(feature = "power-of-two") {
-            // Min is 2^-1075.
-            count += 1075;
-        } else {
-            // Min is 10^-324.
-            count += 324;
-        }
-
-        // Now add the number of significant digits.
-        let radix = format.radix();
-        let formatted_digits = if radix == 10 {
-            // Really should be 18, but add some extra to be cautious.
-            28
-        } else {
-            //  BINARY:
-            //      53 significant mantissa bits for binary, add a few extra.
-            //  RADIX:
-            //      Our limit is `delta`. The maximum relative delta is 2.22e-16,
-            //      around 1. If we have values below 1, our delta is smaller, but
-            //      the max fraction is also a lot smaller. Above, and our fraction
-            //      must be < 1.0, so our delta is less significant. Therefore,
-            //      if our fraction is just less than 1, for a float near 2.0,
-            //      we can do at **maximum** 33 digits (for base 3). Let's just
-            //      assume it's a lot higher, and go with 64.
-            64
-        };
-        let digits = if let Some(max_digits) = self.max_significant_digits() {
-            formatted_digits.min(max_digits.get())
-        } else {
-            formatted_digits
-        };
-        let digits = if let Some(min_digits) = self.min_significant_digits() {
-            digits.max(min_digits.get())
-        } else {
-            formatted_digits
-        };
-        count += digits;
+        self.buffer_size_const::<FORMAT>()
+    }
 
-        count
+    #[inline(always)]
+    fn buffer_size_with_format<T: FormattedSize>(&self, format: &Format) -> usize {
+        self.buffer_size_impl(
+            format.radix(),
+            format.exponent_base(),
+            format.exponent_radix(),
+            format.no_exponent_notation(),
+            format.required_base_prefix(),
+            format.required_base_suffix(),
+        )
     }
 }
 
@@ -748,6 +1638,166 @@ const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
     }
 }
 
+/// Exponent of the smallest denormal `f64`, `2^-1074`.
+const DENORMAL_EXP: usize = 1074;
+
+/// Get the minimum of 2 values as a const fn.
+#[inline(always)]
+const fn min_usize(x: usize, y: usize) -> usize {
+    if x < y {
+        x
+    } else {
+        y
+    }
+}
+
+/// Get the maximum of 2 values as a const fn.
+#[inline(always)]
+const fn max_usize(x: usize, y: usize) -> usize {
+    if x > y {
+        x
+    } else {
+        y
+    }
+}
+
+/// Get the number of digits to write a value in the radix.
+#[inline(always)]
+const fn digit_count(mut value: usize, radix: u32) -> usize {
+    let mut count = 1;
+    while value >= radix as usize {
+        value /= radix as usize;
+        count += 1;
+    }
+    count
+}
+
+/// Get an upper bound for the number of digits in the radix to write
+/// `2^binary_exp`, or the leading zeros to write `2^-binary_exp`.
+///
+/// This is exact for decimal and powers of two, and uses the integral
+/// part of `log2(radix)` for other radixes, which overestimates it.
+#[inline(always)]
+const fn max_float_digits(radix: u32, binary_exp: usize) -> usize {
+    if radix == 10 {
+        // `log10(2) ~= 0.30103`, rounding up.
+        binary_exp * 30103 / 100000 + 1
+    } else {
+        let log2 = 31 - radix.leading_zeros() as usize;
+        (binary_exp - 1) / log2 + 1
+    }
+}
+
+// ARBITRARY
+// ---------
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for OptionsBuilder {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            max_significant_digits: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            min_significant_digits: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            positive_exponent_break: num::NonZeroI32::new(i8::arbitrary(u)? as i32),
+            negative_exponent_break: num::NonZeroI32::new(i8::arbitrary(u)? as i32),
+            min_exponent_digits: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            subnormal_scientific: u.arbitrary()?,
+            round_mode: u.arbitrary()?,
+            exact_rounding: u.arbitrary()?,
+            exact_digits: u.arbitrary()?,
+            trim_floats: u.arbitrary()?,
+            trim_scientific: u.arbitrary()?,
+            trim_integer_zero: u.arbitrary()?,
+            space_positive: u.arbitrary()?,
+            parentheses_negative: u.arbitrary()?,
+            nan_sign: u.arbitrary()?,
+            signed_zero: u.arbitrary()?,
+            positive_inf_sign: u.arbitrary()?,
+            base_prefix: u.arbitrary()?,
+            uppercase_base_prefix: u.arbitrary()?,
+            exponent: u.int_in_range(0..=0x7F)?,
+            decimal_point: u.int_in_range(0..=0x7F)?,
+            nan_string: options::arbitrary_special(u)?,
+            inf_string: options::arbitrary_special(u)?,
+            min_width: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            alignment: u.arbitrary()?,
+            fill: u.int_in_range(0..=0x7F)?,
+            digit_grouping: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            secondary_digit_grouping: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+        })
+    }
+}
+
+// Only valid options are generated: invalid builder states are rejected.
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Options {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let builder: OptionsBuilder = u.arbitrary()?;
+        builder.build().map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+// OVERRIDES
+// ---------
+
+lexical_util::options_override! {
+    /// Set the maximum number of significant digits to write.
+    max_significant_digits: OptionUsize;
+    /// Set the minimum number of significant digits to write.
+    min_significant_digits: OptionUsize;
+    /// Set the maximum exponent prior to using scientific notation.
+    positive_exponent_break: OptionI32;
+    /// Set the minimum exponent prior to using scientific notation.
+    negative_exponent_break: OptionI32;
+    /// Set the minimum number of exponent digits to write.
+    min_exponent_digits: OptionUsize;
+    /// Set if we should always write subnormal floats in scientific notation.
+    subnormal_scientific: bool;
+    /// Set the rounding mode for writing digits with precision control.
+    round_mode: RoundMode;
+    /// Set if we should round to the maximum significant digits exactly.
+    exact_rounding: bool;
+    /// Set if we should write the exact digits, rounded to the maximum significant digits.
+    exact_digits: bool;
+    /// Set if we should trim a trailing `".0"` from floats.
+    trim_floats: bool;
+    /// Set if we should trim a trailing `".0"` from floats in scientific notation.
+    trim_scientific: bool;
+    /// Set if we should trim a leading `"0"` from floats between -1 and 1.
+    trim_integer_zero: bool;
+    /// Set if we should write a leading space for non-negative floats.
+    space_positive: bool;
+    /// Set if we should write negative floats in parentheses.
+    parentheses_negative: bool;
+    /// Set if we should write the sign of negative NaN values.
+    nan_sign: bool;
+    /// Set if we should write a negative sign for negative zero.
+    signed_zero: bool;
+    /// Set if we should write a leading `+` for positive infinity.
+    positive_inf_sign: bool;
+    /// Set if we should write the base prefix before the significant digits.
+    base_prefix: bool;
+    /// Set if we should write the base prefix character in uppercase.
+    uppercase_base_prefix: bool;
+    /// Set the character to designate the exponent component of a float.
+    exponent: u8;
+    /// Set the character to separate the integer from the fraction components.
+    decimal_point: u8;
+    /// Set the string representation for `NaN`.
+    nan_string: Option<&'static [u8]>;
+    /// Set the string representation for `Infinity`.
+    inf_string: Option<&'static [u8]>;
+    /// Set the minimum width to pad the written float to.
+    min_width: OptionUsize;
+    /// Set the alignment of the float when padded to the minimum width.
+    alignment: Alignment;
+    /// Set the character to pad the float to the minimum width.
+    fill: u8;
+    /// Set the number of integer digits between digit separators.
+    digit_grouping: OptionUsize;
+    /// Set the number of integer digits between digit separators after the first group.
+    secondary_digit_grouping: OptionUsize;
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -791,6 +1841,53 @@ pub const CARAT_EXPONENT: Options = unsafe {
 };
 const_assert!(CARAT_EXPONENT.is_valid());
 
+/// Number format to write a float like C's `printf` in the "C" locale.
+///
+/// Special values are written as `nan` and `inf`.
+#[rustfmt::skip]
+pub const C_LOCALE: Options = unsafe {
+    Options::builder()
+        .nan_string(options::C_LOCALE_NAN)
+        .inf_string(options::C_LOCALE_INF)
+        .build_unchecked()
+};
+const_assert!(C_LOCALE.is_valid());
+
+/// Number format to write a float in most European locales, with a decimal comma.
+///
+/// Use a number format with a `.` digit separator and `digit_grouping`
+/// to group the integer digits.
+#[rustfmt::skip]
+pub const EUROPEAN_LOCALE: Options = DECIMAL_COMMA;
+
+/// Number format to write a float in scientific notation with a fixed
+/// number of significant digits, like C's `printf("%e")`.
+///
+/// This writes 7 significant digits, correctly rounded from the exact
+/// value of the float, and the exponent has at least 2 digits, IE,
+/// `1.500000e+00`. This requires the `SCIENTIFIC_FIXED` number format
+/// to always use scientific notation, with a sign in the exponent.
+#[rustfmt::skip]
+pub const SCIENTIFIC_FIXED: Options = unsafe {
+    Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(7))
+        .min_significant_digits(num::NonZeroUsize::new(7))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .exact_digits(true)
+        .signed_zero(true)
+        .nan_string(options::C_LOCALE_NAN)
+        .inf_string(options::C_LOCALE_INF)
+        .build_unchecked()
+};
+const_assert!(SCIENTIFIC_FIXED.is_valid());
+
+/// Number format to write floats readable by most parsers.
+///
+/// This writes the shortest representation, and special values as
+/// `nan` and `inf`.
+#[rustfmt::skip]
+pub const PERMISSIVE: Options = C_LOCALE;
+
 /// Number format for a Rust literal floating-point number.
 #[rustfmt::skip]
 pub const RUST_LITERAL: Options = unsafe {
@@ -811,6 +1908,27 @@ pub const PYTHON_LITERAL: Options = unsafe {
 };
 const_assert!(PYTHON_LITERAL.is_valid());
 
+/// Number format to write a float like Python's `repr`.
+///
+/// This writes the shortest representation, using scientific notation
+/// if the value is below `1e-4` or at least `1e16`. Integral floats keep
+/// the trailing `.0` in fixed notation, and the exponent has at least
+/// 2 digits, IE, `1.0` and `1e-05`. This requires the `PYTHON_REPR`
+/// number format for the `+` sign in positive exponents, IE, `1e+16`.
+#[rustfmt::skip]
+pub const PYTHON_REPR: Options = unsafe {
+    Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(15))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .trim_scientific(true)
+        .signed_zero(true)
+        .nan_string(options::PYTHON_REPR_NAN)
+        .inf_string(options::PYTHON_REPR_INF)
+        .build_unchecked()
+};
+const_assert!(PYTHON_REPR.is_valid());
+
 /// Number format for a C++ literal floating-point number.
 #[rustfmt::skip]
 pub const CXX_LITERAL: Options = unsafe {
@@ -831,6 +1949,35 @@ pub const C_LITERAL: Options = unsafe {
 };
 const_assert!(CXX_LITERAL.is_valid());
 
+/// Number format to write a float like C's `printf("%g")`.
+///
+/// This writes 6 significant digits, correctly rounded from the exact
+/// value of the float, without trailing zeros. Scientific notation is
+/// used if the rounded value is below `1e-4` or at least `1e6`, and the
+/// exponent has at least 2 digits. Negative zero and NaN values with
+/// the sign bit set are written with a sign, like glibc. This requires
+/// the `C_PRINTF_G` number format for the `+` sign in positive exponents,
+/// IE, `1e+06`.
+///
+/// For a precision other than 6, set `max_significant_digits` to the
+/// precision and `positive_exponent_break` to the precision minus 1.
+#[rustfmt::skip]
+pub const C_PRINTF_G: Options = unsafe {
+    Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(6))
+        .positive_exponent_break(num::NonZeroI32::new(5))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .exact_digits(true)
+        .trim_floats(true)
+        .signed_zero(true)
+        .nan_sign(true)
+        .nan_string(options::C_PRINTF_G_NAN)
+        .inf_string(options::C_PRINTF_G_INF)
+        .build_unchecked()
+};
+const_assert!(C_PRINTF_G.is_valid());
+
 /// Number format for a Ruby literal floating-point number.
 #[rustfmt::skip]
 pub const RUBY_LITERAL: Options = unsafe {
@@ -871,6 +2018,29 @@ pub const GO_LITERAL: Options = unsafe {
 };
 const_assert!(GO_LITERAL.is_valid());
 
+/// Number format to write a float like Go's `strconv.FormatFloat(f, 'g', -1, 64)`.
+///
+/// This writes the shortest representation, without a trailing `.0`,
+/// using scientific notation if the value is below `1e-4` or at least
+/// `1e6`. The exponent has at least 2 digits, negative zero is written
+/// as `-0`, and positive infinity as `+Inf`. This requires the
+/// `GO_FORMAT_FLOAT` number format for the `+` sign in positive
+/// exponents, IE, `1e+06`.
+#[rustfmt::skip]
+pub const GO_FORMAT_FLOAT: Options = unsafe {
+    Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(5))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .trim_floats(true)
+        .signed_zero(true)
+        .positive_inf_sign(true)
+        .nan_string(options::GO_FORMAT_FLOAT_NAN)
+        .inf_string(options::GO_FORMAT_FLOAT_INF)
+        .build_unchecked()
+};
+const_assert!(GO_FORMAT_FLOAT.is_valid());
+
 /// Number format for a Haskell literal floating-point number.
 #[rustfmt::skip]
 pub const HASKELL_LITERAL: Options = unsafe {
@@ -908,6 +2078,23 @@ pub const JAVASCRIPT_STRING: Options = unsafe {
 };
 const_assert!(JAVASCRIPT_STRING.is_valid());
 
+/// Number format to write a float like Javascript's `Number.prototype.toString`.
+///
+/// This writes the shortest representation, without a trailing `.0`,
+/// using scientific notation if the value is below `1e-6` or at least
+/// `1e21`. This requires the `JAVASCRIPT_TO_STRING` number format for
+/// the `+` sign in positive exponents, IE, `1e+21`.
+#[rustfmt::skip]
+pub const JAVASCRIPT_TO_STRING: Options = unsafe {
+    Options::builder()
+        .trim_floats(true)
+        .positive_exponent_break(num::NonZeroI32::new(20))
+        .negative_exponent_break(num::NonZeroI32::new(-6))
+        .inf_string(options::JAVASCRIPT_INF)
+        .build_unchecked()
+};
+const_assert!(JAVASCRIPT_TO_STRING.is_valid());
+
 /// Number format for a Perl literal floating-point number.
 #[rustfmt::skip]
 pub const PERL_LITERAL: Options = unsafe {
@@ -1368,6 +2555,16 @@ pub const POSTGRESQL: Options = unsafe {
 };
 const_assert!(POSTGRESQL.is_valid());
 
+/// Number format to write a float like PostgreSQL's `float8` output.
+#[rustfmt::skip]
+pub const POSTGRESQL_STRING: Options = unsafe {
+    Options::builder()
+        .nan_string(options::POSTGRESQL_STRING_NAN)
+        .inf_string(options::POSTGRESQL_STRING_INFINITY)
+        .build_unchecked()
+};
+const_assert!(POSTGRESQL_STRING.is_valid());
+
 /// Number format for a MySQL literal floating-point number.
 #[rustfmt::skip]
 pub const MYSQL: Options = unsafe {
@@ -94,7 +94,7 @@ where
 
     // Write our fraction digits.
     // SAFETY: we have 1100 digits, which is enough for any float f64 or smaller.
-    if fraction > delta {
+    if fraction >= delta {
         loop {
             // Shift up by one digit.
             fraction *= base;
@@ -134,7 +134,7 @@ where
                 }
             }
 
-            if delta >= fraction {
+            if fraction < delta {
                 break;
             }
         }
@@ -176,9 +176,15 @@ where
     // SAFETY: safe since both `integer_cursor` and `fraction_cursor` within bounds.
     let digits = unsafe { &index_unchecked!(buffer[integer_cursor..fraction_cursor]) };
     let zero_count = ltrim_char_count(digits, b'0');
-    let sci_exp: i32 = initial_cursor as i32 - integer_cursor as i32 - zero_count as i32 - 1;
+    let mut sci_exp: i32 = initial_cursor as i32 - integer_cursor as i32 - zero_count as i32 - 1;
+
+    // Normalize the exponent if we have an actual zero.
+    if float == F::ZERO {
+        sci_exp = 0;
+    }
     write_float!(
         FORMAT,
+        float,
         sci_exp,
         options,
         write_float_scientific,
@@ -212,7 +218,6 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options.
     let format = NumberFormat::<{ FORMAT }> {};
@@ -239,16 +244,18 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
     // Non-exponent portion.
     // Get as many digits as possible, up to `MAX_DIGIT_LENGTH+1`
     // since we are ignoring the digit for the first digit,
-    // or the number of written digits.
+    // or the number of written digits. Trim the trailing zeros before
+    // copying, so we never write past the significant digits.
     // SAFETY: safe if the buffer is large enough to hold the significant digits.
     let digit_count = unsafe {
+        let zeros = rtrim_char_count(&index_unchecked!(digits[1..]), b'0');
+        let digit_count = digit_count - zeros;
         index_unchecked_mut!(bytes[0] = digits[0]);
         index_unchecked_mut!(bytes[1]) = decimal_point;
         let src = digits.as_ptr().add(1);
         let dst = &mut index_unchecked_mut!(bytes[2..digit_count + 1]);
         copy_nonoverlapping_unchecked!(dst, src, digit_count - 1);
-        let zeros = rtrim_char_count(&index_unchecked!(bytes[2..digit_count + 1]), b'0');
-        digit_count - zeros
+        digit_count
     };
     // Extra 1 since we have the decimal point.
     let mut cursor = digit_count + 1;
@@ -258,7 +265,10 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Write any trailing digits to the output.
     // SAFETY: bytes cannot be empty.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -281,7 +291,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe if bytes is large enough to store the largest float with the smallest radix.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
@@ -303,16 +313,19 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options.
     let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     let decimal_point = options.decimal_point();
 
-    // Round and truncate the number of significant digits.
+    // Round and truncate the number of significant digits. Leading zeros
+    // are not significant, and may exceed the maximum number of digits.
     let mut start = integer_cursor;
-    let end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
+    // SAFETY: safe since both `integer_cursor` and `fraction_cursor` within bounds.
+    let zeros =
+        ltrim_char_count(unsafe { &index_unchecked!(buffer[start..fraction_cursor]) }, b'0');
+    let end = fraction_cursor.min(start + zeros + MAX_DIGIT_LENGTH + 1);
     // SAFETY: safe since `start + digit_count <= end && end <= buffer.len()`.
     let (mut digit_count, carried) =
         unsafe { truncate_and_round(buffer, start, end, format.radix(), options) };
@@ -330,6 +343,8 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
 
     // SAFETY: safe since `start + digit_count <= end`.
     let digits = unsafe { &index_unchecked!(buffer[start..start + digit_count]) };
+    // Leading zeros are not significant, so only pad the significant digits.
+    let leading_zeros = ltrim_char_count(digits, b'0').min(digit_count - 1);
 
     // Write the integer component.
     let integer_length = initial_cursor - start;
@@ -364,13 +379,16 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
     if fraction_count > 0 {
         // Need to write additional fraction digits.
         // SAFETY: safe if the buffer is large enough to hold the significant digits.
+        // Trim the trailing zeros before copying, so we never write past
+        // the significant digits.
         unsafe {
+            let zeros = rtrim_char_count(&index_unchecked!(digits[..fraction_count]), b'0');
+            let fraction_count = fraction_count - zeros;
             let src = digits.as_ptr();
             let end = cursor + fraction_count;
             let dst = &mut index_unchecked_mut!(bytes[cursor..end]);
             copy_nonoverlapping_unchecked!(dst, src, fraction_count);
-            let zeros = rtrim_char_count(&index_unchecked!(bytes[cursor..end]), b'0');
-            cursor += fraction_count - zeros;
+            cursor = end;
         }
     } else if options.trim_floats() {
         // Remove the decimal point, went too far.
@@ -383,7 +401,8 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
     }
 
     // Determine if we need to add more trailing zeros.
-    let exact_count = shared::min_exact_digits(digit_count, options);
+    let exact_count =
+        shared::min_exact_digits(digit_count - leading_zeros, options) + leading_zeros;
 
     // Write any trailing digits to the output.
     // SAFETY: bytes cannot be empty.
@@ -457,11 +476,21 @@ pub unsafe fn truncate_and_round(
     }
 
     // Need to add the number of leading zeros to the digits digit_count.
+    // These may be more than the number of max digits, so we must
+    // count them over all the digits we wrote.
     let max_digits = {
-        // SAFETY: safe since `start + max_digits < end`.
-        let digits = unsafe { &mut index_unchecked_mut!(buffer[start..start + max_digits]) };
+        // SAFETY: safe since `start <= end <= buffer.len()`.
+        let digits = unsafe { &index_unchecked!(buffer[start..end]) };
         max_digits + ltrim_char_count(digits, b'0')
     };
+    if max_digits >= digit_count {
+        return (digit_count, false);
+    }
+    if options.round_mode() == RoundMode::RoundToOdd {
+        // SAFETY: safe since `start + max_digits < end <= buffer.len()`.
+        let digits = unsafe { &mut index_unchecked_mut!(buffer[start..end]) };
+        return unsafe { shared::round_to_odd(digits, max_digits, end - start, radix) };
+    }
 
     // We need to round-nearest, tie-even, so we need to handle
     // the truncation **here**. If the representation is above
@@ -483,7 +512,8 @@ pub unsafe fn truncate_and_round(
         // Even radix, our halfway point `$c00000.....`.
         // SAFETY: safe if `start <= end, because `max_digits < digit_count`.
         let truncated = unsafe { &index_unchecked!(buffer[start + max_digits + 1..end]) };
-        if truncated.iter().all(|&x| x == b'0') && last & 1 == 0 {
+        let tie_down = options.round_mode() == RoundMode::Round && last & 1 == 0;
+        if truncated.iter().all(|&x| x == b'0') && tie_down {
             // At an exact halfway point, and even, round-down.
             (max_digits, false)
         } else {
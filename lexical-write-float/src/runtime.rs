@@ -0,0 +1,297 @@
+//! Write floats using a number format known at runtime.
+//!
+//! The significant digits are written by the algorithms for a `FORMAT`
+//! const generic with the same mantissa radix and exponent base, and
+//! then the parts of the number grammar read from the [`Format`], such
+//! as the base prefix, the exponent sign and radix, and the digit
+//! separators, are applied to the written bytes.
+
+#![doc(hidden)]
+
+use crate::options::Options;
+use crate::shared::{
+    digits_start_with,
+    group_integer_digits_with,
+    min_exact_digits,
+    pad_width_with,
+};
+use crate::write::WriteFloat;
+use core::num::NonZeroI32;
+use lexical_util::constants::FormattedSize;
+use lexical_util::digit::{char_to_digit_const, digit_to_char_const};
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+#[cfg(not(feature = "power-of-two"))]
+use lexical_util::format::STANDARD;
+use lexical_util::format::{
+    is_valid_float_radix,
+    is_valid_options_punctuation,
+    Format,
+    NumberFormat,
+};
+use lexical_util::options::WriteOptions;
+use lexical_write_integer::write::{base_prefix_char, base_suffix_char, WriteInteger};
+
+/// The radix for the exponent digits written by the const generic algorithms.
+///
+/// This is the largest valid radix, so the exponent is never longer
+/// than the exponent written in the radix of the runtime format.
+#[cfg(feature = "power-of-two")]
+const EXPONENT_RADIX: u8 = if cfg!(feature = "radix") {
+    36
+} else {
+    32
+};
+
+/// Create the format used to write the digits of a runtime format.
+#[cfg(feature = "power-of-two")]
+const fn digits_format(radix: u8, exponent_base: u8) -> u128 {
+    NumberFormatBuilder::new()
+        .mantissa_radix(radix)
+        .exponent_base(core::num::NonZeroU8::new(exponent_base))
+        .exponent_radix(core::num::NonZeroU8::new(EXPONENT_RADIX))
+        .build()
+}
+
+/// The format used to write decimal digits.
+#[cfg(feature = "power-of-two")]
+const DECIMAL: u128 = digits_format(10, 10);
+
+/// The format used to write decimal digits.
+#[cfg(not(feature = "power-of-two"))]
+const DECIMAL: u128 = STANDARD;
+
+/// Call `$body` with `$inner` as a const format with the radix and exponent base.
+///
+/// The radix and exponent base must be validated first.
+macro_rules! with_digits_format {
+    ($radix:expr, $base:expr, $inner:ident => $body:expr) => {
+        with_digits_format!(
+            @arms ($radix, $base), $inner, $body ;
+            power_of_two: (2, 2) (4, 4) (8, 8) (16, 16) (32, 32) (4, 2) (8, 2) (16, 2) (32, 2) (16, 4) ;
+            radix: (3, 3) (5, 5) (6, 6) (7, 7) (9, 9) (11, 11) (12, 12) (13, 13) (14, 14) (15, 15)
+                (17, 17) (18, 18) (19, 19) (20, 20) (21, 21) (22, 22) (23, 23) (24, 24) (25, 25)
+                (26, 26) (27, 27) (28, 28) (29, 29) (30, 30) (31, 31) (33, 33) (34, 34) (35, 35)
+                (36, 36)
+        )
+    };
+    (
+        @arms $key:expr, $inner:ident, $body:expr ;
+        power_of_two: $(($pr:literal, $pb:literal))* ;
+        radix: $(($rr:literal, $rb:literal))*
+    ) => {
+        match $key {
+            $(
+                #[cfg(feature = "power-of-two")]
+                ($pr, $pb) => {
+                    const $inner: u128 = digits_format($pr, $pb);
+                    $body
+                },
+            )*
+            $(
+                #[cfg(feature = "radix")]
+                ($rr, $rb) => {
+                    const $inner: u128 = digits_format($rr, $rb);
+                    $body
+                },
+            )*
+            _ => {
+                const $inner: u128 = DECIMAL;
+                $body
+            },
+        }
+    };
+}
+
+/// Check if the format and options are valid for writing floats.
+#[inline]
+pub fn is_valid_format(format: &Format, options: &Options) -> bool {
+    let radix = format.radix();
+    let exponent_base = format.exponent_base();
+    let is_valid_radix = if cfg!(feature = "power-of-two") {
+        radix == exponent_base
+            || matches!((radix, exponent_base), (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4))
+    } else {
+        radix == exponent_base
+    };
+    is_valid_radix
+        && is_valid_float_radix(radix)
+        && is_valid_float_radix(exponent_base)
+        && is_valid_float_radix(format.exponent_radix())
+        && is_valid_options_punctuation(
+            format.packed(),
+            options.exponent(),
+            options.decimal_point(),
+        )
+}
+
+/// Write a float to the buffer, returning the number of written bytes.
+///
+/// # Panics
+///
+/// Panics if the format is not valid for floats, see [`is_valid_format`],
+/// or if the buffer is smaller than [`WriteOptions::buffer_size_with_format`].
+pub fn write_float<F>(float: F, bytes: &mut [u8], format: &Format, options: &Options) -> usize
+where
+    F: WriteFloat + FormattedSize,
+    F::Unsigned: FormattedSize + WriteInteger,
+{
+    assert!(is_valid_format(format, options));
+    assert!(
+        bytes.len() >= options.buffer_size_with_format::<F>(format),
+        "Buffer is too small: may overwrite buffer, panicking!"
+    );
+
+    // Write without any of the grammar applied after the digits are
+    // written, and use the leading space as a placeholder for a sign.
+    let mut inner = options.clone();
+    // SAFETY: the options are only used to write the float, and the
+    // buffer can hold the float written with the original options.
+    unsafe {
+        inner.set_min_width(None);
+        inner.set_digit_grouping(None);
+        inner.set_secondary_digit_grouping(None);
+        inner.set_min_exponent_digits(None);
+        inner.set_base_prefix(false);
+        if format.required_mantissa_sign() {
+            inner.set_space_positive(true);
+        }
+        if format.required_integer_digits() {
+            inner.set_trim_integer_zero(false);
+        }
+        if format.no_exponent_notation() {
+            inner.set_positive_exponent_break(NonZeroI32::new(i32::MAX));
+            inner.set_negative_exponent_break(NonZeroI32::new(i32::MIN));
+            inner.set_subnormal_scientific(false);
+        } else if format.required_exponent_notation() {
+            inner.set_negative_exponent_break(NonZeroI32::new(i32::MAX));
+        }
+    }
+
+    let radix = format.radix();
+    let (count, exponent_radix) = with_digits_format!(radix, format.exponent_base(), INNER => {
+        // SAFETY: safe since the buffer can hold the float written with the original options.
+        let count = unsafe { float.write_float::<INNER>(bytes, &inner) };
+        (count, NumberFormat::<INNER>::EXPONENT_RADIX)
+    });
+    if format.required_mantissa_sign() && bytes[0] == b' ' {
+        bytes[0] = b'+';
+    }
+
+    let is_special = float.is_special();
+    let mut count = count;
+    if !is_special {
+        count = write_exponent(bytes, count, exponent_radix, format, options);
+    }
+
+    // Write the base prefix after the sign, except for special values.
+    let uppercase = options.uppercase_base_prefix() && !format.case_sensitive_base_prefix();
+    let prefix = base_prefix_char(format.base_prefix(), radix, uppercase);
+    let has_prefix =
+        (options.base_prefix() || format.required_base_prefix()) && prefix != 0 && !is_special;
+    if has_prefix {
+        // SAFETY: safe since `count > 0`.
+        let start = unsafe { digits_start_with(bytes, false) };
+        bytes.copy_within(start..count, start + 2);
+        bytes[start] = b'0';
+        bytes[start + 1] = prefix;
+        count += 2;
+    }
+
+    // Write the base suffix before any closing parenthesis, except for special values.
+    if format.required_base_suffix() && !is_special {
+        let end = count - (bytes[count - 1] == b')') as usize;
+        bytes.copy_within(end..count, end + 1);
+        bytes[end] = base_suffix_char(format.base_suffix(), radix, false);
+        count += 1;
+    }
+
+    let separator = format.digit_separator();
+    // SAFETY: safe since the buffer can hold the separators and the padding.
+    unsafe {
+        let count = group_integer_digits_with(
+            bytes, count, is_special, separator, radix, has_prefix, options,
+        );
+        pad_width_with(bytes, count, is_special, has_prefix, options)
+    }
+}
+
+/// Rewrite the exponent using the exponent sign and radix of the format.
+///
+/// The exponent written by the const generic algorithms is parsed
+/// using `exponent_radix`, and written with the required sign and the
+/// minimum exponent digits. A decimal point is also added to the
+/// significant digits if exponents without a fraction are not allowed.
+fn write_exponent(
+    bytes: &mut [u8],
+    count: usize,
+    exponent_radix: u32,
+    format: &Format,
+    options: &Options,
+) -> usize {
+    // The exponent digits may contain the exponent character, but the
+    // significant digits cannot, so the first match is the exponent.
+    let exponent = match bytes[..count].iter().position(|&c| c == options.exponent()) {
+        Some(index) => index,
+        None => return count,
+    };
+
+    // Trimmed floats are written as `1e5`, which requires a fraction.
+    let mut count = count;
+    let mut exponent = exponent;
+    let decimal_point = options.decimal_point();
+    if format.no_exponent_without_fraction() && !bytes[..exponent].contains(&decimal_point) {
+        let zeros = min_exact_digits(1, options).max(2) - 1;
+        bytes.copy_within(exponent..count, exponent + 1 + zeros);
+        bytes[exponent] = decimal_point;
+        for byte in bytes[exponent + 1..exponent + 1 + zeros].iter_mut() {
+            *byte = b'0';
+        }
+        exponent += 1 + zeros;
+        count += 1 + zeros;
+    }
+
+    // Parse the written exponent.
+    let mut index = exponent + 1;
+    let is_negative = bytes[index] == b'-';
+    if is_negative {
+        index += 1;
+    }
+    let mut value: u32 = 0;
+    while index < count {
+        match char_to_digit_const(bytes[index], exponent_radix) {
+            Some(digit) => value = value * exponent_radix + digit,
+            None => break,
+        }
+        index += 1;
+    }
+
+    // Write the sign and padded digits, after moving any closing parenthesis.
+    let radix = format.exponent_radix();
+    let mut digits: usize = 1;
+    let mut remaining = value;
+    while remaining >= radix {
+        remaining /= radix;
+        digits += 1;
+    }
+    let width = digits.max(options.min_exponent_digits().map_or(0, |x| x.get()));
+    let sign = if is_negative {
+        Some(b'-')
+    } else if format.required_exponent_sign() {
+        Some(b'+')
+    } else {
+        None
+    };
+    let start = exponent + 1 + sign.is_some() as usize;
+    let end = start + width;
+    bytes.copy_within(index..count, end);
+    if let Some(sign) = sign {
+        bytes[exponent + 1] = sign;
+    }
+    for byte in bytes[start..end].iter_mut().rev() {
+        *byte = digit_to_char_const(value % radix, radix);
+        value /= radix;
+    }
+
+    end + count - index
+}
@@ -1,9 +1,13 @@
 //! Shared utilities for writing floats.
 
-use crate::options::{Options, RoundMode};
-use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
+use crate::options::{Alignment, Options, RoundMode};
+use core::cmp;
+use lexical_util::digit::{char_is_digit_const, char_to_valid_digit_const, digit_to_char_const};
 use lexical_util::format::NumberFormat;
-use lexical_write_integer::write::WriteInteger;
+use lexical_util::num::{AsPrimitive, Float};
+use lexical_write_integer::write::{
+    base_prefix, base_suffix, digit_separators, group_digits, WriteInteger,
+};
 
 /// Get the exact number of digits from a minimum bound.
 #[inline(always)]
@@ -52,6 +56,49 @@ pub unsafe fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, b
     (1, true)
 }
 
+/// Round-to-odd the last digit, from a buffer of digits.
+///
+/// If any of the truncated digits in `digits[count..end]` are non-zero,
+/// and the truncated value is even, round it up by 1 unit in the last
+/// place. In an odd radix, every power of the radix is odd, so the parity
+/// of the value is the parity of the sum of its digits, and rounding up
+/// may carry. Returns the digit count and if the input carried.
+///
+/// # Safety
+///
+/// Safe as long as `0 < count <= end <= digits.len()`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub unsafe fn round_to_odd(
+    digits: &mut [u8],
+    count: usize,
+    end: usize,
+    radix: u32,
+) -> (usize, bool) {
+    debug_assert!(count > 0 && count <= end && end <= digits.len());
+
+    // SAFETY: safe if `count <= end <= digits.len()`.
+    let truncated = unsafe { &index_unchecked!(digits[count..end]) };
+    if truncated.iter().all(|&x| x == b'0') {
+        return (count, false);
+    }
+
+    let is_odd = if radix % 2 == 1 {
+        // SAFETY: safe since `count <= digits.len()`.
+        let kept = unsafe { &index_unchecked!(digits[..count]) };
+        kept.iter().fold(0, |acc, &c| acc ^ (char_to_valid_digit_const(c, radix) & 1)) == 1
+    } else {
+        // SAFETY: safe since `count > 0 && count <= digits.len()`.
+        let c = unsafe { index_unchecked!(digits[count - 1]) };
+        char_to_valid_digit_const(c, radix) & 1 == 1
+    };
+    if is_odd {
+        (count, false)
+    } else {
+        // SAFETY: safe since `count <= digits.len()`.
+        unsafe { round_up(digits, count, radix) }
+    }
+}
+
 /// Round the number of digits based on the maximum digits, for decimal digits.
 /// `digits` is a mutable buffer of the current digits, `digit_count` is the
 /// length of the written digits in `digits`, and `exp` is the decimal exponent
@@ -80,12 +127,18 @@ pub unsafe fn truncate_and_round_decimal(
     }
 
     // Check if we're truncating, if so, shorten the digits in the input.
-    if options.round_mode() == RoundMode::Truncate {
+    match options.round_mode() {
         // Don't round input, just shorten number of digits emitted.
-        return (max_digits, false);
+        RoundMode::Truncate => return (max_digits, false),
+        // SAFETY: safe since `0 < max_digits < digit_count <= digits.len()`.
+        RoundMode::RoundToOdd => {
+            return unsafe { round_to_odd(digits, max_digits, digit_count, 10) }
+        },
+        _ => (),
     }
+    let tie_up = options.round_mode() == RoundMode::RoundHalfAway;
 
-    // We need to round-nearest, tie-even, so we need to handle
+    // We need to round-nearest, tie-even or tie-away, so we need to handle
     // the truncation **here**. If the representation is above
     // halfway at all, we need to round up, even if 1 digit.
 
@@ -108,7 +161,7 @@ pub unsafe fn truncate_and_round_decimal(
             let is_above = index_unchecked!(to_round[2..]).iter().any(|&x| x != b'0');
             (is_odd, is_above)
         };
-        if is_odd || is_above {
+        if is_odd || is_above || tie_up {
             // SAFETY: safe if `digit_count <= digits.len()`, because `max_digits < digit_count`.
             unsafe { round_up(digits, max_digits, 10) }
         } else {
@@ -119,6 +172,452 @@ pub unsafe fn truncate_and_round_decimal(
     (digits, carried)
 }
 
+/// Number of limbs required to compare a float exactly to its decimal digits.
+///
+/// The largest intermediate is ~850 bits, for the smallest denormal `f64`
+/// scaled by `5^343`.
+const EXACT_LIMBS: usize = 16;
+
+/// Minimal, fixed-width big integer to compare a float to decimal digits.
+///
+/// The limbs are stored in little-endian order.
+struct ExactInt {
+    data: [u64; EXACT_LIMBS],
+}
+
+impl ExactInt {
+    /// Create a big integer from a native integer.
+    #[inline(always)]
+    fn new(value: u64) -> Self {
+        let mut data = [0u64; EXACT_LIMBS];
+        data[0] = value;
+        Self {
+            data,
+        }
+    }
+
+    /// Multiply by a small integer, in-place.
+    #[inline]
+    fn mul_small(&mut self, y: u64) {
+        let mut carry: u64 = 0;
+        for limb in self.data.iter_mut() {
+            let z = (*limb as u128) * (y as u128) + (carry as u128);
+            *limb = z as u64;
+            carry = (z >> 64) as u64;
+        }
+        debug_assert!(carry == 0);
+    }
+
+    /// Multiply by a power of 5, in-place.
+    #[inline]
+    fn mul_pow5(&mut self, mut exp: u32) {
+        // 5^27 is the largest power of 5 that fits in a u64.
+        const LARGE_POW5: u64 = 7450580596923828125;
+        while exp >= 27 {
+            self.mul_small(LARGE_POW5);
+            exp -= 27;
+        }
+        self.mul_small(5u64.pow(exp));
+    }
+
+    /// Shift-left by a number of bits, in-place.
+    #[inline]
+    fn shl(&mut self, n: u32) {
+        let limbs = (n / 64) as usize;
+        let bits = n % 64;
+        debug_assert!(limbs < EXACT_LIMBS);
+        if limbs != 0 {
+            self.data.copy_within(..EXACT_LIMBS - limbs, limbs);
+            for limb in self.data[..limbs].iter_mut() {
+                *limb = 0;
+            }
+        }
+        if bits != 0 {
+            let mut prev: u64 = 0;
+            for limb in self.data[limbs..].iter_mut() {
+                let next = *limb >> (64 - bits);
+                *limb = (*limb << bits) | prev;
+                prev = next;
+            }
+            debug_assert!(prev == 0);
+        }
+    }
+
+    /// Compare two big integers.
+    #[inline]
+    fn compare(&self, other: &Self) -> cmp::Ordering {
+        self.data.iter().rev().cmp(other.data.iter().rev())
+    }
+}
+
+/// Compare the exact value of a float to `mantissa * 10^exponent`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn compare_exact<F: Float>(float: F, mantissa: u64, exponent: i32) -> cmp::Ordering {
+    // We want to compare `m * 2^e` to `mantissa * 5^exponent * 2^exponent`,
+    // so scale both sides to integers.
+    let mut lhs = ExactInt::new(float.mantissa().as_u64());
+    let mut rhs = ExactInt::new(mantissa);
+    if exponent >= 0 {
+        rhs.mul_pow5(exponent as u32);
+    } else {
+        lhs.mul_pow5(exponent.unsigned_abs());
+    }
+    let shift = float.exponent() - exponent;
+    if shift >= 0 {
+        lhs.shl(shift as u32);
+    } else {
+        rhs.shl(shift.unsigned_abs());
+    }
+    lhs.compare(&rhs)
+}
+
+/// Adjust the shortest digits so halfway cases round using the exact value.
+///
+/// Rounding the shortest digits to `max_significant_digits` only differs
+/// from rounding the exact value if the shortest digits have exactly one
+/// extra digit, which is a `5`: any other rounding boundary would itself
+/// be a shorter representation. If so, nudge the last digit to a `4` or `6`
+/// if the float is below or above the halfway point, respectively. The
+/// `mantissa` must not have any trailing zeros.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_exact_halfway<F: Float>(
+    float: F,
+    mantissa: u64,
+    exponent: i32,
+    options: &Options,
+) -> u64 {
+    let is_exact = options.exact_rounding() || options.exact_digits();
+    let max_digits = match options.max_significant_digits() {
+        Some(digits) if is_exact && digits.get() < 19 => digits.get() as u32,
+        _ => return mantissa,
+    };
+    let is_halfway = mantissa % 10 == 5
+        && mantissa >= 10u64.pow(max_digits)
+        && mantissa < 10u64.pow(max_digits + 1);
+    if !is_halfway {
+        return mantissa;
+    }
+    match compare_exact(float, mantissa, exponent) {
+        cmp::Ordering::Less => mantissa - 1,
+        cmp::Ordering::Greater => mantissa + 1,
+        cmp::Ordering::Equal => mantissa,
+    }
+}
+
+/// Round the exact value of a float to `max_significant_digits`.
+///
+/// This is required if the shortest digits have fewer digits than
+/// `max_significant_digits`, since the exact value may have non-zero
+/// digits after the shortest digits, IE, `5e-324` is exactly
+/// `4.940656458412465...e-324`. The digits are also checked if they
+/// have exactly `max_significant_digits`, since digit generation
+/// algorithms may not produce the closest representation. The exact digits must fit in the native
+/// mantissa, so this is limited to 9 digits for `f32` and 17 for `f64`.
+/// The `mantissa` must not have any trailing zeros, and the rounded
+/// mantissa does not have any trailing zeros.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_exact_digits<F: Float>(
+    float: F,
+    mantissa: u64,
+    exponent: i32,
+    options: &Options,
+) -> (u64, i32) {
+    let limit = if F::BITS <= 32 {
+        9
+    } else {
+        17
+    };
+    let max_digits = match options.max_significant_digits() {
+        Some(digits) if options.exact_digits() && digits.get() <= limit => digits.get() as u32,
+        _ => return (mantissa, exponent),
+    };
+    let mut digit_count: u32 = 1;
+    while digit_count <= max_digits && mantissa >= 10u64.pow(digit_count) {
+        digit_count += 1;
+    }
+    if mantissa == 0 || digit_count > max_digits {
+        return (mantissa, exponent);
+    }
+
+    // Pad the shortest digits to the number of significant digits: if the
+    // exact value is within half a unit of them, they are correctly rounded.
+    let scale = max_digits - digit_count;
+    let padded = mantissa * 10u64.pow(scale);
+    let mut exp = exponent - scale as i32;
+    let is_below = compare_exact(float, padded * 10 - 5, exp - 1) != cmp::Ordering::Greater;
+    let is_above = compare_exact(float, padded * 10 + 5, exp - 1) != cmp::Ordering::Less;
+    if !is_below && !is_above {
+        return (mantissa, exponent);
+    }
+
+    // Find the largest mantissa with `max_digits` digits not above the
+    // exact value. The shortest digits may be in the adjacent decade,
+    // IE, `1e23` is exactly `9.999999999999999161...e22`.
+    let min_mantissa = 10u64.pow(max_digits - 1);
+    let max_mantissa = 10u64.pow(max_digits);
+    if compare_exact(float, min_mantissa, exp) == cmp::Ordering::Less {
+        exp -= 1;
+    } else if compare_exact(float, max_mantissa, exp) != cmp::Ordering::Less {
+        exp += 1;
+    }
+    let mut lo = min_mantissa;
+    let mut hi = max_mantissa;
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if compare_exact(float, mid, exp) == cmp::Ordering::Less {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    // Round the truncated digits using the exact value.
+    let halfway = compare_exact(float, lo * 10 + 5, exp - 1);
+    let is_exact = compare_exact(float, lo, exp) == cmp::Ordering::Equal;
+    let is_odd = lo & 1 == 1;
+    let round_up = match options.round_mode() {
+        RoundMode::Round => {
+            halfway == cmp::Ordering::Greater || (halfway == cmp::Ordering::Equal && is_odd)
+        },
+        RoundMode::RoundHalfAway => halfway != cmp::Ordering::Less,
+        RoundMode::Truncate => false,
+        // An even last digit is never a `9`, so this never carries.
+        RoundMode::RoundToOdd => !is_exact && !is_odd,
+    };
+    let (mut mantissa, mut exp) = if !round_up {
+        (lo, exp)
+    } else if lo + 1 == max_mantissa {
+        (min_mantissa, exp + 1)
+    } else {
+        (lo + 1, exp)
+    };
+    while mantissa % 10 == 0 {
+        mantissa /= 10;
+        exp += 1;
+    }
+    (mantissa, exp)
+}
+
+/// Determine if a float is subnormal.
+///
+/// Zero has a denormal representation, but is not subnormal.
+#[inline(always)]
+pub fn is_subnormal<F: Float>(float: F) -> bool {
+    float.is_denormal() && float != F::ZERO
+}
+
+/// Pad the written float to the minimum width.
+///
+/// Returns the number of bytes written, including the padding.
+///
+/// # Safety
+///
+/// Safe if `bytes` can hold `min_width` elements and `count <= bytes.len()`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub unsafe fn pad_width<const FORMAT: u128>(
+    bytes: &mut [u8],
+    count: usize,
+    is_special: bool,
+    options: &Options,
+) -> usize {
+    let has_prefix = has_base_prefix::<FORMAT>(options);
+    // SAFETY: safe if `bytes` can hold `min_width` elements.
+    unsafe { pad_width_with(bytes, count, is_special, has_prefix, options) }
+}
+
+/// Pad the written float to the minimum width, for a float written
+/// with or without a base prefix.
+///
+/// Returns the number of bytes written, including the padding.
+///
+/// # Safety
+///
+/// Safe if `bytes` can hold `min_width` elements and `count <= bytes.len()`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub unsafe fn pad_width_with(
+    bytes: &mut [u8],
+    count: usize,
+    is_special: bool,
+    has_prefix: bool,
+    options: &Options,
+) -> usize {
+    let width = match options.min_width() {
+        Some(width) if width.get() > count => width.get(),
+        _ => return count,
+    };
+    let padding = width - count;
+    debug_assert!(width <= bytes.len());
+
+    if options.fill() == b'0' && !is_special {
+        // Write the zeros after the sign and base prefix, ignoring the alignment.
+        // SAFETY: safe since `count > 0`.
+        let sign = unsafe { digits_start_with(bytes, has_prefix) };
+        bytes.copy_within(sign..count, sign + padding);
+        // SAFETY: safe if `width <= bytes.len()`.
+        unsafe {
+            slice_fill_unchecked!(index_unchecked_mut!(bytes[sign..sign + padding]), b'0');
+        }
+        return width;
+    }
+
+    // Special values are never padded with zeros.
+    let fill = if options.fill() == b'0' {
+        b' '
+    } else {
+        options.fill()
+    };
+    match options.alignment() {
+        // SAFETY: safe if `width <= bytes.len()`.
+        Alignment::Left => unsafe {
+            slice_fill_unchecked!(index_unchecked_mut!(bytes[count..width]), fill);
+        },
+        Alignment::Right => {
+            bytes.copy_within(..count, padding);
+            // SAFETY: safe if `width <= bytes.len()`.
+            unsafe {
+                slice_fill_unchecked!(index_unchecked_mut!(bytes[..padding]), fill);
+            }
+        },
+    }
+    width
+}
+
+/// Get the base prefix character written before the digits, or 0 if
+/// the float is written without a base prefix.
+///
+/// Required base prefixes are always written, and case-sensitive
+/// ones are never written in uppercase.
+#[inline(always)]
+pub fn written_base_prefix<const FORMAT: u128>(options: &Options) -> u8 {
+    let format = NumberFormat::<FORMAT> {};
+    let required = cfg!(feature = "format") && format.required_base_prefix();
+    let case_sensitive = cfg!(feature = "format") && format.case_sensitive_base_prefix();
+    if options.base_prefix() || required {
+        base_prefix::<FORMAT>(options.uppercase_base_prefix() && !case_sensitive)
+    } else {
+        0
+    }
+}
+
+/// Get the base suffix character written after the digits, or 0 if
+/// the float is written without a base suffix.
+///
+/// Floats are only written with a base suffix if the format requires one.
+#[inline(always)]
+pub fn written_base_suffix<const FORMAT: u128>() -> u8 {
+    let format = NumberFormat::<FORMAT> {};
+    if cfg!(feature = "format") && format.required_base_suffix() {
+        base_suffix::<FORMAT>(false)
+    } else {
+        0
+    }
+}
+
+/// Determine if the float is written with a base prefix.
+#[inline(always)]
+pub fn has_base_prefix<const FORMAT: u128>(options: &Options) -> bool {
+    written_base_prefix::<FORMAT>(options) != 0
+}
+
+/// Get the index of the first significant digit, after any sign and base prefix.
+///
+/// # Safety
+///
+/// Safe if `bytes` is not empty. The float must not be a special value.
+#[inline(always)]
+pub unsafe fn digits_start<const FORMAT: u128>(bytes: &[u8], options: &Options) -> usize {
+    // SAFETY: safe since `bytes.len() > 0`.
+    unsafe { digits_start_with(bytes, has_base_prefix::<FORMAT>(options)) }
+}
+
+/// Get the index of the first significant digit, after any sign and a
+/// base prefix, if the float is written with one.
+///
+/// # Safety
+///
+/// Safe if `bytes` is not empty. The float must not be a special value.
+#[inline(always)]
+pub unsafe fn digits_start_with(bytes: &[u8], has_prefix: bool) -> usize {
+    // SAFETY: safe since `bytes.len() > 0`.
+    let sign = match unsafe { index_unchecked!(bytes[0]) } {
+        b'+' | b'-' | b' ' | b'(' => 1,
+        _ => 0,
+    };
+    if has_prefix {
+        sign + 2
+    } else {
+        sign
+    }
+}
+
+/// Insert digit separators between groups of integer digits.
+///
+/// The digit separator is taken from the number format, and only the
+/// leading integer digits, after any sign and base prefix, are grouped. Returns the
+/// number of bytes written, including the separators.
+///
+/// # Safety
+///
+/// Safe if `bytes` can hold the digit separators and `count <= bytes.len()`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub unsafe fn group_integer_digits<const FORMAT: u128>(
+    bytes: &mut [u8],
+    count: usize,
+    is_special: bool,
+    options: &Options,
+) -> usize {
+    let separator = NumberFormat::<FORMAT>::DIGIT_SEPARATOR;
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let has_prefix = has_base_prefix::<FORMAT>(options);
+    // SAFETY: safe if `bytes` can hold the digit separators.
+    unsafe {
+        group_integer_digits_with(bytes, count, is_special, separator, radix, has_prefix, options)
+    }
+}
+
+/// Insert digit separators between groups of integer digits, using the
+/// digit separator and radix of a runtime number format.
+///
+/// Returns the number of bytes written, including the separators.
+///
+/// # Safety
+///
+/// Safe if `bytes` can hold the digit separators and `count <= bytes.len()`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub unsafe fn group_integer_digits_with(
+    bytes: &mut [u8],
+    count: usize,
+    is_special: bool,
+    separator: u8,
+    radix: u32,
+    has_prefix: bool,
+    options: &Options,
+) -> usize {
+    let primary = match options.digit_grouping() {
+        Some(primary) if separator != 0 && !is_special => primary.get(),
+        _ => return count,
+    };
+    let secondary = options.secondary_digit_grouping().map_or(primary, |x| x.get());
+
+    // SAFETY: safe since `count > 0`.
+    let start = unsafe { digits_start_with(bytes, has_prefix) };
+    // SAFETY: safe since `start <= count <= bytes.len()`.
+    let digits = unsafe { &index_unchecked!(bytes[start..count]) }
+        .iter()
+        .take_while(|&&c| char_is_digit_const(c, radix))
+        .count();
+    let separators = digit_separators(digits, primary, secondary);
+    if separators == 0 {
+        return count;
+    }
+
+    // Shift the fraction and exponent, then group the integer digits.
+    let end = start + digits;
+    bytes.copy_within(end..count, end + separators);
+    group_digits(&mut bytes[start..], digits, separator, primary, secondary);
+    count + separators
+}
+
 /// Write the sign for the exponent.
 ///
 /// # Safety
@@ -149,21 +648,40 @@ pub unsafe fn write_exponent_sign<const FORMAT: u128>(
 
 /// Write the symbol, sign, and digits for the exponent.
 ///
+/// The exponent digits are padded with leading zeros to at least
+/// `min_exponent_digits`, if provided.
+///
 /// # Safety
 ///
-/// Safe if the buffer can hold all the significant digits and the sign
-/// starting from cursor.
+/// Safe if the buffer can hold all the significant digits, the sign,
+/// and the padded exponent digits starting from cursor.
 #[cfg_attr(not(feature = "compact"), inline)]
 pub unsafe fn write_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
-    exponent_character: u8,
+    options: &Options,
 ) {
+    let format = NumberFormat::<{ FORMAT }> {};
     *cursor += unsafe {
-        index_unchecked_mut!(bytes[*cursor]) = exponent_character;
+        index_unchecked_mut!(bytes[*cursor]) = options.exponent();
         *cursor += 1;
         let positive_exp = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
+        if let Some(min_digits) = options.min_exponent_digits() {
+            // Count the exponent digits, and pad with leading zeros.
+            let radix = format.exponent_radix();
+            let mut digits: usize = 1;
+            let mut value = positive_exp;
+            while value >= radix {
+                value /= radix;
+                digits += 1;
+            }
+            while digits < min_digits.get() {
+                index_unchecked_mut!(bytes[*cursor]) = b'0';
+                *cursor += 1;
+                digits += 1;
+            }
+        }
         positive_exp.write_exponent::<u32, FORMAT>(&mut index_unchecked_mut!(bytes[*cursor..]))
     };
 }
@@ -172,6 +690,7 @@ pub unsafe fn write_exponent<const FORMAT: u128>(
 macro_rules! write_float {
     (
         $format:ident,
+        $float:ident,
         $sci_exp:ident,
         $options:ident,
         $write_scientific:ident,
@@ -187,7 +706,10 @@ macro_rules! write_float {
         let max_exp = $options.positive_exponent_break().map_or(9, |x| x.get());
 
         let outside_break = $sci_exp < min_exp || $sci_exp > max_exp;
-        let require_exponent = format.required_exponent_notation() || outside_break;
+        let is_subnormal = crate::shared::is_subnormal($float);
+        let force_subnormal = $options.subnormal_scientific() && is_subnormal;
+        let require_exponent =
+            format.required_exponent_notation() || outside_break || force_subnormal;
         if !format.no_exponent_notation() && require_exponent {
             // Write digits in scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
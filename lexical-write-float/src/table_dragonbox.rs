@@ -4,7 +4,7 @@
 //! of a float to the decimal exponent, with minimal rounding
 //! errors, in a 128 or 192-bit representation.
 //!
-//! DO NOT MODIFY: Generated by `lexical-parse-float/etc/lemire_table.py`
+//! DO NOT MODIFY: Generated by `etc/dragonbox_table.py`
 //!
 //! This adapted from the Rust implementation, based on the fast-float-rust
 //! implementation, and is similarly subject to an Apache2.0/MIT license.
@@ -107,6 +107,7 @@ pub const DRAGONBOX32_POWERS_OF_FIVE: [u64; N32_POWERS_OF_FIVE] = [
 /// This is very similar to the Lemire table, however, a few slight
 /// differences exist: this goes from `5^-292` to `5^326`, a different
 /// range than Lemire which goes from `5^-342 to 5^308`.
+#[cfg(not(feature = "wasm"))]
 pub const DRAGONBOX64_POWERS_OF_FIVE: [(u64, u64); N64_POWERS_OF_FIVE] = [
     (0xff77b1fcbebcdc4f, 0x25e8e89c13bb0f7b), // 5^-292
     (0x9faacf3df73609b1, 0x77b191618c54e9ad), // 5^-291
@@ -728,3 +729,93 @@ pub const DRAGONBOX64_POWERS_OF_FIVE: [(u64, u64); N64_POWERS_OF_FIVE] = [
     (0xc5a05277621be293, 0xc7098b7305241886), // 5^325
     (0xf70867153aa2db38, 0xb8cbee4fc66d1ea8), // 5^326
 ];
+
+/// The number of powers of five between each compressed power.
+#[cfg(feature = "wasm")]
+pub const DRAGONBOX64_STEP: usize = 27;
+
+/// Every 27th power of five for f64, to recover the other powers.
+#[cfg(feature = "wasm")]
+pub const DRAGONBOX64_COMPRESSED_POWERS_OF_FIVE: [(u64, u64); 23] = [
+    (0xff77b1fcbebcdc4f, 0x25e8e89c13bb0f7b), // 5^-292
+    (0xce5d73ff402d98e3, 0xfb0a3d212dc81290), // 5^-265
+    (0xa6b34ad8c9dfc06f, 0xf42faa48c0ea481f), // 5^-238
+    (0x86a8d39ef77164bc, 0xae5dff9c02033198), // 5^-211
+    (0xd98ddaee19068c76, 0x3badd624dd9b0958), // 5^-184
+    (0xafbd2350644eeacf, 0xe5d1929ef90898fb), // 5^-157
+    (0x8df5efabc5979c8f, 0xca8d3ffa1ef463c2), // 5^-130
+    (0xe55990879ddcaabd, 0xcc420a6a101d0516), // 5^-103
+    (0xb94470938fa89bce, 0xf808e40e8d5b3e6a), // 5^-76
+    (0x95a8637627989aad, 0xdde7001379a44aa9), // 5^-49
+    (0xf1c90080baf72cb1, 0x5324c68b12dd6339), // 5^-22
+    (0xc350000000000000, 0x0000000000000000), // 5^5
+    (0x9dc5ada82b70b59d, 0xf020000000000000), // 5^32
+    (0xfee50b7025c36a08, 0x02f236d04753d5b5), // 5^59
+    (0xcde6fd5e09abcf26, 0xed4c0226b55e6f87), // 5^86
+    (0xa6539930bf6bff45, 0x84db8346b786151d), // 5^113
+    (0x865b86925b9bc5c2, 0x0b8a2392ba45a9b3), // 5^140
+    (0xd910f7ff28069da4, 0x1b2ba1518094da05), // 5^167
+    (0xaf58416654a6babb, 0x387ac8d1970027b3), // 5^194
+    (0x8da471a9de737e24, 0x5ceaecfed289e5d3), // 5^221
+    (0xe4d5e82392a40515, 0x0fabaf3feaa5334b), // 5^248
+    (0xb8da1662e7b00a17, 0x3d6a751f3b936244), // 5^275
+    (0x95527a5202df0ccb, 0x0f37801e0c43ebc9), // 5^302
+];
+
+/// The small powers of five to recover the other powers.
+#[cfg(feature = "wasm")]
+pub const DRAGONBOX64_SMALL_POWERS_OF_FIVE: [u64; DRAGONBOX64_STEP] = [
+    1,
+    5,
+    25,
+    125,
+    625,
+    3125,
+    15625,
+    78125,
+    390625,
+    1953125,
+    9765625,
+    48828125,
+    244140625,
+    1220703125,
+    6103515625,
+    30517578125,
+    152587890625,
+    762939453125,
+    3814697265625,
+    19073486328125,
+    95367431640625,
+    476837158203125,
+    2384185791015625,
+    11920928955078125,
+    59604644775390625,
+    298023223876953125,
+    1490116119384765625,
+];
+
+/// The difference between the exact and recovered powers, plus 1,
+/// packed into 2 bits for each power.
+#[cfg(feature = "wasm")]
+pub const DRAGONBOX64_CORRECTIONS: [u64; 20] = [
+    0xa95aaa9aa965aa59,
+    0xaaaa996aaa5aa6aa,
+    0x50415106a5a6aaa6,
+    0x5965995aa5154114,
+    0x5a559556aa565559,
+    0xa6959a96a6aaa999,
+    0x6aa9aa9aaa695aaa,
+    0x5654516aa9aaaaaa,
+    0x5665696a95556955,
+    0x5555555555555559,
+    0x6a55555555555555,
+    0x9a9a9665699aaaa9,
+    0xa59996a9aaaaa6aa,
+    0x155555956965566a,
+    0xaa9a69aaa9556555,
+    0x5545455555865aa6,
+    0x6956aa96a9a695a4,
+    0xaa69656a695aaa66,
+    0x6a6a655aaaaaaaaa,
+    0x000000000026aaa9,
+];
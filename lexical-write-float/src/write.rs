@@ -16,12 +16,13 @@ use crate::radix;
 
 use crate::float::RawFloat;
 use crate::options::Options;
+use crate::shared::{written_base_prefix, written_base_suffix};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::constants::FormattedSize;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
-use lexical_util::format::NumberFormat;
+use lexical_util::format::{is_valid_float_radix, NumberFormat};
 use lexical_write_integer::write::WriteInteger;
 
 /// Write float trait.
@@ -57,6 +58,9 @@ pub trait WriteFloat: RawFloat {
         // Validate our format options.
         let format = NumberFormat::<FORMAT> {};
         assert!(format.is_valid());
+        assert!(is_valid_float_radix(format.mantissa_radix()));
+        assert!(is_valid_float_radix(format.exponent_base()));
+        assert!(is_valid_float_radix(format.exponent_radix()));
         // Avoid any false assumptions for 128-bit floats.
         assert!(Self::BITS <= 64);
 
@@ -70,20 +74,57 @@ pub trait WriteFloat: RawFloat {
             }
         }
 
-        let (float, count, bytes) = if self < Self::ZERO {
+        // Negative zero is only written with a sign if requested.
+        let is_negative = self < Self::ZERO
+            || (options.signed_zero() && self == Self::ZERO && self.is_sign_negative());
+        let (float, count, bytes) = if is_negative && options.parentheses_negative() {
+            // SAFETY: safe if `bytes.len() > 1`.
+            unsafe { index_unchecked_mut!(bytes[0]) = b'(' };
+            (-self, 1, unsafe { &mut index_unchecked_mut!(bytes[1..]) })
+        } else if is_negative {
             // SAFETY: safe if `bytes.len() > 1`.
             unsafe { index_unchecked_mut!(bytes[0]) = b'-' };
             (-self, 1, unsafe { &mut index_unchecked_mut!(bytes[1..]) })
-        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        } else if options.nan_sign() && self.is_nan() && self.is_sign_negative() {
+            // SAFETY: safe if `bytes.len() > 1`.
+            unsafe { index_unchecked_mut!(bytes[0]) = b'-' };
+            (self, 1, unsafe { &mut index_unchecked_mut!(bytes[1..]) })
+        } else if (cfg!(feature = "format") && format.required_mantissa_sign())
+            || (options.positive_inf_sign() && self.is_inf())
+        {
             // SAFETY: safe if `bytes.len() > 1`.
             unsafe { index_unchecked_mut!(bytes[0]) = b'+' };
             (self, 1, unsafe { &mut index_unchecked_mut!(bytes[1..]) })
+        } else if options.space_positive() {
+            // SAFETY: safe if `bytes.len() > 1`.
+            unsafe { index_unchecked_mut!(bytes[0]) = b' ' };
+            (self, 1, unsafe { &mut index_unchecked_mut!(bytes[1..]) })
         } else {
             (self, 0, bytes)
         };
+        // Negative zero written without a sign is positive zero.
+        let float = if float == Self::ZERO {
+            Self::ZERO
+        } else {
+            float
+        };
+
+        // Write the base prefix after the sign, except for special values.
+        let prefix = written_base_prefix::<FORMAT>(options);
+        let (count, bytes) = if prefix != 0 && !self.is_special() {
+            // SAFETY: safe if `bytes.len() > 2`.
+            unsafe {
+                index_unchecked_mut!(bytes[0]) = b'0';
+                index_unchecked_mut!(bytes[1]) = prefix;
+                (count + 2, &mut index_unchecked_mut!(bytes[2..]))
+            }
+        } else {
+            (count, bytes)
+        };
+        let start = count;
 
         // Handle special values.
-        if !self.is_special() {
+        let count = if !self.is_special() {
             #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
             {
                 // SAFETY: safe if the buffer can hold the significant digits
@@ -123,8 +164,9 @@ pub trait WriteFloat: RawFloat {
             }
         } else if self.is_nan() {
             // SAFETY: safe if the buffer is longer than the NaN string.
-            // The NaN string must be <= 50 characters, so safe as long as
-            // the options were build using safe methods.
+            // The NaN string must be <= 50 characters, and is accounted
+            // for in `buffer_size`, so safe as long as the options were
+            // built using safe methods.
             if let Some(nan_string) = options.nan_string() {
                 let length = nan_string.len();
                 unsafe {
@@ -140,8 +182,9 @@ pub trait WriteFloat: RawFloat {
         } else {
             // is_inf
             // SAFETY: safe if the buffer is longer than the Inf string.
-            // The Inf string must be <= 50 characters, so safe as long as
-            // the options were build using safe methods.
+            // The Inf string must be <= 50 characters, and is accounted
+            // for in `buffer_size`, so safe as long as the options were
+            // built using safe methods.
             if let Some(inf_string) = options.inf_string() {
                 let length = inf_string.len();
                 unsafe {
@@ -154,6 +197,44 @@ pub trait WriteFloat: RawFloat {
                 // PANIC: cannot serialize inf.
                 panic!("Inf explicitly disabled but asked to write Inf as string.");
             }
+        };
+
+        // Trim the integer zero before the decimal point, IE, `.5`, unless
+        // the format requires integer digits. Zero is always written as is.
+        let required = cfg!(feature = "format") && format.required_integer_digits();
+        let trim = options.trim_integer_zero() && !required && float != Self::ZERO;
+        let count = if trim && !self.is_special() {
+            // SAFETY: safe since `count - start` digits were written.
+            let digits = unsafe { &mut index_unchecked_mut!(bytes[..count - start]) };
+            if digits.len() > 1 && digits[0] == b'0' && digits[1] == options.decimal_point() {
+                digits.copy_within(1.., 0);
+                count - 1
+            } else {
+                count
+            }
+        } else {
+            count
+        };
+
+        // Write the base suffix after the digits, except for special values.
+        let suffix = written_base_suffix::<FORMAT>();
+        let count = if suffix != 0 && !self.is_special() {
+            // SAFETY: safe if the buffer can hold the base suffix, which
+            // is accounted for in `buffer_size`.
+            unsafe { index_unchecked_mut!(bytes[count - start]) = suffix };
+            count + 1
+        } else {
+            count
+        };
+
+        if is_negative && options.parentheses_negative() {
+            // SAFETY: safe if the buffer can hold the closing parenthesis,
+            // which is accounted for in `buffer_size`. `count` includes the
+            // opening parenthesis and base prefix, which are not part of `bytes`.
+            unsafe { index_unchecked_mut!(bytes[count - start]) = b')' };
+            count + 1
+        } else {
+            count
         }
     }
 }
@@ -99,6 +99,17 @@ fn issue84_test() {
     assert_eq!(lo, 3193382259244375);
 }
 
+#[test]
+fn umul128_test() {
+    let values = [0u64, 1, 3, 0xFFFF_FFFF, 1 << 63, 0x1234_5678_9abc_def0, u64::MAX];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            let product = x as u128 * y as u128;
+            assert_eq!(algorithm::umul128(x, y), ((product >> 64) as u64, product as u64));
+        }
+    }
+}
+
 #[test]
 fn pow32_test() {
     assert_eq!(algorithm::pow32(10, 1), 10);
@@ -635,6 +646,13 @@ fn f32_errors_test() {
     write_float::<_, DECIMAL>(2147483400.0f32, &options, "2147483400.0");
     write_float::<_, DECIMAL>(2147483500.0f32, &options, "2147483500.0");
     write_float::<_, DECIMAL>(2147483600.0f32, &options, "2147483600.0");
+
+    // Exact ties between two shortest representations must round to even.
+    write_float::<_, DECIMAL>(312985.12f32, &options, "312985.12");
+    write_float::<_, DECIMAL>(1595310.2f32, &options, "1595310.2");
+
+    // The left endpoint of the interval is an integer, and is included.
+    write_float::<_, DECIMAL>(77145604096.0f32, &options, "7.71456e10");
 }
 
 #[test]
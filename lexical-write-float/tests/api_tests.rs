@@ -3,8 +3,11 @@ use lexical_util::bf16::bf16;
 use lexical_util::constants::BUFFER_SIZE;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
-use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_util::options::WriteOptions;
+use lexical_write_float::{Alignment, Options, RoundMode, ToLexical, ToLexicalWithOptions};
 use proptest::prelude::*;
 use quickcheck::quickcheck;
 
@@ -49,6 +52,476 @@ fn special_test() {
     assert_eq!(actual, "Infinity");
 }
 
+#[test]
+fn custom_special_test() {
+    let options = Options::builder()
+        .nan_string(Some(b"null"))
+        .inf_string(Some(b"#DIV/0!"))
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = f64::NAN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"null");
+    let bytes = f64::NEG_INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(#DIV/0!)");
+
+    let long: &'static [u8] = b"not a number, or anything resembling a number!!!!";
+    let options = Options::builder().nan_string(Some(long)).build().unwrap();
+    let size = options.buffer_size::<f64, { STANDARD }>();
+    assert!(size > long.len());
+    let mut buffer = vec![b'\x00'; size];
+    let bytes = f64::NAN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, long);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn postgresql_string_test() {
+    use lexical_util::format::POSTGRESQL_STRING;
+    use lexical_write_float::options;
+
+    let options = options::POSTGRESQL_STRING;
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = f64::NAN.to_lexical_with_options::<POSTGRESQL_STRING>(&mut buffer, &options);
+    assert_eq!(bytes, b"NaN");
+    let bytes = f64::INFINITY.to_lexical_with_options::<POSTGRESQL_STRING>(&mut buffer, &options);
+    assert_eq!(bytes, b"Infinity");
+    let bytes =
+        f64::NEG_INFINITY.to_lexical_with_options::<POSTGRESQL_STRING>(&mut buffer, &options);
+    assert_eq!(bytes, b"-Infinity");
+}
+
+#[test]
+fn nan_sign_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let negative_nan = f64::from_bits(f64::NAN.to_bits() | (1 << 63));
+    let bytes = negative_nan.to_lexical(&mut buffer);
+    assert_eq!(bytes, b"NaN");
+
+    let options = Options::builder().nan_sign(true).build().unwrap();
+    let bytes = negative_nan.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-NaN");
+    let positive_nan = f64::from_bits(f64::NAN.to_bits() & !(1 << 63));
+    let bytes = positive_nan.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"NaN");
+    let negative_nan = f32::from_bits(f32::NAN.to_bits() | (1 << 31));
+    let bytes = negative_nan.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-NaN");
+}
+
+#[test]
+fn exact_rounding_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let max_digits = core::num::NonZeroUsize::new(1);
+    let shortest = Options::builder().max_significant_digits(max_digits).build().unwrap();
+    let exact =
+        Options::builder().max_significant_digits(max_digits).exact_rounding(true).build().unwrap();
+
+    // 0.15 is below halfway, 0.45 is above halfway, and 0.25 is exactly halfway.
+    let bytes = 0.15f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &shortest);
+    assert_eq!(bytes, b"0.2");
+    let bytes = 0.15f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.1");
+    let bytes = 0.45f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &shortest);
+    assert_eq!(bytes, b"0.4");
+    let bytes = 0.45f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.5");
+    let bytes = 0.25f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.2");
+    let bytes = 0.35f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.3");
+    let bytes = 5e-324f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"5.0e-324");
+    let bytes = 1.5e308f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"2.0e308");
+
+    // Shortest representations within the limit are unchanged.
+    let max_digits = core::num::NonZeroUsize::new(3);
+    let exact =
+        Options::builder().max_significant_digits(max_digits).exact_rounding(true).build().unwrap();
+    let bytes = 0.15f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.15");
+    let bytes = 1.2345f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"1.23");
+    let bytes = 2.675f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"2.67");
+
+    // The budget is at or above the shortest round-trip representation.
+    let max_digits = core::num::NonZeroUsize::new(17);
+    let exact =
+        Options::builder().max_significant_digits(max_digits).exact_rounding(true).build().unwrap();
+    let bytes = 0.1f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.1");
+    let bytes = 0.3f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.3");
+    let max_digits = core::num::NonZeroUsize::new(9);
+    let exact =
+        Options::builder().max_significant_digits(max_digits).exact_rounding(true).build().unwrap();
+    let bytes = 0.1f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"0.1");
+    let bytes = 1.1f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact);
+    assert_eq!(bytes, b"1.1");
+}
+
+#[test]
+fn exact_digits_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let exact = |max_digits| {
+        Options::builder()
+            .max_significant_digits(core::num::NonZeroUsize::new(max_digits))
+            .exact_digits(true)
+            .build()
+            .unwrap()
+    };
+
+    // The exact value is written, even if the shortest digits are shorter.
+    let bytes = 0.1f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact(17));
+    assert_eq!(bytes, b"0.10000000000000001");
+    let bytes = 0.1f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact(9));
+    assert_eq!(bytes, b"0.100000001");
+    let bytes = 5e-324f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact(3));
+    assert_eq!(bytes, b"4.94e-324");
+
+    // Halfway cases are rounded from the exact value.
+    let bytes = 0.15f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact(1));
+    assert_eq!(bytes, b"0.1");
+    let bytes = 2.675f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact(3));
+    assert_eq!(bytes, b"2.67");
+
+    // Exact values are unchanged.
+    let bytes = 0.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &exact(17));
+    assert_eq!(bytes, b"0.5");
+}
+
+#[test]
+fn round_mode_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = |mode| {
+        Options::builder()
+            .max_significant_digits(core::num::NonZeroUsize::new(2))
+            .round_mode(mode)
+            .build()
+            .unwrap()
+    };
+    let round = options(RoundMode::Round);
+    let truncate = options(RoundMode::Truncate);
+    let half_away = options(RoundMode::RoundHalfAway);
+    let to_odd = options(RoundMode::RoundToOdd);
+
+    let mut write = |f: f64, options: &Options| {
+        let bytes = f.to_lexical_with_options::<{ STANDARD }>(&mut buffer, options);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(write(1.25, &round), "1.2");
+    assert_eq!(write(1.25, &truncate), "1.2");
+    assert_eq!(write(1.25, &half_away), "1.3");
+    assert_eq!(write(1.25, &to_odd), "1.3");
+    assert_eq!(write(1.35, &round), "1.4");
+    assert_eq!(write(1.35, &half_away), "1.4");
+    assert_eq!(write(1.35, &to_odd), "1.3");
+    assert_eq!(write(1.201, &half_away), "1.2");
+    assert_eq!(write(1.201, &to_odd), "1.3");
+    assert_eq!(write(1.2, &to_odd), "1.2");
+    assert_eq!(write(-2.25, &half_away), "-2.3");
+    assert_eq!(write(-2.25, &to_odd), "-2.3");
+}
+
+#[test]
+fn subnormal_scientific_test() {
+    let negative_break = core::num::NonZeroI32::new(-400);
+    let options = Options::builder()
+        .negative_exponent_break(negative_break)
+        .subnormal_scientific(true)
+        .build()
+        .unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size::<f64, { STANDARD }>()];
+    let bytes = 5e-324f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"5.0e-324");
+    let bytes =
+        2.225073858507201e-308f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"2.225073858507201e-308");
+    let bytes = 1e-7f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.0000001");
+    let bytes = 0.0f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.0");
+
+    let options = Options::builder().negative_exponent_break(negative_break).build().unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size::<f64, { STANDARD }>()];
+    let bytes = 5e-324f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes.len(), 326);
+}
+
+#[test]
+fn min_exponent_digits_test() {
+    let options =
+        Options::builder().min_exponent_digits(core::num::NonZeroUsize::new(3)).build().unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size::<f64, { STANDARD }>()];
+    let bytes = 1e-7f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0e-007");
+    let bytes = 1.5e20f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5e020");
+    let bytes = 1e300f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0e300");
+    let bytes = 1.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5");
+}
+
+#[test]
+fn trim_scientific_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().trim_scientific(true).build().unwrap();
+    let bytes = 1e20f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1e20");
+    let bytes = 1.5e20f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5e20");
+    let bytes = 1.0f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0");
+}
+
+#[test]
+fn trim_integer_zero_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().trim_integer_zero(true).build().unwrap();
+    let bytes = 0.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b".5");
+    let bytes = (-0.25f32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-.25");
+    let bytes = 1.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5");
+    let bytes = 0.0f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.0");
+    let bytes = 1e-10f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0e-10");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn trim_integer_zero_required_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().required_integer_digits(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().trim_integer_zero(true).build().unwrap();
+    let bytes = 0.5f64.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.5");
+}
+
+#[test]
+fn signed_zero_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().signed_zero(true).build().unwrap();
+    let bytes = (-0.0f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-0.0");
+    let bytes = 0.0f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.0");
+    let bytes = (-0.0f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &Options::new());
+    assert_eq!(bytes, b"0.0");
+
+    let options = Options::builder().signed_zero(true).parentheses_negative(true).build().unwrap();
+    let bytes = (-0.0f32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(0.0)");
+}
+
+#[test]
+fn positive_inf_sign_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().positive_inf_sign(true).build().unwrap();
+    let bytes = f64::INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"+inf");
+    let bytes = f64::NEG_INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-inf");
+    let bytes = f64::NAN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"NaN");
+    let bytes = 1.5f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5");
+}
+
+#[test]
+fn min_width_test() {
+    let width = core::num::NonZeroUsize::new(8);
+    let options = Options::builder().min_width(width).build().unwrap();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = (-1.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"    -1.5");
+    let bytes = 1.2345678901f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.2345678901");
+
+    let options =
+        Options::builder().min_width(width).alignment(Alignment::Left).fill(b'*').build().unwrap();
+    let bytes = (-1.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-1.5****");
+    let bytes = f64::NAN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"NaN*****");
+
+    let options = Options::builder().min_width(width).fill(b'0').build().unwrap();
+    let bytes = (-1.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-00001.5");
+    let bytes = 1.5f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"000001.5");
+    let bytes = f64::NEG_INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"    -inf");
+
+    let options = Options::builder()
+        .min_width(width)
+        .fill(b'0')
+        .alignment(Alignment::Left)
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    let bytes = (-1.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(0001.5)");
+
+    let options = Options::builder().min_width(core::num::NonZeroUsize::new(400)).build().unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size::<f64, { STANDARD }>()];
+    let bytes = 1.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes.len(), 400);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn base_prefix_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .build();
+    let options = Options::builder().exponent(b'p').base_prefix(true).build().unwrap();
+    let mut buffer = [b'\x00'; 2 * BUFFER_SIZE];
+    let bytes = 3.0f64.to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0x3.0");
+    let bytes = (-0.5f64).to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-0x0.8");
+    let bytes = f64::NEG_INFINITY.to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-inf");
+    // Decimal floats have no base prefix.
+    let bytes = 3.0f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"3.0");
+
+    let options = options
+        .rebuild()
+        .uppercase_base_prefix(true)
+        .parentheses_negative(true)
+        .min_width(core::num::NonZeroUsize::new(12))
+        .fill(b'0')
+        .build()
+        .unwrap();
+    let bytes = (-0.5f64).to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(0X000000.8)");
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn required_base_affix_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .base_prefix(core::num::NonZeroU8::new(b'x'))
+        .base_suffix(core::num::NonZeroU8::new(b'h'))
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .build();
+    const OPTIONAL: u128 = NumberFormatBuilder::rebuild(HEX)
+        .required_base_prefix(false)
+        .required_base_suffix(false)
+        .build();
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let size = options.buffer_size::<f64, OPTIONAL>();
+    assert_eq!(options.buffer_size::<f64, HEX>(), size + 3);
+
+    let mut buffer = [b'\x00'; 2 * BUFFER_SIZE];
+    let bytes = 3.0f64.to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"0x3.0h");
+    let bytes = (-0.5f64).to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-0x0.8h");
+    let bytes = f64::NEG_INFINITY.to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-inf");
+
+    let options =
+        options.rebuild().uppercase_base_prefix(true).parentheses_negative(true).build().unwrap();
+    let bytes = (-0.5f64).to_lexical_with_options::<{ HEX }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(0X0.8h)");
+
+    // Case-sensitive base prefixes are never written in uppercase.
+    const CASE_SENSITIVE: u128 =
+        NumberFormatBuilder::rebuild(HEX).case_sensitive_base_prefix(true).build();
+    let bytes = (-0.5f64).to_lexical_with_options::<{ CASE_SENSITIVE }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(0x0.8h)");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .build();
+    let grouping = core::num::NonZeroUsize::new(3);
+    let options = Options::builder()
+        .digit_grouping(grouping)
+        .decimal_point(b'.')
+        .positive_exponent_break(core::num::NonZeroI32::new(12))
+        .build()
+        .unwrap();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = 1234567.5f64.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1,234,567.5");
+    let bytes = (-999.25f64).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-999.25");
+    let bytes = (-1000.0f64).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-1,000.0");
+    let bytes = 1.5e15f64.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5e15");
+    let bytes = f64::NAN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"NaN");
+
+    let options = options
+        .rebuild()
+        .secondary_digit_grouping(core::num::NonZeroUsize::new(2))
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    let bytes = 1234567.5f64.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"12,34,567.5");
+    let bytes = (-123456789012.0f64).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(1,23,45,67,89,012.0)");
+
+    let options = options.rebuild().min_width(core::num::NonZeroUsize::new(14)).build().unwrap();
+    let bytes = 1234567.5f64.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options);
+    assert_eq!(bytes, b"   12,34,567.5");
+
+    // Without a digit separator, grouping has no effect.
+    let bytes = 1234567.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"     1234567.5");
+}
+
+#[test]
+fn space_positive_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().space_positive(true).build().unwrap();
+    let bytes = 1.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b" 1.5");
+    let bytes = (-1.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"-1.5");
+    let bytes = 0.0f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b" 0.0");
+    let bytes = f64::INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b" inf");
+}
+
+#[test]
+fn parentheses_negative_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().parentheses_negative(true).build().unwrap();
+    let bytes = (-1.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(1.5)");
+    let bytes = 1.5f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.5");
+    let bytes = (-1e300f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(1.0e300)");
+    let bytes = f64::NEG_INFINITY.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(inf)");
+}
+
 #[test]
 #[should_panic]
 fn invalid_nan_test() {
@@ -171,3 +644,68 @@ proptest! {
         }
     }
 }
+
+#[test]
+fn buffer_size_const_test() {
+    const OPTIONS: Options = Options::new();
+    const SIZE: usize = OPTIONS.buffer_size_const::<{ STANDARD }>();
+    assert_eq!(SIZE, 25);
+    assert_eq!(SIZE, OPTIONS.buffer_size::<f64, { STANDARD }>());
+    assert_eq!(SIZE, OPTIONS.buffer_size::<f32, { STANDARD }>());
+
+    let mut buffer = [b'\x00'; SIZE];
+    let bytes = (-f64::MIN_POSITIVE).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &OPTIONS);
+    assert_eq!(bytes, b"-2.2250738585072014e-308");
+    let bytes =
+        (-1.2345678901234567e-5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &OPTIONS);
+    assert_eq!(bytes, b"-0.000012345678901234568");
+
+    let options = Options::builder()
+        .parentheses_negative(true)
+        .positive_exponent_break(core::num::NonZeroI32::new(20))
+        .build()
+        .unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size_const::<{ STANDARD }>()];
+    let bytes =
+        (-1.2345678901234567e20f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"(123456789012345670000.0)");
+
+    let options = Options::builder()
+        .negative_exponent_break(core::num::NonZeroI32::new(-400))
+        .min_significant_digits(core::num::NonZeroUsize::new(20))
+        .build()
+        .unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size_const::<{ STANDARD }>()];
+    let bytes = 5e-324f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes.len(), 345);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn buffer_size_radix_test() {
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    let options = Options::builder().subnormal_scientific(true).build().unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size_const::<BASE3>()];
+    let bytes = 5e-324f64.to_lexical_with_options::<BASE3>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0e-221002");
+    let bytes = (-0.0f64).to_lexical_with_options::<BASE3>(&mut buffer, &options);
+    assert_eq!(bytes, b"0.0");
+    let bytes = f64::MAX.to_lexical_with_options::<BASE3>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0020200012020012100112000100111212e212221");
+
+    let options = Options::builder()
+        .max_significant_digits(core::num::NonZeroUsize::new(133))
+        .negative_exponent_break(core::num::NonZeroI32::new(-179))
+        .build()
+        .unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size_const::<BASE3>()];
+    let bytes = (-9.688487156302786e-81f64).to_lexical_with_options::<BASE3>(&mut buffer, &options);
+    assert_eq!(bytes, b"-0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011011111012210202220120010020211");
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options =
+        Options::builder().max_significant_digits(core::num::NonZeroUsize::new(2)).build().unwrap();
+    let mut buffer = vec![b'\x00'; options.buffer_size_const::<BINARY>()];
+    let bytes = 0.96875f64.to_lexical_with_options::<BINARY>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0");
+}
@@ -149,16 +149,20 @@ fn truncate_and_round_test() {
         .unwrap();
 
     // Above halfway
-    assert_eq!(binary::truncate_and_round(6602499140956772u64, 2, &round), (12, 53));
-    assert_eq!(binary::truncate_and_round(6602499140956772u64, 2, &truncate), (11, 53));
+    assert_eq!(binary::truncate_and_round(6602499140956772u64, -52, 2, &round), (12, -3));
+    assert_eq!(binary::truncate_and_round(6602499140956772u64, -52, 2, &truncate), (11, -3));
 
     // At halfway
-    assert_eq!(binary::truncate_and_round(6473924464345088u64, 2, &round), (12, 53));
-    assert_eq!(binary::truncate_and_round(6473924464345088u64, 2, &truncate), (11, 53));
+    assert_eq!(binary::truncate_and_round(6473924464345088u64, -52, 2, &round), (12, -3));
+    assert_eq!(binary::truncate_and_round(6473924464345088u64, -52, 2, &truncate), (11, -3));
 
     // Below halfway.
-    assert_eq!(binary::truncate_and_round(6473924464345087u64, 2, &round), (11, 53));
-    assert_eq!(binary::truncate_and_round(6473924464345087u64, 2, &truncate), (11, 53));
+    assert_eq!(binary::truncate_and_round(6473924464345087u64, -52, 2, &round), (11, -3));
+    assert_eq!(binary::truncate_and_round(6473924464345087u64, -52, 2, &truncate), (11, -3));
+
+    // The leading digit has only 1 bit, so only 10 bits are kept in radix 8.
+    assert_eq!(binary::truncate_and_round(6473924464345087u64, -52, 8, &round), (0o1340, -9));
+    assert_eq!(binary::truncate_and_round(6473924464345087u64, -52, 8, &truncate), (0o1337, -9));
 }
 
 // NOTE: This doesn't handle float rounding or truncation.
@@ -1087,6 +1091,37 @@ fn write_float_test() {
         .unwrap();
     write_float::<_, BINARY>(1.2345678901234567890e2f64, &truncate, "1111011");
     write_float::<_, BINARY>(1.2345678901234567890e2f64, &round, "1111011.1");
+
+    let half_away = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .round_mode(RoundMode::RoundHalfAway)
+        .build()
+        .unwrap();
+    let to_odd = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .round_mode(RoundMode::RoundToOdd)
+        .build()
+        .unwrap();
+    write_float::<_, BINARY>(1.25f64, &half_away, "1.1");
+    write_float::<_, BINARY>(1.25f64, &to_odd, "1.1");
+    write_float::<_, BINARY>(1.125f64, &half_away, "1.0");
+    write_float::<_, BINARY>(1.125f64, &to_odd, "1.1");
+    write_float::<_, BINARY>(1.0f64, &to_odd, "1.0");
+
+    // Check max digits where the leading digit has fewer bits.
+    let round = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .exponent(b'^')
+        .build()
+        .unwrap();
+    write_float::<_, OCTAL>(1.2345678901234567890e1f64, &round, "14.0");
+    write_float::<_, OCTAL>(1.2345678901234567890e2f64, &round, "170.0");
+    write_float::<_, OCTAL>(1.9375f64, &round, "2.0");
+    write_float::<_, OCTAL>(0.0234f64, &round, "1.4^-2");
+    write_float::<_, OCTAL>(3e-308f64, &round, "2.6^-525");
+    write_float::<_, HEX>(1.2345678901234567890e2f64, &round, "7B.0");
+    write_float::<_, HEX>(1.9375f64, &round, "1.F");
+    write_float::<_, HEX>(3e-308f64, &round, "5.6^-100");
 }
 
 quickcheck! {
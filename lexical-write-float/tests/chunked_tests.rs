@@ -0,0 +1,211 @@
+use core::num;
+
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use lexical_write_float::{write_chunked, Alignment, Options, ToLexicalWithOptions};
+use proptest::prelude::*;
+
+/// Write the float in chunks of `size` bytes, and check it matches the
+/// bytes written to a single buffer.
+fn check_chunked<const FORMAT: u128>(float: f64, options: &Options, size: usize) -> Vec<u8> {
+    let mut expected = vec![b'\x00'; options.buffer_size_const::<FORMAT>()];
+    let expected = float.to_lexical_with_options::<FORMAT>(&mut expected, options);
+
+    let mut buffer = vec![b'\x00'; size];
+    let mut actual = Vec::new();
+    let count = write_chunked::<_, _, (), FORMAT>(float, &mut buffer, options, |chunk| {
+        assert!(!chunk.is_empty() && chunk.len() <= size);
+        actual.extend_from_slice(chunk);
+        Ok(())
+    });
+    assert_eq!(count, Ok(expected.len()));
+    assert_eq!(actual, expected);
+    actual
+}
+
+fn check_sizes<const FORMAT: u128>(float: f64, options: &Options) {
+    for &size in [1, 3, 7, 64].iter() {
+        check_chunked::<FORMAT>(float, options, size);
+    }
+}
+
+const FLOATS: [f64; 12] = [
+    0.0,
+    -0.0,
+    1.5,
+    -1234567.5,
+    1e300,
+    -1e-300,
+    5e-324,
+    f64::MAX,
+    f64::MIN_POSITIVE,
+    f64::NAN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+];
+
+#[test]
+fn chunked_test() {
+    let options = Options::new();
+    for &float in FLOATS.iter() {
+        check_sizes::<STANDARD>(float, &options);
+    }
+
+    let digits = check_chunked::<STANDARD>(1.5, &options, 2);
+    assert_eq!(digits, b"1.5");
+}
+
+#[test]
+fn chunked_significant_digits_test() {
+    let options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(5000)).build().unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<STANDARD>(float, &options);
+    }
+
+    let digits = check_chunked::<STANDARD>(-1.5, &options, 16);
+    assert_eq!(digits.len(), 5002);
+    assert!(digits.starts_with(b"-1.5000"));
+    let digits = check_chunked::<STANDARD>(1e300, &options, 16);
+    assert_eq!(digits.len(), 5005);
+    assert!(digits.ends_with(b"000e300"));
+
+    let options = options
+        .rebuild()
+        .positive_exponent_break(num::NonZeroI32::new(i32::MAX))
+        .negative_exponent_break(num::NonZeroI32::new(i32::MIN))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<STANDARD>(float, &options);
+    }
+}
+
+#[test]
+fn chunked_width_test() {
+    let options = Options::builder()
+        .min_width(num::NonZeroUsize::new(3000))
+        .min_significant_digits(num::NonZeroUsize::new(2000))
+        .build()
+        .unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<STANDARD>(float, &options);
+    }
+
+    let options = options.rebuild().fill(b'0').build().unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<STANDARD>(float, &options);
+    }
+
+    let options = options
+        .rebuild()
+        .fill(b'*')
+        .alignment(Alignment::Left)
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<STANDARD>(float, &options);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn chunked_digit_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .build();
+    let options = Options::builder()
+        .digit_grouping(num::NonZeroUsize::new(3))
+        .secondary_digit_grouping(num::NonZeroUsize::new(2))
+        .positive_exponent_break(num::NonZeroI32::new(i32::MAX))
+        .min_significant_digits(num::NonZeroUsize::new(2000))
+        .min_width(num::NonZeroUsize::new(4000))
+        .fill(b'0')
+        .build()
+        .unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<FORMAT>(float, &options);
+    }
+
+    let digits = check_chunked::<FORMAT>(1234567.5, &options, 5);
+    assert!(digits.starts_with(b"000"));
+    assert!(digits.windows(17).any(|x| x == b"00012,34,567.5000"));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn chunked_power_of_two_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build();
+    let options = Options::builder()
+        .exponent(b'p')
+        .base_prefix(true)
+        .min_significant_digits(num::NonZeroUsize::new(3000))
+        .min_width(num::NonZeroUsize::new(3500))
+        .fill(b'0')
+        .build()
+        .unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<HEX>(float, &options);
+    }
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(3000)).build().unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<BINARY>(float, &options);
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn chunked_radix_test() {
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    let options = Options::builder()
+        .min_significant_digits(num::NonZeroUsize::new(3000))
+        .negative_exponent_break(num::NonZeroI32::new(-1000))
+        .build()
+        .unwrap();
+    for &float in FLOATS.iter() {
+        check_sizes::<BASE3>(float, &options);
+    }
+}
+
+#[test]
+fn chunked_error_test() {
+    let options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(100)).build().unwrap();
+    let mut buffer = [b'\x00'; 16];
+    let mut chunks = 0;
+    let result = write_chunked::<_, _, usize, STANDARD>(1.5f64, &mut buffer, &options, |_| {
+        chunks += 1;
+        if chunks == 3 {
+            Err(chunks)
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(result, Err(3));
+}
+
+proptest! {
+    #[test]
+    fn chunked_proptest(
+        f in f64::MIN..f64::MAX,
+        min_digits in 1usize..3000,
+        size in 1usize..32,
+    ) {
+        let options = Options::builder()
+            .min_significant_digits(num::NonZeroUsize::new(min_digits))
+            .build()
+            .unwrap();
+        check_chunked::<STANDARD>(f, &options, size);
+    }
+}
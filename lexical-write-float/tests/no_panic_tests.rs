@@ -0,0 +1,43 @@
+// These tests only link if the writers have no reachable panics, which
+// requires optimizations: run with `cargo test --release --features no-panic`.
+// The wrappers are never inlined, so constant arguments cannot hide a panic.
+// The checked writers still panic if the buffer is too small, so these
+// check the digit generation with buffers that are always large enough.
+#![cfg(all(feature = "no-panic", not(debug_assertions)))]
+
+use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+use lexical_util::format::STANDARD;
+use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+use no_panic::no_panic;
+
+const OPTIONS: Options = Options::new();
+
+#[no_panic]
+#[inline(never)]
+fn write_f32(value: f32, buffer: &mut [u8; f32::FORMATTED_SIZE_DECIMAL]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `f32`.
+    unsafe { value.to_lexical_unchecked(buffer) }
+}
+
+#[no_panic]
+#[inline(never)]
+fn write_f64(value: f64, buffer: &mut [u8; f64::FORMATTED_SIZE_DECIMAL]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `f64`.
+    unsafe { value.to_lexical_unchecked(buffer) }
+}
+
+#[no_panic]
+#[inline(never)]
+fn write_options_f64(value: f64, buffer: &mut [u8; BUFFER_SIZE]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `f64` with the default options.
+    unsafe { value.to_lexical_with_options_unchecked::<STANDARD>(buffer, &OPTIONS) }
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write_f32(1.5, &mut [0; f32::FORMATTED_SIZE_DECIMAL]), b"1.5");
+    assert_eq!(write_f64(1.5e300, &mut [0; f64::FORMATTED_SIZE_DECIMAL]), b"1.5e300");
+    assert_eq!(write_f64(5e-324, &mut [0; f64::FORMATTED_SIZE_DECIMAL]), b"5.0e-324");
+    assert_eq!(write_f64(f64::NAN, &mut [0; f64::FORMATTED_SIZE_DECIMAL]), b"NaN");
+    assert_eq!(write_options_f64(-0.1, &mut [0; BUFFER_SIZE]), b"-0.1");
+}
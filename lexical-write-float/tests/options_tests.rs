@@ -1,4 +1,5 @@
 use core::num;
+use lexical_util::error::Error;
 use lexical_write_float::options::{self, Options, OptionsBuilder};
 
 #[test]
@@ -27,16 +28,30 @@ fn invalid_decimal_point_test() {
     assert!(builder.build().is_ok());
 }
 
+#[test]
+fn invalid_fill_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.fill(b'\x00');
+    assert!(!builder.is_valid());
+    assert_eq!(builder.build(), Err(Error::InvalidFillCharacter));
+    builder = builder.fill(b'0');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn invalid_nan_test() {
     let mut builder = OptionsBuilder::default();
     builder = builder.nan_string(Some(b"naaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaan"));
     assert!(!builder.is_valid());
-    builder = builder.nan_string(Some(b"inf"));
-    assert!(!builder.is_valid());
-    builder = builder.nan_string(Some(b"na00n"));
+    builder = builder.nan_string(Some(b"na\x00n"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.nan_string(Some(b"null"));
+    assert!(builder.is_valid());
+    builder = builder.nan_string(Some(b"#DIV/0!"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.nan_string(Some(b"nan"));
     assert!(builder.is_valid());
     assert!(builder.build().is_ok());
@@ -49,11 +64,14 @@ fn invalid_inf_test() {
     let mut builder = OptionsBuilder::default();
     builder = builder.inf_string(Some(b"innnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnf"));
     assert!(!builder.is_valid());
-    builder = builder.inf_string(Some(b"nan"));
-    assert!(!builder.is_valid());
-    builder = builder.inf_string(Some(b"in00f"));
+    builder = builder.inf_string(Some(b"in\x7ff"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.inf_string(Some(b"Infinity"));
+    assert!(builder.is_valid());
+    builder = builder.inf_string(Some(b"#DIV/0!"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.inf_string(Some(b"inf"));
     assert!(builder.is_valid());
     assert!(builder.build().is_ok());
@@ -69,23 +87,59 @@ fn builder_test() {
     builder = builder.min_significant_digits(num::NonZeroUsize::new(5));
     builder = builder.positive_exponent_break(num::NonZeroI32::new(9));
     builder = builder.negative_exponent_break(num::NonZeroI32::new(-9));
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(2));
+    builder = builder.subnormal_scientific(true);
     builder = builder.round_mode(options::RoundMode::Truncate);
+    builder = builder.exact_rounding(true);
+    builder = builder.exact_digits(true);
     builder = builder.trim_floats(true);
+    builder = builder.trim_scientific(true);
+    builder = builder.trim_integer_zero(true);
+    builder = builder.space_positive(true);
+    builder = builder.parentheses_negative(true);
+    builder = builder.nan_sign(true);
+    builder = builder.signed_zero(true);
+    builder = builder.positive_inf_sign(true);
+    builder = builder.base_prefix(true);
+    builder = builder.uppercase_base_prefix(true);
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.min_width(num::NonZeroUsize::new(12));
+    builder = builder.alignment(options::Alignment::Left);
+    builder = builder.fill(b'*');
+    builder = builder.digit_grouping(num::NonZeroUsize::new(3));
+    builder = builder.secondary_digit_grouping(num::NonZeroUsize::new(2));
 
     assert_eq!(builder.get_max_significant_digits().unwrap().get(), 10);
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
     assert_eq!(builder.get_positive_exponent_break().unwrap().get(), 9);
     assert_eq!(builder.get_negative_exponent_break().unwrap().get(), -9);
+    assert_eq!(builder.get_min_exponent_digits().unwrap().get(), 2);
+    assert!(builder.get_subnormal_scientific());
     assert_eq!(builder.get_round_mode(), options::RoundMode::Truncate);
+    assert!(builder.get_exact_rounding());
+    assert!(builder.get_exact_digits());
     assert_eq!(builder.get_trim_floats(), true);
+    assert!(builder.get_trim_scientific());
+    assert!(builder.get_trim_integer_zero());
+    assert!(builder.get_space_positive());
+    assert!(builder.get_parentheses_negative());
+    assert!(builder.get_nan_sign());
+    assert!(builder.get_signed_zero());
+    assert!(builder.get_positive_inf_sign());
+    assert!(builder.get_base_prefix());
+    assert!(builder.get_uppercase_base_prefix());
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(builder.get_min_width().unwrap().get(), 12);
+    assert_eq!(builder.get_alignment(), options::Alignment::Left);
+    assert_eq!(builder.get_fill(), b'*');
+    assert_eq!(builder.get_digit_grouping().unwrap().get(), 3);
+    assert_eq!(builder.get_secondary_digit_grouping().unwrap().get(), 2);
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
@@ -100,26 +154,144 @@ fn options_test() {
         opts.set_min_significant_digits(num::NonZeroUsize::new(5));
         opts.set_positive_exponent_break(num::NonZeroI32::new(9));
         opts.set_negative_exponent_break(num::NonZeroI32::new(-9));
+        opts.set_min_exponent_digits(num::NonZeroUsize::new(2));
+        opts.set_subnormal_scientific(true);
         opts.set_round_mode(options::RoundMode::Truncate);
+        opts.set_exact_rounding(true);
+        opts.set_exact_digits(true);
         opts.set_trim_floats(true);
+        opts.set_trim_scientific(true);
+        opts.set_trim_integer_zero(true);
+        opts.set_space_positive(true);
+        opts.set_parentheses_negative(true);
+        opts.set_nan_sign(true);
+        opts.set_signed_zero(true);
+        opts.set_positive_inf_sign(true);
+        opts.set_base_prefix(true);
+        opts.set_uppercase_base_prefix(true);
         opts.set_exponent(b'^');
         opts.set_decimal_point(b',');
         opts.set_nan_string(Some(b"nan"));
         opts.set_inf_string(Some(b"Infinity"));
+        opts.set_min_width(num::NonZeroUsize::new(12));
+        opts.set_alignment(options::Alignment::Left);
+        opts.set_fill(b'*');
+        opts.set_digit_grouping(num::NonZeroUsize::new(3));
+        opts.set_secondary_digit_grouping(num::NonZeroUsize::new(2));
     }
 
     assert_eq!(opts.max_significant_digits().unwrap().get(), 10);
     assert_eq!(opts.min_significant_digits().unwrap().get(), 5);
     assert_eq!(opts.positive_exponent_break().unwrap().get(), 9);
     assert_eq!(opts.negative_exponent_break().unwrap().get(), -9);
+    assert_eq!(opts.min_exponent_digits().unwrap().get(), 2);
+    assert!(opts.subnormal_scientific());
     assert_eq!(opts.round_mode(), options::RoundMode::Truncate);
+    assert!(opts.exact_rounding());
+    assert!(opts.exact_digits());
     assert_eq!(opts.trim_floats(), true);
+    assert!(opts.trim_scientific());
+    assert!(opts.trim_integer_zero());
+    assert!(opts.space_positive());
+    assert!(opts.parentheses_negative());
+    assert!(opts.nan_sign());
+    assert!(opts.signed_zero());
+    assert!(opts.positive_inf_sign());
+    assert!(opts.base_prefix());
+    assert!(opts.uppercase_base_prefix());
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(opts.min_width().unwrap().get(), 12);
+    assert_eq!(opts.alignment(), options::Alignment::Left);
+    assert_eq!(opts.fill(), b'*');
+    assert_eq!(opts.digit_grouping().unwrap().get(), 3);
+    assert_eq!(opts.secondary_digit_grouping().unwrap().get(), 2);
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn format_spec_test() {
+    use lexical_util::format::FormatSpec;
+
+    let spec = FormatSpec::parse("decimal_point=',', nan_string=NaN").unwrap();
+    let options = Options::builder().exponent(b'^').format_spec(&spec).build().unwrap();
+    assert_eq!(options.exponent(), b'^');
+    assert_eq!(options.decimal_point(), b',');
+    assert_eq!(options.nan_string(), Some(&b"NaN"[..]));
+    assert_eq!(options.inf_string(), Some(&b"inf"[..]));
+}
+
+#[test]
+fn build_with_format_test() {
+    use lexical_util::error::Punctuation;
+    use lexical_util::format::{Format, STANDARD};
+
+    let format = Format::new(STANDARD).unwrap();
+    let builder = Options::builder().exponent(b'^').decimal_point(b',');
+    assert_eq!(builder.build_with_format(&format), builder.build());
+    assert_eq!(
+        builder.clone().decimal_point(b'^').build_with_format(&format),
+        Err(Error::ConflictingPunctuation(Punctuation::DecimalPoint, Punctuation::Exponent))
+    );
+    assert_eq!(
+        builder.clone().exponent(b'1').build_with_format(&format),
+        Err(Error::DigitPunctuation(Punctuation::Exponent))
+    );
+    assert_eq!(
+        builder.exponent(b'\x00').build_with_format(&format),
+        Err(Error::InvalidExponentSymbol)
+    );
+}
+
+#[test]
+fn override_test() {
+    use lexical_util::options::OverrideOptions;
+    use lexical_write_float::options::OptionsOverride;
+
+    let options = Options::builder().trim_floats(true).build().unwrap();
+    let overrides = OptionsOverride::new();
+    assert!(overrides.is_empty());
+    assert_eq!(options.with_override(&overrides), Ok(options.clone()));
+
+    let overrides = OptionsOverride::new().max_significant_digits(num::NonZeroUsize::new(3));
+    assert!(!overrides.is_empty());
+    let result = options.with_override(&overrides).unwrap();
+    assert_eq!(result.max_significant_digits(), num::NonZeroUsize::new(3));
+    assert!(result.trim_floats());
+    assert_eq!(options.max_significant_digits(), None);
+
+    let overrides = overrides.min_significant_digits(num::NonZeroUsize::new(5));
+    assert_eq!(options.with_override(&overrides), Err(Error::InvalidFloatPrecision));
+    let overrides = OptionsOverride::new().exponent(b'\x00');
+    assert_eq!(options.with_override(&overrides), Err(Error::InvalidExponentSymbol));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_test() {
+    use lexical_util::arbitrary::{Arbitrary, Unstructured};
+
+    // Pseudo-random input, so the generated values vary between iterations.
+    let mut state = 0x2545_F491_4F6C_DD1Du64;
+    for _ in 0..100 {
+        let bytes: Vec<u8> = (0..256)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        let builder = OptionsBuilder::arbitrary(&mut u).unwrap();
+        if builder.build().is_ok() {
+            assert!(builder.is_valid());
+        }
+        if let Ok(options) = Options::arbitrary(&mut u) {
+            assert!(options.is_valid());
+        }
+    }
+}
@@ -0,0 +1,220 @@
+#![cfg(feature = "format")]
+
+use core::num;
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::format;
+use lexical_write_float::{options, Options, ToLexicalWithOptions};
+
+fn write<const FORMAT: u128>(f: f64, options: &Options) -> String {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = f.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[test]
+fn javascript_to_string_test() {
+    const FORMAT: u128 = format::JAVASCRIPT_TO_STRING;
+    let options = options::JAVASCRIPT_TO_STRING;
+    assert_eq!(write::<FORMAT>(0.0, &options), "0");
+    assert_eq!(write::<FORMAT>(-0.0, &options), "0");
+    assert_eq!(write::<FORMAT>(123.0, &options), "123");
+    assert_eq!(write::<FORMAT>(-1.5, &options), "-1.5");
+    assert_eq!(write::<FORMAT>(0.1 + 0.2, &options), "0.30000000000000004");
+    assert_eq!(write::<FORMAT>(1e20, &options), "100000000000000000000");
+    assert_eq!(write::<FORMAT>(1.2345678901234568e20, &options), "123456789012345680000");
+    assert_eq!(write::<FORMAT>(1e21, &options), "1e+21");
+    assert_eq!(write::<FORMAT>(1.5e300, &options), "1.5e+300");
+    assert_eq!(write::<FORMAT>(1e-6, &options), "0.000001");
+    assert_eq!(write::<FORMAT>(1.5e-7, &options), "1.5e-7");
+    assert_eq!(write::<FORMAT>(1e-7, &options), "1e-7");
+    assert_eq!(write::<FORMAT>(5e-324, &options), "5e-324");
+    assert_eq!(write::<FORMAT>(f64::MAX, &options), "1.7976931348623157e+308");
+    assert_eq!(write::<FORMAT>(f64::NAN, &options), "NaN");
+    assert_eq!(write::<FORMAT>(f64::INFINITY, &options), "Infinity");
+    assert_eq!(write::<FORMAT>(f64::NEG_INFINITY, &options), "-Infinity");
+}
+
+#[test]
+fn python_repr_test() {
+    const FORMAT: u128 = format::PYTHON_REPR;
+    let options = options::PYTHON_REPR;
+    assert_eq!(write::<FORMAT>(0.0, &options), "0.0");
+    assert_eq!(write::<FORMAT>(-0.0, &options), "-0.0");
+    assert_eq!(write::<FORMAT>(1.0, &options), "1.0");
+    assert_eq!(write::<FORMAT>(-1.5, &options), "-1.5");
+    assert_eq!(write::<FORMAT>(0.1 + 0.2, &options), "0.30000000000000004");
+    assert_eq!(write::<FORMAT>(1e15, &options), "1000000000000000.0");
+    assert_eq!(write::<FORMAT>(1e16, &options), "1e+16");
+    assert_eq!(write::<FORMAT>(1.5e16, &options), "1.5e+16");
+    assert_eq!(write::<FORMAT>(1e100, &options), "1e+100");
+    assert_eq!(write::<FORMAT>(0.0001, &options), "0.0001");
+    assert_eq!(write::<FORMAT>(1e-5, &options), "1e-05");
+    assert_eq!(write::<FORMAT>(1.5e-7, &options), "1.5e-07");
+    assert_eq!(write::<FORMAT>(5e-324, &options), "5e-324");
+    assert_eq!(write::<FORMAT>(f64::MAX, &options), "1.7976931348623157e+308");
+    assert_eq!(write::<FORMAT>(f64::NAN, &options), "nan");
+    assert_eq!(write::<FORMAT>(f64::INFINITY, &options), "inf");
+    assert_eq!(write::<FORMAT>(f64::NEG_INFINITY, &options), "-inf");
+}
+
+#[test]
+fn go_format_float_test() {
+    const FORMAT: u128 = format::GO_FORMAT_FLOAT;
+    let options = options::GO_FORMAT_FLOAT;
+    assert_eq!(write::<FORMAT>(0.0, &options), "0");
+    assert_eq!(write::<FORMAT>(-0.0, &options), "-0");
+    assert_eq!(write::<FORMAT>(1.0, &options), "1");
+    assert_eq!(write::<FORMAT>(-1.5, &options), "-1.5");
+    assert_eq!(write::<FORMAT>(0.1 + 0.2, &options), "0.30000000000000004");
+    assert_eq!(write::<FORMAT>(123456.0, &options), "123456");
+    assert_eq!(write::<FORMAT>(1e6, &options), "1e+06");
+    assert_eq!(write::<FORMAT>(1234567.0, &options), "1.234567e+06");
+    assert_eq!(write::<FORMAT>(1e21, &options), "1e+21");
+    assert_eq!(write::<FORMAT>(0.0001, &options), "0.0001");
+    assert_eq!(write::<FORMAT>(1e-5, &options), "1e-05");
+    assert_eq!(write::<FORMAT>(5e-324, &options), "5e-324");
+    assert_eq!(write::<FORMAT>(f64::MAX, &options), "1.7976931348623157e+308");
+    assert_eq!(write::<FORMAT>(f64::NAN, &options), "NaN");
+    assert_eq!(write::<FORMAT>(f64::INFINITY, &options), "+Inf");
+    assert_eq!(write::<FORMAT>(f64::NEG_INFINITY, &options), "-Inf");
+}
+
+#[test]
+fn c_printf_g_test() {
+    const FORMAT: u128 = format::C_PRINTF_G;
+    let options = options::C_PRINTF_G;
+    assert_eq!(write::<FORMAT>(0.0, &options), "0");
+    assert_eq!(write::<FORMAT>(-0.0, &options), "-0");
+    assert_eq!(write::<FORMAT>(1.0, &options), "1");
+    assert_eq!(write::<FORMAT>(0.1 + 0.2, &options), "0.3");
+    assert_eq!(write::<FORMAT>(123456.0, &options), "123456");
+    assert_eq!(write::<FORMAT>(1234567.0, &options), "1.23457e+06");
+    assert_eq!(write::<FORMAT>(999999.5, &options), "1e+06");
+    assert_eq!(write::<FORMAT>(0.0001, &options), "0.0001");
+    assert_eq!(write::<FORMAT>(0.000099999996, &options), "0.0001");
+    assert_eq!(write::<FORMAT>(1e-5, &options), "1e-05");
+    assert_eq!(write::<FORMAT>(2.0000005, &options), "2");
+    assert_eq!(write::<FORMAT>(1.0000025, &options), "1");
+    assert_eq!(write::<FORMAT>(5e-324, &options), "4.94066e-324");
+    assert_eq!(write::<FORMAT>(f64::MAX, &options), "1.79769e+308");
+    assert_eq!(write::<FORMAT>(f64::NAN, &options), "nan");
+    assert_eq!(write::<FORMAT>(-f64::NAN, &options), "-nan");
+    assert_eq!(write::<FORMAT>(f64::INFINITY, &options), "inf");
+    assert_eq!(write::<FORMAT>(f64::NEG_INFINITY, &options), "-inf");
+}
+
+#[cfg(unix)]
+fn libc_printf_g(f: f64, precision: i32) -> String {
+    use std::os::raw::{c_char, c_int};
+
+    extern "C" {
+        fn snprintf(s: *mut c_char, n: usize, format: *const c_char, ...) -> c_int;
+    }
+
+    let mut buffer = [0u8; 64];
+    let format = b"%.*g\0";
+    let count = unsafe {
+        snprintf(
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer.len(),
+            format.as_ptr() as *const c_char,
+            precision as c_int,
+            f,
+        )
+    };
+    String::from_utf8(buffer[..count as usize].to_vec()).unwrap()
+}
+
+#[test]
+#[cfg(unix)]
+fn c_printf_g_libc_test() {
+    const FORMAT: u128 = format::C_PRINTF_G;
+    let values = [
+        0.0,
+        1.0,
+        0.5,
+        0.15,
+        0.25,
+        2.5,
+        2.675,
+        1.0000005,
+        1.0000015,
+        999999.5,
+        9999995.0,
+        0.0001,
+        0.000099999996,
+        0.00009999995,
+        123456.5,
+        1e-5,
+        1e23,
+        1e100,
+        1e-100,
+        1e21,
+        5e-324,
+        2.2250738585072014e-308,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+    ];
+    for &precision in [6, 2, 3, 10, 15, 17].iter() {
+        let options = options::C_PRINTF_G
+            .rebuild()
+            .max_significant_digits(num::NonZeroUsize::new(precision))
+            .positive_exponent_break(num::NonZeroI32::new(precision as i32 - 1))
+            .build()
+            .unwrap();
+        for &value in values.iter() {
+            let expected = libc_printf_g(value, precision as i32);
+            assert_eq!(write::<FORMAT>(value, &options), expected);
+            let expected = libc_printf_g(-value, precision as i32);
+            assert_eq!(write::<FORMAT>(-value, &options), expected);
+        }
+
+        // Use a simple xorshift generator to check values across the range.
+        let mut state = 0x853c_49e6_748f_ea9bu64;
+        for _ in 0..5000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let value = f64::from_bits(state);
+            if value.is_finite() {
+                assert_eq!(
+                    write::<FORMAT>(value, &options),
+                    libc_printf_g(value, precision as i32)
+                );
+            }
+            let value = f32::from_bits(state as u32);
+            if value.is_finite() && precision <= 9 {
+                let mut buffer = [b'\x00'; BUFFER_SIZE];
+                let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+                let expected = libc_printf_g(value as f64, precision as i32);
+                assert_eq!(bytes, expected.as_bytes());
+            }
+        }
+    }
+}
+
+#[test]
+fn scientific_fixed_test() {
+    const FORMAT: u128 = format::SCIENTIFIC_FIXED;
+    let options = options::SCIENTIFIC_FIXED;
+    assert_eq!(write::<FORMAT>(0.0, &options), "0.000000e+00");
+    assert_eq!(write::<FORMAT>(-0.0, &options), "-0.000000e+00");
+    assert_eq!(write::<FORMAT>(1.5, &options), "1.500000e+00");
+    assert_eq!(write::<FORMAT>(0.1 + 0.2, &options), "3.000000e-01");
+    assert_eq!(write::<FORMAT>(1234567.0, &options), "1.234567e+06");
+    assert_eq!(write::<FORMAT>(12345675.0, &options), "1.234568e+07");
+    assert_eq!(write::<FORMAT>(1e-5, &options), "1.000000e-05");
+    assert_eq!(write::<FORMAT>(1e100, &options), "1.000000e+100");
+    assert_eq!(write::<FORMAT>(5e-324, &options), "4.940656e-324");
+    assert_eq!(write::<FORMAT>(f64::NAN, &options), "nan");
+    assert_eq!(write::<FORMAT>(f64::NEG_INFINITY, &options), "-inf");
+}
+
+#[test]
+fn locale_test() {
+    const FORMAT: u128 = format::STANDARD;
+    assert_eq!(write::<FORMAT>(1.5, &options::C_LOCALE), "1.5");
+    assert_eq!(write::<FORMAT>(f64::NAN, &options::C_LOCALE), "nan");
+    assert_eq!(write::<FORMAT>(f64::INFINITY, &options::PERMISSIVE), "inf");
+    assert_eq!(write::<FORMAT>(1.5, &options::EUROPEAN_LOCALE), "1,5");
+}
@@ -183,7 +183,7 @@ fn write_float_test() {
     write_float::<_, BASE3>(
         0.33333333f64,
         &options,
-        "0.0222222222222222212010101201000200000000000000000",
+        "0.022222222222222221201010120100020000000000000000000",
     );
     write_float::<_, BASE3>(
         12157665459056928801.0f64,
@@ -230,6 +230,19 @@ fn write_float_test() {
         .unwrap();
     write_float::<_, BASE3>(23.45678901234567890f64, &round, "220.0");
     write_float::<_, BASE3>(23.45678901234567890f64, &truncate, "210.0");
+
+    // In an odd radix, the parity of the value is the parity of the digit sum.
+    let to_odd = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .round_mode(RoundMode::RoundToOdd)
+        .build()
+        .unwrap();
+    write_float::<_, BASE3>(23.45678901234567890f64, &to_odd, "210.0");
+    write_float::<_, BASE3>(4.5f64, &to_odd, "12.0");
+    write_float::<_, BASE3>(5.5f64, &to_odd, "12.0");
+    write_float::<_, BASE3>(6.5f64, &to_odd, "21.0");
+    write_float::<_, BASE3>(8.5f64, &to_odd, "100.0");
+    write_float::<_, BASE3>(8.0f64, &to_odd, "22.0");
 }
 
 macro_rules! test_radix {
@@ -344,6 +357,32 @@ fn base21_test() {
     assert_eq!(b"4C440700000000000000000000000.0", &buffer[..count]);
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn required_exponent_notation_test() {
+    const FORMAT: u128 =
+        NumberFormatBuilder::rebuild(BASE3).required_exponent_notation(true).build();
+    let options = Options::new();
+    write_float::<_, FORMAT>(0.0f64, &options, "0.0e0");
+    write_float::<_, FORMAT>(1.0f64, &options, "1.0e0");
+    write_float::<_, FORMAT>(0.5f64, &options, "1.111111111111111111111111111111112e-1");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn no_exponent_notation_test() {
+    const FORMAT: u128 = NumberFormatBuilder::rebuild(BASE5).no_exponent_notation(true).build();
+    let options = Options::new();
+    let mut buffer = [b'\x00'; 2000];
+    for &f in [2.0346e-229f64, 1e-300, f64::MIN_POSITIVE, 5e-324].iter() {
+        // The leading zeros must not truncate the significant digits.
+        let count = unsafe { radix::write_float::<_, FORMAT>(f, &mut buffer, &options) };
+        assert!(!buffer[..count].contains(&b'e'));
+        let roundtrip = parse_f64(&buffer[..count], 5, b'e');
+        assert_relative_eq!(f, roundtrip, epsilon = 1e-300, max_relative = 3e-6);
+    }
+}
+
 //  NOTE:
 //      Due to how we round-up by default, for min or max values, the output
 //      frequently rounds up to infinity, meaning we can't roundtrip. These
@@ -0,0 +1,261 @@
+use core::num::{NonZeroI32, NonZeroUsize};
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{Format, STANDARD};
+use lexical_util::options::WriteOptions;
+use lexical_write_float::{Options, ToLexicalWithOptions};
+use proptest::prelude::*;
+
+const F64_VALUES: &[f64] = &[
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    1.5,
+    0.1,
+    -0.3,
+    7.0,
+    1e5,
+    1e-7,
+    123456.789,
+    1e9,
+    1e10,
+    1234567890123.0,
+    1e100,
+    -1e-100,
+    1e-300,
+    5e-324,
+    f64::MAX,
+    f64::MIN_POSITIVE,
+    f64::NAN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+];
+
+/// Write the float with the const generic and runtime writers.
+fn write_both<T, const FORMAT: u128>(value: T, options: &Options) -> (String, String)
+where
+    T: ToLexicalWithOptions<Options = Options> + Copy,
+{
+    let format = Format::new(FORMAT).unwrap();
+    let size = options.buffer_size_with_format::<T>(&format);
+    assert!(size >= options.buffer_size::<T, FORMAT>());
+    let mut expected = vec![b'\x00'; size];
+    let mut actual = vec![b'\x00'; size];
+    let expected = value.to_lexical_with_options::<FORMAT>(&mut expected, options);
+    let actual = value.to_lexical_with_format(&mut actual, &format, options);
+    (String::from_utf8(expected.to_vec()).unwrap(), String::from_utf8(actual.to_vec()).unwrap())
+}
+
+/// Assert the runtime writer matches the const generic writer for every value.
+fn assert_runtime_eq<const FORMAT: u128>(options: &Options) {
+    for &value in F64_VALUES {
+        let (expected, actual) = write_both::<_, FORMAT>(value, options);
+        assert_eq!(expected, actual, "{:?}", value);
+        let (expected, actual) = write_both::<_, FORMAT>(value as f32, options);
+        assert_eq!(expected, actual, "{:?}", value as f32);
+    }
+}
+
+/// Assert the runtime writer matches for the default and formatting options.
+fn assert_format_eq<const FORMAT: u128>(exponent: u8) {
+    let default = Options::builder().exponent(exponent).build().unwrap();
+    let trimmed = Options::builder()
+        .exponent(exponent)
+        .trim_floats(true)
+        .min_exponent_digits(NonZeroUsize::new(3))
+        .positive_exponent_break(NonZeroI32::new(4))
+        .negative_exponent_break(NonZeroI32::new(-2))
+        .build()
+        .unwrap();
+    let exact = Options::builder()
+        .exponent(exponent)
+        .max_significant_digits(NonZeroUsize::new(4))
+        .min_significant_digits(NonZeroUsize::new(3))
+        .trim_scientific(true)
+        .trim_integer_zero(true)
+        .build()
+        .unwrap();
+    let padded = Options::builder()
+        .exponent(exponent)
+        .min_width(NonZeroUsize::new(30))
+        .fill(b'0')
+        .digit_grouping(NonZeroUsize::new(3))
+        .secondary_digit_grouping(NonZeroUsize::new(2))
+        .parentheses_negative(true)
+        .space_positive(true)
+        .build()
+        .unwrap();
+    let signed = Options::builder()
+        .exponent(exponent)
+        .nan_sign(true)
+        .signed_zero(true)
+        .positive_inf_sign(true)
+        .subnormal_scientific(true)
+        .min_width(NonZeroUsize::new(12))
+        .build()
+        .unwrap();
+    for options in [default, trimmed, exact, padded, signed].iter() {
+        assert_runtime_eq::<FORMAT>(options);
+    }
+}
+
+#[test]
+fn standard_runtime_test() {
+    let format = Format::new(STANDARD).unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(
+        b"-1.5e-7",
+        (-1.5e-7f64).to_lexical_with_format(&mut buffer, &format, &Options::new())
+    );
+    assert_format_eq::<STANDARD>(b'e');
+    assert_format_eq::<STANDARD>(b'E');
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_runtime_test() {
+    const BINARY: u128 = NumberFormatBuilder::new().mantissa_radix(2).build();
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(16))
+        .exponent_radix(core::num::NonZeroU8::new(16))
+        .build();
+    const HEX_BINARY: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .build();
+    const BASE32_BINARY: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(32)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(8))
+        .build();
+    const OCTAL: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(8)
+        .exponent_base(core::num::NonZeroU8::new(8))
+        .build();
+
+    let format = Format::new(HEX_BINARY).unwrap();
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(b"0.8", 0.5f64.to_lexical_with_format(&mut buffer, &format, &options));
+    assert_format_eq::<BINARY>(b'e');
+    assert_format_eq::<HEX>(b'p');
+    assert_format_eq::<HEX_BINARY>(b'p');
+    assert_format_eq::<BASE32_BINARY>(b'^');
+    assert_format_eq::<OCTAL>(b'e');
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn odd_radix_runtime_test() {
+    const BASE3: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(3)
+        .exponent_base(core::num::NonZeroU8::new(3))
+        .build();
+    const BASE36: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(36)
+        .exponent_base(core::num::NonZeroU8::new(36))
+        .exponent_radix(core::num::NonZeroU8::new(36))
+        .build();
+    const DECIMAL36: u128 =
+        NumberFormatBuilder::new().exponent_radix(core::num::NonZeroU8::new(36)).build();
+    assert_format_eq::<BASE3>(b'e');
+    assert_format_eq::<BASE36>(b'^');
+    assert_format_eq::<DECIMAL36>(b'^');
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_runtime_test() {
+    const SEPARATOR: u128 =
+        NumberFormatBuilder::new().digit_separator(core::num::NonZeroU8::new(b'_')).build();
+    const SIGN: u128 = NumberFormatBuilder::new()
+        .required_mantissa_sign(true)
+        .required_exponent_sign(true)
+        .build();
+    const FRACTION: u128 = NumberFormatBuilder::new().no_exponent_without_fraction(true).build();
+    const NO_EXPONENT: u128 = NumberFormatBuilder::new().no_exponent_notation(true).build();
+    const EXPONENT: u128 = NumberFormatBuilder::new().required_exponent_notation(true).build();
+    const INTEGER: u128 = NumberFormatBuilder::new().required_integer_digits(true).build();
+
+    let format = Format::new(SIGN).unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(b"+1.0e+10", 1e10f64.to_lexical_with_format(&mut buffer, &format, &Options::new()));
+    assert_format_eq::<SEPARATOR>(b'e');
+    assert_format_eq::<SIGN>(b'e');
+    assert_format_eq::<FRACTION>(b'e');
+    assert_format_eq::<NO_EXPONENT>(b'e');
+    assert_format_eq::<EXPONENT>(b'e');
+    assert_format_eq::<INTEGER>(b'e');
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn affix_runtime_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(core::num::NonZeroU8::new(2))
+        .exponent_radix(core::num::NonZeroU8::new(10))
+        .base_prefix(core::num::NonZeroU8::new(b'x'))
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .required_exponent_sign(true)
+        .build();
+    const BINARY: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(2)
+        .base_prefix(core::num::NonZeroU8::new(b'd'))
+        .no_exponent_without_fraction(true)
+        .build();
+    const REQUIRED: u128 = NumberFormatBuilder::rebuild(HEX)
+        .base_suffix(core::num::NonZeroU8::new(b'h'))
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .case_sensitive_base_prefix(true)
+        .build();
+
+    let format = Format::new(HEX).unwrap();
+    let options = Options::builder().exponent(b'p').base_prefix(true).build().unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(b"-0x0.8", (-0.5f64).to_lexical_with_format(&mut buffer, &format, &options));
+    for &uppercase in [false, true].iter() {
+        let options = Options::builder()
+            .exponent(b'p')
+            .base_prefix(true)
+            .uppercase_base_prefix(uppercase)
+            .digit_grouping(NonZeroUsize::new(2))
+            .min_width(NonZeroUsize::new(24))
+            .fill(b'0')
+            .build()
+            .unwrap();
+        assert_runtime_eq::<HEX>(&options);
+        assert_runtime_eq::<BINARY>(&options);
+        assert_runtime_eq::<REQUIRED>(&options);
+    }
+    assert_format_eq::<HEX>(b'p');
+    assert_format_eq::<BINARY>(b'e');
+    assert_format_eq::<REQUIRED>(b'p');
+}
+
+#[test]
+#[should_panic]
+fn invalid_punctuation_test() {
+    let format = Format::new(STANDARD).unwrap();
+    let options = unsafe { Options::builder().exponent(b'1').build_unchecked() };
+    let mut buffer = [b'\x00'; 512];
+    1.0f64.to_lexical_with_format(&mut buffer, &format, &options);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn runtime_proptest(f in f64::MIN..f64::MAX, grouping in 1usize..5) {
+        let options = Options::builder()
+            .digit_grouping(NonZeroUsize::new(grouping))
+            .min_exponent_digits(NonZeroUsize::new(2))
+            .build()
+            .unwrap();
+        let (expected, actual) = write_both::<_, STANDARD>(f, &options);
+        prop_assert_eq!(expected, actual);
+    }
+}
@@ -11,9 +11,9 @@
 #![doc(hidden)]
 
 use lexical_util::assert::debug_assert_radix;
-use lexical_util::digit::digit_to_char;
+use lexical_util::digit::{digit_to_char, digit_to_char_const};
 use lexical_util::div128::u128_divrem;
-use lexical_util::format::{radix_from_flags, NumberFormat};
+use lexical_util::format::{is_valid_radix, radix_from_flags, NumberFormat};
 use lexical_util::num::{AsCast, UnsignedInteger};
 use lexical_util::step::u64_step;
 
@@ -176,6 +176,102 @@ where
     unsafe { write_digits(value, radix, table, buffer, buffer.len()) }
 }
 
+/// Optimized implementation for power-of-two radixes.
+///
+/// Since the number of digits is trivial to calculate from the number
+/// of significant bits, this writes directly into the buffer, using
+/// shifts and masks rather than divisions, and emits 2 digits at a
+/// time from the `radix^2` table: IE, 1 byte at a time for hexadecimal.
+/// Returns the number of digits written.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is large enough to hold as many digits
+/// that can be in the largest value of `T`, in radix `N`.
+#[inline]
+pub unsafe fn algorithm_pow2<T>(mut value: T, radix: u32, table: &[u8], buffer: &mut [u8]) -> usize
+where
+    T: UnsignedInteger,
+{
+    debug_assert!(radix.is_power_of_two());
+    debug_assert_radix(radix);
+    debug_assert!(table.len() == 2 * (radix * radix) as usize);
+
+    let log2 = radix.trailing_zeros() as usize;
+    let bits = value.bit_length() as usize;
+    let count = (bits.max(1) - 1) / log2 + 1;
+    debug_assert!(count <= buffer.len());
+
+    // SAFETY: safe as long as the buffer can hold `count` digits, and the
+    // table has `2 * radix^2` elements, since `r < radix^2`.
+    let shift2 = 2 * log2 as i32;
+    let shift4 = 2 * shift2;
+    let mask2 = T::from_u32(radix * radix - 1);
+    let mask4 = T::from_u32(radix * radix * radix * radix - 1);
+    let mut index = count;
+
+    // Decode 4 digits at a time.
+    while index >= 4 {
+        let r = value & mask4;
+        value >>= shift4;
+        let r1 = usize::as_cast(T::TWO * (r >> shift2));
+        let r2 = usize::as_cast(T::TWO * (r & mask2));
+        write_digits!(buffer, index, table, r2);
+        write_digits!(buffer, index, table, r1);
+    }
+
+    // Decode 2 digits at a time.
+    if index >= 2 {
+        let r = usize::as_cast(T::TWO * (value & mask2));
+        value >>= shift2;
+        write_digits!(buffer, index, table, r);
+    }
+
+    // Decode the last digit.
+    if index == 1 {
+        // SAFETY: safe, since `value < radix`, so it must be < 32.
+        let r = u32::as_cast(value);
+        write_digit!(buffer, index, r);
+    }
+
+    count
+}
+
+/// Table-free implementation for radixes above 36.
+///
+/// Radixes above 36 use case-sensitive digits, `0-9a-zA-Z`, and have
+/// no pre-computed tables, so this writes 1 digit at a time.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is large enough to hold as many digits
+/// that can be in the largest value of `T`, in radix `N`.
+#[inline]
+pub unsafe fn algorithm_generic<T>(mut value: T, radix: u32, buffer: &mut [u8]) -> usize
+where
+    T: UnsignedInteger,
+{
+    debug_assert!(radix > 36 && is_valid_radix(radix));
+
+    // SAFETY: safe as long as the buffer can hold all the digits of `T::MAX`.
+    let divisor = T::from_u32(radix);
+    let mut index = buffer.len();
+    while value >= divisor {
+        let r = value % divisor;
+        value /= divisor;
+        index -= 1;
+        unsafe {
+            index_unchecked_mut!(buffer[index]) = digit_to_char_const(u32::as_cast(r), radix)
+        };
+    }
+    index -= 1;
+    unsafe {
+        index_unchecked_mut!(buffer[index]) = digit_to_char_const(u32::as_cast(value), radix)
+    };
+
+    index
+}
+
 /// Optimized implementation for radix-N 128-bit numbers.
 ///
 /// # Safety
@@ -3,12 +3,240 @@
 #![doc(hidden)]
 
 use crate::options::Options;
-use crate::write::WriteInteger;
+use crate::write::{base_prefix_char, base_suffix_char, group_digits, WriteInteger};
 use lexical_util::assert::{assert_buffer, debug_assert_buffer};
-use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::constants::FormattedSize;
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{Format, NumberFormat, STANDARD};
 use lexical_util::num::SignedInteger;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
+// GRAMMAR
+
+/// The characters and flags of the number format used to write integers.
+///
+/// The radix is always read from the `FORMAT` const generic, so runtime
+/// formats use a const generic with the same radix.
+#[derive(Clone, Copy)]
+struct Grammar {
+    digit_separator: u8,
+    base_prefix: u8,
+    base_suffix: u8,
+    required_mantissa_sign: bool,
+    required_base_prefix: bool,
+    required_base_suffix: bool,
+    case_sensitive_base_prefix: bool,
+    case_sensitive_base_suffix: bool,
+}
+
+impl Grammar {
+    /// Get the grammar from the `FORMAT` const generic.
+    #[inline(always)]
+    const fn from_const<const FORMAT: u128>() -> Self {
+        let format = NumberFormat::<FORMAT> {};
+        Self {
+            digit_separator: format.digit_separator(),
+            base_prefix: format.base_prefix(),
+            base_suffix: format.base_suffix(),
+            required_mantissa_sign: cfg!(feature = "format") && format.required_mantissa_sign(),
+            required_base_prefix: cfg!(feature = "format") && format.required_base_prefix(),
+            required_base_suffix: cfg!(feature = "format") && format.required_base_suffix(),
+            case_sensitive_base_prefix: cfg!(feature = "format")
+                && format.case_sensitive_base_prefix(),
+            case_sensitive_base_suffix: cfg!(feature = "format")
+                && format.case_sensitive_base_suffix(),
+        }
+    }
+
+    /// Get the grammar from a runtime format.
+    #[inline(always)]
+    const fn from_format(format: &Format) -> Self {
+        Self {
+            digit_separator: format.digit_separator(),
+            base_prefix: format.base_prefix(),
+            base_suffix: format.base_suffix(),
+            required_mantissa_sign: format.required_mantissa_sign(),
+            required_base_prefix: format.required_base_prefix(),
+            required_base_suffix: format.required_base_suffix(),
+            case_sensitive_base_prefix: format.case_sensitive_base_prefix(),
+            case_sensitive_base_suffix: format.case_sensitive_base_suffix(),
+        }
+    }
+}
+
+/// Create the format used to write the digits of a runtime format.
+#[cfg(feature = "power-of-two")]
+const fn digits_format(radix: u8) -> u128 {
+    NumberFormatBuilder::from_radix(radix)
+}
+
+/// Call `$body` with `$inner` as a const format with the same radix.
+macro_rules! with_digits_format {
+    ($radix:expr, $inner:ident => $body:expr) => {
+        with_digits_format!(
+            @arms $radix, $inner, $body ;
+            power_of_two: 2 4 8 16 32 ;
+            radix: 3 5 6 7 9 11 12 13 14 15 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 33 34 35 36
+        )
+    };
+    (
+        @arms $key:expr, $inner:ident, $body:expr ;
+        power_of_two: $($p:literal)* ;
+        radix: $($r:literal)*
+    ) => {
+        match $key {
+            $(
+                #[cfg(feature = "power-of-two")]
+                $p => {
+                    const $inner: u128 = digits_format($p);
+                    $body
+                },
+            )*
+            $(
+                #[cfg(feature = "radix")]
+                $r => {
+                    const $inner: u128 = digits_format($r);
+                    $body
+                },
+            )*
+            _ => {
+                const $inner: u128 = STANDARD;
+                $body
+            },
+        }
+    };
+}
+
+// PADDING
+
+/// Write the base prefix, the mantissa digits, and the base suffix,
+/// left-padding with `0` to the minimum digits.
+///
+/// Required base prefixes and suffixes are always written, and
+/// case-sensitive ones are never written in uppercase.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
+/// and `min_digits` elements, and the base prefix and suffix.
+#[inline(always)]
+unsafe fn write_digits<T, U, const FORMAT: u128>(
+    value: T,
+    buffer: &mut [u8],
+    grammar: Grammar,
+    options: &Options,
+) -> usize
+where
+    T: WriteInteger,
+    U: WriteInteger,
+{
+    let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
+    let uppercase = options.uppercase_base_prefix() && !grammar.case_sensitive_base_prefix;
+    let prefix = base_prefix_char(grammar.base_prefix, radix, uppercase);
+    let count = if (options.base_prefix() || grammar.required_base_prefix) && prefix != 0 {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE + 2` elements.
+        unsafe {
+            index_unchecked_mut!(buffer[0]) = b'0';
+            index_unchecked_mut!(buffer[1]) = prefix;
+            let buffer = &mut index_unchecked_mut!(buffer[2..]);
+            write_digits_impl::<T, U, FORMAT>(value, buffer, grammar, options) + 2
+        }
+    } else {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+        unsafe { write_digits_impl::<T, U, FORMAT>(value, buffer, grammar, options) }
+    };
+
+    let uppercase = options.uppercase_base_suffix() && !grammar.case_sensitive_base_suffix;
+    let suffix = base_suffix_char(grammar.base_suffix, radix, uppercase);
+    if (options.base_suffix() || grammar.required_base_suffix) && suffix != 0 {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE + 1` elements.
+        unsafe { index_unchecked_mut!(buffer[count]) = suffix };
+        count + 1
+    } else {
+        count
+    }
+}
+
+/// Write the mantissa digits, without a base prefix or suffix.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
+/// and `min_digits` elements.
+#[inline(always)]
+unsafe fn write_digits_impl<T, U, const FORMAT: u128>(
+    value: T,
+    buffer: &mut [u8],
+    grammar: Grammar,
+    options: &Options,
+) -> usize
+where
+    T: WriteInteger,
+    U: WriteInteger,
+{
+    // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+    let count = unsafe { value.write_mantissa::<U, FORMAT>(buffer) };
+    let min_digits = options.min_digits().map_or(0, |x| x.get());
+    let count = if count < min_digits {
+        let shift = min_digits - count;
+        buffer.copy_within(..count, shift);
+        // SAFETY: safe as long as there is at least `min_digits` elements.
+        unsafe {
+            slice_fill_unchecked!(index_unchecked_mut!(buffer[..shift]), b'0');
+        }
+        min_digits
+    } else {
+        count
+    };
+
+    let separator = grammar.digit_separator;
+    match options.digit_grouping() {
+        Some(primary) if separator != 0 => {
+            let primary = primary.get();
+            let secondary = options.secondary_digit_grouping().map_or(primary, |x| x.get());
+            group_digits(buffer, count, separator, primary, secondary)
+        },
+        _ => count,
+    }
+}
+
+/// Get the buffer size required to write padded, grouped, or affixed digits.
+///
+/// This includes room for a sign character and a closing parenthesis.
+#[inline(always)]
+fn padded_size<T: FormattedSize, const FORMAT: u128>(grammar: Grammar, options: &Options) -> usize {
+    // Padded digits need room for the digits and a sign.
+    let min_digits = options.min_digits().map_or(0, |x| x.get() + 1);
+    let prefix = 2 * (options.base_prefix() || grammar.required_base_prefix) as usize;
+    let suffix = (options.base_suffix() || grammar.required_base_suffix) as usize;
+    let count = if options.digit_grouping().is_some() || prefix != 0 || suffix != 0 {
+        let count = match NumberFormat::<FORMAT>::RADIX {
+            10 => T::FORMATTED_SIZE_DECIMAL,
+            _ => T::FORMATTED_SIZE,
+        };
+        count.max(min_digits)
+    } else if min_digits != 0 {
+        min_digits
+    } else {
+        return 0;
+    };
+    let separators = match options.digit_grouping() {
+        Some(grouping) => {
+            let secondary = options.secondary_digit_grouping().unwrap_or(grouping);
+            count / grouping.get().min(secondary.get())
+        },
+        None => 0,
+    };
+    // Unsigned types do not reserve any room for the leading space.
+    count
+        + separators
+        + prefix
+        + suffix
+        + options.space_positive() as usize
+        + options.parentheses_negative() as usize
+}
+
 // UNSIGNED
 
 /// Callback for unsigned integer formatter.
@@ -18,22 +246,33 @@ use lexical_util::{to_lexical, to_lexical_with_options};
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
 /// (or `FORMATTED_SIZE_DECIMAL` for decimal).
 #[inline]
-unsafe fn unsigned<Narrow, Wide, const FORMAT: u128>(value: Narrow, buffer: &mut [u8]) -> usize
+unsafe fn unsigned<Narrow, Wide, const FORMAT: u128>(
+    value: Narrow,
+    buffer: &mut [u8],
+    grammar: Grammar,
+    options: &Options,
+) -> usize
 where
     Narrow: WriteInteger,
     Wide: WriteInteger,
 {
-    let format = NumberFormat::<FORMAT> {};
-    if cfg!(feature = "format") && format.required_mantissa_sign() {
+    if grammar.required_mantissa_sign {
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
         unsafe {
             index_unchecked_mut!(buffer[0]) = b'+';
             let buffer = &mut index_unchecked_mut!(buffer[1..]);
-            value.write_mantissa::<Wide, FORMAT>(buffer) + 1
+            write_digits::<_, Wide, FORMAT>(value, buffer, grammar, options) + 1
+        }
+    } else if options.space_positive() {
+        // SAFETY: safe as long as there is at least `buffer_size` elements.
+        unsafe {
+            index_unchecked_mut!(buffer[0]) = b' ';
+            let buffer = &mut index_unchecked_mut!(buffer[1..]);
+            write_digits::<_, Wide, FORMAT>(value, buffer, grammar, options) + 1
         }
     } else {
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
-        unsafe { value.write_mantissa::<Wide, FORMAT>(buffer) }
+        unsafe { write_digits::<_, Wide, FORMAT>(value, buffer, grammar, options) }
     }
 }
 
@@ -49,13 +288,14 @@ where
 unsafe fn signed<Narrow, Wide, Unsigned, const FORMAT: u128>(
     value: Narrow,
     buffer: &mut [u8],
+    grammar: Grammar,
+    options: &Options,
 ) -> usize
 where
     Narrow: SignedInteger,
     Wide: SignedInteger,
     Unsigned: WriteInteger,
 {
-    let format = NumberFormat::<FORMAT> {};
     if value < Narrow::ZERO {
         // Need to cast the value to the same size as unsigned type, since if
         // the value is **exactly** `Narrow::MIN`, and it it is then cast
@@ -63,29 +303,50 @@ where
         // will have a very different value.
         let value = Wide::as_cast(value);
         let unsigned = Unsigned::as_cast(value.wrapping_neg());
+        if options.parentheses_negative() {
+            // SAFETY: safe as long as there is at least `FORMATTED_SIZE + 1` elements.
+            unsafe {
+                index_unchecked_mut!(buffer[0]) = b'(';
+                let digits = &mut index_unchecked_mut!(buffer[1..]);
+                let count = write_digits::<_, Unsigned, FORMAT>(unsigned, digits, grammar, options) + 1;
+                index_unchecked_mut!(buffer[count]) = b')';
+                count + 1
+            }
+        } else {
+            // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+            unsafe {
+                index_unchecked_mut!(buffer[0]) = b'-';
+                let buffer = &mut index_unchecked_mut!(buffer[1..]);
+                write_digits::<_, Unsigned, FORMAT>(unsigned, buffer, grammar, options) + 1
+            }
+        }
+    } else if grammar.required_mantissa_sign {
+        let unsigned = Unsigned::as_cast(value);
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
         unsafe {
-            index_unchecked_mut!(buffer[0]) = b'-';
+            index_unchecked_mut!(buffer[0]) = b'+';
             let buffer = &mut index_unchecked_mut!(buffer[1..]);
-            unsigned.write_mantissa::<Unsigned, FORMAT>(buffer) + 1
+            write_digits::<_, Unsigned, FORMAT>(unsigned, buffer, grammar, options) + 1
         }
-    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+    } else if options.space_positive() {
         let unsigned = Unsigned::as_cast(value);
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
         unsafe {
-            index_unchecked_mut!(buffer[0]) = b'+';
+            index_unchecked_mut!(buffer[0]) = b' ';
             let buffer = &mut index_unchecked_mut!(buffer[1..]);
-            unsigned.write_mantissa::<Unsigned, FORMAT>(buffer) + 1
+            write_digits::<_, Unsigned, FORMAT>(unsigned, buffer, grammar, options) + 1
         }
     } else {
         let unsigned = Unsigned::as_cast(value);
         // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
-        unsafe { unsigned.write_mantissa::<Unsigned, FORMAT>(buffer) }
+        unsafe { write_digits::<_, Unsigned, FORMAT>(unsigned, buffer, grammar, options) }
     }
 }
 
 // API
 
+const DEFAULT_OPTIONS: Options = Options::new();
+
 // Implement ToLexical for numeric type.
 macro_rules! unsigned_to_lexical {
     ($($narrow:tt $wide:tt $(, #[$meta:meta])? ; )*) => ($(
@@ -97,7 +358,7 @@ macro_rules! unsigned_to_lexical {
                 debug_assert_buffer::<$narrow>(10, bytes.len());
                 // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe {
-                    let len = unsigned::<$narrow, $wide, { STANDARD }>(self, bytes);
+                    let len = unsigned::<$narrow, $wide, { STANDARD }>(self, bytes, Grammar::from_const::<STANDARD>(), &DEFAULT_OPTIONS);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -119,14 +380,17 @@ macro_rules! unsigned_to_lexical {
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                // Unsigned types reserve no room for a sign, so the leading space needs 1 extra.
+                let sign = options.space_positive() as usize;
+                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len().saturating_sub(sign));
+                debug_assert!(bytes.len() >= padded_size::<$narrow, FORMAT>(Grammar::from_const::<FORMAT>(), options));
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE + sign`.
                 unsafe {
-                    let len = unsigned::<$narrow, $wide, FORMAT>(self, bytes);
+                    let len = unsigned::<$narrow, $wide, FORMAT>(self, bytes, Grammar::from_const::<FORMAT>(), options);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -138,11 +402,34 @@ macro_rules! unsigned_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                let sign = options.space_positive() as usize;
+                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len().saturating_sub(sign));
+                assert!(bytes.len() >= padded_size::<$narrow, FORMAT>(Grammar::from_const::<FORMAT>(), options), "Buffer is too small: may overwrite buffer, panicking!");
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE + sign`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn to_lexical_with_format<'a>(
+                self,
+                bytes: &'a mut [u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                let grammar = Grammar::from_format(format);
+                let sign = (options.space_positive() || grammar.required_mantissa_sign) as usize;
+                with_digits_format!(format.radix(), INNER => {
+                    assert_buffer::<$narrow>(format.radix(), bytes.len().saturating_sub(sign));
+                    assert!(bytes.len() >= padded_size::<$narrow, INNER>(grammar, options), "Buffer is too small: may overwrite buffer, panicking!");
+                    // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE + sign`.
+                    unsafe {
+                        let len = unsigned::<$narrow, $wide, INNER>(self, bytes, grammar, options);
+                        &mut index_unchecked_mut!(bytes[..len])
+                    }
+                })
+            }
         }
     )*)
 }
@@ -174,7 +461,7 @@ macro_rules! signed_to_lexical {
                 debug_assert_buffer::<$narrow>(10, bytes.len());
                 // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe {
-                    let len = signed::<$narrow, $wide, $unsigned, { STANDARD }>(self, bytes);
+                    let len = signed::<$narrow, $wide, $unsigned, { STANDARD }>(self, bytes, Grammar::from_const::<STANDARD>(), &DEFAULT_OPTIONS);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -196,14 +483,17 @@ macro_rules! signed_to_lexical {
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                // Signed types reserve room for a sign, but not a closing parenthesis.
+                let sign = options.parentheses_negative() as usize;
+                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len().saturating_sub(sign));
+                debug_assert!(bytes.len() >= padded_size::<$narrow, FORMAT>(Grammar::from_const::<FORMAT>(), options));
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE + sign`.
                 unsafe {
-                    let len = signed::<$narrow, $wide, $unsigned, FORMAT>(self, bytes);
+                    let len = signed::<$narrow, $wide, $unsigned, FORMAT>(self, bytes, Grammar::from_const::<FORMAT>(), options);
                     &mut index_unchecked_mut!(bytes[..len])
                 }
             }
@@ -215,11 +505,34 @@ macro_rules! signed_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                let sign = options.parentheses_negative() as usize;
+                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len().saturating_sub(sign));
+                assert!(bytes.len() >= padded_size::<$narrow, FORMAT>(Grammar::from_const::<FORMAT>(), options), "Buffer is too small: may overwrite buffer, panicking!");
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE + sign`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn to_lexical_with_format<'a>(
+                self,
+                bytes: &'a mut [u8],
+                format: &Format,
+                options: &Self::Options,
+            ) -> &'a mut [u8]
+            {
+                let grammar = Grammar::from_format(format);
+                let sign = options.parentheses_negative() as usize;
+                with_digits_format!(format.radix(), INNER => {
+                    assert_buffer::<$narrow>(format.radix(), bytes.len().saturating_sub(sign));
+                    assert!(bytes.len() >= padded_size::<$narrow, INNER>(grammar, options), "Buffer is too small: may overwrite buffer, panicking!");
+                    // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE + sign`.
+                    unsafe {
+                        let len = signed::<$narrow, $wide, $unsigned, INNER>(self, bytes, grammar, options);
+                        &mut index_unchecked_mut!(bytes[..len])
+                    }
+                })
+            }
         }
     )*)
 }
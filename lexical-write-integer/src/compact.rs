@@ -9,8 +9,8 @@
 use core::mem;
 
 use lexical_util::algorithm::copy_to_dst;
-use lexical_util::assert::debug_assert_radix;
-use lexical_util::digit::digit_to_char;
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::format::is_valid_radix;
 use lexical_util::num::{AsCast, UnsignedInteger};
 
 /// Write integral digits to buffer.
@@ -28,25 +28,27 @@ pub unsafe fn write_digits<T: UnsignedInteger>(
     buffer: &mut [u8],
     mut index: usize,
 ) -> usize {
-    debug_assert_radix(radix);
+    debug_assert!(is_valid_radix(radix));
 
     // SAFETY: All of these are safe for the buffer writes as long as
     // the buffer is large enough to hold `T::FORMATTED_SIZE` digits,
-    // and `radix <= 36`.
+    // and `radix <= 62`.
 
     // Decode all but the last digit.
-    let radix = T::from_u32(radix);
-    while value >= radix {
-        let r = value % radix;
-        value /= radix;
+    let divisor = T::from_u32(radix);
+    while value >= divisor {
+        let r = value % divisor;
+        value /= divisor;
         index -= 1;
-        unsafe { index_unchecked_mut!(buffer[index]) = digit_to_char(u32::as_cast(r)) };
+        unsafe {
+            index_unchecked_mut!(buffer[index]) = digit_to_char_const(u32::as_cast(r), radix)
+        };
     }
 
     // Decode last digit.
-    let r = value % radix;
+    let r = value % divisor;
     index -= 1;
-    unsafe { index_unchecked_mut!(buffer[index]) = digit_to_char(u32::as_cast(r)) };
+    unsafe { index_unchecked_mut!(buffer[index]) = digit_to_char_const(u32::as_cast(r), radix) };
 
     index
 }
@@ -8,14 +8,6 @@
 #![cfg_attr(feature = "compact", allow(unused_macros, unused_macro_rules))]
 #![doc(hidden)]
 
-/// Index a buffer, without bounds checking.
-#[cfg(not(feature = "safe"))]
-macro_rules! index_unchecked {
-    ($x:ident[$i:expr]) => {
-        *$x.get_unchecked($i)
-    };
-}
-
 /// Index a buffer and get a mutable reference, without bounds checking.
 // The `($x:ident[$i:expr] = $y:ident[$j:expr])` is not used with `compact`.
 // The newer version of the lint is `unused_macro_rules`, but this isn't
@@ -40,14 +32,6 @@ macro_rules! slice_fill_unchecked {
     };
 }
 
-/// Index a buffer, with bounds checking.
-#[cfg(feature = "safe")]
-macro_rules! index_unchecked {
-    ($x:ident[$i:expr]) => {
-        $x[$i]
-    };
-}
-
 /// Index a buffer and get a mutable reference, with bounds checking.
 // The `($x:ident[$i:expr] = $y:ident[$j:expr])` is not used with `compact`.
 // The newer version of the lint is `unused_macro_rules`, but this isn't
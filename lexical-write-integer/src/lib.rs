@@ -5,9 +5,14 @@
 //! leading to massive performance gains. In addition, decimal
 //! strings pre-calculate the number of digits, avoiding temporary buffers.
 //!
+//! With the `simd` feature, decimal strings are instead written 8 digits
+//! at a time, using SSE2 on x86 and x86_64, NEON on little-endian aarch64,
+//! and otherwise computing the digits in parallel within a 64-bit integer.
+//!
 //! A compact, fallback algorithm uses a naive, simple algorithm,
 //! where each loop generates a single digit. This comes at a performance
-//! penalty, but produces smaller binaries.
+//! penalty, but produces smaller binaries, since it uses repeated division
+//! without any lookup tables.
 //!
 //! # Features
 //!
@@ -16,6 +21,10 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `no-panic` - Remove all reachable panics from the writers, other than
+//!   for buffers that are too small.
+//! * `simd` - Use vectorized algorithms to write 8 decimal digits at a time.
+//! * `arbitrary` - Implement `Arbitrary` for options, for fuzzing.
 //!
 //! # Note
 //!
@@ -56,6 +65,7 @@ pub mod compact;
 pub mod decimal;
 pub mod options;
 pub mod radix;
+pub mod simd;
 pub mod table;
 pub mod write;
 
@@ -67,7 +77,7 @@ mod table_radix;
 // Re-exports
 pub use self::api::{ToLexical, ToLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, OptionsOverride};
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::format::{self, NumberFormatBuilder};
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{OverrideOptions, WriteOptions};
@@ -1,21 +1,226 @@
 //! Configuration options for writing integers.
-//!
-//! This is a dummy implementation, since writing integers never have options.
 
+use core::{mem, num};
+#[cfg(feature = "arbitrary")]
+use lexical_util::arbitrary::{self, Arbitrary, Unstructured};
 use lexical_util::constants::FormattedSize;
+use lexical_util::format::{Format, NumberFormat};
 use lexical_util::options::WriteOptions;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
+/// Type with the exact same size as a `usize`.
+pub type OptionUsize = Option<num::NonZeroUsize>;
+
+// Ensure the sizes are identical.
+const_assert!(mem::size_of::<OptionUsize>() == mem::size_of::<usize>());
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// Write a leading space in place of the sign for non-negative integers.
+    space_positive: bool,
+    /// Write negative integers in parentheses instead of with a leading `-`.
+    parentheses_negative: bool,
+    /// Write the base prefix, IE, `0x`, before the digits.
+    base_prefix: bool,
+    /// Write the base prefix character in uppercase, IE, `0X`.
+    uppercase_base_prefix: bool,
+    /// Write the base suffix, IE, `h`, after the digits.
+    base_suffix: bool,
+    /// Write the base suffix character in uppercase, IE, `H`.
+    uppercase_base_suffix: bool,
+    /// Minimum number of digits to write, left-padding with `0`.
+    min_digits: OptionUsize,
+    /// Number of digits between digit separators.
+    digit_grouping: OptionUsize,
+    /// Number of digits between digit separators after the first group.
+    secondary_digit_grouping: OptionUsize,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            space_positive: false,
+            parentheses_negative: false,
+            base_prefix: false,
+            uppercase_base_prefix: false,
+            base_suffix: false,
+            uppercase_base_suffix: false,
+            min_digits: None,
+            digit_grouping: None,
+            secondary_digit_grouping: None,
+        }
+    }
+
+    // GETTERS
+
+    /// Get if we should write a leading space for non-negative integers.
+    #[inline(always)]
+    pub const fn get_space_positive(&self) -> bool {
+        self.space_positive
+    }
+
+    /// Get if we should write negative integers in parentheses.
+    #[inline(always)]
+    pub const fn get_parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
+    /// Get if we should write the base prefix before the digits.
+    #[inline(always)]
+    pub const fn get_base_prefix(&self) -> bool {
+        self.base_prefix
+    }
+
+    /// Get if we should write the base prefix character in uppercase.
+    #[inline(always)]
+    pub const fn get_uppercase_base_prefix(&self) -> bool {
+        self.uppercase_base_prefix
+    }
+
+    /// Get if we should write the base suffix after the digits.
+    #[inline(always)]
+    pub const fn get_base_suffix(&self) -> bool {
+        self.base_suffix
+    }
+
+    /// Get if we should write the base suffix character in uppercase.
+    #[inline(always)]
+    pub const fn get_uppercase_base_suffix(&self) -> bool {
+        self.uppercase_base_suffix
+    }
+
+    /// Get the minimum number of digits to write.
+    #[inline(always)]
+    pub const fn get_min_digits(&self) -> OptionUsize {
+        self.min_digits
+    }
+
+    /// Get the number of digits between digit separators.
+    #[inline(always)]
+    pub const fn get_digit_grouping(&self) -> OptionUsize {
+        self.digit_grouping
+    }
+
+    /// Get the number of digits between digit separators after the first group.
+    #[inline(always)]
+    pub const fn get_secondary_digit_grouping(&self) -> OptionUsize {
+        self.secondary_digit_grouping
+    }
+
+    // SETTERS
+
+    /// Set if we should write a leading space for non-negative integers.
+    ///
+    /// This matches the space flag of `printf` (`% d`), and is used for
+    /// column-aligned output. A required mantissa sign (`+`) takes
+    /// precedence over the space. Unsigned types need 1 byte more than
+    /// `FORMATTED_SIZE` for the space, which is included in `buffer_size`.
+    #[inline(always)]
+    pub const fn space_positive(mut self, space_positive: bool) -> Self {
+        self.space_positive = space_positive;
+        self
+    }
+
+    /// Set if we should write negative integers in parentheses.
+    ///
+    /// This is the accounting style for negative values, IE, `(15)` rather
+    /// than `-15`, as used in financial exports.
+    #[inline(always)]
+    pub const fn parentheses_negative(mut self, parentheses_negative: bool) -> Self {
+        self.parentheses_negative = parentheses_negative;
+        self
+    }
+
+    /// Set if we should write the base prefix before the digits.
+    ///
+    /// The base prefix is `0` followed by the base prefix character of the
+    /// number format, or if the number format has none, `b`, `o`, or `x`
+    /// for binary, octal, and hexadecimal integers, respectively. It is
+    /// written after any sign and before any zero-padding, IE, `255` with
+    /// 4 minimum digits is written as `0x00FF`. Other radixes without a
+    /// base prefix character are written without a prefix.
+    #[inline(always)]
+    pub const fn base_prefix(mut self, base_prefix: bool) -> Self {
+        self.base_prefix = base_prefix;
+        self
+    }
+
+    /// Set if we should write the base prefix character in uppercase.
+    ///
+    /// If set, hexadecimal integers are written with a `0X` prefix rather
+    /// than `0x`. This has no effect unless `base_prefix` is set, or the
+    /// number format requires a base prefix, and has no effect if the
+    /// number format's base prefix is case-sensitive.
+    #[inline(always)]
+    pub const fn uppercase_base_prefix(mut self, uppercase_base_prefix: bool) -> Self {
+        self.uppercase_base_prefix = uppercase_base_prefix;
+        self
+    }
+
+    /// Set if we should write the base suffix after the digits.
+    ///
+    /// The base suffix is the base suffix character of the number format,
+    /// or if the number format has none, `b`, `o`, or `h` for binary, octal,
+    /// and hexadecimal integers, respectively, IE, `255` is written as `FFh`.
+    /// It is written before any closing parenthesis. Other radixes without
+    /// a base suffix character are written without a suffix.
+    #[inline(always)]
+    pub const fn base_suffix(mut self, base_suffix: bool) -> Self {
+        self.base_suffix = base_suffix;
+        self
+    }
+
+    /// Set if we should write the base suffix character in uppercase.
+    ///
+    /// If set, hexadecimal integers are written with a `H` suffix rather
+    /// than `h`. This has no effect unless `base_suffix` is set, or the
+    /// number format requires a base suffix, and has no effect if the
+    /// number format's base suffix is case-sensitive.
+    #[inline(always)]
+    pub const fn uppercase_base_suffix(mut self, uppercase_base_suffix: bool) -> Self {
+        self.uppercase_base_suffix = uppercase_base_suffix;
+        self
+    }
+
+    /// Set the minimum number of digits to write.
+    ///
+    /// If the integer has fewer digits, it is left-padded with `0` after
+    /// any sign, IE, `7` with 3 minimum digits is written as `007`, and
+    /// `-7` as `-007`. `None` or `1` disables padding.
+    #[inline(always)]
+    pub const fn min_digits(mut self, min_digits: OptionUsize) -> Self {
+        self.min_digits = min_digits;
+        self
+    }
+
+    /// Set the number of digits between digit separators.
+    ///
+    /// The digit separator is taken from the number format, so integers are
+    /// written with the same separator used to parse them, IE, `1000000`
+    /// with 3 digits per group and a `_` separator is written as
+    /// `1_000_000`. If the number format has no digit separator, this
+    /// has no effect. Zero-padding to `min_digits` is grouped as well.
+    #[inline(always)]
+    pub const fn digit_grouping(mut self, digit_grouping: OptionUsize) -> Self {
+        self.digit_grouping = digit_grouping;
+        self
+    }
+
+    /// Set the number of digits between digit separators after the first group.
+    ///
+    /// This allows grouping patterns where the first group, from the right,
+    /// has a different size than the following groups, IE, the Indian
+    /// numbering system with 3 digits and then 2 digits per group writes
+    /// `1234567` as `12,34,567`. If `None`, all groups have `digit_grouping`
+    /// digits. If `digit_grouping` is `None`, this has no effect.
+    #[inline(always)]
+    pub const fn secondary_digit_grouping(mut self, secondary_digit_grouping: OptionUsize) -> Self {
+        self.secondary_digit_grouping = secondary_digit_grouping;
+        self
     }
 
     // BUILDERS
@@ -33,7 +238,17 @@ impl OptionsBuilder {
     /// Safe as long as `is_valid` is true.
     #[inline(always)]
     pub const unsafe fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            space_positive: self.space_positive,
+            parentheses_negative: self.parentheses_negative,
+            base_prefix: self.base_prefix,
+            uppercase_base_prefix: self.uppercase_base_prefix,
+            base_suffix: self.base_suffix,
+            uppercase_base_suffix: self.uppercase_base_suffix,
+            min_digits: self.min_digits,
+            digit_grouping: self.digit_grouping,
+            secondary_digit_grouping: self.secondary_digit_grouping,
+        }
     }
 
     /// Build the Options struct.
@@ -60,24 +275,193 @@ impl Default for OptionsBuilder {
 ///
 /// # pub fn main() {
 /// let options = Options::builder()
+///     .space_positive(true)
 ///     .build()
 ///     .unwrap();
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// Write a leading space in place of the sign for non-negative integers.
+    space_positive: bool,
+    /// Write negative integers in parentheses instead of with a leading `-`.
+    parentheses_negative: bool,
+    /// Write the base prefix, IE, `0x`, before the digits.
+    base_prefix: bool,
+    /// Write the base prefix character in uppercase, IE, `0X`.
+    uppercase_base_prefix: bool,
+    /// Write the base suffix, IE, `h`, after the digits.
+    base_suffix: bool,
+    /// Write the base suffix character in uppercase, IE, `H`.
+    uppercase_base_suffix: bool,
+    /// Minimum number of digits to write, left-padding with `0`.
+    min_digits: OptionUsize,
+    /// Number of digits between digit separators.
+    digit_grouping: OptionUsize,
+    /// Number of digits between digit separators after the first group.
+    secondary_digit_grouping: OptionUsize,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        // SAFETY: always safe since it uses the default arguments.
+        unsafe { Self::builder().build_unchecked() }
     }
 
     /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.rebuild().is_valid()
+    }
+
+    // GETTERS
+
+    /// Get if we should write a leading space for non-negative integers.
+    #[inline(always)]
+    pub const fn space_positive(&self) -> bool {
+        self.space_positive
+    }
+
+    /// Get if we should write negative integers in parentheses.
+    #[inline(always)]
+    pub const fn parentheses_negative(&self) -> bool {
+        self.parentheses_negative
+    }
+
+    /// Get if we should write the base prefix before the digits.
+    #[inline(always)]
+    pub const fn base_prefix(&self) -> bool {
+        self.base_prefix
+    }
+
+    /// Get if we should write the base prefix character in uppercase.
+    #[inline(always)]
+    pub const fn uppercase_base_prefix(&self) -> bool {
+        self.uppercase_base_prefix
+    }
+
+    /// Get if we should write the base suffix after the digits.
+    #[inline(always)]
+    pub const fn base_suffix(&self) -> bool {
+        self.base_suffix
+    }
+
+    /// Get if we should write the base suffix character in uppercase.
+    #[inline(always)]
+    pub const fn uppercase_base_suffix(&self) -> bool {
+        self.uppercase_base_suffix
+    }
+
+    /// Get the minimum number of digits to write.
+    #[inline(always)]
+    pub const fn min_digits(&self) -> OptionUsize {
+        self.min_digits
+    }
+
+    /// Get the number of digits between digit separators.
+    #[inline(always)]
+    pub const fn digit_grouping(&self) -> OptionUsize {
+        self.digit_grouping
+    }
+
+    /// Get the number of digits between digit separators after the first group.
+    #[inline(always)]
+    pub const fn secondary_digit_grouping(&self) -> OptionUsize {
+        self.secondary_digit_grouping
+    }
+
+    // SETTERS
+
+    /// Set if we should write a leading space for non-negative integers.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_space_positive(&mut self, space_positive: bool) {
+        self.space_positive = space_positive;
+    }
+
+    /// Set if we should write negative integers in parentheses.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_parentheses_negative(&mut self, parentheses_negative: bool) {
+        self.parentheses_negative = parentheses_negative;
+    }
+
+    /// Set if we should write the base prefix before the digits.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_base_prefix(&mut self, base_prefix: bool) {
+        self.base_prefix = base_prefix;
+    }
+
+    /// Set if we should write the base prefix character in uppercase.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_uppercase_base_prefix(&mut self, uppercase_base_prefix: bool) {
+        self.uppercase_base_prefix = uppercase_base_prefix;
+    }
+
+    /// Set if we should write the base suffix after the digits.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_base_suffix(&mut self, base_suffix: bool) {
+        self.base_suffix = base_suffix;
+    }
+
+    /// Set if we should write the base suffix character in uppercase.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_uppercase_base_suffix(&mut self, uppercase_base_suffix: bool) {
+        self.uppercase_base_suffix = uppercase_base_suffix;
+    }
+
+    /// Set the minimum number of digits to write.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_min_digits(&mut self, min_digits: OptionUsize) {
+        self.min_digits = min_digits;
+    }
+
+    /// Set the number of digits between digit separators.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_digit_grouping(&mut self, digit_grouping: OptionUsize) {
+        self.digit_grouping = digit_grouping;
+    }
+
+    /// Set the number of digits between digit separators after the first group.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_secondary_digit_grouping(&mut self, secondary_digit_grouping: OptionUsize) {
+        self.secondary_digit_grouping = secondary_digit_grouping;
     }
 
     // BUILDERS
@@ -91,7 +475,17 @@ impl Options {
     /// Create OptionsBuilder using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            space_positive: self.space_positive,
+            parentheses_negative: self.parentheses_negative,
+            base_prefix: self.base_prefix,
+            uppercase_base_prefix: self.uppercase_base_prefix,
+            base_suffix: self.base_suffix,
+            uppercase_base_suffix: self.uppercase_base_suffix,
+            min_digits: self.min_digits,
+            digit_grouping: self.digit_grouping,
+            secondary_digit_grouping: self.secondary_digit_grouping,
+        }
     }
 }
 
@@ -110,10 +504,117 @@ impl WriteOptions for Options {
 
     #[inline(always)]
     fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        T::FORMATTED_SIZE
+        let format = NumberFormat::<FORMAT> {};
+        self.buffer_size_impl::<T>(
+            format.radix(),
+            cfg!(feature = "format") && format.required_mantissa_sign(),
+            format.required_base_prefix(),
+            format.required_base_suffix(),
+        )
+    }
+
+    #[inline(always)]
+    fn buffer_size_with_format<T: FormattedSize>(&self, format: &Format) -> usize {
+        self.buffer_size_impl::<T>(
+            format.radix(),
+            format.required_mantissa_sign(),
+            format.required_base_prefix(),
+            format.required_base_suffix(),
+        )
+    }
+}
+
+impl Options {
+    /// Get an upper bound on the buffer size, from the number format.
+    #[inline(always)]
+    fn buffer_size_impl<T: FormattedSize>(
+        &self,
+        radix: u32,
+        required_sign: bool,
+        required_base_prefix: bool,
+        required_base_suffix: bool,
+    ) -> usize {
+        // Add 1 for the leading space or a required sign, since unsigned
+        // types do not reserve any room for a sign, and 1 for the closing
+        // parenthesis. Padded digits need room for the digits and a sign,
+        // and grouped digits need room for the digit separators.
+        let min_digits = self.min_digits.map_or(0, |x| x.get() + 1);
+        let digits = match radix {
+            10 => T::FORMATTED_SIZE_DECIMAL,
+            _ => T::FORMATTED_SIZE,
+        };
+        let count = digits.max(min_digits);
+        let separators = match (self.digit_grouping, self.secondary_digit_grouping) {
+            (Some(primary), Some(secondary)) => count / primary.get().min(secondary.get()),
+            (Some(primary), None) => count / primary.get(),
+            _ => 0,
+        };
+        // Add 2 for the base prefix, and 1 for the base suffix,
+        // which are always written if required by the format.
+        let prefix = 2 * (self.base_prefix || required_base_prefix) as usize;
+        let suffix = (self.base_suffix || required_base_suffix) as usize;
+        count
+            + separators
+            + prefix
+            + suffix
+            + (self.space_positive || required_sign) as usize
+            + self.parentheses_negative as usize
     }
 }
 
+// ARBITRARY
+// ---------
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for OptionsBuilder {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            space_positive: u.arbitrary()?,
+            parentheses_negative: u.arbitrary()?,
+            base_prefix: u.arbitrary()?,
+            uppercase_base_prefix: u.arbitrary()?,
+            base_suffix: u.arbitrary()?,
+            uppercase_base_suffix: u.arbitrary()?,
+            min_digits: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            digit_grouping: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+            secondary_digit_grouping: num::NonZeroUsize::new(u8::arbitrary(u)? as usize),
+        })
+    }
+}
+
+// Only valid options are generated: invalid builder states are rejected.
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Options {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let builder: OptionsBuilder = u.arbitrary()?;
+        builder.build().map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+// OVERRIDES
+// ---------
+
+lexical_util::options_override! {
+    /// Set if we should write a leading space for non-negative integers.
+    space_positive: bool;
+    /// Set if we should write negative integers in parentheses.
+    parentheses_negative: bool;
+    /// Set if we should write the base prefix before the digits.
+    base_prefix: bool;
+    /// Set if we should write the base prefix character in uppercase.
+    uppercase_base_prefix: bool;
+    /// Set if we should write the base suffix after the digits.
+    base_suffix: bool;
+    /// Set if we should write the base suffix character in uppercase.
+    uppercase_base_suffix: bool;
+    /// Set the minimum number of digits to write.
+    min_digits: OptionUsize;
+    /// Set the number of digits between digit separators.
+    digit_grouping: OptionUsize;
+    /// Set the number of digits between digit separators after the first group.
+    secondary_digit_grouping: OptionUsize;
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
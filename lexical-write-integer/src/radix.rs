@@ -5,6 +5,10 @@
 //! reasons, it makes no sense to pre-compute the number of digits,
 //! and therefore
 //!
+//! Power-of-two radixes are the exception: the number of digits is
+//! calculated from the number of significant bits, so the digits are
+//! written directly to the buffer, using shifts and masks.
+//!
 //! See [Algorithm.md](/docs/Algorithm.md) for a more detailed description of
 //! the algorithm choice here.
 
@@ -12,7 +16,7 @@
 #![cfg(feature = "power-of-two")]
 #![doc(hidden)]
 
-use crate::algorithm::{algorithm, algorithm_u128};
+use crate::algorithm::{algorithm, algorithm_generic, algorithm_pow2, algorithm_u128};
 use crate::table::get_table;
 use core::mem;
 use lexical_util::algorithm::copy_to_dst;
@@ -58,12 +62,21 @@ macro_rules! radix_impl {
                 // SAFETY: safe as long as buffer is large enough to hold the max value.
                 // We never read unwritten values, and we never assume the data is initialized.
                 debug_assert!(<Self as Integer>::BITS <= 64);
+                let radix = format::radix_from_flags(FORMAT, MASK, SHIFT);
+                if radix.is_power_of_two() {
+                    let table = get_table::<FORMAT, MASK, SHIFT>();
+                    return unsafe { algorithm_pow2(self, radix, table, buffer) };
+                }
+
                 let mut digits: mem::MaybeUninit<[u8; 64]> = mem::MaybeUninit::uninit();
                 unsafe {
                     let digits = &mut *digits.as_mut_ptr();
-                    let radix = format::radix_from_flags(FORMAT, MASK, SHIFT);
-                    let table = get_table::<FORMAT, MASK, SHIFT>();
-                    let index = algorithm(self, radix, table, digits);
+                    let index = if cfg!(feature = "radix") && radix > 36 {
+                        algorithm_generic(self, radix, digits)
+                    } else {
+                        let table = get_table::<FORMAT, MASK, SHIFT>();
+                        algorithm(self, radix, table, digits)
+                    };
                     copy_to_dst(buffer, &mut index_unchecked_mut!(digits[index..]))
                 }
             }
@@ -82,11 +95,21 @@ impl Radix for u128 {
         // SAFETY: safe as long as buffer is large enough to hold the max value.
         // We never read unwritten values, and we never assume the data is initialized.
         // Need at least 128-bits, at least as many as the bits in the current type.
+        let radix = format::radix_from_flags(FORMAT, MASK, SHIFT);
+        if radix.is_power_of_two() {
+            let table = get_table::<FORMAT, MASK, SHIFT>();
+            return unsafe { algorithm_pow2(self, radix, table, buffer) };
+        }
+
         let mut digits: mem::MaybeUninit<[u8; 128]> = mem::MaybeUninit::uninit();
         unsafe {
             let digits = &mut *digits.as_mut_ptr();
-            let table = get_table::<FORMAT, MASK, SHIFT>();
-            let index = algorithm_u128::<FORMAT, MASK, SHIFT>(self, table, digits);
+            let index = if cfg!(feature = "radix") && radix > 36 {
+                algorithm_generic(self, radix, digits)
+            } else {
+                let table = get_table::<FORMAT, MASK, SHIFT>();
+                algorithm_u128::<FORMAT, MASK, SHIFT>(self, table, digits)
+            };
             copy_to_dst(buffer, &mut index_unchecked_mut!(digits[index..]))
         }
     }
@@ -0,0 +1,225 @@
+//! Vectorized, lexical integer-to-string conversion routines.
+//!
+//! These write 8 decimal digits at a time, splitting each chunk into
+//! 2 groups of 4 digits, and dividing every group by the powers of 10
+//! in parallel, based on "Fast Integer To String Conversion", by
+//! Wojciech Muła. This uses SSE2 on x86 and x86_64, NEON on little-endian
+//! aarch64, and otherwise computes the digits in parallel within a single
+//! 64-bit integer (SIMD-within-a-register), which is portable to all
+//! targets. The NEON intrinsics require Rust 1.59+.
+//!
+//! Only full chunks of 8 digits are vectorized: the leading digits
+//! are written with the scalar algorithm.
+
+#![cfg(not(feature = "compact"))]
+#![cfg(feature = "simd")]
+#![doc(hidden)]
+
+use crate::algorithm::algorithm;
+use crate::table::DIGIT_TO_BASE10_SQUARED;
+
+/// The number of digits written per chunk.
+const CHUNK: usize = 8;
+
+/// The divisor to split values into chunks of 8 digits.
+const CHUNK_DIVISOR: u64 = 100_000_000;
+
+/// Write exactly 8 digits, using the vector instructions of the target.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is at least 8 elements long, and
+/// `value < 10^8`.
+#[inline(always)]
+pub unsafe fn write_8_digits(value: u32, buffer: &mut [u8]) {
+    // SAFETY: safe as long as the buffer can hold 8 digits.
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    unsafe {
+        write_8_digits_sse2(value, buffer)
+    };
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon", target_endian = "little"))]
+    unsafe {
+        write_8_digits_neon(value, buffer)
+    };
+    #[cfg(not(any(
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon", target_endian = "little")
+    )))]
+    unsafe {
+        write_8_digits_swar(value, buffer)
+    };
+}
+
+/// Write exactly 8 digits, using SIMD-within-a-register.
+///
+/// Each step divides every lane in parallel, using multiplication by
+/// a reciprocal that is exact over the range of each lane: `x * 10486 >> 20`
+/// is `x / 100` for `x < 43699`, and `x * 103 >> 10` is `x / 10` for `x < 179`.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is at least 8 elements long, and
+/// `value < 10^8`.
+#[inline(always)]
+pub unsafe fn write_8_digits_swar(value: u32, buffer: &mut [u8]) {
+    debug_assert!(value < CHUNK_DIVISOR as u32);
+    debug_assert!(buffer.len() >= CHUNK);
+
+    // 32-bit lanes of [ abcd, efgh ].
+    let x = (value / 10000) as u64 | ((value % 10000) as u64) << 32;
+    // 16-bit lanes of [ ab, cd, ef, gh ].
+    let hi = ((x * 10486) >> 20) & 0x0000_007F_0000_007F;
+    let x = ((x - hi * 100) << 16) | hi;
+    // 8-bit lanes of [ a, b, c, d, e, f, g, h ].
+    let hi = ((x * 103) >> 10) & 0x000F_000F_000F_000F;
+    let x = ((x - hi * 10) << 8) | hi;
+
+    let ascii = (x + 0x3030_3030_3030_3030).to_le_bytes();
+    // SAFETY: safe as long as the buffer can hold 8 digits.
+    unsafe {
+        let dst = &mut index_unchecked_mut!(buffer[..CHUNK]);
+        dst.copy_from_slice(&ascii);
+    }
+}
+
+/// Write exactly 8 digits, using SSE2 intrinsics.
+///
+/// Each group of 4 digits, `x`, is broadcast to 4 16-bit lanes, which are
+/// divided by `1000`, `100`, `10` and `1` using the high bits of the
+/// products with scaled reciprocals: `4x * 8389 >> 25` is `x / 1000`,
+/// `4x * 5243 >> 21` is `x / 100` and `4x * 13108 >> 19` is `x / 10`,
+/// for `x < 10^4`. This gives `[ a, ab, abc, abcd ]`, and subtracting
+/// 10 times the previous lane leaves the digits.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is at least 8 elements long, and
+/// `value < 10^8`.
+#[inline(always)]
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+pub unsafe fn write_8_digits_sse2(value: u32, buffer: &mut [u8]) {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    debug_assert!(value < CHUNK_DIVISOR as u32);
+    debug_assert!(buffer.len() >= CHUNK);
+
+    // 16-bit lanes of [ 4abcd, 4efgh, 0, 0, 0, 0, 0, 0 ].
+    let x = ((value / 10000) | (value % 10000) << 16) << 2;
+    // SAFETY: safe since SSE2 is enabled, and the buffer can hold 8 digits.
+    unsafe {
+        let x = _mm_cvtsi32_si128(x as i32);
+        // [ 4abcd, 4abcd, 4abcd, 4abcd, 4efgh, 4efgh, 4efgh, 4efgh ].
+        let x = _mm_unpacklo_epi16(x, x);
+        let x = _mm_unpacklo_epi32(x, x);
+        // [ a, ab, abc, abcd, e, ef, efg, efgh ].
+        let div = _mm_setr_epi16(8389, 5243, 13108, -32768, 8389, 5243, 13108, -32768);
+        let shift = _mm_setr_epi16(128, 2048, 8192, -32768, 128, 2048, 8192, -32768);
+        let x = _mm_mulhi_epu16(_mm_mulhi_epu16(x, div), shift);
+        // [ a, b, c, d, e, f, g, h ].
+        let tens = _mm_slli_epi64(_mm_mullo_epi16(x, _mm_set1_epi16(10)), 16);
+        let digits = _mm_sub_epi16(x, tens);
+
+        let ascii = _mm_add_epi8(_mm_packus_epi16(digits, digits), _mm_set1_epi8(b'0' as i8));
+        _mm_storel_epi64(buffer.as_mut_ptr() as *mut __m128i, ascii);
+    }
+}
+
+/// Write exactly 8 digits, using NEON intrinsics.
+///
+/// Each group of 4 digits, `x`, is broadcast to 4 lanes, which are
+/// divided by `1000`, `100`, `10` and `1` using widening multiplication
+/// by reciprocals: `x * 8389 >> 23` is `x / 1000`, `x * 5243 >> 19` is
+/// `x / 100` and `x * 13108 >> 17` is `x / 10`, for `x < 10^4`. This
+/// gives `[ a, ab, abc, abcd ]`, and subtracting 10 times the previous
+/// lane leaves the digits.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is at least 8 elements long, and
+/// `value < 10^8`.
+#[inline(always)]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon", target_endian = "little"))]
+pub unsafe fn write_8_digits_neon(value: u32, buffer: &mut [u8]) {
+    use core::arch::aarch64::*;
+
+    const DIV: [u16; 4] = [8389, 5243, 13108, 1];
+    const SHIFT: [i32; 4] = [-23, -19, -17, 0];
+
+    debug_assert!(value < CHUNK_DIVISOR as u32);
+    debug_assert!(buffer.len() >= CHUNK);
+
+    let abcd = (value / 10000) as u16;
+    let efgh = (value % 10000) as u16;
+    // SAFETY: safe since NEON is enabled, and the buffer can hold 8 digits.
+    unsafe {
+        let div = vld1_u16(DIV.as_ptr());
+        let shift = vld1q_s32(SHIFT.as_ptr());
+        // [ a, ab, abc, abcd, e, ef, efg, efgh ].
+        let hi = vmovn_u32(vshlq_u32(vmull_u16(vdup_n_u16(abcd), div), shift));
+        let lo = vmovn_u32(vshlq_u32(vmull_u16(vdup_n_u16(efgh), div), shift));
+        let x = vcombine_u16(hi, lo);
+        // [ a, b, c, d, e, f, g, h ].
+        let tens = vreinterpretq_u64_u16(vmulq_n_u16(x, 10));
+        let tens = vreinterpretq_u16_u64(vshlq_n_u64::<16>(tens));
+        let digits = vsubq_u16(x, tens);
+
+        let ascii = vadd_u8(vmovn_u16(digits), vdup_n_u8(b'0'));
+        vst1_u8(buffer.as_mut_ptr(), ascii);
+    }
+}
+
+/// Write the decimal digits of a value, 8 digits at a time.
+///
+/// The buffer must be exactly the number of digits in the value long,
+/// since the digits are written from the end of the buffer.
+///
+/// # Safety
+///
+/// Safe as long as the buffer is exactly the number of digits in `value`.
+#[inline(always)]
+pub unsafe fn decimal(mut value: u64, buffer: &mut [u8]) {
+    // SAFETY: safe as long as the buffer can hold all the digits, since we
+    // only write full chunks when at least 8 more digits remain.
+    let mut index = buffer.len();
+    while value >= CHUNK_DIVISOR {
+        let chunk = (value % CHUNK_DIVISOR) as u32;
+        value /= CHUNK_DIVISOR;
+        index -= CHUNK;
+        unsafe { write_8_digits(chunk, &mut index_unchecked_mut!(buffer[index..])) };
+    }
+    unsafe {
+        let buffer = &mut index_unchecked_mut!(buffer[..index]);
+        algorithm(value as u32, 10, &DIGIT_TO_BASE10_SQUARED, buffer);
+    }
+}
+
+/// Write the decimal digits of a value, left-padded with `0` to the
+/// length of the buffer, 8 digits at a time.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold all the digits in `value`.
+#[inline(always)]
+pub unsafe fn decimal_padded(mut value: u64, buffer: &mut [u8]) {
+    // SAFETY: safe as long as the buffer can hold all the digits, since we
+    // only write full chunks when at least 8 more digits remain.
+    let mut index = buffer.len();
+    while index >= CHUNK {
+        let chunk = (value % CHUNK_DIVISOR) as u32;
+        value /= CHUNK_DIVISOR;
+        index -= CHUNK;
+        unsafe { write_8_digits(chunk, &mut index_unchecked_mut!(buffer[index..])) };
+    }
+    if index != 0 {
+        debug_assert!(value < 10u64.pow(index as u32));
+        unsafe {
+            let buffer = &mut index_unchecked_mut!(buffer[..index]);
+            let start = algorithm(value as u32, 10, &DIGIT_TO_BASE10_SQUARED, buffer);
+            let zeros = &mut index_unchecked_mut!(buffer[..start]);
+            slice_fill_unchecked!(zeros, b'0');
+        }
+    }
+}
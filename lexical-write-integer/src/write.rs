@@ -156,3 +156,118 @@ macro_rules! write_integer_impl {
 }
 
 write_integer_impl! { u8 u16 u32 u64 u128 usize }
+
+/// Get the number of digit separators required to group the digits.
+///
+/// The first group, from the right, has `primary` digits, and all
+/// following groups have `secondary` digits.
+#[inline(always)]
+pub const fn digit_separators(count: usize, primary: usize, secondary: usize) -> usize {
+    if count <= primary {
+        0
+    } else {
+        1 + (count - primary - 1) / secondary
+    }
+}
+
+/// Insert digit separators between groups of digits, from the right.
+///
+/// The first group, from the right, has `primary` digits, and all
+/// following groups have `secondary` digits. Returns the number of
+/// bytes written, including the separators.
+///
+/// # Panics
+///
+/// Panics if the buffer cannot hold the digits and the separators.
+#[inline]
+pub fn group_digits(
+    buffer: &mut [u8],
+    count: usize,
+    separator: u8,
+    primary: usize,
+    secondary: usize,
+) -> usize {
+    let length = count + digit_separators(count, primary, secondary);
+    let mut src = count;
+    let mut dst = length;
+    let mut digits = 0;
+    let mut grouping = primary;
+    // Once all the separators are written, the remaining digits are in place.
+    while src < dst {
+        if digits == grouping {
+            dst -= 1;
+            buffer[dst] = separator;
+            digits = 0;
+            grouping = secondary;
+        }
+        src -= 1;
+        dst -= 1;
+        buffer[dst] = buffer[src];
+        digits += 1;
+    }
+    length
+}
+
+/// Get the base prefix character for the number format.
+///
+/// This is the base prefix character of the number format, or if the
+/// number format has none, `b`, `o`, or `x` for binary, octal, and
+/// hexadecimal, respectively. Returns 0 if the radix has no base prefix.
+#[inline(always)]
+pub fn base_prefix<const FORMAT: u128>(uppercase: bool) -> u8 {
+    let format = format::NumberFormat::<FORMAT> {};
+    base_prefix_char(format.base_prefix(), format.mantissa_radix(), uppercase)
+}
+
+/// Get the base suffix character for the number format.
+///
+/// This is the base suffix character of the number format, or if the
+/// number format has none, `b`, `o`, or `h` for binary, octal, and
+/// hexadecimal, respectively. Returns 0 if the radix has no base suffix.
+#[inline(always)]
+pub fn base_suffix<const FORMAT: u128>(uppercase: bool) -> u8 {
+    let format = format::NumberFormat::<FORMAT> {};
+    base_suffix_char(format.base_suffix(), format.mantissa_radix(), uppercase)
+}
+
+/// Get the base prefix character from the format's base prefix and radix.
+///
+/// See [`base_prefix`] for the default characters for each radix.
+#[inline(always)]
+pub fn base_prefix_char(prefix: u8, radix: u32, uppercase: bool) -> u8 {
+    let prefix = match prefix {
+        0 => match radix {
+            2 => b'b',
+            8 => b'o',
+            16 => b'x',
+            _ => 0,
+        },
+        prefix => prefix,
+    };
+    if uppercase {
+        prefix.to_ascii_uppercase()
+    } else {
+        prefix
+    }
+}
+
+/// Get the base suffix character from the format's base suffix and radix.
+///
+/// See [`base_suffix`] for the default characters for each radix.
+#[inline(always)]
+pub fn base_suffix_char(suffix: u8, radix: u32, uppercase: bool) -> u8 {
+    let suffix = match suffix {
+        0 => match radix {
+            2 => b'b',
+            8 => b'o',
+            16 => b'h',
+            _ => 0,
+        },
+        suffix => suffix,
+    };
+    if uppercase {
+        suffix.to_ascii_uppercase()
+    } else {
+        suffix
+    }
+}
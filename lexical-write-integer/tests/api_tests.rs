@@ -2,11 +2,14 @@
 mod util;
 
 use core::fmt::Debug;
+#[cfg(feature = "format")]
+use core::num::NonZeroU8;
+use core::num::NonZeroUsize;
 use core::str::{from_utf8_unchecked, FromStr};
 use lexical_util::constants::FormattedSize;
 #[cfg(feature = "radix")]
 use lexical_util::constants::BUFFER_SIZE;
-#[cfg(feature = "format")]
+#[cfg(any(feature = "format", feature = "power-of-two"))]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
 use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
@@ -190,6 +193,30 @@ fn proptest_failures_radix() {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
     let options = Options::new();
     assert_eq!(b"A8", 128u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+
+    const HEX: u128 = from_radix(16);
+    let options = Options::builder().min_digits(NonZeroUsize::new(4)).build().unwrap();
+    assert_eq!(b"00FF", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix62_test() {
+    const BASE62: u128 = from_radix(62);
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    assert_eq!(b"a", 10u8.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(b"A", 36u8.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(b"-Z", (-61i8).to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(b"4GFfc3", u32::MAX.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options));
+    assert_eq!(
+        b"-aZl8N0y58M8",
+        i64::MIN.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options)
+    );
+    assert_eq!(
+        b"7N42dgm5tFLK9N8MT7fHC7",
+        u128::MAX.to_lexical_with_options::<{ BASE62 }>(&mut buffer, &options)
+    );
 }
 
 #[test]
@@ -208,6 +235,285 @@ fn options_test() {
     assert_eq!(b"0", 0i128.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
 }
 
+#[test]
+fn space_positive_test() {
+    let mut buffer = [b'\x00'; 48];
+    let options = Options::builder().space_positive(true).build().unwrap();
+    assert_eq!(b" 0", 0u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b" 255", 255u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b" 1", 1i32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"-1", (-1i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let mut buffer = [b'\x00'; 4];
+    assert_eq!(b" 255", 255u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+}
+
+#[test]
+fn space_positive_max_test() {
+    use lexical_util::options::WriteOptions;
+
+    let options = Options::builder().space_positive(true).build().unwrap();
+    macro_rules! check {
+        ($($t:ty)*) => ($({
+            let mut buffer = vec![b'\x00'; options.buffer_size::<$t, STANDARD>()];
+            let expected = format!(" {}", <$t>::MAX);
+            let result = <$t>::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+            assert_eq!(result, expected.as_bytes());
+        })*);
+    }
+    check! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+}
+
+#[test]
+fn parentheses_negative_test() {
+    let mut buffer = [b'\x00'; 48];
+    let options = Options::builder().parentheses_negative(true).build().unwrap();
+    assert_eq!(b"(15)", (-15i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"15", 15i32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"0", 0i64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"255", 255u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let mut buffer = [b'\x00'; 5];
+    assert_eq!(b"(128)", i8::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+}
+
+#[test]
+fn min_digits_test() {
+    let mut buffer = [b'\x00'; 48];
+    let options = Options::builder().min_digits(NonZeroUsize::new(3)).build().unwrap();
+    assert_eq!(b"007", 7u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"-007", (-7i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"000", 0u64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"255", 255u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"1234", 1234i64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options =
+        options.rebuild().space_positive(true).parentheses_negative(true).build().unwrap();
+    assert_eq!(b" 042", 42u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"(042)", (-42i8).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options = Options::builder().min_digits(NonZeroUsize::new(40)).build().unwrap();
+    let mut buffer = [b'\x00'; 41];
+    let expected = format!("-{:0>40}", 128);
+    let result = i8::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(expected.as_bytes(), result);
+}
+
+#[test]
+#[should_panic]
+fn min_digits_buffer_test() {
+    let mut buffer = [b'\x00'; 40];
+    let options = Options::builder().min_digits(NonZeroUsize::new(40)).build().unwrap();
+    1u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn digit_grouping_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .build();
+    let mut buffer = [b'\x00'; 64];
+    let options = Options::builder().digit_grouping(NonZeroUsize::new(3)).build().unwrap();
+    assert_eq!(b"0", 0u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"999", 999u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"1_000", 1000u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(
+        b"1_000_000",
+        1000000u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+    assert_eq!(b"-128", i8::MIN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(
+        b"-9_223_372_036_854_775_808",
+        i64::MIN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+    assert_eq!(
+        b"340_282_366_920_938_463_463_374_607_431_768_211_455",
+        u128::MAX.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+
+    let options = options.rebuild().min_digits(NonZeroUsize::new(6)).build().unwrap();
+    assert_eq!(b"000_042", 42u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+
+    let options = options.rebuild().parentheses_negative(true).build().unwrap();
+    assert_eq!(
+        b"(012_345)",
+        (-12345i32).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+
+    let options = Options::builder().digit_grouping(NonZeroUsize::new(4)).build().unwrap();
+    assert_eq!(
+        b"1_0000_0000",
+        100000000u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+
+    let options = Options::builder()
+        .digit_grouping(NonZeroUsize::new(3))
+        .secondary_digit_grouping(NonZeroUsize::new(2))
+        .build()
+        .unwrap();
+    assert_eq!(
+        b"12_34_567",
+        1234567u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+    assert_eq!(
+        b"-10_00_00_000",
+        (-100000000i32).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+
+    // Without a digit separator, grouping has no effect.
+    assert_eq!(
+        b"100000000",
+        100000000u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn base_prefix_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    const OCTAL: u128 = NumberFormatBuilder::from_radix(8);
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    let mut buffer = [b'\x00'; 128];
+    let options = Options::builder().base_prefix(true).build().unwrap();
+    assert_eq!(b"0b101", 5u8.to_lexical_with_options::<{ BINARY }>(&mut buffer, &options));
+    assert_eq!(b"0o17", 15u32.to_lexical_with_options::<{ OCTAL }>(&mut buffer, &options));
+    assert_eq!(b"0xFF", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+    assert_eq!(b"-0x80", i8::MIN.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+    assert_eq!(b"255", 255u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options = options
+        .rebuild()
+        .uppercase_base_prefix(true)
+        .min_digits(NonZeroUsize::new(4))
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    assert_eq!(b"0X00FF", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+    assert_eq!(b"(0X0080)", i8::MIN.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+
+    let options = Options::builder().base_prefix(true).build().unwrap();
+    let mut buffer = [b'\x00'; u128::FORMATTED_SIZE + 2];
+    let expected = format!("0b{:b}", u128::MAX);
+    let result = u128::MAX.to_lexical_with_options::<{ BINARY }>(&mut buffer, &options);
+    assert_eq!(expected.as_bytes(), result);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn base_suffix_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    const OCTAL: u128 = NumberFormatBuilder::from_radix(8);
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    let mut buffer = [b'\x00'; 128];
+    let options = Options::builder().base_suffix(true).build().unwrap();
+    assert_eq!(b"1010b", 10u8.to_lexical_with_options::<{ BINARY }>(&mut buffer, &options));
+    assert_eq!(b"17o", 15u32.to_lexical_with_options::<{ OCTAL }>(&mut buffer, &options));
+    assert_eq!(b"FFh", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+    assert_eq!(b"-80h", i8::MIN.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+    assert_eq!(b"255", 255u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options = options
+        .rebuild()
+        .uppercase_base_suffix(true)
+        .min_digits(NonZeroUsize::new(4))
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    assert_eq!(b"00FFH", 255u8.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+    assert_eq!(b"(0080H)", i8::MIN.to_lexical_with_options::<{ HEX }>(&mut buffer, &options));
+
+    let options = Options::builder().base_suffix(true).build().unwrap();
+    let mut buffer = [b'\x00'; u128::FORMATTED_SIZE + 1];
+    let expected = format!("{:b}b", u128::MAX);
+    let result = u128::MAX.to_lexical_with_options::<{ BINARY }>(&mut buffer, &options);
+    assert_eq!(expected.as_bytes(), result);
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn format_base_prefix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().base_prefix(NonZeroU8::new(b'd')).build();
+    let mut buffer = [b'\x00'; 64];
+    let options = Options::builder().base_prefix(true).build().unwrap();
+    assert_eq!(b"0d255", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    let options = options.rebuild().uppercase_base_prefix(true).build().unwrap();
+    assert_eq!(b"0D255", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn format_base_suffix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().base_suffix(NonZeroU8::new(b'd')).build();
+    let mut buffer = [b'\x00'; 64];
+    let options = Options::builder().base_suffix(true).build().unwrap();
+    assert_eq!(b"255d", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    let options = options.rebuild().uppercase_base_suffix(true).build().unwrap();
+    assert_eq!(b"255D", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn r_literal_test() {
+    use lexical_util::format::R_LITERAL;
+
+    let mut buffer = [b'\x00'; 64];
+    let options = Options::builder().base_suffix(true).uppercase_base_suffix(true).build().unwrap();
+    assert_eq!(b"100L", 100u32.to_lexical_with_options::<{ R_LITERAL }>(&mut buffer, &options));
+    assert_eq!(b"-5L", (-5i64).to_lexical_with_options::<{ R_LITERAL }>(&mut buffer, &options));
+    let options = Options::new();
+    assert_eq!(b"100", 100u32.to_lexical_with_options::<{ R_LITERAL }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn required_base_affix_test() {
+    use lexical_util::options::WriteOptions;
+
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .radix(16)
+        .base_prefix(NonZeroU8::new(b'x'))
+        .base_suffix(NonZeroU8::new(b'h'))
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .build();
+    let mut buffer = [b'\x00'; 64];
+    let options = Options::new();
+    assert_eq!(b"0xFFh", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"0x0h", 0u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"-0x80h", i8::MIN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+
+    let options = Options::builder()
+        .uppercase_base_prefix(true)
+        .uppercase_base_suffix(true)
+        .parentheses_negative(true)
+        .build()
+        .unwrap();
+    assert_eq!(b"0XFFH", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"(0X80H)", i8::MIN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+
+    // Case-sensitive base prefixes and suffixes are never written in uppercase.
+    const CASE_SENSITIVE: u128 = NumberFormatBuilder::rebuild(FORMAT)
+        .case_sensitive_base_prefix(true)
+        .case_sensitive_base_suffix(true)
+        .build();
+    assert_eq!(
+        b"0xFFh",
+        255u8.to_lexical_with_options::<{ CASE_SENSITIVE }>(&mut buffer, &options)
+    );
+
+    // The buffer size includes the required base prefix and suffix.
+    const BINARY: u128 = NumberFormatBuilder::rebuild(FORMAT).radix(2).build();
+    let options = Options::new();
+    let size = options.buffer_size::<u128, BINARY>();
+    assert_eq!(size, u128::FORMATTED_SIZE + 3);
+    let mut buffer = vec![b'\x00'; size];
+    let expected = format!("0x{:b}h", u128::MAX);
+    let result = u128::MAX.to_lexical_with_options::<{ BINARY }>(&mut buffer, &options);
+    assert_eq!(expected.as_bytes(), result);
+}
+
 #[test]
 #[cfg(feature = "radix")]
 fn options_radix_test() {
@@ -0,0 +1,64 @@
+#![cfg(feature = "compact")]
+
+use core::str::from_utf8_unchecked;
+use lexical_write_integer::compact::{self, Compact};
+use quickcheck::quickcheck;
+
+fn write_digits<T: Compact>(value: T, radix: u32, expected: &[u8]) {
+    let mut buffer = [b'\x00'; 128];
+    let length = buffer.len();
+    let index = unsafe { compact::write_digits(value, radix, &mut buffer, length) };
+    assert_eq!(&buffer[index..], expected);
+}
+
+#[test]
+fn write_digits_test() {
+    write_digits(0u8, 10, b"0");
+    write_digits(9u8, 10, b"9");
+    write_digits(10u8, 10, b"10");
+    write_digits(u8::MAX, 10, b"255");
+    write_digits(u32::MAX, 10, b"4294967295");
+    write_digits(u128::MAX, 10, b"340282366920938463463374607431768211455");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn write_digits_power_of_two_test() {
+    write_digits(5u32, 2, b"101");
+    write_digits(u64::MAX, 16, b"FFFFFFFFFFFFFFFF");
+    write_digits(u128::MAX, 2, &[b'1'; 128]);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn write_digits_radix_test() {
+    write_digits(1295u32, 36, b"ZZ");
+    write_digits(10u32, 37, b"a");
+    write_digits(36u32, 37, b"A");
+    write_digits(3843u32, 62, b"ZZ");
+}
+
+fn compact<T: Compact>(value: T, radix: u32) -> String {
+    let mut buffer = [b'\x00'; 128];
+    let count = unsafe { value.compact(radix, &mut buffer) };
+    unsafe { from_utf8_unchecked(&buffer[..count]) }.to_string()
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn u32_quickcheck(x: u32) -> bool {
+        compact(x, 10) == x.to_string()
+    }
+
+    #[cfg_attr(miri, ignore)]
+    fn u64_quickcheck(x: u64) -> bool {
+        compact(x, 10) == x.to_string()
+            && (!cfg!(feature = "power-of-two") || compact(x, 16) == format!("{:X}", x))
+    }
+
+    #[cfg_attr(miri, ignore)]
+    fn u128_quickcheck(x: u128) -> bool {
+        compact(x, 10) == x.to_string()
+            && (!cfg!(feature = "power-of-two") || compact(x, 2) == format!("{:b}", x))
+    }
+}
@@ -400,6 +400,27 @@ fn u128toa_test() {
         assert_eq!(10000000000000000000000000000000001u128.decimal(&mut buffer), 35);
         assert_eq!(&buffer[..35], b"10000000000000000000000000000000001");
 
+        assert_eq!(18446744073709551616u128.decimal(&mut buffer), 20);
+        assert_eq!(&buffer[..20], b"18446744073709551616");
+
+        assert_eq!(184467440737095516150000000000000000001u128.decimal(&mut buffer), 39);
+        assert_eq!(&buffer[..39], b"184467440737095516150000000000000000001");
+
+        assert_eq!(99999999999999999999999999999999999999u128.decimal(&mut buffer), 38);
+        assert_eq!(&buffer[..38], b"99999999999999999999999999999999999999");
+
+        assert_eq!(100000000000000000000000000000000000000u128.decimal(&mut buffer), 39);
+        assert_eq!(&buffer[..39], b"100000000000000000000000000000000000000");
+
+        assert_eq!(100000000000000000000000000000000000001u128.decimal(&mut buffer), 39);
+        assert_eq!(&buffer[..39], b"100000000000000000000000000000000000001");
+
+        assert_eq!(100000000000000000009999999999999999999u128.decimal(&mut buffer), 39);
+        assert_eq!(&buffer[..39], b"100000000000000000009999999999999999999");
+
+        assert_eq!(170141183460469231731687303715884105733u128.decimal(&mut buffer), 39);
+        assert_eq!(&buffer[..39], b"170141183460469231731687303715884105733");
+
         assert_eq!(340282366920938463463374607431768211455u128.decimal(&mut buffer), 39);
         assert_eq!(&buffer[..39], b"340282366920938463463374607431768211455");
     }
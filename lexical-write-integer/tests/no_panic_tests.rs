@@ -0,0 +1,57 @@
+// These tests only link if the writers have no reachable panics, which
+// requires optimizations: run with `cargo test --release --features no-panic`.
+// The wrappers are never inlined, so constant arguments cannot hide a panic.
+// The checked writers still panic if the buffer is too small, so these
+// check the digit generation with buffers that are always large enough.
+#![cfg(all(feature = "no-panic", not(debug_assertions)))]
+
+use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+use lexical_util::format::STANDARD;
+use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
+use no_panic::no_panic;
+
+const OPTIONS: Options = Options::new();
+
+#[no_panic]
+#[inline(never)]
+fn write_u8(value: u8, buffer: &mut [u8; u8::FORMATTED_SIZE_DECIMAL]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `u8`.
+    unsafe { value.to_lexical_unchecked(buffer) }
+}
+
+#[no_panic]
+#[inline(never)]
+fn write_i32(value: i32, buffer: &mut [u8; i32::FORMATTED_SIZE_DECIMAL]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `i32`.
+    unsafe { value.to_lexical_unchecked(buffer) }
+}
+
+#[no_panic]
+#[inline(never)]
+fn write_u64(value: u64, buffer: &mut [u8; u64::FORMATTED_SIZE_DECIMAL]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `u64`.
+    unsafe { value.to_lexical_unchecked(buffer) }
+}
+
+#[no_panic]
+#[inline(never)]
+fn write_i128(value: i128, buffer: &mut [u8; i128::FORMATTED_SIZE_DECIMAL]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `i128`.
+    unsafe { value.to_lexical_unchecked(buffer) }
+}
+
+#[no_panic]
+#[inline(never)]
+fn write_options_u64(value: u64, buffer: &mut [u8; BUFFER_SIZE]) -> &[u8] {
+    // SAFETY: safe since the buffer can hold any `u64` with the default options.
+    unsafe { value.to_lexical_with_options_unchecked::<STANDARD>(buffer, &OPTIONS) }
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write_u8(255, &mut [0; u8::FORMATTED_SIZE_DECIMAL]), b"255");
+    assert_eq!(write_i32(i32::MIN, &mut [0; i32::FORMATTED_SIZE_DECIMAL]), b"-2147483648");
+    assert_eq!(write_u64(u64::MAX, &mut [0; u64::FORMATTED_SIZE_DECIMAL]), b"18446744073709551615");
+    assert_eq!(write_i128(-1, &mut [0; i128::FORMATTED_SIZE_DECIMAL]), b"-1");
+    assert_eq!(write_options_u64(1234, &mut [0; BUFFER_SIZE]), b"1234");
+}
@@ -1,3 +1,7 @@
+use core::num::NonZeroUsize;
+use lexical_util::constants::FormattedSize;
+use lexical_util::format::STANDARD;
+use lexical_util::options::WriteOptions;
 use lexical_write_integer::options::{Options, OptionsBuilder};
 
 #[test]
@@ -10,3 +14,99 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(X.rebuild(), Options::builder());
 }
+
+#[test]
+fn builder_tests() {
+    let builder = OptionsBuilder::new().space_positive(true);
+    assert!(builder.get_space_positive());
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert!(options.space_positive());
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL + 1);
+    unsafe { options.set_space_positive(false) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().parentheses_negative(true);
+    assert!(builder.get_parentheses_negative());
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert!(options.parentheses_negative());
+    unsafe { options.set_parentheses_negative(false) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().base_prefix(true).uppercase_base_prefix(true);
+    assert!(builder.get_base_prefix());
+    assert!(builder.get_uppercase_base_prefix());
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert!(options.base_prefix());
+    assert!(options.uppercase_base_prefix());
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL + 2);
+    unsafe { options.set_base_prefix(false) };
+    unsafe { options.set_uppercase_base_prefix(false) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().base_suffix(true).uppercase_base_suffix(true);
+    assert!(builder.get_base_suffix());
+    assert!(builder.get_uppercase_base_suffix());
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert!(options.base_suffix());
+    assert!(options.uppercase_base_suffix());
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL + 1);
+    unsafe { options.set_base_suffix(false) };
+    unsafe { options.set_uppercase_base_suffix(false) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().min_digits(NonZeroUsize::new(3));
+    assert_eq!(builder.get_min_digits(), NonZeroUsize::new(3));
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.min_digits(), NonZeroUsize::new(3));
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL);
+    unsafe { options.set_min_digits(NonZeroUsize::new(100)) };
+    assert_eq!(options.buffer_size::<u8, { STANDARD }>(), 101);
+    unsafe { options.set_min_digits(None) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().digit_grouping(NonZeroUsize::new(3));
+    assert_eq!(builder.get_digit_grouping(), NonZeroUsize::new(3));
+    assert!(builder.is_valid());
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.digit_grouping(), NonZeroUsize::new(3));
+    let count = u64::FORMATTED_SIZE_DECIMAL;
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), count + count / 3);
+    unsafe { options.set_digit_grouping(None) };
+    assert_eq!(options, Options::new());
+
+    let builder = OptionsBuilder::new().secondary_digit_grouping(NonZeroUsize::new(2));
+    assert_eq!(builder.get_secondary_digit_grouping(), NonZeroUsize::new(2));
+    assert!(builder.is_valid());
+
+    let mut options = builder.digit_grouping(NonZeroUsize::new(3)).build().unwrap();
+    assert_eq!(options.secondary_digit_grouping(), NonZeroUsize::new(2));
+    let count = u64::FORMATTED_SIZE_DECIMAL;
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), count + count / 2);
+    unsafe { options.set_digit_grouping(None) };
+    unsafe { options.set_secondary_digit_grouping(None) };
+    assert_eq!(options, Options::new());
+}
+
+#[test]
+fn override_test() {
+    use lexical_util::options::OverrideOptions;
+    use lexical_write_integer::options::OptionsOverride;
+
+    let options = Options::builder().space_positive(true).build().unwrap();
+    let overrides = OptionsOverride::new().min_digits(NonZeroUsize::new(4)).space_positive(false);
+    let result = options.with_override(&overrides).unwrap();
+    assert_eq!(result.min_digits(), NonZeroUsize::new(4));
+    assert!(!result.space_positive());
+    assert!(options.space_positive());
+}
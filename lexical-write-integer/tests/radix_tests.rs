@@ -50,6 +50,35 @@ fn binary_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "power-of-two")]
+fn power_of_two_test() {
+    const OCTAL: u128 = from_radix(8);
+    write_integer::<u32, OCTAL>(0u32, b"0");
+    write_integer::<u32, OCTAL>(7u32, b"7");
+    write_integer::<u32, OCTAL>(8u32, b"10");
+    write_integer::<u32, OCTAL>(64u32, b"100");
+    write_integer::<u32, OCTAL>(u32::MAX, b"37777777777");
+    write_integer::<u64, OCTAL>(u64::MAX, b"1777777777777777777777");
+    write_integer::<u128, OCTAL>(u128::MAX, b"3777777777777777777777777777777777777777777");
+
+    const HEX: u128 = from_radix(16);
+    write_integer::<u32, HEX>(0u32, b"0");
+    write_integer::<u32, HEX>(0xFu32, b"F");
+    write_integer::<u32, HEX>(0x10u32, b"10");
+    write_integer::<u32, HEX>(0x100u32, b"100");
+    write_integer::<u32, HEX>(0xDEADBEEFu32, b"DEADBEEF");
+    write_integer::<u64, HEX>(0x123456789ABCDEFu64, b"123456789ABCDEF");
+    write_integer::<u64, HEX>(u64::MAX, b"FFFFFFFFFFFFFFFF");
+    write_integer::<u128, HEX>(u128::MAX, b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+
+    const BASE32: u128 = from_radix(32);
+    write_integer::<u32, BASE32>(31u32, b"V");
+    write_integer::<u32, BASE32>(32u32, b"10");
+    write_integer::<u32, BASE32>(u32::MAX, b"3VVVVVV");
+    write_integer::<u128, BASE32>(u128::MAX, b"7VVVVVVVVVVVVVVVVVVVVVVVVV");
+}
+
 #[test]
 #[cfg(feature = "radix")]
 fn radix_test() {
@@ -90,6 +119,23 @@ fn radix_test() {
     write_integer::<u32, { from_radix(36) }>(37u32, b"11");
 }
 
+#[test]
+#[cfg(feature = "radix")]
+fn radix62_test() {
+    write_integer::<u32, { from_radix(37) }>(36u32, b"A");
+    write_integer::<u32, { from_radix(37) }>(37u32, b"10");
+    write_integer::<u32, { from_radix(40) }>(12345678u32, b"4wA1C");
+    write_integer::<u32, { from_radix(62) }>(0u32, b"0");
+    write_integer::<u32, { from_radix(62) }>(10u32, b"a");
+    write_integer::<u32, { from_radix(62) }>(37u32, b"B");
+    write_integer::<u32, { from_radix(62) }>(61u32, b"Z");
+    write_integer::<u32, { from_radix(62) }>(u32::MAX, b"4GFfc3");
+    write_integer::<u64, { from_radix(58) }>(u64::MAX, b"GLubwBAfCsn");
+    write_integer::<u64, { from_radix(62) }>(u64::MAX, b"lYGhA16ahyf");
+    write_integer::<u128, { from_radix(37) }>(u128::MAX, b"7vjunjqs9ismyb6hxocnwpcmw");
+    write_integer::<u128, { from_radix(62) }>(u128::MAX, b"7N42dgm5tFLK9N8MT7fHC7");
+}
+
 // We need to trick the algorithm into thinking we're using a const.
 // Useful for proptests, useless everywhere else.
 macro_rules! to_radix {
@@ -0,0 +1,137 @@
+#[cfg(feature = "power-of-two")]
+mod util;
+
+use core::num::NonZeroUsize;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::{Format, STANDARD};
+use lexical_util::options::WriteOptions;
+use lexical_write_integer::{Options, ToLexicalWithOptions};
+use proptest::prelude::*;
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+/// Assert the runtime writer matches the const generic writer for every value.
+macro_rules! assert_runtime_eq {
+    ($format:ident, $options:expr ; $($t:ty)*) => {{
+        let format = Format::new($format).unwrap();
+        let options = $options;
+        $(
+            for &value in [<$t>::MIN, <$t>::MAX, 0 as $t, 1 as $t, 7 as $t, 100 as $t, <$t>::MAX / 3].iter() {
+                let mut expected = [b'\x00'; 512];
+                let mut actual = [b'\x00'; 512];
+                let size = options.buffer_size_with_format::<$t>(&format);
+                assert!(size >= options.buffer_size::<$t, $format>());
+                assert_eq!(
+                    value.to_lexical_with_options::<$format>(&mut expected, &options),
+                    value.to_lexical_with_format(&mut actual[..size], &format, &options),
+                    "{:?}", value
+                );
+            }
+        )*
+    }};
+}
+
+/// Assert the runtime writer matches for the default and formatting options.
+macro_rules! assert_format_eq {
+    ($format:ident) => {{
+        let affix = Options::builder()
+            .base_prefix(true)
+            .base_suffix(true)
+            .uppercase_base_prefix(true)
+            .build()
+            .unwrap();
+        let padded = Options::builder()
+            .min_digits(NonZeroUsize::new(12))
+            .digit_grouping(NonZeroUsize::new(3))
+            .secondary_digit_grouping(NonZeroUsize::new(2))
+            .space_positive(true)
+            .parentheses_negative(true)
+            .build()
+            .unwrap();
+        assert_runtime_eq!($format, Options::new() ; u8 i8 u16 i16 u32 i32 u64 i64 u128 i128);
+        assert_runtime_eq!($format, affix ; u8 i8 u32 i32 u64 i64 u128 i128);
+        assert_runtime_eq!($format, padded ; u8 i8 u32 i32 u64 i64 u128 i128);
+    }};
+}
+
+#[test]
+fn standard_runtime_test() {
+    let format = Format::new(STANDARD).unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(b"-1234", (-1234i32).to_lexical_with_format(&mut buffer, &format, &Options::new()));
+    assert_format_eq!(STANDARD);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn radix_runtime_test() {
+    const BINARY: u128 = from_radix(2);
+    const HEX: u128 = from_radix(16);
+    let format = Format::new(HEX).unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(b"-FF", (-255i32).to_lexical_with_format(&mut buffer, &format, &Options::new()));
+    assert_format_eq!(BINARY);
+    assert_format_eq!(HEX);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn odd_radix_runtime_test() {
+    const BASE3: u128 = from_radix(3);
+    const BASE36: u128 = from_radix(36);
+    assert_format_eq!(BASE3);
+    assert_format_eq!(BASE36);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format_runtime_test() {
+    const SEPARATOR: u128 =
+        NumberFormatBuilder::new().digit_separator(core::num::NonZeroU8::new(b'_')).build();
+    const REQUIRED_SIGN: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+
+    let format = Format::new(REQUIRED_SIGN).unwrap();
+    let mut buffer = [b'\x00'; 512];
+    assert_eq!(b"+12", 12u8.to_lexical_with_format(&mut buffer, &format, &Options::new()));
+    assert_format_eq!(SEPARATOR);
+    assert_format_eq!(REQUIRED_SIGN);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn affix_runtime_test() {
+    const AFFIX: u128 = NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .base_prefix(core::num::NonZeroU8::new(b'q'))
+        .base_suffix(core::num::NonZeroU8::new(b'w'))
+        .build();
+    const HEX: u128 = NumberFormatBuilder::rebuild(AFFIX).radix(16).build();
+    const REQUIRED: u128 = NumberFormatBuilder::rebuild(HEX)
+        .required_base_prefix(true)
+        .required_base_suffix(true)
+        .case_sensitive_base_prefix(true)
+        .build();
+    assert_format_eq!(AFFIX);
+    assert_format_eq!(HEX);
+    assert_format_eq!(REQUIRED);
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn runtime_proptest(i in i64::MIN..i64::MAX, grouping in 1usize..5) {
+        let options = Options::builder()
+            .digit_grouping(NonZeroUsize::new(grouping))
+            .parentheses_negative(true)
+            .build()
+            .unwrap();
+        let format = Format::new(STANDARD).unwrap();
+        let mut expected = [b'\x00'; 512];
+        let mut actual = [b'\x00'; 512];
+        prop_assert_eq!(
+            i.to_lexical_with_options::<STANDARD>(&mut expected, &options),
+            i.to_lexical_with_format(&mut actual, &format, &options)
+        );
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(not(feature = "compact"))]
+#![cfg(feature = "simd")]
+
+use lexical_write_integer::decimal::Decimal;
+use lexical_write_integer::simd;
+use quickcheck::quickcheck;
+
+fn write_8_digits_with(value: u32, write: unsafe fn(u32, &mut [u8])) {
+    let expected = format!("{:08}", value);
+    let mut buffer = [b'\x00'; 8];
+    unsafe { write(value, &mut buffer) };
+    assert_eq!(&buffer, expected.as_bytes());
+}
+
+fn write_8_digits(value: u32) {
+    write_8_digits_with(value, simd::write_8_digits);
+    write_8_digits_with(value, simd::write_8_digits_swar);
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+    write_8_digits_with(value, simd::write_8_digits_sse2);
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon", target_endian = "little"))]
+    write_8_digits_with(value, simd::write_8_digits_neon);
+}
+
+#[test]
+fn write_8_digits_test() {
+    write_8_digits(0);
+    write_8_digits(1);
+    write_8_digits(9);
+    write_8_digits(10);
+    write_8_digits(9999);
+    write_8_digits(10000);
+    write_8_digits(10001);
+    write_8_digits(12345678);
+    write_8_digits(87654321);
+    write_8_digits(90000009);
+    write_8_digits(99999999);
+    for value in (0..100_000_000).step_by(9973) {
+        write_8_digits(value);
+    }
+}
+
+#[test]
+fn decimal_padded_test() {
+    let mut buffer = [b'\x00'; 19];
+    unsafe { simd::decimal_padded(0, &mut buffer) };
+    assert_eq!(&buffer, b"0000000000000000000");
+    unsafe { simd::decimal_padded(123456789, &mut buffer) };
+    assert_eq!(&buffer, b"0000000000123456789");
+    unsafe { simd::decimal_padded(9999999999999999999, &mut buffer) };
+    assert_eq!(&buffer, b"9999999999999999999");
+
+    let mut buffer = [b'\x00'; 8];
+    unsafe { simd::decimal_padded(42, &mut buffer) };
+    assert_eq!(&buffer, b"00000042");
+}
+
+#[test]
+fn decimal_test() {
+    let mut buffer = [b'\x00'; 48];
+    unsafe {
+        assert_eq!(99999999u32.decimal(&mut buffer), 8);
+        assert_eq!(&buffer[..8], b"99999999");
+        assert_eq!(100000000u32.decimal(&mut buffer), 9);
+        assert_eq!(&buffer[..9], b"100000000");
+        assert_eq!(u32::MAX.decimal(&mut buffer), 10);
+        assert_eq!(&buffer[..10], b"4294967295");
+        assert_eq!(10000000000000000u64.decimal(&mut buffer), 17);
+        assert_eq!(&buffer[..17], b"10000000000000000");
+        assert_eq!(u64::MAX.decimal(&mut buffer), 20);
+        assert_eq!(&buffer[..20], b"18446744073709551615");
+        assert_eq!(u128::MAX.decimal(&mut buffer), 39);
+        assert_eq!(&buffer[..39], b"340282366920938463463374607431768211455");
+    }
+}
+
+quickcheck! {
+    #[cfg_attr(miri, ignore)]
+    fn write_8_digits_quickcheck(x: u32) -> bool {
+        write_8_digits(x % 100_000_000);
+        true
+    }
+
+    #[cfg_attr(miri, ignore)]
+    fn u64toa_quickcheck(x: u64) -> bool {
+        let actual = x.to_string();
+        let mut buffer = [b'\x00'; 32];
+        actual.len() == unsafe { x.decimal(&mut buffer) } &&
+            &buffer[..actual.len()] == actual.as_bytes()
+    }
+}
@@ -0,0 +1,133 @@
+//! Append numbers to vectors and strings, without intermediate buffers.
+
+#![cfg(feature = "write")]
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::{vector_spare_as_slice, ToLexical, ToLexicalWithOptions, WriteOptions};
+
+/// Append a number to a vector of decimal-encoded bytes.
+///
+/// The vector reserves space for the number, which is then written
+/// directly into the spare capacity, avoiding an intermediate buffer.
+///
+/// * `n`       - Number to write.
+/// * `vec`     - Vector to append the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let mut vec = b"x=".to_vec();
+/// lexical::write_vec(5, &mut vec);
+/// assert_eq!(vec, b"x=5");
+/// # }
+/// ```
+#[inline]
+pub fn write_vec<N: ToLexical>(n: N, vec: &mut Vec<u8>) {
+    vec.reserve(N::FORMATTED_SIZE_DECIMAL);
+    // SAFETY: safe since the spare capacity is of sufficient size.
+    unsafe {
+        let count = lexical_core::write_unchecked(n, vector_spare_as_slice(vec)).len();
+        vec.set_len(vec.len() + count);
+    }
+}
+
+/// Append a number to a vector of bytes with custom writing options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to write.
+/// * `vec`     - Vector to append the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// let mut vec = b"x=".to_vec();
+/// lexical::write_vec_with_options::<_, FORMAT>(1.0, &mut vec, &options);
+/// assert_eq!(vec, b"x=1");
+/// # }
+/// ```
+#[inline]
+pub fn write_vec_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    vec: &mut Vec<u8>,
+    options: &N::Options,
+) {
+    // Need to use the buffer_size hint to properly deal with float formatting options.
+    vec.reserve(N::Options::buffer_size::<N, FORMAT>(options));
+    // SAFETY: safe since the spare capacity is of sufficient size.
+    unsafe {
+        let count = lexical_core::write_with_options_unchecked::<_, FORMAT>(
+            n,
+            vector_spare_as_slice(vec),
+            options,
+        )
+        .len();
+        vec.set_len(vec.len() + count);
+    }
+}
+
+/// Append a number to a decimal-encoded string.
+///
+/// * `n`       - Number to write.
+/// * `string`  - String to append the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let mut string = String::from("x=");
+/// lexical::write_string(0.5, &mut string);
+/// assert_eq!(string, "x=0.5");
+/// # }
+/// ```
+#[inline]
+pub fn write_string<N: ToLexical>(n: N, string: &mut String) {
+    // SAFETY: safe since all the numerical strings we write are ASCII.
+    write_vec(n, unsafe { string.as_mut_vec() })
+}
+
+/// Append a number to a string with custom writing options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to write.
+/// * `string`  - String to append the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// let mut string = String::from("x=");
+/// lexical::write_string_with_options::<_, FORMAT>(1.0, &mut string, &options);
+/// assert_eq!(string, "x=1");
+/// # }
+/// ```
+#[inline]
+pub fn write_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    string: &mut String,
+    options: &N::Options,
+) {
+    // SAFETY: safe since all the numerical strings we write are ASCII.
+    write_vec_with_options::<_, FORMAT>(n, unsafe { string.as_mut_vec() }, options)
+}
@@ -41,6 +41,13 @@
 #![cfg_attr(feature = "write", doc = "")]
 #![cfg_attr(feature = "write", doc = " - [`to_string`]")]
 #![cfg_attr(feature = "write", doc = " - [`to_string_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`to_string_with_format`]")]
+#![cfg_attr(feature = "write", doc = " - [`to_string_with_override`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_vec`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_vec_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_string`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_string_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`display`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -48,6 +55,9 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_with_format`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_format`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_with_override`]")]
 //!
 //! # Features
 //!
@@ -251,12 +261,22 @@
 //!
 //! [`to_string`]: fn.to_string.html
 //! [`to_string_with_options`]: fn.to_string_with_options.html
+//! [`to_string_with_format`]: fn.to_string_with_format.html
+//! [`to_string_with_override`]: fn.to_string_with_override.html
+//! [`write_vec`]: fn.write_vec.html
+//! [`write_vec_with_options`]: fn.write_vec_with_options.html
+//! [`write_string`]: fn.write_string.html
+//! [`write_string_with_options`]: fn.write_string_with_options.html
+//! [`display`]: fn.display.html
 //! [`write_with_options`]: crate::write_with_options
 //! [`write_with_options_unchecked`]: crate::write_with_options_unchecked
 //! [`parse`]: crate::parse
 //! [`parse_partial`]: crate::parse_partial
 //! [`parse_with_options`]: crate::parse_with_options
 //! [`parse_partial_with_options`]: crate::parse_partial_with_options
+//! [`parse_with_format`]: crate::parse_with_format
+//! [`parse_partial_with_format`]: crate::parse_partial_with_format
+//! [`parse_with_override`]: crate::parse_with_override
 //!
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
 //! [`ParseFloatOptions`]: crate::ParseFloatOptions
@@ -274,34 +294,112 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+#[cfg(feature = "write")]
+use core::fmt;
+
 #[cfg(all(feature = "write", not(feature = "std")))]
 use alloc::string::String;
 #[cfg(all(feature = "write", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "write", not(feature = "std")))]
 use alloc::vec::Vec;
 #[cfg(all(feature = "write", feature = "std"))]
 use std::string::String;
 #[cfg(all(feature = "write", feature = "std"))]
 use std::vec::Vec;
 
-pub use lexical_core::format::{self, format_error, format_is_valid, NumberFormatBuilder};
-#[cfg(feature = "parse")]
-pub use lexical_core::Error;
+#[cfg(feature = "write")]
+mod append;
+
+#[cfg(feature = "write")]
+pub use self::append::{write_string, write_string_with_options};
+#[cfg(feature = "write")]
+pub use self::append::{write_vec, write_vec_with_options};
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_core::charconv;
+#[cfg(all(feature = "parse", feature = "std"))]
+pub use lexical_core::column;
+#[cfg(feature = "write-integers")]
+pub use lexical_core::itoa;
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+pub use lexical_core::lint;
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_core::numerals;
+#[cfg(feature = "write-floats")]
+pub use lexical_core::ryu;
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+pub use lexical_core::smallest;
+pub use lexical_core::format::{self, format_error, format_is_valid, Format, NumberFormatBuilder};
+#[cfg(all(feature = "proptest", feature = "std"))]
+pub use lexical_core::strategy;
+#[cfg(all(feature = "parse", feature = "write"))]
+pub use lexical_core::{reformat, reformat_with_formats};
+#[cfg(all(feature = "write", feature = "arrayvec"))]
+pub use lexical_core::write_array_string;
+#[cfg(all(feature = "write", feature = "heapless"))]
+pub use lexical_core::write_heapless;
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_core::OverrideOptions;
 #[cfg(feature = "parse")]
 pub use lexical_core::ParseOptions;
-#[cfg(feature = "parse")]
+#[cfg(any(feature = "parse", feature = "write"))]
 pub use lexical_core::Result;
 #[cfg(feature = "write")]
 pub use lexical_core::WriteOptions;
 #[cfg(feature = "f16")]
 pub use lexical_core::{bf16, f16};
 #[cfg(feature = "parse-floats")]
-pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
+pub use lexical_core::{
+    const_f32,
+    const_f64,
+    constant,
+    parse_float_options,
+    parse_narrow,
+    parse_narrow_with_options,
+    representation,
+    representation_with_options,
+    Narrow,
+    ParseFloatOptions,
+    ParseFloatOptionsBuilder,
+    ParseFloatOptionsOverride,
+    Representation,
+    MAX_SLOW_PATH_STACK_BYTES,
+};
+#[cfg(all(feature = "parse-floats", feature = "stats"))]
+pub use lexical_core::{reset_stats, stats, Stats};
 #[cfg(feature = "parse-integers")]
-pub use lexical_core::{parse_integer_options, ParseIntegerOptions, ParseIntegerOptionsBuilder};
+pub use lexical_core::{
+    parse_integer_options,
+    ParseIntegerOptions,
+    ParseIntegerOptionsBuilder,
+    ParseIntegerOptionsOverride,
+};
 #[cfg(feature = "write-floats")]
-pub use lexical_core::{write_float_options, WriteFloatOptions, WriteFloatOptionsBuilder};
+pub use lexical_core::{
+    write_float_options,
+    WriteFloatOptions,
+    WriteFloatOptionsBuilder,
+    WriteFloatOptionsOverride,
+};
 #[cfg(feature = "write-integers")]
-pub use lexical_core::{write_integer_options, WriteIntegerOptions, WriteIntegerOptionsBuilder};
+pub use lexical_core::{
+    write_integer_options,
+    WriteIntegerOptions,
+    WriteIntegerOptionsBuilder,
+    WriteIntegerOptionsOverride,
+};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use lexical_core::{write_io, write_io_with_options};
+#[cfg(feature = "write")]
+pub use lexical_core::{write_to, write_to_with_options};
+#[cfg(feature = "write")]
+pub use lexical_core::{write_slice, write_slice_with_options};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use lexical_core::{write_slice_to_vec, write_slice_to_vec_with_options};
+#[cfg(all(feature = "write", feature = "std", feature = "rayon"))]
+pub use lexical_core::{write_slice_parallel, write_slice_parallel_with_options};
+#[cfg(any(feature = "parse", feature = "write"))]
+pub use lexical_core::{Error, ErrorContext, ErrorKind, PartialError};
 #[cfg(feature = "write")]
 pub use lexical_core::{FormattedSize, BUFFER_SIZE};
 #[cfg(feature = "parse")]
@@ -324,6 +422,19 @@ unsafe fn vector_as_slice<T>(buf: &mut Vec<T>) -> &mut [T] {
     unsafe { core::slice::from_raw_parts_mut(first, buf.capacity()) }
 }
 
+/// Get the spare capacity of a vector as a slice.
+///
+/// # Safety
+///
+/// Safe if we never read uninitialized memory.
+#[inline]
+#[cfg(feature = "write")]
+unsafe fn vector_spare_as_slice<T>(buf: &mut Vec<T>) -> &mut [T] {
+    let len = buf.len();
+    // SAFETY: safe if as long as uninitialized memory is never read.
+    unsafe { &mut vector_as_slice(buf)[len..] }
+}
+
 /// High-level conversion of a number to a decimal-encoded string.
 ///
 /// * `n`       - Number to convert to string.
@@ -390,6 +501,145 @@ pub fn to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
     }
 }
 
+/// High-level conversion of a number to a string with a number format
+/// known at runtime.
+///
+/// * `n`       - Number to convert to string.
+/// * `format`  - Validated number format.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics for floats if the mantissa radix and exponent base are not a
+/// supported combination, or if the options punctuation conflicts with
+/// the format.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let format = lexical::Format::new(lexical::format::STANDARD).unwrap();
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::to_string_with_format(0.0, &format, &options), "0");
+/// assert_eq!(lexical::to_string_with_format(123.456, &format, &options), "123.456");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn to_string_with_format<N: ToLexicalWithOptions>(
+    n: N,
+    format: &Format,
+    options: &N::Options,
+) -> String {
+    let size = N::Options::buffer_size_with_format::<N>(options, format);
+    let mut buf = vec![0u8; size];
+    let len = lexical_core::write_with_format(n, &mut buf, format, options).len();
+    buf.truncate(len);
+    // SAFETY: safe since the written number is always valid ASCII.
+    unsafe { String::from_utf8_unchecked(buf) }
+}
+
+/// High-level conversion of a number to a string with per-call option
+/// overrides.
+///
+/// This is the same as [`to_string_with_options`], except the overrides
+/// are applied to the options for this call only. Returns an error if
+/// the options with the overrides applied are invalid.
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `n`         - Number to convert to string.
+/// * `options`   - Options to specify number writing.
+/// * `overrides` - Overrides for a subset of the options.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// use core::num::NonZeroUsize;
+///
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::new();
+/// let overrides =
+///     lexical::WriteFloatOptionsOverride::new().max_significant_digits(NonZeroUsize::new(3));
+/// let string = lexical::to_string_with_override::<_, FORMAT>(123.456, &options, &overrides);
+/// assert_eq!(string, Ok("123.0".to_string()));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn to_string_with_override<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    options: &N::Options,
+    overrides: &<N::Options as OverrideOptions>::Override,
+) -> Result<String>
+where
+    N::Options: OverrideOptions,
+{
+    let options = options.with_override(overrides)?;
+    Ok(to_string_with_options::<N, FORMAT>(n, &options))
+}
+
+/// Adapter to display a number with custom writing options.
+///
+/// Returns a value implementing [`fmt::Display`], which writes the number
+/// without allocating, so formatted numbers can be embedded in `format!`
+/// or `write!`. Formatter flags, such as the width or fill, are ignored:
+/// use the writing options instead.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to display.
+/// * `options` - Options to specify number writing.
+///
+/// Without the `std` feature, options that may require a buffer larger
+/// than [`BUFFER_SIZE`] bytes, such as large exponent break points,
+/// cause formatting to fail with [`fmt::Error`].
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// let value = lexical::display::<_, FORMAT>(1.0, &options);
+/// assert_eq!(format!("x={}", value), "x=1");
+/// # }
+/// ```
+///
+/// [`fmt::Display`]: core::fmt::Display
+/// [`fmt::Error`]: core::fmt::Error
+#[inline]
+#[cfg(feature = "write")]
+pub fn display<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    options: &N::Options,
+) -> impl fmt::Display + '_ {
+    Display::<N, FORMAT> {
+        n,
+        options,
+    }
+}
+
+/// Number with custom writing options, implementing [`fmt::Display`].
+#[cfg(feature = "write")]
+struct Display<'a, N: ToLexicalWithOptions, const FORMAT: u128> {
+    n: N,
+    options: &'a N::Options,
+}
+
+#[cfg(feature = "write")]
+impl<'a, N: ToLexicalWithOptions, const FORMAT: u128> fmt::Display for Display<'a, N, FORMAT> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        lexical_core::write_to_with_options::<_, _, FORMAT>(self.n, f, self.options)
+    }
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
@@ -554,3 +804,178 @@ pub fn parse_partial_with_options<
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes.as_ref(), options)
 }
+
+/// High-level conversion of bytes to a number, recovering the partially
+/// parsed value on an error.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. Otherwise, the error includes the value parsed
+/// from the longest valid prefix of the string, and the number of bytes
+/// in that prefix, for best-effort recovery. For integers that overflow,
+/// this is the value before the digit that overflowed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_recover::<u8, _>("255"), Ok(255));
+///
+/// let error = lexical::parse_recover::<u8, _>("2567").unwrap_err();
+/// assert!(error.error().is_overflow());
+/// assert_eq!(error.value(), Some(&25));
+/// assert_eq!(error.count(), 2);
+///
+/// let error = lexical::parse_recover::<f32, _>("1.5x").unwrap_err();
+/// assert_eq!(error.error(), lexical::Error::InvalidDigit(3));
+/// assert_eq!(error.value(), Some(&1.5));
+/// assert_eq!(error.count(), 3);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_recover<N: FromLexical, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+) -> core::result::Result<N, PartialError<N>> {
+    lexical_core::parse_recover(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with custom parsing options,
+/// recovering the partially parsed value on an error.
+///
+/// This is the same as [`parse_recover`], except the number is parsed
+/// with custom parsing options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let error = lexical::parse_recover_with_options::<f32, _, FORMAT>("1,25 ", &options);
+/// assert_eq!(error.unwrap_err().value(), Some(&1.25));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_recover_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> core::result::Result<N, PartialError<N>> {
+    lexical_core::parse_recover_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level conversion of bytes to a number with a number format
+/// known at runtime.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Validated number format.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let format = lexical::Format::new(lexical::format::STANDARD).unwrap();
+/// let options = lexical::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::parse_with_format::<f32, _>("1,2345", &format, &options), Ok(1.2345));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_with_format<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+    format: &Format,
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_with_format(bytes.as_ref(), format, options)
+}
+
+/// High-level, partial conversion of bytes to a number with a number
+/// format known at runtime.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Validated number format.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let format = lexical::Format::new(lexical::format::STANDARD).unwrap();
+/// let options = lexical::ParseFloatOptions::new();
+/// assert_eq!(lexical::parse_partial_with_format::<f32, _>("1.5x", &format, &options), Ok((1.5, 3)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_partial_with_format<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>>(
+    bytes: Bytes,
+    format: &Format,
+    options: &N::Options,
+) -> Result<(N, usize)> {
+    N::from_lexical_partial_with_format(bytes.as_ref(), format, options)
+}
+
+/// High-level conversion of bytes to a number with per-call option
+/// overrides.
+///
+/// This is the same as [`parse_with_options`], except the overrides
+/// are applied to the options for this call only. Returns an error if
+/// the options with the overrides applied are invalid.
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `bytes`     - Byte slice to convert to number.
+/// * `options`   - Options to specify number parsing.
+/// * `overrides` - Overrides for a subset of the options.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseFloatOptions::new();
+/// let overrides = lexical::ParseFloatOptionsOverride::new().decimal_point(b',');
+/// assert_eq!(lexical::parse_with_override::<f32, _, FORMAT>("1,2345", &options, &overrides), Ok(1.2345));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_with_override<N: FromLexicalWithOptions, Bytes: AsRef<[u8]>, const FORMAT: u128>(
+    bytes: Bytes,
+    options: &N::Options,
+    overrides: &<N::Options as OverrideOptions>::Override,
+) -> Result<N>
+where
+    N::Options: OverrideOptions,
+{
+    lexical_core::parse_with_override::<N, FORMAT>(bytes.as_ref(), options, overrides)
+}
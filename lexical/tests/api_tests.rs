@@ -16,6 +16,67 @@ fn float_to_string_test() {
     assert_eq!(lexical::to_string_with_options::<_, FORMAT>(12345.0f32, &options), "12345.0");
 }
 
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_vec_test() {
+    let mut vec = b"x=".to_vec();
+    lexical::write_vec(12345u32, &mut vec);
+    lexical::write_vec(-1i8, &mut vec);
+    assert_eq!(vec, b"x=12345-1");
+
+    let options = lexical::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let mut string = String::from("x=");
+    lexical::write_string(12345u32, &mut string);
+    lexical::write_string_with_options::<_, FORMAT>(u128::MAX, &mut string, &options);
+    assert_eq!(string, format!("x=12345{}", u128::MAX));
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_vec_test() {
+    let mut string = String::new();
+    lexical::write_string(12345.0f32, &mut string);
+    assert_eq!(string, "12345.0");
+
+    // Large exponent break points require more than `BUFFER_SIZE` bytes.
+    let options = lexical::WriteFloatOptions::builder()
+        .positive_exponent_break(core::num::NonZeroI32::new(400))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let mut vec = b"x=".to_vec();
+    lexical::write_vec_with_options::<_, FORMAT>(1e300f64, &mut vec, &options);
+    assert_eq!(vec, format!("x=1{}.0", "0".repeat(300)).as_bytes());
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_display_test() {
+    let options = lexical::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let value = lexical::display::<_, FORMAT>(-12345i32, &options);
+    assert_eq!(format!("{}", value), "-12345");
+    assert_eq!(format!("[{}, {}]", value, value), "[-12345, -12345]");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_display_test() {
+    let options = lexical::WriteFloatOptions::builder().trim_floats(true).build().unwrap();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    assert_eq!(format!("x={}", lexical::display::<_, FORMAT>(12345.0f32, &options)), "x=12345");
+    assert_eq!(format!("{}", lexical::display::<_, FORMAT>(1.5f64, &options)), "1.5");
+
+    // Large exponent break points require more than `BUFFER_SIZE` bytes.
+    let options = lexical::WriteFloatOptions::builder()
+        .positive_exponent_break(core::num::NonZeroI32::new(400))
+        .build()
+        .unwrap();
+    let expected = format!("1{}.0", "0".repeat(300));
+    assert_eq!(format!("{}", lexical::display::<_, FORMAT>(1e300f64, &options)), expected);
+}
+
 #[test]
 #[cfg(feature = "parse-integers")]
 fn string_to_integer_test() {
@@ -10,6 +10,7 @@ cd "$script_dir"/..
 WORKSPACES=(
     "lexical"
     "lexical-core"
+    "lexical-format-macro"
     "lexical-parse-float"
     "lexical-parse-integer"
     "lexical-write-float"